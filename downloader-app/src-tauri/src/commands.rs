@@ -10,14 +10,16 @@ use std::time::Duration;
 
 use downloader_core::project::{
     append_project_download_log, append_project_index, generate_sidecars_for_completed,
-    project_history_key, resolve_project_output_dir,
+    project_history_key, resolve_project_output_dir, write_project_manifest,
+    write_project_topics_index,
 };
 use downloader_core::{
-    DEFAULT_CONCURRENCY, Database, DownloadAttemptQuery, DownloadAttemptStatus, DownloadEngine,
-    DownloadedRegistry, HttpClient, InputType, NewDownloadAttempt, Queue, QueueMetadata,
-    QueueProcessingOptions, QueueStatus, RateLimiter, RegistryLookup, ResolveContext, ResolveError,
-    RetryPolicy, build_default_resolver_registry, build_preferred_filename,
-    extract_reference_confidence, load_runtime_cookie_jar, parse_input, parse_ris_content,
+    CrossrefConfig, DEFAULT_CONCURRENCY, Database, DownloadAttemptQuery, DownloadAttemptStatus,
+    DownloadEngine, DownloadedRegistry, HttpClient, InputType, NewDownloadAttempt, Queue,
+    QueueMetadata, QueueProcessingOptions, QueueStatus, RateLimiter, RegistryLookup,
+    ResolveContext, ResolveError, RetryPolicy, SidecarFormat, build_default_resolver_registry,
+    build_preferred_filename, extract_reference_confidence, load_runtime_cookie_jar,
+    load_runtime_domain_headers, parse_input, parse_ris_content,
 };
 use serde::Serialize;
 use tauri::Emitter;
@@ -95,7 +97,7 @@ pub struct ConvertProgress {
 // Managed app state (shared between commands)
 // ---------------------------------------------------------------------------
 
-/// Shared state managed by Tauri. Holds the current download's interrupt flag.
+/// Shared state managed by Tauri. Holds the current download's interrupt and pause flags.
 ///
 /// # Concurrency contract
 ///
@@ -109,12 +111,16 @@ pub struct AppState {
     /// Set to `Some(flag)` while a `start_download_with_progress` call is active.
     /// `cancel_download` stores the flag here; each new run creates a fresh Arc.
     pub interrupted: Mutex<Option<Arc<AtomicBool>>>,
+    /// Set to `Some(flag)` while a `start_download_with_progress` call is active.
+    /// `pause_download` / `resume_download` toggle the flag; cleared with the interrupt slot.
+    pub paused: Mutex<Option<Arc<AtomicBool>>>,
 }
 
 impl Default for AppState {
     fn default() -> Self {
         Self {
             interrupted: Mutex::new(None),
+            paused: Mutex::new(None),
         }
     }
 }
@@ -123,21 +129,45 @@ impl Default for AppState {
 // Cookie jar loader
 // ---------------------------------------------------------------------------
 
-/// Builds an `HttpClient` with persisted cookies (if available), or a plain one.
+/// Builds an `HttpClient` with persisted cookies and auth tokens (if available),
+/// or a plain one.
+///
+/// When `domains` is given, only cookies for those hosts are loaded so a
+/// download never carries cookies for unrelated sites.
 ///
 /// Failures loading cookies are logged and silently ignored — downloads proceed
 /// without auth rather than failing hard on a keychain or decryption problem.
-fn build_http_client_with_cookies() -> HttpClient {
-    match load_runtime_cookie_jar(None, false) {
+fn build_http_client_with_cookies(domains: Option<&[String]>) -> HttpClient {
+    let client = match load_runtime_cookie_jar(None, false, domains) {
         Ok(Some(jar)) => HttpClient::with_cookie_jar(jar),
         Ok(None) => HttpClient::new(),
         Err(e) => {
             warn!(error = %e, "Could not load persisted cookies; continuing without auth");
             HttpClient::new()
         }
+    };
+    match load_runtime_domain_headers(None) {
+        Ok(headers) => client.with_domain_headers(headers),
+        Err(e) => {
+            warn!(error = %e, "Could not load auth tokens; continuing without them");
+            client
+        }
     }
 }
 
+/// Returns the distinct hosts of the project's pending (resolved) queue URLs.
+async fn pending_download_hosts(queue: &Queue, project_key: &str) -> Vec<String> {
+    let hosts: HashSet<String> = queue
+        .list_by_status_in_project(QueueStatus::Pending, Some(project_key))
+        .await
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|item| Url::parse(&item.url).ok())
+        .filter_map(|url| url.host_str().map(str::to_ascii_lowercase))
+        .collect();
+    hosts.into_iter().collect()
+}
+
 // ---------------------------------------------------------------------------
 // Config loader
 // ---------------------------------------------------------------------------
@@ -293,8 +323,15 @@ fn mark_interrupt_requested(state: &AppState) {
     }
 }
 
+fn set_pause_requested(state: &AppState, paused: bool) {
+    if let Some(flag) = state.paused.lock().unwrap().as_ref() {
+        flag.store(paused, Ordering::SeqCst);
+    }
+}
+
 fn clear_interrupt_slot(state: &AppState) {
     *state.interrupted.lock().unwrap() = None;
+    *state.paused.lock().unwrap() = None;
 }
 
 // ---------------------------------------------------------------------------
@@ -441,6 +478,8 @@ async fn log_skipped_attempt(
         topics: None,
         parse_confidence: metadata.parse_confidence.as_deref(),
         parse_confidence_factors: metadata.parse_confidence_factors.as_deref(),
+        resolver_path: metadata.resolver_path.as_deref(),
+        input_source_file: metadata.input_source_file.as_deref(),
     };
     if let Err(err) = queue.log_download_attempt(&attempt).await {
         warn!(
@@ -472,15 +511,18 @@ async fn resolve_and_enqueue(
 
     let parse_result = parse_input(&joined);
 
-    let cookie_jar = match load_runtime_cookie_jar(None, false) {
+    let cookie_jar = match load_runtime_cookie_jar(None, false, None) {
         Ok(jar) => jar,
         Err(e) => {
             warn!(error = %e, "Could not load persisted cookies for resolver");
             None
         }
     };
-    let resolver_registry =
-        build_default_resolver_registry(cookie_jar, "downloader-app@downloader");
+    let resolver_registry = build_default_resolver_registry(
+        cookie_jar,
+        &CrossrefConfig::new("downloader-app@downloader"),
+        &[],
+    );
     let resolve_context = ResolveContext::default();
 
     let mut outcome = ResolveEnqueueOutcome {
@@ -517,12 +559,17 @@ async fn resolve_and_enqueue(
             suggested_filename: Some(build_preferred_filename(&resolved.url, &resolved.metadata)),
             title: resolved.metadata.get("title").cloned(),
             authors: resolved.metadata.get("authors").cloned(),
+            author_orcids: resolved.metadata.get("author_orcids").cloned(),
+            abstract_text: resolved.metadata.get("abstract").cloned(),
             year: resolved.metadata.get("year").cloned(),
             doi: resolved.metadata.get("doi").cloned(),
             topics: None,
             parse_confidence: reference_confidence.map(|d| d.level.to_string()),
             parse_confidence_factors: reference_confidence
                 .and_then(|d| serde_json::to_string(&d.factors).ok()),
+            read_timeout_secs: resolved.read_timeout_secs,
+            resolver_path: resolved.resolver_path.clone(),
+            input_source_file: None,
         };
 
         if queue
@@ -747,7 +794,8 @@ pub async fn start_download(
         .and_then(|mut v| v.pop())
         .map(|a| a.id);
 
-    let client = build_http_client_with_cookies();
+    let hosts = pending_download_hosts(&queue, &project_key).await;
+    let client = build_http_client_with_cookies(Some(&hosts));
     let rate_limiter = Arc::new(RateLimiter::new(Duration::from_millis(0)));
     let engine = DownloadEngine::new(defaults.concurrency, RetryPolicy::default(), rate_limiter)
         .map_err(|e| {
@@ -781,7 +829,15 @@ pub async fn start_download(
     if project.is_some() {
         let _ = append_project_index(&queue, &output_dir, &completed_before).await;
         let _ = append_project_download_log(&queue, &output_dir, log_watermark).await;
-        generate_sidecars_for_completed(&queue, &output_dir, &completed_before).await;
+        let _ = write_project_manifest(&queue, &output_dir).await;
+        let _ = write_project_topics_index(&queue, &output_dir, &[]).await;
+        generate_sidecars_for_completed(
+            &queue,
+            &output_dir,
+            &completed_before,
+            SidecarFormat::JsonLd,
+        )
+        .await;
     }
 
     for item in queue
@@ -946,8 +1002,12 @@ pub async fn start_download_with_progress(
     // Create a fresh interrupt flag for this run; register it for cancel_download.
     let flag = Arc::new(AtomicBool::new(false));
     *state.interrupted.lock().unwrap() = Some(Arc::clone(&flag));
+    // Likewise a fresh pause flag for pause_download / resume_download.
+    let paused = Arc::new(AtomicBool::new(false));
+    *state.paused.lock().unwrap() = Some(Arc::clone(&paused));
 
-    let client = build_http_client_with_cookies();
+    let hosts = pending_download_hosts(&queue, &project_key).await;
+    let client = build_http_client_with_cookies(Some(&hosts));
     let rate_limiter = Arc::new(RateLimiter::new(Duration::from_millis(0)));
     let engine = DownloadEngine::new(defaults.concurrency, RetryPolicy::default(), rate_limiter)
         .map_err(|e| {
@@ -1008,6 +1068,7 @@ pub async fn start_download_with_progress(
                 flag_for_engine,
                 QueueProcessingOptions {
                     project_scope: Some(project_key_for_engine),
+                    paused: Some(paused),
                     ..QueueProcessingOptions::default()
                 },
             )
@@ -1114,11 +1175,19 @@ pub async fn start_download_with_progress(
     // Clear the interrupt flag slot.
     clear_interrupt_slot(&state);
 
-    // Generate project artefacts (index.md, download.log, sidecars) when a project is set.
+    // Generate project artefacts (index.md, download.log, manifest.json, sidecars) when a project is set.
     if project.is_some() {
         let _ = append_project_index(&queue, &output_dir, &completed_before).await;
         let _ = append_project_download_log(&queue, &output_dir, log_watermark).await;
-        generate_sidecars_for_completed(&queue, &output_dir, &completed_before).await;
+        let _ = write_project_manifest(&queue, &output_dir).await;
+        let _ = write_project_topics_index(&queue, &output_dir, &[]).await;
+        generate_sidecars_for_completed(
+            &queue,
+            &output_dir,
+            &completed_before,
+            SidecarFormat::JsonLd,
+        )
+        .await;
     }
 
     for item in queue
@@ -1156,6 +1225,24 @@ pub async fn cancel_download(state: tauri::State<'_, AppState>) -> Result<(), St
     Ok(())
 }
 
+/// Pauses an active `start_download_with_progress` run.
+///
+/// In-flight downloads finish; no new item starts until `resume_download` is called.
+#[tracing::instrument(skip(state))]
+#[tauri::command]
+pub async fn pause_download(state: tauri::State<'_, AppState>) -> Result<(), String> {
+    set_pause_requested(&state, true);
+    Ok(())
+}
+
+/// Resumes a run paused by `pause_download`.
+#[tracing::instrument(skip(state))]
+#[tauri::command]
+pub async fn resume_download(state: tauri::State<'_, AppState>) -> Result<(), String> {
+    set_pause_requested(&state, false);
+    Ok(())
+}
+
 /// Opens a folder in the OS file manager (Finder, Explorer, Nautilus, etc.).
 #[tracing::instrument(skip(app_handle))]
 #[tauri::command]
@@ -1690,6 +1777,7 @@ mod tests {
         use std::sync::{Arc, Mutex};
         let state_inner = AppState {
             interrupted: Mutex::new(Some(Arc::new(AtomicBool::new(false)))),
+            ..AppState::default()
         };
         // Extract the flag before moving state_inner into a State wrapper.
         let flag_clone = Arc::clone(state_inner.interrupted.lock().unwrap().as_ref().unwrap());
@@ -2053,10 +2141,34 @@ mod tests {
         assert!(state.interrupted.lock().unwrap().is_none());
     }
 
+    #[test]
+    fn test_set_pause_requested_toggles_active_flag() {
+        let flag = Arc::new(AtomicBool::new(false));
+        let state = AppState {
+            paused: Mutex::new(Some(Arc::clone(&flag))),
+            ..AppState::default()
+        };
+        set_pause_requested(&state, true);
+        assert!(flag.load(Ordering::SeqCst), "pause sets the flag");
+        set_pause_requested(&state, false);
+        assert!(!flag.load(Ordering::SeqCst), "resume clears the flag");
+    }
+
+    #[test]
+    fn test_clear_interrupt_slot_also_clears_pause_slot() {
+        let state = AppState {
+            interrupted: Mutex::new(Some(Arc::new(AtomicBool::new(false)))),
+            paused: Mutex::new(Some(Arc::new(AtomicBool::new(true)))),
+        };
+        clear_interrupt_slot(&state);
+        assert!(state.paused.lock().unwrap().is_none());
+    }
+
     #[test]
     fn test_clear_interrupt_slot_resets_state_on_success_path() {
         let state = AppState {
             interrupted: Mutex::new(Some(Arc::new(AtomicBool::new(false)))),
+            ..AppState::default()
         };
         clear_interrupt_slot(&state);
         assert!(state.interrupted.lock().unwrap().is_none());
@@ -2066,6 +2178,7 @@ mod tests {
     fn test_clear_interrupt_slot_resets_state_on_engine_error_path() {
         let state = AppState {
             interrupted: Mutex::new(Some(Arc::new(AtomicBool::new(true)))),
+            ..AppState::default()
         };
         clear_interrupt_slot(&state);
         assert!(state.interrupted.lock().unwrap().is_none());
@@ -2075,6 +2188,7 @@ mod tests {
     fn test_clear_interrupt_slot_resets_state_on_join_error_path() {
         let state = AppState {
             interrupted: Mutex::new(Some(Arc::new(AtomicBool::new(true)))),
+            ..AppState::default()
         };
         clear_interrupt_slot(&state);
         assert!(state.interrupted.lock().unwrap().is_none());
@@ -13,6 +13,8 @@ pub fn run() {
             commands::start_download,
             commands::start_download_with_progress,
             commands::cancel_download,
+            commands::pause_download,
+            commands::resume_download,
             commands::list_projects,
             commands::open_folder,
             commands::pick_bibliography_files,
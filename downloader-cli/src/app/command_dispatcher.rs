@@ -1,13 +1,18 @@
-//! CLI command routing: runs Auth, Log, Search, and Config subcommands.
+//! CLI command routing: runs Auth, Log, Search, Stats, Config, Queue, Db, and Completions
+//! subcommands.
 //!
 //! If the user invoked a top-level command (e.g. `downloader auth`, `downloader log`),
 //! this module runs the corresponding handler and returns the exit outcome. Otherwise
-//! returns `None` so the caller continues with the main download flow.
+//! returns `None` so the caller continues with the main download flow. `downloader resolve`
+//! also returns `None`: it runs the main flow and stops before downloading.
 
 use anyhow::Result;
 
 use crate::app::{config_runtime, terminal};
-use crate::cli::{AuthCommand, Cli, Command, ConfigCommand};
+use crate::cli::{
+    AuthCommand, BudgetCommand, Cli, Command, ConfigCommand, DbCommand, HistoryCommand,
+    QueueCommand,
+};
 use crate::{ProcessExit, commands};
 
 /// If `cli` has a top-level command, run it and return `Some(exit)`; otherwise return `None`.
@@ -24,16 +29,23 @@ pub(crate) async fn try_dispatch(
         terminal::no_color_env_requested(),
         terminal::is_dumb_terminal(),
     );
-    terminal::init_tracing("info", false, no_color);
+    terminal::init_tracing("info", false, no_color, cli.download.log_format);
 
     match command {
+        Command::Resolve(_) => return Ok(None),
         Command::Auth { command } => match command {
             AuthCommand::Capture(capture_args) => {
-                commands::run_auth_capture_command(capture_args.save_cookies)?;
+                commands::run_auth_capture_command(capture_args).await?;
             }
             AuthCommand::Clear => {
                 commands::run_auth_clear_command()?;
             }
+            AuthCommand::Token(token_args) => {
+                commands::run_auth_token_command(token_args)?;
+            }
+            AuthCommand::Status(status_args) => {
+                commands::run_auth_status_command(status_args.probe).await?;
+            }
         },
         Command::Log(log_args) => {
             commands::run_log_command(log_args).await?;
@@ -41,10 +53,22 @@ pub(crate) async fn try_dispatch(
         Command::Search(search_args) => {
             commands::run_search_command(search_args).await?;
         }
+        Command::Stats(stats_args) => {
+            commands::run_stats_command(stats_args).await?;
+        }
         Command::Config { command } => match command {
             ConfigCommand::Show => {
                 commands::run_config_show_command(&cli.download, cli_sources)?;
             }
+            ConfigCommand::Validate(validate_args) => {
+                commands::run_config_validate_command(validate_args.file.as_deref())?;
+            }
+            ConfigCommand::Init(init_args) => {
+                commands::run_config_init_command(init_args.force)?;
+            }
+            ConfigCommand::Set(set_args) => {
+                commands::run_config_set_command(&set_args.key, &set_args.value)?;
+            }
         },
         Command::Export(export_args) => {
             commands::run_export_command(export_args).await?;
@@ -52,6 +76,50 @@ pub(crate) async fn try_dispatch(
         Command::Convert(convert_args) => {
             commands::run_convert_command(convert_args).await?;
         }
+        Command::Reindex(reindex_args) => {
+            commands::run_reindex_command(reindex_args).await?;
+        }
+        Command::Queue { command } => match command {
+            QueueCommand::Status(status_args) => {
+                commands::run_queue_status_command(status_args).await?;
+            }
+            QueueCommand::Export(transfer_args) => {
+                commands::run_queue_export_command(transfer_args).await?;
+            }
+            QueueCommand::Import(transfer_args) => {
+                commands::run_queue_import_command(transfer_args).await?;
+            }
+            QueueCommand::DeadLetter(scope_args) => {
+                commands::run_queue_dead_letter_command(scope_args).await?;
+            }
+            QueueCommand::Requeue(requeue_args) => {
+                commands::run_queue_requeue_command(requeue_args).await?;
+            }
+            QueueCommand::Reprioritize(reprioritize_args) => {
+                commands::run_queue_reprioritize_command(reprioritize_args).await?;
+            }
+        },
+        Command::Db { command } => match command {
+            DbCommand::Backup(backup_args) => {
+                commands::run_db_backup_command(backup_args).await?;
+            }
+            DbCommand::Compact(scope_args) => {
+                commands::run_db_compact_command(scope_args).await?;
+            }
+        },
+        Command::History { command } => match command {
+            HistoryCommand::Prune(prune_args) => {
+                commands::run_history_prune_command(prune_args).await?;
+            }
+        },
+        Command::Budget { command } => match command {
+            BudgetCommand::Status(status_args) => {
+                commands::run_budget_status_command(status_args).await?;
+            }
+        },
+        Command::Completions(completions_args) => {
+            commands::run_completions_command(completions_args)?;
+        }
     }
 
     Ok(Some(ProcessExit::Success))
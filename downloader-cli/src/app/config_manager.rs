@@ -1,11 +1,19 @@
 //! Configuration lifecycle: load file config, merge CLI, resolve timeouts and DB options.
 
+use std::path::{Path, PathBuf};
+
 use anyhow::Result;
 
 use crate::app::config_runtime::{self, CliValueSources, HttpTimeoutSettings};
-use crate::app_config::load_default_file_config;
+use crate::app_config::{
+    discover_ignore_file, discover_project_config, layer_project_config, load_default_file_config,
+};
 use crate::cli::{Cli, DownloadArgs};
-use downloader_core::{DatabaseOptions, configure_resolver_http_timeouts};
+use downloader_core::{
+    CrossrefApiToken, DEFAULT_MAX_REDIRECTS, DatabaseOptions, DomainHeaders, RetryPolicy,
+    configure_max_redirects, configure_resolver_http_timeouts,
+    configure_resolver_timeout_overrides,
+};
 
 /// Resolved configuration bundle used to build RunContext.
 /// Its fields are copied into RunContext in runtime; ResolvedConfig is not stored in RunContext.
@@ -13,27 +21,93 @@ pub(crate) struct ResolvedConfig {
     pub(crate) args: DownloadArgs,
     pub(crate) http_timeouts: HttpTimeoutSettings,
     pub(crate) db_options: DatabaseOptions,
+    /// Download retry policy with config-file status and backoff overrides.
+    pub(crate) retry_policy: RetryPolicy,
+    /// Bearer tokens from the global config file (`auth_bearer`).
+    pub(crate) auth_bearer: Option<DomainHeaders>,
+    /// Crossref polite-pool contact from the global config file (`crossref_mailto`).
+    pub(crate) crossref_mailto: Option<String>,
+    /// Crossref Plus API token from the global config file (`crossref_token`).
+    pub(crate) crossref_token: Option<CrossrefApiToken>,
+    /// Project config problems to log once tracing is initialised.
+    pub(crate) config_warnings: Vec<String>,
 }
 
-/// Load file config, merge CLI overrides, resolve HTTP timeouts and DB options, apply resolver timeouts.
+/// Load file config, merge CLI overrides, resolve HTTP timeouts and DB options, apply resolver
+/// timeouts (global and per-resolver) and the shared redirect limit.
 /// Single entry point that returns a resolved config bundle.
 pub(crate) fn resolve_config(cli: &Cli, cli_sources: &CliValueSources) -> Result<ResolvedConfig> {
     let loaded_config = load_default_file_config()?;
-    let args = config_runtime::apply_config_defaults(
-        cli.download.clone(),
+
+    // Project config discovery starts where downloads will land; the global
+    // `output_dir` is the only global key that can influence it.
+    let start_dir = cli
+        .download_args()
+        .output_dir
+        .clone()
+        .or_else(|| {
+            loaded_config
+                .config
+                .as_ref()
+                .and_then(|config| config.output_dir.clone())
+        })
+        .unwrap_or_else(|| PathBuf::from("."));
+    let project_config = discover_project_config(Path::new(&start_dir))?;
+    let mut config_warnings = Vec::new();
+    let layered_config = project_config.map(|project| {
+        config_warnings.extend(
+            project
+                .warnings
+                .into_iter()
+                .map(|warning| format!("{}: {warning}", project.path.display())),
+        );
+        layer_project_config(loaded_config.config.as_ref(), &project.config)
+    });
+
+    let mut args = config_runtime::apply_config_defaults(
+        cli.download_args().clone(),
         cli_sources,
-        loaded_config.config.as_ref(),
+        layered_config.as_ref().or(loaded_config.config.as_ref()),
     )?;
+    if args.ignore_file.is_none() {
+        args.ignore_file = discover_ignore_file(Path::new(&start_dir));
+    }
     let http_timeouts = config_runtime::resolve_http_timeouts(loaded_config.config.as_ref());
     let db_options = config_runtime::resolve_db_options(loaded_config.config.as_ref());
+    let retry_policy =
+        config_runtime::resolve_retry_policy(args.max_retries, loaded_config.config.as_ref());
     configure_resolver_http_timeouts(
         http_timeouts.resolver_connect_secs,
         http_timeouts.resolver_read_secs,
     );
+    configure_resolver_timeout_overrides(
+        loaded_config
+            .config
+            .as_ref()
+            .map(|config| config.resolver_timeouts.clone())
+            .unwrap_or_default(),
+    );
+    configure_max_redirects(
+        loaded_config
+            .config
+            .as_ref()
+            .and_then(|config| config.max_redirects)
+            .unwrap_or(DEFAULT_MAX_REDIRECTS),
+    );
+    let auth_bearer = loaded_config
+        .config
+        .as_ref()
+        .and_then(|config| config.auth_bearer.clone());
+    let global_config = loaded_config.config.as_ref();
     Ok(ResolvedConfig {
         args,
         http_timeouts,
         db_options,
+        retry_policy,
+        auth_bearer,
+        crossref_mailto: global_config.and_then(|config| config.crossref_mailto.clone()),
+        crossref_token: global_config.and_then(|config| config.crossref_token.clone()),
+        config_warnings,
     })
 }
 
@@ -50,6 +124,7 @@ mod tests {
     /// succeeds and returns default-like values for args and timeouts.
     #[test]
     fn test_resolve_config_no_config_file_returns_defaults() {
+        let _env = xdg_env_lock().lock().unwrap();
         let temp = TempDir::new().unwrap();
         let prev = std::env::var_os("XDG_CONFIG_HOME");
         // SAFETY: test isolates env change and restores on drop.
@@ -85,6 +160,54 @@ mod tests {
         );
     }
 
+    /// CLI flags beat the project config, which beats the global config.
+    #[test]
+    fn test_resolve_config_layers_project_config_between_cli_and_global() {
+        let _env = xdg_env_lock().lock().unwrap();
+        let config_home = TempDir::new().unwrap();
+        let prev = std::env::var_os("XDG_CONFIG_HOME");
+        // SAFETY: test isolates env change and restores on drop.
+        unsafe {
+            std::env::set_var("XDG_CONFIG_HOME", config_home.path());
+        }
+        let _restore = RestoreEnv::new("XDG_CONFIG_HOME", prev);
+        std::fs::create_dir_all(config_home.path().join("downloader")).unwrap();
+        std::fs::write(
+            config_home.path().join("downloader").join("config.toml"),
+            "concurrency = 8\nrate_limit = 500\n",
+        )
+        .unwrap();
+
+        let project_root = TempDir::new().unwrap();
+        std::fs::create_dir_all(project_root.path().join(".downloader")).unwrap();
+        std::fs::write(
+            project_root.path().join(".downloader").join("config.toml"),
+            "concurrency = 3\nrate_limit = 2000\nsidecar = true\ndb_busy_timeout_ms = 10\n",
+        )
+        .unwrap();
+
+        let output_dir = project_root.path().to_str().unwrap();
+        let cli = Cli::try_parse_from(["downloader", "-o", output_dir, "-l", "0"]).unwrap();
+        let sources = CliValueSources {
+            output_dir: true,
+            rate_limit: true,
+            ..CliValueSources::default()
+        };
+        let resolved = resolve_config(&cli, &sources).unwrap();
+
+        assert_eq!(resolved.args.concurrency, 3, "project beats global");
+        assert_eq!(resolved.args.rate_limit, 0, "CLI beats project");
+        assert!(resolved.args.sidecar);
+        assert_eq!(resolved.config_warnings.len(), 1);
+        assert!(resolved.config_warnings[0].contains("db_busy_timeout_ms"));
+    }
+
+    /// Serialises tests that point `XDG_CONFIG_HOME` at a temp dir.
+    fn xdg_env_lock() -> &'static std::sync::Mutex<()> {
+        static LOCK: std::sync::OnceLock<std::sync::Mutex<()>> = std::sync::OnceLock::new();
+        LOCK.get_or_init(|| std::sync::Mutex::new(()))
+    }
+
     /// Restores an env var to its previous value (or removes it) when dropped.
     struct RestoreEnv {
         key: &'static str,
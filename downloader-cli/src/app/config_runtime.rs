@@ -1,6 +1,8 @@
+use std::time::Duration;
+
 use anyhow::{Result, bail};
 use clap::{ArgMatches, CommandFactory, FromArgMatches, parser::ValueSource};
-use downloader_core::DatabaseOptions;
+use downloader_core::{DatabaseOptions, FailureType, RetryPolicy};
 
 use crate::app_config::{FileConfig, VerbositySetting};
 use crate::cli::{Cli, DownloadArgs};
@@ -18,6 +20,8 @@ pub(crate) const RESPECTFUL_MAX_RETRIES: u8 = 1;
 #[derive(Debug, Clone, Copy, Default)]
 pub(crate) struct CliValueSources {
     pub(crate) output_dir: bool,
+    pub(crate) project: bool,
+    pub(crate) layout: bool,
     pub(crate) concurrency: bool,
     pub(crate) rate_limit: bool,
     pub(crate) respectful: bool,
@@ -53,19 +57,23 @@ pub(crate) fn parse_cli_with_sources() -> (Cli, CliValueSources) {
     let command = Cli::command();
     let matches = command.get_matches();
     let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|err| err.exit());
+    // `downloader resolve` carries its own copy of the download flags.
+    let download_matches = matches.subcommand_matches("resolve").unwrap_or(&matches);
 
     let sources = CliValueSources {
-        output_dir: is_commandline_value(&matches, "output_dir"),
-        concurrency: is_commandline_value(&matches, "concurrency"),
-        rate_limit: is_commandline_value(&matches, "rate_limit"),
-        respectful: is_commandline_value(&matches, "respectful"),
-        check_robots: is_commandline_value(&matches, "check_robots"),
-        verbose: is_commandline_value(&matches, "verbose"),
-        quiet: is_commandline_value(&matches, "quiet"),
-        debug: is_commandline_value(&matches, "debug"),
-        detect_topics: is_commandline_value(&matches, "detect_topics"),
-        topics_file: is_commandline_value(&matches, "topics_file"),
-        sidecar: is_commandline_value(&matches, "sidecar"),
+        output_dir: is_commandline_value(download_matches, "output_dir"),
+        project: is_commandline_value(download_matches, "project"),
+        layout: is_commandline_value(download_matches, "layout"),
+        concurrency: is_commandline_value(download_matches, "concurrency"),
+        rate_limit: is_commandline_value(download_matches, "rate_limit"),
+        respectful: is_commandline_value(download_matches, "respectful"),
+        check_robots: is_commandline_value(download_matches, "check_robots"),
+        verbose: is_commandline_value(download_matches, "verbose"),
+        quiet: is_commandline_value(download_matches, "quiet"),
+        debug: is_commandline_value(download_matches, "debug"),
+        detect_topics: is_commandline_value(download_matches, "detect_topics"),
+        topics_file: is_commandline_value(download_matches, "topics_file"),
+        sidecar: is_commandline_value(download_matches, "sidecar"),
     };
     (cli, sources)
 }
@@ -87,6 +95,19 @@ pub(crate) fn apply_config_defaults(
             args.output_dir = Some(output_dir.clone());
         }
 
+        if !cli_sources.project
+            && args.project.is_none()
+            && let Some(project) = &file_config.project
+        {
+            args.project = Some(project.clone());
+        }
+
+        if !cli_sources.layout
+            && let Some(layout) = file_config.layout
+        {
+            args.layout = layout.into();
+        }
+
         if !cli_sources.concurrency
             && let Some(concurrency) = file_config.concurrency
         {
@@ -139,6 +160,17 @@ pub(crate) fn apply_config_defaults(
         {
             args.sidecar = sidecar;
         }
+
+        // `--disable-resolver` replaces the config list rather than adding to it.
+        if args.disable_resolver.is_empty() {
+            args.disable_resolver = file_config.disabled_resolvers.clone();
+        }
+        if args.robots_allow.is_empty() {
+            args.robots_allow = file_config.robots_allow.clone();
+        }
+        if args.byte_budget.is_none() {
+            args.byte_budget = file_config.byte_budget;
+        }
     }
 
     // When --respectful is set, override concurrency, rate_limit, max_retries (plan: respectful wins).
@@ -223,13 +255,38 @@ pub(crate) fn resolve_db_options(file_config: Option<&FileConfig>) -> DatabaseOp
         options.max_connections = n;
     }
     if let Some(ms) = file_config.db_busy_timeout_ms {
-        options.busy_timeout_ms = ms;
+        options.busy_timeout = Duration::from_millis(u64::from(ms));
+    }
+    if let Some(mode) = file_config.db_synchronous {
+        options.synchronous = mode;
     }
     options
 }
 
+/// Builds the download retry policy: `max_retries` attempts plus any
+/// `retry_status.*` / `retry_max_backoff.*` overrides from the config file.
+pub(crate) fn resolve_retry_policy(
+    max_retries: u8,
+    file_config: Option<&FileConfig>,
+) -> RetryPolicy {
+    let mut policy = RetryPolicy::with_max_attempts(u32::from(max_retries));
+    let Some(file_config) = file_config else {
+        return policy;
+    };
+    for (&status, &failure_type) in &file_config.retry_status {
+        policy = policy.with_status_override(status, failure_type);
+    }
+    if let Some(secs) = file_config.retry_max_backoff_transient_secs {
+        policy = policy.with_max_delay_for(FailureType::Transient, Duration::from_secs(secs));
+    }
+    if let Some(secs) = file_config.retry_max_backoff_rate_limited_secs {
+        policy = policy.with_max_delay_for(FailureType::RateLimited, Duration::from_secs(secs));
+    }
+    policy
+}
+
 pub(crate) fn resolve_default_log_level(args: &DownloadArgs) -> &'static str {
-    if args.quiet {
+    if args.quiet || args.stdout {
         "error"
     } else if args.debug {
         "trace"
@@ -7,7 +7,9 @@ use reqwest::cookie::Jar;
 
 use crate::app::config_runtime::HttpTimeoutSettings;
 use crate::cli::DownloadArgs;
-use downloader_core::{DatabaseOptions, ParsedItem};
+use downloader_core::{
+    CrossrefConfig, DatabaseOptions, DomainHeaders, ParsedItem, RetryPolicy, SetCookieLog,
+};
 
 /// Holds shared state built during startup so the rest of `run_downloader`
 /// can use `ctx.args`, `ctx.output_dir`, etc., instead of passing many arguments.
@@ -15,14 +17,22 @@ pub(crate) struct RunContext {
     pub(crate) args: DownloadArgs,
     pub(crate) http_timeouts: HttpTimeoutSettings,
     pub(crate) db_options: DatabaseOptions,
+    pub(crate) retry_policy: RetryPolicy,
     pub(crate) output_dir: PathBuf,
     pub(crate) cookie_jar: Option<Arc<Jar>>,
+    /// `Set-Cookie` headers the download client received, for cookie write-back.
+    pub(crate) set_cookie_log: Arc<SetCookieLog>,
+    /// Per-domain auth headers (bearer tokens) attached alongside cookies.
+    pub(crate) domain_headers: DomainHeaders,
+    /// Crossref polite-pool contact and optional Plus token for resolvers.
+    pub(crate) crossref: CrossrefConfig,
     pub(crate) input_text: Option<String>,
     pub(crate) piped_stdin_was_empty: bool,
-    /// Pre-parsed items from bibliography files (`--bibliography`).
+    /// Pre-parsed items from positional input files and bibliography files (`--bibliography`).
     ///
     /// These are injected directly into the resolution pipeline alongside
     /// items produced by `parse_input(input_text)`, preserving full metadata
-    /// (title, authors, year) extracted from `.bib` and `.ris` files.
+    /// (title, authors, year) extracted from `.bib` and `.ris` files. Items from
+    /// positional files carry their [`ParsedItem::source_file`].
     pub(crate) bibliography_items: Vec<ParsedItem>,
 }
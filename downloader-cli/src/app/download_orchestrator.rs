@@ -1,29 +1,30 @@
 //! Build HTTP client and download engine, then process the queue.
 //! Maps engine errors to anyhow with context for CI diagnostics.
 
+use std::collections::BTreeSet;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 use std::time::Duration;
 
 use anyhow::{Context, Result};
 use downloader_core::{
-    DownloadEngine, HttpClient, Queue, QueueProcessingOptions, RateLimiter, RetryPolicy,
-    RobotsCache, project_history_key,
+    ContentTopicExtractor, DEFAULT_CONTENT_TOPIC_PAGES, DownloadEngine, HttpClient, Queue,
+    QueueProcessingOptions, RateLimiter, RobotsCache, UrlPreflight, load_custom_topics,
+    load_runtime_cookie_jar, project_history_key,
 };
+use reqwest::cookie::Jar;
 use tracing::debug;
+use url::Url;
 
 use crate::app::context::RunContext;
 
-/// Builds client, engine, and options; runs queue processing. Returns download statistics.
-pub(crate) async fn run_download(
-    ctx: &RunContext,
-    queue: Arc<Queue>,
-    interrupted: Arc<AtomicBool>,
-) -> Result<downloader_core::DownloadStats> {
-    let client = if let Some(jar) = &ctx.cookie_jar {
+/// Builds the download HTTP client: cookies, domain headers, and download timeouts.
+pub(crate) fn build_download_client(ctx: &RunContext) -> HttpClient {
+    if let Some(jar) = &ctx.cookie_jar {
         debug!("Creating HTTP client with cookie jar");
-        HttpClient::with_cookie_jar_and_timeouts(
+        HttpClient::with_recorded_cookie_jar_and_timeouts(
             jar.clone(),
+            Arc::clone(&ctx.set_cookie_log),
             ctx.http_timeouts.download_connect_secs,
             ctx.http_timeouts.download_read_secs,
         )
@@ -32,31 +33,85 @@ pub(crate) async fn run_download(
             ctx.http_timeouts.download_connect_secs,
             ctx.http_timeouts.download_read_secs,
         )
-    };
+    }
+    .with_domain_headers(ctx.domain_headers.clone())
+}
 
-    let retry_policy = RetryPolicy::with_max_attempts(u32::from(ctx.args.max_retries));
+/// Returns the distinct, lowercased hosts of `urls`; unparseable URLs are skipped.
+pub(crate) fn download_hosts<'a>(urls: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let hosts: BTreeSet<String> = urls
+        .into_iter()
+        .filter_map(|raw| Url::parse(raw).ok())
+        .filter_map(|url| url.host_str().map(str::to_ascii_lowercase))
+        .collect();
+    hosts.into_iter().collect()
+}
+
+/// Reloads the cookie jar with only the cookies for `hosts`, so downloads never
+/// carry cookies for sites outside the resolved queue.
+///
+/// Cookies read from stdin cannot be read a second time, so that jar is kept
+/// as loaded.
+pub(crate) fn scope_cookie_jar(ctx: &RunContext, hosts: &[String]) -> Result<Option<Arc<Jar>>> {
+    if ctx.cookie_jar.is_none() || ctx.args.cookies.as_deref() == Some("-") {
+        return Ok(ctx.cookie_jar.clone());
+    }
+    debug!(hosts = hosts.len(), "Scoping cookie jar to download hosts");
+    load_runtime_cookie_jar(ctx.args.cookies.as_deref(), false, Some(hosts))
+        .context("failed to reload cookies for download hosts")
+}
 
-    let rate_limiter = if ctx.args.rate_limit == 0 {
+/// Builds the per-domain rate limiter from `--rate-limit`, `--rate-limit-jitter`,
+/// and `--rate-adaptive`.
+pub(crate) fn build_rate_limiter(ctx: &RunContext) -> Arc<RateLimiter> {
+    let delay = Duration::from_millis(ctx.args.rate_limit);
+    let limiter = if ctx.args.rate_limit == 0 && !ctx.args.rate_adaptive {
         debug!("rate limiting disabled");
-        Arc::new(RateLimiter::disabled())
+        RateLimiter::disabled()
     } else if ctx.args.rate_limit_jitter > 0 {
         debug!(
             rate_limit_ms = ctx.args.rate_limit,
             jitter_ms = ctx.args.rate_limit_jitter,
             "rate limiting with jitter enabled"
         );
-        Arc::new(RateLimiter::new_with_jitter(
-            Duration::from_millis(ctx.args.rate_limit),
-            ctx.args.rate_limit_jitter,
-        ))
+        RateLimiter::new_with_jitter(delay, ctx.args.rate_limit_jitter)
     } else {
         debug!(rate_limit_ms = ctx.args.rate_limit, "rate limiting enabled");
-        Arc::new(RateLimiter::new(Duration::from_millis(ctx.args.rate_limit)))
+        RateLimiter::new(delay)
     };
+    if ctx.args.rate_adaptive {
+        debug!("adaptive rate limiting enabled");
+        Arc::new(limiter.with_adaptive())
+    } else {
+        Arc::new(limiter)
+    }
+}
+
+/// Builds a `--check` pre-flight with the same client, rate limit, and robots
+/// policy as [`run_download`].
+pub(crate) fn build_preflight(ctx: &RunContext) -> UrlPreflight {
+    let preflight = UrlPreflight::new(build_download_client(ctx), build_rate_limiter(ctx));
+    if ctx.args.check_robots {
+        preflight.with_robots(Arc::new(RobotsCache::new()), ctx.args.robots_allow.clone())
+    } else {
+        preflight
+    }
+}
+
+/// Builds client, engine, and options; runs queue processing. Returns download statistics.
+pub(crate) async fn run_download(
+    ctx: &RunContext,
+    queue: Arc<Queue>,
+    interrupted: Arc<AtomicBool>,
+    abort: Arc<AtomicBool>,
+    paused: Arc<AtomicBool>,
+) -> Result<downloader_core::DownloadStats> {
+    let client = build_download_client(ctx);
+    let rate_limiter = build_rate_limiter(ctx);
 
     let engine = DownloadEngine::new(
         usize::from(ctx.args.concurrency),
-        retry_policy,
+        ctx.retry_policy.clone(),
         rate_limiter,
     )
     .context("invalid download engine configuration")?;
@@ -67,6 +122,21 @@ pub(crate) async fn run_download(
         None
     };
 
+    let content_topics = if ctx.args.topics_from_content {
+        let custom_topics = match ctx.args.topics_file.as_deref() {
+            Some(path) => load_custom_topics(path)
+                .with_context(|| format!("Cannot read topics file '{}'", path.display()))?,
+            None => Vec::new(),
+        };
+        debug!("Content topic extraction enabled");
+        Some(Arc::new(ContentTopicExtractor::new(
+            DEFAULT_CONTENT_TOPIC_PAGES,
+            custom_topics,
+        )))
+    } else {
+        None
+    };
+
     let stats = engine
         .process_queue_interruptible_with_options(
             queue.as_ref(),
@@ -75,9 +145,26 @@ pub(crate) async fn run_download(
             interrupted,
             QueueProcessingOptions {
                 generate_sidecars: ctx.args.sidecar,
+                sidecar_format: ctx.args.sidecar_format.into(),
+                layout: ctx.args.layout.into(),
                 check_robots: ctx.args.check_robots,
                 robots_cache,
+                robots_allow: ctx.args.robots_allow.clone(),
                 project_scope: Some(project_history_key(&ctx.output_dir)),
+                paused: Some(paused),
+                content_topics,
+                abort: Some(abort),
+                max_items: ctx
+                    .args
+                    .max_items
+                    .map(|max| usize::try_from(max).unwrap_or(usize::MAX)),
+                segmented_threshold: ctx
+                    .args
+                    .segments
+                    .map(|_| ctx.args.segment_threshold_mb.saturating_mul(1024 * 1024)),
+                segments: ctx.args.segments.map_or(0, usize::from),
+                min_file_size: (ctx.args.min_file_size > 0).then_some(ctx.args.min_file_size),
+                byte_budget: ctx.args.byte_budget,
             },
         )
         .await
@@ -85,3 +172,19 @@ pub(crate) async fn run_download(
 
     Ok(stats)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_download_hosts_dedups_and_lowercases() {
+        let hosts = download_hosts([
+            "https://Example.com/a.pdf",
+            "https://example.com/b.pdf",
+            "http://cdn.example.org:8080/c.pdf",
+            "not a url",
+        ]);
+        assert_eq!(hosts, vec!["cdn.example.org", "example.com"]);
+    }
+}
@@ -1,22 +1,42 @@
 //! Input validation, cookie jar loading, and assembly of input text from URLs and/or stdin.
 
+use std::collections::HashSet;
 use std::io::{self, IsTerminal, Read};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::{Context, Result, bail};
-use downloader_core::{ParsedItem, load_runtime_cookie_jar, parse_ris_content};
+use downloader_core::{
+    InputType, ParsedItem, extract_reference_confidence, load_runtime_cookie_jar,
+    looks_like_endnote_xml, looks_like_ris, parse_endnote_xml, parse_input, parse_ris_content,
+};
+use flate2::read::MultiGzDecoder;
 use reqwest::cookie::Jar;
 use tracing::info;
 
 use crate::app::validation;
-use crate::cli::DownloadArgs;
+use crate::cli::{DownloadArgs, ReferenceConfidenceArg};
+
+/// Report of references held back by `--min-reference-confidence`, written to the output dir.
+pub(crate) const NEEDS_VERIFICATION_FILENAME: &str = "needs-verification.txt";
+
+/// Leading bytes of every gzip stream (RFC 1952).
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Positional argument that reads an input file from stdin.
+const STDIN_INPUT_ARG: &str = "-";
+
+/// Source recorded on items read through [`STDIN_INPUT_ARG`].
+const STDIN_SOURCE_LABEL: &str = "stdin";
 
 /// Validates download input, loads the runtime cookie jar, assembles input text from
-/// positional URLs and/or stdin, and reads any bibliography files supplied via
+/// positional URLs and/or stdin, and reads any input files given positionally or via
 /// `--bibliography`. Returns values needed to build RunContext and to decide
 /// dry-run / quick-start.
 ///
+/// A positional argument that is `-` or names an existing file is read as an input
+/// file (see [`read_input_files`]); every other positional is URL/DOI text.
+///
 /// Returns `(cookie_jar, input_text, piped_stdin_was_empty, bibliography_items)`.
 #[allow(clippy::type_complexity)]
 pub(crate) fn process_input(
@@ -31,17 +51,31 @@ pub(crate) fn process_input(
         stdin_is_terminal,
     )?;
 
-    let cookie_jar = load_runtime_cookie_jar(args.cookies.as_deref(), args.save_cookies)?;
+    let (input_files, literal_inputs): (Vec<&str>, Vec<&str>) = args
+        .urls
+        .iter()
+        .map(String::as_str)
+        .partition(|arg| is_input_file_arg(arg));
+    let stdin_is_input_file = input_files.contains(&STDIN_INPUT_ARG);
+    if cookies_from_stdin && stdin_is_input_file {
+        bail!(
+            "What: both --cookies and an input file read from stdin ('-'). \
+             Why: stdin can only be read once. \
+             Fix: pass the cookies or the input list as a file path instead."
+        );
+    }
+
+    // Resolvers see every cookie; the jar is scoped to the resolved hosts before downloading.
+    let cookie_jar = load_runtime_cookie_jar(args.cookies.as_deref(), args.save_cookies, None)?;
 
     let mut input_segments = Vec::new();
-    if !args.urls.is_empty() {
-        input_segments.push(args.urls.join("\n"));
+    if !literal_inputs.is_empty() {
+        input_segments.push(literal_inputs.join("\n"));
     }
 
     let mut piped_stdin_was_empty = false;
-    if !cookies_from_stdin && !stdin_is_terminal {
-        let mut buffer = String::new();
-        io::stdin().read_to_string(&mut buffer)?;
+    if !cookies_from_stdin && !stdin_is_input_file && !stdin_is_terminal {
+        let buffer = read_input_text(io::stdin().lock())?;
         if buffer.trim().is_empty() {
             piped_stdin_was_empty = true;
         } else {
@@ -49,8 +83,9 @@ pub(crate) fn process_input(
         }
     }
 
+    let mut bibliography_items = read_input_files(&input_files)?;
+
     // Process --bibliography files.
-    let mut bibliography_items: Vec<ParsedItem> = Vec::new();
     for path in &args.bibliography_files {
         let (bib_segment, mut bib_items) = read_bibliography_file(path)?;
         if let Some(segment) = bib_segment {
@@ -73,46 +108,205 @@ pub(crate) fn process_input(
     ))
 }
 
+/// Returns true when a positional argument should be read as an input file.
+fn is_input_file_arg(arg: &str) -> bool {
+    arg == STDIN_INPUT_ARG || Path::new(arg).is_file()
+}
+
+/// Reads positional input files in order and merges their items into one list.
+///
+/// Each item records the file it came from ([`STDIN_SOURCE_LABEL`] for `-`).
+/// A DOI or URL already taken from an earlier file is dropped, and a one-line
+/// summary is logged per file.
+///
+/// # Errors
+///
+/// Returns an error when a file (or stdin) cannot be read.
+fn read_input_files(args: &[&str]) -> Result<Vec<ParsedItem>> {
+    let mut seen = HashSet::new();
+    let mut merged = Vec::new();
+    for &arg in args {
+        let (label, text, extension) = if arg == STDIN_INPUT_ARG {
+            let text = read_input_text(io::stdin().lock()).context(
+                "Cannot read input from stdin ('-'). \
+                 Why: the piped content is not valid (gzipped) UTF-8 text. \
+                 Fix: check what is piped into the command.",
+            )?;
+            (STDIN_SOURCE_LABEL, text, String::new())
+        } else {
+            let path = Path::new(arg);
+            let text = read_bibliography_text(path, "input")?;
+            (arg, text, bibliography_extension(path))
+        };
+
+        let (format, items, skipped) = parse_input_file(&text, &extension);
+        let found = items.len();
+        let mut duplicates = 0usize;
+        for item in items {
+            if matches!(item.input_type, InputType::Doi | InputType::Url)
+                && !seen.insert(item.to_string())
+            {
+                duplicates += 1;
+                continue;
+            }
+            merged.push(item.with_source_file(label));
+        }
+        info!(
+            file = label,
+            format,
+            items = found - duplicates,
+            duplicates,
+            skipped,
+            "Parsed input file"
+        );
+    }
+    Ok(merged)
+}
+
+/// Parses one input file's text, choosing the parser from its extension or content.
+///
+/// `.ris` and `.xml` files (or text that opens like RIS / EndNote XML) go to their
+/// bibliography parsers; everything else, including BibTeX, goes to [`parse_input`].
+///
+/// Returns `(format_name, items, skipped_count)`.
+fn parse_input_file(text: &str, extension: &str) -> (&'static str, Vec<ParsedItem>, usize) {
+    if extension == "ris" || (extension != "xml" && looks_like_ris(text)) {
+        let result = parse_ris_content(text);
+        return ("RIS", result.items, result.skipped.len());
+    }
+    if extension == "xml" || looks_like_endnote_xml(text) {
+        let result = parse_endnote_xml(text);
+        return ("EndNote XML", result.items, result.skipped.len());
+    }
+    let result = parse_input(text);
+    let skipped = result.skipped_count();
+    let format = if result.type_counts().bibtex > 0 {
+        "BibTeX"
+    } else {
+        "text"
+    };
+    (format, result.items, skipped)
+}
+
+/// Splits parsed items into those to resolve and references parsed below
+/// `min_confidence`, which must be verified by hand instead.
+///
+/// Non-reference items are always kept. Returns `(kept, needs_verification)`.
+pub(crate) fn split_by_reference_confidence(
+    items: Vec<&ParsedItem>,
+    min_confidence: ReferenceConfidenceArg,
+) -> (Vec<&ParsedItem>, Vec<&ParsedItem>) {
+    if min_confidence == ReferenceConfidenceArg::Low {
+        return (items, Vec::new());
+    }
+    items.into_iter().partition(|item| {
+        item.input_type != InputType::Reference
+            || min_confidence.admits(extract_reference_confidence(&item.raw).level)
+    })
+}
+
+/// Writes the raw text of `references`, one per line, to
+/// [`NEEDS_VERIFICATION_FILENAME`] in `output_dir`, replacing any earlier report.
+///
+/// # Errors
+///
+/// Returns an error when the report file cannot be written.
+pub(crate) fn write_needs_verification_report(
+    output_dir: &Path,
+    references: &[&ParsedItem],
+) -> Result<PathBuf> {
+    let path = output_dir.join(NEEDS_VERIFICATION_FILENAME);
+    let mut content = references
+        .iter()
+        .map(|item| item.raw.trim())
+        .collect::<Vec<_>>()
+        .join("\n");
+    content.push('\n');
+    std::fs::write(&path, content).with_context(|| {
+        format!(
+            "Cannot write '{}'. \
+             Why: the output directory may not be writable. \
+             Fix: check directory permissions, or lower --min-reference-confidence.",
+            path.display()
+        )
+    })?;
+    Ok(path)
+}
+
+/// Reads `reader` to a string, gunzipping it first when it starts with the gzip magic bytes.
+///
+/// Other input passes through unchanged. Concatenated gzip members (`cat a.gz b.gz`)
+/// are decoded as one stream.
+///
+/// # Errors
+///
+/// Returns an error when reading or decompressing fails, or the text is not UTF-8.
+fn read_input_text(mut reader: impl Read) -> io::Result<String> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+    if !bytes.starts_with(&GZIP_MAGIC) {
+        return String::from_utf8(bytes)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error));
+    }
+    let mut text = String::new();
+    MultiGzDecoder::new(bytes.as_slice()).read_to_string(&mut text)?;
+    Ok(text)
+}
+
+/// Lowercased format extension of a bibliography path, looking past a trailing `.gz`
+/// (`refs.bib.gz` → `bib`).
+fn bibliography_extension(path: &Path) -> String {
+    let extension = |path: &Path| {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or("")
+            .to_ascii_lowercase()
+    };
+    match extension(path).as_str() {
+        "gz" => extension(&path.with_extension("")),
+        _ => extension(path),
+    }
+}
+
+/// Reads a bibliography file as text, transparently decompressing gzip content.
+fn read_bibliography_text(path: &Path, format_name: &str) -> Result<String> {
+    std::fs::File::open(path)
+        .and_then(read_input_text)
+        .with_context(|| {
+            format!(
+                "Cannot read {format_name} file '{}'. \
+                 Why: the file may not exist, is not readable, or is not valid (gzipped) UTF-8 text. \
+                 Fix: check the path and file permissions.",
+                path.display()
+            )
+        })
+}
+
 /// Reads a bibliography file and returns either raw text (for `.bib`) or parsed items (for `.ris`).
 ///
 /// - `.bib` files: raw content is returned as a string segment for `parse_input` (which has
 ///   native BibTeX support). The items `Vec` will be empty.
 /// - `.ris` files: content is parsed with [`parse_ris_content`] and the resulting items are
 ///   returned. The segment `Option` will be `None`.
+/// - `.xml` files: treated as EndNote XML exports and parsed with [`parse_endnote_xml`],
+///   returned the same way as `.ris`.
 /// - Other extensions: an error is returned describing supported formats.
 ///
+/// Any of these may be gzip-compressed (`refs.bib.gz`); the format comes from the
+/// extension before `.gz`.
+///
 /// # Errors
 ///
 /// Returns an error when the file cannot be read, or has an unsupported extension.
 fn read_bibliography_file(path: &Path) -> Result<(Option<String>, Vec<ParsedItem>)> {
-    let extension = path
-        .extension()
-        .and_then(|ext| ext.to_str())
-        .unwrap_or("")
-        .to_ascii_lowercase();
-
-    match extension.as_str() {
+    match bibliography_extension(path).as_str() {
         "bib" => {
-            let content = std::fs::read_to_string(path).with_context(|| {
-                format!(
-                    "Cannot read BibTeX file '{}'. \
-                     Why: the file may not exist or is not readable. \
-                     Fix: check the path and file permissions.",
-                    path.display()
-                )
-            })?;
+            let content = read_bibliography_text(path, "BibTeX")?;
             info!(path = %path.display(), "Read BibTeX bibliography file");
             Ok((Some(content), Vec::new()))
         }
         "ris" => {
-            let content = std::fs::read_to_string(path).with_context(|| {
-                format!(
-                    "Cannot read RIS file '{}'. \
-                     Why: the file may not exist or is not readable. \
-                     Fix: check the path and file permissions.",
-                    path.display()
-                )
-            })?;
+            let content = read_bibliography_text(path, "RIS")?;
             let result = parse_ris_content(&content);
             info!(
                 path = %path.display(),
@@ -130,10 +324,29 @@ fn read_bibliography_file(path: &Path) -> Result<(Option<String>, Vec<ParsedItem
             }
             Ok((None, result.items))
         }
+        "xml" => {
+            let content = read_bibliography_text(path, "EndNote XML")?;
+            let result = parse_endnote_xml(&content);
+            info!(
+                path = %path.display(),
+                entries = result.entries.len(),
+                items = result.items.len(),
+                skipped = result.skipped.len(),
+                "Parsed EndNote XML bibliography file"
+            );
+            for skip_msg in &result.skipped {
+                tracing::warn!(
+                    path = %path.display(),
+                    message = %skip_msg,
+                    "Skipped EndNote record"
+                );
+            }
+            Ok((None, result.items))
+        }
         other => {
             bail!(
                 "What: unsupported bibliography file format '.{other}'. \
-                 Why: only BibTeX (.bib) and RIS (.ris) files are supported. \
+                 Why: only BibTeX (.bib), RIS (.ris), and EndNote XML (.xml) files are supported. \
                  Fix: convert the file to .bib or .ris format, or supply DOIs/URLs directly."
             );
         }
@@ -142,11 +355,120 @@ fn read_bibliography_file(path: &Path) -> Result<(Option<String>, Vec<ParsedItem
 
 #[cfg(test)]
 mod tests {
-    use super::process_input;
-    use crate::cli::Cli;
+    use super::{
+        NEEDS_VERIFICATION_FILENAME, parse_input_file, process_input, read_bibliography_file,
+        read_input_files, read_input_text, split_by_reference_confidence,
+        write_needs_verification_report,
+    };
+    use crate::cli::{Cli, ReferenceConfidenceArg};
     use clap::Parser;
+    use downloader_core::ParsedItem;
     use std::io::IsTerminal;
 
+    #[test]
+    fn test_split_by_reference_confidence_holds_back_weak_references() {
+        let high = ParsedItem::reference(
+            "Smith, J. (2024). Paper Title. Journal.",
+            "Smith, J. (2024). Paper Title. Journal.",
+        );
+        let low = ParsedItem::reference("smith 2024", "smith 2024");
+        let url = ParsedItem::url("https://example.com/a.pdf", "https://example.com/a.pdf");
+        let items = vec![&high, &low, &url];
+
+        let (kept, held) =
+            split_by_reference_confidence(items.clone(), ReferenceConfidenceArg::Low);
+        assert_eq!(kept.len(), 3);
+        assert!(held.is_empty());
+
+        let (kept, held) = split_by_reference_confidence(items, ReferenceConfidenceArg::Medium);
+        let kept_raw: Vec<_> = kept.iter().map(|item| item.raw.as_str()).collect();
+        assert_eq!(
+            kept_raw,
+            [
+                "Smith, J. (2024). Paper Title. Journal.",
+                "https://example.com/a.pdf"
+            ]
+        );
+        assert_eq!(held.len(), 1);
+        assert_eq!(held[0].raw, "smith 2024");
+    }
+
+    #[test]
+    fn test_write_needs_verification_report_lists_one_reference_per_line() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let first = ParsedItem::reference("smith 2024", "smith 2024");
+        let second = ParsedItem::reference("  Doe notes  ", "Doe notes");
+
+        let path = write_needs_verification_report(dir.path(), &[&first, &second]).unwrap();
+        assert_eq!(path, dir.path().join(NEEDS_VERIFICATION_FILENAME));
+        assert_eq!(
+            std::fs::read_to_string(path).unwrap(),
+            "smith 2024\nDoe notes\n"
+        );
+    }
+
+    const GZIP_TEST_BIB: &str = "@article{k1, title={First}, author={Smith, J.}, year={2024}, doi={10.1234/first}}\n\
+         @article{k2, title={Second}, author={Doe, A.}, year={2023}, url={https://example.com/second.pdf}}\n";
+
+    fn gzip(bytes: &[u8]) -> Vec<u8> {
+        use flate2::Compression;
+        use flate2::write::GzEncoder;
+        use std::io::Write;
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(bytes).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn test_read_input_text_passes_plain_text_through() {
+        let text = read_input_text(GZIP_TEST_BIB.as_bytes()).unwrap();
+        assert_eq!(text, GZIP_TEST_BIB);
+    }
+
+    #[test]
+    fn test_read_input_text_decompresses_gzip() {
+        let compressed = gzip(GZIP_TEST_BIB.as_bytes());
+        assert_eq!(
+            read_input_text(compressed.as_slice()).unwrap(),
+            GZIP_TEST_BIB
+        );
+    }
+
+    #[test]
+    fn test_read_input_text_rejects_invalid_utf8() {
+        let error = read_input_text(&[0xff, 0xfe, 0x00][..]).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    /// A gzipped `.bib.gz` file parses to exactly the same items as the plain `.bib`.
+    #[test]
+    fn test_gzipped_bibliography_parses_identically() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let plain_path = dir.path().join("refs.bib");
+        let gz_path = dir.path().join("refs.bib.gz");
+        std::fs::write(&plain_path, GZIP_TEST_BIB).unwrap();
+        std::fs::write(&gz_path, gzip(GZIP_TEST_BIB.as_bytes())).unwrap();
+
+        let (plain_text, plain_items) = read_bibliography_file(&plain_path).unwrap();
+        let (gz_text, gz_items) = read_bibliography_file(&gz_path).unwrap();
+        assert_eq!(gz_text, plain_text);
+        assert!(plain_items.is_empty() && gz_items.is_empty());
+
+        let plain = downloader_core::parse_input(&plain_text.unwrap());
+        let gz = downloader_core::parse_input(&gz_text.unwrap());
+        assert!(!plain.items.is_empty());
+        assert_eq!(format!("{:?}", gz.items), format!("{:?}", plain.items));
+    }
+
+    #[test]
+    fn test_gzipped_bibliography_format_comes_from_inner_extension() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("refs.csv.gz");
+        std::fs::write(&path, gzip(b"a,b\n")).unwrap();
+        let msg = read_bibliography_file(&path).unwrap_err().to_string();
+        assert!(msg.contains("unsupported bibliography file format '.csv'"));
+    }
+
     /// With empty urls and when stdin is not read (terminal) or read and empty,
     /// input_text is None. When stdin is terminal we don't read it so we only assert input_text.is_none().
     /// When stdin is non-terminal the test would read stdin and might block in cargo test; so we only run the assertion path when stdin is terminal.
@@ -297,4 +619,80 @@ mod tests {
             "bibliography_items should contain the DOI"
         );
     }
+
+    #[test]
+    fn test_parse_input_file_sniffs_ris_without_extension() {
+        let (format, items, _) =
+            parse_input_file("TY  - JOUR\nDO  - 10.9999/sniffed\nER  - \n", "txt");
+        assert_eq!(format, "RIS");
+        assert_eq!(items[0].value, "10.9999/sniffed");
+
+        let (format, items, _) = parse_input_file("https://example.com/a.pdf\n", "");
+        assert_eq!(format, "text");
+        assert_eq!(items.len(), 1);
+    }
+
+    /// Items from several files are merged in order, tagged with their file, and
+    /// DOIs repeated in a later file are dropped.
+    #[test]
+    fn test_read_input_files_merges_with_source_and_cross_file_dedup() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let list = dir.path().join("list.txt");
+        let ris = dir.path().join("refs.ris");
+        std::fs::write(&list, "10.1234/shared\nhttps://example.com/only-list.pdf\n").unwrap();
+        std::fs::write(
+            &ris,
+            "TY  - JOUR\nDO  - 10.1234/shared\nER  - \nTY  - JOUR\nDO  - 10.1234/only-ris\nER  - \n",
+        )
+        .unwrap();
+        let list_arg = list.to_str().unwrap();
+        let ris_arg = ris.to_str().unwrap();
+
+        let items = read_input_files(&[list_arg, ris_arg]).unwrap();
+        let tagged: Vec<_> = items
+            .iter()
+            .map(|item| (item.value.as_str(), item.source_file.as_deref().unwrap()))
+            .collect();
+        assert_eq!(
+            tagged,
+            [
+                ("10.1234/shared", list_arg),
+                ("https://example.com/only-list.pdf", list_arg),
+                ("10.1234/only-ris", ris_arg),
+            ]
+        );
+    }
+
+    /// An existing file given positionally is read as an input file, not as URL text.
+    #[test]
+    fn test_process_input_positional_file_is_read_as_input_file() {
+        if !std::io::stdin().is_terminal() {
+            return;
+        }
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("refs.bib");
+        std::fs::write(&path, GZIP_TEST_BIB).unwrap();
+        let path = path.to_str().unwrap().to_string();
+
+        let cli =
+            Cli::try_parse_from(["downloader", &path, "https://example.com/direct.pdf"]).unwrap();
+        let (_, input_text, _, items) = process_input(&cli.download).unwrap();
+        assert_eq!(
+            input_text.as_deref(),
+            Some("https://example.com/direct.pdf")
+        );
+        assert!(!items.is_empty());
+        assert!(
+            items
+                .iter()
+                .all(|item| item.source_file.as_deref() == Some(path.as_str()))
+        );
+    }
+
+    #[test]
+    fn test_process_input_rejects_stdin_file_with_stdin_cookies() {
+        let cli = Cli::try_parse_from(["downloader", "--cookies", "-", "-"]).unwrap();
+        let msg = process_input(&cli.download).unwrap_err().to_string();
+        assert!(msg.contains("stdin can only be read once"));
+    }
 }
@@ -7,9 +7,11 @@ pub(crate) mod context;
 pub(crate) mod download_orchestrator;
 pub(crate) mod exit_handler;
 pub(crate) mod input_processor;
+pub(crate) mod preprint_merge;
 pub(crate) mod progress_manager;
 pub(crate) mod queue_manager;
 pub(crate) mod resolution_orchestrator;
+pub(crate) mod run_report_writer;
 pub(crate) mod runtime;
 pub(crate) mod terminal;
 pub(crate) mod validation;
@@ -0,0 +1,260 @@
+//! Opt-in merging of arXiv preprints with their published versions (`--merge-duplicates`).
+//!
+//! After resolution, an arXiv result and a published-DOI result that describe
+//! the same work are collapsed into one: the arXiv copy (always open access) is
+//! kept and the published URL becomes its fallback. A pair matches when arXiv
+//! records the published DOI for the preprint, or when both titles are
+//! near-identical.
+
+use std::collections::{HashMap, HashSet};
+
+use downloader_core::{
+    ArxivWork, ResolveError, ResolvedUrl, arxiv_id_from_doi, lookup_arxiv_works,
+};
+use tracing::{debug, warn};
+
+use crate::search::{fuzzy_similarity, normalize_search_text};
+
+/// Minimum [`fuzzy_similarity`] between normalized titles for a title match.
+const TITLE_MATCH_THRESHOLD: f64 = 0.9;
+
+/// Titles with fewer words than this are too generic to match on.
+const MIN_TITLE_MATCH_WORDS: usize = 4;
+
+/// Metadata copied from the published version when the preprint lacks it.
+const MERGED_METADATA_KEYS: &[&str] = &["title", "authors", "year", "abstract"];
+
+/// A published result folded into the arXiv result describing the same work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PreprintMerge {
+    kept: usize,
+    dropped: usize,
+}
+
+/// Merges arXiv/published duplicates among `results` in place.
+///
+/// Looks up the arXiv preprints' titles and journal DOIs (a failed lookup
+/// falls back to titles already known), then folds each matched published
+/// result into its preprint. Returns the indices of the folded results, which
+/// should not be enqueued. `api_url` overrides the arXiv API endpoint.
+pub(crate) async fn merge_preprint_duplicates(
+    results: &mut [Result<ResolvedUrl, ResolveError>],
+    api_url: Option<&str>,
+) -> HashSet<usize> {
+    let preprint_ids: Vec<String> = results
+        .iter()
+        .filter_map(|result| preprint_id(result.as_ref().ok()?))
+        .collect();
+    if preprint_ids.is_empty() || !results.iter().flatten().any(is_published) {
+        return HashSet::new();
+    }
+
+    let works = match lookup_arxiv_works(&preprint_ids, api_url).await {
+        Ok(works) => works,
+        Err(error) => {
+            warn!(error = %error, "arXiv lookup failed; matching preprints by title only");
+            HashMap::new()
+        }
+    };
+
+    let merges = plan_preprint_merges(results, &works);
+    let mut dropped = HashSet::with_capacity(merges.len());
+    for merge in merges {
+        let Ok(published) = &results[merge.dropped] else {
+            continue;
+        };
+        let published = published.clone();
+        let Ok(preprint) = &mut results[merge.kept] else {
+            continue;
+        };
+        if let Some(title) = preprint_id(preprint)
+            .and_then(|id| works.get(&id))
+            .and_then(|work| work.title.clone())
+        {
+            preprint
+                .metadata
+                .entry("title".to_string())
+                .or_insert(title);
+        }
+        for key in MERGED_METADATA_KEYS {
+            if let Some(value) = published.metadata.get(*key) {
+                preprint
+                    .metadata
+                    .entry((*key).to_string())
+                    .or_insert_with(|| value.clone());
+            }
+        }
+        debug!(
+            preprint = %preprint.url,
+            published = %published.url,
+            "Merged arXiv preprint with its published version"
+        );
+        preprint.fallback_urls.push(published.url);
+        dropped.insert(merge.dropped);
+    }
+    dropped
+}
+
+/// Pairs each arXiv result with at most one published result for the same work.
+fn plan_preprint_merges(
+    results: &[Result<ResolvedUrl, ResolveError>],
+    works: &HashMap<String, ArxivWork>,
+) -> Vec<PreprintMerge> {
+    let mut merges = Vec::new();
+    let mut claimed = HashSet::new();
+    for (kept, preprint) in results.iter().enumerate() {
+        let Some(preprint) = preprint.as_ref().ok() else {
+            continue;
+        };
+        let Some(id) = preprint_id(preprint) else {
+            continue;
+        };
+        let work = works.get(&id);
+        let published_doi = work.and_then(|work| work.published_doi.as_deref());
+        let title = work
+            .and_then(|work| work.title.as_deref())
+            .or_else(|| preprint.metadata.get("title").map(String::as_str));
+
+        let matched = results.iter().enumerate().find(|(index, candidate)| {
+            !claimed.contains(index)
+                && candidate.as_ref().is_ok_and(|candidate| {
+                    is_published(candidate) && is_same_work(candidate, published_doi, title)
+                })
+        });
+        if let Some((dropped, _)) = matched {
+            claimed.insert(dropped);
+            merges.push(PreprintMerge { kept, dropped });
+        }
+    }
+    merges
+}
+
+/// Returns the version-less arXiv ID of a result resolved from arXiv.
+fn preprint_id(resolved: &ResolvedUrl) -> Option<String> {
+    let id = arxiv_id_from_doi(resolved.metadata.get("doi")?)?;
+    Some(strip_arxiv_version(&id).to_string())
+}
+
+/// True for results carrying a DOI that is not an arXiv DOI.
+fn is_published(resolved: &ResolvedUrl) -> bool {
+    resolved
+        .metadata
+        .get("doi")
+        .is_some_and(|doi| arxiv_id_from_doi(doi).is_none())
+}
+
+fn is_same_work(published: &ResolvedUrl, published_doi: Option<&str>, title: Option<&str>) -> bool {
+    let doi_matches = published_doi
+        .zip(published.metadata.get("doi"))
+        .is_some_and(|(expected, doi)| expected.eq_ignore_ascii_case(doi));
+    doi_matches
+        || title.zip(published.metadata.get("title")).is_some_and(
+            |(preprint_title, published_title)| titles_match(preprint_title, published_title),
+        )
+}
+
+fn titles_match(left: &str, right: &str) -> bool {
+    let left = normalize_search_text(left);
+    let right = normalize_search_text(right);
+    left.split_whitespace().count() >= MIN_TITLE_MATCH_WORDS
+        && right.split_whitespace().count() >= MIN_TITLE_MATCH_WORDS
+        && fuzzy_similarity(&left, &right) >= TITLE_MATCH_THRESHOLD
+}
+
+/// `2301.01234v2` → `2301.01234`; IDs without a version are returned as-is.
+fn strip_arxiv_version(id: &str) -> &str {
+    match id.rfind('v') {
+        Some(pos)
+            if pos + 1 < id.len() && id[pos + 1..].bytes().all(|byte| byte.is_ascii_digit()) =>
+        {
+            &id[..pos]
+        }
+        _ => id,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolved(url: &str, doi: &str, title: Option<&str>) -> Result<ResolvedUrl, ResolveError> {
+        let mut metadata = HashMap::from([("doi".to_string(), doi.to_string())]);
+        if let Some(title) = title {
+            metadata.insert("title".to_string(), title.to_string());
+        }
+        Ok(ResolvedUrl::with_metadata(url, metadata))
+    }
+
+    #[test]
+    fn test_plan_preprint_merges_matches_on_arxiv_reported_doi() {
+        let results = vec![
+            resolved(
+                "https://publisher.example/paper.pdf",
+                "10.1000/journal.5",
+                None,
+            ),
+            resolved(
+                "https://arxiv.org/pdf/2301.01234v2.pdf",
+                "10.48550/arXiv.2301.01234v2",
+                None,
+            ),
+        ];
+        let works = HashMap::from([(
+            "2301.01234".to_string(),
+            ArxivWork {
+                title: None,
+                published_doi: Some("10.1000/JOURNAL.5".to_string()),
+            },
+        )]);
+
+        assert_eq!(
+            plan_preprint_merges(&results, &works),
+            [PreprintMerge {
+                kept: 1,
+                dropped: 0
+            }]
+        );
+    }
+
+    #[test]
+    fn test_plan_preprint_merges_matches_near_identical_titles_only() {
+        let results = vec![
+            resolved(
+                "https://arxiv.org/pdf/2301.01234.pdf",
+                "10.48550/arXiv.2301.01234",
+                Some("Deep Residual Learning for Image Recognition"),
+            ),
+            resolved(
+                "https://publisher.example/a.pdf",
+                "10.1000/a",
+                Some("Deep residual learning for image recognition."),
+            ),
+            resolved(
+                "https://arxiv.org/pdf/2302.00001.pdf",
+                "10.48550/arXiv.2302.00001",
+                Some("Attention"),
+            ),
+            resolved(
+                "https://publisher.example/b.pdf",
+                "10.1000/b",
+                Some("Attention"),
+            ),
+        ];
+
+        assert_eq!(
+            plan_preprint_merges(&results, &HashMap::new()),
+            [PreprintMerge {
+                kept: 0,
+                dropped: 1
+            }],
+            "short generic titles are not merged"
+        );
+    }
+
+    #[test]
+    fn test_strip_arxiv_version() {
+        assert_eq!(strip_arxiv_version("2301.01234v12"), "2301.01234");
+        assert_eq!(strip_arxiv_version("2301.01234"), "2301.01234");
+        assert_eq!(strip_arxiv_version("solv-int/9901001"), "solv-int/9901001");
+    }
+}
@@ -3,30 +3,57 @@
 //! Do not log cookie_jar contents or URLs that could correlate with authenticated
 //! sessions; limit debug logs to counts and non-sensitive metadata.
 
+use std::collections::{BTreeMap, HashSet};
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::{Result, bail};
 use downloader_core::{
-    DownloadAttemptStatus, DownloadedRegistry, InputType, NewDownloadAttempt, Queue, QueueMetadata,
-    RegistryLookup, ResolveContext, TopicExtractor, build_default_resolver_registry,
-    build_preferred_filename, extract_reference_confidence, load_custom_topics,
-    match_custom_topics, normalize_topics, parse_input,
+    DownloadAttemptStatus, DownloadedRegistry, IgnoreList, InputType, NewDownloadAttempt,
+    ParsedItem, Queue, QueueMetadata, RateLimiter, RegistryLookup, ResolveContext, TopicExtractor,
+    build_default_resolver_registry, build_preferred_filename, enable_crossref_metadata_enrichment,
+    extract_reference_confidence, load_custom_topics, match_custom_topics, normalize_topics,
+    parse_input,
 };
 use tracing::{debug, info, warn};
 
 use crate::app::context::RunContext;
+use crate::app::input_processor;
+use crate::app::preprint_merge;
 use crate::output;
 
+/// Minimum spacing between resolver API calls to the same host (e.g. Crossref)
+/// while inputs resolve in parallel.
+const RESOLVER_API_MIN_INTERVAL: Duration = Duration::from_millis(100);
+
+/// History reason for inputs skipped by the user's ignore file.
+const IGNORED_REASON: &str = "ignored_by_ignore_file";
+
+/// History reason for items whose topics miss every `--topic-filter` topic.
+const TOPIC_FILTERED_REASON: &str = "topic_filtered";
+
+/// History reason for published versions folded into their arXiv preprint by `--merge-duplicates`.
+const MERGED_DUPLICATE_REASON: &str = "merged_duplicate";
+
 /// Outcome of the resolution phase: counts and first error for runtime to decide bails.
 pub(crate) struct ResolutionOutcome {
     pub(crate) parsed_item_count: usize,
     pub(crate) enqueued_count: usize,
     pub(crate) duplicate_skipped_count: usize,
+    /// Published versions merged into their arXiv preprint (`--merge-duplicates`).
+    pub(crate) merged_duplicate_count: usize,
+    pub(crate) ignored_count: usize,
+    /// Items dropped because no keyword matched `--topic-filter`.
+    pub(crate) topic_filtered_count: usize,
+    /// References held back by `--min-reference-confidence` for manual verification.
+    pub(crate) needs_verification_count: usize,
     pub(crate) resolution_failed_auth_count: usize,
     pub(crate) resolution_failed_other_count: usize,
     pub(crate) enqueue_failed_count: usize,
     pub(crate) first_resolution_error: Option<String>,
     pub(crate) first_enqueue_error: Option<String>,
+    /// Successful resolutions per resolver name.
+    pub(crate) resolver_counts: BTreeMap<String, usize>,
 }
 
 impl ResolutionOutcome {
@@ -65,6 +92,8 @@ async fn log_skipped_attempt(
         topics: None,
         parse_confidence: metadata.parse_confidence.as_deref(),
         parse_confidence_factors: metadata.parse_confidence_factors.as_deref(),
+        resolver_path: metadata.resolver_path.as_deref(),
+        input_source_file: metadata.input_source_file.as_deref(),
     };
     if let Err(err) = queue.log_download_attempt(&attempt).await {
         warn!(
@@ -76,8 +105,37 @@ async fn log_skipped_attempt(
     }
 }
 
+/// True when keywords from the item's title and abstract overlap `topic_filter`
+/// (already normalized), using the substring matching of `--topics-file`.
+/// Items without a title cannot be matched.
+fn matches_topic_filter(
+    extractor: &TopicExtractor,
+    metadata: &QueueMetadata,
+    topic_filter: &[String],
+) -> bool {
+    let Some(title) = metadata.title.as_deref() else {
+        return false;
+    };
+    let keywords = extractor.extract_from_metadata(title, metadata.abstract_text.as_deref());
+    match_custom_topics(keywords, topic_filter.to_vec())
+        .iter()
+        .any(|topic| topic_filter.contains(topic))
+}
+
+/// Returns the text handed to resolvers: the raw entry for BibTeX, the normalized value otherwise.
+fn resolver_input(item: &ParsedItem) -> &str {
+    if item.input_type == InputType::BibTex {
+        item.raw.as_str()
+    } else {
+        item.value.as_str()
+    }
+}
+
 /// Parses input text, resolves each item to a URL, enqueues with metadata.
 ///
+/// Items are resolved concurrently (up to `--resolve-concurrency` at a time);
+/// deduplication and enqueueing then run in input order.
+///
 /// When `ctx.input_text` is `None` and `ctx.bibliography_items` is empty, returns zeros.
 /// Bibliography items (from `--bibliography` files) are resolved alongside items extracted
 /// from `parse_input(ctx.input_text)`.
@@ -93,11 +151,16 @@ pub(crate) async fn run_resolution(
             parsed_item_count: 0,
             enqueued_count: 0,
             duplicate_skipped_count: 0,
+            merged_duplicate_count: 0,
+            ignored_count: 0,
+            topic_filtered_count: 0,
+            needs_verification_count: 0,
             resolution_failed_auth_count: 0,
             resolution_failed_other_count: 0,
             enqueue_failed_count: 0,
             first_resolution_error: None,
             first_enqueue_error: None,
+            resolver_counts: BTreeMap::new(),
         });
     }
 
@@ -130,6 +193,22 @@ pub(crate) async fn run_resolution(
         .collect();
     let parsed_item_count = all_items.len();
 
+    let (all_items, needs_verification) = input_processor::split_by_reference_confidence(
+        all_items,
+        ctx.args.min_reference_confidence,
+    );
+    let needs_verification_count = needs_verification.len();
+    if needs_verification_count > 0 {
+        let report_path =
+            input_processor::write_needs_verification_report(&ctx.output_dir, &needs_verification)?;
+        info!(
+            count = needs_verification_count,
+            min_confidence = ?ctx.args.min_reference_confidence,
+            path = %report_path.display(),
+            "Held back references below --min-reference-confidence for manual verification"
+        );
+    }
+
     let mut resolution_failed_auth_count = 0usize;
     let mut resolution_failed_other_count = 0usize;
     let mut duplicate_skipped_count = 0usize;
@@ -137,6 +216,7 @@ pub(crate) async fn run_resolution(
     let mut enqueue_failed_count = 0usize;
     let mut first_resolution_error: Option<String> = None;
     let mut first_enqueue_error: Option<String> = None;
+    let mut resolver_counts: BTreeMap<String, usize> = BTreeMap::new();
 
     if !ctx.bibliography_items.is_empty() {
         info!(
@@ -150,19 +230,31 @@ pub(crate) async fn run_resolution(
             parsed_item_count,
             enqueued_count: 0,
             duplicate_skipped_count: 0,
+            merged_duplicate_count: 0,
+            ignored_count: 0,
+            topic_filtered_count: 0,
+            needs_verification_count,
             resolution_failed_auth_count: 0,
             resolution_failed_other_count: 0,
             enqueue_failed_count: 0,
             first_resolution_error: None,
             first_enqueue_error: None,
+            resolver_counts: BTreeMap::new(),
         });
     }
 
     output::log_parse_feedback(&parse_result);
 
-    let resolver_registry =
-        build_default_resolver_registry(ctx.cookie_jar.clone(), "downloader@example.com");
-    let resolve_context = ResolveContext::default();
+    let mut resolver_registry = build_default_resolver_registry(
+        ctx.cookie_jar.clone(),
+        &ctx.crossref,
+        &ctx.args.disable_resolver,
+    );
+    if ctx.args.enrich_metadata {
+        enable_crossref_metadata_enrichment(&mut resolver_registry, &ctx.crossref);
+    }
+    let resolve_context = ResolveContext::default()
+        .with_rate_limiter(Arc::new(RateLimiter::new(RESOLVER_API_MIN_INTERVAL)));
 
     let topic_extractor = if ctx.args.detect_topics {
         debug!("Topic extractor initialized");
@@ -171,6 +263,12 @@ pub(crate) async fn run_resolution(
         None
     };
 
+    // The filter extracts its own keywords, so it works without --detect-topics.
+    let topic_filter = normalize_topics(ctx.args.topic_filter.clone());
+    let filter_extractor = (!topic_filter.is_empty()).then(TopicExtractor::new);
+    let mut topic_filtered_count = 0usize;
+    let mut topic_kept_count = 0usize;
+
     let custom_topics = if let Some(ref topics_path) = ctx.args.topics_file {
         match load_custom_topics(topics_path) {
             Ok(topics) => {
@@ -189,18 +287,80 @@ pub(crate) async fn run_resolution(
         Vec::new()
     };
 
-    for item in &all_items {
-        let resolver_input = if item.input_type == InputType::BibTex {
-            item.raw.as_str()
-        } else {
-            item.value.as_str()
-        };
+    let ignore_list = match ctx.args.ignore_file.as_deref() {
+        Some(ignore_path) => match IgnoreList::load(ignore_path) {
+            Ok(list) => {
+                info!(patterns = list.len(), path = %ignore_path.display(), "Loaded ignore file");
+                Some(list)
+            }
+            Err(error) => {
+                bail!(
+                    "Cannot use ignore file '{}'\n  {error}\n  \
+                    Fix the listed pattern or path, or pass a different --ignore-file.",
+                    ignore_path.display()
+                );
+            }
+        },
+        None => None,
+    };
 
-        let resolved_item = match resolver_registry
-            .resolve_to_url(resolver_input, item.input_type, &resolve_context)
-            .await
+    // Direct URLs are checked before resolution so ignored hosts are never contacted.
+    let mut ignored_count = 0usize;
+    let mut items_to_resolve = Vec::with_capacity(all_items.len());
+    for item in all_items {
+        if item.input_type == InputType::Url
+            && let Some(pattern) = ignore_list
+                .as_ref()
+                .and_then(|list| list.matching_pattern(&item.value))
         {
+            debug!(pattern, "Skipping input matching ignore file");
+            ignored_count += 1;
+            log_skipped_attempt(
+                queue.as_ref(),
+                project_key,
+                &item.value,
+                &item.raw,
+                &QueueMetadata::default(),
+                IGNORED_REASON,
+            )
+            .await;
+            continue;
+        }
+        items_to_resolve.push(item);
+    }
+    let all_items = items_to_resolve;
+
+    let resolver_inputs: Vec<(String, InputType)> = all_items
+        .iter()
+        .map(|item| (resolver_input(item).to_string(), item.input_type))
+        .collect();
+    let mut resolve_results = resolver_registry
+        .resolve_many(
+            &resolver_inputs,
+            usize::from(ctx.args.resolve_concurrency),
+            &resolve_context,
+        )
+        .await;
+    let merged_away = if ctx.args.merge_duplicates {
+        preprint_merge::merge_preprint_duplicates(&mut resolve_results, None).await
+    } else {
+        HashSet::new()
+    };
+    let mut merged_duplicate_count = 0usize;
+
+    for (index, (item, resolve_result)) in all_items.iter().zip(resolve_results).enumerate() {
+        let resolver_input = resolver_input(item);
+
+        let resolved_item = match resolve_result {
             Ok(resolved) => {
+                *resolver_counts
+                    .entry(
+                        resolved
+                            .resolver
+                            .clone()
+                            .unwrap_or_else(|| "unknown".to_string()),
+                    )
+                    .or_insert(0) += 1;
                 if !resolved.metadata.is_empty() {
                     debug!(
                         metadata_fields = resolved.metadata.len(),
@@ -237,9 +397,29 @@ pub(crate) async fn run_resolution(
             }
         };
 
-        let Some(resolved) = resolved_item else {
+        let Some(mut resolved) = resolved_item else {
             continue;
         };
+        if merged_away.contains(&index) {
+            merged_duplicate_count += 1;
+            log_skipped_attempt(
+                queue.as_ref(),
+                project_key,
+                &resolved.url,
+                &item.raw,
+                &QueueMetadata::default(),
+                MERGED_DUPLICATE_REASON,
+            )
+            .await;
+            continue;
+        }
+        // Markdown/HTML link text stands in for a title the resolver could not supply.
+        if let Some(hint) = &item.title_hint {
+            resolved
+                .metadata
+                .entry("title".to_string())
+                .or_insert_with(|| hint.clone());
+        }
         let queue_value = resolved.url;
 
         let reference_confidence = (item.input_type == InputType::Reference)
@@ -249,6 +429,8 @@ pub(crate) async fn run_resolution(
             suggested_filename: Some(build_preferred_filename(&queue_value, &resolved.metadata)),
             title: resolved.metadata.get("title").cloned(),
             authors: resolved.metadata.get("authors").cloned(),
+            author_orcids: resolved.metadata.get("author_orcids").cloned(),
+            abstract_text: resolved.metadata.get("abstract").cloned(),
             year: resolved.metadata.get("year").cloned(),
             doi: resolved.metadata.get("doi").cloned(),
             topics: topic_extractor.as_ref().and_then(|extractor| {
@@ -276,8 +458,49 @@ pub(crate) async fn run_resolution(
             parse_confidence: reference_confidence.map(|details| details.level.to_string()),
             parse_confidence_factors: reference_confidence
                 .and_then(|details| serde_json::to_string(&details.factors).ok()),
+            read_timeout_secs: resolved.read_timeout_secs,
+            resolver_path: resolved.resolver_path.clone(),
+            input_source_file: item.source_file.clone(),
         };
 
+        // Resolved URLs (e.g. a DOI's publisher link) may land on an ignored host.
+        if let Some(pattern) = ignore_list
+            .as_ref()
+            .and_then(|list| list.matching_pattern(&queue_value))
+        {
+            debug!(pattern, "Skipping resolved URL matching ignore file");
+            ignored_count += 1;
+            log_skipped_attempt(
+                queue.as_ref(),
+                project_key,
+                &queue_value,
+                &item.raw,
+                &queue_metadata,
+                IGNORED_REASON,
+            )
+            .await;
+            continue;
+        }
+
+        if let Some(extractor) = &filter_extractor {
+            if matches_topic_filter(extractor, &queue_metadata, &topic_filter) {
+                topic_kept_count += 1;
+            } else {
+                debug!("Skipping item outside --topic-filter");
+                topic_filtered_count += 1;
+                log_skipped_attempt(
+                    queue.as_ref(),
+                    project_key,
+                    &queue_value,
+                    &item.raw,
+                    &queue_metadata,
+                    TOPIC_FILTERED_REASON,
+                )
+                .await;
+                continue;
+            }
+        }
+
         if queue
             .has_active_url_in_project(&queue_value, Some(project_key))
             .await?
@@ -324,13 +547,18 @@ pub(crate) async fn run_resolution(
             RegistryLookup::Miss => {}
         }
 
+        let priority = ctx
+            .args
+            .priority
+            .unwrap_or_else(|| item.input_type.default_queue_priority());
         if let Err(err) = queue
-            .enqueue_with_metadata_in_project(
+            .enqueue_with_priority(
                 &queue_value,
                 item.input_type.queue_source_type(),
                 Some(&item.raw),
                 Some(&queue_metadata),
                 Some(project_key),
+                priority,
             )
             .await
         {
@@ -345,6 +573,7 @@ pub(crate) async fn run_resolution(
         debug!(
             input_type = %item.input_type,
             source_type = item.input_type.queue_source_type(),
+            priority,
             "Enqueued parsed item"
         );
     }
@@ -356,29 +585,58 @@ pub(crate) async fn run_resolution(
         );
     }
 
+    if ignored_count > 0 {
+        info!(
+            ignored = ignored_count,
+            "Filtered inputs matching ignore file"
+        );
+    }
+
+    if filter_extractor.is_some() {
+        info!(
+            kept = topic_kept_count,
+            filtered = topic_filtered_count,
+            "Applied --topic-filter to resolved metadata"
+        );
+    }
+
+    if merged_duplicate_count > 0 {
+        info!(
+            merged = merged_duplicate_count,
+            "Merged published versions into their arXiv preprints"
+        );
+    }
+
     registry.save_if_dirty()?;
 
     Ok(ResolutionOutcome {
         parsed_item_count,
         enqueued_count,
         duplicate_skipped_count,
+        merged_duplicate_count,
+        ignored_count,
+        topic_filtered_count,
+        needs_verification_count,
         resolution_failed_auth_count,
         resolution_failed_other_count,
         enqueue_failed_count,
         first_resolution_error,
         first_enqueue_error,
+        resolver_counts,
     })
 }
 
 #[cfg(test)]
 mod tests {
-    use super::run_resolution;
+    use super::{matches_topic_filter, run_resolution};
     use crate::app::config_runtime::HttpTimeoutSettings;
     use crate::app::context::RunContext;
     use crate::cli::Cli;
     use clap::Parser;
     use downloader_core::{
-        Database, DatabaseOptions, DownloadedRegistry, Queue, project_history_key,
+        CrossrefConfig, Database, DatabaseOptions, DomainHeaders, DownloadAttemptQuery,
+        DownloadAttemptStatus, DownloadedRegistry, Queue, QueueMetadata, RetryPolicy,
+        TopicExtractor, project_history_key,
     };
     use std::path::PathBuf;
     use std::sync::Arc;
@@ -393,8 +651,12 @@ mod tests {
             args: cli.download,
             http_timeouts: HttpTimeoutSettings::default(),
             db_options: DatabaseOptions::default(),
+            retry_policy: RetryPolicy::default(),
             output_dir: PathBuf::from("."),
             cookie_jar: None,
+            set_cookie_log: Arc::default(),
+            domain_headers: DomainHeaders::default(),
+            crossref: CrossrefConfig::default(),
             input_text: None,
             piped_stdin_was_empty: false,
             bibliography_items: Vec::new(),
@@ -411,4 +673,78 @@ mod tests {
         assert_eq!(outcome.resolution_failed_other_count, 0);
         assert_eq!(outcome.first_resolution_error, None);
     }
+
+    #[tokio::test]
+    async fn run_resolution_skips_urls_matching_ignore_file() {
+        let db = Database::new_in_memory().await.unwrap();
+        let queue = Arc::new(Queue::new(db));
+        let temp = tempfile::TempDir::new().unwrap();
+        let ignore_path = temp.path().join("ignore");
+        std::fs::write(&ignore_path, "# broken mirror\n*.mirror.test\n").unwrap();
+
+        let cli =
+            Cli::try_parse_from(["downloader", "--ignore-file", ignore_path.to_str().unwrap()])
+                .unwrap();
+        let ctx = RunContext {
+            args: cli.download,
+            http_timeouts: HttpTimeoutSettings::default(),
+            db_options: DatabaseOptions::default(),
+            retry_policy: RetryPolicy::default(),
+            output_dir: temp.path().to_path_buf(),
+            cookie_jar: None,
+            set_cookie_log: Arc::default(),
+            domain_headers: DomainHeaders::default(),
+            crossref: CrossrefConfig::default(),
+            input_text: Some("https://eu.mirror.test/paper.pdf".to_string()),
+            piped_stdin_was_empty: false,
+            bibliography_items: Vec::new(),
+        };
+
+        let project_key = project_history_key(&ctx.output_dir);
+        let mut registry = DownloadedRegistry::load(&ctx.output_dir, &project_key).unwrap();
+        let outcome = run_resolution(&ctx, Arc::clone(&queue), &project_key, &mut registry)
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.parsed_item_count, 1);
+        assert_eq!(outcome.ignored_count, 1);
+        assert_eq!(outcome.enqueued_count, 0);
+        assert!(!outcome.has_failures());
+
+        let attempts = queue
+            .query_download_attempts(&DownloadAttemptQuery::default())
+            .await
+            .unwrap();
+        assert_eq!(attempts.len(), 1);
+        assert_eq!(attempts[0].status(), DownloadAttemptStatus::Skipped);
+        assert_eq!(
+            attempts[0].error_message.as_deref(),
+            Some("ignored_by_ignore_file")
+        );
+    }
+
+    #[test]
+    fn matches_topic_filter_requires_keyword_overlap_with_a_title() {
+        let extractor = TopicExtractor::new();
+        let metadata = QueueMetadata {
+            title: Some("Carbon emissions from global shipping routes".to_string()),
+            ..QueueMetadata::default()
+        };
+
+        assert!(matches_topic_filter(
+            &extractor,
+            &metadata,
+            &["emissions".to_string()]
+        ));
+        assert!(!matches_topic_filter(
+            &extractor,
+            &metadata,
+            &["genomics".to_string()]
+        ));
+        assert!(!matches_topic_filter(
+            &extractor,
+            &QueueMetadata::default(),
+            &["emissions".to_string()]
+        ));
+    }
 }
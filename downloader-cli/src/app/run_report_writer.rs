@@ -0,0 +1,207 @@
+//! `--report-dir` audit reports: one timestamped JSON file per download run.
+//!
+//! Unlike the `--json` stdout report this is persisted and always carries the
+//! failure breakdown. Writing it is best-effort: callers log a warning on
+//! error and keep the run's exit code.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use downloader_core::atomic_write::atomic_write_json;
+use downloader_core::{DownloadAttempt, DownloadAttemptStatus, DownloadStats};
+use serde::Serialize;
+
+use crate::failure;
+
+/// Persistent record of one download run.
+#[derive(Debug, Serialize)]
+pub(crate) struct RunAuditReport {
+    /// Unix seconds when the report was built; also names the file.
+    pub generated_at: u64,
+    pub stats: RunAuditStats,
+    /// Failed attempts per [`failure::FailureCategory::key`].
+    pub failure_categories: BTreeMap<&'static str, usize>,
+    /// Successful resolutions per resolver name.
+    pub resolver_usage: BTreeMap<String, usize>,
+    /// History row ids (as in `downloader log`) completed during this run.
+    pub completed_ids: Vec<i64>,
+    /// History row ids that failed during this run.
+    pub failed_ids: Vec<i64>,
+}
+
+/// Engine counters and transfer aggregates from [`DownloadStats`].
+#[derive(Debug, Serialize)]
+pub(crate) struct RunAuditStats {
+    pub total_queued: usize,
+    pub completed: usize,
+    pub failed: usize,
+    pub dead_lettered: usize,
+    pub retried: usize,
+    pub interrupted: bool,
+    /// `--max-items` stopped the run with items still pending.
+    pub capped: bool,
+    pub total_bytes: u64,
+    pub bytes_per_sec: Option<f64>,
+    pub p50_duration_ms: Option<u64>,
+    pub p95_duration_ms: Option<u64>,
+    pub slowest_host: Option<String>,
+}
+
+impl RunAuditReport {
+    /// Builds the report from engine stats, resolver counts, and this run's history rows.
+    pub(crate) fn new(
+        generated_at: u64,
+        stats: &DownloadStats,
+        total_queued: usize,
+        resolver_usage: BTreeMap<String, usize>,
+        attempts: &[DownloadAttempt],
+    ) -> Self {
+        let summary = stats.summary();
+        let mut failure_categories = BTreeMap::new();
+        let mut completed_ids = Vec::new();
+        let mut failed_ids = Vec::new();
+        for attempt in attempts {
+            match attempt.status() {
+                DownloadAttemptStatus::Success => completed_ids.push(attempt.id),
+                DownloadAttemptStatus::Failed => {
+                    failed_ids.push(attempt.id);
+                    let category = failure::classify_failure(
+                        attempt.error_message.as_deref().unwrap_or_default(),
+                    )
+                    .category;
+                    *failure_categories.entry(category.key()).or_insert(0) += 1;
+                }
+                DownloadAttemptStatus::Skipped => {}
+            }
+        }
+        // History queries return newest first; report in run order.
+        completed_ids.sort_unstable();
+        failed_ids.sort_unstable();
+
+        Self {
+            generated_at,
+            stats: RunAuditStats {
+                total_queued,
+                completed: stats.completed(),
+                failed: stats.failed(),
+                dead_lettered: stats.dead_lettered(),
+                retried: stats.retried(),
+                interrupted: stats.was_interrupted(),
+                capped: stats.was_capped(),
+                total_bytes: summary.overall.total_bytes,
+                bytes_per_sec: summary.overall.bytes_per_sec,
+                p50_duration_ms: summary.overall.p50_duration_ms,
+                p95_duration_ms: summary.overall.p95_duration_ms,
+                slowest_host: summary.slowest_host.map(|host| host.domain),
+            },
+            failure_categories,
+            resolver_usage,
+            completed_ids,
+            failed_ids,
+        }
+    }
+}
+
+/// Current time in Unix seconds, used to timestamp report files.
+pub(crate) fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+/// Writes `report` to `<report_dir>/run-report-<unix>.json`, creating the
+/// directory if needed. A second report in the same second gets a `-<n>` suffix.
+pub(crate) fn write_run_report(report_dir: &Path, report: &RunAuditReport) -> Result<PathBuf> {
+    let stem = format!("run-report-{}", report.generated_at);
+    let mut path = report_dir.join(format!("{stem}.json"));
+    let mut suffix = 1;
+    while path.exists() {
+        suffix += 1;
+        path = report_dir.join(format!("{stem}-{suffix}.json"));
+    }
+    atomic_write_json(&path, report)
+        .with_context(|| format!("Cannot write run report '{}'", path.display()))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn attempt(id: i64, status: &str, error: Option<&str>) -> DownloadAttempt {
+        DownloadAttempt {
+            id,
+            url: format!("https://example.com/{id}.pdf"),
+            status_str: status.to_string(),
+            file_path: None,
+            title: None,
+            authors: None,
+            doi: None,
+            parse_confidence: None,
+            parse_confidence_factors: None,
+            project: None,
+            started_at: "2026-10-16 12:00:00".to_string(),
+            error_message: error.map(String::from),
+            error_type: None,
+            retry_count: 0,
+            last_retry_at: None,
+            original_input: None,
+            http_status: None,
+            duration_ms: None,
+            resolver_path: None,
+            input_source_file: None,
+        }
+    }
+
+    #[test]
+    fn test_run_audit_report_counts_failure_categories_and_ids() {
+        let attempts = vec![
+            attempt(
+                4,
+                "failed",
+                Some("[AUTH] HTTP 401 for https://example.com/4.pdf"),
+            ),
+            attempt(3, "failed", Some("HTTP 404 Not Found")),
+            attempt(2, "skipped", Some("duplicate_active")),
+            attempt(1, "success", None),
+        ];
+        let resolvers = BTreeMap::from([("direct".to_string(), 3)]);
+
+        let report = RunAuditReport::new(
+            1_700_000_000,
+            &DownloadStats::new(),
+            3,
+            resolvers,
+            &attempts,
+        );
+        let json = serde_json::to_value(&report).unwrap();
+
+        assert_eq!(json["generated_at"], 1_700_000_000);
+        assert_eq!(json["stats"]["total_queued"], 3);
+        assert_eq!(json["failure_categories"]["auth"], 1);
+        assert_eq!(json["failure_categories"]["input_source"], 1);
+        assert_eq!(json["resolver_usage"]["direct"], 3);
+        assert_eq!(json["completed_ids"], serde_json::json!([1]));
+        assert_eq!(json["failed_ids"], serde_json::json!([3, 4]));
+    }
+
+    #[test]
+    fn test_write_run_report_creates_dir_and_avoids_overwrite() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let report_dir = tmp.path().join("reports");
+        let report = RunAuditReport::new(42, &DownloadStats::new(), 0, BTreeMap::new(), &[]);
+
+        let first = write_run_report(&report_dir, &report).unwrap();
+        let second = write_run_report(&report_dir, &report).unwrap();
+
+        assert_eq!(first, report_dir.join("run-report-42.json"));
+        assert_eq!(second, report_dir.join("run-report-42-2.json"));
+        let written: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&first).unwrap()).unwrap();
+        assert_eq!(written["stats"]["completed"], 0);
+        assert_eq!(written["failure_categories"], serde_json::json!({}));
+    }
+}
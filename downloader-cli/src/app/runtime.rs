@@ -1,20 +1,25 @@
 use std::collections::HashSet;
 use std::fs;
 use std::io::{self, BufRead as _, IsTerminal, Write as _};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use anyhow::{Result, bail};
-use downloader_core::{DownloadedRegistry, QueueStatus};
+use downloader_core::{
+    CrossrefConfig, DEFAULT_CROSSREF_MAILTO, DownloadStats, DownloadedRegistry, Queue, QueueStatus,
+    load_custom_topics, load_runtime_domain_headers, serialize_jar_to_netscape,
+};
 use tracing::{debug, info, warn};
 
+use crate::app::resolution_orchestrator::ResolutionOutcome;
 use crate::app::{
     command_dispatcher, config_manager, config_runtime, context, download_orchestrator,
     exit_handler, input_processor, progress_manager, queue_manager, resolution_orchestrator,
-    terminal,
+    run_report_writer, terminal,
 };
 use crate::app_config::{load_default_file_config, write_tos_acknowledged};
+use crate::cli::{Command, DryRunFormatArg, LogFormatArg};
 use crate::{ProcessExit, commands, output, project};
 
 /// Checks whether the user has acknowledged their Terms of Service responsibilities.
@@ -133,6 +138,208 @@ fn save_registry_or_warn(registry: &mut DownloadedRegistry) {
     }
 }
 
+/// Writes cookies refreshed during this run back to the `--cookies` file.
+///
+/// Uses the URLs of items completed or failed in this run; write errors are
+/// reported as warnings so they never fail an otherwise successful run.
+async fn write_back_cookie_file(
+    ctx: &context::RunContext,
+    queue: &Queue,
+    project_key: &str,
+    completed_before: &HashSet<i64>,
+) -> Result<()> {
+    let (Some(jar), Some(cookie_file)) = (&ctx.cookie_jar, ctx.args.cookies.as_deref()) else {
+        return Ok(());
+    };
+    if cookie_file == "-" {
+        debug!("cookies were read from stdin; skipping cookie write-back");
+        return Ok(());
+    }
+
+    let mut used_urls: Vec<String> = queue
+        .list_by_status_in_project(QueueStatus::Completed, Some(project_key))
+        .await?
+        .into_iter()
+        .filter(|item| !completed_before.contains(&item.id))
+        .map(|item| item.url)
+        .collect();
+    used_urls.extend(
+        queue
+            .list_by_status_in_project(QueueStatus::Failed, Some(project_key))
+            .await?
+            .into_iter()
+            .map(|item| item.url),
+    );
+
+    match serialize_jar_to_netscape(jar, Path::new(cookie_file), &used_urls, &ctx.set_cookie_log) {
+        Ok(0) => {}
+        Ok(changed) => info!(changed, path = cookie_file, "Updated cookie file"),
+        Err(err) => warn!(
+            error = %err,
+            path = cookie_file,
+            "Failed to write refreshed cookies back to cookie file"
+        ),
+    }
+    Ok(())
+}
+
+/// Ends a `downloader resolve` run: reports resolved vs unresolvable inputs and
+/// optionally exports the queue. Every resolved item stays `Pending` for a later
+/// download run (possibly on another machine after `queue import`).
+async fn finish_resolve_only(
+    queue: &Queue,
+    project_key: &str,
+    resolution: &ResolutionOutcome,
+    export: Option<&Path>,
+) -> Result<ProcessExit> {
+    let pending_in_queue = queue
+        .list_by_status_in_project(QueueStatus::Pending, Some(project_key))
+        .await?
+        .len();
+    let lines = output::resolve_only_summary_lines(
+        resolution.parsed_item_count,
+        resolution.enqueued_count,
+        resolution.duplicate_skipped_count,
+        resolution.resolution_failed_auth_count,
+        resolution.resolution_failed_other_count,
+        resolution.enqueue_failed_count,
+        pending_in_queue,
+    );
+
+    // Keep stdout clean for JSON when exporting to `-`.
+    let export_to_stdout = export.is_some_and(|path| path.as_os_str() == "-");
+    for line in &lines {
+        if export_to_stdout {
+            eprintln!("{line}");
+        } else {
+            println!("{line}");
+        }
+    }
+
+    if let Some(export) = export {
+        commands::export_queue_json(queue, export).await?;
+    }
+
+    Ok(if resolution.has_failures() {
+        ProcessExit::Partial
+    } else {
+        ProcessExit::Success
+    })
+}
+
+/// Prints the `--json` run report from this run's history rows (`id > history_start_id`).
+async fn print_json_run_report(
+    queue: &Queue,
+    project_key: &str,
+    history_start_id: Option<i64>,
+    completed: usize,
+    failed: usize,
+) -> Result<()> {
+    let attempts = project::query_session_attempts(queue, project_key, history_start_id).await?;
+    output::RunReport::new(completed, failed, &attempts).print()
+}
+
+/// Writes the `--report-dir` audit report. Best-effort like sidecars: any
+/// failure is logged and never changes the run's exit code.
+async fn write_run_report_or_warn(
+    report_dir: &Path,
+    queue: &Queue,
+    project_key: &str,
+    history_start_id: Option<i64>,
+    stats: &DownloadStats,
+    total_queued: usize,
+    resolution: &ResolutionOutcome,
+) {
+    let attempts = match project::query_session_attempts(queue, project_key, history_start_id).await
+    {
+        Ok(attempts) => attempts,
+        Err(error) => {
+            warn!(error = %error, "Skipping run report: could not read this run's history");
+            return;
+        }
+    };
+    let report = run_report_writer::RunAuditReport::new(
+        run_report_writer::unix_now(),
+        stats,
+        total_queued,
+        resolution.resolver_counts.clone(),
+        &attempts,
+    );
+    match run_report_writer::write_run_report(report_dir, &report) {
+        Ok(path) => info!(path = %path.display(), "Wrote run report"),
+        Err(error) => warn!(error = %format!("{error:#}"), "Failed to write run report"),
+    }
+}
+
+/// Two-stage Ctrl-C: the first stops claiming new items and lets in-flight
+/// downloads finish; the second aborts them, returning their items to pending.
+fn spawn_interrupt_handler(interrupted: Arc<AtomicBool>, abort: Arc<AtomicBool>) {
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_err() {
+            return;
+        }
+        interrupted.store(true, Ordering::SeqCst);
+        eprintln!("Interrupted: finishing in-flight downloads. Press Ctrl-C again to abort them.");
+        if tokio::signal::ctrl_c().await.is_ok() {
+            abort.store(true, Ordering::SeqCst);
+            eprintln!("Aborting in-flight downloads.");
+            info!("in-flight downloads aborted via second Ctrl-C");
+        }
+    });
+}
+
+/// Builds the command that resumes an interrupted run from its queue.
+fn resume_command(output_dir: Option<&Path>, project: Option<&str>) -> String {
+    let quote = |value: &str| {
+        if value.contains(char::is_whitespace) {
+            format!("\"{value}\"")
+        } else {
+            value.to_string()
+        }
+    };
+    let mut command = String::from("downloader");
+    if let Some(dir) = output_dir {
+        command.push_str(&format!(" --output-dir {}", quote(&dir.to_string_lossy())));
+    }
+    if let Some(project) = project {
+        command.push_str(&format!(" --project {}", quote(project)));
+    }
+    command
+}
+
+fn interrupted_summary(pending: i64, resume_command: &str) -> String {
+    let noun = if pending == 1 { "item" } else { "items" };
+    format!("{pending} {noun} left pending, re-run to resume: {resume_command}")
+}
+
+/// Toggles `paused` on each `SIGUSR1`, letting long runs be paused without losing state.
+#[cfg(unix)]
+fn spawn_pause_toggle_handler(paused: Arc<AtomicBool>) {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    let mut sigusr1 = match signal(SignalKind::user_defined1()) {
+        Ok(stream) => stream,
+        Err(err) => {
+            debug!(error = %err, "SIGUSR1 handler unavailable; pause toggle disabled");
+            return;
+        }
+    };
+    tokio::spawn(async move {
+        while sigusr1.recv().await.is_some() {
+            let now_paused = !paused.fetch_xor(true, Ordering::SeqCst);
+            if now_paused {
+                eprintln!("Paused: finishing in-flight downloads. Send SIGUSR1 again to resume.");
+            } else {
+                eprintln!("Resumed.");
+            }
+            info!(paused = now_paused, "pause toggled via SIGUSR1");
+        }
+    });
+}
+
+#[cfg(not(unix))]
+fn spawn_pause_toggle_handler(_paused: Arc<AtomicBool>) {}
+
 pub(crate) async fn run_downloader() -> Result<ProcessExit> {
     let (cli, cli_sources) = config_runtime::parse_cli_with_sources();
 
@@ -140,19 +347,47 @@ pub(crate) async fn run_downloader() -> Result<ProcessExit> {
         return Ok(exit);
     }
 
-    let tos_result = check_tos_acknowledgment(cli.download.quiet)?;
+    let tos_result =
+        check_tos_acknowledgment(cli.download_args().quiet || cli.download_args().stdout)?;
     if tos_result != ProcessExit::Success {
         return Ok(tos_result);
     }
 
     let resolved = config_manager::resolve_config(&cli, &cli_sources)?;
+    // `Some(export)` when running `downloader resolve`.
+    let resolve_only = match &cli.command {
+        Some(Command::Resolve(resolve_args)) => Some(resolve_args.export.clone()),
+        _ => None,
+    };
+    if resolve_only.is_some() && (resolved.args.stdout || resolved.args.dry_run) {
+        bail!(
+            "What: `downloader resolve` cannot be combined with --stdout or --dry-run\n\
+             Why: resolve only fills the queue; it never downloads or previews\n\
+             Fix: drop the flag, or run `downloader --dry-run` to preview resolution instead"
+        );
+    }
+    if resolve_only.is_some() && resolved.args.json {
+        bail!(
+            "What: `downloader resolve` does not support --json\n\
+             Why: --json reports download results, and resolve downloads nothing\n\
+             Fix: use `downloader resolve --export -` to print the resolved queue as JSON"
+        );
+    }
 
     let default_level = config_runtime::resolve_default_log_level(&resolved.args);
     let force_cli_log_level = config_runtime::should_force_cli_log_level(&cli_sources);
     let no_color = terminal::is_no_color_requested(&resolved.args);
-    terminal::init_tracing(default_level, force_cli_log_level, no_color);
+    terminal::init_tracing(
+        default_level,
+        force_cli_log_level,
+        no_color,
+        resolved.args.log_format,
+    );
 
     debug!("CLI arguments parsed");
+    for warning in &resolved.config_warnings {
+        warn!("{warning}");
+    }
     info!("Downloader starting");
 
     let base_output_dir = resolved
@@ -168,21 +403,72 @@ pub(crate) async fn run_downloader() -> Result<ProcessExit> {
 
     let (cookie_jar, input_text, piped_stdin_was_empty, bibliography_items) =
         input_processor::process_input(&resolved.args)?;
+    let domain_headers = load_runtime_domain_headers(resolved.auth_bearer.as_ref())?;
+    // Built after tracing starts so an invalid `crossref_mailto` warning is visible.
+    let crossref = CrossrefConfig::new(
+        resolved
+            .crossref_mailto
+            .as_deref()
+            .unwrap_or(DEFAULT_CROSSREF_MAILTO),
+    )
+    .with_api_token(resolved.crossref_token);
 
-    let ctx = context::RunContext {
+    let mut ctx = context::RunContext {
         args: resolved.args,
         http_timeouts: resolved.http_timeouts,
         db_options: resolved.db_options,
+        retry_policy: resolved.retry_policy,
         output_dir,
         cookie_jar,
+        set_cookie_log: Arc::default(),
+        domain_headers,
+        crossref,
         input_text,
         piped_stdin_was_empty,
         bibliography_items,
     };
 
+    if ctx.args.stdout {
+        commands::run_stdout_download(
+            ctx.input_text.as_deref(),
+            &ctx.bibliography_items,
+            ctx.cookie_jar.clone(),
+            &ctx.domain_headers,
+            ctx.http_timeouts.download_connect_secs,
+            ctx.http_timeouts.download_read_secs,
+            &ctx.crossref,
+            &ctx.args.disable_resolver,
+        )
+        .await?;
+        return Ok(ProcessExit::Success);
+    }
+
     if ctx.args.dry_run {
-        if let Some(input_text) = ctx.input_text.as_deref() {
-            commands::run_dry_run_preview(input_text, ctx.cookie_jar.clone()).await?;
+        let preflight = ctx
+            .args
+            .check
+            .then(|| download_orchestrator::build_preflight(&ctx));
+        if ctx.args.format == DryRunFormatArg::Json {
+            commands::run_dry_run_json(
+                ctx.input_text.as_deref(),
+                &ctx.bibliography_items,
+                ctx.cookie_jar.clone(),
+                &ctx.output_dir,
+                ctx.args.enrich_metadata,
+                &ctx.crossref,
+                &ctx.args.disable_resolver,
+                preflight.as_ref(),
+            )
+            .await?;
+        } else if let Some(input_text) = ctx.input_text.as_deref() {
+            commands::run_dry_run_preview(
+                input_text,
+                ctx.cookie_jar.clone(),
+                &ctx.crossref,
+                &ctx.args.disable_resolver,
+                preflight.as_ref(),
+            )
+            .await?;
         } else if !ctx.bibliography_items.is_empty() {
             // Bibliography-only dry run: report item count as a preview.
             output::print_bibliography_dry_run_summary(ctx.bibliography_items.len());
@@ -198,7 +484,12 @@ pub(crate) async fn run_downloader() -> Result<ProcessExit> {
     let has_prior_state = state_dir.exists();
 
     if ctx.input_text.is_none() && ctx.bibliography_items.is_empty() && !has_prior_state {
-        output::print_quick_start_guidance(ctx.piped_stdin_was_empty);
+        if ctx.args.json {
+            warn!("{}", output::NO_INPUT_GUIDANCE);
+            output::RunReport::default().print()?;
+        } else {
+            output::print_quick_start_guidance(ctx.piped_stdin_was_empty);
+        }
         return Ok(ProcessExit::Success);
     }
 
@@ -238,14 +529,21 @@ pub(crate) async fn run_downloader() -> Result<ProcessExit> {
         );
     }
 
+    if let Some(export) = resolve_only {
+        return finish_resolve_only(queue.as_ref(), &project_key, &resolution, export.as_deref())
+            .await;
+    }
+
     let pending_items = queue
         .list_by_status_in_project(QueueStatus::Pending, Some(&project_key))
         .await?;
     let total_queued = pending_items.len();
+    // Queued low-confidence references plus those held back for verification.
     let uncertain_references_in_run = pending_items
         .iter()
         .filter(|item| item.parse_confidence.as_deref() == Some("low"))
-        .count();
+        .count()
+        + resolution.needs_verification_count;
 
     if total_queued == 0 {
         if resolution.duplicate_skipped_count > 0 && !resolution.has_failures() {
@@ -253,6 +551,9 @@ pub(crate) async fn run_downloader() -> Result<ProcessExit> {
                 skipped_duplicates = resolution.duplicate_skipped_count,
                 "No new downloads needed; all items were already downloaded"
             );
+            if ctx.args.json {
+                print_json_run_report(queue.as_ref(), &project_key, history_start_id, 0, 0).await?;
+            }
             return Ok(ProcessExit::Success);
         }
         if resolution.has_failures() {
@@ -260,11 +561,17 @@ pub(crate) async fn run_downloader() -> Result<ProcessExit> {
             bail!(
                 "No runnable items remained after resolution.\n  \
                  Duplicates skipped: {}\n  \
+                 Merged into arXiv preprints: {}\n  \
+                 Ignored by ignore file: {}\n  \
+                 Filtered by --topic-filter: {}\n  \
                  Resolution failures (auth): {}\n  \
                  Resolution failures (other): {}\n  \
                  Enqueue failures: {}\n  \
                  First enqueue error: {}",
                 resolution.duplicate_skipped_count,
+                resolution.merged_duplicate_count,
+                resolution.ignored_count,
+                resolution.topic_filtered_count,
                 resolution.resolution_failed_auth_count,
                 resolution.resolution_failed_other_count,
                 resolution.enqueue_failed_count,
@@ -272,9 +579,16 @@ pub(crate) async fn run_downloader() -> Result<ProcessExit> {
             );
         }
         info!("No queue items were enqueued for downloading");
+        if ctx.args.json {
+            print_json_run_report(queue.as_ref(), &project_key, history_start_id, 0, 0).await?;
+        }
         return Ok(ProcessExit::Success);
     }
 
+    let download_hosts =
+        download_orchestrator::download_hosts(pending_items.iter().map(|item| item.url.as_str()));
+    ctx.cookie_jar = download_orchestrator::scope_cookie_jar(&ctx, &download_hosts)?;
+
     let completed_before: HashSet<i64> = queue
         .list_by_status_in_project(QueueStatus::Completed, Some(&project_key))
         .await?
@@ -283,24 +597,34 @@ pub(crate) async fn run_downloader() -> Result<ProcessExit> {
         .collect();
 
     let interrupted = Arc::new(AtomicBool::new(false));
-    let interrupted_signal = Arc::clone(&interrupted);
-    tokio::spawn(async move {
-        if tokio::signal::ctrl_c().await.is_ok() {
-            interrupted_signal.store(true, Ordering::SeqCst);
-        }
-    });
+    let abort = Arc::new(AtomicBool::new(false));
+    spawn_interrupt_handler(Arc::clone(&interrupted), Arc::clone(&abort));
+    let paused = Arc::new(AtomicBool::new(false));
+    spawn_pause_toggle_handler(Arc::clone(&paused));
 
+    // JSON log lines share stderr with the progress bar, so keep it out of them.
     let use_spinner = terminal::should_use_spinner(
         io::stderr().is_terminal(),
-        ctx.args.quiet,
+        ctx.args.quiet || ctx.args.log_format == LogFormatArg::Json,
         terminal::is_dumb_terminal(),
     );
+    // With --max-items the run ends once the cap is reached, so size the bar to it.
+    let progress_total = ctx
+        .args
+        .max_items
+        .and_then(|max| usize::try_from(max).ok())
+        .map_or(total_queued, |max| max.min(total_queued));
     let (progress_handle, progress_stop) =
-        progress_manager::spawn_progress_ui(use_spinner, Arc::clone(&queue), total_queued);
+        progress_manager::spawn_progress_ui(use_spinner, Arc::clone(&queue), progress_total);
 
-    let stats =
-        download_orchestrator::run_download(&ctx, Arc::clone(&queue), Arc::clone(&interrupted))
-            .await?;
+    let stats = download_orchestrator::run_download(
+        &ctx,
+        Arc::clone(&queue),
+        Arc::clone(&interrupted),
+        abort,
+        paused,
+    )
+    .await?;
 
     progress_stop.store(true, Ordering::SeqCst);
     if let Some(handle) = progress_handle {
@@ -310,6 +634,7 @@ pub(crate) async fn run_downloader() -> Result<ProcessExit> {
     info!(
         completed = stats.completed(),
         failed = stats.failed(),
+        dead_lettered = stats.dead_lettered(),
         retried = stats.retried(),
         total_queued,
         "Download complete"
@@ -322,6 +647,7 @@ pub(crate) async fn run_downloader() -> Result<ProcessExit> {
         total_queued,
         ctx.args.project.as_ref().map(|_| ctx.output_dir.as_path()),
         uncertain_references_in_run,
+        ctx.args.json,
     )
     .await?;
 
@@ -330,6 +656,7 @@ pub(crate) async fn run_downloader() -> Result<ProcessExit> {
             queue.as_ref(),
             &ctx.output_dir,
             &completed_before,
+            ctx.args.sidecar_format.into(),
         )
         .await;
         if count > 0 {
@@ -349,11 +676,51 @@ pub(crate) async fn run_downloader() -> Result<ProcessExit> {
     }
     save_registry_or_warn(&mut registry);
 
+    if ctx.args.save_cookies {
+        write_back_cookie_file(&ctx, queue.as_ref(), &project_key, &completed_before).await?;
+    }
+
+    if ctx.args.json {
+        print_json_run_report(
+            queue.as_ref(),
+            &project_key,
+            history_start_id,
+            stats.completed(),
+            stats.failed(),
+        )
+        .await?;
+    }
+
+    if let Some(report_dir) = ctx.args.report_dir.as_deref() {
+        write_run_report_or_warn(
+            report_dir,
+            queue.as_ref(),
+            &project_key,
+            history_start_id,
+            &stats,
+            total_queued,
+            &resolution,
+        )
+        .await;
+    }
+
     if stats.was_interrupted() || interrupted.load(Ordering::SeqCst) {
+        let pending = queue
+            .count_by_status_in_project(QueueStatus::Pending, Some(&project_key))
+            .await?;
         warn!(
             completed = stats.completed(),
-            total_queued, "Interrupted. Run again to resume."
+            pending, total_queued, "Interrupted. Run again to resume."
         );
+        if !ctx.args.quiet {
+            eprintln!(
+                "{}",
+                interrupted_summary(
+                    pending,
+                    &resume_command(ctx.args.output_dir.as_deref(), ctx.args.project.as_deref())
+                )
+            );
+        }
         return Ok(ProcessExit::Failure);
     }
 
@@ -361,6 +728,17 @@ pub(crate) async fn run_downloader() -> Result<ProcessExit> {
         project::append_project_download_log(queue.as_ref(), &ctx.output_dir, history_start_id)
             .await?;
         project::append_project_index(queue.as_ref(), &ctx.output_dir, &completed_before).await?;
+        project::write_project_manifest(queue.as_ref(), &ctx.output_dir).await?;
+        // The topics file already loaded once during resolution; a read error
+        // here only drops synonym collapsing from topics.md.
+        let custom_topics = ctx
+            .args
+            .topics_file
+            .as_deref()
+            .and_then(|path| load_custom_topics(path).ok())
+            .unwrap_or_default();
+        project::write_project_topics_index(queue.as_ref(), &ctx.output_dir, &custom_topics)
+            .await?;
     }
 
     Ok(exit_handler::determine_exit_outcome(
@@ -375,6 +753,24 @@ mod tests {
     use super::*;
     use downloader_core::project::project_history_key;
 
+    #[test]
+    fn test_interrupted_summary_names_pending_count_and_resume_command() {
+        let command = resume_command(Some(Path::new("/data/my papers")), Some("climate"));
+        assert_eq!(
+            command,
+            "downloader --output-dir \"/data/my papers\" --project climate"
+        );
+        assert_eq!(
+            interrupted_summary(3, &command),
+            "3 items left pending, re-run to resume: \
+             downloader --output-dir \"/data/my papers\" --project climate"
+        );
+        assert_eq!(
+            interrupted_summary(1, &resume_command(None, None)),
+            "1 item left pending, re-run to resume: downloader"
+        );
+    }
+
     #[test]
     fn test_save_registry_or_warn_keeps_success_path_when_persist_succeeds() {
         let temp = tempfile::TempDir::new().unwrap();
@@ -1,4 +1,4 @@
-use crate::cli::DownloadArgs;
+use crate::cli::{DownloadArgs, LogFormatArg};
 
 pub(crate) fn no_color_env_requested() -> bool {
     std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
@@ -30,16 +30,28 @@ pub(crate) fn should_use_spinner(
     stderr_is_terminal && !quiet && !dumb_terminal
 }
 
-pub(crate) fn init_tracing(default_level: &str, force_cli_level: bool, no_color: bool) {
+pub(crate) fn init_tracing(
+    default_level: &str,
+    force_cli_level: bool,
+    no_color: bool,
+    log_format: LogFormatArg,
+) {
     let filter = if force_cli_level {
         tracing_subscriber::EnvFilter::new(default_level)
     } else {
         tracing_subscriber::EnvFilter::try_from_default_env()
             .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new(default_level))
     };
-    let _ = tracing_subscriber::fmt()
+    let builder = tracing_subscriber::fmt()
         .with_writer(std::io::stderr)
-        .with_ansi(!no_color)
-        .with_env_filter(filter)
-        .try_init();
+        .with_env_filter(filter);
+    let _ = match log_format {
+        LogFormatArg::Text => builder.with_ansi(!no_color).try_init(),
+        LogFormatArg::Json => builder
+            .json()
+            .with_ansi(false)
+            .with_current_span(true)
+            .with_span_list(true)
+            .try_init(),
+    };
 }
@@ -41,6 +41,36 @@ pub(crate) fn reject_misplaced_auth_namespace(urls: &[String]) -> Result<()> {
     );
 }
 
+/// Accepted shapes for a history datetime filter (`d` is any ASCII digit).
+const HISTORY_DATETIME_PATTERNS: &[&str] =
+    &["dddd-dd-dd", "dddd-dd-dd dd:dd", "dddd-dd-dd dd:dd:dd"];
+
+/// Checks that `value` (given for `flag`) is a date or SQLite datetime string.
+///
+/// History timestamps are compared as text, so anything else would silently
+/// match the wrong rows.
+pub(crate) fn validate_history_datetime(flag: &str, value: &str) -> Result<()> {
+    let matches_pattern = |pattern: &str| {
+        value.len() == pattern.len()
+            && value.bytes().zip(pattern.bytes()).all(|(byte, shape)| {
+                if shape == b'd' {
+                    byte.is_ascii_digit()
+                } else {
+                    byte == shape
+                }
+            })
+    };
+    if HISTORY_DATETIME_PATTERNS
+        .iter()
+        .any(|pattern| matches_pattern(pattern))
+    {
+        return Ok(());
+    }
+    bail!(
+        "What: Invalid {flag} value '{value}'\nWhy: history timestamps are compared as SQLite datetime text\nFix: Use YYYY-MM-DD or YYYY-MM-DD HH:MM:SS."
+    );
+}
+
 pub(crate) fn validate_search_date_range(since: Option<&str>, until: Option<&str>) -> Result<()> {
     if let (Some(since), Some(until)) = (since, until)
         && since > until
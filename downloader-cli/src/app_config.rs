@@ -1,17 +1,27 @@
 //! Application configuration loading for CLI defaults.
 
+use std::collections::BTreeMap;
 use std::env;
+use std::fmt;
 use std::fs;
 use std::io::Write as _;
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result, bail};
+use downloader_core::{
+    CrossrefApiToken, DomainHeaders, FailureType, IGNORE_FILENAME, LayoutMode,
+    ResolverTimeoutOverride, SynchronousMode,
+};
 
 /// TOML-backed file configuration for downloader defaults.
 #[derive(Debug, Clone, Default)]
 pub struct FileConfig {
     /// Default output directory for downloads.
     pub output_dir: Option<PathBuf>,
+    /// Default project folder name (normally set from a project config).
+    pub project: Option<String>,
+    /// Default output subfolder layout.
+    pub layout: Option<LayoutMode>,
     /// Default concurrency (same range as CLI).
     pub concurrency: Option<u8>,
     /// Default per-domain rate limit in milliseconds.
@@ -20,6 +30,9 @@ pub struct FileConfig {
     pub respectful: Option<bool>,
     /// Check robots.txt before downloading.
     pub check_robots: Option<bool>,
+    /// Domains whose robots.txt disallows are overridden, from
+    /// `robots_allow = "<domain>,<domain>"`.
+    pub robots_allow: Vec<String>,
     /// Default verbosity mode.
     pub verbosity: Option<VerbositySetting>,
     /// Enable topic auto-detection from paper metadata (Story 8.1).
@@ -36,10 +49,34 @@ pub struct FileConfig {
     pub resolver_connect_timeout_secs: Option<u64>,
     /// Optional resolver client read timeout in seconds.
     pub resolver_read_timeout_secs: Option<u64>,
+    /// Optional redirect limit shared by the download client and resolvers.
+    pub max_redirects: Option<usize>,
+    /// Optional byte budget for the rolling download window (`--byte-budget`).
+    pub byte_budget: Option<u64>,
+    /// Resolvers to skip, from `disabled_resolvers = "<name>,<name>"`.
+    pub disabled_resolvers: Vec<String>,
+    /// Per-resolver timeout overrides keyed by resolver name, from
+    /// `resolver_timeouts.<name>_connect_secs` / `resolver_timeouts.<name>_read_secs`.
+    pub resolver_timeouts: BTreeMap<String, ResolverTimeoutOverride>,
+    /// Retry behaviour per HTTP status from `retry_status.<code> = "retry" | "permanent" | "needs-auth"`,
+    /// layered over the built-in classification.
+    pub retry_status: BTreeMap<u16, FailureType>,
+    /// Optional backoff cap in seconds for transient failures (`retry_max_backoff.transient_secs`).
+    pub retry_max_backoff_transient_secs: Option<u64>,
+    /// Optional backoff cap in seconds for rate-limited failures (`retry_max_backoff.rate_limited_secs`).
+    pub retry_max_backoff_rate_limited_secs: Option<u64>,
     /// Optional database pool max connections (1..=20).
     pub db_max_connections: Option<u32>,
     /// Optional database busy timeout in milliseconds.
     pub db_busy_timeout_ms: Option<u32>,
+    /// Optional `SQLite` `synchronous` mode (`off`, `normal`, `full`, `extra`).
+    pub db_synchronous: Option<SynchronousMode>,
+    /// Per-domain bearer tokens from `auth_bearer = "<domain>=<token>,..."`.
+    pub auth_bearer: Option<DomainHeaders>,
+    /// Contact address for Crossref's polite pool (`crossref_mailto`).
+    pub crossref_mailto: Option<String>,
+    /// Crossref Plus API token (`crossref_token`); redacted from `Debug` output.
+    pub crossref_token: Option<CrossrefApiToken>,
     /// Whether the user has acknowledged Terms of Service responsibilities.
     pub tos_acknowledged: Option<bool>,
 }
@@ -74,6 +111,31 @@ impl FileConfig {
             "resolver_read_timeout_secs",
             self.resolver_read_timeout_secs,
         )?;
+        for (name, timeouts) in &self.resolver_timeouts {
+            validate_timeout_secs(
+                &format!("resolver_timeouts.{name}_connect_secs"),
+                timeouts.connect_secs,
+            )?;
+            validate_timeout_secs(
+                &format!("resolver_timeouts.{name}_read_secs"),
+                timeouts.read_secs,
+            )?;
+        }
+        validate_timeout_secs(
+            "retry_max_backoff.transient_secs",
+            self.retry_max_backoff_transient_secs,
+        )?;
+        validate_timeout_secs(
+            "retry_max_backoff.rate_limited_secs",
+            self.retry_max_backoff_rate_limited_secs,
+        )?;
+        if let Some(max_redirects) = self.max_redirects
+            && max_redirects > 50
+        {
+            bail!(
+                "Invalid config value for `max_redirects`: {max_redirects}. Expected range: 0..=50"
+            );
+        }
         validate_db_max_connections(self.db_max_connections)?;
         validate_db_busy_timeout_ms(self.db_busy_timeout_ms)?;
 
@@ -133,6 +195,34 @@ impl VerbositySetting {
     }
 }
 
+/// Keys honoured in a project-level `.downloader/config.toml`.
+///
+/// Everything else (output directory, timeouts, database tuning, ToS state) is
+/// machine-wide and only read from the global config.
+pub const PROJECT_SCOPABLE_KEYS: &[&str] = &[
+    "project",
+    "layout",
+    "concurrency",
+    "rate_limit",
+    "respectful",
+    "check_robots",
+    "verbosity",
+    "detect_topics",
+    "topics_file",
+    "sidecar",
+];
+
+/// A project-level config discovered above the output directory.
+#[derive(Debug, Clone)]
+pub struct ProjectConfig {
+    /// Path of the discovered `.downloader/config.toml`.
+    pub path: PathBuf,
+    /// Parsed project-scopable settings.
+    pub config: FileConfig,
+    /// Keys that were skipped because they are unknown or not project-scopable.
+    pub warnings: Vec<String>,
+}
+
 /// Loaded config metadata.
 #[derive(Debug, Clone)]
 pub struct LoadedConfig {
@@ -200,6 +290,66 @@ pub fn load_default_file_config() -> Result<LoadedConfig> {
     })
 }
 
+/// Finds the nearest `.downloader/config.toml` at or above `start_dir`.
+///
+/// Relative `topics_file` paths are resolved against the directory that holds
+/// `.downloader/`, so a project config keeps working from any subdirectory.
+/// Unknown or global-only keys are skipped and reported in
+/// [`ProjectConfig::warnings`]; malformed values are still hard errors.
+pub fn discover_project_config(start_dir: &Path) -> Result<Option<ProjectConfig>> {
+    let start_dir = std::path::absolute(start_dir).unwrap_or_else(|_| start_dir.to_path_buf());
+    let Some((root, path)) = start_dir.ancestors().find_map(|dir| {
+        let candidate = dir.join(".downloader").join("config.toml");
+        candidate.is_file().then(|| (dir.to_path_buf(), candidate))
+    }) else {
+        return Ok(None);
+    };
+
+    let raw = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read project config file '{}'", path.display()))?;
+    let (mut config, warnings) = parse_project_config_str(&raw)
+        .with_context(|| format!("Failed to parse project config file '{}'", path.display()))?;
+    if let Some(topics_file) = config.topics_file.take() {
+        config.topics_file = Some(root.join(topics_file));
+    }
+
+    Ok(Some(ProjectConfig {
+        path,
+        config,
+        warnings,
+    }))
+}
+
+/// Finds the nearest `.downloader/ignore` at or above `start_dir`.
+#[must_use]
+pub fn discover_ignore_file(start_dir: &Path) -> Option<PathBuf> {
+    let start_dir = std::path::absolute(start_dir).unwrap_or_else(|_| start_dir.to_path_buf());
+    start_dir
+        .ancestors()
+        .map(|dir| dir.join(".downloader").join(IGNORE_FILENAME))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Layers project-scopable settings from `project` over `global`.
+///
+/// Yields CLI > project > global > defaults once the result goes through
+/// `apply_config_defaults`.
+#[must_use]
+pub fn layer_project_config(global: Option<&FileConfig>, project: &FileConfig) -> FileConfig {
+    let mut merged = global.cloned().unwrap_or_default();
+    merged.project = project.project.clone().or(merged.project);
+    merged.layout = project.layout.or(merged.layout);
+    merged.concurrency = project.concurrency.or(merged.concurrency);
+    merged.rate_limit = project.rate_limit.or(merged.rate_limit);
+    merged.respectful = project.respectful.or(merged.respectful);
+    merged.check_robots = project.check_robots.or(merged.check_robots);
+    merged.verbosity = project.verbosity.or(merged.verbosity);
+    merged.detect_topics = project.detect_topics.or(merged.detect_topics);
+    merged.topics_file = project.topics_file.clone().or(merged.topics_file);
+    merged.sidecar = project.sidecar.or(merged.sidecar);
+    merged
+}
+
 /// Persists `tos_acknowledged = true` to the config file, creating it (and its parent
 /// directories) if they do not yet exist.  This is a best-effort append: if the config file
 /// already contains the key the line will be duplicated (harmless — the last value wins in our
@@ -270,6 +420,274 @@ pub fn write_tos_acknowledged() -> Result<()> {
     Ok(())
 }
 
+/// Every plain key accepted in `config.toml`.
+const CONFIG_KEYS: &[&str] = &[
+    "output_dir",
+    "project",
+    "layout",
+    "concurrency",
+    "rate_limit",
+    "respectful",
+    "check_robots",
+    "robots_allow",
+    "verbosity",
+    "detect_topics",
+    "topics_file",
+    "sidecar",
+    "download_connect_timeout_secs",
+    "download_read_timeout_secs",
+    "resolver_connect_timeout_secs",
+    "resolver_read_timeout_secs",
+    "max_redirects",
+    "byte_budget",
+    "disabled_resolvers",
+    "db_max_connections",
+    "db_busy_timeout_ms",
+    "db_synchronous",
+    "auth_bearer",
+    "crossref_mailto",
+    "crossref_token",
+    "tos_acknowledged",
+    "retry_max_backoff.transient_secs",
+    "retry_max_backoff.rate_limited_secs",
+];
+
+/// Prefixes of the per-name keys (`retry_status.<code>`, `resolver_timeouts.<name>_*`).
+const CONFIG_KEY_PREFIXES: &[&str] = &["retry_status.", "resolver_timeouts."];
+
+/// Smallest similarity for an unknown key to be offered as a likely typo.
+const KEY_SUGGESTION_THRESHOLD: f64 = 0.7;
+
+/// One problem found by [`validate_config_str`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigProblem {
+    /// 1-based line number of the offending entry.
+    pub line: usize,
+    /// What is wrong.
+    pub what: String,
+    /// Why it matters, including the parser's own error.
+    pub why: String,
+    /// How to correct it.
+    pub fix: String,
+}
+
+impl fmt::Display for ConfigProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "What: {}\nWhy: {}\nFix: {}",
+            self.what, self.why, self.fix
+        )
+    }
+}
+
+/// Checks every line of a config file, collecting all problems instead of
+/// stopping at the first one like the loader does.
+///
+/// Unknown keys are reported with the closest known key when one is similar
+/// enough; values are parsed and range-checked with the loader's own rules.
+#[must_use]
+pub fn validate_config_str(raw: &str) -> Vec<ConfigProblem> {
+    let mut problems = Vec::new();
+    for (line_index, raw_line) in raw.lines().enumerate() {
+        let line = line_index + 1;
+        let entry = strip_inline_comment(raw_line).trim();
+        if entry.is_empty() {
+            continue;
+        }
+        if let Some((key, _)) = entry.split_once('=')
+            && !is_known_config_key(key.trim())
+        {
+            let key = key.trim();
+            problems.push(ConfigProblem {
+                line,
+                what: format!("Unknown config key `{key}` on line {line}"),
+                why: "The loader rejects unknown keys, so every run would fail on this file"
+                    .to_string(),
+                fix: closest_config_key(key).map_or_else(
+                    || "Remove the line, or check the README for supported keys".to_string(),
+                    |known| format!("Did you mean `{known}`?"),
+                ),
+            });
+            continue;
+        }
+
+        let mut cfg = FileConfig::default();
+        if let Err(error) =
+            apply_config_line(&mut cfg, line_index, raw_line).and_then(|()| cfg.validate())
+        {
+            problems.push(ConfigProblem {
+                line,
+                what: format!("Invalid config entry on line {line}: `{entry}`"),
+                why: format!("{error:#}"),
+                fix: "Correct the value as described, or remove the line to use the default"
+                    .to_string(),
+            });
+        }
+    }
+    problems
+}
+
+fn is_known_config_key(key: &str) -> bool {
+    CONFIG_KEYS.contains(&key)
+        || CONFIG_KEY_PREFIXES
+            .iter()
+            .any(|prefix| key.starts_with(prefix))
+}
+
+/// Returns the known key most similar to `key`, if any is close enough.
+fn closest_config_key(key: &str) -> Option<&'static str> {
+    CONFIG_KEYS
+        .iter()
+        .map(|known| (*known, strsim::normalized_levenshtein(key, known)))
+        .filter(|(_, similarity)| *similarity >= KEY_SUGGESTION_THRESHOLD)
+        .max_by(|(_, lhs), (_, rhs)| lhs.total_cmp(rhs))
+        .map(|(known, _)| known)
+}
+
+/// Commented default `config.toml` written by `downloader config init`.
+///
+/// Every entry is commented out, so the file changes nothing until a line is
+/// uncommented (by hand or with `downloader config set`).
+pub const DEFAULT_CONFIG_TEMPLATE: &str = r#"# downloader configuration.
+# Uncomment a line to change its default, or run `downloader config set <key> <value>`.
+# Command-line flags always win over values set here.
+
+# Where downloads are written (default: current directory).
+# output_dir = "~/papers"
+
+# Output subfolder layout: "flat", "by-year", "by-first-author", or "by-topic".
+# layout = "flat"
+
+# Parallel downloads (1..=100).
+# concurrency = 10
+
+# Minimum milliseconds between requests to the same domain (0..=60000).
+# rate_limit = 1000
+
+# Conservative concurrency, rate limit, and retries for sensitive environments.
+# respectful = false
+
+# Honour robots.txt, except for the listed domains.
+# check_robots = false
+# robots_allow = "example.org"
+
+# Output verbosity: "default", "verbose", "quiet", or "debug".
+# verbosity = "default"
+
+# Topic detection and sidecar metadata files.
+# detect_topics = false
+# topics_file = "topics.txt"
+# sidecar = false
+
+# Network timeouts in seconds and the redirect limit (0..=50).
+# download_connect_timeout_secs = 30
+# download_read_timeout_secs = 300
+# resolver_connect_timeout_secs = 10
+# resolver_read_timeout_secs = 30
+# max_redirects = 10
+
+# Stop dequeuing after this many bytes in the rolling 30-day window (K/M/G/T suffixes).
+# byte_budget = "50G"
+
+# Resolvers to skip, by name.
+# disabled_resolvers = "ieee,springer"
+
+# Database tuning.
+# db_max_connections = 5
+# db_busy_timeout_ms = 5000
+# db_synchronous = "normal"
+
+# Contact address for Crossref's polite pool.
+# crossref_mailto = "you@example.com"
+"#;
+
+/// Returns `raw` with `key` set to `value`, leaving every other line untouched.
+///
+/// An active `key = ...` line is replaced in place (keeping an inline
+/// comment); otherwise the first commented-out `# key = ...` line is
+/// uncommented, and failing that the entry is appended. Bare values for
+/// string keys are quoted automatically. The new entry is checked with the
+/// loader's own parsing and range rules before anything is returned.
+pub fn set_config_value(raw: &str, key: &str, value: &str) -> Result<String> {
+    if !is_known_config_key(key) {
+        bail!(
+            "What: Unknown config key `{key}`\n\
+             Why: The loader rejects unknown keys, so the file would no longer load\n\
+             Fix: {}",
+            closest_config_key(key).map_or_else(
+                || "Check the README for supported keys".to_string(),
+                |known| format!("Did you mean `{known}`?"),
+            )
+        );
+    }
+    let entry = config_entry(key, value)?;
+
+    let mut lines: Vec<String> = raw.lines().map(str::to_string).collect();
+    let mut replaced = false;
+    for line in &mut lines {
+        let active = strip_inline_comment(line);
+        if config_line_key(active) == Some(key) {
+            let comment = &line[active.len()..];
+            *line = if comment.is_empty() {
+                entry.clone()
+            } else {
+                format!("{entry} {comment}")
+            };
+            replaced = true;
+        }
+    }
+    if !replaced {
+        let commented = lines.iter_mut().find(|line| {
+            line.trim_start()
+                .strip_prefix('#')
+                .is_some_and(|rest| config_line_key(rest) == Some(key))
+        });
+        match commented {
+            Some(line) => *line = entry,
+            None => lines.push(entry),
+        }
+    }
+
+    let mut updated = lines.join("\n");
+    updated.push('\n');
+    Ok(updated)
+}
+
+/// Builds a validated `key = value` line, quoting `value` when the key takes a string.
+fn config_entry(key: &str, value: &str) -> Result<String> {
+    let value = value.trim();
+    let mut entry = format!("{key} = {value}");
+    let mut result = check_config_entry(&entry);
+    if let Err(error) = &result
+        && error.root_cause().to_string() == EXPECTED_STRING_MESSAGE
+        && !value.contains('"')
+    {
+        entry = format!("{key} = \"{value}\"");
+        result = check_config_entry(&entry);
+    }
+    match result {
+        Ok(()) => Ok(entry),
+        Err(error) => bail!(
+            "What: Invalid value for config key `{key}`\n\
+             Why: {}\n\
+             Fix: Pass a value of the expected type and range, e.g. `downloader config set concurrency 4`",
+            error.root_cause()
+        ),
+    }
+}
+
+fn check_config_entry(entry: &str) -> Result<()> {
+    let mut cfg = FileConfig::default();
+    apply_config_line(&mut cfg, 0, entry)?;
+    cfg.validate()
+}
+
+/// Key of a `key = value` line (comments already stripped), if it has one.
+fn config_line_key(line: &str) -> Option<&str> {
+    line.split_once('=').map(|(key, _)| key.trim())
+}
+
 fn load_file_config(path: &Path) -> Result<FileConfig> {
     let raw = fs::read_to_string(path)
         .with_context(|| format!("Failed to read config file '{}'", path.display()))?;
@@ -277,163 +695,370 @@ fn load_file_config(path: &Path) -> Result<FileConfig> {
         .with_context(|| format!("Failed to parse config file '{}'", path.display()))
 }
 
+/// Parses a project config, blanking out lines whose key is not project-scopable
+/// so the strict parser never sees them and line numbers in errors stay accurate.
+fn parse_project_config_str(raw: &str) -> Result<(FileConfig, Vec<String>)> {
+    let mut warnings = Vec::new();
+    let filtered = raw
+        .lines()
+        .enumerate()
+        .map(|(line_index, raw_line)| {
+            let key = strip_inline_comment(raw_line)
+                .split_once('=')
+                .map(|(key, _)| key.trim());
+            match key {
+                Some(key) if !PROJECT_SCOPABLE_KEYS.contains(&key) => {
+                    warnings.push(format!(
+                        "Ignoring key '{key}' on line {} of project config: only {} can be set per project",
+                        line_index + 1,
+                        PROJECT_SCOPABLE_KEYS.join(", ")
+                    ));
+                    ""
+                }
+                _ => raw_line,
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let config = parse_config_str(&filtered)?;
+    Ok((config, warnings))
+}
+
 fn parse_config_str(raw: &str) -> Result<FileConfig> {
     let mut cfg = FileConfig::default();
     for (line_index, raw_line) in raw.lines().enumerate() {
-        let line = strip_inline_comment(raw_line).trim();
-        if line.is_empty() {
-            continue;
-        }
+        apply_config_line(&mut cfg, line_index, raw_line)?;
+    }
+    cfg.validate()?;
+    Ok(cfg)
+}
 
-        let Some((raw_key, raw_value)) = line.split_once('=') else {
-            bail!(
-                "Invalid config syntax on line {}: expected key = value",
-                line_index + 1
-            );
-        };
+/// Parses one config line (0-based `line_index`) into `cfg`; blank and
+/// comment-only lines are ignored. Range checks are left to [`FileConfig::validate`].
+fn apply_config_line(cfg: &mut FileConfig, line_index: usize, raw_line: &str) -> Result<()> {
+    let line = strip_inline_comment(raw_line).trim();
+    if line.is_empty() {
+        return Ok(());
+    }
 
-        let key = raw_key.trim();
-        let value = raw_value.trim();
+    let Some((raw_key, raw_value)) = line.split_once('=') else {
+        bail!(
+            "Invalid config syntax on line {}: expected key = value",
+            line_index + 1
+        );
+    };
 
-        match key {
-            "output_dir" => {
-                let parsed = parse_string_literal(value).with_context(|| {
-                    format!("Invalid `output_dir` value on line {}", line_index + 1)
-                })?;
-                cfg.output_dir = Some(PathBuf::from(parsed));
-            }
-            "concurrency" => {
-                let parsed = parse_integer_u8(value).with_context(|| {
-                    format!("Invalid `concurrency` value on line {}", line_index + 1)
-                })?;
-                cfg.concurrency = Some(parsed);
-            }
-            "rate_limit" => {
-                let parsed = parse_integer_u64(value).with_context(|| {
-                    format!("Invalid `rate_limit` value on line {}", line_index + 1)
-                })?;
-                cfg.rate_limit = Some(parsed);
-            }
-            "respectful" => {
-                let parsed = parse_boolean(value).with_context(|| {
-                    format!("Invalid `respectful` value on line {}", line_index + 1)
-                })?;
-                cfg.respectful = Some(parsed);
-            }
-            "check_robots" => {
-                let parsed = parse_boolean(value).with_context(|| {
-                    format!("Invalid `check_robots` value on line {}", line_index + 1)
-                })?;
-                cfg.check_robots = Some(parsed);
-            }
-            "verbosity" => {
-                let parsed = parse_string_literal(value).with_context(|| {
-                    format!("Invalid `verbosity` value on line {}", line_index + 1)
-                })?;
-                cfg.verbosity = Some(parse_verbosity(&parsed).with_context(|| {
-                    format!(
-                        "Invalid `verbosity` value '{}' on line {}",
-                        parsed,
-                        line_index + 1
-                    )
+    let key = raw_key.trim();
+    let value = raw_value.trim();
+
+    match key {
+        "output_dir" => {
+            let parsed = parse_string_literal(value).with_context(|| {
+                format!("Invalid `output_dir` value on line {}", line_index + 1)
+            })?;
+            cfg.output_dir = Some(PathBuf::from(parsed));
+        }
+        "project" => {
+            let parsed = parse_string_literal(value)
+                .with_context(|| format!("Invalid `project` value on line {}", line_index + 1))?;
+            cfg.project = Some(parsed);
+        }
+        "layout" => {
+            let parsed = parse_string_literal(value)
+                .with_context(|| format!("Invalid `layout` value on line {}", line_index + 1))?;
+            cfg.layout = Some(parse_layout(&parsed).with_context(|| {
+                format!(
+                    "Invalid `layout` value '{}' on line {}",
+                    parsed,
+                    line_index + 1
+                )
+            })?);
+        }
+        "concurrency" => {
+            let parsed = parse_integer_u8(value).with_context(|| {
+                format!("Invalid `concurrency` value on line {}", line_index + 1)
+            })?;
+            cfg.concurrency = Some(parsed);
+        }
+        "rate_limit" => {
+            let parsed = parse_integer_u64(value).with_context(|| {
+                format!("Invalid `rate_limit` value on line {}", line_index + 1)
+            })?;
+            cfg.rate_limit = Some(parsed);
+        }
+        "respectful" => {
+            let parsed = parse_boolean(value).with_context(|| {
+                format!("Invalid `respectful` value on line {}", line_index + 1)
+            })?;
+            cfg.respectful = Some(parsed);
+        }
+        "check_robots" => {
+            let parsed = parse_boolean(value).with_context(|| {
+                format!("Invalid `check_robots` value on line {}", line_index + 1)
+            })?;
+            cfg.check_robots = Some(parsed);
+        }
+        "robots_allow" => {
+            let parsed = parse_string_literal(value).with_context(|| {
+                format!("Invalid `robots_allow` value on line {}", line_index + 1)
+            })?;
+            cfg.robots_allow = parsed
+                .split(',')
+                .map(|domain| domain.trim().trim_start_matches('.').to_ascii_lowercase())
+                .filter(|domain| !domain.is_empty())
+                .collect();
+        }
+        "verbosity" => {
+            let parsed = parse_string_literal(value)
+                .with_context(|| format!("Invalid `verbosity` value on line {}", line_index + 1))?;
+            cfg.verbosity = Some(parse_verbosity(&parsed).with_context(|| {
+                format!(
+                    "Invalid `verbosity` value '{}' on line {}",
+                    parsed,
+                    line_index + 1
+                )
+            })?);
+        }
+        "detect_topics" => {
+            let parsed = parse_boolean(value).with_context(|| {
+                format!("Invalid `detect_topics` value on line {}", line_index + 1)
+            })?;
+            cfg.detect_topics = Some(parsed);
+        }
+        "topics_file" => {
+            let parsed = parse_string_literal(value).with_context(|| {
+                format!("Invalid `topics_file` value on line {}", line_index + 1)
+            })?;
+            cfg.topics_file = Some(PathBuf::from(parsed));
+        }
+        "sidecar" => {
+            let parsed = parse_boolean(value)
+                .with_context(|| format!("Invalid `sidecar` value on line {}", line_index + 1))?;
+            cfg.sidecar = Some(parsed);
+        }
+        "download_connect_timeout_secs" => {
+            let parsed = parse_integer_u64(value).with_context(|| {
+                format!(
+                    "Invalid `download_connect_timeout_secs` value on line {}",
+                    line_index + 1
+                )
+            })?;
+            cfg.download_connect_timeout_secs = Some(parsed);
+        }
+        "download_read_timeout_secs" => {
+            let parsed = parse_integer_u64(value).with_context(|| {
+                format!(
+                    "Invalid `download_read_timeout_secs` value on line {}",
+                    line_index + 1
+                )
+            })?;
+            cfg.download_read_timeout_secs = Some(parsed);
+        }
+        "resolver_connect_timeout_secs" => {
+            let parsed = parse_integer_u64(value).with_context(|| {
+                format!(
+                    "Invalid `resolver_connect_timeout_secs` value on line {}",
+                    line_index + 1
+                )
+            })?;
+            cfg.resolver_connect_timeout_secs = Some(parsed);
+        }
+        "resolver_read_timeout_secs" => {
+            let parsed = parse_integer_u64(value).with_context(|| {
+                format!(
+                    "Invalid `resolver_read_timeout_secs` value on line {}",
+                    line_index + 1
+                )
+            })?;
+            cfg.resolver_read_timeout_secs = Some(parsed);
+        }
+        "max_redirects" => {
+            let parsed = parse_integer_u64(value).with_context(|| {
+                format!("Invalid `max_redirects` value on line {}", line_index + 1)
+            })?;
+            let n = usize::try_from(parsed)
+                .map_err(|_| anyhow::anyhow!("max_redirects out of range for usize"))?;
+            cfg.max_redirects = Some(n);
+        }
+        "byte_budget" => {
+            let parsed = parse_byte_budget(value).with_context(|| {
+                format!("Invalid `byte_budget` value on line {}", line_index + 1)
+            })?;
+            cfg.byte_budget = Some(parsed);
+        }
+        "disabled_resolvers" => {
+            let parsed = parse_string_literal(value).with_context(|| {
+                format!(
+                    "Invalid `disabled_resolvers` value on line {}",
+                    line_index + 1
+                )
+            })?;
+            cfg.disabled_resolvers = parsed
+                .split(',')
+                .map(|name| name.trim().to_ascii_lowercase())
+                .filter(|name| !name.is_empty())
+                .collect();
+        }
+        "db_max_connections" => {
+            let parsed = parse_integer_u64(value).with_context(|| {
+                format!(
+                    "Invalid `db_max_connections` value on line {}",
+                    line_index + 1
+                )
+            })?;
+            let n = u32::try_from(parsed)
+                .map_err(|_| anyhow::anyhow!("db_max_connections out of range for u32"))?;
+            cfg.db_max_connections = Some(n);
+        }
+        "db_busy_timeout_ms" => {
+            let parsed = parse_integer_u64(value).with_context(|| {
+                format!(
+                    "Invalid `db_busy_timeout_ms` value on line {}",
+                    line_index + 1
+                )
+            })?;
+            let n = u32::try_from(parsed)
+                .map_err(|_| anyhow::anyhow!("db_busy_timeout_ms out of range for u32"))?;
+            cfg.db_busy_timeout_ms = Some(n);
+        }
+        "db_synchronous" => {
+            let parsed = parse_string_literal(value).with_context(|| {
+                format!("Invalid `db_synchronous` value on line {}", line_index + 1)
+            })?;
+            cfg.db_synchronous = Some(parse_synchronous(&parsed).with_context(|| {
+                format!(
+                    "Invalid `db_synchronous` value '{}' on line {}",
+                    parsed,
+                    line_index + 1
+                )
+            })?);
+        }
+        "auth_bearer" => {
+            let parsed = parse_string_literal(value).with_context(|| {
+                format!("Invalid `auth_bearer` value on line {}", line_index + 1)
+            })?;
+            cfg.auth_bearer =
+                Some(DomainHeaders::parse_bearer_spec(&parsed).with_context(|| {
+                    format!("Invalid `auth_bearer` value on line {}", line_index + 1)
                 })?);
-            }
-            "detect_topics" => {
-                let parsed = parse_boolean(value).with_context(|| {
-                    format!("Invalid `detect_topics` value on line {}", line_index + 1)
-                })?;
-                cfg.detect_topics = Some(parsed);
-            }
-            "topics_file" => {
-                let parsed = parse_string_literal(value).with_context(|| {
-                    format!("Invalid `topics_file` value on line {}", line_index + 1)
-                })?;
-                cfg.topics_file = Some(PathBuf::from(parsed));
-            }
-            "sidecar" => {
-                let parsed = parse_boolean(value).with_context(|| {
-                    format!("Invalid `sidecar` value on line {}", line_index + 1)
-                })?;
-                cfg.sidecar = Some(parsed);
-            }
-            "download_connect_timeout_secs" => {
-                let parsed = parse_integer_u64(value).with_context(|| {
-                    format!(
-                        "Invalid `download_connect_timeout_secs` value on line {}",
-                        line_index + 1
-                    )
-                })?;
-                cfg.download_connect_timeout_secs = Some(parsed);
-            }
-            "download_read_timeout_secs" => {
-                let parsed = parse_integer_u64(value).with_context(|| {
-                    format!(
-                        "Invalid `download_read_timeout_secs` value on line {}",
-                        line_index + 1
-                    )
-                })?;
-                cfg.download_read_timeout_secs = Some(parsed);
-            }
-            "resolver_connect_timeout_secs" => {
-                let parsed = parse_integer_u64(value).with_context(|| {
-                    format!(
-                        "Invalid `resolver_connect_timeout_secs` value on line {}",
-                        line_index + 1
-                    )
-                })?;
-                cfg.resolver_connect_timeout_secs = Some(parsed);
-            }
-            "resolver_read_timeout_secs" => {
-                let parsed = parse_integer_u64(value).with_context(|| {
-                    format!(
-                        "Invalid `resolver_read_timeout_secs` value on line {}",
-                        line_index + 1
-                    )
-                })?;
-                cfg.resolver_read_timeout_secs = Some(parsed);
-            }
-            "db_max_connections" => {
-                let parsed = parse_integer_u64(value).with_context(|| {
-                    format!(
-                        "Invalid `db_max_connections` value on line {}",
-                        line_index + 1
-                    )
-                })?;
-                let n = u32::try_from(parsed)
-                    .map_err(|_| anyhow::anyhow!("db_max_connections out of range for u32"))?;
-                cfg.db_max_connections = Some(n);
-            }
-            "db_busy_timeout_ms" => {
-                let parsed = parse_integer_u64(value).with_context(|| {
-                    format!(
-                        "Invalid `db_busy_timeout_ms` value on line {}",
-                        line_index + 1
-                    )
-                })?;
-                let n = u32::try_from(parsed)
-                    .map_err(|_| anyhow::anyhow!("db_busy_timeout_ms out of range for u32"))?;
-                cfg.db_busy_timeout_ms = Some(n);
-            }
-            "tos_acknowledged" => {
-                let parsed = parse_boolean(value).with_context(|| {
-                    format!(
-                        "Invalid `tos_acknowledged` value on line {}",
-                        line_index + 1
-                    )
-                })?;
-                cfg.tos_acknowledged = Some(parsed);
-            }
-            unknown => {
+        }
+        "crossref_mailto" => {
+            let parsed = parse_string_literal(value).with_context(|| {
+                format!("Invalid `crossref_mailto` value on line {}", line_index + 1)
+            })?;
+            cfg.crossref_mailto = Some(parsed);
+        }
+        "crossref_token" => {
+            let parsed = parse_string_literal(value).with_context(|| {
+                format!("Invalid `crossref_token` value on line {}", line_index + 1)
+            })?;
+            cfg.crossref_token = Some(CrossrefApiToken::new(&parsed).with_context(|| {
+                format!(
+                    "Invalid `crossref_token` value on line {}: expected a non-empty token without spaces",
+                    line_index + 1
+                )
+            })?);
+        }
+        "tos_acknowledged" => {
+            let parsed = parse_boolean(value).with_context(|| {
+                format!(
+                    "Invalid `tos_acknowledged` value on line {}",
+                    line_index + 1
+                )
+            })?;
+            cfg.tos_acknowledged = Some(parsed);
+        }
+        "retry_max_backoff.transient_secs" => {
+            let parsed = parse_integer_u64(value).with_context(|| {
+                format!(
+                    "Invalid `retry_max_backoff.transient_secs` value on line {}",
+                    line_index + 1
+                )
+            })?;
+            cfg.retry_max_backoff_transient_secs = Some(parsed);
+        }
+        "retry_max_backoff.rate_limited_secs" => {
+            let parsed = parse_integer_u64(value).with_context(|| {
+                format!(
+                    "Invalid `retry_max_backoff.rate_limited_secs` value on line {}",
+                    line_index + 1
+                )
+            })?;
+            cfg.retry_max_backoff_rate_limited_secs = Some(parsed);
+        }
+        status_key if status_key.starts_with("retry_status.") => {
+            let Some(status) = status_key
+                .strip_prefix("retry_status.")
+                .and_then(|code| code.parse::<u16>().ok())
+                .filter(|code| (100..=599).contains(code))
+            else {
                 bail!(
-                    "Unknown configuration key: '{}' on line {}",
-                    unknown,
+                    "Unknown configuration key: '{}' on line {}. Expected \
+                     retry_status.<HTTP status code>",
+                    status_key,
                     line_index + 1
                 );
+            };
+            let parsed = parse_string_literal(value).with_context(|| {
+                format!("Invalid `{status_key}` value on line {}", line_index + 1)
+            })?;
+            let behavior = parse_retry_behavior(&parsed).with_context(|| {
+                format!(
+                    "Invalid `{status_key}` value '{}' on line {}",
+                    parsed,
+                    line_index + 1
+                )
+            })?;
+            cfg.retry_status.insert(status, behavior);
+        }
+        resolver_key if resolver_key.starts_with("resolver_timeouts.") => {
+            let parsed = parse_integer_u64(value).with_context(|| {
+                format!("Invalid `{resolver_key}` value on line {}", line_index + 1)
+            })?;
+            let Some((resolver, field)) = parse_resolver_timeout_key(resolver_key) else {
+                bail!(
+                    "Unknown configuration key: '{}' on line {}. Expected \
+                     resolver_timeouts.<resolver>_connect_secs or \
+                     resolver_timeouts.<resolver>_read_secs",
+                    resolver_key,
+                    line_index + 1
+                );
+            };
+            let entry = cfg
+                .resolver_timeouts
+                .entry(resolver.to_string())
+                .or_default();
+            match field {
+                ResolverTimeoutField::Connect => entry.connect_secs = Some(parsed),
+                ResolverTimeoutField::Read => entry.read_secs = Some(parsed),
             }
         }
+        unknown => {
+            bail!(
+                "Unknown configuration key: '{}' on line {}",
+                unknown,
+                line_index + 1
+            );
+        }
     }
-    cfg.validate()?;
-    Ok(cfg)
+    Ok(())
+}
+
+enum ResolverTimeoutField {
+    Connect,
+    Read,
+}
+
+/// Splits `resolver_timeouts.<name>_<connect|read>_secs` into the resolver
+/// name and the timeout it sets.
+fn parse_resolver_timeout_key(key: &str) -> Option<(&str, ResolverTimeoutField)> {
+    let rest = key.strip_prefix("resolver_timeouts.")?;
+    let (name, field) = if let Some(name) = rest.strip_suffix("_connect_secs") {
+        (name, ResolverTimeoutField::Connect)
+    } else {
+        (rest.strip_suffix("_read_secs")?, ResolverTimeoutField::Read)
+    };
+    (!name.is_empty()).then_some((name, field))
 }
 
 fn strip_inline_comment(line: &str) -> &str {
@@ -448,9 +1073,12 @@ fn strip_inline_comment(line: &str) -> &str {
     line
 }
 
+/// Parse error for a bare value given to a string key; `config set` quotes on it.
+const EXPECTED_STRING_MESSAGE: &str = "Expected double-quoted string";
+
 fn parse_string_literal(raw_value: &str) -> Result<String> {
     if raw_value.len() < 2 || !raw_value.starts_with('"') || !raw_value.ends_with('"') {
-        bail!("Expected double-quoted string");
+        bail!(EXPECTED_STRING_MESSAGE);
     }
     Ok(raw_value[1..raw_value.len() - 1].to_string())
 }
@@ -476,6 +1104,27 @@ fn parse_integer_u64(raw_value: &str) -> Result<u64> {
     u64::try_from(value).map_err(|_| anyhow::anyhow!("Integer value out of range for u64"))
 }
 
+/// Accepts a plain integer or a quoted size with a suffix (`"50G"`).
+fn parse_byte_budget(raw_value: &str) -> Result<u64> {
+    if let Ok(bytes) = parse_integer_u64(raw_value) {
+        if bytes == 0 {
+            bail!("Expected a budget greater than zero");
+        }
+        return Ok(bytes);
+    }
+    let literal = parse_string_literal(raw_value.trim())?;
+    crate::cli::parse_byte_size(&literal).map_err(anyhow::Error::msg)
+}
+
+fn parse_retry_behavior(value: &str) -> Result<FailureType> {
+    match value {
+        "retry" => Ok(FailureType::Transient),
+        "permanent" => Ok(FailureType::Permanent),
+        "needs-auth" => Ok(FailureType::NeedsAuth),
+        _ => bail!("Expected one of: retry, permanent, needs-auth"),
+    }
+}
+
 fn parse_verbosity(value: &str) -> Result<VerbositySetting> {
     match value {
         "default" => Ok(VerbositySetting::Default),
@@ -486,6 +1135,26 @@ fn parse_verbosity(value: &str) -> Result<VerbositySetting> {
     }
 }
 
+fn parse_layout(value: &str) -> Result<LayoutMode> {
+    match value {
+        "flat" => Ok(LayoutMode::Flat),
+        "by-year" => Ok(LayoutMode::ByYear),
+        "by-first-author" => Ok(LayoutMode::ByFirstAuthor),
+        "by-topic" => Ok(LayoutMode::ByTopic),
+        _ => bail!("Expected one of: flat, by-year, by-first-author, by-topic"),
+    }
+}
+
+fn parse_synchronous(value: &str) -> Result<SynchronousMode> {
+    match value {
+        "off" => Ok(SynchronousMode::Off),
+        "normal" => Ok(SynchronousMode::Normal),
+        "full" => Ok(SynchronousMode::Full),
+        "extra" => Ok(SynchronousMode::Extra),
+        _ => bail!("Expected one of: off, normal, full, extra"),
+    }
+}
+
 fn parse_boolean(raw_value: &str) -> Result<bool> {
     match raw_value.trim() {
         "true" => Ok(true),
@@ -638,6 +1307,254 @@ resolver_read_timeout_secs = 45
         assert!(err.to_string().contains("download_connect_timeout_secs"));
     }
 
+    #[test]
+    fn test_parse_config_byte_budget() {
+        let cfg = parse_config_str("byte_budget = \"2G\"").expect("byte_budget should parse");
+        assert_eq!(cfg.byte_budget, Some(2 * 1024 * 1024 * 1024));
+        let cfg = parse_config_str("byte_budget = 4096").expect("integer budget should parse");
+        assert_eq!(cfg.byte_budget, Some(4096));
+        assert!(parse_config_str("byte_budget = 0").is_err());
+
+        let updated = set_config_value("", "byte_budget", "500M").unwrap();
+        assert_eq!(updated, "byte_budget = \"500M\"\n");
+    }
+
+    #[test]
+    fn test_parse_config_max_redirects() {
+        let cfg = parse_config_str("max_redirects = 3").expect("max_redirects should parse");
+        assert_eq!(cfg.max_redirects, Some(3));
+
+        let err = parse_config_str("max_redirects = 500").expect_err("out of range expected");
+        assert!(err.to_string().contains("max_redirects"));
+    }
+
+    #[test]
+    fn test_validate_config_reports_every_problem() {
+        let problems = validate_config_str(
+            "concurrency = 4\nmax_redirect = 3\nconcurrency = 0\n# comment\nrate_limit = \"x\"\n",
+        );
+        let lines: Vec<_> = problems.iter().map(|problem| problem.line).collect();
+        assert_eq!(lines, [2, 3, 5]);
+        assert_eq!(problems[0].fix, "Did you mean `max_redirects`?");
+        assert!(problems[1].why.contains("1..=100"), "{}", problems[1].why);
+        assert!(problems[2].to_string().starts_with("What: "));
+    }
+
+    #[test]
+    fn test_validate_config_accepts_known_and_prefixed_keys() {
+        let problems = validate_config_str(
+            "concurrency = 4\nretry_status.503 = \"retry\"\nresolver_timeouts.crossref_read_secs = 60\n",
+        );
+        assert!(problems.is_empty(), "{problems:?}");
+        assert_eq!(closest_config_key("zzz"), None);
+    }
+
+    #[test]
+    fn test_set_config_value_replaces_uncomments_or_appends() {
+        let raw = "# settings\nconcurrency = 4 # tuned\n# layout = \"flat\"\nsidecar = true\n";
+
+        let updated = set_config_value(raw, "concurrency", "8").unwrap();
+        assert_eq!(
+            updated,
+            "# settings\nconcurrency = 8 # tuned\n# layout = \"flat\"\nsidecar = true\n"
+        );
+
+        let updated = set_config_value(&updated, "layout", "by-year").unwrap();
+        assert!(updated.contains("\nlayout = \"by-year\"\n"), "{updated}");
+
+        let updated = set_config_value(&updated, "max_redirects", "3").unwrap();
+        assert!(
+            updated.ends_with("sidecar = true\nmax_redirects = 3\n"),
+            "{updated}"
+        );
+        assert!(parse_config_str(&updated).is_ok());
+        assert!(validate_config_str(DEFAULT_CONFIG_TEMPLATE).is_empty());
+    }
+
+    #[test]
+    fn test_set_config_value_rejects_unknown_keys_and_invalid_values() {
+        let error = set_config_value("", "concurency", "4").unwrap_err();
+        assert!(
+            error.to_string().contains("Did you mean `concurrency`?"),
+            "{error}"
+        );
+
+        let error = set_config_value("", "concurrency", "0").unwrap_err();
+        assert!(error.to_string().contains("1..=100"), "{error}");
+
+        let error = set_config_value("", "layout", "sideways").unwrap_err();
+        assert!(error.to_string().contains("Expected one of"), "{error}");
+    }
+
+    #[test]
+    fn test_parse_config_disabled_resolvers() {
+        let cfg = parse_config_str(r#"disabled_resolvers = "IEEE, crossref,""#)
+            .expect("disabled_resolvers should parse");
+        assert_eq!(cfg.disabled_resolvers, ["ieee", "crossref"]);
+    }
+
+    #[test]
+    fn test_parse_config_robots_allow() {
+        let cfg = parse_config_str(r#"robots_allow = "Repo.Example.edu, .dspace.org,""#)
+            .expect("robots_allow should parse");
+        assert_eq!(cfg.robots_allow, ["repo.example.edu", "dspace.org"]);
+    }
+
+    #[test]
+    fn test_parse_config_resolver_timeout_overrides() {
+        let cfg = parse_config_str(
+            r#"
+resolver_timeouts.crossref_read_secs = 60
+resolver_timeouts.crossref_connect_secs = 5
+resolver_timeouts.semantic_scholar_read_secs = 20
+"#,
+        )
+        .expect("resolver timeout overrides should parse");
+        let crossref = cfg.resolver_timeouts["crossref"];
+        assert_eq!(crossref.read_secs, Some(60));
+        assert_eq!(crossref.connect_secs, Some(5));
+        let semantic_scholar = cfg.resolver_timeouts["semantic_scholar"];
+        assert_eq!(semantic_scholar.read_secs, Some(20));
+        assert_eq!(semantic_scholar.connect_secs, None);
+    }
+
+    #[test]
+    fn test_parse_config_retry_overrides() {
+        let cfg = parse_config_str(
+            r#"
+retry_status.500 = "permanent"
+retry_status.403 = "retry"
+retry_status.418 = "needs-auth"
+retry_max_backoff.rate_limited_secs = 300
+"#,
+        )
+        .expect("retry overrides should parse");
+        assert_eq!(cfg.retry_status[&500], FailureType::Permanent);
+        assert_eq!(cfg.retry_status[&403], FailureType::Transient);
+        assert_eq!(cfg.retry_status[&418], FailureType::NeedsAuth);
+        assert_eq!(cfg.retry_max_backoff_rate_limited_secs, Some(300));
+        assert_eq!(cfg.retry_max_backoff_transient_secs, None);
+    }
+
+    #[test]
+    fn test_parse_config_rejects_invalid_retry_status() {
+        let err = parse_config_str("retry_status.abc = \"retry\"")
+            .expect_err("non-numeric status expected to fail");
+        assert!(err.to_string().contains("Unknown configuration key"));
+        let err = parse_config_str("retry_status.500 = \"sometimes\"")
+            .expect_err("unknown behaviour expected to fail");
+        assert!(err.to_string().contains("retry_status.500"));
+    }
+
+    #[test]
+    fn test_parse_config_rejects_malformed_resolver_timeout_key() {
+        let err = parse_config_str("resolver_timeouts.crossref_secs = 10")
+            .expect_err("missing connect/read suffix");
+        assert!(err.to_string().contains("Unknown configuration key"));
+
+        let err = parse_config_str("resolver_timeouts.crossref_read_secs = 0")
+            .expect_err("0 is below range");
+        assert!(
+            err.to_string()
+                .contains("resolver_timeouts.crossref_read_secs")
+        );
+    }
+
+    #[test]
+    fn test_parse_project_config_warns_on_unknown_and_global_only_keys() {
+        let (cfg, warnings) = parse_project_config_str(
+            r#"
+project = "Climate Research"
+concurrency = 4
+db_max_connections = 5
+mystery = 1
+"#,
+        )
+        .expect("project config should parse");
+        assert_eq!(cfg.project.as_deref(), Some("Climate Research"));
+        assert_eq!(cfg.concurrency, Some(4));
+        assert!(cfg.db_max_connections.is_none());
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("'db_max_connections' on line 4"));
+        assert!(warnings[1].contains("'mystery' on line 5"));
+    }
+
+    #[test]
+    fn test_parse_project_config_still_rejects_invalid_values() {
+        let err = parse_project_config_str("sidecar = yes").expect_err("invalid boolean expected");
+        assert!(err.to_string().contains("sidecar"));
+    }
+
+    #[test]
+    fn test_discover_project_config_walks_up_from_output_dir() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let config_dir = temp.path().join(".downloader");
+        fs::create_dir_all(&config_dir).unwrap();
+        fs::write(
+            config_dir.join("config.toml"),
+            "detect_topics = true\ntopics_file = \"topics.txt\"\n",
+        )
+        .unwrap();
+        let nested = temp.path().join("papers").join("2026");
+
+        let found = discover_project_config(&nested)
+            .unwrap()
+            .expect("config above output dir should be found");
+        assert_eq!(found.path, config_dir.join("config.toml"));
+        assert_eq!(found.config.detect_topics, Some(true));
+        assert_eq!(
+            found.config.topics_file,
+            Some(temp.path().join("topics.txt"))
+        );
+        assert!(found.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_discover_ignore_file_walks_up_from_output_dir() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let nested = temp.path().join("papers");
+        fs::create_dir_all(&nested).unwrap();
+        assert_eq!(discover_ignore_file(&nested), None);
+
+        let config_dir = temp.path().join(".downloader");
+        fs::create_dir_all(&config_dir).unwrap();
+        fs::write(config_dir.join("ignore"), "*.example.com\n").unwrap();
+        assert_eq!(
+            discover_ignore_file(&nested),
+            Some(config_dir.join("ignore"))
+        );
+    }
+
+    #[test]
+    fn test_layer_project_config_overrides_global_scopable_keys_only() {
+        let global = FileConfig {
+            concurrency: Some(8),
+            rate_limit: Some(500),
+            db_max_connections: Some(3),
+            ..FileConfig::default()
+        };
+        let project = FileConfig {
+            concurrency: Some(2),
+            sidecar: Some(true),
+            db_max_connections: Some(10),
+            ..FileConfig::default()
+        };
+        let merged = layer_project_config(Some(&global), &project);
+        assert_eq!(merged.concurrency, Some(2));
+        assert_eq!(merged.rate_limit, Some(500));
+        assert_eq!(merged.sidecar, Some(true));
+        assert_eq!(merged.db_max_connections, Some(3));
+    }
+
+    #[test]
+    fn test_parse_config_layout() {
+        let cfg = parse_config_str("layout = \"by-year\"").expect("layout should parse");
+        assert_eq!(cfg.layout, Some(LayoutMode::ByYear));
+
+        let err = parse_config_str("layout = \"by-month\"").expect_err("unknown layout");
+        assert!(format!("{err:#}").contains("by-first-author"));
+    }
+
     #[test]
     fn test_parse_config_rejects_unknown_keys() {
         let err = parse_config_str("unknown_key = 123").expect_err("unknown key error expected");
@@ -651,11 +1568,64 @@ resolver_read_timeout_secs = 45
             r#"
 db_max_connections = 10
 db_busy_timeout_ms = 3000
+db_synchronous = "normal"
 "#,
         )
         .expect("db options should parse");
         assert_eq!(cfg.db_max_connections, Some(10));
         assert_eq!(cfg.db_busy_timeout_ms, Some(3000));
+        assert_eq!(cfg.db_synchronous, Some(SynchronousMode::Normal));
+    }
+
+    #[test]
+    fn test_parse_config_auth_bearer() {
+        let cfg = parse_config_str(r#"auth_bearer = "api.example.org=abc, gw.example.edu=xyz""#)
+            .expect("auth_bearer should parse");
+        let headers = cfg.auth_bearer.expect("auth_bearer should be set");
+        assert_eq!(
+            headers.domains().collect::<Vec<_>>(),
+            vec!["api.example.org", "gw.example.edu"]
+        );
+        assert!(!format!("{headers:?}").contains("abc"));
+    }
+
+    #[test]
+    fn test_parse_config_rejects_malformed_auth_bearer_without_echoing_token() {
+        let err = parse_config_str(r#"auth_bearer = "secret-token""#)
+            .expect_err("entry without a domain should be rejected");
+        let message = format!("{err:#}");
+        assert!(message.contains("auth_bearer"));
+        assert!(!message.contains("secret-token"));
+    }
+
+    #[test]
+    fn test_parse_config_crossref_mailto_and_token() {
+        let cfg = parse_config_str(
+            "crossref_mailto = \"me@lab.example.org\"\ncrossref_token = \"plus-secret\"",
+        )
+        .expect("crossref keys should parse");
+        assert_eq!(cfg.crossref_mailto.as_deref(), Some("me@lab.example.org"));
+        assert_eq!(
+            cfg.crossref_token.as_ref().map(CrossrefApiToken::expose),
+            Some("plus-secret")
+        );
+        assert!(!format!("{cfg:?}").contains("plus-secret"));
+    }
+
+    #[test]
+    fn test_parse_config_rejects_crossref_token_with_spaces_without_echoing_it() {
+        let err = parse_config_str(r#"crossref_token = "plus secret""#)
+            .expect_err("tokens with spaces cannot be sent as a header");
+        let message = format!("{err:#}");
+        assert!(message.contains("crossref_token"));
+        assert!(!message.contains("plus secret"));
+    }
+
+    #[test]
+    fn test_parse_config_rejects_unknown_db_synchronous() {
+        let err = parse_config_str("db_synchronous = \"sometimes\"")
+            .expect_err("unknown synchronous mode should be rejected");
+        assert!(err.to_string().contains("db_synchronous"));
     }
 
     #[test]
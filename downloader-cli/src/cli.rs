@@ -4,7 +4,10 @@ use std::path::PathBuf;
 
 use clap::{Args as ClapArgs, Parser, Subcommand, ValueEnum};
 
-use downloader_core::{DEFAULT_CONCURRENCY, DEFAULT_MAX_RETRIES};
+use downloader_core::{DEFAULT_CONCURRENCY, DEFAULT_MAX_RETRIES, DownloadErrorType};
+
+/// Default number of inputs resolved in parallel before downloads start.
+pub const DEFAULT_RESOLVE_CONCURRENCY: u8 = 4;
 
 /// Batch download and organize reference documents.
 ///
@@ -27,6 +30,17 @@ pub struct Cli {
     pub download: DownloadArgs,
 }
 
+impl Cli {
+    /// Download-mode arguments in effect: the `resolve` subcommand's copy, or the top-level flags.
+    #[must_use]
+    pub fn download_args(&self) -> &DownloadArgs {
+        match &self.command {
+            Some(Command::Resolve(resolve_args)) => &resolve_args.download,
+            _ => &self.download,
+        }
+    }
+}
+
 /// Top-level command namespaces.
 #[derive(Subcommand, Debug, PartialEq, Eq)]
 pub enum Command {
@@ -35,10 +49,14 @@ pub enum Command {
         #[command(subcommand)]
         command: AuthCommand,
     },
+    /// Resolve inputs and queue them as pending without downloading anything.
+    Resolve(Box<ResolveArgs>),
     /// Query persisted download history.
     Log(LogArgs),
     /// Search persisted download history metadata.
     Search(SearchArgs),
+    /// Show lifetime download throughput and duration aggregates per domain.
+    Stats(StatsArgs),
     /// Manage downloader configuration.
     Config {
         #[command(subcommand)]
@@ -48,6 +66,62 @@ pub enum Command {
     Export(ExportArgs),
     /// Convert HTML files in a corpus directory to PDF using headless Chrome.
     Convert(ConvertArgs),
+    /// Rebuild a project's index.md, topics.md, and manifest.json from the files on disk.
+    Reindex(ReindexArgs),
+    /// Move the persisted download queue between machines as JSON.
+    Queue {
+        #[command(subcommand)]
+        command: QueueCommand,
+    },
+    /// Maintain the queue/history database (`.downloader/queue.db`).
+    Db {
+        #[command(subcommand)]
+        command: DbCommand,
+    },
+    /// Maintain persisted download history.
+    History {
+        #[command(subcommand)]
+        command: HistoryCommand,
+    },
+    /// Inspect the persisted download byte budget.
+    Budget {
+        #[command(subcommand)]
+        command: BudgetCommand,
+    },
+    /// Print a shell completion script to stdout.
+    Completions(CompletionsArgs),
+}
+
+/// Shells supported by `downloader completions`.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionShell {
+    /// Bash (`complete -F`).
+    Bash,
+    /// Zsh (`#compdef`).
+    Zsh,
+    /// Fish (`complete -c`).
+    Fish,
+    /// `PowerShell` (`Register-ArgumentCompleter`).
+    Powershell,
+}
+
+impl From<CompletionShell> for clap_complete::Shell {
+    fn from(shell: CompletionShell) -> Self {
+        match shell {
+            CompletionShell::Bash => clap_complete::Shell::Bash,
+            CompletionShell::Zsh => clap_complete::Shell::Zsh,
+            CompletionShell::Fish => clap_complete::Shell::Fish,
+            CompletionShell::Powershell => clap_complete::Shell::PowerShell,
+        }
+    }
+}
+
+/// Arguments for `downloader completions`.
+#[derive(ClapArgs, Debug, Clone, PartialEq, Eq)]
+pub struct CompletionsArgs {
+    /// Shell to generate the completion script for.
+    #[arg(value_enum)]
+    pub shell: CompletionShell,
 }
 
 /// Export format selection for `downloader export`.
@@ -68,6 +142,120 @@ impl From<ExportFormatArg> for downloader_core::ExportFormat {
     }
 }
 
+/// Cookie source for `downloader auth capture --from`.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CookieSourceArg {
+    /// Netscape `cookies.txt` export file.
+    Netscape,
+    /// JSON cookie export file from a browser extension.
+    Json,
+    /// HAR archive saved from browser dev tools.
+    Har,
+    /// Firefox profile directory (or its `cookies.sqlite`).
+    Firefox,
+    /// Chrome/Chromium/Edge/Brave profile directory (or its `Cookies` database).
+    Chrome,
+}
+
+/// Sidecar file format for `downloader --sidecar`.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SidecarFormatArg {
+    /// Schema.org JSON-LD (`.json`) — read back by `downloader export` (default).
+    #[default]
+    JsonLd,
+    /// Single-record RIS (`.ris`) — imports directly into Zotero, Mendeley, or EndNote.
+    Ris,
+}
+
+impl From<SidecarFormatArg> for downloader_core::SidecarFormat {
+    fn from(arg: SidecarFormatArg) -> Self {
+        match arg {
+            SidecarFormatArg::JsonLd => downloader_core::SidecarFormat::JsonLd,
+            SidecarFormatArg::Ris => downloader_core::SidecarFormat::Ris,
+        }
+    }
+}
+
+/// Output folder layout for `downloader --layout`.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LayoutArg {
+    /// All files directly in the output folder (default).
+    #[default]
+    Flat,
+    /// `<year>/<file>` subfolders.
+    ByYear,
+    /// `<first author>/<file>` subfolders.
+    ByFirstAuthor,
+    /// `<first detected topic>/<file>` subfolders (pair with --detect-topics).
+    ByTopic,
+}
+
+/// Minimum parse confidence for `downloader --min-reference-confidence`.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum ReferenceConfidenceArg {
+    /// Accept every reference (default).
+    #[default]
+    Low,
+    /// Require at least two of author, year, and title.
+    Medium,
+    /// Require author, year, and title.
+    High,
+}
+
+impl ReferenceConfidenceArg {
+    /// Returns true when a reference parsed at `confidence` meets this minimum.
+    pub fn admits(self, confidence: downloader_core::Confidence) -> bool {
+        let level = match confidence {
+            downloader_core::Confidence::Low => Self::Low,
+            downloader_core::Confidence::Medium => Self::Medium,
+            downloader_core::Confidence::High => Self::High,
+        };
+        level >= self
+    }
+}
+
+impl From<LayoutArg> for downloader_core::LayoutMode {
+    fn from(arg: LayoutArg) -> Self {
+        match arg {
+            LayoutArg::Flat => downloader_core::LayoutMode::Flat,
+            LayoutArg::ByYear => downloader_core::LayoutMode::ByYear,
+            LayoutArg::ByFirstAuthor => downloader_core::LayoutMode::ByFirstAuthor,
+            LayoutArg::ByTopic => downloader_core::LayoutMode::ByTopic,
+        }
+    }
+}
+
+impl From<downloader_core::LayoutMode> for LayoutArg {
+    fn from(mode: downloader_core::LayoutMode) -> Self {
+        match mode {
+            downloader_core::LayoutMode::Flat => LayoutArg::Flat,
+            downloader_core::LayoutMode::ByYear => LayoutArg::ByYear,
+            downloader_core::LayoutMode::ByFirstAuthor => LayoutArg::ByFirstAuthor,
+            downloader_core::LayoutMode::ByTopic => LayoutArg::ByTopic,
+        }
+    }
+}
+
+/// Output format for `downloader --dry-run`.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DryRunFormatArg {
+    /// Human-readable preview lines (default).
+    #[default]
+    Text,
+    /// A JSON array with one object per input, for auditing resolution in CI.
+    Json,
+}
+
+/// Log line format for `--log-format`.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFormatArg {
+    /// Human-readable tracing lines (default).
+    #[default]
+    Text,
+    /// One JSON object per line, with the current span and its parents' fields.
+    Json,
+}
+
 /// Arguments for `downloader export`.
 #[derive(ClapArgs, Debug, Clone, PartialEq, Eq)]
 pub struct ExportArgs {
@@ -84,6 +272,18 @@ pub struct ExportArgs {
     pub output: Option<PathBuf>,
 }
 
+/// Arguments for `downloader reindex`.
+#[derive(ClapArgs, Debug, Clone, PartialEq, Eq)]
+pub struct ReindexArgs {
+    /// Project folder containing `.downloader/queue.db`.
+    #[arg(value_name = "PROJECT_DIR")]
+    pub dir: PathBuf,
+
+    /// Path to custom topics file (one topic per line), as for `--topics-file` on downloads.
+    #[arg(long = "topics-file", value_name = "FILE")]
+    pub topics_file: Option<PathBuf>,
+}
+
 /// Arguments for `downloader convert`.
 #[derive(ClapArgs, Debug, Clone, PartialEq, Eq)]
 pub struct ConvertArgs {
@@ -110,8 +310,12 @@ pub struct ConvertArgs {
 pub enum AuthCommand {
     /// Capture browser cookies from stdin/path and validate them.
     Capture(AuthCaptureArgs),
-    /// Clear persisted encrypted cookies.
+    /// Clear persisted encrypted cookies and auth tokens.
     Clear,
+    /// Store (or remove) a bearer token sent to one domain and its subdomains.
+    Token(AuthTokenArgs),
+    /// Report stored sessions per domain: cookie counts and soonest expiry.
+    Status(AuthStatusArgs),
 }
 
 /// Config command variants.
@@ -119,6 +323,207 @@ pub enum AuthCommand {
 pub enum ConfigCommand {
     /// Show effective configuration values.
     Show,
+    /// Check a config file for unknown keys and invalid values.
+    Validate(ConfigValidateArgs),
+    /// Write a commented default `config.toml` to the standard location.
+    Init(ConfigInitArgs),
+    /// Set one key in `config.toml`, keeping other lines and comments.
+    Set(ConfigSetArgs),
+}
+
+/// Arguments for `downloader config validate`.
+#[derive(ClapArgs, Debug, Clone, PartialEq, Eq)]
+pub struct ConfigValidateArgs {
+    /// Config file to check (defaults to the global `config.toml`).
+    #[arg(long, value_name = "FILE")]
+    pub file: Option<PathBuf>,
+}
+
+/// Arguments for `downloader config init`.
+#[derive(ClapArgs, Debug, Clone, PartialEq, Eq)]
+pub struct ConfigInitArgs {
+    /// Replace an existing config file.
+    #[arg(long)]
+    pub force: bool,
+}
+
+/// Arguments for `downloader config set`.
+#[derive(ClapArgs, Debug, Clone, PartialEq, Eq)]
+pub struct ConfigSetArgs {
+    /// Config key, as listed by `downloader config show` or the README.
+    #[arg(value_name = "KEY")]
+    pub key: String,
+    /// New value; string values may be given without quotes.
+    #[arg(value_name = "VALUE", allow_hyphen_values = true)]
+    pub value: String,
+}
+
+/// Queue command variants.
+#[derive(Subcommand, Debug, PartialEq, Eq)]
+pub enum QueueCommand {
+    /// Show item counts by status, the oldest pending item's age, and failure categories.
+    Status(QueueStatusArgs),
+    /// Write every queue item (status and metadata included) as JSON.
+    Export(QueueTransferArgs),
+    /// Re-insert queue items from a JSON file produced by `queue export`.
+    Import(QueueTransferArgs),
+    /// List permanently failed (dead-lettered) queue items.
+    DeadLetter(QueueScopeArgs),
+    /// Return dead-lettered queue items to pending so the next run retries them.
+    Requeue(QueueRequeueArgs),
+    /// Change a queue item's priority (higher priorities are downloaded first).
+    Reprioritize(QueueReprioritizeArgs),
+}
+
+/// Queue location arguments shared by queue maintenance commands.
+#[derive(ClapArgs, Debug, Clone, PartialEq, Eq)]
+pub struct QueueScopeArgs {
+    /// Output directory root containing `.downloader/queue.db` (default: current directory).
+    #[arg(short = 'o', long = "output-dir", value_name = "DIR")]
+    pub output_dir: Option<PathBuf>,
+
+    /// Project folder name (uses same sanitization rules as download mode).
+    #[arg(long, value_name = "NAME")]
+    pub project: Option<String>,
+}
+
+/// Arguments for `downloader queue status`.
+#[derive(ClapArgs, Debug, Clone, PartialEq, Eq)]
+pub struct QueueStatusArgs {
+    #[command(flatten)]
+    pub scope: QueueScopeArgs,
+
+    /// Redraw the status every few seconds until Ctrl+C (for watching a background run).
+    #[arg(long)]
+    pub watch: bool,
+}
+
+/// Arguments for `downloader queue requeue`.
+#[derive(ClapArgs, Debug, Clone, PartialEq, Eq)]
+pub struct QueueRequeueArgs {
+    #[command(flatten)]
+    pub scope: QueueScopeArgs,
+
+    /// Dead-lettered item ID to requeue (repeatable). Requeues all when omitted.
+    #[arg(long = "id", value_name = "ID")]
+    pub ids: Vec<i64>,
+}
+
+/// Arguments for `downloader queue reprioritize`.
+#[derive(ClapArgs, Debug, Clone, PartialEq, Eq)]
+pub struct QueueReprioritizeArgs {
+    #[command(flatten)]
+    pub scope: QueueScopeArgs,
+
+    /// Queue item ID to update.
+    #[arg(value_name = "ID")]
+    pub id: i64,
+
+    /// New priority; higher values are dequeued first.
+    #[arg(value_name = "PRIORITY", allow_negative_numbers = true)]
+    pub priority: i64,
+}
+
+/// Database maintenance command variants.
+#[derive(Subcommand, Debug, PartialEq, Eq)]
+pub enum DbCommand {
+    /// Write a consistent single-file snapshot of the queue/history database.
+    Backup(DbBackupArgs),
+    /// Reclaim disk space left by deleted queue/history rows (offline maintenance).
+    Compact(QueueScopeArgs),
+}
+
+/// History maintenance command variants.
+#[derive(Subcommand, Debug, PartialEq, Eq)]
+pub enum HistoryCommand {
+    /// Delete download history rows older than a date (queue items are left alone).
+    Prune(HistoryPruneArgs),
+}
+
+/// Arguments for `downloader history prune`.
+#[derive(ClapArgs, Debug, Clone, PartialEq, Eq)]
+pub struct HistoryPruneArgs {
+    #[command(flatten)]
+    pub scope: QueueScopeArgs,
+
+    /// Delete rows started before this date (`YYYY-MM-DD` or `YYYY-MM-DD HH:MM:SS`).
+    #[arg(long, value_name = "DATETIME")]
+    pub before: String,
+
+    /// Only delete rows with this attempt status.
+    #[arg(long, value_enum)]
+    pub status: Option<HistoryStatusArg>,
+
+    /// Report how many rows would be deleted without deleting anything.
+    #[arg(long, conflicts_with = "vacuum")]
+    pub dry_run: bool,
+
+    /// Compact the database afterwards to return the freed space (as `db compact`).
+    #[arg(long)]
+    pub vacuum: bool,
+}
+
+/// Subcommands for `downloader budget`.
+#[derive(Subcommand, Debug, Clone, PartialEq, Eq)]
+pub enum BudgetCommand {
+    /// Show bytes downloaded in the rolling window and what is left of the budget.
+    Status(BudgetStatusArgs),
+}
+
+/// Arguments for `downloader budget status`.
+#[derive(ClapArgs, Debug, Clone, PartialEq, Eq)]
+pub struct BudgetStatusArgs {
+    #[command(flatten)]
+    pub scope: QueueScopeArgs,
+
+    /// Budget to compare against (default: `byte_budget` from the config file).
+    #[arg(long = "byte-budget", value_name = "SIZE", value_parser = parse_byte_size)]
+    pub byte_budget: Option<u64>,
+}
+
+/// Arguments for `downloader db backup`.
+#[derive(ClapArgs, Debug, Clone, PartialEq, Eq)]
+pub struct DbBackupArgs {
+    /// Snapshot file to create.
+    #[arg(value_name = "PATH")]
+    pub path: PathBuf,
+
+    #[command(flatten)]
+    pub scope: QueueScopeArgs,
+
+    /// Replace `PATH` if it already exists.
+    #[arg(long)]
+    pub force: bool,
+}
+
+/// Arguments shared by `downloader queue export` and `downloader queue import`.
+#[derive(ClapArgs, Debug, Clone, PartialEq, Eq)]
+pub struct QueueTransferArgs {
+    /// JSON file to write (export) or read (import). Use `-` for stdout/stdin.
+    #[arg(value_name = "FILE")]
+    pub file: PathBuf,
+
+    /// Output directory root containing `.downloader/queue.db` (default: current directory).
+    #[arg(short = 'o', long = "output-dir", value_name = "DIR")]
+    pub output_dir: Option<PathBuf>,
+
+    /// Project folder name (uses same sanitization rules as download mode).
+    #[arg(long, value_name = "NAME")]
+    pub project: Option<String>,
+}
+
+/// Arguments for `downloader resolve`.
+///
+/// Accepts the same input, output, and resolver flags as a download run;
+/// download-only flags such as `--concurrency` have no effect.
+#[derive(ClapArgs, Debug, PartialEq, Eq)]
+pub struct ResolveArgs {
+    /// Also write the queue as JSON (same format as `queue export`). Use `-` for stdout.
+    #[arg(long, value_name = "FILE")]
+    pub export: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub download: DownloadArgs,
 }
 
 /// Arguments for `downloader auth capture`.
@@ -127,6 +532,44 @@ pub struct AuthCaptureArgs {
     /// Persist cookies securely (encrypted at rest) for future runs.
     #[arg(long)]
     pub save_cookies: bool,
+
+    /// Read cookies from this source instead of prompting for a file or pasted text.
+    #[arg(long, value_enum, requires = "profile")]
+    pub from: Option<CookieSourceArg>,
+
+    /// Cookie file, or browser profile directory for `--from firefox|chrome`.
+    #[arg(long, value_name = "PATH", requires = "from")]
+    pub profile: Option<PathBuf>,
+
+    /// Keep only cookies for these domains and their subdomains (comma-separated).
+    ///
+    /// With `--save-cookies`, stored cookies for other domains are kept.
+    #[arg(long, value_name = "DOMAINS", value_delimiter = ',')]
+    pub domains: Vec<String>,
+}
+
+/// Arguments for `downloader auth status`.
+#[derive(ClapArgs, Debug, PartialEq, Eq)]
+pub struct AuthStatusArgs {
+    /// Send one HEAD request per domain with the stored cookies to check the session still works.
+    #[arg(long)]
+    pub probe: bool,
+}
+
+/// Arguments for `downloader auth token`.
+#[derive(ClapArgs, Debug, PartialEq, Eq)]
+pub struct AuthTokenArgs {
+    /// Domain the token applies to (subdomains included), e.g. api.example.org.
+    #[arg(long)]
+    pub domain: String,
+
+    /// Bearer token to send as `Authorization: Bearer <token>`; use `-` to read it from stdin.
+    #[arg(long, required_unless_present = "remove", conflicts_with = "remove")]
+    pub bearer: Option<String>,
+
+    /// Remove the stored token for the domain instead of setting one.
+    #[arg(long)]
+    pub remove: bool,
 }
 
 /// Status filter values for `downloader log --status`.
@@ -160,6 +603,10 @@ pub struct LogArgs {
     #[arg(long, value_name = "DOMAIN")]
     pub domain: Option<String>,
 
+    /// Filter failed attempts by error type (e.g. dns, tls, timeout, not_found).
+    #[arg(long = "error-type", value_name = "TYPE")]
+    pub error_type: Option<DownloadErrorType>,
+
     /// Shortcut for failed attempts only.
     #[arg(long, conflicts_with = "status")]
     pub failed: bool,
@@ -171,6 +618,39 @@ pub struct LogArgs {
     /// Maximum rows to show (default 50, max 10000).
     #[arg(long, default_value_t = 50, value_parser = clap::value_parser!(u16).range(1..=10000))]
     pub limit: u16,
+
+    /// Keep printing new attempts as they are logged, like `tail -f`, until Ctrl+C.
+    #[arg(short = 'f', long)]
+    pub follow: bool,
+
+    /// Also search symlinked directories for project history (loops are detected).
+    #[arg(long = "follow-symlinks")]
+    pub follow_symlinks: bool,
+
+    /// Merge every discovered project history, tagging rows with their project
+    /// and skipping databases that fail to open.
+    #[arg(long = "all-projects", conflicts_with_all = ["project", "follow"])]
+    pub all_projects: bool,
+}
+
+/// Arguments for `downloader stats`.
+#[derive(ClapArgs, Debug, Clone, PartialEq, Eq)]
+pub struct StatsArgs {
+    /// Output directory root containing `.downloader/queue.db` (default: current directory).
+    #[arg(short = 'o', long = "output-dir", value_name = "DIR")]
+    pub output_dir: Option<PathBuf>,
+
+    /// Project folder filter/name (uses same sanitization rules as download mode).
+    #[arg(long, value_name = "NAME")]
+    pub project: Option<String>,
+
+    /// Only aggregate attempts started at/after a timestamp (SQLite datetime string).
+    #[arg(long, value_name = "DATETIME")]
+    pub since: Option<String>,
+
+    /// Also search symlinked directories for project history (loops are detected).
+    #[arg(long = "follow-symlinks")]
+    pub follow_symlinks: bool,
 }
 
 /// Arguments for `downloader search`.
@@ -203,10 +683,19 @@ pub struct SearchArgs {
     /// Open the top-ranked result file in the system default app.
     #[arg(long = "open")]
     pub open: bool,
+
+    /// Also search symlinked directories for project history (loops are detected).
+    #[arg(long = "follow-symlinks")]
+    pub follow_symlinks: bool,
+
+    /// Rank results from every discovered project history together, tagging
+    /// rows with their project and skipping databases that fail to open.
+    #[arg(long = "all-projects", conflicts_with = "project")]
+    pub all_projects: bool,
 }
 
 /// Download-mode arguments (default command when no subcommand is provided).
-#[derive(ClapArgs, Debug, Clone)]
+#[derive(ClapArgs, Debug, Clone, PartialEq, Eq)]
 pub struct DownloadArgs {
     /// Increase output verbosity (-v for debug, -vv for trace)
     #[arg(short, long, action = clap::ArgAction::Count, conflicts_with_all = ["quiet", "debug"])]
@@ -224,14 +713,62 @@ pub struct DownloadArgs {
     #[arg(long = "no-color")]
     pub no_color: bool,
 
+    /// Log line format on stderr: `text` or `json` (JSON lines for log aggregators).
+    #[arg(long = "log-format", value_enum, default_value_t = LogFormatArg::Text)]
+    pub log_format: LogFormatArg,
+
     /// Parse and resolve input without downloading files or writing queue records.
     #[arg(short = 'n', long = "dry-run")]
     pub dry_run: bool,
 
+    /// Dry-run output format (`json` prints a machine-readable plan to stdout).
+    #[arg(long = "format", value_enum, default_value_t = DryRunFormatArg::Text, requires = "dry_run")]
+    pub format: DryRunFormatArg,
+
+    /// With --dry-run, send a HEAD request per resolved URL and report status, type, and size
+    #[arg(long = "check", requires = "dry_run")]
+    pub check: bool,
+
+    /// Stream a single input's bytes to stdout instead of saving a file (implies --quiet).
+    #[arg(long = "stdout", conflicts_with = "dry_run")]
+    pub stdout: bool,
+
+    /// Print the whole run's result as one JSON document on stdout; logs stay on stderr.
+    #[arg(long = "json", conflicts_with_all = ["stdout", "dry_run"])]
+    pub json: bool,
+
+    /// Save a `run-report-<unix>.json` audit file (stats, failure breakdown, resolver usage) in DIR after each download run
+    #[arg(long = "report-dir", value_name = "DIR")]
+    pub report_dir: Option<PathBuf>,
+
+    /// Download at most N queued items this run; the rest stay pending for the next run
+    #[arg(long = "max-items", value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    pub max_items: Option<u64>,
+
+    /// Stop dequeuing once this many bytes were downloaded in the rolling 30-day window (e.g. 500M, 50G); in-flight items finish
+    #[arg(long = "byte-budget", value_name = "SIZE", value_parser = parse_byte_size)]
+    pub byte_budget: Option<u64>,
+
+    /// Download files of at least --segment-threshold-mb as N parallel range requests (2-16) when the server supports ranges
+    #[arg(long = "segments", value_name = "N", value_parser = clap::value_parser!(u8).range(2..=16))]
+    pub segments: Option<u8>,
+
+    /// Minimum file size in MB for --segments to split a download
+    #[arg(long = "segment-threshold-mb", value_name = "MB", default_value_t = 100, requires = "segments", value_parser = clap::value_parser!(u64).range(1..))]
+    pub segment_threshold_mb: u64,
+
+    /// Fail empty downloads, and PDFs under this many bytes or not starting with %PDF (0 disables the check)
+    #[arg(long = "min-file-size", value_name = "BYTES", default_value_t = 1024)]
+    pub min_file_size: u64,
+
     /// Maximum concurrent downloads (1-100)
     #[arg(short = 'c', long, default_value_t = DEFAULT_CONCURRENCY as u8, value_parser = clap::value_parser!(u8).range(1..=100))]
     pub concurrency: u8,
 
+    /// Maximum inputs resolved in parallel before downloading (1-32)
+    #[arg(long = "resolve-concurrency", default_value_t = DEFAULT_RESOLVE_CONCURRENCY, value_parser = clap::value_parser!(u8).range(1..=32))]
+    pub resolve_concurrency: u8,
+
     /// Maximum retry attempts for transient failures (0-10)
     #[arg(short = 'r', long, default_value_t = DEFAULT_MAX_RETRIES as u8, value_parser = clap::value_parser!(u8).range(0..=10))]
     pub max_retries: u8,
@@ -248,10 +785,28 @@ pub struct DownloadArgs {
     #[arg(long, default_value_t = 0, value_parser = clap::value_parser!(u64).range(0..=60000))]
     pub rate_limit_jitter: u64,
 
+    /// Double a domain's delay on each 429/503 and ease back after repeated successes.
+    ///
+    /// The `--rate-limit` delay stays the floor. Also applies with `--rate-limit 0`,
+    /// starting from a 1 s delay at the first 429/503.
+    #[arg(long = "rate-adaptive")]
+    pub rate_adaptive: bool,
+
     /// Check robots.txt before downloading; when --respectful, this is enabled.
     #[arg(long)]
     pub check_robots: bool,
 
+    /// Download from this domain (and its subdomains) even where robots.txt disallows it.
+    ///
+    /// Repeat the flag for several domains. Replaces the `robots_allow`
+    /// config list when given.
+    #[arg(long = "robots-allow", value_name = "DOMAIN", action = clap::ArgAction::Append)]
+    pub robots_allow: Vec<String>,
+
+    /// Skip inputs whose URL matches a domain or URL pattern in this file (default: nearest .downloader/ignore)
+    #[arg(long = "ignore-file", value_name = "FILE")]
+    pub ignore_file: Option<PathBuf>,
+
     /// Output directory for downloaded files (default: current directory)
     #[arg(short = 'o', long = "output-dir", value_name = "DIR")]
     pub output_dir: Option<PathBuf>,
@@ -260,11 +815,29 @@ pub struct DownloadArgs {
     #[arg(long, value_name = "NAME")]
     pub project: Option<String>,
 
+    /// Arrange saved files in subfolders by year, first author, or topic
+    #[arg(long, value_enum, default_value_t = LayoutArg::Flat)]
+    pub layout: LayoutArg,
+
+    /// Skip references parsed below this confidence and list them in needs-verification.txt
+    #[arg(
+        long = "min-reference-confidence",
+        value_enum,
+        default_value_t = ReferenceConfidenceArg::Low,
+        value_name = "LEVEL"
+    )]
+    pub min_reference_confidence: ReferenceConfidenceArg,
+
+    /// Queue priority for items enqueued by this run; higher runs first (default: DOIs before URLs)
+    #[arg(long, value_name = "N", allow_negative_numbers = true)]
+    pub priority: Option<i64>,
+
     /// Cookie file in Netscape format (use `-` for stdin)
     #[arg(long, value_name = "FILE")]
     pub cookies: Option<String>,
 
-    /// Persist cookies securely (encrypted at rest) for future runs
+    /// Persist cookies securely (encrypted at rest) for future runs, and write cookies
+    /// refreshed during the run back to the --cookies file
     #[arg(long)]
     pub save_cookies: bool,
 
@@ -276,23 +849,92 @@ pub struct DownloadArgs {
     #[arg(long = "topics-file", value_name = "FILE", requires = "detect_topics")]
     pub topics_file: Option<PathBuf>,
 
+    /// Derive topics from the text of downloaded PDFs' first pages, not just titles
+    #[arg(long = "topics-from-content", requires = "detect_topics")]
+    pub topics_from_content: bool,
+
+    /// Only enqueue items whose title/abstract keywords match one of these comma-separated topics.
+    ///
+    /// Matching uses the metadata known at enqueue time, so items without a
+    /// resolved title are filtered out; content-derived topics cannot be used.
+    #[arg(long = "topic-filter", value_name = "TOPICS", value_delimiter = ',')]
+    pub topic_filter: Vec<String>,
+
     /// Write a JSON-LD sidecar file alongside each downloaded file (Story 8.2)
     #[arg(long = "sidecar")]
     pub sidecar: bool,
 
-    /// Bibliography file(s) to import (.bib or .ris format).
+    /// Sidecar file format written by `--sidecar`.
+    #[arg(long = "sidecar-format", value_enum, default_value_t = SidecarFormatArg::JsonLd)]
+    pub sidecar_format: SidecarFormatArg,
+
+    /// Fill missing title/author/journal metadata from Crossref when a DOI is known.
+    ///
+    /// Costs one extra Crossref request per resolved item; metadata from the
+    /// primary resolver is never overwritten.
+    #[arg(long = "enrich-metadata")]
+    pub enrich_metadata: bool,
+
+    /// Download an arXiv preprint and its published DOI only once when both are in the input.
+    ///
+    /// Pairs are matched by the journal DOI arXiv records for the preprint or by
+    /// near-identical titles (one arXiv API request per 100 preprints). The
+    /// open-access arXiv copy is kept; the other URL becomes its fallback.
+    #[arg(long = "merge-duplicates")]
+    pub merge_duplicates: bool,
+
+    /// Skip a resolver by name (e.g. `ieee`, `crossref`) even when it could handle an input.
+    ///
+    /// Repeat the flag to disable several resolvers. Replaces the
+    /// `disabled_resolvers` config list when given.
+    #[arg(long = "disable-resolver", value_name = "NAME", action = clap::ArgAction::Append)]
+    pub disable_resolver: Vec<String>,
+
+    /// Bibliography file(s) to import (.bib, .ris, or EndNote .xml format).
     ///
     /// Each file is parsed for DOIs, URLs, and reference metadata. DOIs are
     /// preferred over URLs when both appear in the same entry. Multiple files
-    /// can be supplied by repeating the flag.
+    /// can be supplied by repeating the flag. Gzip-compressed files
+    /// (`refs.bib.gz`) are decompressed transparently.
     #[arg(long = "bibliography", short = 'B', value_name = "FILE", action = clap::ArgAction::Append)]
     pub bibliography_files: Vec<PathBuf>,
 
-    /// URLs to download (reads from stdin if not provided).
+    /// URLs/DOIs to download, or input files to read them from (reads from stdin if not provided;
+    /// gzipped input is detected and decompressed). An argument naming an existing file is parsed
+    /// as a list (plain text, BibTeX, RIS, or EndNote XML); `-` reads such a list from stdin.
     /// Flags may appear before or after URLs. Use `--` to pass a URL that starts with `-`.
     pub urls: Vec<String>,
 }
 
+/// Parses a positive byte count with an optional `K`/`M`/`G`/`T` suffix (powers of 1024).
+///
+/// `500M`, `50GB`, `2GiB`, and plain `1048576` are all accepted.
+pub fn parse_byte_size(value: &str) -> Result<u64, String> {
+    let invalid =
+        || format!("invalid size '{value}': expected bytes or a number with K, M, G, or T");
+    let trimmed = value.trim();
+    let split = trimmed
+        .find(|ch: char| !ch.is_ascii_digit())
+        .unwrap_or(trimmed.len());
+    let (digits, suffix) = trimmed.split_at(split);
+    let number: u64 = digits.parse().map_err(|_| invalid())?;
+    let shift = match suffix.trim().to_ascii_uppercase().as_str() {
+        "" | "B" => 0,
+        "K" | "KB" | "KIB" => 10,
+        "M" | "MB" | "MIB" => 20,
+        "G" | "GB" | "GIB" => 30,
+        "T" | "TB" | "TIB" => 40,
+        _ => return Err(invalid()),
+    };
+    let bytes = number
+        .checked_mul(1_u64 << shift)
+        .ok_or_else(|| format!("size '{value}' is too large"))?;
+    if bytes == 0 {
+        return Err("size must be greater than zero".to_string());
+    }
+    Ok(bytes)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -379,68 +1021,304 @@ mod tests {
     }
 
     #[test]
-    fn test_cli_no_color_flag_sets_no_color() {
-        let args = parse_download(["downloader", "--no-color"]);
-        assert!(args.no_color);
+    fn test_cli_no_color_flag_sets_no_color() {
+        let args = parse_download(["downloader", "--no-color"]);
+        assert!(args.no_color);
+    }
+
+    #[test]
+    fn test_cli_detect_topics_flag_sets_detect_topics() {
+        let args = parse_download(["downloader", "--detect-topics"]);
+        assert!(args.detect_topics);
+        assert_eq!(args.topics_file, None);
+    }
+
+    #[test]
+    fn test_cli_topic_filter_splits_on_commas() {
+        assert!(parse_download(["downloader"]).topic_filter.is_empty());
+        let args = parse_download(["downloader", "--topic-filter", "climate,emissions"]);
+        assert_eq!(args.topic_filter, ["climate", "emissions"]);
+    }
+
+    #[test]
+    fn test_cli_topics_file_requires_detect_topics() {
+        // topics-file without detect-topics should fail
+        let result = Cli::try_parse_from(["downloader", "--topics-file", "topics.txt"]);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::MissingRequiredArgument);
+    }
+
+    #[test]
+    fn test_cli_topics_from_content_requires_detect_topics() {
+        assert!(Cli::try_parse_from(["downloader", "--topics-from-content"]).is_err());
+        let args = parse_download(["downloader", "--detect-topics", "--topics-from-content"]);
+        assert!(args.topics_from_content);
+    }
+
+    #[test]
+    fn test_cli_topics_file_with_detect_topics_succeeds() {
+        let args = parse_download([
+            "downloader",
+            "--detect-topics",
+            "--topics-file",
+            "custom.txt",
+        ]);
+        assert!(args.detect_topics);
+        assert_eq!(args.topics_file, Some(PathBuf::from("custom.txt")));
+    }
+
+    #[test]
+    fn test_cli_sidecar_flag_sets_sidecar() {
+        let args = parse_download(["downloader", "--sidecar"]);
+        assert!(args.sidecar);
+    }
+
+    #[test]
+    fn test_cli_sidecar_flag_defaults_to_false() {
+        let args = parse_download(["downloader"]);
+        assert!(!args.sidecar);
+    }
+
+    #[test]
+    fn test_cli_sidecar_format_defaults_to_json_ld() {
+        let args = parse_download(["downloader", "--sidecar"]);
+        assert_eq!(args.sidecar_format, SidecarFormatArg::JsonLd);
+    }
+
+    #[test]
+    fn test_cli_sidecar_format_accepts_ris() {
+        let args = parse_download(["downloader", "--sidecar", "--sidecar-format", "ris"]);
+        assert_eq!(args.sidecar_format, SidecarFormatArg::Ris);
+    }
+
+    #[test]
+    fn test_cli_layout_defaults_to_flat() {
+        let args = parse_download(["downloader"]);
+        assert_eq!(args.layout, LayoutArg::Flat);
+    }
+
+    #[test]
+    fn test_cli_layout_accepts_kebab_case_modes() {
+        let args = parse_download(["downloader", "--layout", "by-first-author"]);
+        assert_eq!(args.layout, LayoutArg::ByFirstAuthor);
+    }
+
+    #[test]
+    fn test_cli_priority_defaults_to_none_and_accepts_negative_values() {
+        assert_eq!(parse_download(["downloader"]).priority, None);
+        let args = parse_download(["downloader", "--priority", "-5"]);
+        assert_eq!(args.priority, Some(-5));
+    }
+
+    #[test]
+    fn test_cli_sidecar_flag_with_url() {
+        let args = parse_download(["downloader", "--sidecar", "https://example.com/paper.pdf"]);
+        assert!(args.sidecar);
+        assert_eq!(args.urls, vec!["https://example.com/paper.pdf"]);
+    }
+
+    #[test]
+    fn test_cli_dry_run_long_flag_sets_true() {
+        let args = parse_download(["downloader", "--dry-run"]);
+        assert!(args.dry_run);
+    }
+
+    #[test]
+    fn test_cli_dry_run_short_flag_sets_true() {
+        let args = parse_download(["downloader", "-n"]);
+        assert!(args.dry_run);
+    }
+
+    #[test]
+    fn test_cli_resolve_concurrency_defaults_and_parses() {
+        let args = parse_download(["downloader"]);
+        assert_eq!(args.resolve_concurrency, DEFAULT_RESOLVE_CONCURRENCY);
+
+        let args = parse_download(["downloader", "--resolve-concurrency", "12"]);
+        assert_eq!(args.resolve_concurrency, 12);
+    }
+
+    #[test]
+    fn test_cli_resolve_concurrency_rejects_out_of_range() {
+        assert!(Cli::try_parse_from(["downloader", "--resolve-concurrency", "0"]).is_err());
+        assert!(Cli::try_parse_from(["downloader", "--resolve-concurrency", "33"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_dry_run_format_defaults_to_text() {
+        let args = parse_download(["downloader", "--dry-run"]);
+        assert_eq!(args.format, DryRunFormatArg::Text);
+        let args = parse_download(["downloader", "--dry-run", "--format", "json"]);
+        assert_eq!(args.format, DryRunFormatArg::Json);
+    }
+
+    #[test]
+    fn test_cli_format_requires_dry_run() {
+        let result = Cli::try_parse_from(["downloader", "--format", "json"]);
+        assert!(result.is_err(), "--format should require --dry-run");
+    }
+
+    #[test]
+    fn test_cli_stdout_flag_with_url() {
+        let args = parse_download(["downloader", "--stdout", "https://example.com/paper.pdf"]);
+        assert!(args.stdout);
+        assert_eq!(args.urls, vec!["https://example.com/paper.pdf"]);
+    }
+
+    #[test]
+    fn test_cli_stdout_conflicts_with_dry_run() {
+        let result = Cli::try_parse_from(["downloader", "--stdout", "--dry-run"]);
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn test_cli_json_flag_parses_and_conflicts_with_stdout() {
+        let args = parse_download(["downloader", "--json", "https://example.com/paper.pdf"]);
+        assert!(args.json);
+
+        let err = Cli::try_parse_from(["downloader", "--json", "--stdout"]).unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::ArgumentConflict);
+        let err = Cli::try_parse_from(["downloader", "--json", "--dry-run"]).unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn test_cli_report_dir_flag_parses() {
+        assert!(parse_download(["downloader"]).report_dir.is_none());
+        let args = parse_download(["downloader", "--report-dir", "audit/reports"]);
+        assert_eq!(args.report_dir, Some(PathBuf::from("audit/reports")));
+    }
+
+    #[test]
+    fn test_cli_check_flag_requires_dry_run() {
+        assert!(!parse_download(["downloader"]).check);
+        assert!(parse_download(["downloader", "--dry-run", "--check"]).check);
+        assert!(Cli::try_parse_from(["downloader", "--check"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_max_items_flag_parses_and_rejects_zero() {
+        assert!(parse_download(["downloader"]).max_items.is_none());
+        let args = parse_download(["downloader", "--max-items", "25"]);
+        assert_eq!(args.max_items, Some(25));
+        assert!(Cli::try_parse_from(["downloader", "--max-items", "0"]).is_err());
     }
 
     #[test]
-    fn test_cli_detect_topics_flag_sets_detect_topics() {
-        let args = parse_download(["downloader", "--detect-topics"]);
-        assert!(args.detect_topics);
-        assert_eq!(args.topics_file, None);
+    fn test_cli_log_format_defaults_to_text() {
+        assert_eq!(
+            parse_download(["downloader"]).log_format,
+            LogFormatArg::Text
+        );
+        let args = parse_download(["downloader", "--log-format", "json"]);
+        assert_eq!(args.log_format, LogFormatArg::Json);
+        assert!(Cli::try_parse_from(["downloader", "--log-format", "xml"]).is_err());
     }
 
     #[test]
-    fn test_cli_topics_file_requires_detect_topics() {
-        // topics-file without detect-topics should fail
-        let result = Cli::try_parse_from(["downloader", "--topics-file", "topics.txt"]);
-        assert!(result.is_err());
-        let err = result.unwrap_err();
-        assert_eq!(err.kind(), clap::error::ErrorKind::MissingRequiredArgument);
+    fn test_cli_byte_budget_accepts_size_suffixes() {
+        assert!(parse_download(["downloader"]).byte_budget.is_none());
+        let args = parse_download(["downloader", "--byte-budget", "50G"]);
+        assert_eq!(args.byte_budget, Some(50 * 1024 * 1024 * 1024));
+        assert_eq!(parse_byte_size("1048576"), Ok(1_048_576));
+        assert_eq!(parse_byte_size("2 MiB"), Ok(2 * 1024 * 1024));
+        assert_eq!(parse_byte_size("3kb"), Ok(3 * 1024));
+        assert!(parse_byte_size("0").is_err());
+        assert!(parse_byte_size("1.5G").is_err());
+        assert!(parse_byte_size("10X").is_err());
+        assert!(parse_byte_size("99999999999T").is_err());
     }
 
     #[test]
-    fn test_cli_topics_file_with_detect_topics_succeeds() {
-        let args = parse_download([
+    fn test_cli_budget_status_parses_scope_and_budget() {
+        let cli = Cli::try_parse_from([
             "downloader",
-            "--detect-topics",
-            "--topics-file",
-            "custom.txt",
-        ]);
-        assert!(args.detect_topics);
-        assert_eq!(args.topics_file, Some(PathBuf::from("custom.txt")));
+            "budget",
+            "status",
+            "--project",
+            "thesis",
+            "--byte-budget",
+            "1G",
+        ])
+        .unwrap();
+        match cli.command {
+            Some(Command::Budget {
+                command: BudgetCommand::Status(args),
+            }) => {
+                assert_eq!(args.scope.project.as_deref(), Some("thesis"));
+                assert_eq!(args.byte_budget, Some(1024 * 1024 * 1024));
+            }
+            other => panic!("expected budget status command, got {other:?}"),
+        }
     }
 
     #[test]
-    fn test_cli_sidecar_flag_sets_sidecar() {
-        let args = parse_download(["downloader", "--sidecar"]);
-        assert!(args.sidecar);
+    fn test_cli_min_file_size_defaults_to_one_kib() {
+        assert_eq!(parse_download(["downloader"]).min_file_size, 1024);
+        let args = parse_download(["downloader", "--min-file-size", "0"]);
+        assert_eq!(args.min_file_size, 0);
     }
 
     #[test]
-    fn test_cli_sidecar_flag_defaults_to_false() {
+    fn test_cli_segments_flag_parses_and_bounds() {
         let args = parse_download(["downloader"]);
-        assert!(!args.sidecar);
+        assert!(args.segments.is_none());
+        assert_eq!(args.segment_threshold_mb, 100);
+        let args = parse_download([
+            "downloader",
+            "--segments",
+            "4",
+            "--segment-threshold-mb",
+            "500",
+        ]);
+        assert_eq!(args.segments, Some(4));
+        assert_eq!(args.segment_threshold_mb, 500);
+        assert!(Cli::try_parse_from(["downloader", "--segments", "1"]).is_err());
+        assert!(Cli::try_parse_from(["downloader", "--segments", "17"]).is_err());
+        assert!(Cli::try_parse_from(["downloader", "--segment-threshold-mb", "50"]).is_err());
     }
 
     #[test]
-    fn test_cli_sidecar_flag_with_url() {
-        let args = parse_download(["downloader", "--sidecar", "https://example.com/paper.pdf"]);
-        assert!(args.sidecar);
-        assert_eq!(args.urls, vec!["https://example.com/paper.pdf"]);
+    fn test_cli_disable_resolver_is_repeatable() {
+        assert!(parse_download(["downloader"]).disable_resolver.is_empty());
+        let args = parse_download([
+            "downloader",
+            "--disable-resolver",
+            "ieee",
+            "--disable-resolver",
+            "crossref",
+            "10.1234/example",
+        ]);
+        assert_eq!(args.disable_resolver, ["ieee", "crossref"]);
     }
 
     #[test]
-    fn test_cli_dry_run_long_flag_sets_true() {
-        let args = parse_download(["downloader", "--dry-run"]);
-        assert!(args.dry_run);
+    fn test_cli_robots_allow_is_repeatable() {
+        assert!(parse_download(["downloader"]).robots_allow.is_empty());
+        let args = parse_download([
+            "downloader",
+            "--check-robots",
+            "--robots-allow",
+            "repo.example.edu",
+            "--robots-allow",
+            "dspace.example.org",
+        ]);
+        assert!(args.check_robots);
+        assert_eq!(
+            args.robots_allow,
+            ["repo.example.edu", "dspace.example.org"]
+        );
     }
 
     #[test]
-    fn test_cli_dry_run_short_flag_sets_true() {
-        let args = parse_download(["downloader", "-n"]);
-        assert!(args.dry_run);
+    fn test_cli_enrich_metadata_flag_defaults_off() {
+        assert!(!parse_download(["downloader"]).enrich_metadata);
+        assert!(!parse_download(["downloader"]).merge_duplicates);
+        assert!(parse_download(["downloader", "--merge-duplicates"]).merge_duplicates);
+        let args = parse_download(["downloader", "--enrich-metadata", "10.1234/example"]);
+        assert!(args.enrich_metadata);
     }
 
     #[test]
@@ -601,6 +1479,14 @@ mod tests {
         assert_eq!(err.kind(), clap::error::ErrorKind::ValueValidation);
     }
 
+    #[test]
+    fn test_cli_rate_adaptive_defaults_off() {
+        assert!(!parse_download(["downloader"]).rate_adaptive);
+        let args = parse_download(["downloader", "--rate-adaptive", "-l", "0"]);
+        assert!(args.rate_adaptive);
+        assert_eq!(args.rate_limit, 0);
+    }
+
     #[test]
     fn test_cli_combined_all_flags() {
         let args = parse_download(["downloader", "-c", "20", "-r", "5", "-l", "2000"]);
@@ -615,6 +1501,36 @@ mod tests {
         assert!(args.respectful);
     }
 
+    #[test]
+    fn test_cli_ignore_file_flag() {
+        let args = parse_download(["downloader", "--ignore-file", "skip.txt"]);
+        assert_eq!(args.ignore_file, Some(PathBuf::from("skip.txt")));
+        assert_eq!(parse_download(["downloader"]).ignore_file, None);
+    }
+
+    #[test]
+    fn test_cli_min_reference_confidence_defaults_to_low() {
+        let args = parse_download(["downloader"]);
+        assert_eq!(args.min_reference_confidence, ReferenceConfidenceArg::Low);
+        let args = parse_download(["downloader", "--min-reference-confidence", "medium"]);
+        assert_eq!(
+            args.min_reference_confidence,
+            ReferenceConfidenceArg::Medium
+        );
+        assert!(
+            Cli::try_parse_from(["downloader", "--min-reference-confidence", "certain"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_reference_confidence_arg_admits_levels_at_or_above_minimum() {
+        use downloader_core::Confidence;
+        assert!(ReferenceConfidenceArg::Low.admits(Confidence::Low));
+        assert!(!ReferenceConfidenceArg::Medium.admits(Confidence::Low));
+        assert!(ReferenceConfidenceArg::Medium.admits(Confidence::High));
+        assert!(!ReferenceConfidenceArg::High.admits(Confidence::Medium));
+    }
+
     #[test]
     fn test_cli_respectful_default_off() {
         let args = parse_download(["downloader"]);
@@ -755,6 +1671,42 @@ mod tests {
         assert_eq!(args.project, Some("Lab".to_string()));
     }
 
+    // ==================== Resolve Subcommand Tests ====================
+
+    #[test]
+    fn test_cli_resolve_parses_download_flags_and_export() {
+        let cli = Cli::try_parse_from([
+            "downloader",
+            "resolve",
+            "--resolve-concurrency",
+            "8",
+            "-o",
+            "papers",
+            "--export",
+            "queue.json",
+            "10.1234/example",
+        ])
+        .expect("resolve should parse");
+        let Some(Command::Resolve(resolve_args)) = &cli.command else {
+            panic!("expected resolve command, got {:?}", cli.command);
+        };
+        assert_eq!(resolve_args.export, Some(PathBuf::from("queue.json")));
+        let args = cli.download_args();
+        assert_eq!(args.resolve_concurrency, 8);
+        assert_eq!(args.output_dir, Some(PathBuf::from("papers")));
+        assert_eq!(args.urls, vec!["10.1234/example".to_string()]);
+        assert!(cli.download.urls.is_empty());
+    }
+
+    #[test]
+    fn test_cli_download_args_defaults_to_top_level_flags() {
+        let cli = Cli::try_parse_from(["downloader", "-o", "papers"]).unwrap();
+        assert_eq!(
+            cli.download_args().output_dir,
+            Some(PathBuf::from("papers"))
+        );
+    }
+
     // ==================== Auth Subcommand Tests ====================
 
     #[test]
@@ -765,11 +1717,55 @@ mod tests {
         assert!(matches!(
             cli.command,
             Some(Command::Auth {
-                command: AuthCommand::Capture(AuthCaptureArgs { save_cookies: true })
+                command: AuthCommand::Capture(AuthCaptureArgs {
+                    save_cookies: true,
+                    from: None,
+                    profile: None,
+                    ..
+                })
             })
         ));
     }
 
+    #[test]
+    fn test_cli_auth_capture_parses_browser_source_and_domains() {
+        let cli = Cli::try_parse_from([
+            "downloader",
+            "auth",
+            "capture",
+            "--from",
+            "firefox",
+            "--profile",
+            "/home/me/.mozilla/firefox/abc.default",
+            "--domains",
+            "jstor.org,sciencedirect.com",
+        ])
+        .expect("auth capture --from should parse");
+        let Some(Command::Auth {
+            command: AuthCommand::Capture(args),
+        }) = cli.command
+        else {
+            panic!("expected auth capture");
+        };
+        assert_eq!(args.from, Some(CookieSourceArg::Firefox));
+        assert_eq!(
+            args.profile.as_deref(),
+            Some(std::path::Path::new(
+                "/home/me/.mozilla/firefox/abc.default"
+            ))
+        );
+        assert_eq!(args.domains, vec!["jstor.org", "sciencedirect.com"]);
+    }
+
+    #[test]
+    fn test_cli_auth_capture_from_requires_profile() {
+        let result = Cli::try_parse_from(["downloader", "auth", "capture", "--from", "chrome"]);
+        assert_eq!(
+            result.unwrap_err().kind(),
+            clap::error::ErrorKind::MissingRequiredArgument
+        );
+    }
+
     #[test]
     fn test_cli_auth_clear_parses() {
         let cli = Cli::try_parse_from(["downloader", "auth", "clear"]).expect("auth clear parses");
@@ -781,6 +1777,71 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_cli_auth_status_parses_probe_flag() {
+        let cli = Cli::try_parse_from(["downloader", "auth", "status", "--probe"])
+            .expect("auth status should parse");
+        assert_eq!(
+            cli.command,
+            Some(Command::Auth {
+                command: AuthCommand::Status(AuthStatusArgs { probe: true })
+            })
+        );
+    }
+
+    #[test]
+    fn test_cli_auth_token_parses_domain_and_bearer() {
+        let cli = Cli::try_parse_from([
+            "downloader",
+            "auth",
+            "token",
+            "--domain",
+            "api.example.org",
+            "--bearer",
+            "abc",
+        ])
+        .expect("auth token should parse");
+        assert_eq!(
+            cli.command,
+            Some(Command::Auth {
+                command: AuthCommand::Token(AuthTokenArgs {
+                    domain: "api.example.org".to_string(),
+                    bearer: Some("abc".to_string()),
+                    remove: false,
+                })
+            })
+        );
+    }
+
+    #[test]
+    fn test_cli_auth_token_requires_bearer_or_remove() {
+        let missing =
+            Cli::try_parse_from(["downloader", "auth", "token", "--domain", "api.example.org"]);
+        assert!(missing.is_err());
+
+        let both = Cli::try_parse_from([
+            "downloader",
+            "auth",
+            "token",
+            "--domain",
+            "api.example.org",
+            "--bearer",
+            "abc",
+            "--remove",
+        ]);
+        assert!(both.is_err());
+
+        let remove = Cli::try_parse_from([
+            "downloader",
+            "auth",
+            "token",
+            "--domain",
+            "api.example.org",
+            "--remove",
+        ]);
+        assert!(remove.is_ok());
+    }
+
     #[test]
     fn test_cli_auth_requires_subcommand() {
         let result = Cli::try_parse_from(["downloader", "auth"]);
@@ -838,6 +1899,28 @@ mod tests {
         assert!(args.project.is_none());
         assert!(args.domain.is_none());
         assert!(args.since.is_none());
+        assert!(args.error_type.is_none());
+        assert!(!args.follow);
+    }
+
+    #[test]
+    fn test_cli_log_command_parses_follow_with_failed_filter() {
+        let args = parse_log(["downloader", "log", "-f", "--failed"]);
+        assert!(args.follow);
+        assert!(args.failed);
+
+        let args = parse_log(["downloader", "log", "--follow", "--project", "X"]);
+        assert!(args.follow);
+        assert_eq!(args.project.as_deref(), Some("X"));
+    }
+
+    #[test]
+    fn test_cli_log_command_parses_error_type_filter() {
+        let args = parse_log(["downloader", "log", "--error-type", "connection_reset"]);
+        assert_eq!(args.error_type, Some(DownloadErrorType::ConnectionReset));
+
+        let result = Cli::try_parse_from(["downloader", "log", "--error-type", "bogus"]);
+        assert!(result.is_err());
     }
 
     #[test]
@@ -946,6 +2029,28 @@ mod tests {
         assert!(args.open);
     }
 
+    #[test]
+    fn test_cli_all_projects_flag_parses_and_conflicts_with_project() {
+        assert!(parse_search(["downloader", "search", "q", "--all-projects"]).all_projects);
+        assert!(parse_log(["downloader", "log", "--all-projects"]).all_projects);
+        for args in [
+            [
+                "downloader",
+                "search",
+                "q",
+                "--all-projects",
+                "--project",
+                "A",
+            ]
+            .as_slice(),
+            ["downloader", "log", "--all-projects", "--project", "A"].as_slice(),
+            ["downloader", "log", "--all-projects", "--follow"].as_slice(),
+        ] {
+            let err = Cli::try_parse_from(args.iter().copied()).unwrap_err();
+            assert_eq!(err.kind(), clap::error::ErrorKind::ArgumentConflict);
+        }
+    }
+
     #[test]
     fn test_cli_search_command_requires_query() {
         let result = Cli::try_parse_from(["downloader", "search"]);
@@ -962,6 +2067,48 @@ mod tests {
         assert_eq!(err.kind(), clap::error::ErrorKind::ValueValidation);
     }
 
+    // ==================== Stats Command Tests ====================
+
+    #[test]
+    fn test_cli_stats_command_parses_filters() {
+        let cli = Cli::try_parse_from([
+            "downloader",
+            "stats",
+            "-o",
+            "/tmp/out",
+            "--project",
+            "Climate Research",
+            "--since",
+            "2026-02-01 00:00:00",
+        ])
+        .unwrap();
+        let Some(Command::Stats(args)) = cli.command else {
+            panic!("expected stats command");
+        };
+        assert_eq!(args.output_dir, Some(PathBuf::from("/tmp/out")));
+        assert_eq!(args.project.as_deref(), Some("Climate Research"));
+        assert_eq!(args.since.as_deref(), Some("2026-02-01 00:00:00"));
+    }
+
+    // ==================== Completions Command Tests ====================
+
+    #[test]
+    fn test_cli_completions_command_parses_shell() {
+        let cli = Cli::try_parse_from(["downloader", "completions", "powershell"]).unwrap();
+        assert_eq!(
+            cli.command,
+            Some(Command::Completions(CompletionsArgs {
+                shell: CompletionShell::Powershell,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_cli_completions_command_rejects_unknown_shell() {
+        let err = Cli::try_parse_from(["downloader", "completions", "tcsh"]).unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::InvalidValue);
+    }
+
     // ==================== Config Command Tests ====================
 
     #[test]
@@ -977,6 +2124,39 @@ mod tests {
         assert!(cli.download.urls.is_empty());
     }
 
+    #[test]
+    fn test_cli_config_validate_parses_optional_file() {
+        let cli = Cli::try_parse_from(["downloader", "config", "validate", "--file", "x.toml"])
+            .expect("config validate should parse");
+        assert!(matches!(
+            cli.command,
+            Some(Command::Config {
+                command: ConfigCommand::Validate(ConfigValidateArgs { file: Some(ref file) })
+            }) if file == std::path::Path::new("x.toml")
+        ));
+    }
+
+    #[test]
+    fn test_cli_config_init_and_set_parse() {
+        let cli = Cli::try_parse_from(["downloader", "config", "init", "--force"])
+            .expect("config init should parse");
+        assert!(matches!(
+            cli.command,
+            Some(Command::Config {
+                command: ConfigCommand::Init(ConfigInitArgs { force: true })
+            })
+        ));
+
+        let cli = Cli::try_parse_from(["downloader", "config", "set", "layout", "by-year"])
+            .expect("config set should parse");
+        assert!(matches!(
+            cli.command,
+            Some(Command::Config {
+                command: ConfigCommand::Set(ConfigSetArgs { ref key, ref value })
+            }) if key == "layout" && value == "by-year"
+        ));
+    }
+
     #[test]
     fn test_cli_config_requires_subcommand() {
         let result = Cli::try_parse_from(["downloader", "config"]);
@@ -1041,4 +2221,233 @@ mod tests {
         );
         assert_eq!(ExportFormat::from(ExportFormatArg::Ris), ExportFormat::Ris);
     }
+
+    // ==================== Reindex Command Tests ====================
+
+    #[test]
+    fn test_cli_reindex_parses_dir_and_topics_file() {
+        let cli = Cli::try_parse_from([
+            "downloader",
+            "reindex",
+            "./downloads/climate",
+            "--topics-file",
+            "topics.txt",
+        ])
+        .unwrap();
+        let Some(Command::Reindex(args)) = cli.command else {
+            panic!("expected reindex command");
+        };
+        assert_eq!(args.dir, PathBuf::from("./downloads/climate"));
+        assert_eq!(args.topics_file, Some(PathBuf::from("topics.txt")));
+    }
+
+    #[test]
+    fn test_cli_reindex_requires_dir() {
+        let err = Cli::try_parse_from(["downloader", "reindex"]).unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::MissingRequiredArgument);
+    }
+
+    // ==================== Queue Command Tests ====================
+
+    #[test]
+    fn test_cli_queue_export_parses_file_and_scope() {
+        let cli = Cli::try_parse_from([
+            "downloader",
+            "queue",
+            "export",
+            "queue.json",
+            "--output-dir",
+            "/data",
+            "--project",
+            "Climate",
+        ])
+        .unwrap();
+        match cli.command {
+            Some(Command::Queue {
+                command: QueueCommand::Export(args),
+            }) => {
+                assert_eq!(args.file, PathBuf::from("queue.json"));
+                assert_eq!(args.output_dir, Some(PathBuf::from("/data")));
+                assert_eq!(args.project.as_deref(), Some("Climate"));
+            }
+            other => panic!("expected queue export command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_cli_queue_import_accepts_stdin_marker() {
+        let cli = Cli::try_parse_from(["downloader", "queue", "import", "-"]).unwrap();
+        match cli.command {
+            Some(Command::Queue {
+                command: QueueCommand::Import(args),
+            }) => assert_eq!(args.file, PathBuf::from("-")),
+            other => panic!("expected queue import command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_cli_queue_dead_letter_parses_scope() {
+        let cli =
+            Cli::try_parse_from(["downloader", "queue", "dead-letter", "-o", "/data"]).unwrap();
+        match cli.command {
+            Some(Command::Queue {
+                command: QueueCommand::DeadLetter(args),
+            }) => {
+                assert_eq!(args.output_dir, Some(PathBuf::from("/data")));
+                assert!(args.project.is_none());
+            }
+            other => panic!("expected queue dead-letter command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_cli_queue_status_parses_watch_and_scope() {
+        let cli =
+            Cli::try_parse_from(["downloader", "queue", "status", "--watch", "--project", "X"])
+                .unwrap();
+        match cli.command {
+            Some(Command::Queue {
+                command: QueueCommand::Status(args),
+            }) => {
+                assert!(args.watch);
+                assert_eq!(args.scope.project.as_deref(), Some("X"));
+            }
+            other => panic!("expected queue status command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_cli_queue_requeue_collects_repeated_ids() {
+        let cli = Cli::try_parse_from([
+            "downloader",
+            "queue",
+            "requeue",
+            "--id",
+            "3",
+            "--id",
+            "7",
+            "--project",
+            "Climate",
+        ])
+        .unwrap();
+        match cli.command {
+            Some(Command::Queue {
+                command: QueueCommand::Requeue(args),
+            }) => {
+                assert_eq!(args.ids, vec![3, 7]);
+                assert_eq!(args.scope.project.as_deref(), Some("Climate"));
+            }
+            other => panic!("expected queue requeue command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_cli_queue_reprioritize_parses_id_and_priority() {
+        let cli = Cli::try_parse_from([
+            "downloader",
+            "queue",
+            "reprioritize",
+            "12",
+            "-3",
+            "-o",
+            "/data",
+        ])
+        .unwrap();
+        match cli.command {
+            Some(Command::Queue {
+                command: QueueCommand::Reprioritize(args),
+            }) => {
+                assert_eq!(args.id, 12);
+                assert_eq!(args.priority, -3);
+                assert_eq!(args.scope.output_dir, Some(PathBuf::from("/data")));
+            }
+            other => panic!("expected queue reprioritize command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_cli_db_backup_parses_path_scope_and_force() {
+        let cli = Cli::try_parse_from([
+            "downloader",
+            "db",
+            "backup",
+            "snapshot.db",
+            "--project",
+            "Climate",
+            "--force",
+        ])
+        .unwrap();
+        match cli.command {
+            Some(Command::Db {
+                command: DbCommand::Backup(args),
+            }) => {
+                assert_eq!(args.path, PathBuf::from("snapshot.db"));
+                assert_eq!(args.scope.project.as_deref(), Some("Climate"));
+                assert!(args.force);
+            }
+            other => panic!("expected db backup command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_cli_db_compact_parses_scope() {
+        let cli = Cli::try_parse_from(["downloader", "db", "compact", "-o", "/data"]).unwrap();
+        match cli.command {
+            Some(Command::Db {
+                command: DbCommand::Compact(args),
+            }) => assert_eq!(args.output_dir, Some(PathBuf::from("/data"))),
+            other => panic!("expected db compact command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_cli_history_prune_parses_before_status_and_dry_run() {
+        let cli = Cli::try_parse_from([
+            "downloader",
+            "history",
+            "prune",
+            "--before",
+            "2025-01-01",
+            "--status",
+            "failed",
+            "--dry-run",
+        ])
+        .unwrap();
+        match cli.command {
+            Some(Command::History {
+                command: HistoryCommand::Prune(args),
+            }) => {
+                assert_eq!(args.before, "2025-01-01");
+                assert_eq!(args.status, Some(HistoryStatusArg::Failed));
+                assert!(args.dry_run && !args.vacuum);
+            }
+            other => panic!("expected history prune command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_cli_history_prune_requires_before_and_rejects_dry_run_with_vacuum() {
+        assert!(Cli::try_parse_from(["downloader", "history", "prune"]).is_err());
+        let err = Cli::try_parse_from([
+            "downloader",
+            "history",
+            "prune",
+            "--before",
+            "2025-01-01",
+            "--dry-run",
+            "--vacuum",
+        ])
+        .unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn test_cli_queue_requires_file() {
+        let result = Cli::try_parse_from(["downloader", "queue", "export"]);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().kind(),
+            clap::error::ErrorKind::MissingRequiredArgument
+        );
+    }
 }
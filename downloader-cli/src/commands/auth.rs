@@ -1,35 +1,81 @@
-//! Auth command handlers: capture and clear persisted cookies.
+//! Auth command handlers: capture and clear persisted cookies, store bearer tokens,
+//! and report session health.
 
+use std::collections::BTreeMap;
 use std::fs;
 use std::io::{self, IsTerminal, Read};
 use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use anyhow::{Result, anyhow, bail};
+use downloader_core::download::BROWSER_USER_AGENT;
 use downloader_core::{
-    CapturedCookieFormat, clear_persisted_cookies, parse_captured_cookies, persisted_cookie_path,
-    store_persisted_cookies, unique_domain_count,
+    BrowserCookieStore, CapturedCookieFormat, CapturedCookies, CookieLine, DomainHeaders,
+    clear_persisted_cookies, cookie_in_scope, load_cookies_into_jar, load_persisted_cookies,
+    load_persisted_domain_headers, parse_captured_cookies, persisted_cookie_path,
+    read_browser_cookies, store_persisted_cookies, store_persisted_domain_headers,
+    unique_domain_count,
 };
+use reqwest::header::LOCATION;
+use reqwest::redirect::Policy;
 use tracing::{info, warn};
 
-pub fn run_auth_capture_command(save_cookies: bool) -> Result<()> {
-    info!("Browser cookie capture");
-    info!("1. Install a cookie export extension (e.g., 'Get cookies.txt LOCALLY').");
-    info!("2. Log into the site you want to download from.");
-    info!("3. Export cookies to Netscape format (or JSON).");
-    info!("4. Paste the cookie file path or pipe cookie contents.");
+use crate::cli::{AuthCaptureArgs, AuthTokenArgs, CookieSourceArg};
 
-    let raw_input = read_cookie_capture_input()?;
-    let parsed = parse_captured_cookies(&raw_input)
-        .map_err(|error| anyhow!("Cookie capture failed: {error}"))?;
+/// Runs `downloader auth capture`: validates cookies and optionally persists them.
+///
+/// Cookies come from `--from`/`--profile` when given, otherwise from a pasted
+/// path or piped text. `--domains` narrows them (and any HAR auth headers) to
+/// those domains; saving then replaces only the stored cookies for those domains.
+pub async fn run_auth_capture_command(args: &AuthCaptureArgs) -> Result<()> {
+    let mut parsed = match (args.from, args.profile.as_deref()) {
+        (Some(source), Some(path)) => read_cookie_source(source, path).await?,
+        _ => {
+            info!("Browser cookie capture");
+            info!("1. Install a cookie export extension (e.g., 'Get cookies.txt LOCALLY').");
+            info!("2. Log into the site you want to download from.");
+            info!("3. Export cookies to Netscape format (or JSON, or save a HAR from dev tools).");
+            info!("4. Paste the cookie file path or pipe cookie contents.");
+
+            let raw_input = read_cookie_capture_input()?;
+            parse_captured_cookies(&raw_input)
+                .map_err(|error| anyhow!("Cookie capture failed: {error}"))?
+        }
+    };
 
     for warning in &parsed.warnings {
         warn!("{warning}");
     }
 
-    let format_label = match parsed.format {
-        CapturedCookieFormat::Netscape => "netscape",
-        CapturedCookieFormat::Json => "json",
-    };
+    let mut replay_headers = DomainHeaders::from_replay_headers(&parsed.replay_headers);
+    if !args.domains.is_empty() {
+        let before = parsed.cookies.len();
+        parsed
+            .cookies
+            .retain(|cookie| cookie_in_scope(&cookie.domain, &args.domains));
+        let out_of_scope: Vec<String> = replay_headers
+            .domains()
+            .filter(|host| !cookie_in_scope(host, &args.domains))
+            .map(str::to_string)
+            .collect();
+        for host in &out_of_scope {
+            replay_headers.remove_domain(host);
+        }
+        info!(
+            kept = parsed.cookies.len(),
+            skipped = before - parsed.cookies.len(),
+            domains = %args.domains.join(", "),
+            "Filtered captured cookies by domain"
+        );
+        if parsed.cookies.is_empty() {
+            bail!(
+                "What: No captured cookies belong to {}\nWhy: The cookie source has no cookies for those domains (or they expired)\nFix: Sign in to the site in that browser profile, or check the --domains spelling",
+                args.domains.join(", ")
+            );
+        }
+    }
+
+    let format_label = capture_format_label(parsed.format);
     let domains = unique_domain_count(&parsed.cookies);
 
     info!(
@@ -39,10 +85,34 @@ pub fn run_auth_capture_command(save_cookies: bool) -> Result<()> {
         "Cookie capture validation complete"
     );
 
-    if save_cookies {
-        let persisted_path = store_persisted_cookies(&parsed.cookies)
+    if !replay_headers.is_empty() {
+        let hosts: Vec<&str> = replay_headers.domains().collect();
+        info!(
+            hosts = %hosts.join(", "),
+            "HAR capture includes Authorization/X-* headers (values not shown)"
+        );
+    }
+
+    if args.save_cookies {
+        let cookies = if args.domains.is_empty() {
+            parsed.cookies
+        } else {
+            let stored = load_persisted_cookies()
+                .map_err(|error| anyhow!("Failed to load persisted cookies: {error}"))?
+                .unwrap_or_default();
+            merge_scoped_cookies(stored, parsed.cookies, &args.domains)
+        };
+        let persisted_path = store_persisted_cookies(&cookies)
             .map_err(|error| anyhow!("Failed to persist cookies securely: {error}"))?;
         info!(path = %persisted_path.display(), "Saved encrypted cookies");
+
+        if !replay_headers.is_empty() {
+            let mut headers = load_stored_domain_headers()?;
+            headers.extend(replay_headers);
+            let path = store_persisted_domain_headers(&headers)
+                .map_err(|error| anyhow!("Failed to persist auth headers securely: {error}"))?;
+            info!(path = %path.display(), "Saved encrypted auth headers from HAR capture");
+        }
     }
 
     info!("Cookies captured for {domains} domains");
@@ -50,6 +120,69 @@ pub fn run_auth_capture_command(save_cookies: bool) -> Result<()> {
     Ok(())
 }
 
+/// Reads cookies from the file or browser profile named by `--from`/`--profile`.
+async fn read_cookie_source(source: CookieSourceArg, path: &Path) -> Result<CapturedCookies> {
+    let (expected, browser) = match source {
+        CookieSourceArg::Netscape => (CapturedCookieFormat::Netscape, None),
+        CookieSourceArg::Json => (CapturedCookieFormat::Json, None),
+        CookieSourceArg::Har => (CapturedCookieFormat::Har, None),
+        CookieSourceArg::Firefox => (
+            CapturedCookieFormat::Firefox,
+            Some(BrowserCookieStore::Firefox),
+        ),
+        CookieSourceArg::Chrome => (
+            CapturedCookieFormat::Chrome,
+            Some(BrowserCookieStore::Chrome),
+        ),
+    };
+
+    if let Some(store) = browser {
+        info!(profile = %path.display(), "Reading browser cookie database");
+        return read_browser_cookies(store, path)
+            .await
+            .map_err(|error| anyhow!("Cookie capture failed: {error}"));
+    }
+
+    let contents = fs::read_to_string(path)
+        .map_err(|error| anyhow!("Cannot read cookie file '{}': {}", path.display(), error))?;
+    let parsed = parse_captured_cookies(&contents)
+        .map_err(|error| anyhow!("Cookie capture failed: {error}"))?;
+    if parsed.format != expected {
+        let detected = capture_format_label(parsed.format);
+        bail!(
+            "What: {} is not a {} cookie file\nWhy: Its contents look like {detected}\nFix: Pass `--from {detected}` instead, or point --profile at the right file",
+            path.display(),
+            capture_format_label(expected),
+        );
+    }
+    Ok(parsed)
+}
+
+/// Name of a capture format as spelled by `--from`.
+fn capture_format_label(format: CapturedCookieFormat) -> &'static str {
+    match format {
+        CapturedCookieFormat::Netscape => "netscape",
+        CapturedCookieFormat::Json => "json",
+        CapturedCookieFormat::Har => "har",
+        CapturedCookieFormat::Firefox => "firefox",
+        CapturedCookieFormat::Chrome => "chrome",
+    }
+}
+
+/// Replaces the stored cookies for `domains` with `captured`, keeping the rest.
+fn merge_scoped_cookies(
+    stored: Vec<CookieLine>,
+    captured: Vec<CookieLine>,
+    domains: &[String],
+) -> Vec<CookieLine> {
+    let mut merged: Vec<CookieLine> = stored
+        .into_iter()
+        .filter(|cookie| !cookie_in_scope(&cookie.domain, domains))
+        .collect();
+    merged.extend(captured);
+    merged
+}
+
 pub fn run_auth_clear_command() -> Result<()> {
     let removed = clear_persisted_cookies()
         .map_err(|error| anyhow!("Failed to clear persisted cookies: {error}"))?;
@@ -57,14 +190,265 @@ pub fn run_auth_clear_command() -> Result<()> {
     if removed {
         let path = persisted_cookie_path()
             .map_err(|error| anyhow!("Failed to resolve cookie storage path: {error}"))?;
-        info!(path = %path.display(), "Cleared persisted auth cookies");
+        info!(path = %path.display(), "Cleared persisted auth cookies and tokens");
     } else {
+        info!("No persisted auth cookies or tokens found");
+    }
+
+    Ok(())
+}
+
+pub fn run_auth_token_command(args: &AuthTokenArgs) -> Result<()> {
+    let mut headers = load_stored_domain_headers()?;
+
+    if args.remove {
+        if !headers.remove_domain(&args.domain) {
+            info!(domain = %args.domain, "No stored auth token for domain");
+            return Ok(());
+        }
+    } else {
+        let token = match args.bearer.as_deref() {
+            Some("-") => {
+                let mut buffer = String::new();
+                io::stdin().read_to_string(&mut buffer)?;
+                buffer
+            }
+            Some(token) => token.to_string(),
+            None => bail!("--bearer is required unless --remove is given"),
+        };
+        headers
+            .insert_bearer(&args.domain, &token)
+            .map_err(|error| anyhow!("Cannot store auth token: {error}"))?;
+    }
+
+    let path = store_persisted_domain_headers(&headers)
+        .map_err(|error| anyhow!("Failed to persist auth token securely: {error}"))?;
+    if args.remove {
+        info!(domain = %args.domain, path = %path.display(), "Removed stored auth token");
+    } else {
+        info!(domain = %args.domain, path = %path.display(), "Saved encrypted auth token");
+    }
+
+    Ok(())
+}
+
+/// Cookies expiring within this window are flagged as expiring soon.
+const EXPIRY_WARNING_WINDOW_SECS: u64 = 24 * 60 * 60;
+
+/// Per-request timeout for `auth status --probe`.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Location fragments that mark a redirect to a sign-in page.
+const LOGIN_LOCATION_MARKERS: &[&str] = &[
+    "login",
+    "signin",
+    "sign-in",
+    "sso",
+    "shibboleth",
+    "/idp/",
+    "/auth",
+];
+
+/// Reports persisted cookie sessions per domain, optionally probing each one.
+///
+/// Only domains, counts, and expiry times are printed; cookie and token
+/// values never leave the encrypted store.
+pub async fn run_auth_status_command(probe: bool) -> Result<()> {
+    let tokens = load_stored_domain_headers()?;
+    if !tokens.is_empty() {
+        let domains: Vec<&str> = tokens.domains().collect();
+        info!(domains = %domains.join(", "), "Stored auth tokens (values not shown)");
+    }
+
+    let cookies = load_persisted_cookies()
+        .map_err(|error| anyhow!("Failed to load persisted cookies: {error}"))?
+        .unwrap_or_default();
+    if cookies.is_empty() {
         info!("No persisted auth cookies found");
+        info!("Run `downloader auth capture --save-cookies` to store a browser session.");
+        return Ok(());
+    }
+
+    let now = unix_now();
+    let statuses = summarize_cookie_domains(&cookies, now);
+    info!(
+        cookies = cookies.len(),
+        domains = unique_domain_count(&cookies),
+        "Persisted auth cookies"
+    );
+
+    let client = if probe {
+        Some(build_probe_client(&cookies)?)
+    } else {
+        None
+    };
+
+    for status in &statuses {
+        info!(
+            domain = %status.domain,
+            cookies = status.cookies,
+            expired = status.expired,
+            soonest_expiry = %describe_soonest_expiry(status.soonest_expiry, now),
+            "Cookie session"
+        );
+        if status.all_expired() {
+            warn!(
+                "{}",
+                dead_session_message(&status.domain, "every stored cookie for it has expired")
+            );
+            continue;
+        }
+        let Some(client) = &client else {
+            continue;
+        };
+        match probe_domain(client, &status.domain).await {
+            ProbeOutcome::Alive(http_status) => {
+                info!(domain = %status.domain, http_status, "Session probe succeeded");
+            }
+            ProbeOutcome::Dead(reason) => {
+                warn!("{}", dead_session_message(&status.domain, &reason));
+            }
+            ProbeOutcome::Inconclusive(reason) => {
+                info!(domain = %status.domain, reason = %reason, "Session probe inconclusive");
+            }
+        }
     }
 
     Ok(())
 }
 
+/// Cookie health for one domain of the persisted store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DomainCookieStatus {
+    domain: String,
+    cookies: usize,
+    expired: usize,
+    /// Earliest expiry (Unix seconds) among unexpired persistent cookies.
+    soonest_expiry: Option<u64>,
+}
+
+impl DomainCookieStatus {
+    fn all_expired(&self) -> bool {
+        self.expired == self.cookies
+    }
+}
+
+/// Groups cookies by domain (leading `.` ignored, as in [`unique_domain_count`]).
+fn summarize_cookie_domains(cookies: &[CookieLine], now: u64) -> Vec<DomainCookieStatus> {
+    let mut by_domain: BTreeMap<String, DomainCookieStatus> = BTreeMap::new();
+    for cookie in cookies {
+        let domain = cookie.domain.trim_start_matches('.').to_ascii_lowercase();
+        let status = by_domain
+            .entry(domain.clone())
+            .or_insert_with(|| DomainCookieStatus {
+                domain,
+                cookies: 0,
+                expired: 0,
+                soonest_expiry: None,
+            });
+        status.cookies += 1;
+        match cookie.expires {
+            0 => {}
+            expires if expires <= now => status.expired += 1,
+            expires => {
+                status.soonest_expiry = Some(
+                    status
+                        .soonest_expiry
+                        .map_or(expires, |soonest| soonest.min(expires)),
+                );
+            }
+        }
+    }
+    by_domain.into_values().collect()
+}
+
+fn describe_soonest_expiry(soonest_expiry: Option<u64>, now: u64) -> String {
+    let Some(expires) = soonest_expiry else {
+        return "session cookies only".to_string();
+    };
+    let remaining = expires.saturating_sub(now);
+    let (value, unit) = if remaining >= 86_400 {
+        (remaining / 86_400, "d")
+    } else if remaining >= 3_600 {
+        (remaining / 3_600, "h")
+    } else {
+        (remaining / 60, "m")
+    };
+    if remaining < EXPIRY_WARNING_WINDOW_SECS {
+        format!("in {value}{unit} (expiring soon)")
+    } else {
+        format!("in {value}{unit}")
+    }
+}
+
+fn dead_session_message(domain: &str, reason: &str) -> String {
+    format!(
+        "What: The {domain} session looks dead\nWhy: {reason}\nFix: Sign in to {domain} in your browser, then run `downloader auth capture --save-cookies` again."
+    )
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ProbeOutcome {
+    Alive(u16),
+    Dead(String),
+    Inconclusive(String),
+}
+
+fn build_probe_client(cookies: &[CookieLine]) -> Result<reqwest::Client> {
+    reqwest::Client::builder()
+        .cookie_provider(load_cookies_into_jar(cookies, None))
+        .redirect(Policy::none())
+        .timeout(PROBE_TIMEOUT)
+        .user_agent(BROWSER_USER_AGENT)
+        .build()
+        .map_err(|error| anyhow!("Failed to build session probe client: {error}"))
+}
+
+/// Sends one `HEAD` to the domain root with the stored cookies attached.
+async fn probe_domain(client: &reqwest::Client, domain: &str) -> ProbeOutcome {
+    let response = match client.head(format!("https://{domain}/")).send().await {
+        Ok(response) => response,
+        Err(error) => return ProbeOutcome::Inconclusive(format!("request failed: {error}")),
+    };
+    let location = response
+        .headers()
+        .get(LOCATION)
+        .and_then(|value| value.to_str().ok());
+    classify_probe_response(response.status().as_u16(), location)
+}
+
+fn classify_probe_response(status: u16, location: Option<&str>) -> ProbeOutcome {
+    match status {
+        401 | 403 => ProbeOutcome::Dead(format!(
+            "the site answered HTTP {status} with the stored cookies attached"
+        )),
+        300..=399 if location.is_some_and(is_login_location) => {
+            ProbeOutcome::Dead("the site redirected to a sign-in page".to_string())
+        }
+        200..=399 => ProbeOutcome::Alive(status),
+        _ => ProbeOutcome::Inconclusive(format!("HTTP {status}")),
+    }
+}
+
+fn is_login_location(location: &str) -> bool {
+    let lower = location.to_ascii_lowercase();
+    LOGIN_LOCATION_MARKERS
+        .iter()
+        .any(|marker| lower.contains(marker))
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+fn load_stored_domain_headers() -> Result<DomainHeaders> {
+    Ok(load_persisted_domain_headers()
+        .map_err(|error| anyhow!("Failed to load stored auth tokens: {error}"))?
+        .unwrap_or_default())
+}
+
 fn read_cookie_capture_input() -> Result<String> {
     if !io::stdin().is_terminal() {
         let mut buffer = String::new();
@@ -100,3 +484,129 @@ fn read_cookie_capture_input() -> Result<String> {
         Ok(input.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: u64 = 1_800_000_000;
+
+    fn cookie(domain: &str, expires: u64) -> CookieLine {
+        CookieLine::new(
+            domain.to_string(),
+            true,
+            "/".to_string(),
+            true,
+            expires,
+            "sid".to_string(),
+            "secret".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_merge_scoped_cookies_replaces_only_requested_domains() {
+        let stored = vec![
+            cookie(".jstor.org", 0),
+            cookie("www.jstor.org", 0),
+            cookie(".wiley.com", 0),
+        ];
+        let captured = vec![cookie(".jstor.org", NOW)];
+        let domains = vec!["jstor.org".to_string()];
+
+        let merged = merge_scoped_cookies(stored, captured, &domains);
+
+        let summary: Vec<(&str, u64)> = merged
+            .iter()
+            .map(|cookie| (cookie.domain.as_str(), cookie.expires))
+            .collect();
+        assert_eq!(summary, vec![(".wiley.com", 0), (".jstor.org", NOW)]);
+    }
+
+    #[test]
+    fn test_capture_format_label_matches_from_values() {
+        use clap::ValueEnum;
+
+        for format in [
+            CapturedCookieFormat::Netscape,
+            CapturedCookieFormat::Json,
+            CapturedCookieFormat::Har,
+            CapturedCookieFormat::Firefox,
+            CapturedCookieFormat::Chrome,
+        ] {
+            let label = capture_format_label(format);
+            assert!(
+                CookieSourceArg::from_str(label, false).is_ok(),
+                "`--from {label}` should parse"
+            );
+        }
+    }
+
+    #[test]
+    fn test_summarize_cookie_domains_groups_and_tracks_soonest_expiry() {
+        let cookies = [
+            cookie(".example.org", NOW + 7_200),
+            cookie("example.org", NOW + 600),
+            cookie("example.org", 0),
+            cookie(".stale.net", NOW - 10),
+        ];
+
+        let statuses = summarize_cookie_domains(&cookies, NOW);
+
+        assert_eq!(statuses.len(), unique_domain_count(&cookies));
+        assert_eq!(
+            statuses[0],
+            DomainCookieStatus {
+                domain: "example.org".to_string(),
+                cookies: 3,
+                expired: 0,
+                soonest_expiry: Some(NOW + 600),
+            }
+        );
+        assert_eq!(statuses[1].domain, "stale.net");
+        assert!(statuses[1].all_expired());
+        assert!(!statuses[0].all_expired());
+    }
+
+    #[test]
+    fn test_describe_soonest_expiry_flags_cookies_expiring_soon() {
+        assert_eq!(describe_soonest_expiry(None, NOW), "session cookies only");
+        assert_eq!(
+            describe_soonest_expiry(Some(NOW + 2 * 3_600), NOW),
+            "in 2h (expiring soon)"
+        );
+        assert_eq!(
+            describe_soonest_expiry(Some(NOW + 3 * 86_400), NOW),
+            "in 3d"
+        );
+    }
+
+    #[test]
+    fn test_classify_probe_response_detects_dead_sessions() {
+        assert_eq!(classify_probe_response(200, None), ProbeOutcome::Alive(200));
+        assert_eq!(
+            classify_probe_response(302, Some("https://example.org/dashboard")),
+            ProbeOutcome::Alive(302)
+        );
+        assert!(matches!(
+            classify_probe_response(302, Some("https://idp.example.org/SignIn?next=/")),
+            ProbeOutcome::Dead(_)
+        ));
+        assert!(matches!(
+            classify_probe_response(403, None),
+            ProbeOutcome::Dead(_)
+        ));
+        assert!(matches!(
+            classify_probe_response(500, None),
+            ProbeOutcome::Inconclusive(_)
+        ));
+    }
+
+    #[test]
+    fn test_dead_session_message_uses_what_why_fix_without_secrets() {
+        let message = dead_session_message("example.org", "the site answered HTTP 403");
+        assert!(message.starts_with("What: "));
+        assert!(message.contains("\nWhy: the site answered HTTP 403"));
+        assert!(message.contains("\nFix: "));
+        assert!(!message.contains("secret"));
+    }
+}
@@ -0,0 +1,91 @@
+//! Byte budget command handlers: reporting usage against `--byte-budget`.
+
+use anyhow::Result;
+use downloader_core::{BYTE_BUDGET_WINDOW_DAYS, Database, Queue};
+
+use super::queue::{queue_db_path, resolve_queue_output_dir};
+use crate::app_config::load_default_file_config;
+use crate::cli::BudgetStatusArgs;
+use crate::output::format_bytes;
+
+/// Runs `downloader budget status`: prints bytes downloaded in the rolling
+/// window and how much of the budget is left.
+///
+/// The budget comes from `--byte-budget`, falling back to `byte_budget` in the
+/// config file; without either only the usage is shown.
+pub async fn run_budget_status_command(args: &BudgetStatusArgs) -> Result<()> {
+    let output_dir = resolve_queue_output_dir(
+        args.scope.output_dir.as_deref(),
+        args.scope.project.as_deref(),
+    )?;
+    let budget = match args.byte_budget {
+        Some(budget) => Some(budget),
+        None => load_default_file_config()?
+            .config
+            .and_then(|config| config.byte_budget),
+    };
+
+    let db_path = queue_db_path(&output_dir);
+    let used = if db_path.exists() {
+        let db = Database::new(&db_path).await?;
+        let used = Queue::new(db.clone()).downloaded_bytes_in_window().await?;
+        db.close().await;
+        used
+    } else {
+        eprintln!("No download queue found under {}.", output_dir.display());
+        0
+    };
+
+    println!("{}", render_budget_status(used, budget));
+    Ok(())
+}
+
+fn render_budget_status(used: u64, budget: Option<u64>) -> String {
+    let mut lines = vec![format!(
+        "Downloaded (last {BYTE_BUDGET_WINDOW_DAYS} days): {} ({used} bytes)",
+        format_bytes(used)
+    )];
+    match budget {
+        Some(budget) => {
+            let remaining = budget.saturating_sub(used);
+            lines.push(format!("Budget: {} ({budget} bytes)", format_bytes(budget)));
+            if remaining == 0 {
+                lines.push(
+                    "Remaining: 0 bytes (budget exhausted; pending items resume as older downloads leave the window)"
+                        .to_string(),
+                );
+            } else {
+                lines.push(format!(
+                    "Remaining: {} ({remaining} bytes)",
+                    format_bytes(remaining)
+                ));
+            }
+        }
+        None => lines.push(
+            "Budget: not set (pass --byte-budget or set `byte_budget` in the config file)"
+                .to_string(),
+        ),
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_budget_status_reports_remaining_and_exhaustion() {
+        let status = render_budget_status(1024, Some(4096));
+        assert!(status.contains("(1024 bytes)"), "{status}");
+        assert!(
+            status.ends_with("Remaining: 3.0 KiB (3072 bytes)"),
+            "{status}"
+        );
+
+        let exhausted = render_budget_status(5000, Some(4096));
+        assert!(exhausted.contains("budget exhausted"), "{exhausted}");
+
+        let unset = render_budget_status(0, None);
+        assert!(unset.contains("Budget: not set"), "{unset}");
+    }
+}
@@ -0,0 +1,67 @@
+//! Completions command handler: print a shell completion script for the CLI.
+
+use std::io::{self, Write};
+
+use anyhow::Result;
+use clap::CommandFactory;
+
+use crate::cli::{Cli, CompletionShell, CompletionsArgs};
+
+pub fn run_completions_command(args: &CompletionsArgs) -> Result<()> {
+    let mut stdout = io::stdout().lock();
+    write_completions(args.shell, &mut stdout);
+    stdout.flush()?;
+    Ok(())
+}
+
+fn write_completions(shell: CompletionShell, out: &mut impl Write) {
+    let mut command = Cli::command();
+    let bin_name = command.get_name().to_string();
+    clap_complete::generate(
+        clap_complete::Shell::from(shell),
+        &mut command,
+        bin_name,
+        out,
+    );
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn completions_for(shell: CompletionShell) -> String {
+        let mut buffer = Vec::new();
+        write_completions(shell, &mut buffer);
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[test]
+    fn test_bash_completions_include_subcommands_and_flags() {
+        let script = completions_for(CompletionShell::Bash);
+        for subcommand in ["auth", "search", "log", "config", "completions"] {
+            assert!(
+                script.contains(subcommand),
+                "missing subcommand {subcommand}"
+            );
+        }
+        assert!(script.contains("--output-dir"));
+        assert!(script.contains("--dry-run"));
+    }
+
+    #[test]
+    fn test_every_shell_generates_a_script() {
+        for shell in [
+            CompletionShell::Bash,
+            CompletionShell::Zsh,
+            CompletionShell::Fish,
+            CompletionShell::Powershell,
+        ] {
+            let script = completions_for(shell);
+            assert!(
+                script.contains("downloader"),
+                "{shell:?} script names the binary"
+            );
+        }
+    }
+}
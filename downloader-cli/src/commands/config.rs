@@ -1,11 +1,15 @@
-//! Config command handlers: show effective configuration.
+//! Config command handlers: show effective configuration, validate config files,
+//! and create or edit the global `config.toml`.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{Context, Result, bail};
 
 use crate::CliValueSources;
-use crate::app_config::load_default_file_config;
+use crate::app_config::{
+    DEFAULT_CONFIG_TEMPLATE, load_default_file_config, resolve_default_config_path,
+    set_config_value, validate_config_str,
+};
 use crate::cli::DownloadArgs;
 
 pub fn run_config_show_command(
@@ -37,9 +41,24 @@ pub fn run_config_show_command(
     println!("concurrency = {}", effective.concurrency);
     println!("rate_limit = {}", effective.rate_limit);
     println!("rate_limit_jitter = {}", effective.rate_limit_jitter);
+    println!("rate_adaptive = {}", effective.rate_adaptive);
     println!("max_retries = {}", effective.max_retries);
     println!("respectful = {}", effective.respectful);
     println!("check_robots = {}", effective.check_robots);
+    if !effective.robots_allow.is_empty() {
+        println!("robots_allow = {}", effective.robots_allow.join(","));
+    }
+    if let Some(byte_budget) = effective.byte_budget {
+        println!("byte_budget = {byte_budget}");
+    }
+    if let Some(config) = loaded_config.config.as_ref() {
+        if let Some(mailto) = &config.crossref_mailto {
+            println!("crossref_mailto = {mailto}");
+        }
+        if config.crossref_token.is_some() {
+            println!("crossref_token = [REDACTED]");
+        }
+    }
     println!(
         "verbosity = {}",
         crate::verbosity_label(effective.verbose, effective.quiet, effective.debug)
@@ -47,3 +66,105 @@ pub fn run_config_show_command(
 
     Ok(())
 }
+
+/// Checks a config file (the default `config.toml` unless `file` is given)
+/// and reports every problem, failing when any is found so CI can gate on it.
+pub fn run_config_validate_command(file: Option<&Path>) -> Result<()> {
+    let path = match file {
+        Some(path) => path.to_path_buf(),
+        None => resolve_default_config_path().context(
+            "What: No config path could be resolved\n\
+             Why: Neither XDG_CONFIG_HOME nor HOME is set\n\
+             Fix: Pass the file to check with `downloader config validate --file <FILE>`",
+        )?,
+    };
+    if file.is_none() && !path.exists() {
+        println!(
+            "No config file at {}; defaults are used, nothing to validate.",
+            path.display()
+        );
+        return Ok(());
+    }
+    let raw = std::fs::read_to_string(&path).with_context(|| {
+        format!(
+            "What: Cannot read config file {}\n\
+             Why: The file is missing or unreadable\n\
+             Fix: Check the path and its permissions",
+            path.display()
+        )
+    })?;
+
+    let problems = validate_config_str(&raw);
+    if problems.is_empty() {
+        println!("{}: config is valid", path.display());
+        return Ok(());
+    }
+    for problem in &problems {
+        println!("{}:{}\n{problem}\n", path.display(), problem.line);
+    }
+    bail!(
+        "What: {} has {} config problem(s)\n\
+         Why: Runs using this file would fail to load it\n\
+         Fix: Correct the entries listed above and re-run `downloader config validate`",
+        path.display(),
+        problems.len()
+    );
+}
+
+/// Writes the commented default `config.toml`, refusing to replace an
+/// existing file unless `force` is set.
+pub fn run_config_init_command(force: bool) -> Result<()> {
+    let path = default_config_path("downloader config init")?;
+    if path.exists() && !force {
+        bail!(
+            "What: Config file {} already exists\n\
+             Why: `config init` does not overwrite existing settings\n\
+             Fix: Edit it with `downloader config set <KEY> <VALUE>`, or re-run with --force to replace it",
+            path.display()
+        );
+    }
+    write_config_file(&path, DEFAULT_CONFIG_TEMPLATE)?;
+    println!("Wrote default config to {}", path.display());
+    Ok(())
+}
+
+/// Sets one key in the global `config.toml`, creating the file if needed.
+/// Other lines, including comments, are left as they are.
+pub fn run_config_set_command(key: &str, value: &str) -> Result<()> {
+    let path = default_config_path("downloader config set")?;
+    let raw = if path.exists() {
+        std::fs::read_to_string(&path).with_context(|| {
+            format!(
+                "What: Cannot read config file {}\n\
+                 Why: The file is unreadable\n\
+                 Fix: Check its permissions",
+                path.display()
+            )
+        })?
+    } else {
+        String::new()
+    };
+    let updated = set_config_value(&raw, key, value)?;
+    write_config_file(&path, &updated)?;
+    println!("Set {key} in {}", path.display());
+    Ok(())
+}
+
+fn default_config_path(command: &str) -> Result<PathBuf> {
+    resolve_default_config_path().with_context(|| {
+        format!(
+            "What: No config path could be resolved\n\
+             Why: Neither XDG_CONFIG_HOME nor HOME is set\n\
+             Fix: Set HOME (or XDG_CONFIG_HOME) and re-run `{command}`"
+        )
+    })
+}
+
+fn write_config_file(path: &Path, contents: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create config directory '{}'", parent.display()))?;
+    }
+    std::fs::write(path, contents)
+        .with_context(|| format!("Failed to write config file '{}'", path.display()))
+}
@@ -0,0 +1,202 @@
+//! Database maintenance command handlers: online backup and offline compaction.
+
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use downloader_core::{Database, Queue, QueueStatus};
+use tracing::info;
+
+use super::queue::{queue_db_path, resolve_queue_output_dir};
+use crate::cli::{DbBackupArgs, QueueScopeArgs};
+
+/// Runs `downloader db backup`: snapshots the scope's queue database to `args.path`.
+///
+/// The snapshot is taken with the database online, so it is safe to run while
+/// another downloader process is writing to the same queue.
+pub async fn run_db_backup_command(args: &DbBackupArgs) -> Result<()> {
+    let output_dir = resolve_queue_output_dir(
+        args.scope.output_dir.as_deref(),
+        args.scope.project.as_deref(),
+    )?;
+    let db_path = queue_db_path(&output_dir);
+    if !db_path.exists() {
+        eprintln!("No download queue found under {}.", output_dir.display());
+        return Ok(());
+    }
+    if is_same_file(&db_path, &args.path) {
+        bail!(
+            "What: Refusing to back up the queue database onto itself\n\
+             Why: {path} is the live database\n\
+             Fix: choose a different backup path",
+            path = args.path.display()
+        );
+    }
+
+    if args.path.exists() && !args.force {
+        bail!(
+            "What: Backup target already exists\n\
+             Why: {path} would be overwritten\n\
+             Fix: choose a new path or pass --force to replace it",
+            path = args.path.display()
+        );
+    }
+
+    let db = Database::new(&db_path).await?;
+    // With --force the old backup is only replaced once the new one is complete.
+    let backup = if args.force {
+        db.backup_replacing(&args.path).await
+    } else {
+        db.backup_to(&args.path).await
+    };
+    backup.with_context(|| {
+        format!(
+            "What: Failed to back up the queue database\n\
+             Why: the snapshot could not be written to {path}\n\
+             Fix: check that the parent directory exists and is writable",
+            path = args.path.display()
+        )
+    })?;
+    db.close().await;
+
+    info!(path = %args.path.display(), "Queue database backed up");
+    println!("Backed up {} to {}", db_path.display(), args.path.display());
+    Ok(())
+}
+
+/// Runs `downloader db compact`: vacuums the scope's queue database and reports reclaimed bytes.
+///
+/// Refuses to run while any queue item is in progress, because `VACUUM` needs
+/// the database to itself and would stall or break a running download.
+pub async fn run_db_compact_command(args: &QueueScopeArgs) -> Result<()> {
+    let output_dir = resolve_queue_output_dir(args.output_dir.as_deref(), args.project.as_deref())?;
+    let db_path = queue_db_path(&output_dir);
+    if !db_path.exists() {
+        eprintln!("No download queue found under {}.", output_dir.display());
+        return Ok(());
+    }
+
+    let db = Database::new(&db_path).await?;
+    let in_progress = Queue::new(db.clone())
+        .count_by_status(QueueStatus::InProgress)
+        .await?;
+    if in_progress > 0 {
+        db.close().await;
+        bail!(
+            "What: Refusing to compact the queue database\n\
+             Why: {in_progress} queue item(s) are in progress, so a download may be running\n\
+             Fix: wait for the run to finish, then retry; items left by a crashed run are reset by the next download run once that process has exited"
+        );
+    }
+
+    let size_before = database_footprint(&db_path);
+    db.compact().await.with_context(|| {
+        format!(
+            "What: Failed to compact the queue database\n\
+             Why: {path} is in use by another process\n\
+             Fix: close other downloader instances and retry",
+            path = db_path.display()
+        )
+    })?;
+    db.close().await;
+    let size_after = database_footprint(&db_path);
+
+    let reclaimed = size_before.saturating_sub(size_after);
+    info!(
+        size_before,
+        size_after, reclaimed, "Queue database compacted"
+    );
+    println!(
+        "Compacted {}: {size_before} -> {size_after} bytes ({reclaimed} bytes reclaimed)",
+        db_path.display()
+    );
+    Ok(())
+}
+
+/// Returns the on-disk size of the database file plus its WAL, in bytes.
+fn database_footprint(db_path: &Path) -> u64 {
+    let mut wal_name = db_path.as_os_str().to_os_string();
+    wal_name.push("-wal");
+    [db_path, Path::new(&wal_name)]
+        .iter()
+        .filter_map(|path| std::fs::metadata(path).ok())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+fn is_same_file(lhs: &Path, rhs: &Path) -> bool {
+    match (lhs.canonicalize(), rhs.canonicalize()) {
+        (Ok(lhs), Ok(rhs)) => lhs == rhs,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::QueueScopeArgs;
+
+    fn backup_args(output_dir: &Path, path: &Path, force: bool) -> DbBackupArgs {
+        DbBackupArgs {
+            path: path.to_path_buf(),
+            scope: QueueScopeArgs {
+                output_dir: Some(output_dir.to_path_buf()),
+                project: None,
+            },
+            force,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_db_backup_requires_force_to_replace_existing_target() {
+        let temp = tempfile::TempDir::new().unwrap();
+        Database::new(&queue_db_path(temp.path()))
+            .await
+            .unwrap()
+            .close()
+            .await;
+        let target = temp.path().join("snapshot.db");
+        std::fs::write(&target, b"old").unwrap();
+
+        let refused = run_db_backup_command(&backup_args(temp.path(), &target, false)).await;
+        assert!(refused.is_err());
+        assert_eq!(std::fs::read(&target).unwrap(), b"old");
+
+        run_db_backup_command(&backup_args(temp.path(), &target, true))
+            .await
+            .unwrap();
+        assert_ne!(std::fs::read(&target).unwrap(), b"old");
+    }
+
+    #[tokio::test]
+    async fn test_db_compact_refuses_while_items_are_in_progress() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let db = Database::new(&queue_db_path(temp.path())).await.unwrap();
+        let queue = Queue::new(db.clone());
+        queue
+            .enqueue("https://example.com/a.pdf", "direct_url", None)
+            .await
+            .unwrap();
+        queue.dequeue().await.unwrap().unwrap();
+        db.close().await;
+        let scope = QueueScopeArgs {
+            output_dir: Some(temp.path().to_path_buf()),
+            project: None,
+        };
+
+        let error = run_db_compact_command(&scope).await.unwrap_err();
+
+        assert!(error.to_string().contains("in progress"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn test_db_backup_refuses_live_database_as_target() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let db_path = queue_db_path(temp.path());
+        Database::new(&db_path).await.unwrap().close().await;
+
+        let result = run_db_backup_command(&backup_args(temp.path(), &db_path, true)).await;
+
+        assert!(result.is_err());
+        assert!(db_path.exists());
+    }
+}
@@ -1,13 +1,32 @@
 //! Dry-run command flow for previewing parse+resolution behavior.
 
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::Result;
-use downloader_core::{ResolveContext, build_default_resolver_registry, parse_input};
+use downloader_core::{
+    CrossrefConfig, DownloadedRegistry, InputType, ParsedItem, PreflightResult, PreflightStatus,
+    RegistryLookup, ResolveContext, ResolveError, ResolvedUrl, UrlPreflight,
+    build_default_resolver_registry, enable_crossref_metadata_enrichment, parse_input,
+    project_history_key,
+};
 use reqwest::cookie::Jar;
+use serde::Serialize;
 use tracing::{info, warn};
 
-pub async fn run_dry_run_preview(input_text: &str, cookie_jar: Option<Arc<Jar>>) -> Result<()> {
+/// Runs the text `--dry-run` preview: parses and resolves each input and
+/// prints where it would download from.
+///
+/// With `preflight` (`--check`), each resolved URL is also probed and the
+/// result printed under it, followed by a per-outcome summary.
+pub async fn run_dry_run_preview(
+    input_text: &str,
+    cookie_jar: Option<Arc<Jar>>,
+    crossref: &CrossrefConfig,
+    disabled_resolvers: &[String],
+    preflight: Option<&UrlPreflight>,
+) -> Result<()> {
     let parse_result = parse_input(input_text);
     let counts = parse_result.type_counts();
     info!(
@@ -31,7 +50,8 @@ pub async fn run_dry_run_preview(input_text: &str, cookie_jar: Option<Arc<Jar>>)
 
     crate::log_parse_feedback(&parse_result);
 
-    let resolver_registry = build_default_resolver_registry(cookie_jar, "downloader@example.com");
+    let resolver_registry =
+        build_default_resolver_registry(cookie_jar, crossref, disabled_resolvers);
     let resolve_context = ResolveContext::default();
 
     println!(
@@ -42,6 +62,7 @@ pub async fn run_dry_run_preview(input_text: &str, cookie_jar: Option<Arc<Jar>>)
 
     let mut resolved_count = 0usize;
     let mut unresolved_count = 0usize;
+    let mut check_counts: BTreeMap<&'static str, usize> = BTreeMap::new();
     for item in &parse_result.items {
         let resolver_input = if item.input_type == downloader_core::InputType::BibTex {
             item.raw.as_str()
@@ -59,6 +80,11 @@ pub async fn run_dry_run_preview(input_text: &str, cookie_jar: Option<Arc<Jar>>)
                     "- [resolved][{}] {} -> {}",
                     item.input_type, item.value, resolved.url
                 );
+                if let Some(preflight) = preflight {
+                    let result = preflight.check(&resolved.url).await;
+                    println!("    check: {}", format_preflight(&result));
+                    *check_counts.entry(result.status.as_str()).or_insert(0) += 1;
+                }
             }
             Err(error) => {
                 unresolved_count += 1;
@@ -76,6 +102,9 @@ pub async fn run_dry_run_preview(input_text: &str, cookie_jar: Option<Arc<Jar>>)
         "Dry run summary: {} resolved, {} unresolved.",
         resolved_count, unresolved_count
     );
+    if preflight.is_some() {
+        println!("Check summary: {}", format_check_counts(&check_counts));
+    }
     println!("Dry run - no files downloaded");
     Ok(())
 }
@@ -83,3 +112,321 @@ pub async fn run_dry_run_preview(input_text: &str, cookie_jar: Option<Arc<Jar>>)
 fn preview_single_line(text: &str) -> String {
     text.split_whitespace().collect::<Vec<_>>().join(" ")
 }
+
+/// One-line rendering of a pre-flight, e.g. `ok HTTP 200 via HEAD, application/pdf, 12345 bytes`.
+fn format_preflight(result: &PreflightResult) -> String {
+    let mut line = result.status.as_str().to_string();
+    if let Some(status) = result.http_status {
+        line.push_str(&format!(" HTTP {status}"));
+    }
+    if let Some(method) = result.method {
+        line.push_str(&format!(" via {method}"));
+    }
+    if let Some(content_type) = &result.content_type {
+        line.push_str(&format!(", {content_type}"));
+    }
+    if let Some(length) = result.content_length {
+        line.push_str(&format!(", {length} bytes"));
+    }
+    if result.status != PreflightStatus::Ok
+        && result.http_status.is_none()
+        && let Some(error) = &result.error
+    {
+        line.push_str(&format!(": {}", preview_single_line(error)));
+    }
+    line
+}
+
+/// `"3 ok, 1 auth, 1 not_found"`, or `"nothing checked"`.
+fn format_check_counts(counts: &BTreeMap<&'static str, usize>) -> String {
+    if counts.is_empty() {
+        return "nothing checked".to_string();
+    }
+    counts
+        .iter()
+        .map(|(status, count)| format!("{count} {status}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// `--check` result in `--dry-run --format json` output.
+#[derive(Debug, Serialize)]
+struct DryRunCheck {
+    status: &'static str,
+    http_status: Option<u16>,
+    method: Option<&'static str>,
+    content_type: Option<String>,
+    content_length: Option<u64>,
+    error: Option<String>,
+}
+
+impl From<PreflightResult> for DryRunCheck {
+    fn from(result: PreflightResult) -> Self {
+        Self {
+            status: result.status.as_str(),
+            http_status: result.http_status,
+            method: result.method,
+            content_type: result.content_type,
+            content_length: result.content_length,
+            error: result.error,
+        }
+    }
+}
+
+/// One input's resolution decision in `--dry-run --format json` output.
+#[derive(Debug, Serialize)]
+struct DryRunPlanEntry {
+    input: String,
+    input_type: String,
+    resolved_url: Option<String>,
+    fallback_urls: Vec<String>,
+    resolver_name: Option<String>,
+    metadata: BTreeMap<String, String>,
+    would_skip_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    check: Option<DryRunCheck>,
+}
+
+/// Runs `--dry-run --format json`: resolves text and bibliography inputs and
+/// prints a JSON array describing what a real run would do with each.
+///
+/// Items that would be skipped carry `would_skip_reason`, using the same
+/// codes the download run logs (`duplicate_existing`) plus
+/// `duplicate_in_input` and `unresolved: <error>`. With `preflight`
+/// (`--check`), resolved items also carry a `check` object. Only the JSON
+/// document is written to stdout.
+#[allow(clippy::too_many_arguments)]
+pub async fn run_dry_run_json(
+    input_text: Option<&str>,
+    bibliography_items: &[ParsedItem],
+    cookie_jar: Option<Arc<Jar>>,
+    output_dir: &Path,
+    enrich_metadata: bool,
+    crossref: &CrossrefConfig,
+    disabled_resolvers: &[String],
+    preflight: Option<&UrlPreflight>,
+) -> Result<()> {
+    let mut items = input_text
+        .map(|text| parse_input(text).items)
+        .unwrap_or_default();
+    items.extend(bibliography_items.iter().cloned());
+
+    let mut dedup_registry = load_registry_read_only(output_dir);
+    let mut resolver_registry =
+        build_default_resolver_registry(cookie_jar, crossref, disabled_resolvers);
+    if enrich_metadata {
+        enable_crossref_metadata_enrichment(&mut resolver_registry, crossref);
+    }
+    let resolve_context = ResolveContext::default();
+
+    let mut seen_urls = HashSet::new();
+    let mut entries = Vec::with_capacity(items.len());
+    for item in &items {
+        let resolver_input = if item.input_type == InputType::BibTex {
+            item.raw.as_str()
+        } else {
+            item.value.as_str()
+        };
+        let result = resolver_registry
+            .resolve_to_url(resolver_input, item.input_type, &resolve_context)
+            .await;
+        let mut entry = plan_entry(
+            item,
+            result,
+            dedup_registry.as_mut(),
+            output_dir,
+            &mut seen_urls,
+        );
+        if let Some(preflight) = preflight
+            && let Some(url) = &entry.resolved_url
+        {
+            entry.check = Some(preflight.check(url).await.into());
+        }
+        entries.push(entry);
+    }
+
+    println!("{}", serde_json::to_string_pretty(&entries)?);
+    Ok(())
+}
+
+/// Opens the project's dedup registry only when one already exists, so a dry
+/// run never creates `.downloader/` state. A held lock just disables the check.
+fn load_registry_read_only(output_dir: &Path) -> Option<DownloadedRegistry> {
+    if !DownloadedRegistry::path_for_output_dir(output_dir).exists() {
+        return None;
+    }
+    match DownloadedRegistry::load(output_dir, &project_history_key(output_dir)) {
+        Ok(registry) => Some(registry),
+        Err(error) => {
+            warn!(error = %error, "Dedup registry unavailable; dry run will not flag downloaded duplicates");
+            None
+        }
+    }
+}
+
+fn plan_entry(
+    item: &ParsedItem,
+    result: Result<ResolvedUrl, ResolveError>,
+    dedup_registry: Option<&mut DownloadedRegistry>,
+    output_dir: &Path,
+    seen_urls: &mut HashSet<String>,
+) -> DryRunPlanEntry {
+    let mut entry = DryRunPlanEntry {
+        input: item.value.clone(),
+        input_type: item.input_type.to_string().to_lowercase(),
+        resolved_url: None,
+        fallback_urls: Vec::new(),
+        resolver_name: None,
+        metadata: BTreeMap::new(),
+        would_skip_reason: None,
+        check: None,
+    };
+
+    let resolved = match result {
+        Ok(resolved) => resolved,
+        Err(error) => {
+            entry.would_skip_reason = Some(format!(
+                "unresolved: {}",
+                preview_single_line(&error.to_string())
+            ));
+            return entry;
+        }
+    };
+
+    let doi = resolved
+        .metadata
+        .get("doi")
+        .cloned()
+        .or_else(|| (item.input_type == InputType::Doi).then(|| item.value.clone()));
+    entry.would_skip_reason = if !seen_urls.insert(resolved.url.clone()) {
+        Some("duplicate_in_input".to_string())
+    } else if let Some(registry) = dedup_registry
+        && matches!(
+            registry.lookup(output_dir, &resolved.url, doi.as_deref()),
+            RegistryLookup::Hit { .. }
+        )
+    {
+        Some("duplicate_existing".to_string())
+    } else {
+        None
+    };
+    entry.resolved_url = Some(resolved.url);
+    entry.fallback_urls = resolved.fallback_urls;
+    entry.resolver_name = resolved.resolver;
+    entry.metadata = resolved.metadata.into_iter().collect();
+    entry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url_item(url: &str) -> ParsedItem {
+        ParsedItem::url(url, url)
+    }
+
+    #[test]
+    fn test_plan_entry_reports_resolver_and_metadata() {
+        let mut resolved = ResolvedUrl::new("https://example.com/a.pdf");
+        resolved.resolver = Some("direct".to_string());
+        resolved
+            .metadata
+            .insert("title".to_string(), "A Paper".to_string());
+
+        let entry = plan_entry(
+            &url_item("https://example.com/a.pdf"),
+            Ok(resolved),
+            None,
+            Path::new("."),
+            &mut HashSet::new(),
+        );
+        let json = serde_json::to_value(&entry).unwrap();
+
+        assert_eq!(json["input_type"], "url");
+        assert_eq!(json["resolved_url"], "https://example.com/a.pdf");
+        assert_eq!(json["resolver_name"], "direct");
+        assert_eq!(json["metadata"]["title"], "A Paper");
+        assert!(json["would_skip_reason"].is_null());
+    }
+
+    #[test]
+    fn test_plan_entry_flags_repeated_url_as_duplicate() {
+        let mut seen = HashSet::new();
+        let item = url_item("https://example.com/a.pdf");
+        let first = plan_entry(
+            &item,
+            Ok(ResolvedUrl::new("https://example.com/a.pdf")),
+            None,
+            Path::new("."),
+            &mut seen,
+        );
+        let second = plan_entry(
+            &item,
+            Ok(ResolvedUrl::new("https://example.com/a.pdf")),
+            None,
+            Path::new("."),
+            &mut seen,
+        );
+
+        assert_eq!(first.would_skip_reason, None);
+        assert_eq!(
+            second.would_skip_reason.as_deref(),
+            Some("duplicate_in_input")
+        );
+    }
+
+    #[test]
+    fn test_format_preflight_renders_headers_and_errors() {
+        let ok = PreflightResult {
+            status: PreflightStatus::Ok,
+            http_status: Some(200),
+            content_type: Some("application/pdf".to_string()),
+            content_length: Some(12345),
+            method: Some("HEAD"),
+            error: None,
+        };
+        assert_eq!(
+            format_preflight(&ok),
+            "ok HTTP 200 via HEAD, application/pdf, 12345 bytes"
+        );
+
+        let network = PreflightResult {
+            status: PreflightStatus::Failed,
+            http_status: None,
+            content_type: None,
+            content_length: None,
+            method: Some("HEAD"),
+            error: Some("Network error\n  connection refused".to_string()),
+        };
+        assert_eq!(
+            format_preflight(&network),
+            "failed via HEAD: Network error connection refused"
+        );
+    }
+
+    #[test]
+    fn test_format_check_counts_lists_each_outcome() {
+        let counts = BTreeMap::from([("auth", 1), ("not_found", 2), ("ok", 3)]);
+        assert_eq!(format_check_counts(&counts), "1 auth, 2 not_found, 3 ok");
+        assert_eq!(format_check_counts(&BTreeMap::new()), "nothing checked");
+    }
+
+    #[test]
+    fn test_plan_entry_marks_unresolved_items() {
+        let entry = plan_entry(
+            &url_item("https://example.com/x"),
+            Err(ResolveError::no_resolver("https://example.com/x")),
+            None,
+            Path::new("."),
+            &mut HashSet::new(),
+        );
+
+        assert!(entry.resolved_url.is_none());
+        assert!(
+            entry
+                .would_skip_reason
+                .as_deref()
+                .is_some_and(|reason| reason.starts_with("unresolved: "))
+        );
+    }
+}
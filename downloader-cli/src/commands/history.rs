@@ -0,0 +1,98 @@
+//! History maintenance command handlers: pruning old download history rows.
+
+use anyhow::Result;
+use downloader_core::{Database, Queue};
+use tracing::info;
+
+use super::db::run_db_compact_command;
+use super::queue::{queue_db_path, resolve_queue_output_dir};
+use crate::cli::HistoryPruneArgs;
+use crate::{map_history_status, validate_history_datetime};
+
+/// Runs `downloader history prune`: deletes history rows started before `--before`.
+///
+/// Only the history table is touched; queue items keep their state. With
+/// `--dry-run` the matching rows are counted instead, and with `--vacuum` the
+/// database is compacted afterwards (see [`run_db_compact_command`]).
+pub async fn run_history_prune_command(args: &HistoryPruneArgs) -> Result<()> {
+    validate_history_datetime("--before", &args.before)?;
+    let output_dir = resolve_queue_output_dir(
+        args.scope.output_dir.as_deref(),
+        args.scope.project.as_deref(),
+    )?;
+    let db_path = queue_db_path(&output_dir);
+    if !db_path.exists() {
+        eprintln!("No download history found under {}.", output_dir.display());
+        return Ok(());
+    }
+
+    let status = args.status.map(map_history_status);
+    let rows_label = status.map_or_else(
+        || "history rows".to_string(),
+        |status| format!("{status} history rows"),
+    );
+
+    let db = Database::new(&db_path).await?;
+    let queue = Queue::new(db.clone());
+    if args.dry_run {
+        let matching = queue
+            .count_download_attempts_before(&args.before, status)
+            .await?;
+        db.close().await;
+        println!(
+            "Would delete {matching} {rows_label} started before {} (dry run, nothing deleted).",
+            args.before
+        );
+        return Ok(());
+    }
+
+    let removed = queue.prune_download_attempts(&args.before, status).await?;
+    db.close().await;
+    info!(removed, before = %args.before, "Pruned download history");
+    println!(
+        "Deleted {removed} {rows_label} started before {}.",
+        args.before
+    );
+
+    if args.vacuum {
+        run_db_compact_command(&args.scope).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{HistoryStatusArg, QueueScopeArgs};
+
+    fn prune_args(output_dir: &std::path::Path, before: &str, dry_run: bool) -> HistoryPruneArgs {
+        HistoryPruneArgs {
+            scope: QueueScopeArgs {
+                output_dir: Some(output_dir.to_path_buf()),
+                project: None,
+            },
+            before: before.to_string(),
+            status: Some(HistoryStatusArg::Failed),
+            dry_run,
+            vacuum: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_history_prune_rejects_malformed_before_date() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let error = run_history_prune_command(&prune_args(temp.path(), "last-year", false))
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("Invalid --before value"));
+    }
+
+    #[tokio::test]
+    async fn test_history_prune_without_database_is_a_no_op() {
+        let temp = tempfile::TempDir::new().unwrap();
+        run_history_prune_command(&prune_args(temp.path(), "2025-01-01", true))
+            .await
+            .unwrap();
+        assert!(!queue_db_path(temp.path()).exists());
+    }
+}
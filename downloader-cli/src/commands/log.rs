@@ -1,9 +1,13 @@
 //! Log command handler: query and display download history.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use anyhow::Result;
-use downloader_core::{Database, DownloadAttemptQuery, DownloadAttemptStatus, Queue};
+use downloader_core::{
+    Database, DownloadAttempt, DownloadAttemptQuery, DownloadAttemptStatus, Queue,
+};
+use tracing::warn;
 
 use crate::cli::LogArgs;
 use crate::map_history_status;
@@ -13,6 +17,9 @@ use crate::render_history_cli_row;
 
 const PROJECT_LOG_QUERY_PAGE_SIZE: usize = 10_000;
 
+/// Pause between history polls in `downloader log --follow`.
+const LOG_FOLLOW_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
 pub async fn run_log_command(args: &LogArgs) -> Result<()> {
     let base_output_dir = args
         .output_dir
@@ -29,9 +36,14 @@ pub async fn run_log_command(args: &LogArgs) -> Result<()> {
             Some(project::project_history_key(&output_dir)),
         )
     } else {
-        let db_paths = project::discover_history_db_paths(&base_output_dir)?;
+        let db_paths = project::discover_history_db_paths(&base_output_dir, args.follow_symlinks)?;
+        let scope = if args.all_projects {
+            "all projects"
+        } else {
+            "global"
+        };
         (
-            format!("global under {}", base_output_dir.display()),
+            format!("{scope} under {}", base_output_dir.display()),
             db_paths,
             None,
         )
@@ -42,39 +54,40 @@ pub async fn run_log_command(args: &LogArgs) -> Result<()> {
         return Ok(());
     }
 
-    let mut query = DownloadAttemptQuery {
-        since: args.since.clone(),
-        limit: usize::from(args.limit)
-            .saturating_add(1)
-            .min(PROJECT_LOG_QUERY_PAGE_SIZE),
-        domain: args.domain.clone(),
-        uncertain_only: args.uncertain,
-        ..DownloadAttemptQuery::default()
-    };
-    if let Some(project_key) = project_scope_key {
-        query.project = Some(project_key);
-    }
-    if args.failed {
-        query.status = Some(DownloadAttemptStatus::Failed);
-    } else if let Some(status) = args.status {
-        query.status = Some(map_history_status(status));
+    let query = build_log_query(args, project_scope_key);
+    if args.follow {
+        return follow_log(args, &db_paths, query).await;
     }
 
     let mut attempts = Vec::new();
     let mut maybe_capped_by_hard_limit = false;
     for db_path in &db_paths {
-        let db = Database::new(db_path).await?;
-        let queue = Queue::new(db);
-        let db_attempts = queue.query_download_attempts(&query).await?;
+        let db_attempts = match query_attempts(db_path, &query).await {
+            Ok(db_attempts) => db_attempts,
+            Err(error) if args.all_projects => {
+                warn!(
+                    path = %db_path.display(),
+                    error = %error,
+                    "Skipping history database that could not be read"
+                );
+                continue;
+            }
+            Err(error) => return Err(error),
+        };
         if usize::from(args.limit) == PROJECT_LOG_QUERY_PAGE_SIZE
             && db_attempts.len() == PROJECT_LOG_QUERY_PAGE_SIZE
         {
             maybe_capped_by_hard_limit = true;
         }
-        attempts.extend(db_attempts);
+        let project_label = project::history_project_label(&base_output_dir, db_path);
+        attempts.extend(
+            db_attempts
+                .into_iter()
+                .map(|attempt| (project_label.clone(), attempt)),
+        );
     }
 
-    attempts.sort_by(|left, right| {
+    attempts.sort_by(|(_, left), (_, right)| {
         right
             .started_at
             .cmp(&left.started_at)
@@ -93,8 +106,15 @@ pub async fn run_log_command(args: &LogArgs) -> Result<()> {
     }
 
     let width = output::terminal_width();
-    for attempt in &attempts {
-        println!("{}", render_history_cli_row(attempt, args.failed, width));
+    for (project_label, attempt) in &attempts {
+        if args.all_projects {
+            println!(
+                "{}",
+                render_project_history_row(project_label, attempt, args.failed, width)
+            );
+        } else {
+            println!("{}", render_history_cli_row(attempt, args.failed, width));
+        }
     }
     if truncated {
         println!(
@@ -108,3 +128,103 @@ pub async fn run_log_command(args: &LogArgs) -> Result<()> {
 
     Ok(())
 }
+
+async fn query_attempts(
+    db_path: &Path,
+    query: &DownloadAttemptQuery,
+) -> Result<Vec<DownloadAttempt>> {
+    let queue = Queue::new(Database::new(db_path).await?);
+    Ok(queue.query_download_attempts(query).await?)
+}
+
+/// Renders a history row prefixed with its project label; the row is narrowed
+/// so the prefixed line still fits `width`.
+fn render_project_history_row(
+    project_label: &str,
+    attempt: &DownloadAttempt,
+    failed_only: bool,
+    width: usize,
+) -> String {
+    let prefix = format!("{project_label} | ");
+    let row_width = width.saturating_sub(prefix.chars().count()).max(1);
+    format!(
+        "{prefix}{}",
+        render_history_cli_row(attempt, failed_only, row_width)
+    )
+}
+
+fn build_log_query(args: &LogArgs, project_scope_key: Option<String>) -> DownloadAttemptQuery {
+    let mut query = DownloadAttemptQuery {
+        since: args.since.clone(),
+        limit: usize::from(args.limit)
+            .saturating_add(1)
+            .min(PROJECT_LOG_QUERY_PAGE_SIZE),
+        domain: args.domain.clone(),
+        error_type: args.error_type,
+        uncertain_only: args.uncertain,
+        project: project_scope_key,
+        ..DownloadAttemptQuery::default()
+    };
+    if args.failed {
+        query.status = Some(DownloadAttemptStatus::Failed);
+    } else if let Some(status) = args.status {
+        query.status = Some(map_history_status(status));
+    }
+    query
+}
+
+/// Prints the most recent `--limit` matching rows oldest-first, then polls each
+/// history database for rows past the last seen id until Ctrl+C.
+async fn follow_log(
+    args: &LogArgs,
+    db_paths: &[PathBuf],
+    mut query: DownloadAttemptQuery,
+) -> Result<()> {
+    let width = output::terminal_width();
+    let mut cursors = Vec::with_capacity(db_paths.len());
+    let mut backlog = Vec::new();
+    query.limit = usize::from(args.limit);
+    for db_path in db_paths {
+        let queue = Queue::new(Database::new(db_path).await?);
+        let attempts = queue.query_download_attempts(&query).await?;
+        let last_seen_id = attempts.iter().map(|attempt| attempt.id).max();
+        backlog.extend(attempts);
+        cursors.push((queue, last_seen_id));
+    }
+    sort_oldest_first(&mut backlog);
+    let skip = backlog.len().saturating_sub(usize::from(args.limit));
+    for attempt in &backlog[skip..] {
+        println!("{}", render_history_cli_row(attempt, args.failed, width));
+    }
+    println!("Following new download attempts; press Ctrl+C to stop.");
+
+    query.limit = PROJECT_LOG_QUERY_PAGE_SIZE;
+    loop {
+        tokio::select! {
+            () = tokio::time::sleep(LOG_FOLLOW_POLL_INTERVAL) => {}
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+        }
+
+        let mut fresh = Vec::new();
+        for (queue, last_seen_id) in &mut cursors {
+            query.after_id = *last_seen_id;
+            let attempts = queue.query_download_attempts(&query).await?;
+            if let Some(max_id) = attempts.iter().map(|attempt| attempt.id).max() {
+                *last_seen_id = Some(max_id);
+            }
+            fresh.extend(attempts);
+        }
+        sort_oldest_first(&mut fresh);
+        for attempt in &fresh {
+            println!("{}", render_history_cli_row(attempt, args.failed, width));
+        }
+    }
+}
+
+fn sort_oldest_first(attempts: &mut [DownloadAttempt]) {
+    attempts.sort_by(|left, right| {
+        left.started_at
+            .cmp(&right.started_at)
+            .then_with(|| left.id.cmp(&right.id))
+    });
+}
@@ -1,17 +1,43 @@
 //! CLI command handlers.
 
 mod auth;
+mod budget;
+mod completions;
 mod config;
 mod convert;
+mod db;
 mod dry_run;
 mod export;
+mod history;
 mod log;
+mod queue;
+mod reindex;
 mod search;
+mod stats;
+mod stdout;
 
-pub use auth::{run_auth_capture_command, run_auth_clear_command};
-pub use config::run_config_show_command;
+pub use auth::{
+    run_auth_capture_command, run_auth_clear_command, run_auth_status_command,
+    run_auth_token_command,
+};
+pub use budget::run_budget_status_command;
+pub use completions::run_completions_command;
+pub use config::{
+    run_config_init_command, run_config_set_command, run_config_show_command,
+    run_config_validate_command,
+};
 pub use convert::run_convert_command;
-pub use dry_run::run_dry_run_preview;
+pub use db::{run_db_backup_command, run_db_compact_command};
+pub use dry_run::{run_dry_run_json, run_dry_run_preview};
 pub use export::run_export_command;
+pub use history::run_history_prune_command;
 pub use log::run_log_command;
+pub(crate) use queue::export_queue_json;
+pub use queue::{
+    run_queue_dead_letter_command, run_queue_export_command, run_queue_import_command,
+    run_queue_reprioritize_command, run_queue_requeue_command, run_queue_status_command,
+};
+pub use reindex::run_reindex_command;
 pub use search::run_search_command;
+pub use stats::run_stats_command;
+pub use stdout::run_stdout_download;
@@ -0,0 +1,504 @@
+//! Queue command handlers: status, JSON export/import, and dead-letter maintenance.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use downloader_core::{Database, Queue, QueueStatus};
+use tracing::info;
+
+use crate::cli::{
+    QueueReprioritizeArgs, QueueRequeueArgs, QueueScopeArgs, QueueStatusArgs, QueueTransferArgs,
+};
+use crate::{failure, project};
+
+/// Pause between redraws of `downloader queue status --watch`.
+const QUEUE_STATUS_WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Statuses in the order `downloader queue status` lists them.
+const QUEUE_STATUS_ORDER: [QueueStatus; 4] = [
+    QueueStatus::Pending,
+    QueueStatus::InProgress,
+    QueueStatus::Completed,
+    QueueStatus::Failed,
+];
+
+/// Point-in-time counts shown by `downloader queue status`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct QueueStatusSnapshot {
+    counts: [i64; 4],
+    oldest_pending_age_secs: Option<i64>,
+    dead_lettered: usize,
+    failure_categories: BTreeMap<&'static str, usize>,
+}
+
+/// Runs `downloader queue status`: prints item counts by status for the output scope.
+///
+/// Read-only. With `--watch`, redraws every few seconds until Ctrl+C.
+pub async fn run_queue_status_command(args: &QueueStatusArgs) -> Result<()> {
+    let Some((queue, project_key)) = open_existing_queue(&args.scope).await? else {
+        return Ok(());
+    };
+
+    if !args.watch {
+        let snapshot = queue_status_snapshot(&queue, &project_key).await?;
+        print!("{}", render_queue_status(&snapshot));
+        return Ok(());
+    }
+
+    let redraw = io::stdout().is_terminal();
+    loop {
+        let snapshot = queue_status_snapshot(&queue, &project_key).await?;
+        let mut stdout = io::stdout();
+        if redraw {
+            // Clear the screen and move the cursor home.
+            write!(stdout, "\x1b[2J\x1b[H")?;
+        }
+        write!(stdout, "{}", render_queue_status(&snapshot))?;
+        writeln!(
+            stdout,
+            "Refreshing every {}s; press Ctrl+C to stop.\n",
+            QUEUE_STATUS_WATCH_INTERVAL.as_secs()
+        )?;
+        stdout.flush()?;
+
+        tokio::select! {
+            () = tokio::time::sleep(QUEUE_STATUS_WATCH_INTERVAL) => {}
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+        }
+    }
+}
+
+async fn queue_status_snapshot(queue: &Queue, project_key: &str) -> Result<QueueStatusSnapshot> {
+    let mut counts = [0; 4];
+    for (count, status) in counts.iter_mut().zip(QUEUE_STATUS_ORDER) {
+        *count = queue
+            .count_by_status_in_project(status, Some(project_key))
+            .await?;
+    }
+    let oldest_pending_age_secs = queue
+        .oldest_item_age_secs_in_project(QueueStatus::Pending, Some(project_key))
+        .await?;
+
+    let failed = queue
+        .list_by_status_in_project(QueueStatus::Failed, Some(project_key))
+        .await?;
+    let mut failure_categories = BTreeMap::new();
+    for item in &failed {
+        let category =
+            failure::classify_failure(item.last_error.as_deref().unwrap_or_default()).category;
+        *failure_categories.entry(category.key()).or_insert(0) += 1;
+    }
+
+    Ok(QueueStatusSnapshot {
+        counts,
+        oldest_pending_age_secs,
+        dead_lettered: failed.iter().filter(|item| item.dead_letter).count(),
+        failure_categories,
+    })
+}
+
+fn render_queue_status(snapshot: &QueueStatusSnapshot) -> String {
+    let mut out = String::new();
+    for (status, count) in QUEUE_STATUS_ORDER.iter().zip(snapshot.counts) {
+        let detail = match status {
+            QueueStatus::Pending => snapshot
+                .oldest_pending_age_secs
+                .map(|secs| format!("  (oldest {})", format_age(secs))),
+            QueueStatus::Failed if snapshot.dead_lettered > 0 => {
+                Some(format!("  ({} dead-lettered)", snapshot.dead_lettered))
+            }
+            _ => None,
+        };
+        out.push_str(&format!(
+            "{:<12}{count:>8}{}\n",
+            status.as_str(),
+            detail.unwrap_or_default()
+        ));
+    }
+    if !snapshot.failure_categories.is_empty() {
+        let breakdown = snapshot
+            .failure_categories
+            .iter()
+            .map(|(category, count)| format!("{count} {category}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        out.push_str(&format!("Failures: {breakdown}\n"));
+    }
+    out
+}
+
+/// Coarse human age: `45s`, `12m`, `3h 5m`, `2d 4h`.
+fn format_age(secs: i64) -> String {
+    let (days, hours, minutes) = (secs / 86_400, secs % 86_400 / 3_600, secs % 3_600 / 60);
+    if days > 0 {
+        format!("{days}d {hours}h")
+    } else if hours > 0 {
+        format!("{hours}h {minutes}m")
+    } else if minutes > 0 {
+        format!("{minutes}m")
+    } else {
+        format!("{secs}s")
+    }
+}
+
+/// Runs `downloader queue export`: writes every queue item to `args.file` (or stdout for `-`).
+pub async fn run_queue_export_command(args: &QueueTransferArgs) -> Result<()> {
+    let output_dir = resolve_queue_output_dir(args.output_dir.as_deref(), args.project.as_deref())?;
+    let db_path = queue_db_path(&output_dir);
+    if !db_path.exists() {
+        eprintln!("No download queue found under {}.", output_dir.display());
+        return Ok(());
+    }
+
+    let queue = Queue::new(Database::new(&db_path).await?);
+    export_queue_json(&queue, &args.file).await
+}
+
+/// Writes every item in `queue` as JSON to `file`, or to stdout for `-`.
+pub(crate) async fn export_queue_json(queue: &Queue, file: &Path) -> Result<()> {
+    if is_stdio_marker(file) {
+        let mut stdout = io::stdout();
+        queue.export_json(&mut stdout).await?;
+        stdout.write_all(b"\n")?;
+        return Ok(());
+    }
+
+    let handle = File::create(file).with_context(|| {
+        format!(
+            "What: Failed to create queue export file\n\
+             Why: could not write to {path}\n\
+             Fix: check that the parent directory exists and is writable",
+            path = file.display()
+        )
+    })?;
+    let mut writer = BufWriter::new(handle);
+    queue.export_json(&mut writer).await?;
+    writer.flush()?;
+
+    info!(path = %file.display(), "Queue exported");
+    println!("Exported queue to {}", file.display());
+    Ok(())
+}
+
+/// Runs `downloader queue import`: re-inserts queue items from `args.file` (or stdin for `-`).
+///
+/// Imported rows are scoped to the destination output directory so the next
+/// download run in that directory picks them up.
+pub async fn run_queue_import_command(args: &QueueTransferArgs) -> Result<()> {
+    let output_dir = resolve_queue_output_dir(args.output_dir.as_deref(), args.project.as_deref())?;
+    let queue = Queue::new(Database::new(&queue_db_path(&output_dir)).await?);
+    let project_key = project::project_history_key(&output_dir);
+
+    let imported = if is_stdio_marker(&args.file) {
+        queue
+            .import_json_into_project(io::stdin(), Some(&project_key))
+            .await?
+    } else {
+        let file = File::open(&args.file).with_context(|| {
+            format!(
+                "What: Failed to open queue import file\n\
+                 Why: {path} could not be read\n\
+                 Fix: verify the path exists and points to a `downloader queue export` file",
+                path = args.file.display()
+            )
+        })?;
+        queue
+            .import_json_into_project(BufReader::new(file), Some(&project_key))
+            .await?
+    };
+
+    info!(imported, "Queue imported");
+    println!(
+        "Imported {imported} queue item(s) into {}",
+        output_dir.display()
+    );
+    Ok(())
+}
+
+/// Runs `downloader queue dead-letter`: lists permanently failed items for the output scope.
+pub async fn run_queue_dead_letter_command(args: &QueueScopeArgs) -> Result<()> {
+    let Some((queue, project_key)) = open_existing_queue(args).await? else {
+        return Ok(());
+    };
+
+    let items = queue
+        .list_dead_letter_in_project(Some(&project_key))
+        .await?;
+    if items.is_empty() {
+        println!("No dead-lettered queue items.");
+        return Ok(());
+    }
+
+    for item in &items {
+        let reason = item
+            .last_error
+            .as_deref()
+            .and_then(|error| error.lines().next())
+            .unwrap_or("unknown");
+        println!("[{}] {}\n    {reason}", item.id, item.url);
+    }
+    println!(
+        "{} dead-lettered item(s). Requeue with `downloader queue requeue [--id ID]`.",
+        items.len()
+    );
+    Ok(())
+}
+
+/// Runs `downloader queue requeue`: returns dead-lettered items to pending.
+///
+/// Requeues the listed `--id` values, or every dead-lettered item in scope when none are given.
+pub async fn run_queue_requeue_command(args: &QueueRequeueArgs) -> Result<()> {
+    let Some((queue, project_key)) = open_existing_queue(&args.scope).await? else {
+        return Ok(());
+    };
+
+    let requeued = if args.ids.is_empty() {
+        queue
+            .requeue_all_dead_letter_in_project(Some(&project_key))
+            .await?
+    } else {
+        for id in &args.ids {
+            queue.requeue_dead_letter(*id).await.with_context(|| {
+                format!(
+                    "What: Failed to requeue item {id}\n\
+                     Why: no dead-lettered queue item has that ID\n\
+                     Fix: run `downloader queue dead-letter` to list requeueable IDs"
+                )
+            })?;
+        }
+        args.ids.len() as u64
+    };
+
+    info!(requeued, "Dead-lettered items requeued");
+    println!("Requeued {requeued} item(s). Run downloader again in this directory to retry them.");
+    Ok(())
+}
+
+/// Runs `downloader queue reprioritize`: sets the priority of one queue item.
+///
+/// Takes effect immediately, including for a run already in progress in the same directory.
+pub async fn run_queue_reprioritize_command(args: &QueueReprioritizeArgs) -> Result<()> {
+    let Some((queue, _project_key)) = open_existing_queue(&args.scope).await? else {
+        return Ok(());
+    };
+
+    queue
+        .set_priority(args.id, args.priority)
+        .await
+        .with_context(|| {
+            format!(
+                "What: Failed to reprioritize item {id}\n\
+                 Why: no queue item has that ID\n\
+                 Fix: run `downloader queue export -` to list queue item IDs",
+                id = args.id
+            )
+        })?;
+
+    info!(
+        id = args.id,
+        priority = args.priority,
+        "Queue item reprioritized"
+    );
+    println!("Set priority of item {} to {}.", args.id, args.priority);
+    Ok(())
+}
+
+/// Opens the queue database for an existing output scope, returning `None`
+/// (after telling the user) when no queue has been created there yet.
+async fn open_existing_queue(args: &QueueScopeArgs) -> Result<Option<(Queue, String)>> {
+    let output_dir = resolve_queue_output_dir(args.output_dir.as_deref(), args.project.as_deref())?;
+    let db_path = queue_db_path(&output_dir);
+    if !db_path.exists() {
+        eprintln!("No download queue found under {}.", output_dir.display());
+        return Ok(None);
+    }
+    let queue = Queue::new(Database::new(&db_path).await?);
+    Ok(Some((queue, project::project_history_key(&output_dir))))
+}
+
+pub(super) fn resolve_queue_output_dir(
+    output_dir: Option<&Path>,
+    project: Option<&str>,
+) -> Result<PathBuf> {
+    let base_output_dir = output_dir.map_or_else(|| PathBuf::from("."), Path::to_path_buf);
+    Ok(project::resolve_project_output_dir(
+        &base_output_dir,
+        project,
+    )?)
+}
+
+pub(super) fn queue_db_path(output_dir: &Path) -> PathBuf {
+    output_dir.join(".downloader").join("queue.db")
+}
+
+fn is_stdio_marker(path: &Path) -> bool {
+    path.as_os_str() == "-"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_queue_output_dir_defaults_to_current_dir() {
+        assert_eq!(
+            resolve_queue_output_dir(None, None).unwrap(),
+            PathBuf::from(".")
+        );
+    }
+
+    #[test]
+    fn test_resolve_queue_output_dir_appends_project_folder() {
+        let dir = resolve_queue_output_dir(Some(Path::new("/data")), Some("Climate")).unwrap();
+        assert!(dir.starts_with("/data"));
+        assert_ne!(dir, PathBuf::from("/data"));
+    }
+
+    #[tokio::test]
+    async fn test_requeue_command_without_queue_db_is_a_no_op() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let args = QueueRequeueArgs {
+            scope: QueueScopeArgs {
+                output_dir: Some(temp.path().to_path_buf()),
+                project: None,
+            },
+            ids: Vec::new(),
+        };
+        run_queue_requeue_command(&args).await.unwrap();
+        assert!(!queue_db_path(temp.path()).exists());
+    }
+
+    #[tokio::test]
+    async fn test_reprioritize_command_moves_item_to_front() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let db_path = queue_db_path(temp.path());
+        std::fs::create_dir_all(db_path.parent().unwrap()).unwrap();
+        let queue = Queue::new(Database::new(&db_path).await.unwrap());
+        queue
+            .enqueue("https://example.com/a.pdf", "direct_url", None)
+            .await
+            .unwrap();
+        let last = queue
+            .enqueue("https://example.com/b.pdf", "direct_url", None)
+            .await
+            .unwrap();
+
+        let args = QueueReprioritizeArgs {
+            scope: QueueScopeArgs {
+                output_dir: Some(temp.path().to_path_buf()),
+                project: None,
+            },
+            id: last,
+            priority: 50,
+        };
+        run_queue_reprioritize_command(&args).await.unwrap();
+
+        let next = queue.dequeue().await.unwrap().unwrap();
+        assert_eq!(next.id, last);
+    }
+
+    #[tokio::test]
+    async fn test_reprioritize_command_rejects_unknown_id() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let db_path = queue_db_path(temp.path());
+        std::fs::create_dir_all(db_path.parent().unwrap()).unwrap();
+        Database::new(&db_path).await.unwrap();
+
+        let args = QueueReprioritizeArgs {
+            scope: QueueScopeArgs {
+                output_dir: Some(temp.path().to_path_buf()),
+                project: None,
+            },
+            id: 42,
+            priority: 1,
+        };
+        let err = run_queue_reprioritize_command(&args).await.unwrap_err();
+        assert!(err.to_string().contains("reprioritize item 42"));
+    }
+
+    #[test]
+    fn test_format_age_uses_two_largest_units() {
+        assert_eq!(format_age(42), "42s");
+        assert_eq!(format_age(12 * 60 + 5), "12m");
+        assert_eq!(format_age(3 * 3_600 + 5 * 60), "3h 5m");
+        assert_eq!(format_age(2 * 86_400 + 4 * 3_600 + 59), "2d 4h");
+    }
+
+    #[tokio::test]
+    async fn test_queue_status_snapshot_counts_scoped_items_and_failures() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let db_path = queue_db_path(temp.path());
+        std::fs::create_dir_all(db_path.parent().unwrap()).unwrap();
+        let queue = Queue::new(Database::new(&db_path).await.unwrap());
+        let key = project::project_history_key(temp.path());
+        let mut ids = Vec::new();
+        for name in ["a", "b", "c", "d", "e"] {
+            ids.push(
+                queue
+                    .enqueue_in_project(
+                        &format!("https://example.com/{name}.pdf"),
+                        "direct_url",
+                        None,
+                        Some(&key),
+                    )
+                    .await
+                    .unwrap(),
+            );
+        }
+        queue
+            .enqueue_in_project(
+                "https://example.com/other.pdf",
+                "direct_url",
+                None,
+                Some("/elsewhere"),
+            )
+            .await
+            .unwrap();
+        queue.mark_completed(ids[0]).await.unwrap();
+        queue
+            .mark_failed(ids[1], "[AUTH] Authentication required for example.com", 0)
+            .await
+            .unwrap();
+        queue
+            .mark_dead_letter(ids[2], "HTTP 404 downloading https://example.com/c.pdf", 0)
+            .await
+            .unwrap();
+
+        let snapshot = queue_status_snapshot(&queue, &key).await.unwrap();
+        assert_eq!(snapshot.counts, [2, 0, 1, 2]);
+        assert_eq!(snapshot.dead_lettered, 1);
+        assert!(snapshot.oldest_pending_age_secs.is_some());
+
+        let rendered = render_queue_status(&snapshot);
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert!(lines[0].starts_with("pending") && lines[0].contains("(oldest "));
+        assert_eq!(lines[1], "in_progress        0");
+        assert_eq!(lines[2], "completed          1");
+        assert_eq!(lines[3], "failed             2  (1 dead-lettered)");
+        assert_eq!(lines[4], "Failures: 1 auth, 1 input_source");
+    }
+
+    #[tokio::test]
+    async fn test_queue_status_command_without_queue_db_is_a_no_op() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let args = QueueStatusArgs {
+            scope: QueueScopeArgs {
+                output_dir: Some(temp.path().to_path_buf()),
+                project: None,
+            },
+            watch: false,
+        };
+        run_queue_status_command(&args).await.unwrap();
+        assert!(!queue_db_path(temp.path()).exists());
+    }
+
+    #[test]
+    fn test_is_stdio_marker() {
+        assert!(is_stdio_marker(Path::new("-")));
+        assert!(!is_stdio_marker(Path::new("queue.json")));
+    }
+}
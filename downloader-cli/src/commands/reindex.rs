@@ -0,0 +1,56 @@
+//! Reindex command handler: rebuild a project's index, topics, and manifest from disk.
+
+use anyhow::{Context, Result, bail};
+use downloader_core::{Database, Queue, load_custom_topics};
+
+use super::queue::queue_db_path;
+use crate::cli::ReindexArgs;
+use crate::project;
+
+/// Runs `downloader reindex`: matches the files in `args.dir` against the
+/// project's queue and regenerates `index.md`, `topics.md`, and `manifest.json`.
+///
+/// Files added by hand are listed as untracked; completed items whose file was
+/// moved within the folder are re-linked in the queue.
+pub async fn run_reindex_command(args: &ReindexArgs) -> Result<()> {
+    let db_path = queue_db_path(&args.dir);
+    if !db_path.exists() {
+        bail!(
+            "What: No downloader project found\n\
+             Why: {path} does not exist\n\
+             Fix: pass the project folder that contains .downloader/ \
+             (e.g. ./downloads/my-project)",
+            path = db_path.display()
+        );
+    }
+    let custom_topics = match args.topics_file.as_deref() {
+        Some(path) => load_custom_topics(path).with_context(|| {
+            format!(
+                "What: Failed to read topics file\n\
+                 Why: {path} could not be read\n\
+                 Fix: check the path passed to --topics-file",
+                path = path.display()
+            )
+        })?,
+        None => Vec::new(),
+    };
+
+    let queue = Queue::new(Database::new(&db_path).await?);
+    let summary = project::reindex_project(&queue, &args.dir, &custom_topics).await?;
+
+    println!(
+        "Reindexed {}: {} tracked, {} re-linked, {} untracked, {} missing",
+        args.dir.display(),
+        summary.tracked,
+        summary.relinked,
+        summary.untracked,
+        summary.missing
+    );
+    if summary.missing > 0 {
+        eprintln!(
+            "{} completed downloads are no longer in the project folder and were left out of the index.",
+            summary.missing
+        );
+    }
+    Ok(())
+}
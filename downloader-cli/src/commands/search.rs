@@ -1,9 +1,10 @@
 //! Search command handler: query history and open top result.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
-use downloader_core::{Database, DownloadSearchQuery, Queue};
+use downloader_core::{Database, DownloadSearchCandidate, DownloadSearchQuery, Queue};
+use tracing::warn;
 
 use crate::cli::SearchArgs;
 use crate::open_path_in_default_app;
@@ -34,9 +35,14 @@ pub async fn run_search_command(args: &SearchArgs) -> Result<()> {
             Some(project::project_history_key(&output_dir)),
         )
     } else {
-        let db_paths = project::discover_history_db_paths(&base_output_dir)?;
+        let db_paths = project::discover_history_db_paths(&base_output_dir, args.follow_symlinks)?;
+        let scope = if args.all_projects {
+            "all projects"
+        } else {
+            "global"
+        };
         (
-            format!("global under {}", base_output_dir.display()),
+            format!("{scope} under {}", base_output_dir.display()),
             db_paths,
             None,
         )
@@ -61,16 +67,30 @@ pub async fn run_search_command(args: &SearchArgs) -> Result<()> {
     let mut candidates = Vec::new();
     let mut maybe_capped_by_hard_limit = false;
     for db_path in &db_paths {
-        let db = Database::new(db_path).await?;
-        let queue = Queue::new(db);
-        let mut db_candidates = queue.query_download_search_candidates(&query).await?;
+        let mut db_candidates = match query_search_candidates(db_path, &query).await {
+            Ok(db_candidates) => db_candidates,
+            Err(error) if args.all_projects => {
+                warn!(
+                    path = %db_path.display(),
+                    error = %error,
+                    "Skipping history database that could not be searched"
+                );
+                continue;
+            }
+            Err(error) => return Err(error),
+        };
         for candidate in &mut db_candidates {
             resolve_search_candidate_file_path(candidate, db_path);
         }
         if db_candidates.len() == SEARCH_CANDIDATE_LIMIT_PER_DB {
             maybe_capped_by_hard_limit = true;
         }
-        candidates.extend(db_candidates);
+        let project_label = project::history_project_label(&base_output_dir, db_path);
+        candidates.extend(
+            db_candidates
+                .into_iter()
+                .map(|candidate| (project_label.clone(), candidate)),
+        );
     }
 
     if candidates.is_empty() {
@@ -78,7 +98,15 @@ pub async fn run_search_command(args: &SearchArgs) -> Result<()> {
         return Ok(());
     }
 
-    let mut ranked = search::rank_search_candidates(&args.query, candidates);
+    let mut ranked = if args.all_projects {
+        search::rank_project_search_candidates(&args.query, candidates)
+    } else {
+        let candidates = candidates
+            .into_iter()
+            .map(|(_, candidate)| candidate)
+            .collect();
+        search::rank_search_candidates(&args.query, candidates)
+    };
     if ranked.is_empty() {
         println!("No search results matched the current query and filters.");
         return Ok(());
@@ -128,3 +156,11 @@ pub async fn run_search_command(args: &SearchArgs) -> Result<()> {
 
     Ok(())
 }
+
+async fn query_search_candidates(
+    db_path: &Path,
+    query: &DownloadSearchQuery,
+) -> Result<Vec<DownloadSearchCandidate>> {
+    let queue = Queue::new(Database::new(db_path).await?);
+    Ok(queue.query_download_search_candidates(query).await?)
+}
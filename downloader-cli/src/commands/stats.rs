@@ -0,0 +1,85 @@
+//! Stats command handler: lifetime throughput and duration aggregates per domain.
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use downloader_core::{Database, DomainStatsCollector, Queue};
+
+use crate::cli::StatsArgs;
+use crate::output;
+use crate::project;
+
+pub async fn run_stats_command(args: &StatsArgs) -> Result<()> {
+    let base_output_dir = args
+        .output_dir
+        .clone()
+        .unwrap_or_else(|| PathBuf::from("."));
+    let (history_scope_label, db_paths, project_scope_key) = if let Some(project_name) =
+        args.project.as_deref()
+    {
+        let output_dir = project::resolve_project_output_dir(&base_output_dir, Some(project_name))?;
+        let db_path = output_dir.join(".downloader").join("queue.db");
+        (
+            format!("project {}", output_dir.display()),
+            vec![db_path],
+            Some(project::project_history_key(&output_dir)),
+        )
+    } else {
+        let db_paths = project::discover_history_db_paths(&base_output_dir, args.follow_symlinks)?;
+        (
+            format!("global under {}", base_output_dir.display()),
+            db_paths,
+            None,
+        )
+    };
+
+    if db_paths.is_empty() {
+        println!("No download history found for {history_scope_label}.");
+        return Ok(());
+    }
+
+    let mut collector = DomainStatsCollector::new();
+    for db_path in &db_paths {
+        let db = Database::new(db_path).await?;
+        let queue = Queue::new(db);
+        queue
+            .collect_domain_stats(
+                project_scope_key.as_deref(),
+                args.since.as_deref(),
+                &mut collector,
+            )
+            .await?;
+    }
+
+    if collector.is_empty() {
+        println!("No history rows matched the current filters.");
+        return Ok(());
+    }
+
+    // Slowest measured hosts first; hosts without a measurable throughput go last.
+    let mut domains = collector.finish();
+    domains.sort_by(|left, right| {
+        match (left.summary.bytes_per_sec, right.summary.bytes_per_sec) {
+            (Some(left_rate), Some(right_rate)) => left_rate.total_cmp(&right_rate),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+        .then_with(|| left.domain.cmp(&right.domain))
+    });
+
+    let width = output::terminal_width();
+    println!(
+        "{}",
+        output::truncate_to_width(&output::domain_stats_header(), width)
+    );
+    for domain in &domains {
+        println!("{}", output::render_domain_stats_row(domain, width));
+    }
+    println!(
+        "Aggregated {} domains for {history_scope_label}.",
+        domains.len()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+//! Stdout download mode: stream a single resolved input to stdout for piping.
+
+use std::io::{self, Write as _};
+use std::sync::Arc;
+
+use anyhow::{Result, anyhow, bail};
+use downloader_core::{
+    CrossrefConfig, DomainHeaders, HttpClient, InputType, ParsedItem, ResolveContext,
+    build_default_resolver_registry, parse_input,
+};
+use reqwest::cookie::Jar;
+use tracing::debug;
+
+use crate::failure::classify_failure;
+
+/// Runs `downloader --stdout <input>`: resolves exactly one input and writes
+/// its bytes to stdout.
+///
+/// Nothing but the downloaded body is written to stdout; every diagnostic
+/// goes to stderr via the returned error.
+#[allow(clippy::too_many_arguments)]
+pub async fn run_stdout_download(
+    input_text: Option<&str>,
+    bibliography_items: &[ParsedItem],
+    cookie_jar: Option<Arc<Jar>>,
+    domain_headers: &DomainHeaders,
+    connect_timeout_secs: u64,
+    read_timeout_secs: u64,
+    crossref: &CrossrefConfig,
+    disabled_resolvers: &[String],
+) -> Result<()> {
+    let item = select_single_input(input_text, bibliography_items)?;
+    let resolver_input = if item.input_type == InputType::BibTex {
+        item.raw.as_str()
+    } else {
+        item.value.as_str()
+    };
+
+    let registry =
+        build_default_resolver_registry(cookie_jar.clone(), crossref, disabled_resolvers);
+    let resolved = registry
+        .resolve_to_url(resolver_input, item.input_type, &ResolveContext::default())
+        .await
+        .map_err(|error| {
+            anyhow!(
+                "What: Could not resolve {value} to a downloadable URL\n\
+                 Why: {error}\n\
+                 Fix: check the input, or run `downloader --dry-run {value}` to inspect resolution",
+                value = item.value
+            )
+        })?;
+    debug!(url = %resolved.url, "streaming resolved URL to stdout");
+
+    let client = match cookie_jar {
+        Some(jar) => {
+            HttpClient::with_cookie_jar_and_timeouts(jar, connect_timeout_secs, read_timeout_secs)
+        }
+        None => HttpClient::new_with_timeouts(connect_timeout_secs, read_timeout_secs),
+    }
+    .with_domain_headers(domain_headers.clone());
+
+    let mut stdout = io::stdout().lock();
+    client
+        .download_to_writer(&resolved.url, &mut stdout)
+        .await
+        .map_err(|error| {
+            let message = error.to_string();
+            let descriptor = classify_failure(&message);
+            anyhow!(
+                "What: {what}\nWhy: {message}\nFix: {fix}",
+                what = descriptor.what,
+                fix = descriptor.fix
+            )
+        })?;
+    stdout.flush()?;
+    Ok(())
+}
+
+/// Returns the only input item, refusing empty or multi-item input since
+/// several bodies concatenated on stdout could not be told apart.
+fn select_single_input(
+    input_text: Option<&str>,
+    bibliography_items: &[ParsedItem],
+) -> Result<ParsedItem> {
+    let mut items = input_text
+        .map(|text| parse_input(text).items)
+        .unwrap_or_default();
+    items.extend(bibliography_items.iter().cloned());
+
+    match items.len() {
+        1 => Ok(items.remove(0)),
+        0 => bail!(
+            "What: --stdout needs an input to download\n\
+             Why: no URL, DOI, or reference was found in the arguments or stdin\n\
+             Fix: pass exactly one input, e.g. `downloader --stdout https://example.com/paper.pdf`"
+        ),
+        count => bail!(
+            "What: --stdout accepts exactly one input\n\
+             Why: {count} inputs were given, and their bytes would be indistinguishable on stdout\n\
+             Fix: pass a single URL/DOI, or drop --stdout to download all inputs to files"
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select_single_input_accepts_one_url() {
+        let item = select_single_input(Some("https://example.com/a.pdf"), &[]).unwrap();
+        assert_eq!(item.input_type, InputType::Url);
+        assert_eq!(item.value, "https://example.com/a.pdf");
+    }
+
+    #[test]
+    fn test_select_single_input_rejects_multiple_inputs() {
+        let error = select_single_input(
+            Some("https://example.com/a.pdf\nhttps://example.com/b.pdf"),
+            &[],
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(error.contains("What: --stdout accepts exactly one input"));
+        assert!(error.contains("2 inputs"));
+    }
+
+    #[test]
+    fn test_select_single_input_counts_bibliography_items() {
+        let bib = ParsedItem::new("raw", InputType::Doi, "10.1000/xyz");
+        let error = select_single_input(Some("https://example.com/a.pdf"), &[bib])
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("exactly one input"));
+    }
+
+    #[test]
+    fn test_select_single_input_rejects_empty_input() {
+        let error = select_single_input(None, &[]).unwrap_err().to_string();
+        assert!(error.contains("What: --stdout needs an input"));
+    }
+}
@@ -21,6 +21,17 @@ impl FailureCategory {
         }
     }
 
+    /// Stable `snake_case` identifier for machine-readable reports.
+    #[must_use]
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Auth => "auth",
+            Self::InputSource => "input_source",
+            Self::Network => "network",
+            Self::Other => "other",
+        }
+    }
+
     #[must_use]
     pub fn label(self) -> &'static str {
         match self {
@@ -40,6 +51,62 @@ pub struct FailureDescriptor {
     pub fix: &'static str,
 }
 
+const TIMEOUT_FAILURE: FailureDescriptor = FailureDescriptor {
+    category: FailureCategory::Network,
+    what: "Download timed out",
+    why: "The remote host did not respond within the request timeout window.",
+    fix: "Increase retries or check network stability before retrying.",
+};
+
+const DNS_FAILURE: FailureDescriptor = FailureDescriptor {
+    category: FailureCategory::Network,
+    what: "DNS lookup failed",
+    why: "The source host name could not be resolved.",
+    fix: "Check the host name and your DNS/VPN settings, then retry.",
+};
+
+const TLS_FAILURE: FailureDescriptor = FailureDescriptor {
+    category: FailureCategory::Network,
+    what: "TLS handshake failed",
+    why: "The server certificate could not be verified or the TLS handshake was rejected.",
+    fix: "Check the system clock and any TLS-intercepting proxy, then retry.",
+};
+
+const CONNECTION_RESET_FAILURE: FailureDescriptor = FailureDescriptor {
+    category: FailureCategory::Network,
+    what: "Connection reset",
+    why: "The server dropped the connection before the transfer finished.",
+    fix: "Retry later or lower --concurrency for this host.",
+};
+
+const ROBOTS_FAILURE: FailureDescriptor = FailureDescriptor {
+    category: FailureCategory::InputSource,
+    what: "Blocked by robots.txt",
+    why: "The site's robots.txt forbids fetching this URL.",
+    fix: "Download it manually or rerun without `--check-robots`/`--respectful`.",
+};
+
+const TOO_LARGE_FAILURE: FailureDescriptor = FailureDescriptor {
+    category: FailureCategory::InputSource,
+    what: "File too large",
+    why: "The server refused the transfer as too large (HTTP 413).",
+    fix: "Download the file manually.",
+};
+
+const CHECKSUM_FAILURE: FailureDescriptor = FailureDescriptor {
+    category: FailureCategory::Other,
+    what: "Incomplete or corrupted download",
+    why: "The saved file failed the integrity check against the server's length.",
+    fix: "Retry the download.",
+};
+
+const CONTENT_TYPE_FAILURE: FailureDescriptor = FailureDescriptor {
+    category: FailureCategory::InputSource,
+    what: "Unexpected content type",
+    why: "The server did not serve this resource as a downloadable file (HTTP 406/415, or a tiny or non-PDF body).",
+    fix: "Open the URL in a browser to find the direct download link.",
+};
+
 /// Classifies an error message string into a category and descriptor.
 #[must_use]
 pub fn classify_failure(error: &str) -> FailureDescriptor {
@@ -66,20 +133,35 @@ pub fn classify_failure(error: &str) -> FailureDescriptor {
             why: "The resolved source returned HTTP 404, which usually means the link is stale.",
             fix: "Verify the source URL or reference and retry with an updated link.",
         }
-    } else if error.contains("timeout") {
+    } else if error.contains("disk full writing") {
         FailureDescriptor {
-            category: FailureCategory::Network,
-            what: "Download timed out",
-            why: "The remote host did not respond within the request timeout window.",
-            fix: "Increase retries or check network stability before retrying.",
+            category: FailureCategory::Other,
+            what: "Disk full",
+            why: "The output volume ran out of space while the file was being written.",
+            fix: "Free up space on the output volume (or pick another --output-dir), then retry.",
         }
-    } else if error.contains("network error") {
+    } else if error.contains("too many redirects") {
         FailureDescriptor {
-            category: FailureCategory::Network,
-            what: "Network request failed",
-            why: "Connectivity, DNS, TLS, or VPN conditions interrupted the request.",
-            fix: "Check connectivity/VPN settings, then rerun to resume.",
+            category: FailureCategory::InputSource,
+            what: "Too many redirects",
+            why: "The source kept redirecting past the redirect limit, usually in a loop.",
+            fix: "Open the last hop in a browser; raise `max_redirects` in config.toml if the chain is legitimate.",
         }
+    } else if error.contains("robots.txt disallows") {
+        ROBOTS_FAILURE
+    } else if error.contains("integrity check failed") {
+        CHECKSUM_FAILURE
+    } else if error.contains("HTTP 413") {
+        TOO_LARGE_FAILURE
+    } else if error.contains("HTTP 406")
+        || error.contains("HTTP 415")
+        || error.contains("suspicious content")
+    {
+        CONTENT_TYPE_FAILURE
+    } else if error.contains("timeout") || error.contains("HTTP 408") {
+        TIMEOUT_FAILURE
+    } else if error.contains("network error") {
+        classify_network_failure(error)
     } else if error.contains("invalid URL")
         || error.contains("invalid DOI")
         || error.contains("could not parse reference")
@@ -100,6 +182,28 @@ pub fn classify_failure(error: &str) -> FailureDescriptor {
     }
 }
 
+/// Narrows a `network error ...` message by the transport cause it carries.
+fn classify_network_failure(error: &str) -> FailureDescriptor {
+    let lower = error.to_ascii_lowercase();
+    if lower.contains("dns error") || lower.contains("failed to lookup address") {
+        DNS_FAILURE
+    } else if ["certificate", "tls", "ssl", "handshake"]
+        .iter()
+        .any(|needle| lower.contains(needle))
+    {
+        TLS_FAILURE
+    } else if lower.contains("connection reset") {
+        CONNECTION_RESET_FAILURE
+    } else {
+        FailureDescriptor {
+            category: FailureCategory::Network,
+            what: "Network request failed",
+            why: "Connectivity, DNS, TLS, or VPN conditions interrupted the request.",
+            fix: "Check connectivity/VPN settings, then rerun to resume.",
+        }
+    }
+}
+
 /// Returns a descriptor for a history attempt (typed error first, then message-based classification).
 #[must_use]
 pub fn history_failure_descriptor(attempt: &DownloadAttempt) -> FailureDescriptor {
@@ -147,12 +251,32 @@ fn descriptor_from_error_type(attempt: &DownloadAttempt) -> Option<FailureDescri
             why: "The supplied source format could not be interpreted safely.",
             fix: "Check input formatting and rerun with a valid URL/DOI/reference.",
         }),
+        Some(DownloadErrorType::DiskFull) => Some(FailureDescriptor {
+            category: FailureCategory::Other,
+            what: "Disk full",
+            why: "The output volume ran out of space while the file was being written.",
+            fix: "Free up space on the output volume (or pick another --output-dir), then retry.",
+        }),
+        Some(DownloadErrorType::TooManyRedirects) => Some(FailureDescriptor {
+            category: FailureCategory::InputSource,
+            what: "Too many redirects",
+            why: "The source kept redirecting past the redirect limit, usually in a loop.",
+            fix: "Open the last hop in a browser; raise `max_redirects` in config.toml if the chain is legitimate.",
+        }),
         Some(DownloadErrorType::Network) => Some(FailureDescriptor {
             category: FailureCategory::Network,
             what: "Network request failed",
             why: "Connectivity, DNS, TLS, or VPN conditions interrupted the request.",
             fix: "Check connectivity/VPN settings, then retry.",
         }),
+        Some(DownloadErrorType::Timeout) => Some(TIMEOUT_FAILURE),
+        Some(DownloadErrorType::Dns) => Some(DNS_FAILURE),
+        Some(DownloadErrorType::Tls) => Some(TLS_FAILURE),
+        Some(DownloadErrorType::ConnectionReset) => Some(CONNECTION_RESET_FAILURE),
+        Some(DownloadErrorType::RobotsDisallowed) => Some(ROBOTS_FAILURE),
+        Some(DownloadErrorType::TooLarge) => Some(TOO_LARGE_FAILURE),
+        Some(DownloadErrorType::ChecksumMismatch) => Some(CHECKSUM_FAILURE),
+        Some(DownloadErrorType::UnexpectedContentType) => Some(CONTENT_TYPE_FAILURE),
         None => None,
     }
 }
@@ -195,6 +319,20 @@ fn typed_history_suggestion(
             "Check input formatting and rerun with a valid URL/DOI/reference."
         }
         DownloadErrorType::Network => "Check connectivity/VPN settings, then retry.",
+        DownloadErrorType::DiskFull => {
+            "Free up space on the output volume (or pick another --output-dir), then retry."
+        }
+        DownloadErrorType::TooManyRedirects => {
+            "Open the last hop in a browser; raise `max_redirects` in config.toml if the chain is legitimate."
+        }
+        DownloadErrorType::Timeout => TIMEOUT_FAILURE.fix,
+        DownloadErrorType::Dns => DNS_FAILURE.fix,
+        DownloadErrorType::Tls => TLS_FAILURE.fix,
+        DownloadErrorType::ConnectionReset => CONNECTION_RESET_FAILURE.fix,
+        DownloadErrorType::RobotsDisallowed => ROBOTS_FAILURE.fix,
+        DownloadErrorType::TooLarge => TOO_LARGE_FAILURE.fix,
+        DownloadErrorType::ChecksumMismatch => CHECKSUM_FAILURE.fix,
+        DownloadErrorType::UnexpectedContentType => CONTENT_TYPE_FAILURE.fix,
     }
 }
 
@@ -264,6 +402,8 @@ mod tests {
             original_input: None,
             http_status: Some(401),
             duration_ms: Some(42),
+            resolver_path: None,
+            input_source_file: None,
         }
     }
 
@@ -288,6 +428,78 @@ mod tests {
         assert!(d.what.contains("timed out"));
     }
 
+    #[test]
+    fn test_classify_failure_disk_full() {
+        let d = classify_failure("disk full writing /tmp/out/paper.pdf");
+        assert_eq!(d.category, FailureCategory::Other);
+        assert_eq!(d.what, "Disk full");
+    }
+
+    #[test]
+    fn test_classify_failure_too_many_redirects() {
+        let d = classify_failure(
+            "too many redirects downloading https://example.com/a: stopped after 10 (last hops: ...)",
+        );
+        assert_eq!(d.category, FailureCategory::InputSource);
+        assert_eq!(d.what, "Too many redirects");
+    }
+
+    #[test]
+    fn test_classify_failure_narrows_network_and_source_errors() {
+        let cases = [
+            (
+                "network error downloading https://a.example/x.pdf: error sending request: client error (Connect): dns error: failed to lookup address information",
+                "DNS lookup failed",
+            ),
+            (
+                "network error downloading https://a.example/x.pdf: error sending request: invalid peer certificate: UnknownIssuer",
+                "TLS handshake failed",
+            ),
+            (
+                "network error downloading https://a.example/x.pdf: connection reset by peer",
+                "Connection reset",
+            ),
+            (
+                "robots.txt disallows downloading https://a.example/x.pdf",
+                "Blocked by robots.txt",
+            ),
+            (
+                "HTTP 413 downloading https://a.example/x.pdf",
+                "File too large",
+            ),
+            (
+                "HTTP 415 downloading https://a.example/x.pdf",
+                "Unexpected content type",
+            ),
+            (
+                "integrity check failed for /tmp/x.pdf: expected 10 bytes, got 4",
+                "Incomplete or corrupted download",
+            ),
+        ];
+        for (message, what) in cases {
+            assert_eq!(classify_failure(message).what, what, "{message}");
+        }
+        assert_eq!(
+            classify_failure("network error downloading https://a.example/x.pdf: refused").what,
+            "Network request failed"
+        );
+    }
+
+    #[test]
+    fn test_history_failure_descriptor_uses_fine_grained_types() {
+        let attempt = history_attempt(Some("dns"), None);
+        let descriptor = history_failure_descriptor(&attempt);
+        assert_eq!(descriptor.category, FailureCategory::Network);
+        assert_eq!(descriptor.what, "DNS lookup failed");
+
+        let attempt = history_attempt(Some("robots_disallowed"), None);
+        assert_eq!(
+            history_failure_descriptor(&attempt).category,
+            FailureCategory::InputSource
+        );
+        assert!(history_failure_suggestion(&attempt).contains("--check-robots"));
+    }
+
     #[test]
     fn test_classify_failure_other() {
         let d = classify_failure("HTTP 500 internal server error");
@@ -134,6 +134,10 @@ pub(crate) fn validate_search_date_range(since: Option<&str>, until: Option<&str
     app::validation::validate_search_date_range(since, until)
 }
 
+pub(crate) fn validate_history_datetime(flag: &str, value: &str) -> Result<()> {
+    app::validation::validate_history_datetime(flag, value)
+}
+
 pub(crate) fn resolve_search_candidate_file_path(
     candidate: &mut DownloadSearchCandidate,
     db_path: &Path,
@@ -160,9 +164,25 @@ pub(crate) fn resolve_search_candidate_file_path(
 pub(crate) fn render_search_cli_row(result: &search::RankedSearchResult, width: usize) -> String {
     let title_or_file = search_result_title_or_file(result);
     let path = result.candidate.file_path.as_deref().unwrap_or("n/a");
+    let resolver_suffix = result
+        .candidate
+        .resolver_path
+        .as_deref()
+        .map(|path| format!(" | via={path}"))
+        .unwrap_or_default();
+    let project_prefix = result
+        .project
+        .as_deref()
+        .map(|project| format!("{project} | "))
+        .unwrap_or_default();
     let base_line = format!(
-        "{} | {} | match={} | {}",
-        result.candidate.started_at, title_or_file, result.matched_field, path
+        "{} | {}{} | match={}{} | {}",
+        result.candidate.started_at,
+        project_prefix,
+        title_or_file,
+        result.matched_field,
+        resolver_suffix,
+        path
     );
     output::truncate_to_width(&base_line, width)
 }
@@ -321,7 +341,13 @@ async fn generate_sidecars_for_completed(
     output_dir: &Path,
     completed_before: &HashSet<i64>,
 ) -> usize {
-    project::generate_sidecars_for_completed(queue, output_dir, completed_before).await
+    project::generate_sidecars_for_completed(
+        queue,
+        output_dir,
+        completed_before,
+        downloader_core::SidecarFormat::JsonLd,
+    )
+    .await
 }
 
 #[cfg(test)]
@@ -347,7 +373,7 @@ mod tests {
         render_project_download_log_section, render_search_cli_row, resolve_default_log_level,
         resolve_http_timeouts, resolve_search_candidate_file_path, search_result_title_or_file,
         should_disable_color, should_force_cli_log_level, should_use_spinner,
-        validate_cookie_stdin_conflict, validate_search_date_range,
+        validate_cookie_stdin_conflict, validate_history_datetime, validate_search_date_range,
     };
 
     fn parse_download_args(
@@ -393,6 +419,43 @@ mod tests {
         assert!(summary.contains("(1 references need verification)"));
     }
 
+    #[test]
+    fn test_build_parse_feedback_summary_lists_weakest_references_first() {
+        let mut result = ParseResult::new();
+        for raw in [
+            "Weak candidate text with little structure",
+            "2024",
+            "Smith, J. (2024). Paper Title. Journal.",
+        ] {
+            result.add_item(ParsedItem::reference(raw, raw));
+        }
+
+        let summary = build_parse_feedback_summary(&result);
+        let weakest = summary
+            .split_once("[weakest: ")
+            .map(|(_, rest)| rest)
+            .expect("weakest references listed");
+        let first = weakest
+            .find("\"2024\" 0.25")
+            .expect("year-only reference listed");
+        let second = weakest
+            .find("\"Weak candidate")
+            .expect("title-only reference listed");
+        assert!(first < second, "{summary}");
+        assert!(!weakest.contains("Smith"), "{summary}");
+    }
+
+    #[test]
+    fn test_build_parse_feedback_summary_counts_likely_malformed_inputs() {
+        let result = downloader_core::parse_input("htps://example.com/a.pdf\nwww.example.org");
+
+        let summary = build_parse_feedback_summary(&result);
+        assert!(summary.contains("2 likely-malformed inputs skipped"));
+
+        let clean = build_parse_feedback_summary(&downloader_core::parse_input("plain prose"));
+        assert!(!clean.contains("likely-malformed"));
+    }
+
     #[test]
     fn test_build_parse_feedback_summary_does_not_flag_medium_confidence_reference() {
         let mut result = ParseResult::new();
@@ -483,6 +546,12 @@ mod tests {
         assert_eq!(resolve_default_log_level(&args), "error");
     }
 
+    #[test]
+    fn test_resolve_default_log_level_stdout_is_error() {
+        let args = parse_download_args(["downloader", "--stdout", "https://example.com/a.pdf"]);
+        assert_eq!(resolve_default_log_level(&args), "error");
+    }
+
     #[test]
     fn test_should_force_cli_log_level_when_debug_flag_present() {
         let sources = CliValueSources {
@@ -714,6 +783,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_apply_config_defaults_merges_disabled_resolvers_from_config() {
+        let args = parse_download_args(["downloader"]);
+        let sources = CliValueSources::default();
+        let file_config = FileConfig {
+            disabled_resolvers: vec!["ieee".to_string(), "sciencedirect".to_string()],
+            ..FileConfig::default()
+        };
+        let merged = apply_config_defaults(args, &sources, Some(&file_config)).unwrap();
+        assert_eq!(merged.disable_resolver, ["ieee", "sciencedirect"]);
+    }
+
+    #[test]
+    fn test_apply_config_defaults_cli_disable_resolver_replaces_config_list() {
+        let args = parse_download_args(["downloader", "--disable-resolver", "crossref"]);
+        let sources = CliValueSources::default();
+        let file_config = FileConfig {
+            disabled_resolvers: vec!["ieee".to_string(), "sciencedirect".to_string()],
+            ..FileConfig::default()
+        };
+        let merged = apply_config_defaults(args, &sources, Some(&file_config)).unwrap();
+        assert_eq!(
+            merged.disable_resolver,
+            ["crossref"],
+            "--disable-resolver should replace, not extend, the config list"
+        );
+    }
+
     #[test]
     fn test_resolve_http_timeouts_defaults_when_no_config() {
         let settings = resolve_http_timeouts(None);
@@ -739,6 +836,32 @@ mod tests {
         assert_eq!(settings.resolver_read_secs, 45);
     }
 
+    #[test]
+    fn test_resolve_retry_policy_applies_config_overrides() {
+        use downloader_core::FailureType;
+        use downloader_core::download::DownloadError;
+        use std::time::Duration;
+
+        let mut file_config = FileConfig {
+            retry_max_backoff_rate_limited_secs: Some(300),
+            ..FileConfig::default()
+        };
+        file_config.retry_status.insert(500, FailureType::Permanent);
+        let policy = super::app::config_runtime::resolve_retry_policy(4, Some(&file_config));
+
+        assert_eq!(policy.max_attempts(), 4);
+        let error = DownloadError::http_status("https://example.com/a.pdf", 500);
+        assert_eq!(policy.classify(&error), FailureType::Permanent);
+        assert_eq!(
+            policy.max_delay_for(FailureType::RateLimited),
+            Duration::from_secs(300)
+        );
+        assert_eq!(
+            policy.max_delay_for(FailureType::Transient),
+            Duration::from_secs(32)
+        );
+    }
+
     #[test]
     fn test_classify_failure_auth_prefix() {
         let descriptor = failure::classify_failure(
@@ -858,6 +981,22 @@ mod tests {
         assert!(rendered.contains("- 🌐 Network: 1"));
     }
 
+    #[test]
+    fn test_render_failure_summary_lines_breaks_categories_into_kinds() {
+        let reasons = vec![
+            "network error downloading https://a.example/x.pdf: dns error: failed to lookup address information",
+            "network error downloading https://b.example/y.pdf: dns error: failed to lookup address information",
+            "timeout downloading https://c.example/z.pdf",
+            "HTTP 404 downloading https://example.com/missing.pdf",
+        ];
+        let lines = render_failure_summary_lines(&reasons, 200);
+        let rendered = lines.join("\n");
+
+        assert!(rendered.contains("- 🌐 Network: 3"));
+        assert!(rendered.contains("  Kinds: DNS lookup failed (2), Download timed out (1)"));
+        assert!(rendered.contains("  Kinds: Source not found (1)"));
+    }
+
     #[test]
     fn test_render_failure_summary_lines_include_what_why_fix_triplet() {
         let reasons = vec!["HTTP 404 downloading https://example.com/missing.pdf"];
@@ -1094,13 +1233,45 @@ mod tests {
         std::fs::write(&root_db, b"").unwrap();
         std::fs::write(&nested_db, b"").unwrap();
 
-        let discovered = project::discover_history_db_paths(root.path()).unwrap();
+        let discovered = project::discover_history_db_paths(root.path(), false).unwrap();
 
         assert_eq!(discovered.len(), 2);
         assert!(discovered.contains(&root_db));
         assert!(discovered.contains(&nested_db));
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_discover_history_db_paths_terminates_on_symlink_cycle() {
+        let root = TempDir::new().unwrap();
+        let project_db = root.path().join("ProjectA/.downloader/queue.db");
+        std::fs::create_dir_all(project_db.parent().unwrap()).unwrap();
+        std::fs::write(&project_db, b"").unwrap();
+        std::os::unix::fs::symlink(root.path(), root.path().join("ProjectA/loop")).unwrap();
+
+        let skipped = project::discover_history_db_paths(root.path(), false).unwrap();
+        let followed = project::discover_history_db_paths(root.path(), true).unwrap();
+
+        assert_eq!(skipped, std::slice::from_ref(&project_db));
+        assert_eq!(followed, [project_db]);
+    }
+
+    #[test]
+    fn test_history_project_label_is_relative_to_output_root() {
+        let base = Path::new("/library");
+        assert_eq!(
+            project::history_project_label(base, Path::new("/library/.downloader/queue.db")),
+            "."
+        );
+        assert_eq!(
+            project::history_project_label(
+                base,
+                &Path::new("/library/ProjectA/Study/.downloader").join("queue.db")
+            ),
+            Path::new("ProjectA/Study").display().to_string()
+        );
+    }
+
     #[tokio::test]
     async fn test_append_project_index_creates_index_with_entries() {
         let db = Database::new_in_memory().await.unwrap();
@@ -1112,11 +1283,16 @@ mod tests {
             suggested_filename: Some("Smith_2024_Climate_Study.pdf".to_string()),
             title: Some("Climate Study".to_string()),
             authors: Some("Smith, John".to_string()),
+            author_orcids: None,
+            abstract_text: None,
             year: Some("2024".to_string()),
             doi: Some("10.1000/test".to_string()),
             topics: None,
             parse_confidence: None,
             parse_confidence_factors: None,
+            read_timeout_secs: None,
+            resolver_path: None,
+            input_source_file: None,
         };
         let id = queue
             .enqueue_with_metadata_in_project(
@@ -1164,11 +1340,16 @@ mod tests {
             suggested_filename: Some("Doe_2025_Energy.pdf".to_string()),
             title: Some("Energy Analysis".to_string()),
             authors: Some("Doe, Jane".to_string()),
+            author_orcids: None,
+            abstract_text: None,
             year: Some("2025".to_string()),
             doi: Some("10.1000/energy".to_string()),
             topics: None,
             parse_confidence: None,
             parse_confidence_factors: None,
+            read_timeout_secs: None,
+            resolver_path: None,
+            input_source_file: None,
         };
         let id = queue
             .enqueue_with_metadata_in_project(
@@ -1208,11 +1389,16 @@ mod tests {
             suggested_filename: Some("Old_2023_Item.pdf".to_string()),
             title: Some("Old Item".to_string()),
             authors: Some("Legacy, User".to_string()),
+            author_orcids: None,
+            abstract_text: None,
             year: Some("2023".to_string()),
             doi: Some("10.1000/old".to_string()),
             topics: None,
             parse_confidence: None,
             parse_confidence_factors: None,
+            read_timeout_secs: None,
+            resolver_path: None,
+            input_source_file: None,
         };
         let old_id = queue
             .enqueue_with_metadata_in_project(
@@ -1238,11 +1424,16 @@ mod tests {
             suggested_filename: Some("New_2026_Item.pdf".to_string()),
             title: Some("New Item".to_string()),
             authors: Some("Current, User".to_string()),
+            author_orcids: None,
+            abstract_text: None,
             year: Some("2026".to_string()),
             doi: Some("10.1000/new".to_string()),
             topics: None,
             parse_confidence: None,
             parse_confidence_factors: None,
+            read_timeout_secs: None,
+            resolver_path: None,
+            input_source_file: None,
         };
         let new_id = queue
             .enqueue_with_metadata_in_project(
@@ -1298,6 +1489,8 @@ mod tests {
             original_input: Some("10.1000/example".to_string()),
             http_status: Some(404),
             duration_ms: Some(250),
+            resolver_path: None,
+            input_source_file: None,
         }];
 
         let section = render_project_download_log_section("unix-1", &attempts);
@@ -1337,6 +1530,8 @@ mod tests {
             topics: None,
             parse_confidence: None,
             parse_confidence_factors: None,
+            resolver_path: None,
+            input_source_file: None,
         };
         let first_id = queue.log_download_attempt(&first).await.unwrap();
 
@@ -1367,6 +1562,8 @@ mod tests {
             topics: None,
             parse_confidence: None,
             parse_confidence_factors: None,
+            resolver_path: None,
+            input_source_file: None,
         };
         let second_id = queue.log_download_attempt(&second).await.unwrap();
 
@@ -1559,6 +1756,7 @@ mod tests {
             authors: authors.map(std::string::ToString::to_string),
             doi: doi.map(std::string::ToString::to_string),
             started_at: started_at.to_string(),
+            resolver_path: None,
         }
     }
 
@@ -1625,6 +1823,31 @@ mod tests {
         assert_eq!(ranked[1].candidate.id, 10);
     }
 
+    #[test]
+    fn test_rank_project_search_candidates_ranks_union_and_keeps_project_labels() {
+        let candidate = |id, title| {
+            make_search_candidate(id, "2026-02-01 00:00:00", Some(title), None, None, None)
+        };
+        let ranked = search::rank_project_search_candidates(
+            "attention",
+            vec![
+                (
+                    "ProjectA".to_string(),
+                    candidate(1, "Attention Is All You Need"),
+                ),
+                ("ProjectA".to_string(), candidate(2, "Unrelated")),
+                ("ProjectB".to_string(), candidate(1, "Attention")),
+            ],
+        );
+
+        let order: Vec<_> = ranked
+            .iter()
+            .map(|result| (result.project.as_deref(), result.candidate.id))
+            .collect();
+        assert_eq!(order, [(Some("ProjectB"), 1), (Some("ProjectA"), 1)]);
+        assert!(render_search_cli_row(&ranked[0], 200).contains(" | ProjectB | Attention | "));
+    }
+
     #[test]
     fn test_classify_search_match_typos_hit_fuzzy_threshold() {
         let query = search::normalize_search_text("attenton");
@@ -1648,6 +1871,7 @@ mod tests {
             match_kind: search::SearchMatchKind::Exact,
             similarity: 1.0,
             matched_field: "title",
+            project: None,
         };
         let right = search::RankedSearchResult {
             candidate: make_search_candidate(
@@ -1661,6 +1885,7 @@ mod tests {
             match_kind: search::SearchMatchKind::Exact,
             similarity: 1.0,
             matched_field: "title",
+            project: None,
         };
         assert_eq!(
             search::compare_search_results(&left, &right),
@@ -1686,6 +1911,7 @@ mod tests {
             match_kind: search::SearchMatchKind::Substring,
             similarity: 0.8,
             matched_field: "title",
+            project: None,
         };
 
         let rendered = render_search_cli_row(&result, 200);
@@ -1693,6 +1919,30 @@ mod tests {
         assert!(rendered.contains("Attention Is All You Need"));
         assert!(rendered.contains("/tmp/attention.pdf"));
         assert!(rendered.contains("match=title"));
+        assert!(!rendered.contains("via="));
+    }
+
+    #[test]
+    fn test_render_search_cli_row_includes_resolver_path_when_known() {
+        let mut candidate = make_search_candidate(
+            32,
+            "2026-02-03 10:00:00",
+            Some("Attention Is All You Need"),
+            None,
+            None,
+            Some("/tmp/attention.pdf"),
+        );
+        candidate.resolver_path = Some("arxiv->direct".to_string());
+        let result = search::RankedSearchResult {
+            candidate,
+            match_kind: search::SearchMatchKind::Substring,
+            similarity: 0.8,
+            matched_field: "title",
+            project: None,
+        };
+
+        let rendered = render_search_cli_row(&result, 200);
+        assert!(rendered.contains("match=title | via=arxiv->direct | /tmp/attention.pdf"));
     }
 
     #[test]
@@ -1709,6 +1959,7 @@ mod tests {
             match_kind: search::SearchMatchKind::Substring,
             similarity: 0.7,
             matched_field: "authors",
+            project: None,
         };
 
         assert_eq!(search_result_title_or_file(&result), "fallback-file.pdf");
@@ -1726,6 +1977,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate_history_datetime_accepts_dates_and_sqlite_datetimes() {
+        for value in ["2025-01-01", "2025-01-01 08:30", "2025-01-01 08:30:00"] {
+            assert!(
+                validate_history_datetime("--before", value).is_ok(),
+                "{value}"
+            );
+        }
+        for value in ["2025-1-1", "01/01/2025", "2025-01-01T08:30:00", "last year"] {
+            let err = validate_history_datetime("--before", value)
+                .expect_err("malformed datetime should fail");
+            assert!(err.to_string().contains("What: Invalid --before value"));
+        }
+    }
+
     #[test]
     fn test_validate_search_date_range_rejects_inverted_bounds() {
         let err =
@@ -1840,11 +2106,13 @@ mod tests {
             original_input: Some("10.1234/failure".to_string()),
             http_status: Some(404),
             duration_ms: Some(50),
+            resolver_path: Some("crossref->direct".to_string()),
+            input_source_file: Some("refs.ris".to_string()),
         };
 
         let row = render_history_cli_row(&attempt, true, 120);
         assert!(row.contains("FAILED"));
-        assert!(row.contains("confidence=low"));
+        assert!(row.contains("confidence=low | via=crossref->direct | from=refs.ris"));
         assert!(row.contains("❌ What: Source not found"));
         assert!(row.contains("Why:"));
         assert!(row.contains("Fix: Verify the source URL/DOI/reference"));
@@ -1873,6 +2141,8 @@ mod tests {
             original_input: Some("https://proxy.example.com/failure.pdf".to_string()),
             http_status: Some(407),
             duration_ms: Some(50),
+            resolver_path: None,
+            input_source_file: None,
         };
 
         let row = render_history_cli_row(&attempt, true, 120);
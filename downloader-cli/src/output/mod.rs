@@ -4,14 +4,18 @@ use std::path::Path;
 
 use anyhow::Result;
 use downloader_core::{
-    DownloadAttempt, DownloadAttemptStatus, DownloadStats, Queue, QueueStatus,
-    extract_reference_confidence,
+    BYTE_BUDGET_WINDOW_DAYS, DomainStats, DownloadAttempt, DownloadAttemptStatus, DownloadStats,
+    DownloadStatsSummary, Queue, QueueStatus, extract_reference_confidence,
 };
 use tracing::info;
 
 use crate::cli::HistoryStatusArg;
 use crate::failure;
 
+mod run_report;
+
+pub(crate) use run_report::RunReport;
+
 /// Message when no input was provided at all.
 pub const NO_INPUT_GUIDANCE: &str = "No input provided. Pipe URLs via stdin or pass as arguments.";
 
@@ -117,16 +121,32 @@ pub(crate) fn build_parse_feedback_summary(parse_result: &downloader_core::Parse
             " ({} references need verification)",
             confidence_distribution.low
         ));
+        let weakest: Vec<String> = confidence_distribution
+            .weakest
+            .iter()
+            .map(|(score, raw)| format!("\"{}\" {score:.2}", truncate_to_width(raw, 40)))
+            .collect();
+        summary.push_str(&format!(" [weakest: {}]", weakest.join("; ")));
+    }
+
+    let malformed = parse_result.likely_malformed_count();
+    if malformed > 0 {
+        summary.push_str(&format!(", {malformed} likely-malformed inputs skipped"));
     }
 
     summary
 }
 
-#[derive(Debug, Clone, Copy, Default)]
+/// Low-confidence references listed in the parse summary, weakest first.
+const WEAKEST_REFERENCES_SHOWN: usize = 3;
+
+#[derive(Debug, Clone, Default)]
 struct ReferenceConfidenceDistribution {
     high: usize,
     medium: usize,
     low: usize,
+    /// Scores and raw text of the lowest-scoring low-confidence references.
+    weakest: Vec<(f64, String)>,
 }
 
 fn reference_confidence_distribution(
@@ -134,15 +154,30 @@ fn reference_confidence_distribution(
 ) -> ReferenceConfidenceDistribution {
     let mut distribution = ReferenceConfidenceDistribution::default();
     for item in parse_result.references() {
-        match extract_reference_confidence(&item.raw).level {
+        let details = extract_reference_confidence(&item.raw);
+        match details.level {
             downloader_core::Confidence::High => distribution.high += 1,
             downloader_core::Confidence::Medium => distribution.medium += 1,
-            downloader_core::Confidence::Low => distribution.low += 1,
+            downloader_core::Confidence::Low => {
+                distribution.low += 1;
+                distribution
+                    .weakest
+                    .push((details.score, preview_single_line(&item.raw)));
+            }
         }
     }
+    // Stable sort: equal scores keep input order.
+    distribution
+        .weakest
+        .sort_by(|left, right| left.0.total_cmp(&right.0));
+    distribution.weakest.truncate(WEAKEST_REFERENCES_SHOWN);
     distribution
 }
 
+fn preview_single_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
 pub(crate) fn map_history_status(status: HistoryStatusArg) -> DownloadAttemptStatus {
     match status {
         HistoryStatusArg::Success => DownloadAttemptStatus::Success,
@@ -165,7 +200,19 @@ pub(crate) fn render_history_cli_row(
         .as_deref()
         .map(|value| format!(" | confidence={value}"))
         .unwrap_or_default();
-    let base_line = format!("{date} | {status} | {title_or_file}{confidence_suffix} | {source}");
+    let resolver_suffix = attempt
+        .resolver_path
+        .as_deref()
+        .map(|path| format!(" | via={path}"))
+        .unwrap_or_default();
+    let input_file_suffix = attempt
+        .input_source_file
+        .as_deref()
+        .map(|file| format!(" | from={file}"))
+        .unwrap_or_default();
+    let base_line = format!(
+        "{date} | {status} | {title_or_file}{confidence_suffix}{resolver_suffix}{input_file_suffix} | {source}"
+    );
 
     if failed_only && attempt.status() == DownloadAttemptStatus::Failed {
         let descriptor = failure::history_failure_descriptor(attempt);
@@ -192,6 +239,7 @@ pub(crate) async fn print_completion_summary(
     total_queued: usize,
     project_output_dir: Option<&Path>,
     uncertain_references_in_run: usize,
+    json_output: bool,
 ) -> Result<()> {
     let failed_items = queue.list_by_status(QueueStatus::Failed).await?;
     let succeeded = stats.completed();
@@ -205,6 +253,19 @@ pub(crate) async fn print_completion_summary(
     if let Some(project_dir) = project_output_dir {
         info!(project_dir = %project_dir.display(), "Project folder");
     }
+    if stats.was_capped() {
+        info!("{}", capped_summary_line(stats.total(), total_queued));
+    }
+    if let Some(remaining) = stats.budget_exhausted() {
+        info!(
+            budget_remaining_bytes = remaining,
+            "{}",
+            budget_exhausted_summary_line(remaining)
+        );
+    }
+    if let Some(throughput_line) = throughput_summary_line(&stats.summary()) {
+        info!("{throughput_line}");
+    }
     if let Some(summary_line) = uncertain_reference_summary_line(uncertain_references_in_run) {
         info!(
             uncertain_references = uncertain_references_in_run,
@@ -212,13 +273,26 @@ pub(crate) async fn print_completion_summary(
         );
     }
 
+    let dead_lettered = stats.dead_lettered();
+    if dead_lettered > 0 {
+        info!(
+            dead_lettered,
+            "Permanent failures moved to dead-letter; review with `downloader queue dead-letter` and retry with `downloader queue requeue`"
+        );
+    }
+
     if !failed_items.is_empty() {
         let reasons: Vec<&str> = failed_items
             .iter()
             .map(|item| item.last_error.as_deref().unwrap_or("unknown"))
             .collect();
+        // `--json` owns stdout; keep the human failure summary on stderr.
         for line in render_failure_summary_lines(&reasons, terminal_width()) {
-            println!("{line}");
+            if json_output {
+                eprintln!("{line}");
+            } else {
+                println!("{line}");
+            }
         }
     }
 
@@ -236,6 +310,137 @@ pub(crate) fn uncertain_reference_summary_line(
     ))
 }
 
+/// Summary line for a run stopped by `--max-items` with items still pending.
+pub(crate) fn capped_summary_line(processed: usize, total_queued: usize) -> String {
+    format!(
+        "Processed {processed} of {total_queued} pending (capped); run again to continue with the rest"
+    )
+}
+
+/// Summary line for a run stopped by `--byte-budget` with items still pending.
+pub(crate) fn budget_exhausted_summary_line(remaining: u64) -> String {
+    format!(
+        "Budget exhausted, {remaining} bytes remaining in the {BYTE_BUDGET_WINDOW_DAYS}-day window; the rest stay pending (check with `downloader budget status`)"
+    )
+}
+
+/// One-line transfer summary for a run; `None` when nothing completed.
+pub(crate) fn throughput_summary_line(summary: &DownloadStatsSummary) -> Option<String> {
+    let overall = &summary.overall;
+    if overall.succeeded == 0 {
+        return None;
+    }
+    let mut line = format!(
+        "Transferred {} at {}; per-item p50 {}, p95 {}",
+        format_bytes(overall.total_bytes),
+        format_throughput(overall.bytes_per_sec),
+        format_duration_ms(overall.p50_duration_ms),
+        format_duration_ms(overall.p95_duration_ms),
+    );
+    if let Some(slowest) = &summary.slowest_host {
+        line.push_str(&format!(
+            "; slowest host {} ({})",
+            slowest.domain,
+            format_throughput(slowest.summary.bytes_per_sec)
+        ));
+    }
+    Some(line)
+}
+
+/// Header row for `downloader stats`, aligned with [`render_domain_stats_row`].
+pub(crate) fn domain_stats_header() -> String {
+    format!(
+        "{:<32} {:>8} {:>8} {:>11} {:>12} {:>8} {:>8}",
+        "DOMAIN", "OK", "FAILED", "BYTES", "THROUGHPUT", "P50", "P95"
+    )
+}
+
+/// One `downloader stats` row for a host.
+pub(crate) fn render_domain_stats_row(stats: &DomainStats, width: usize) -> String {
+    let summary = &stats.summary;
+    let row = format!(
+        "{:<32} {:>8} {:>8} {:>11} {:>12} {:>8} {:>8}",
+        truncate_to_width(&stats.domain, 32),
+        summary.succeeded,
+        summary.attempts.saturating_sub(summary.succeeded),
+        format_bytes(summary.total_bytes),
+        format_throughput(summary.bytes_per_sec),
+        format_duration_ms(summary.p50_duration_ms),
+        format_duration_ms(summary.p95_duration_ms),
+    );
+    truncate_to_width(&row, width)
+}
+
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = UNITS[0];
+    for next_unit in &UNITS[1..] {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next_unit;
+    }
+    format!("{value:.1} {unit}")
+}
+
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+fn format_throughput(bytes_per_sec: Option<f64>) -> String {
+    match bytes_per_sec {
+        Some(rate) if rate.is_finite() && rate >= 0.0 => {
+            format!("{}/s", format_bytes(rate.round() as u64))
+        }
+        _ => "n/a".to_string(),
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn format_duration_ms(duration_ms: Option<u64>) -> String {
+    match duration_ms {
+        Some(ms) if ms < 1_000 => format!("{ms}ms"),
+        Some(ms) => format!("{:.1}s", ms as f64 / 1_000.0),
+        None => "n/a".to_string(),
+    }
+}
+
+/// Summary printed by `downloader resolve`: resolved vs unresolvable inputs.
+pub(crate) fn resolve_only_summary_lines(
+    parsed: usize,
+    enqueued: usize,
+    already_downloaded: usize,
+    unresolvable_auth: usize,
+    unresolvable_other: usize,
+    enqueue_failed: usize,
+    pending_in_queue: usize,
+) -> Vec<String> {
+    let mut lines = vec![format!(
+        "Resolved {enqueued} of {parsed} inputs; {pending_in_queue} items pending in queue"
+    )];
+    let unresolvable = unresolvable_auth + unresolvable_other;
+    if unresolvable > 0 {
+        lines.push(format!(
+            "  Unresolvable: {unresolvable} ({unresolvable_auth} need authentication)"
+        ));
+    }
+    if already_downloaded > 0 {
+        lines.push(format!("  Already downloaded: {already_downloaded}"));
+    }
+    if enqueue_failed > 0 {
+        lines.push(format!("  Failed to enqueue: {enqueue_failed}"));
+    }
+    lines.push("Run `downloader` in the same output directory to download them.".to_string());
+    lines
+}
+
 pub(crate) fn render_failure_summary_lines(failed_reasons: &[&str], width: usize) -> Vec<String> {
     use std::collections::BTreeMap;
 
@@ -244,6 +449,8 @@ pub(crate) fn render_failure_summary_lines(failed_reasons: &[&str], width: usize
     }
 
     let mut grouped: BTreeMap<failure::FailureCategory, usize> = BTreeMap::new();
+    let mut kinds: BTreeMap<failure::FailureCategory, BTreeMap<&'static str, usize>> =
+        BTreeMap::new();
     let mut auth_domains: BTreeMap<String, usize> = BTreeMap::new();
 
     for reason in failed_reasons {
@@ -252,6 +459,11 @@ pub(crate) fn render_failure_summary_lines(failed_reasons: &[&str], width: usize
             .entry(descriptor.category)
             .and_modify(|count| *count += 1)
             .or_insert(1);
+        *kinds
+            .entry(descriptor.category)
+            .or_default()
+            .entry(descriptor.what)
+            .or_insert(0) += 1;
 
         if descriptor.category == failure::FailureCategory::Auth
             && let Some(domain) = failure::extract_auth_domain(reason)
@@ -269,6 +481,16 @@ pub(crate) fn render_failure_summary_lines(failed_reasons: &[&str], width: usize
             &format!("  What: {}", descriptor.what),
             width,
         ));
+        if let Some(category_kinds) = kinds.get(category) {
+            let breakdown: Vec<String> = category_kinds
+                .iter()
+                .map(|(what, count)| format!("{what} ({count})"))
+                .collect();
+            lines.push(truncate_to_width(
+                &format!("  Kinds: {}", breakdown.join(", ")),
+                width,
+            ));
+        }
 
         let why_detail = if *category == failure::FailureCategory::Auth && !auth_domains.is_empty()
         {
@@ -319,6 +541,90 @@ pub(crate) fn download_log_source(attempt: &DownloadAttempt) -> &str {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_resolve_only_summary_lines_report_unresolvable_counts() {
+        let lines = resolve_only_summary_lines(10, 6, 1, 2, 1, 0, 9);
+        assert_eq!(
+            lines[0],
+            "Resolved 6 of 10 inputs; 9 items pending in queue"
+        );
+        assert_eq!(lines[1], "  Unresolvable: 3 (2 need authentication)");
+        assert_eq!(lines[2], "  Already downloaded: 1");
+        assert_eq!(lines.len(), 4);
+    }
+
+    #[test]
+    fn test_resolve_only_summary_lines_omit_zero_counts() {
+        let lines = resolve_only_summary_lines(2, 2, 0, 0, 0, 0, 2);
+        assert_eq!(lines.len(), 2);
+    }
+
+    #[test]
+    fn test_format_bytes_units() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MiB");
+    }
+
+    #[test]
+    fn test_capped_summary_line_reports_processed_of_pending() {
+        assert_eq!(
+            capped_summary_line(5, 120),
+            "Processed 5 of 120 pending (capped); run again to continue with the rest"
+        );
+    }
+
+    #[test]
+    fn test_budget_exhausted_summary_line_reports_remaining_bytes() {
+        assert_eq!(
+            budget_exhausted_summary_line(512),
+            "Budget exhausted, 512 bytes remaining in the 30-day window; the rest stay pending (check with `downloader budget status`)"
+        );
+    }
+
+    #[test]
+    fn test_throughput_summary_line_mentions_slowest_host() {
+        let summary = DownloadStatsSummary {
+            overall: downloader_core::ThroughputSummary {
+                attempts: 2,
+                succeeded: 2,
+                total_bytes: 2048,
+                bytes_per_sec: Some(1024.0),
+                p50_duration_ms: Some(900),
+                p95_duration_ms: Some(1_500),
+            },
+            slowest_host: Some(DomainStats {
+                domain: "slow.example.org".to_string(),
+                summary: downloader_core::ThroughputSummary {
+                    bytes_per_sec: Some(512.0),
+                    ..downloader_core::ThroughputSummary::default()
+                },
+            }),
+        };
+        assert_eq!(
+            throughput_summary_line(&summary).as_deref(),
+            Some(
+                "Transferred 2.0 KiB at 1.0 KiB/s; per-item p50 900ms, p95 1.5s; slowest host slow.example.org (512 B/s)"
+            )
+        );
+        assert!(throughput_summary_line(&DownloadStatsSummary::default()).is_none());
+    }
+
+    #[test]
+    fn test_render_domain_stats_row_handles_missing_throughput() {
+        let stats = DomainStats {
+            domain: "example.org".to_string(),
+            summary: downloader_core::ThroughputSummary {
+                attempts: 3,
+                succeeded: 2,
+                ..downloader_core::ThroughputSummary::default()
+            },
+        };
+        let row = render_domain_stats_row(&stats, 200);
+        assert!(row.starts_with("example.org"));
+        assert!(row.contains("n/a"));
+    }
+
     #[test]
     fn test_terminal_width_returns_sensible_value() {
         let w = terminal_width();
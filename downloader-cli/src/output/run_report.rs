@@ -0,0 +1,128 @@
+//! `--json` run report: one JSON document on stdout describing the whole run.
+
+use anyhow::Result;
+use downloader_core::{DownloadAttempt, DownloadAttemptStatus};
+use serde::Serialize;
+
+/// Whole-run result printed by `downloader --json`.
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct RunReport {
+    pub completed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    pub items: Vec<RunReportItem>,
+}
+
+/// One history row written during the run.
+#[derive(Debug, Serialize)]
+pub(crate) struct RunReportItem {
+    pub id: i64,
+    pub input: String,
+    pub url: String,
+    pub status: String,
+    pub saved_path: Option<String>,
+    pub error: Option<String>,
+    pub error_type: Option<String>,
+}
+
+impl RunReport {
+    /// Builds the report from engine counters and the run's history rows.
+    ///
+    /// `completed`/`failed` come from the engine so they match the exit code;
+    /// `skipped` counts duplicate-skip rows, which the engine never sees.
+    pub(crate) fn new(completed: usize, failed: usize, attempts: &[DownloadAttempt]) -> Self {
+        let items: Vec<RunReportItem> = attempts
+            .iter()
+            .map(|attempt| RunReportItem {
+                id: attempt.id,
+                input: attempt
+                    .original_input
+                    .clone()
+                    .unwrap_or_else(|| attempt.url.clone()),
+                url: attempt.url.clone(),
+                status: attempt.status().as_str().to_string(),
+                saved_path: attempt.file_path.clone(),
+                error: attempt.error_message.clone(),
+                error_type: attempt.error_type.clone(),
+            })
+            .collect();
+        let skipped = attempts
+            .iter()
+            .filter(|attempt| attempt.status() == DownloadAttemptStatus::Skipped)
+            .count();
+        Self {
+            completed,
+            failed,
+            skipped,
+            items,
+        }
+    }
+
+    /// Writes the report to stdout as pretty-printed JSON.
+    pub(crate) fn print(&self) -> Result<()> {
+        println!("{}", serde_json::to_string_pretty(self)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn attempt(id: i64, status: &str) -> DownloadAttempt {
+        DownloadAttempt {
+            id,
+            url: format!("https://example.com/{id}.pdf"),
+            status_str: status.to_string(),
+            file_path: (status == "success").then(|| format!("/tmp/{id}.pdf")),
+            title: None,
+            authors: None,
+            doi: None,
+            parse_confidence: None,
+            parse_confidence_factors: None,
+            project: None,
+            started_at: "2026-10-16 12:00:00".to_string(),
+            error_message: (status == "failed").then(|| "HTTP 404".to_string()),
+            error_type: (status == "failed").then(|| "not_found".to_string()),
+            retry_count: 0,
+            last_retry_at: None,
+            original_input: (id == 1).then(|| "10.1234/example".to_string()),
+            http_status: None,
+            duration_ms: None,
+            resolver_path: None,
+            input_source_file: None,
+        }
+    }
+
+    #[test]
+    fn test_run_report_serializes_expected_shape() {
+        let attempts = vec![
+            attempt(1, "success"),
+            attempt(2, "failed"),
+            attempt(3, "skipped"),
+        ];
+        let report = RunReport::new(1, 1, &attempts);
+        let json: serde_json::Value = serde_json::to_value(&report).unwrap();
+
+        assert_eq!(json["completed"], 1);
+        assert_eq!(json["failed"], 1);
+        assert_eq!(json["skipped"], 1);
+        assert_eq!(json["items"].as_array().unwrap().len(), 3);
+        assert_eq!(json["items"][0]["input"], "10.1234/example");
+        assert_eq!(json["items"][0]["saved_path"], "/tmp/1.pdf");
+        assert_eq!(json["items"][1]["input"], "https://example.com/2.pdf");
+        assert_eq!(json["items"][1]["error_type"], "not_found");
+        assert_eq!(json["items"][2]["status"], "skipped");
+        assert!(json["items"][2]["error"].is_null());
+    }
+
+    #[test]
+    fn test_empty_run_report_has_all_fields() {
+        let json = serde_json::to_value(RunReport::default()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"completed": 0, "failed": 0, "skipped": 0, "items": []})
+        );
+    }
+}
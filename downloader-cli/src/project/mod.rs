@@ -4,11 +4,12 @@
 //! [`downloader_core::project`]. This module re-exports those items and adds
 //! the CLI-specific `discover_history_db_paths` helper.
 
+use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 
 use anyhow::Result;
-use tracing::warn;
+use tracing::{debug, warn};
 
 // ---------------------------------------------------------------------------
 // Re-exports from core (production code uses these)
@@ -16,7 +17,8 @@ use tracing::warn;
 
 pub use downloader_core::project::{
     append_project_download_log, append_project_index, generate_sidecars_for_completed,
-    project_history_key, resolve_project_output_dir,
+    project_history_key, query_session_attempts, reindex_project, resolve_project_output_dir,
+    write_project_manifest, write_project_topics_index,
 };
 
 // ---------------------------------------------------------------------------
@@ -33,16 +35,36 @@ pub use downloader_core::project::{
 // CLI-specific: history database discovery
 // ---------------------------------------------------------------------------
 
+/// Deepest directory level below the output root searched for history databases.
+const MAX_DISCOVERY_DEPTH: usize = 32;
+
 /// Discovers all `.downloader/queue.db` paths under `base_output_dir` (recursive).
-pub fn discover_history_db_paths(base_output_dir: &Path) -> Result<Vec<PathBuf>> {
+///
+/// Symlinked directories are skipped unless `follow_symlinks` is set. Each
+/// directory is visited once by canonical path, so symlink loops terminate,
+/// and traversal stops [`MAX_DISCOVERY_DEPTH`] levels below the root.
+pub fn discover_history_db_paths(
+    base_output_dir: &Path,
+    follow_symlinks: bool,
+) -> Result<Vec<PathBuf>> {
     if !base_output_dir.exists() {
         return Ok(Vec::new());
     }
 
     let mut db_paths = Vec::new();
-    let mut stack = vec![base_output_dir.to_path_buf()];
+    let mut visited = HashSet::new();
+    let mut stack = vec![(base_output_dir.to_path_buf(), 0usize)];
+
+    while let Some((dir, depth)) = stack.pop() {
+        let canonical = fs::canonicalize(&dir).unwrap_or_else(|_| dir.clone());
+        if !visited.insert(canonical) {
+            debug!(
+                path = %dir.display(),
+                "Skipping already visited directory (symlink cycle) while discovering history databases"
+            );
+            continue;
+        }
 
-    while let Some(dir) = stack.pop() {
         let entries = match fs::read_dir(&dir) {
             Ok(entries) => entries,
             Err(error) => {
@@ -74,11 +96,23 @@ pub fn discover_history_db_paths(base_output_dir: &Path) -> Result<Vec<PathBuf>>
                     continue;
                 }
             };
-            if !file_type.is_dir() {
+
+            let path = entry.path();
+            if file_type.is_symlink() {
+                if !path.is_dir() {
+                    continue;
+                }
+                if !follow_symlinks {
+                    debug!(
+                        path = %path.display(),
+                        "Skipping symlinked directory (pass --follow-symlinks to search it)"
+                    );
+                    continue;
+                }
+            } else if !file_type.is_dir() {
                 continue;
             }
 
-            let path = entry.path();
             if entry.file_name() == ".downloader" {
                 let db_path = path.join("queue.db");
                 if db_path.exists() {
@@ -87,7 +121,15 @@ pub fn discover_history_db_paths(base_output_dir: &Path) -> Result<Vec<PathBuf>>
                 continue;
             }
 
-            stack.push(path);
+            if depth >= MAX_DISCOVERY_DEPTH {
+                warn!(
+                    path = %path.display(),
+                    max_depth = MAX_DISCOVERY_DEPTH,
+                    "Not descending further while discovering history databases"
+                );
+                continue;
+            }
+            stack.push((path, depth + 1));
         }
     }
 
@@ -95,3 +137,14 @@ pub fn discover_history_db_paths(base_output_dir: &Path) -> Result<Vec<PathBuf>>
     db_paths.dedup();
     Ok(db_paths)
 }
+
+/// Labels a discovered history database by its project folder relative to
+/// `base_output_dir` (`.` for the root itself).
+pub fn history_project_label(base_output_dir: &Path, db_path: &Path) -> String {
+    let project_dir = db_path.parent().and_then(Path::parent).unwrap_or(db_path);
+    match project_dir.strip_prefix(base_output_dir) {
+        Ok(relative) if relative.as_os_str().is_empty() => ".".to_string(),
+        Ok(relative) => relative.display().to_string(),
+        Err(_) => project_dir.display().to_string(),
+    }
+}
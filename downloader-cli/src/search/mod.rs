@@ -24,12 +24,39 @@ pub struct RankedSearchResult {
     pub match_kind: SearchMatchKind,
     pub similarity: f64,
     pub matched_field: &'static str,
+    /// Project label of the history database the candidate came from, set by
+    /// [`rank_project_search_candidates`].
+    pub project: Option<String>,
 }
 
 /// Ranks candidates by best match across title, authors, and DOI.
 pub fn rank_search_candidates(
     query: &str,
     candidates: Vec<DownloadSearchCandidate>,
+) -> Vec<RankedSearchResult> {
+    rank_tagged_candidates(
+        query,
+        candidates.into_iter().map(|candidate| (None, candidate)),
+    )
+}
+
+/// Ranks `(project label, candidate)` pairs gathered from several history
+/// databases as one result set, keeping each result's project label.
+pub fn rank_project_search_candidates(
+    query: &str,
+    candidates: Vec<(String, DownloadSearchCandidate)>,
+) -> Vec<RankedSearchResult> {
+    rank_tagged_candidates(
+        query,
+        candidates
+            .into_iter()
+            .map(|(project, candidate)| (Some(project), candidate)),
+    )
+}
+
+fn rank_tagged_candidates(
+    query: &str,
+    candidates: impl Iterator<Item = (Option<String>, DownloadSearchCandidate)>,
 ) -> Vec<RankedSearchResult> {
     let query_norm = normalize_search_text(query);
     if query_norm.is_empty() {
@@ -37,8 +64,7 @@ pub fn rank_search_candidates(
     }
 
     let mut ranked: Vec<RankedSearchResult> = candidates
-        .into_iter()
-        .filter_map(|candidate| {
+        .filter_map(|(project, candidate)| {
             let mut best: Option<(SearchMatchKind, f64, &'static str)> = None;
             for (field_name, field_value) in [
                 ("title", candidate.title.as_deref()),
@@ -66,6 +92,7 @@ pub fn rank_search_candidates(
                     match_kind,
                     similarity,
                     matched_field,
+                    project,
                 },
             )
         })
@@ -183,18 +210,21 @@ mod tests {
             doi: None,
             started_at: String::new(),
             file_path: None,
+            resolver_path: None,
         };
         let exact = RankedSearchResult {
             candidate: base.clone(),
             match_kind: SearchMatchKind::Exact,
             similarity: 1.0,
             matched_field: "title",
+            project: None,
         };
         let fuzzy = RankedSearchResult {
             candidate: base,
             match_kind: SearchMatchKind::Fuzzy,
             similarity: 0.9,
             matched_field: "title",
+            project: None,
         };
         assert_eq!(
             compare_search_results(&exact, &fuzzy),
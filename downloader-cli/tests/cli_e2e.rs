@@ -71,6 +71,8 @@ fn seed_success_history_row_with_confidence(
             topics: None,
             parse_confidence,
             parse_confidence_factors,
+            resolver_path: None,
+            input_source_file: None,
         };
         queue.log_download_attempt(&attempt).await.unwrap();
     });
@@ -110,6 +112,8 @@ fn seed_search_history_row(
             topics: None,
             parse_confidence: None,
             parse_confidence_factors: None,
+            resolver_path: None,
+            input_source_file: None,
         };
         queue.log_download_attempt(&attempt).await.unwrap();
     });
@@ -149,6 +153,8 @@ fn seed_failed_history_row(
             parse_confidence_factors: Some(
                 r#"{"has_authors":false,"has_year":true,"has_title":false,"author_count":0}"#,
             ),
+            resolver_path: None,
+            input_source_file: None,
         };
         queue.log_download_attempt(&attempt).await.unwrap();
     });
@@ -430,6 +436,32 @@ fn test_binary_config_show_loads_home_fallback_file() {
         .stdout(predicate::str::contains("verbosity = quiet"));
 }
 
+/// Test that `config validate` lists every problem and exits non-zero.
+#[test]
+fn test_binary_config_validate_reports_problems_and_fails() {
+    let tempdir = TempDir::new().unwrap();
+    let config_home = tempdir.path().join("xdg-config");
+    write_downloader_config(&config_home, "concurrency = 0\nmax_redirect = 3\n");
+
+    let mut cmd = Command::cargo_bin("downloader").unwrap();
+    cmd.args(["config", "validate"])
+        .env("XDG_CONFIG_HOME", &config_home)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "Invalid config entry on line 1: `concurrency = 0`",
+        ))
+        .stdout(predicate::str::contains("Did you mean `max_redirects`?"));
+
+    write_downloader_config(&config_home, "concurrency = 4\n");
+    let mut cmd = Command::cargo_bin("downloader").unwrap();
+    cmd.args(["config", "validate"])
+        .env("XDG_CONFIG_HOME", &config_home)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("config is valid"));
+}
+
 /// Test that download mode picks output_dir from config defaults when CLI is unset.
 #[test]
 fn test_binary_download_uses_config_output_dir_default() {
@@ -11,6 +11,13 @@ use tempfile::TempDir;
 use wiremock::matchers::{method, path};
 use wiremock::{Mock, ResponseTemplate};
 
+/// A body that passes the default `--min-file-size` PDF guard.
+fn pdf_body() -> Vec<u8> {
+    let mut body = b"%PDF-1.4\n".to_vec();
+    body.resize(2048, b'0');
+    body
+}
+
 #[tokio::test]
 async fn test_binary_exit_code_partial_success_is_one() {
     let Some(mock_server) = start_mock_server_or_skip().await else {
@@ -21,7 +28,7 @@ async fn test_binary_exit_code_partial_success_is_one() {
         .and(path("/ok"))
         .respond_with(
             ResponseTemplate::new(200)
-                .set_body_bytes(b"PDF")
+                .set_body_bytes(pdf_body())
                 .insert_header("Content-Type", "application/pdf"),
         )
         .mount(&mock_server)
@@ -64,7 +71,7 @@ async fn test_binary_exit_code_partial_success_stdout_contains_failure_summary()
         .and(path("/ok"))
         .respond_with(
             ResponseTemplate::new(200)
-                .set_body_bytes(b"PDF")
+                .set_body_bytes(pdf_body())
                 .insert_header("Content-Type", "application/pdf"),
         )
         .mount(&mock_server)
@@ -102,3 +109,144 @@ async fn test_binary_exit_code_partial_success_stdout_contains_failure_summary()
         stdout.as_ref()
     );
 }
+
+/// `--report-dir` persists a failure breakdown, and an unwritable report dir
+/// must not change the partial-success exit code.
+#[tokio::test]
+async fn test_binary_report_dir_writes_report_without_changing_exit_code() {
+    let Some(mock_server) = start_mock_server_or_skip().await else {
+        return socket_skip_return();
+    };
+
+    Mock::given(method("GET"))
+        .and(path("/ok"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_bytes(pdf_body())
+                .insert_header("Content-Type", "application/pdf"),
+        )
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/fail"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+
+    let tempdir = TempDir::new().unwrap();
+    let config_home = tempdir.path().join("xdg-config");
+    std::fs::create_dir_all(config_home.join("downloader")).unwrap();
+    let report_dir = tempdir.path().join("reports");
+
+    let mut cmd = Command::cargo_bin("downloader").unwrap();
+    cmd.arg("--output-dir")
+        .arg(tempdir.path().join("out"))
+        .arg("--report-dir")
+        .arg(&report_dir)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("-q")
+        .arg(format!("{}/ok", mock_server.uri()))
+        .arg(format!("{}/fail", mock_server.uri()));
+    assert_eq!(cmd.assert().failure().get_output().status.code(), Some(1));
+
+    let reports: Vec<_> = std::fs::read_dir(&report_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    assert_eq!(reports.len(), 1, "one report per run: {reports:?}");
+    let name = reports[0]
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .into_owned();
+    assert!(
+        name.starts_with("run-report-") && name.ends_with(".json"),
+        "{name}"
+    );
+    let report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&reports[0]).unwrap()).unwrap();
+    assert_eq!(report["stats"]["completed"], 1);
+    assert_eq!(report["stats"]["failed"], 1);
+    assert_eq!(report["failure_categories"]["input_source"], 1);
+    assert_eq!(report["resolver_usage"]["direct"], 2);
+    assert_eq!(report["completed_ids"].as_array().unwrap().len(), 1);
+    assert_eq!(report["failed_ids"].as_array().unwrap().len(), 1);
+
+    // A regular file where the directory should be makes the write fail.
+    let blocked = tempdir.path().join("not-a-dir");
+    std::fs::write(&blocked, b"").unwrap();
+    let mut cmd = Command::cargo_bin("downloader").unwrap();
+    cmd.arg("--output-dir")
+        .arg(tempdir.path().join("out-blocked"))
+        .arg("--report-dir")
+        .arg(&blocked)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("-q")
+        .arg(format!("{}/ok", mock_server.uri()))
+        .arg(format!("{}/fail", mock_server.uri()));
+    assert_eq!(cmd.assert().failure().get_output().status.code(), Some(1));
+}
+
+/// `--dry-run --check` probes each resolved URL with HEAD and summarizes the
+/// outcomes without downloading anything.
+#[tokio::test]
+async fn test_binary_dry_run_check_reports_head_status_per_url() {
+    let Some(mock_server) = start_mock_server_or_skip().await else {
+        return socket_skip_return();
+    };
+
+    Mock::given(method("HEAD"))
+        .and(path("/ok"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/pdf")
+                .insert_header("Content-Length", "2048"),
+        )
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("HEAD"))
+        .and(path("/fail"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+
+    // The only GETs left would be downloads once the landing-page resolver is off.
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(b"PDF"))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let tempdir = TempDir::new().unwrap();
+    let config_home = tempdir.path().join("xdg-config");
+    std::fs::create_dir_all(config_home.join("downloader")).unwrap();
+
+    let mut cmd = Command::cargo_bin("downloader").unwrap();
+    cmd.arg("--output-dir")
+        .arg(tempdir.path().join("out"))
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("--dry-run")
+        .arg("--check")
+        .arg("--disable-resolver")
+        .arg("citation_meta")
+        .arg(format!("{}/ok", mock_server.uri()))
+        .arg(format!("{}/fail", mock_server.uri()));
+
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let stdout = String::from_utf8_lossy(&output);
+    assert!(
+        stdout.contains("check: ok HTTP 200 via HEAD, application/pdf, 2048 bytes"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("check: not_found HTTP 404 via HEAD"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("Check summary: 1 not_found, 1 ok"),
+        "{stdout}"
+    );
+    assert!(!tempdir.path().join("out").join("ok").exists());
+}
@@ -36,6 +36,8 @@ fn seed_log_history(db_path: &std::path::Path, url: &str, title: &str) {
             topics: None,
             parse_confidence: None,
             parse_confidence_factors: None,
+            resolver_path: None,
+            input_source_file: None,
         };
         queue.log_download_attempt(&attempt).await.unwrap();
     });
@@ -0,0 +1,10 @@
+-- Migration: Flag permanently failed queue rows as dead-lettered.
+--
+-- Dead-lettered rows keep status 'failed' but are set aside for explicit
+-- triage: only an explicit requeue returns them to 'pending'.
+
+ALTER TABLE queue
+ADD COLUMN dead_letter INTEGER NOT NULL DEFAULT 0;
+
+CREATE INDEX IF NOT EXISTS idx_queue_project_dead_letter
+ON queue(project, dead_letter);
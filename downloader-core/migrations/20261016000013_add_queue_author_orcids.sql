@@ -0,0 +1,7 @@
+-- Migration: Store per-author ORCID iDs captured at enqueue time.
+--
+-- Semicolon-separated list aligned positionally with meta_authors; an empty
+-- slot means that author has no ORCID. Used for sidecar author identifiers.
+
+ALTER TABLE queue
+ADD COLUMN meta_author_orcids TEXT;
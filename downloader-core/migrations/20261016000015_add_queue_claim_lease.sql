@@ -0,0 +1,11 @@
+-- Migration: Stamp a lease on claimed queue rows.
+--
+-- `dequeue` records which process claimed a row and when, so startup crash
+-- recovery only reclaims rows whose lease is stale instead of stealing work
+-- from another live process sharing the same database.
+
+ALTER TABLE queue
+ADD COLUMN claimed_by TEXT;
+
+ALTER TABLE queue
+ADD COLUMN claimed_at TEXT;
@@ -0,0 +1,84 @@
+-- Migration: Allow the finer-grained download_log error_type values
+-- (disk_full, too_many_redirects, tls, dns, connection_reset, timeout,
+-- robots_disallowed, too_large, checksum_mismatch, unexpected_content_type).
+--
+-- SQLite cannot alter a CHECK constraint in place, so the table is rebuilt
+-- with the widened constraint and its rows and indexes carried over.
+
+CREATE TABLE download_log_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    url TEXT NOT NULL,
+    final_url TEXT,
+    status TEXT NOT NULL CHECK (status IN ('success', 'failed', 'skipped')),
+    file_path TEXT,
+    file_size INTEGER,
+    content_type TEXT,
+    started_at TEXT NOT NULL,
+    completed_at TEXT,
+    error_message TEXT,
+    project TEXT,
+    http_status INTEGER,
+    duration_ms INTEGER,
+    title TEXT,
+    authors TEXT,
+    doi TEXT,
+    error_type TEXT CHECK (
+        error_type IN (
+            'network', 'auth', 'not_found', 'parse_error', 'disk_full',
+            'too_many_redirects', 'tls', 'dns', 'connection_reset', 'timeout',
+            'robots_disallowed', 'too_large', 'checksum_mismatch',
+            'unexpected_content_type'
+        )
+    ),
+    retry_count INTEGER NOT NULL DEFAULT 0,
+    last_retry_at TEXT,
+    original_input TEXT,
+    topics TEXT, -- JSON array of topic strings
+    parse_confidence TEXT,
+    parse_confidence_factors TEXT
+);
+
+INSERT INTO download_log_new (
+    id, url, final_url, status, file_path, file_size, content_type, started_at,
+    completed_at, error_message, project, http_status, duration_ms, title, authors,
+    doi, error_type, retry_count, last_retry_at, original_input, topics,
+    parse_confidence, parse_confidence_factors
+)
+SELECT
+    id, url, final_url, status, file_path, file_size, content_type, started_at,
+    completed_at, error_message, project, http_status, duration_ms, title, authors,
+    doi, error_type, retry_count, last_retry_at, original_input, topics,
+    parse_confidence, parse_confidence_factors
+FROM download_log;
+
+DROP TABLE download_log;
+
+ALTER TABLE download_log_new RENAME TO download_log;
+
+CREATE INDEX IF NOT EXISTS idx_download_log_project ON download_log(project);
+
+CREATE INDEX IF NOT EXISTS idx_download_log_started_at ON download_log(started_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_download_log_url ON download_log(url);
+
+CREATE INDEX IF NOT EXISTS idx_download_log_status ON download_log(status);
+
+CREATE INDEX IF NOT EXISTS idx_download_log_project_status_started_at
+ON download_log(project, status, started_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_download_log_doi ON download_log(doi);
+
+CREATE INDEX IF NOT EXISTS idx_download_log_error_type_started_at
+ON download_log(error_type, started_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_download_log_status_retry
+ON download_log(status, retry_count DESC, started_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_download_log_topics ON download_log(topics);
+
+CREATE INDEX IF NOT EXISTS idx_download_log_parse_confidence_started_at
+ON download_log(parse_confidence, started_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_download_log_uncertain_started_at
+ON download_log(started_at DESC)
+WHERE parse_confidence = 'low';
@@ -0,0 +1,7 @@
+-- Migration: Store an optional per-item read timeout override.
+--
+-- Seconds; NULL means the client's default read timeout. Set for large files
+-- that legitimately take longer than the default to download.
+
+ALTER TABLE queue
+ADD COLUMN read_timeout_secs INTEGER;
@@ -0,0 +1,10 @@
+-- Migration: Record which resolvers produced each queued URL and history row.
+--
+-- `->`-joined resolver names in the order they fired (e.g. `crossref->direct`).
+-- NULL for rows written before this column existed and for unresolved inputs.
+
+ALTER TABLE queue
+ADD COLUMN resolver_path TEXT;
+
+ALTER TABLE download_log
+ADD COLUMN resolver_path TEXT;
@@ -0,0 +1,7 @@
+-- Migration: Store the abstract captured at enqueue time.
+--
+-- Plain text (markup such as Crossref JATS is stripped by resolvers). Used for
+-- the Schema.org `abstract` in JSON-LD sidecars; NULL when no resolver found one.
+
+ALTER TABLE queue
+ADD COLUMN meta_abstract TEXT;
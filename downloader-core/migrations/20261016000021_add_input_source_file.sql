@@ -0,0 +1,10 @@
+-- Migration: Record which input file each queued URL and history row came from.
+--
+-- The path as given on the command line (`reading/ml.bib`), or `stdin`.
+-- NULL for inline URL/DOI arguments and for rows written before this column existed.
+
+ALTER TABLE queue
+ADD COLUMN input_source_file TEXT;
+
+ALTER TABLE download_log
+ADD COLUMN input_source_file TEXT;
@@ -0,0 +1,9 @@
+-- Migration: Daily totals of downloaded bytes for the rolling byte budget.
+--
+-- One row per UTC day (`YYYY-MM-DD`); each completed download adds its size.
+-- Days older than the budget window are pruned as new bytes are recorded.
+
+CREATE TABLE IF NOT EXISTS byte_usage (
+    day TEXT PRIMARY KEY NOT NULL,
+    bytes INTEGER NOT NULL DEFAULT 0
+);
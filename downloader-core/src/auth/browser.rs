@@ -0,0 +1,282 @@
+//! Cookie import straight from a browser profile's cookie database.
+//!
+//! Firefox keeps cookies in `cookies.sqlite` (`moz_cookies`) and Chromium-based
+//! browsers in `Cookies` (`cookies`, under `Network/` in recent versions). Both
+//! are `SQLite` files the browser keeps open, so they are opened read-only and
+//! immutable: no lock is taken, and writes still in the browser's WAL are not
+//! seen. Closing the browser first gives the freshest cookies.
+//!
+//! Chromium encrypts cookie values with a key held by the OS keychain. Those
+//! rows cannot be decrypted here and are skipped with a warning; only rows that
+//! carry a plain `value` are imported.
+
+use std::path::{Path, PathBuf};
+
+use sqlx::sqlite::SqliteConnectOptions;
+use sqlx::{Connection, FromRow, SqliteConnection};
+use tracing::{debug, instrument};
+
+use super::CookieLine;
+use super::capture::{
+    CaptureError, CapturedCookieFormat, CapturedCookies, ReplayHeaders, unix_now, validate_cookies,
+};
+
+/// Seconds between 1601-01-01 (the Chromium/Windows epoch) and the Unix epoch.
+const CHROMIUM_EPOCH_OFFSET_SECS: i64 = 11_644_473_600;
+
+/// Firefox expiry values above this are milliseconds, not seconds.
+const FIREFOX_MILLIS_EXPIRY_THRESHOLD: i64 = 100_000_000_000;
+
+/// Browser whose on-disk cookie database should be read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrowserCookieStore {
+    /// Firefox `cookies.sqlite`.
+    Firefox,
+    /// Chrome, Chromium, Edge, or Brave `Cookies`.
+    Chrome,
+}
+
+#[derive(FromRow)]
+struct FirefoxCookieRow {
+    host: String,
+    path: String,
+    is_secure: i64,
+    expiry: i64,
+    name: String,
+    value: String,
+}
+
+#[derive(FromRow)]
+struct ChromeCookieRow {
+    host_key: String,
+    path: String,
+    is_secure: i64,
+    expires_utc: i64,
+    name: String,
+    value: String,
+    encrypted_len: i64,
+}
+
+/// Reads and validates cookies from a browser profile directory or cookie database file.
+///
+/// `profile` may be the profile directory or the database file itself.
+/// Expired and empty cookies are dropped with warnings, as for pasted exports.
+///
+/// # Errors
+///
+/// Returns [`CaptureError::BrowserStore`] when the database is missing or
+/// cannot be read, and [`CaptureError::NoValidCookies`] when no usable cookie
+/// remains (for Chromium, typically because every value is encrypted).
+#[instrument(level = "debug", skip(profile), fields(profile = %profile.display()))]
+pub async fn read_browser_cookies(
+    store: BrowserCookieStore,
+    profile: &Path,
+) -> Result<CapturedCookies, CaptureError> {
+    let db_path = cookie_db_path(store, profile);
+    if !db_path.is_file() {
+        return Err(CaptureError::browser_store(
+            &db_path,
+            "file not found; pass the browser profile directory or its cookie database",
+        ));
+    }
+
+    let options = SqliteConnectOptions::new()
+        .filename(&db_path)
+        .read_only(true)
+        .immutable(true);
+    let mut conn = SqliteConnection::connect_with(&options)
+        .await
+        .map_err(|error| CaptureError::browser_store(&db_path, &error.to_string()))?;
+
+    let (cookies, mut warnings, format) = match store {
+        BrowserCookieStore::Firefox => {
+            let rows: Vec<FirefoxCookieRow> = sqlx::query_as(
+                "SELECT host, path, isSecure AS is_secure, expiry, name, value FROM moz_cookies",
+            )
+            .fetch_all(&mut conn)
+            .await
+            .map_err(|error| CaptureError::browser_store(&db_path, &error.to_string()))?;
+            let cookies = rows.into_iter().map(firefox_cookie).collect();
+            (cookies, Vec::new(), CapturedCookieFormat::Firefox)
+        }
+        BrowserCookieStore::Chrome => {
+            let rows: Vec<ChromeCookieRow> = sqlx::query_as(
+                "SELECT host_key, path, is_secure, expires_utc, name, value,
+                        length(encrypted_value) AS encrypted_len
+                 FROM cookies",
+            )
+            .fetch_all(&mut conn)
+            .await
+            .map_err(|error| CaptureError::browser_store(&db_path, &error.to_string()))?;
+            let (cookies, warnings) = chrome_cookies(rows);
+            (cookies, warnings, CapturedCookieFormat::Chrome)
+        }
+    };
+    // Read-only connection; a failed close leaves nothing to clean up.
+    let _ = conn.close().await;
+
+    let (valid_cookies, validation_warnings) = validate_cookies(cookies, unix_now());
+    warnings.extend(validation_warnings);
+    debug!(
+        cookies = valid_cookies.len(),
+        "read browser cookie database"
+    );
+
+    if valid_cookies.is_empty() {
+        return Err(CaptureError::NoValidCookies);
+    }
+
+    Ok(CapturedCookies {
+        cookies: valid_cookies,
+        warnings,
+        format,
+        replay_headers: ReplayHeaders::default(),
+    })
+}
+
+fn cookie_db_path(store: BrowserCookieStore, profile: &Path) -> PathBuf {
+    if !profile.is_dir() {
+        return profile.to_path_buf();
+    }
+    match store {
+        BrowserCookieStore::Firefox => profile.join("cookies.sqlite"),
+        BrowserCookieStore::Chrome => {
+            let network = profile.join("Network").join("Cookies");
+            if network.is_file() {
+                network
+            } else {
+                profile.join("Cookies")
+            }
+        }
+    }
+}
+
+fn firefox_cookie(row: FirefoxCookieRow) -> CookieLine {
+    let expiry = if row.expiry > FIREFOX_MILLIS_EXPIRY_THRESHOLD {
+        row.expiry / 1000
+    } else {
+        row.expiry
+    };
+    CookieLine::new(
+        row.host.clone(),
+        row.host.starts_with('.'),
+        row.path,
+        row.is_secure != 0,
+        u64::try_from(expiry).unwrap_or(0),
+        row.name,
+        row.value,
+    )
+}
+
+fn chrome_cookies(rows: Vec<ChromeCookieRow>) -> (Vec<CookieLine>, Vec<String>) {
+    let mut cookies = Vec::with_capacity(rows.len());
+    let mut encrypted = 0usize;
+    for row in rows {
+        if row.value.is_empty() && row.encrypted_len > 0 {
+            encrypted += 1;
+            continue;
+        }
+        let expires = if row.expires_utc > 0 {
+            u64::try_from(row.expires_utc / 1_000_000 - CHROMIUM_EPOCH_OFFSET_SECS).unwrap_or(0)
+        } else {
+            0
+        };
+        cookies.push(CookieLine::new(
+            row.host_key.clone(),
+            row.host_key.starts_with('.'),
+            row.path,
+            row.is_secure != 0,
+            expires,
+            row.name,
+            row.value,
+        ));
+    }
+
+    let mut warnings = Vec::new();
+    if encrypted > 0 {
+        warnings.push(format!(
+            "skipped {encrypted} cookies whose values are encrypted by the OS keychain; \
+             export them with a cookies.txt extension and use `--from netscape` instead"
+        ));
+    }
+    (cookies, warnings)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    async fn create_db(path: &Path, statements: &[&str]) {
+        let options = SqliteConnectOptions::new()
+            .filename(path)
+            .create_if_missing(true);
+        let mut conn = SqliteConnection::connect_with(&options).await.unwrap();
+        for statement in statements {
+            sqlx::query(statement).execute(&mut conn).await.unwrap();
+        }
+        conn.close().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_read_browser_cookies_firefox_profile_dir() {
+        let profile = tempfile::tempdir().unwrap();
+        create_db(
+            &profile.path().join("cookies.sqlite"),
+            &[
+                "CREATE TABLE moz_cookies (host TEXT, path TEXT, isSecure INTEGER, expiry INTEGER, name TEXT, value TEXT)",
+                "INSERT INTO moz_cookies VALUES ('.jstor.org', '/', 1, 4102444800, 'sid', 'abc')",
+                "INSERT INTO moz_cookies VALUES ('www.sciencedirect.com', '/', 0, 4102444800000, 'token', 'xyz')",
+                "INSERT INTO moz_cookies VALUES ('old.example.com', '/', 0, 1, 'gone', 'v')",
+            ],
+        )
+        .await;
+
+        let parsed = read_browser_cookies(BrowserCookieStore::Firefox, profile.path())
+            .await
+            .unwrap();
+
+        assert_eq!(parsed.format, CapturedCookieFormat::Firefox);
+        assert_eq!(parsed.cookies.len(), 2);
+        assert!(parsed.cookies[0].tailmatch && parsed.cookies[0].secure);
+        assert_eq!(
+            parsed.cookies[1].expires, 4_102_444_800,
+            "millis normalized"
+        );
+        assert!(parsed.warnings.iter().any(|w| w.contains("expired")));
+    }
+
+    #[tokio::test]
+    async fn test_read_browser_cookies_chrome_skips_encrypted_values() {
+        let profile = tempfile::tempdir().unwrap();
+        std::fs::create_dir(profile.path().join("Network")).unwrap();
+        create_db(
+            &profile.path().join("Network").join("Cookies"),
+            &[
+                "CREATE TABLE cookies (host_key TEXT, path TEXT, is_secure INTEGER, expires_utc INTEGER, name TEXT, value TEXT, encrypted_value BLOB)",
+                "INSERT INTO cookies VALUES ('.jstor.org', '/', 1, 15746918400000000, 'sid', 'plain', x'')",
+                "INSERT INTO cookies VALUES ('.jstor.org', '/', 1, 0, 'locked', '', x'763130ff')",
+            ],
+        )
+        .await;
+
+        let parsed = read_browser_cookies(BrowserCookieStore::Chrome, profile.path())
+            .await
+            .unwrap();
+
+        assert_eq!(parsed.format, CapturedCookieFormat::Chrome);
+        assert_eq!(parsed.cookies.len(), 1);
+        assert_eq!(parsed.cookies[0].value(), "plain");
+        assert_eq!(parsed.cookies[0].expires, 4_102_444_800);
+        assert!(parsed.warnings[0].contains("skipped 1 cookies"));
+    }
+
+    #[tokio::test]
+    async fn test_read_browser_cookies_missing_database_is_browser_store_error() {
+        let profile = tempfile::tempdir().unwrap();
+        let error = read_browser_cookies(BrowserCookieStore::Firefox, profile.path())
+            .await
+            .unwrap_err();
+        assert!(matches!(error, CaptureError::BrowserStore { .. }));
+    }
+}
@@ -3,9 +3,12 @@
 //! Supports common browser extension export formats:
 //! - Netscape HTTP Cookie File format
 //! - JSON cookie exports (array or `{ "cookies": [...] }`)
+//! - HAR archives saved from browser dev tools (`{ "log": { "entries": [...] } }`)
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
 use std::io::BufReader;
+use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use serde::Deserialize;
@@ -20,6 +23,12 @@ pub enum CapturedCookieFormat {
     Netscape,
     /// JSON export format.
     Json,
+    /// HTTP Archive (HAR) captured from browser dev tools.
+    Har,
+    /// Firefox profile cookie database.
+    Firefox,
+    /// Chromium-family profile cookie database.
+    Chrome,
 }
 
 /// Parsed and validated cookies captured from user input.
@@ -31,6 +40,57 @@ pub struct CapturedCookies {
     pub warnings: Vec<String>,
     /// Input format that was parsed.
     pub format: CapturedCookieFormat,
+    /// Per-host request headers worth replaying (HAR input only; empty otherwise).
+    pub replay_headers: ReplayHeaders,
+}
+
+/// Request headers captured per host so a client can replay them.
+///
+/// Holds `Authorization` and custom `X-*` headers seen in a HAR capture,
+/// keyed by lowercase host and header name. Values are sensitive and are
+/// redacted from `Debug` output.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct ReplayHeaders {
+    by_host: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl ReplayHeaders {
+    /// Returns true when no headers were captured.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.by_host.is_empty()
+    }
+
+    /// Returns the hosts that have captured headers, in sorted order.
+    pub fn hosts(&self) -> impl Iterator<Item = &str> {
+        self.by_host.keys().map(String::as_str)
+    }
+
+    /// Returns the headers captured for `host` (name → value).
+    #[must_use]
+    pub fn for_host(&self, host: &str) -> Option<&BTreeMap<String, String>> {
+        self.by_host.get(&host.to_ascii_lowercase())
+    }
+
+    fn insert(&mut self, host: &str, name: &str, value: &str) {
+        self.by_host
+            .entry(host.to_ascii_lowercase())
+            .or_default()
+            .insert(name.to_ascii_lowercase(), value.to_string());
+    }
+}
+
+// Custom Debug impl that redacts header values.
+impl fmt::Debug for ReplayHeaders {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.by_host
+                    .iter()
+                    .map(|(host, headers)| (host, headers.keys().collect::<Vec<_>>())),
+            )
+            .finish()
+    }
 }
 
 /// Errors that can occur while parsing browser cookie capture input.
@@ -48,6 +108,26 @@ pub enum CaptureError {
     /// No valid cookies remained after validation.
     #[error("no valid cookies found after validation")]
     NoValidCookies,
+    /// JSON parsed but is not a HAR archive.
+    #[error("invalid HAR file: {0}")]
+    InvalidHar(String),
+    /// A browser cookie database could not be opened or read.
+    #[error("cannot read browser cookie database {}: {reason}", path.display())]
+    BrowserStore {
+        /// Database file that was tried.
+        path: PathBuf,
+        /// Underlying failure.
+        reason: String,
+    },
+}
+
+impl CaptureError {
+    pub(super) fn browser_store(path: &Path, reason: &str) -> Self {
+        Self::BrowserStore {
+            path: path.to_path_buf(),
+            reason: reason.to_string(),
+        }
+    }
 }
 
 /// Parse and validate cookie capture input from either Netscape or JSON format.
@@ -63,6 +143,10 @@ pub fn parse_captured_cookies(input: &str) -> Result<CapturedCookies, CaptureErr
         return Err(CaptureError::EmptyInput);
     }
 
+    if looks_like_har(trimmed) {
+        return parse_har_cookies(trimmed);
+    }
+
     let (cookies, mut warnings, format) = if looks_like_json(trimmed) {
         let (cookies, warnings) = parse_json_cookies(trimmed)?;
         (cookies, warnings, CapturedCookieFormat::Json)
@@ -87,6 +171,122 @@ pub fn parse_captured_cookies(input: &str) -> Result<CapturedCookies, CaptureErr
         cookies: valid_cookies,
         warnings,
         format,
+        replay_headers: ReplayHeaders::default(),
+    })
+}
+
+/// Extracts cookies and replayable request headers from a HAR archive.
+///
+/// Request cookies (the `cookies` array, or the `Cookie` header when that array
+/// is empty) become host-only cookies for the request host; response cookies
+/// keep their domain, path, secure flag, and expiry. Later entries win, so a
+/// cookie the server refreshed mid-capture replaces the value sent earlier.
+/// Cookies are returned grouped by domain. `Authorization` and `X-*` request
+/// headers are collected per host in [`CapturedCookies::replay_headers`].
+///
+/// # Errors
+///
+/// Returns [`CaptureError::EmptyInput`] for blank input, [`CaptureError::Json`]
+/// or [`CaptureError::InvalidHar`] when the input is not a HAR archive, and
+/// [`CaptureError::NoValidCookies`] when no usable cookies remain.
+#[instrument(level = "debug", skip(har_json))]
+pub fn parse_har_cookies(har_json: &str) -> Result<CapturedCookies, CaptureError> {
+    let trimmed = har_json.trim();
+    if trimmed.is_empty() {
+        return Err(CaptureError::EmptyInput);
+    }
+
+    let har: HarFile = serde_json::from_str(trimmed)?;
+    let Some(log) = har.log else {
+        return Err(CaptureError::InvalidHar(
+            "missing top-level `log` object".to_string(),
+        ));
+    };
+
+    let mut cookies: Vec<CookieLine> = Vec::new();
+    let mut index_by_key: HashMap<(String, String, String), usize> = HashMap::new();
+    let mut upsert = |cookie: CookieLine| {
+        let key = (
+            cookie.domain.to_ascii_lowercase(),
+            cookie.path.clone(),
+            cookie.name.clone(),
+        );
+        if let Some(&idx) = index_by_key.get(&key) {
+            cookies[idx] = cookie;
+        } else {
+            index_by_key.insert(key, cookies.len());
+            cookies.push(cookie);
+        }
+    };
+    let mut replay_headers = ReplayHeaders::default();
+    let mut warnings = Vec::new();
+
+    for (index, entry) in log.entries.into_iter().enumerate() {
+        let Ok(url) = entry.request.url.parse::<url::Url>() else {
+            warnings.push(format!("entry {}: request URL is not valid", index + 1));
+            continue;
+        };
+        let Some(host) = url.host_str().map(str::to_ascii_lowercase) else {
+            warnings.push(format!("entry {}: request URL has no host", index + 1));
+            continue;
+        };
+        let secure = url.scheme() == "https";
+
+        let request_cookies = if entry.request.cookies.is_empty() {
+            cookie_header_pairs(&entry.request.headers)
+        } else {
+            entry
+                .request
+                .cookies
+                .into_iter()
+                .map(|cookie| (cookie.name, cookie.value))
+                .collect()
+        };
+        for (name, value) in request_cookies {
+            upsert(CookieLine::new(
+                host.clone(),
+                false,
+                "/".to_string(),
+                secure,
+                0,
+                name,
+                value,
+            ));
+        }
+
+        for header in &entry.request.headers {
+            let name = header.name.to_ascii_lowercase();
+            if name == "authorization" || name.starts_with("x-") {
+                replay_headers.insert(&host, &name, &header.value);
+            }
+        }
+
+        if let Some(response) = entry.response {
+            for cookie in response.cookies {
+                upsert(convert_har_response_cookie(cookie, &host, secure));
+            }
+        }
+    }
+
+    let (mut valid_cookies, validation_warnings) = validate_cookies(cookies, unix_now());
+    warnings.extend(validation_warnings);
+
+    if valid_cookies.is_empty() {
+        return Err(CaptureError::NoValidCookies);
+    }
+
+    // Stable sort keeps capture order within each domain group.
+    valid_cookies.sort_by(|a, b| {
+        a.domain
+            .trim_start_matches('.')
+            .cmp(b.domain.trim_start_matches('.'))
+    });
+
+    Ok(CapturedCookies {
+        cookies: valid_cookies,
+        warnings,
+        format: CapturedCookieFormat::Har,
+        replay_headers,
     })
 }
 
@@ -105,7 +305,107 @@ fn looks_like_json(input: &str) -> bool {
     input.starts_with('[') || input.starts_with('{')
 }
 
-fn validate_cookies(cookies: Vec<CookieLine>, now: u64) -> (Vec<CookieLine>, Vec<String>) {
+fn looks_like_har(input: &str) -> bool {
+    input.starts_with('{') && input.contains("\"log\"") && input.contains("\"entries\"")
+}
+
+/// Splits `Cookie` request headers into `(name, value)` pairs.
+fn cookie_header_pairs(headers: &[HarHeader]) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .filter(|header| header.name.eq_ignore_ascii_case("cookie"))
+        .flat_map(|header| header.value.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .collect()
+}
+
+fn convert_har_response_cookie(cookie: HarCookie, host: &str, secure: bool) -> CookieLine {
+    let (domain, tailmatch) = match cookie.domain.as_deref().map(str::trim) {
+        Some(domain) if !domain.is_empty() => {
+            let bare = domain.trim_start_matches('.').to_ascii_lowercase();
+            (format!(".{bare}"), true)
+        }
+        _ => (host.to_string(), false),
+    };
+    let path = cookie
+        .path
+        .filter(|path| path.starts_with('/'))
+        .unwrap_or_else(|| "/".to_string());
+    let expires = cookie
+        .expires
+        .as_deref()
+        .and_then(parse_har_expiry)
+        .unwrap_or(0);
+
+    CookieLine::new(
+        domain,
+        tailmatch,
+        path,
+        cookie.secure.unwrap_or(secure),
+        expires,
+        cookie.name,
+        cookie.value,
+    )
+}
+
+/// Parses a HAR cookie expiry (ISO 8601, or an HTTP-date) into Unix seconds.
+fn parse_har_expiry(value: &str) -> Option<u64> {
+    let value = value.trim();
+    if let Ok(time) = httpdate::parse_http_date(value) {
+        return time
+            .duration_since(UNIX_EPOCH)
+            .ok()
+            .map(|duration| duration.as_secs());
+    }
+
+    // YYYY-MM-DDTHH:MM:SS[.fff](Z|±HH:MM)
+    let (date, rest) = value.split_once('T')?;
+    let mut date_parts = date.splitn(3, '-').map(str::parse::<i64>);
+    let (year, month, day) = (
+        date_parts.next()?.ok()?,
+        date_parts.next()?.ok()?,
+        date_parts.next()?.ok()?,
+    );
+    let time = rest.get(..8)?;
+    let mut time_parts = time.splitn(3, ':').map(str::parse::<i64>);
+    let (hour, minute, second) = (
+        time_parts.next()?.ok()?,
+        time_parts.next()?.ok()?,
+        time_parts.next()?.ok()?,
+    );
+    let zone = rest
+        .get(8..)?
+        .trim_start_matches(|c: char| c == '.' || c.is_ascii_digit());
+    let offset_secs = match zone {
+        "" | "Z" | "z" => 0,
+        _ => {
+            let sign = if zone.starts_with('-') { -1 } else { 1 };
+            let (hours, minutes) = zone.get(1..)?.split_once(':')?;
+            sign * (hours.parse::<i64>().ok()? * 3600 + minutes.parse::<i64>().ok()? * 60)
+        }
+    };
+
+    let secs = days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second
+        - offset_secs;
+    u64::try_from(secs).ok()
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date (Howard Hinnant's algorithm).
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+pub(super) fn validate_cookies(
+    cookies: Vec<CookieLine>,
+    now: u64,
+) -> (Vec<CookieLine>, Vec<String>) {
     let mut valid = Vec::new();
     let mut warnings = Vec::new();
 
@@ -142,7 +442,7 @@ fn validate_cookies(cookies: Vec<CookieLine>, now: u64) -> (Vec<CookieLine>, Vec
     (valid, warnings)
 }
 
-fn unix_now() -> u64 {
+pub(super) fn unix_now() -> u64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .map_or(0_u64, |duration| duration.as_secs())
@@ -271,6 +571,54 @@ struct JsonCookieEntry {
     expires: Option<f64>,
 }
 
+#[derive(Debug, Deserialize)]
+struct HarFile {
+    log: Option<HarLog>,
+}
+
+#[derive(Debug, Deserialize)]
+struct HarLog {
+    #[serde(default)]
+    entries: Vec<HarEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct HarEntry {
+    request: HarRequest,
+    response: Option<HarResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+struct HarRequest {
+    url: String,
+    #[serde(default)]
+    headers: Vec<HarHeader>,
+    #[serde(default)]
+    cookies: Vec<HarCookie>,
+}
+
+#[derive(Debug, Deserialize)]
+struct HarResponse {
+    #[serde(default)]
+    cookies: Vec<HarCookie>,
+}
+
+#[derive(Debug, Deserialize)]
+struct HarHeader {
+    name: String,
+    value: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct HarCookie {
+    name: String,
+    value: String,
+    path: Option<String>,
+    domain: Option<String>,
+    expires: Option<String>,
+    secure: Option<bool>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -363,6 +711,132 @@ mod tests {
         assert!(matches!(result, Err(CaptureError::EmptyInput)));
     }
 
+    const SAMPLE_HAR: &str = r#"{
+  "log": {
+    "version": "1.2",
+    "entries": [
+      {
+        "request": {
+          "url": "https://ieeexplore.ieee.org/document/1",
+          "headers": [
+            {"name": "Cookie", "value": "JSESSIONID=first; ERIGHTS=abc"},
+            {"name": "Authorization", "value": "Bearer secret-token"},
+            {"name": "X-Client-Id", "value": "web"},
+            {"name": "Accept", "value": "*/*"}
+          ],
+          "cookies": []
+        },
+        "response": {
+          "cookies": [
+            {"name": "JSESSIONID", "value": "refreshed", "path": "/", "secure": true}
+          ]
+        }
+      },
+      {
+        "request": {
+          "url": "https://www.sciencedirect.com/science/article/pii/S1",
+          "headers": [],
+          "cookies": [{"name": "sd_session", "value": "xyz"}]
+        },
+        "response": {
+          "cookies": [
+            {
+              "name": "remember",
+              "value": "1",
+              "domain": ".sciencedirect.com",
+              "path": "/",
+              "expires": "2100-01-01T00:00:00.000Z"
+            }
+          ]
+        }
+      }
+    ]
+  }
+}"#;
+
+    #[test]
+    fn test_parse_har_cookies_extracts_request_and_response_cookies() {
+        let parsed = parse_har_cookies(SAMPLE_HAR).unwrap();
+        assert_eq!(parsed.format, CapturedCookieFormat::Har);
+
+        let find = |name: &str| parsed.cookies.iter().find(|c| c.name == name).unwrap();
+        assert_eq!(
+            find("JSESSIONID").value(),
+            "refreshed",
+            "response cookie should replace the earlier request value"
+        );
+        assert_eq!(find("ERIGHTS").domain, "ieeexplore.ieee.org");
+        assert!(!find("ERIGHTS").tailmatch);
+        assert_eq!(find("sd_session").domain, "www.sciencedirect.com");
+
+        let remember = find("remember");
+        assert_eq!(remember.domain, ".sciencedirect.com");
+        assert!(remember.tailmatch);
+        assert_eq!(remember.expires, 4_102_444_800);
+    }
+
+    #[test]
+    fn test_parse_har_cookies_groups_cookies_by_domain() {
+        let parsed = parse_har_cookies(SAMPLE_HAR).unwrap();
+        let domains: Vec<&str> = parsed
+            .cookies
+            .iter()
+            .map(|c| c.domain.trim_start_matches('.'))
+            .collect();
+        let mut grouped = domains.clone();
+        grouped.sort_unstable();
+        assert_eq!(domains, grouped);
+        assert_eq!(unique_domain_count(&parsed.cookies), 3);
+    }
+
+    #[test]
+    fn test_parse_har_cookies_captures_replay_headers_per_host() {
+        let parsed = parse_har_cookies(SAMPLE_HAR).unwrap();
+        let headers = parsed
+            .replay_headers
+            .for_host("ieeexplore.ieee.org")
+            .unwrap();
+        assert_eq!(headers.get("authorization").unwrap(), "Bearer secret-token");
+        assert_eq!(headers.get("x-client-id").unwrap(), "web");
+        assert!(!headers.contains_key("accept"));
+        assert!(
+            parsed
+                .replay_headers
+                .for_host("www.sciencedirect.com")
+                .is_none()
+        );
+        assert!(
+            !format!("{:?}", parsed.replay_headers).contains("secret-token"),
+            "Debug output must not leak header values"
+        );
+    }
+
+    #[test]
+    fn test_parse_captured_cookies_routes_har_input() {
+        let parsed = parse_captured_cookies(SAMPLE_HAR).unwrap();
+        assert_eq!(parsed.format, CapturedCookieFormat::Har);
+    }
+
+    #[test]
+    fn test_parse_har_cookies_rejects_json_without_log() {
+        let result = parse_har_cookies(r#"{"entries": []}"#);
+        assert!(matches!(result, Err(CaptureError::InvalidHar(_))));
+    }
+
+    #[test]
+    fn test_parse_har_expiry_handles_offsets_and_http_dates() {
+        assert_eq!(parse_har_expiry("1970-01-02T00:00:00Z"), Some(86_400));
+        assert_eq!(
+            parse_har_expiry("1970-01-02T01:00:00.123+01:00"),
+            Some(86_400)
+        );
+        assert_eq!(
+            parse_har_expiry("Fri, 01 Jan 2100 00:00:00 GMT"),
+            Some(4_102_444_800)
+        );
+        assert_eq!(parse_har_expiry("not a date"), None);
+    }
+
     #[test]
     fn test_validate_cookies_filters_expired_with_explicit_time() {
         let cookies = vec![
@@ -2,13 +2,22 @@
 //!
 //! Parses the Netscape HTTP cookie file format (7 TAB-separated fields per line)
 //! and loads cookies into a `reqwest::cookie::Jar` for use with the HTTP client.
+//! After a run, [`serialize_jar_to_netscape`] writes cookies the servers
+//! refreshed back into the same file, using a [`SetCookieLog`] for the
+//! attributes of cookies the file did not have yet.
 
 use std::fmt;
+use std::fs;
 use std::io::BufRead;
-use std::sync::Arc;
+use std::path::Path;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{SystemTime, UNIX_EPOCH};
 
-use reqwest::cookie::Jar;
-use tracing::{debug, instrument, warn};
+use reqwest::cookie::{CookieStore, Jar};
+use reqwest::header::HeaderValue;
+use tracing::{debug, info, instrument, warn};
+
+use crate::atomic_write::atomic_write;
 
 /// A single parsed cookie from a Netscape-format cookie file.
 ///
@@ -120,8 +129,9 @@ pub struct ParseResult {
 /// Each non-comment, non-blank line must contain exactly 7 TAB-separated fields:
 /// `domain`, `tailmatch`, `path`, `secure`, `expires`, `name`, `value`.
 ///
-/// Lines starting with `#` and blank lines are skipped. The optional
-/// `# Netscape HTTP Cookie File` header is accepted.
+/// Lines starting with `#` and blank lines are skipped, except the
+/// `#HttpOnly_` prefix curl and browsers put on HTTP-only cookies. The
+/// optional `# Netscape HTTP Cookie File` header is accepted.
 ///
 /// # Errors
 ///
@@ -146,7 +156,7 @@ pub fn parse_netscape_cookies(reader: impl BufRead) -> Result<ParseResult, Cooki
         }
 
         // Skip comment lines (including the optional Netscape header)
-        if line.starts_with('#') {
+        if is_comment_line(line) {
             continue;
         }
 
@@ -179,8 +189,17 @@ pub fn parse_netscape_cookies(reader: impl BufRead) -> Result<ParseResult, Cooki
     Ok(ParseResult { cookies, warnings })
 }
 
-/// Parses a single cookie line into a `CookieLine`.
+/// Prefix marking an HTTP-only cookie line in the Netscape format.
+const HTTP_ONLY_PREFIX: &str = "#HttpOnly_";
+
+/// Returns true for `#` comment lines; `#HttpOnly_` cookie lines are not comments.
+fn is_comment_line(line: &str) -> bool {
+    line.starts_with('#') && !line.starts_with(HTTP_ONLY_PREFIX)
+}
+
+/// Parses a single cookie line into a `CookieLine`, ignoring any `#HttpOnly_` prefix.
 fn parse_cookie_line(line: &str, line_number: usize) -> Result<CookieLine, CookieError> {
+    let line = line.strip_prefix(HTTP_ONLY_PREFIX).unwrap_or(line);
     let fields: Vec<&str> = line.split('\t').collect();
 
     if fields.len() != 7 {
@@ -267,14 +286,26 @@ fn redact_line_for_error(line: &str) -> String {
 /// Each `CookieLine` is converted to a `Set-Cookie` header string and added
 /// to the jar with the appropriate origin URL for domain matching.
 ///
+/// When `domains` is given, only cookies scoped to those hosts are loaded: a
+/// cookie is kept when it would be sent to one of the hosts, or when its
+/// domain is a subdomain of one. Other cookies are skipped, so a large cookie
+/// file does not put institutional cookies in front of unrelated sites.
+///
 /// # Returns
 ///
 /// An `Arc<Jar>` suitable for passing to `reqwest::ClientBuilder::cookie_provider()`.
-#[instrument(level = "debug", skip(cookies))]
-pub fn load_cookies_into_jar(cookies: &[CookieLine]) -> Arc<Jar> {
+#[instrument(level = "debug", skip(cookies, domains))]
+pub fn load_cookies_into_jar(cookies: &[CookieLine], domains: Option<&[String]>) -> Arc<Jar> {
     let jar = Arc::new(Jar::default());
 
+    let mut excluded = 0usize;
     for cookie in cookies {
+        if let Some(domains) = domains
+            && !cookie_in_scope(&cookie.domain, domains)
+        {
+            excluded += 1;
+            continue;
+        }
         let set_cookie = build_set_cookie_string(cookie);
         let origin_url = build_origin_url(cookie);
 
@@ -293,10 +324,372 @@ pub fn load_cookies_into_jar(cookies: &[CookieLine]) -> Arc<Jar> {
             );
         }
     }
+    if excluded > 0 {
+        debug!(
+            excluded,
+            "skipped cookies for domains outside the requested scope"
+        );
+    }
 
     jar
 }
 
+/// Returns true when a cookie for `cookie_domain` applies to one of `hosts`,
+/// or is set on a subdomain of one.
+#[must_use]
+pub fn cookie_in_scope(cookie_domain: &str, hosts: &[String]) -> bool {
+    hosts.iter().any(|host| {
+        let host = host.trim().trim_start_matches('.');
+        !host.is_empty()
+            && (cookie_domain_matches(cookie_domain, host)
+                || cookie_domain_matches(host, cookie_domain.trim_start_matches('.')))
+    })
+}
+
+/// Writes cookies held by `jar` for `used_urls` back into the Netscape file at `path`.
+///
+/// Only cookies the jar sends to `used_urls` are considered, so domains the run
+/// never contacted are left alone. Existing lines, `#HttpOnly_` ones included,
+/// are updated in place: they keep their domain, path, and secure flag, and
+/// take the new value and any new expiry from `set_cookies`. Cookies the
+/// servers newly set are appended with the domain, path, expiry, and flags of
+/// their `Set-Cookie` header in `set_cookies`; new cookies missing from the log
+/// are skipped, because the jar does not expose their attributes. Comments and
+/// malformed lines are copied through unchanged.
+///
+/// The file is rewritten atomically, and only when at least one cookie changed.
+///
+/// # Returns
+///
+/// The number of cookies updated or added (0 when the file was left untouched).
+///
+/// # Errors
+///
+/// Returns [`CookieError::Io`] if the file cannot be read or rewritten.
+#[instrument(
+    level = "debug",
+    skip(jar, used_urls, set_cookies),
+    fields(path = %path.display())
+)]
+pub fn serialize_jar_to_netscape(
+    jar: &Jar,
+    path: &Path,
+    used_urls: &[String],
+    set_cookies: &SetCookieLog,
+) -> Result<usize, CookieError> {
+    let original = fs::read_to_string(path)?;
+    let mut lines: Vec<NetscapeLine> = original
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            let trimmed = line.trim_end();
+            if trimmed.is_empty() || is_comment_line(trimmed) {
+                return NetscapeLine::Verbatim(line.to_string());
+            }
+            parse_cookie_line(trimmed, idx + 1).map_or_else(
+                |_| NetscapeLine::Verbatim(line.to_string()),
+                |cookie| NetscapeLine::Cookie {
+                    cookie,
+                    http_only: trimmed.starts_with(HTTP_ONLY_PREFIX),
+                },
+            )
+        })
+        .collect();
+
+    let mut changed = 0;
+    for raw_url in used_urls {
+        let Ok(url) = raw_url.parse::<url::Url>() else {
+            continue;
+        };
+        let Some(host) = url.host_str() else {
+            continue;
+        };
+        let Some(header) = jar.cookies(&url) else {
+            continue;
+        };
+        let Ok(header) = header.to_str() else {
+            continue;
+        };
+
+        for pair in header.split(';') {
+            let Some((name, value)) = pair.trim().split_once('=') else {
+                continue;
+            };
+            let recorded = set_cookies.latest(name, host, url.path());
+            let existing = lines.iter_mut().find_map(|line| match line {
+                NetscapeLine::Cookie { cookie, .. }
+                    if cookie.name == name
+                        && cookie_domain_matches(&cookie.domain, host)
+                        && url.path().starts_with(&cookie.path) =>
+                {
+                    Some(cookie)
+                }
+                _ => None,
+            });
+            match (existing, recorded) {
+                (Some(cookie), recorded) => {
+                    let expires = recorded.map_or(cookie.expires, |r| r.cookie.expires);
+                    if cookie.value != value || cookie.expires != expires {
+                        cookie.value = value.to_string();
+                        cookie.expires = expires;
+                        changed += 1;
+                    }
+                }
+                (None, Some(mut recorded)) => {
+                    recorded.cookie.value = value.to_string();
+                    lines.push(NetscapeLine::Cookie {
+                        cookie: recorded.cookie,
+                        http_only: recorded.http_only,
+                    });
+                    changed += 1;
+                }
+                (None, None) => {
+                    debug!(
+                        name,
+                        host, "skipping new cookie without a recorded Set-Cookie header"
+                    );
+                }
+            }
+        }
+    }
+
+    if changed == 0 {
+        debug!("no cookies changed; leaving cookie file untouched");
+        return Ok(0);
+    }
+
+    let mut output: String = lines
+        .iter()
+        .map(NetscapeLine::render)
+        .collect::<Vec<_>>()
+        .join("\n");
+    output.push('\n');
+    atomic_write(path, output.as_bytes())?;
+    info!(changed, "Wrote refreshed cookies back to cookie file");
+    Ok(changed)
+}
+
+/// One line of a Netscape cookie file, kept in order for write-back.
+enum NetscapeLine {
+    Cookie { cookie: CookieLine, http_only: bool },
+    Verbatim(String),
+}
+
+impl NetscapeLine {
+    fn render(&self) -> String {
+        match self {
+            Self::Cookie {
+                cookie,
+                http_only: true,
+            } => format!("{HTTP_ONLY_PREFIX}{}", format_netscape_line(cookie)),
+            Self::Cookie { cookie, .. } => format_netscape_line(cookie),
+            Self::Verbatim(line) => line.clone(),
+        }
+    }
+}
+
+/// `Set-Cookie` headers received by a client built with a [`RecordingJar`].
+///
+/// Write-back uses them to save new cookies with the domain, path, expiry,
+/// and flags the server sent, which the jar itself does not expose.
+#[derive(Debug, Default)]
+pub struct SetCookieLog {
+    cookies: Mutex<Vec<RecordedCookie>>,
+}
+
+/// A cookie parsed from a `Set-Cookie` header.
+#[derive(Debug, Clone)]
+struct RecordedCookie {
+    cookie: CookieLine,
+    http_only: bool,
+}
+
+impl SetCookieLog {
+    /// Creates an empty log.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a `Set-Cookie` header received from `url`.
+    ///
+    /// Headers that are malformed, or whose `Domain` does not cover the host
+    /// of `url`, are ignored the same way the jar ignores them.
+    pub fn record(&self, header: &str, url: &url::Url) {
+        if let Some(recorded) = parse_set_cookie(header, url) {
+            self.cookies
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(recorded);
+        }
+    }
+
+    /// Returns the most recent cookie named `name` that applies to `host` and `path`.
+    fn latest(&self, name: &str, host: &str, path: &str) -> Option<RecordedCookie> {
+        self.cookies
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .rev()
+            .find(|recorded| {
+                recorded.cookie.name == name
+                    && path.starts_with(&recorded.cookie.path)
+                    && if recorded.cookie.tailmatch {
+                        cookie_domain_matches(&recorded.cookie.domain, host)
+                    } else {
+                        recorded.cookie.domain.eq_ignore_ascii_case(host)
+                    }
+            })
+            .cloned()
+    }
+}
+
+/// Cookie store that keeps cookies in a [`Jar`] and records every
+/// `Set-Cookie` header in a [`SetCookieLog`].
+pub struct RecordingJar {
+    jar: Arc<Jar>,
+    log: Arc<SetCookieLog>,
+}
+
+impl RecordingJar {
+    /// Wraps `jar`, recording the headers it receives into `log`.
+    #[must_use]
+    pub fn new(jar: Arc<Jar>, log: Arc<SetCookieLog>) -> Self {
+        Self { jar, log }
+    }
+}
+
+impl CookieStore for RecordingJar {
+    fn set_cookies(&self, cookie_headers: &mut dyn Iterator<Item = &HeaderValue>, url: &url::Url) {
+        let headers: Vec<&HeaderValue> = cookie_headers.collect();
+        for header in &headers {
+            if let Ok(header) = header.to_str() {
+                self.log.record(header, url);
+            }
+        }
+        self.jar.set_cookies(&mut headers.into_iter(), url);
+    }
+
+    fn cookies(&self, url: &url::Url) -> Option<HeaderValue> {
+        self.jar.cookies(url)
+    }
+}
+
+/// Parses a `Set-Cookie` header received from `url` into Netscape fields.
+///
+/// Follows RFC 6265: a missing `Domain` makes a host-only cookie, a missing
+/// `Path` defaults to the directory of the request path, and `Max-Age` wins
+/// over `Expires`. Cookies without either are session cookies (expiry 0).
+fn parse_set_cookie(header: &str, url: &url::Url) -> Option<RecordedCookie> {
+    let host = url.host_str()?;
+    let mut parts = header.split(';');
+    let (name, value) = parts.next()?.split_once('=')?;
+    let name = name.trim();
+    if name.is_empty() {
+        return None;
+    }
+
+    let mut domain = None;
+    let mut path = None;
+    let mut secure = false;
+    let mut http_only = false;
+    let mut max_age = None;
+    let mut expires = None;
+    for attribute in parts {
+        let (key, attr_value) = attribute
+            .split_once('=')
+            .map_or((attribute.trim(), ""), |(k, v)| (k.trim(), v.trim()));
+        match key.to_ascii_lowercase().as_str() {
+            "domain" => {
+                let attr_domain = attr_value.trim_start_matches('.').to_ascii_lowercase();
+                if !attr_domain.is_empty() {
+                    domain = Some(attr_domain);
+                }
+            }
+            "path" if attr_value.starts_with('/') => path = Some(attr_value.to_string()),
+            "secure" => secure = true,
+            "httponly" => http_only = true,
+            "max-age" => max_age = attr_value.parse::<i64>().ok(),
+            "expires" => expires = parse_cookie_date(attr_value),
+            _ => {}
+        }
+    }
+
+    let (domain, tailmatch) = match domain {
+        Some(domain) if cookie_domain_matches(&domain, host) => (format!(".{domain}"), true),
+        Some(_) => return None,
+        None => (host.to_ascii_lowercase(), false),
+    };
+    let path = path.unwrap_or_else(|| default_cookie_path(url.path()));
+    let expires = match max_age {
+        Some(seconds) => {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs());
+            now.saturating_add_signed(seconds).max(1)
+        }
+        None => expires.unwrap_or(0),
+    };
+
+    Some(RecordedCookie {
+        cookie: CookieLine::new(
+            domain,
+            tailmatch,
+            path,
+            secure,
+            expires,
+            name.to_string(),
+            value.trim().to_string(),
+        ),
+        http_only,
+    })
+}
+
+/// Parses a cookie `Expires` date into a Unix timestamp.
+///
+/// Accepts HTTP dates and the common `Wed, 21-Oct-2026 07:28:00 GMT` variant.
+fn parse_cookie_date(value: &str) -> Option<u64> {
+    let time = httpdate::parse_http_date(value)
+        .or_else(|_| httpdate::parse_http_date(&value.replace('-', " ")))
+        .ok()?;
+    Some(
+        time.duration_since(UNIX_EPOCH)
+            .map_or(1, |elapsed| elapsed.as_secs().max(1)),
+    )
+}
+
+/// RFC 6265 default-path: the request path up to, not including, its last `/`.
+fn default_cookie_path(request_path: &str) -> String {
+    match request_path.rfind('/') {
+        Some(0) | None => "/".to_string(),
+        Some(idx) => request_path[..idx].to_string(),
+    }
+}
+
+/// Formats a cookie as a 7-field Netscape line.
+fn format_netscape_line(cookie: &CookieLine) -> String {
+    let flag = |value: bool| if value { "TRUE" } else { "FALSE" };
+    format!(
+        "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+        cookie.domain,
+        flag(cookie.tailmatch),
+        cookie.path,
+        flag(cookie.secure),
+        cookie.expires,
+        cookie.name,
+        cookie.value()
+    )
+}
+
+/// Returns true when `host` is `domain` or one of its subdomains.
+fn cookie_domain_matches(domain: &str, host: &str) -> bool {
+    let domain = domain.trim_start_matches('.');
+    host.eq_ignore_ascii_case(domain)
+        || host
+            .to_ascii_lowercase()
+            .ends_with(&format!(".{}", domain.to_ascii_lowercase()))
+}
+
 /// Builds a `Set-Cookie` header string from a `CookieLine`.
 fn build_set_cookie_string(cookie: &CookieLine) -> String {
     let mut parts = vec![format!("{}={}", cookie.name, cookie.value())];
@@ -401,6 +794,15 @@ mod tests {
         assert!(result.warnings.is_empty());
     }
 
+    #[test]
+    fn test_parse_netscape_cookies_reads_http_only_lines() {
+        let input = "#HttpOnly_.example.com\tTRUE\t/\tTRUE\t0\tsid\tabc\n";
+        let result = parse_netscape_cookies(cursor(input)).unwrap();
+        assert_eq!(result.cookies.len(), 1);
+        assert_eq!(result.cookies[0].domain, ".example.com");
+        assert_eq!(result.cookies[0].name, "sid");
+    }
+
     #[test]
     fn test_parse_netscape_cookies_no_header() {
         let input = ".example.com\tTRUE\t/\tFALSE\t0\tname\tvalue\n";
@@ -555,6 +957,135 @@ also bad
         assert!(redacted.contains("name"));
     }
 
+    // ---- Write-back ----
+
+    const WRITE_BACK_FILE: &str = "\
+# Netscape HTTP Cookie File
+#HttpOnly_.example.com\tTRUE\t/\tTRUE\t4102444800\thttponly\tkeep
+.example.com\tTRUE\t/\tTRUE\t4102444800\tsession\told
+.unused.org\tTRUE\t/\tFALSE\t4102444800\tother\tstale
+";
+
+    fn write_back_fixture() -> (tempfile::TempDir, std::path::PathBuf, Arc<Jar>) {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("cookies.txt");
+        std::fs::write(&path, WRITE_BACK_FILE).unwrap();
+        let parsed = parse_netscape_cookies(cursor(WRITE_BACK_FILE)).unwrap();
+        let jar = load_cookies_into_jar(&parsed.cookies, None);
+        (dir, path, jar)
+    }
+
+    #[test]
+    fn test_serialize_jar_to_netscape_refreshes_value_and_keeps_expiry() {
+        let (_dir, path, jar) = write_back_fixture();
+        let url = "https://www.example.com/paper.pdf"
+            .parse::<url::Url>()
+            .unwrap();
+        jar.add_cookie_str("session=new; Domain=.example.com; Path=/", &url);
+
+        let changed =
+            serialize_jar_to_netscape(&jar, &path, &[url.to_string()], &SetCookieLog::new())
+                .unwrap();
+        assert_eq!(changed, 1);
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert!(written.contains(".example.com\tTRUE\t/\tTRUE\t4102444800\tsession\tnew\n"));
+        assert!(
+            written.contains(".unused.org\tTRUE\t/\tFALSE\t4102444800\tother\tstale"),
+            "cookies for domains the run never used stay untouched"
+        );
+    }
+
+    #[test]
+    fn test_serialize_jar_to_netscape_updates_http_only_lines_in_place() {
+        let (_dir, path, jar) = write_back_fixture();
+        let log = Arc::new(SetCookieLog::new());
+        let recording = RecordingJar::new(Arc::clone(&jar), Arc::clone(&log));
+        let url = "https://www.example.com/paper.pdf"
+            .parse::<url::Url>()
+            .unwrap();
+        let header =
+            HeaderValue::from_static("httponly=refreshed; Domain=.example.com; Path=/; HttpOnly");
+        recording.set_cookies(&mut std::iter::once(&header), &url);
+
+        let changed = serialize_jar_to_netscape(&jar, &path, &[url.to_string()], &log).unwrap();
+        assert_eq!(changed, 1);
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert!(
+            written.contains("#HttpOnly_.example.com\tTRUE\t/\tTRUE\t0\thttponly\trefreshed\n"),
+            "the HttpOnly line takes the new value and session expiry: {written}"
+        );
+        assert_eq!(written.matches("\thttponly\t").count(), 1);
+    }
+
+    #[test]
+    fn test_serialize_jar_to_netscape_appends_new_cookies_with_set_cookie_attributes() {
+        let (_dir, path, jar) = write_back_fixture();
+        let log = Arc::new(SetCookieLog::new());
+        let recording = RecordingJar::new(Arc::clone(&jar), Arc::clone(&log));
+        let url = "https://www.example.com/papers/paper.pdf"
+            .parse::<url::Url>()
+            .unwrap();
+        let header = HeaderValue::from_static(
+            "fresh=1; Path=/papers; Expires=Fri, 01 Jan 2100 00:00:00 GMT; Secure; HttpOnly",
+        );
+        recording.set_cookies(&mut std::iter::once(&header), &url);
+        // Set through another client, so its attributes were never recorded.
+        jar.add_cookie_str("unrecorded=1; Path=/", &url);
+
+        let changed = serialize_jar_to_netscape(&jar, &path, &[url.to_string()], &log).unwrap();
+        assert_eq!(changed, 1);
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert!(
+            written.contains(
+                "#HttpOnly_www.example.com\tFALSE\t/papers\tTRUE\t4102444800\tfresh\t1\n"
+            ),
+            "{written}"
+        );
+        assert!(!written.contains("unrecorded"));
+    }
+
+    #[test]
+    fn test_parse_set_cookie_applies_domain_and_default_path() {
+        let url = "https://www.example.com/a/b/paper.pdf"
+            .parse::<url::Url>()
+            .unwrap();
+
+        let recorded = parse_set_cookie("sid=abc; Domain=example.com; Max-Age=60", &url).unwrap();
+        assert_eq!(recorded.cookie.domain, ".example.com");
+        assert!(recorded.cookie.tailmatch);
+        assert_eq!(recorded.cookie.path, "/a/b");
+        assert!(recorded.cookie.expires > 0);
+        assert!(!recorded.http_only);
+
+        assert!(parse_set_cookie("sid=abc; Domain=other.org", &url).is_none());
+    }
+
+    #[test]
+    fn test_serialize_jar_to_netscape_leaves_file_untouched_without_changes() {
+        let (_dir, path, jar) = write_back_fixture();
+
+        let changed = serialize_jar_to_netscape(
+            &jar,
+            &path,
+            &["https://example.com/paper.pdf".to_string()],
+            &SetCookieLog::new(),
+        )
+        .unwrap();
+
+        assert_eq!(changed, 0);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), WRITE_BACK_FILE);
+    }
+
+    #[test]
+    fn test_cookie_domain_matches_subdomains_only() {
+        assert!(cookie_domain_matches(".example.com", "example.com"));
+        assert!(cookie_domain_matches(".example.com", "www.Example.com"));
+        assert!(!cookie_domain_matches(".example.com", "badexample.com"));
+    }
+
     // ---- Task 2 tests: Jar loading ----
 
     #[test]
@@ -569,7 +1100,7 @@ also bad
             value: "abc123".to_string(),
         }];
 
-        let jar = load_cookies_into_jar(&cookies);
+        let jar = load_cookies_into_jar(&cookies, None);
 
         // Verify cookie is retrievable for matching domain
         let url = "http://example.com/page".parse::<url::Url>().unwrap();
@@ -597,7 +1128,7 @@ also bad
             value: "abc123".to_string(),
         }];
 
-        let jar = load_cookies_into_jar(&cookies);
+        let jar = load_cookies_into_jar(&cookies, None);
 
         // Should match subdomain
         let url = "http://sub.example.com/page".parse::<url::Url>().unwrap();
@@ -620,7 +1151,7 @@ also bad
             value: "abc123".to_string(),
         }];
 
-        let jar = load_cookies_into_jar(&cookies);
+        let jar = load_cookies_into_jar(&cookies, None);
 
         // Should NOT match different domain
         let url = "http://other.com/page".parse::<url::Url>().unwrap();
@@ -643,7 +1174,7 @@ also bad
             value: "secret".to_string(),
         }];
 
-        let jar = load_cookies_into_jar(&cookies);
+        let jar = load_cookies_into_jar(&cookies, None);
 
         // Should be available for HTTPS
         let https_url = "https://secure.com/page".parse::<url::Url>().unwrap();
@@ -654,10 +1185,49 @@ also bad
         );
     }
 
+    #[test]
+    fn test_load_cookies_into_jar_scopes_to_requested_domains() {
+        let cookie = |domain: &str, name: &str| CookieLine {
+            domain: domain.to_string(),
+            tailmatch: true,
+            path: "/".to_string(),
+            secure: false,
+            expires: 0,
+            name: name.to_string(),
+            value: "value".to_string(),
+        };
+        let cookies = vec![
+            cookie(".wiley.com", "publisher"),
+            cookie("onlinelibrary.wiley.com", "library"),
+            cookie(".university.edu", "institution"),
+        ];
+
+        let jar = load_cookies_into_jar(&cookies, Some(&["www.wiley.com".to_string()]));
+
+        let header = |url: &str| {
+            jar.cookies(&url.parse::<url::Url>().unwrap())
+                .map(|value| value.to_str().unwrap().to_string())
+        };
+        assert!(
+            header("https://www.wiley.com/")
+                .unwrap()
+                .contains("publisher")
+        );
+        assert!(
+            header("https://university.edu/").is_none(),
+            "cookies for hosts outside the scope must not be loaded"
+        );
+        assert!(cookie_in_scope(
+            "onlinelibrary.wiley.com",
+            &["wiley.com".to_string()]
+        ));
+        assert!(!cookie_in_scope(".wiley.com", &[]));
+    }
+
     #[test]
     fn test_load_cookies_into_jar_empty_list() {
         let cookies: Vec<CookieLine> = vec![];
-        let jar = load_cookies_into_jar(&cookies);
+        let jar = load_cookies_into_jar(&cookies, None);
         let url = "http://example.com/".parse::<url::Url>().unwrap();
         assert!(jar.cookies(&url).is_none());
     }
@@ -0,0 +1,398 @@
+//! Per-domain request headers for token-based authentication.
+//!
+//! Some APIs and institutional gateways expect `Authorization: Bearer <token>`
+//! instead of (or in addition to) session cookies. [`DomainHeaders`] maps a
+//! domain to the headers that should be attached to requests for that domain
+//! and its subdomains. Header values are treated as secrets: they are marked
+//! sensitive on the wire and never appear in `Debug` output.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+
+use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderName, HeaderValue};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+use url::{Host, Url};
+
+use super::{ReplayHeaders, load_persisted_domain_headers};
+
+/// Environment variable holding bearer tokens as `domain=token` pairs
+/// separated by commas (e.g. `api.example.org=abc123,gateway.example.edu=xyz`).
+pub const AUTH_BEARER_ENV: &str = "DOWNLOADER_AUTH_BEARER";
+
+/// Errors raised while building a [`DomainHeaders`] store.
+///
+/// Messages never include header values, only domains and header names.
+#[derive(Debug, thiserror::Error)]
+pub enum DomainHeadersError {
+    /// A `domain=token` entry could not be split.
+    #[error("auth token entry #{index} is not in `<domain>=<token>` form")]
+    InvalidEntry {
+        /// 1-based position of the entry in the spec.
+        index: usize,
+    },
+    /// The domain is empty or contains a scheme, path, port, or whitespace.
+    #[error("invalid auth domain '{0}': expected a bare host such as api.example.org")]
+    InvalidDomain(String),
+    /// The header name or value is not valid HTTP.
+    #[error("invalid auth header '{name}' for {domain}")]
+    InvalidHeader {
+        /// Domain the header was configured for.
+        domain: String,
+        /// Header name as given.
+        name: String,
+    },
+}
+
+/// Headers to attach to requests, keyed by domain.
+///
+/// A domain entry applies to the domain itself and every subdomain. When more
+/// than one entry matches a host, the most specific (longest) domain wins for
+/// any header both define.
+#[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomainHeaders {
+    domains: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl DomainHeaders {
+    /// Creates an empty header store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true when no domain has headers configured.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.domains.is_empty()
+    }
+
+    /// Returns the configured domains, in sorted order.
+    pub fn domains(&self) -> impl Iterator<Item = &str> {
+        self.domains.keys().map(String::as_str)
+    }
+
+    /// Sets `name: value` for `domain`, replacing any existing value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DomainHeadersError`] when the domain is not a bare host or the
+    /// header is not valid HTTP.
+    pub fn insert(
+        &mut self,
+        domain: &str,
+        name: &str,
+        value: &str,
+    ) -> Result<(), DomainHeadersError> {
+        let domain = normalize_domain(domain)?;
+        let invalid_header = || DomainHeadersError::InvalidHeader {
+            domain: domain.clone(),
+            name: name.to_string(),
+        };
+        let header_name =
+            HeaderName::from_bytes(name.trim().as_bytes()).map_err(|_| invalid_header())?;
+        HeaderValue::from_str(value.trim()).map_err(|_| invalid_header())?;
+
+        self.domains
+            .entry(domain)
+            .or_default()
+            .insert(header_name.as_str().to_string(), value.trim().to_string());
+        Ok(())
+    }
+
+    /// Sets `Authorization: Bearer <token>` for `domain`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DomainHeadersError`] when the domain or token is invalid.
+    pub fn insert_bearer(&mut self, domain: &str, token: &str) -> Result<(), DomainHeadersError> {
+        let token = token.trim();
+        if token.is_empty() {
+            return Err(DomainHeadersError::InvalidHeader {
+                domain: domain.trim().to_string(),
+                name: AUTHORIZATION.as_str().to_string(),
+            });
+        }
+        self.insert(domain, AUTHORIZATION.as_str(), &format!("Bearer {token}"))
+    }
+
+    /// Removes every header configured for `domain`. Returns true if any existed.
+    pub fn remove_domain(&mut self, domain: &str) -> bool {
+        normalize_domain(domain).is_ok_and(|domain| self.domains.remove(&domain).is_some())
+    }
+
+    /// Merges `other` into `self`; headers in `other` win on conflict.
+    pub fn extend(&mut self, other: Self) {
+        for (domain, headers) in other.domains {
+            self.domains.entry(domain).or_default().extend(headers);
+        }
+    }
+
+    /// Parses a comma-separated `domain=token` bearer spec (see [`AUTH_BEARER_ENV`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DomainHeadersError`] for malformed entries, naming the entry
+    /// position rather than echoing the token.
+    pub fn parse_bearer_spec(spec: &str) -> Result<Self, DomainHeadersError> {
+        let mut headers = Self::new();
+        for (index, entry) in spec
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .enumerate()
+        {
+            let Some((domain, token)) = entry.split_once('=') else {
+                return Err(DomainHeadersError::InvalidEntry { index: index + 1 });
+            };
+            headers.insert_bearer(domain, token)?;
+        }
+        Ok(headers)
+    }
+
+    /// Builds a store from headers captured in a HAR file.
+    ///
+    /// Captured hosts are kept as-is, so they also apply to their subdomains.
+    /// Headers that are not valid HTTP are skipped.
+    #[must_use]
+    pub fn from_replay_headers(replay: &ReplayHeaders) -> Self {
+        let mut headers = Self::new();
+        for host in replay.hosts() {
+            let Some(captured) = replay.for_host(host) else {
+                continue;
+            };
+            for (name, value) in captured {
+                if headers.insert(host, name, value).is_err() {
+                    warn!(host, header = %name, "Skipping captured header that is not valid HTTP");
+                }
+            }
+        }
+        headers
+    }
+
+    /// Returns the headers that apply to `url`, with values marked sensitive.
+    ///
+    /// Headers are secrets, so they only apply to `https` URLs; plain `http`
+    /// is allowed for loopback hosts only, where nothing crosses the network.
+    #[must_use]
+    pub fn header_map_for_url(&self, url: &Url) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        if !is_secure_origin(url) {
+            return map;
+        }
+        let Some(host) = url.host_str().map(str::to_ascii_lowercase) else {
+            return map;
+        };
+
+        let mut matching: Vec<(&String, &BTreeMap<String, String>)> = self
+            .domains
+            .iter()
+            .filter(|(domain, _)| domain_matches(&host, domain))
+            .collect();
+        // Less specific domains first so longer matches overwrite them.
+        matching.sort_by_key(|(domain, _)| domain.len());
+
+        for (_, headers) in matching {
+            for (name, value) in headers {
+                let (Ok(name), Ok(mut value)) = (
+                    HeaderName::from_bytes(name.as_bytes()),
+                    HeaderValue::from_str(value),
+                ) else {
+                    continue;
+                };
+                value.set_sensitive(true);
+                map.insert(name, value);
+            }
+        }
+        map
+    }
+}
+
+/// Returns true for `https` URLs and `http` URLs to a loopback host.
+fn is_secure_origin(url: &Url) -> bool {
+    match url.scheme() {
+        "https" => true,
+        "http" => match url.host() {
+            Some(Host::Domain(domain)) => domain.eq_ignore_ascii_case("localhost"),
+            Some(Host::Ipv4(ip)) => ip.is_loopback(),
+            Some(Host::Ipv6(ip)) => ip.is_loopback(),
+            None => false,
+        },
+        _ => false,
+    }
+}
+
+// Custom Debug impl that redacts header values.
+impl fmt::Debug for DomainHeaders {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.domains
+                    .iter()
+                    .map(|(domain, headers)| (domain, headers.keys().collect::<Vec<_>>())),
+            )
+            .finish()
+    }
+}
+
+/// Loads per-domain headers from the encrypted store, config, and environment.
+///
+/// Later sources win: persisted headers (`downloader auth token`), then
+/// `configured` (from the config file), then [`AUTH_BEARER_ENV`]. A persisted
+/// store that cannot be read is logged and skipped, matching cookie loading.
+///
+/// # Errors
+///
+/// Returns [`DomainHeadersError`] when [`AUTH_BEARER_ENV`] is malformed.
+pub fn load_runtime_domain_headers(
+    configured: Option<&DomainHeaders>,
+) -> Result<DomainHeaders, DomainHeadersError> {
+    let mut headers = match load_persisted_domain_headers() {
+        Ok(Some(persisted)) => persisted,
+        Ok(None) => DomainHeaders::new(),
+        Err(error) => {
+            warn!(
+                error = %error,
+                "Failed to load persisted auth headers; continuing without stored tokens"
+            );
+            DomainHeaders::new()
+        }
+    };
+
+    if let Some(configured) = configured {
+        headers.extend(configured.clone());
+    }
+
+    if let Some(spec) = env::var_os(AUTH_BEARER_ENV) {
+        headers.extend(DomainHeaders::parse_bearer_spec(&spec.to_string_lossy())?);
+    }
+
+    if !headers.is_empty() {
+        info!(
+            domains = %headers.domains().collect::<Vec<_>>().join(", "),
+            "Loaded per-domain auth headers"
+        );
+    }
+    Ok(headers)
+}
+
+fn normalize_domain(domain: &str) -> Result<String, DomainHeadersError> {
+    let normalized = domain.trim().trim_start_matches('.').to_ascii_lowercase();
+    let is_bare_host = !normalized.is_empty()
+        && normalized
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-');
+    if is_bare_host {
+        Ok(normalized)
+    } else {
+        Err(DomainHeadersError::InvalidDomain(domain.trim().to_string()))
+    }
+}
+
+fn domain_matches(host: &str, domain: &str) -> bool {
+    host == domain
+        || host
+            .strip_suffix(domain)
+            .is_some_and(|prefix| prefix.ends_with('.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(value: &str) -> Url {
+        Url::parse(value).unwrap()
+    }
+
+    #[test]
+    fn test_insert_bearer_applies_to_domain_and_subdomains() {
+        let mut headers = DomainHeaders::new();
+        headers.insert_bearer("example.org", "tok").unwrap();
+
+        let exact = headers.header_map_for_url(&url("https://example.org/a.pdf"));
+        assert_eq!(exact.get(AUTHORIZATION).unwrap(), "Bearer tok");
+        let sub = headers.header_map_for_url(&url("https://api.example.org/a.pdf"));
+        assert_eq!(sub.get(AUTHORIZATION).unwrap(), "Bearer tok");
+        let other = headers.header_map_for_url(&url("https://badexample.org/a.pdf"));
+        assert!(other.is_empty());
+    }
+
+    #[test]
+    fn test_header_map_is_empty_for_plain_http_except_loopback() {
+        let mut headers = DomainHeaders::new();
+        headers.insert_bearer("example.org", "tok").unwrap();
+        headers.insert_bearer("localhost", "local").unwrap();
+
+        assert!(
+            headers
+                .header_map_for_url(&url("http://example.org/a.pdf"))
+                .is_empty()
+        );
+        assert!(
+            headers
+                .header_map_for_url(&url("ftp://example.org/a.pdf"))
+                .is_empty()
+        );
+        let local = headers.header_map_for_url(&url("http://localhost:8080/a.pdf"));
+        assert_eq!(local.get(AUTHORIZATION).unwrap(), "Bearer local");
+    }
+
+    #[test]
+    fn test_header_map_prefers_most_specific_domain() {
+        let mut headers = DomainHeaders::new();
+        headers.insert_bearer("example.org", "broad").unwrap();
+        headers.insert_bearer("api.example.org", "narrow").unwrap();
+        headers.insert("example.org", "X-Client", "web").unwrap();
+
+        let map = headers.header_map_for_url(&url("https://api.example.org/x"));
+        assert_eq!(map.get(AUTHORIZATION).unwrap(), "Bearer narrow");
+        assert_eq!(map.get("x-client").unwrap(), "web");
+        assert!(map.get(AUTHORIZATION).unwrap().is_sensitive());
+    }
+
+    #[test]
+    fn test_parse_bearer_spec_reads_multiple_entries() {
+        let headers =
+            DomainHeaders::parse_bearer_spec(" api.example.org=abc , .Gateway.Example.edu=xyz,")
+                .unwrap();
+        assert_eq!(
+            headers.domains().collect::<Vec<_>>(),
+            vec!["api.example.org", "gateway.example.edu"]
+        );
+    }
+
+    #[test]
+    fn test_parse_bearer_spec_errors_do_not_echo_tokens() {
+        let err = DomainHeaders::parse_bearer_spec("example.org=ok,secret-token").unwrap_err();
+        assert!(matches!(err, DomainHeadersError::InvalidEntry { index: 2 }));
+        assert!(!err.to_string().contains("secret-token"));
+
+        let err = DomainHeaders::parse_bearer_spec("https://example.org/=tok").unwrap_err();
+        assert!(matches!(err, DomainHeadersError::InvalidDomain(_)));
+    }
+
+    #[test]
+    fn test_debug_output_redacts_values() {
+        let mut headers = DomainHeaders::new();
+        headers
+            .insert_bearer("example.org", "secret-token")
+            .unwrap();
+        let debug = format!("{headers:?}");
+        assert!(debug.contains("example.org"));
+        assert!(debug.contains("authorization"));
+        assert!(!debug.contains("secret-token"));
+    }
+
+    #[test]
+    fn test_extend_overrides_and_remove_domain() {
+        let mut base = DomainHeaders::parse_bearer_spec("example.org=old").unwrap();
+        base.extend(DomainHeaders::parse_bearer_spec("example.org=new").unwrap());
+        let map = base.header_map_for_url(&url("https://example.org/"));
+        assert_eq!(map.get(AUTHORIZATION).unwrap(), "Bearer new");
+
+        assert!(base.remove_domain("EXAMPLE.org"));
+        assert!(base.is_empty());
+        assert!(!base.remove_domain("example.org"));
+    }
+}
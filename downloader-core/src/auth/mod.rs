@@ -1,22 +1,32 @@
 //! Authentication and cookie management.
 //!
 //! This module provides cookie loading from Netscape-format cookie files,
-//! which can be exported from browsers or browser extensions.
+//! which can be exported from browsers or browser extensions, or read directly
+//! from a browser profile, and per-domain auth headers (bearer tokens) that
+//! compose with those cookies.
 
+mod browser;
 mod capture;
 mod cookies;
+mod headers;
 mod runtime_cookies;
 mod storage;
 
+pub use browser::{BrowserCookieStore, read_browser_cookies};
 pub use capture::{
-    CaptureError, CapturedCookieFormat, CapturedCookies, parse_captured_cookies,
-    unique_domain_count,
+    CaptureError, CapturedCookieFormat, CapturedCookies, ReplayHeaders, parse_captured_cookies,
+    parse_har_cookies, unique_domain_count,
 };
 pub use cookies::{
-    CookieError, CookieLine, ParseResult, load_cookies_into_jar, parse_netscape_cookies,
+    CookieError, CookieLine, ParseResult, RecordingJar, SetCookieLog, cookie_in_scope,
+    load_cookies_into_jar, parse_netscape_cookies, serialize_jar_to_netscape,
+};
+pub use headers::{
+    AUTH_BEARER_ENV, DomainHeaders, DomainHeadersError, load_runtime_domain_headers,
 };
 pub use runtime_cookies::{RuntimeCookieError, load_runtime_cookie_jar};
 pub use storage::{
-    StorageError, clear_persisted_cookies, load_persisted_cookies, persisted_cookie_path,
-    rotate_key, store_persisted_cookies,
+    StorageError, clear_persisted_cookies, load_persisted_cookies, load_persisted_domain_headers,
+    persisted_cookie_path, persisted_domain_headers_path, rotate_key, store_persisted_cookies,
+    store_persisted_domain_headers,
 };
@@ -33,6 +33,9 @@ pub enum RuntimeCookieError {
 /// This keeps runtime orchestration code focused on flow control while auth/cookie
 /// parsing and persistence behavior stays in the auth module.
 ///
+/// `domains` limits the jar to cookies for those hosts (see
+/// [`load_cookies_into_jar`]); `--save-cookies` still persists the full set.
+///
 /// # Errors
 ///
 /// Returns an error when the provided cookie source file cannot be read, when
@@ -40,6 +43,7 @@ pub enum RuntimeCookieError {
 pub fn load_runtime_cookie_jar(
     cookie_source: Option<&str>,
     save_cookies: bool,
+    domains: Option<&[String]>,
 ) -> Result<Option<Arc<Jar>>, RuntimeCookieError> {
     if let Some(cookie_source) = cookie_source {
         let reader: Box<dyn io::BufRead> = if cookie_source == "-" {
@@ -60,14 +64,14 @@ pub fn load_runtime_cookie_jar(
             warn!(line = line_num, reason = %reason, "Skipping malformed cookie line");
         }
 
-        let domains: HashSet<&str> = parse_result
+        let cookie_domains: HashSet<&str> = parse_result
             .cookies
             .iter()
             .map(|cookie| cookie.domain.as_str())
             .collect();
         info!(
             count = parse_result.cookies.len(),
-            domains = domains.len(),
+            domains = cookie_domains.len(),
             "Loaded cookies"
         );
 
@@ -79,7 +83,7 @@ pub fn load_runtime_cookie_jar(
             );
         }
 
-        return Ok(Some(load_cookies_into_jar(&parse_result.cookies)));
+        return Ok(Some(load_cookies_into_jar(&parse_result.cookies, domains)));
     }
 
     match load_persisted_cookies() {
@@ -89,7 +93,7 @@ pub fn load_runtime_cookie_jar(
                 domains = unique_domain_count(&cookies),
                 "Loaded encrypted persisted cookies"
             );
-            Ok(Some(load_cookies_into_jar(&cookies)))
+            Ok(Some(load_cookies_into_jar(&cookies, domains)))
         }
         Ok(None) => Ok(None),
         Err(error) => {
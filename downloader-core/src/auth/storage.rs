@@ -2,6 +2,8 @@
 //!
 //! Cookie persistence is opt-in and writes encrypted data to:
 //! `~/.config/downloader/cookies.enc` (or `$XDG_CONFIG_HOME/downloader/cookies.enc`).
+//! Per-domain auth headers (`downloader auth token`) use the same key and
+//! format in `auth-headers.enc` next to it.
 
 use std::env;
 use std::ffi::OsString;
@@ -14,9 +16,10 @@ use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
 use rand::RngCore;
 use sha2::{Digest, Sha256};
 
-use super::CookieLine;
+use super::{CookieLine, DomainHeaders};
 
 const COOKIE_FILE_NAME: &str = "cookies.enc";
+const HEADERS_FILE_NAME: &str = "auth-headers.enc";
 const KEYRING_SERVICE: &str = "downloader";
 const KEYRING_ENTRY_NAME: &str = "cookie-master-key-v1";
 const MAGIC: &[u8; 4] = b"DLC1";
@@ -127,36 +130,84 @@ pub fn load_persisted_cookies() -> Result<Option<Vec<CookieLine>>, StorageError>
     Ok(Some(cookies))
 }
 
-/// Rotates the encryption key for persisted cookies.
+/// Returns the persisted auth header path (`~/.config/downloader/auth-headers.enc`).
 ///
-/// This decrypts the existing cookies, generates a new key, and re-encrypts them.
+/// # Errors
+///
+/// Returns [`StorageError::ConfigDirUnavailable`] if no usable config dir is found.
+pub fn persisted_domain_headers_path() -> Result<PathBuf, StorageError> {
+    Ok(default_config_dir()?.join(HEADERS_FILE_NAME))
+}
+
+/// Stores per-domain auth headers encrypted with the cookie store key.
+///
+/// # Errors
+///
+/// Returns [`StorageError`] when key retrieval, encryption, or file writing fails.
+pub fn store_persisted_domain_headers(headers: &DomainHeaders) -> Result<PathBuf, StorageError> {
+    let path = persisted_domain_headers_path()?;
+    let key = load_or_create_key()?;
+    store_persisted_domain_headers_with_key(headers, &path, &key)?;
+    Ok(path)
+}
+
+/// Loads and decrypts persisted per-domain auth headers.
+///
+/// Returns `Ok(None)` when no persisted header file exists.
+///
+/// # Errors
+///
+/// Returns [`StorageError`] when key retrieval, decryption, or parsing fails.
+pub fn load_persisted_domain_headers() -> Result<Option<DomainHeaders>, StorageError> {
+    let path = persisted_domain_headers_path()?;
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let key = load_or_create_key()?;
+    let headers = load_persisted_domain_headers_with_key(&path, &key)?;
+    Ok(Some(headers))
+}
+
+/// Rotates the encryption key for persisted cookies and auth headers.
+///
+/// This decrypts the existing data, generates a new key, and re-encrypts it.
 ///
 /// # Errors
 ///
 /// Returns [`StorageError`] if loading, clearing, or storing fails.
 pub fn rotate_key() -> Result<(), StorageError> {
-    if let Some(cookies) = load_persisted_cookies()? {
-        clear_persisted_cookies()?;
+    let cookies = load_persisted_cookies()?;
+    let headers = load_persisted_domain_headers()?;
+    if cookies.is_none() && headers.is_none() {
+        return Ok(());
+    }
+
+    clear_persisted_cookies()?;
+    if let Some(cookies) = cookies {
         store_persisted_cookies(&cookies)?;
     }
+    if let Some(headers) = headers {
+        store_persisted_domain_headers(&headers)?;
+    }
     Ok(())
 }
 
-/// Removes persisted cookies and best-effort clears keychain key.
+/// Removes persisted cookies and auth headers, and best-effort clears keychain key.
 ///
-/// Returns `true` when the cookie file existed and was deleted.
+/// Returns `true` when the cookie or header file existed and was deleted.
 ///
 /// # Errors
 ///
 /// Returns [`StorageError`] when file removal fails.
 pub fn clear_persisted_cookies() -> Result<bool, StorageError> {
-    let path = persisted_cookie_path()?;
-    let removed = if path.exists() {
-        fs::remove_file(&path)?;
-        true
-    } else {
-        false
-    };
+    let mut removed = false;
+    for path in [persisted_cookie_path()?, persisted_domain_headers_path()?] {
+        if path.exists() {
+            fs::remove_file(&path)?;
+            removed = true;
+        }
+    }
 
     if env::var_os("DOWNLOADER_MASTER_KEY").is_none() {
         let _ = delete_keychain_key();
@@ -288,6 +339,26 @@ fn store_persisted_cookies_with_key(
     Ok(())
 }
 
+fn store_persisted_domain_headers_with_key(
+    headers: &DomainHeaders,
+    path: &Path,
+    key_material: &str,
+) -> Result<(), StorageError> {
+    let plaintext = serde_json::to_vec(headers)?;
+    let encrypted = encrypt_bytes(&plaintext, key_material)?;
+    write_encrypted_payload(path, &encrypted)?;
+    Ok(())
+}
+
+fn load_persisted_domain_headers_with_key(
+    path: &Path,
+    key_material: &str,
+) -> Result<DomainHeaders, StorageError> {
+    let bytes = fs::read(path)?;
+    let plaintext = decrypt_bytes(&bytes, key_material)?;
+    Ok(serde_json::from_slice(&plaintext)?)
+}
+
 fn write_encrypted_payload(path: &Path, payload: &[u8]) -> Result<(), StorageError> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)?;
@@ -395,6 +466,25 @@ mod tests {
         assert_eq!(loaded[0].value(), "secret");
     }
 
+    #[test]
+    fn test_domain_headers_round_trip_with_explicit_key() {
+        let tempdir = TempDir::new().unwrap();
+        let path = tempdir.path().join("auth-headers.enc");
+        let mut headers = DomainHeaders::new();
+        headers.insert_bearer("api.example.org", "secret").unwrap();
+
+        store_persisted_domain_headers_with_key(&headers, &path, "test-key").unwrap();
+        assert!(
+            !fs::read(&path)
+                .unwrap()
+                .windows(b"secret".len())
+                .any(|window| window == b"secret"),
+            "token must not be stored in plaintext"
+        );
+        let loaded = load_persisted_domain_headers_with_key(&path, "test-key").unwrap();
+        assert_eq!(loaded, headers);
+    }
+
     #[test]
     fn test_load_with_wrong_key_fails() {
         let tempdir = TempDir::new().unwrap();
@@ -4,12 +4,14 @@
 //! - Connection pool management
 //! - WAL mode for concurrent reads
 //! - Automatic migration execution
+//! - Online single-file backups (`VACUUM INTO`)
+//! - Offline compaction (`VACUUM` + WAL truncation)
 //!
 //! # Example
 //!
 //! ```no_run
 //! use downloader_core::Database;
-//! use std::path::Path;
+//! use std::path::{Path, PathBuf};
 //!
 //! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 //! let db = Database::new(Path::new("downloads.db")).await?;
@@ -18,11 +20,14 @@
 //! # }
 //! ```
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use sqlx::sqlite::{SqlitePool, SqlitePoolOptions};
+use sqlx::sqlite::{
+    SqliteConnectOptions, SqliteJournalMode, SqlitePool, SqlitePoolOptions, SqliteSynchronous,
+};
 use thiserror::Error;
-use tracing::instrument;
+use tracing::{instrument, warn};
 
 /// Default maximum number of connections in the pool.
 /// Kept low for `SQLite` since it uses file-level locking.
@@ -32,21 +37,62 @@ pub const DEFAULT_MAX_CONNECTIONS: u32 = 5;
 /// Connections will wait this long before returning `SQLITE_BUSY`.
 pub const DEFAULT_BUSY_TIMEOUT_MS: u32 = 5000;
 
-/// Optional database connection settings (pool size, busy timeout).
-/// Used by [`Database::new_with_options`].
+/// `SQLite` `synchronous` setting: how often commits are flushed to disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SynchronousMode {
+    /// Never fsync; fastest, but a power loss can corrupt the database.
+    Off,
+    /// Fsync at WAL checkpoints only; a power loss may drop the latest commits.
+    Normal,
+    /// Fsync on every commit (the `SQLite` default).
+    #[default]
+    Full,
+    /// Like `Full`, and also syncs the directory after journal deletion.
+    Extra,
+}
+
+impl SynchronousMode {
+    /// Returns the lowercase `PRAGMA synchronous` name, as used in config files.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Normal => "normal",
+            Self::Full => "full",
+            Self::Extra => "extra",
+        }
+    }
+}
+
+impl From<SynchronousMode> for SqliteSynchronous {
+    fn from(mode: SynchronousMode) -> Self {
+        match mode {
+            SynchronousMode::Off => Self::Off,
+            SynchronousMode::Normal => Self::Normal,
+            SynchronousMode::Full => Self::Full,
+            SynchronousMode::Extra => Self::Extra,
+        }
+    }
+}
+
+/// Optional database connection settings (pool size, busy timeout, sync mode).
+/// Used by [`Database::new_with_options`]; applied to every pooled connection.
 #[derive(Debug, Clone)]
 pub struct DatabaseOptions {
     /// Maximum connections in the pool (1..=20 for `SQLite`).
     pub max_connections: u32,
-    /// Busy timeout in milliseconds.
-    pub busy_timeout_ms: u32,
+    /// How long a connection waits on a locked database before failing with `SQLITE_BUSY`.
+    pub busy_timeout: Duration,
+    /// `PRAGMA synchronous` mode.
+    pub synchronous: SynchronousMode,
 }
 
 impl Default for DatabaseOptions {
     fn default() -> Self {
         Self {
             max_connections: DEFAULT_MAX_CONNECTIONS,
-            busy_timeout_ms: DEFAULT_BUSY_TIMEOUT_MS,
+            busy_timeout: Duration::from_millis(u64::from(DEFAULT_BUSY_TIMEOUT_MS)),
+            synchronous: SynchronousMode::default(),
         }
     }
 }
@@ -68,6 +114,17 @@ pub enum DbError {
         path: std::path::PathBuf,
         source: std::io::Error,
     },
+
+    /// Refused to overwrite an existing file with a backup.
+    #[error("backup target '{path}' already exists")]
+    BackupTargetExists { path: PathBuf },
+
+    /// Failed to move a finished backup snapshot into place.
+    #[error("failed to write database backup '{path}': {source}")]
+    BackupWrite {
+        path: PathBuf,
+        source: std::io::Error,
+    },
 }
 
 /// Database connection wrapper with connection pool.
@@ -101,7 +158,7 @@ impl Database {
         Self::new_with_options(db_path, &DatabaseOptions::default()).await
     }
 
-    /// Creates a new database connection with explicit pool and timeout options.
+    /// Creates a new database connection with explicit pool, timeout, and sync options.
     ///
     /// # Errors
     ///
@@ -122,21 +179,18 @@ impl Database {
             }
         }
 
-        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        // Pragmas set on the connect options run on every new pool
+        // connection, not just the first one.
+        let connect_options = SqliteConnectOptions::new()
+            .filename(db_path)
+            .create_if_missing(true)
+            .journal_mode(SqliteJournalMode::Wal)
+            .busy_timeout(options.busy_timeout)
+            .synchronous(options.synchronous.into());
 
         let pool = SqlitePoolOptions::new()
             .max_connections(options.max_connections)
-            .connect(&db_url)
-            .await?;
-
-        // Enable WAL mode for concurrent reads
-        sqlx::query("PRAGMA journal_mode=WAL")
-            .execute(&pool)
-            .await?;
-
-        // Set busy timeout to avoid immediate lock errors
-        sqlx::query(&format!("PRAGMA busy_timeout={}", options.busy_timeout_ms))
-            .execute(&pool)
+            .connect_with(connect_options)
             .await?;
 
         // Run migrations
@@ -192,6 +246,90 @@ impl Database {
         Ok(result.0.to_lowercase() == "wal")
     }
 
+    /// Writes a consistent single-file snapshot of the database to `path`.
+    ///
+    /// Uses `VACUUM INTO`, which copies from a single read transaction, so the
+    /// snapshot is consistent even while other connections keep writing in WAL
+    /// mode. The copy is written next to `path` first and renamed into place, so
+    /// an interrupted backup never leaves a partial file behind.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::BackupTargetExists` if `path` already exists,
+    /// `DbError::Connection` if the snapshot cannot be written,
+    /// or `DbError::BackupWrite` if it cannot be moved to `path`.
+    #[instrument(skip(self, path), fields(path = %path.display()))]
+    pub async fn backup_to(&self, path: &Path) -> Result<(), DbError> {
+        if path.exists() {
+            return Err(DbError::BackupTargetExists {
+                path: path.to_path_buf(),
+            });
+        }
+        self.write_backup(path).await
+    }
+
+    /// Like [`Database::backup_to`], but replaces an existing file at `path`.
+    ///
+    /// The existing file is only replaced once the new snapshot is complete,
+    /// so a failed backup leaves it intact.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::Connection` if the snapshot cannot be written,
+    /// or `DbError::BackupWrite` if it cannot be moved to `path`.
+    #[instrument(skip(self, path), fields(path = %path.display()))]
+    pub async fn backup_replacing(&self, path: &Path) -> Result<(), DbError> {
+        self.write_backup(path).await
+    }
+
+    /// Writes a `VACUUM INTO` snapshot next to `path`, then renames it into place.
+    async fn write_backup(&self, path: &Path) -> Result<(), DbError> {
+        let partial_path = partial_backup_path(path);
+        // A leftover from an interrupted run would make VACUUM INTO fail.
+        let _ = std::fs::remove_file(&partial_path);
+
+        if let Err(error) = sqlx::query("VACUUM INTO ?")
+            .bind(partial_path.to_string_lossy().into_owned())
+            .execute(&self.pool)
+            .await
+        {
+            let _ = std::fs::remove_file(&partial_path);
+            return Err(error.into());
+        }
+
+        std::fs::rename(&partial_path, path).map_err(|source| {
+            let _ = std::fs::remove_file(&partial_path);
+            DbError::BackupWrite {
+                path: path.to_path_buf(),
+                source,
+            }
+        })
+    }
+
+    /// Rebuilds the database file to return space freed by deleted rows.
+    ///
+    /// Runs `VACUUM` followed by `PRAGMA wal_checkpoint(TRUNCATE)` so the
+    /// reclaimed pages leave both the main file and the WAL. This is offline
+    /// maintenance: `VACUUM` needs the database to itself, so run it only when
+    /// no download is using the queue.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError::Connection` if `VACUUM` or the checkpoint fails
+    /// (e.g. `SQLITE_BUSY` while another process holds a transaction).
+    #[instrument(skip(self))]
+    pub async fn compact(&self) -> Result<(), DbError> {
+        sqlx::query("VACUUM").execute(&self.pool).await?;
+
+        let (busy,): (i64,) = sqlx::query_as("PRAGMA wal_checkpoint(TRUNCATE)")
+            .fetch_one(&self.pool)
+            .await?;
+        if busy != 0 {
+            warn!("WAL checkpoint could not complete; the WAL file keeps its current size");
+        }
+        Ok(())
+    }
+
     /// Gracefully closes all connections in the pool.
     ///
     /// This should be called before the application exits to ensure
@@ -203,6 +341,13 @@ impl Database {
     }
 }
 
+/// Returns the sibling path a backup is staged at before being renamed to `path`.
+fn partial_backup_path(path: &Path) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".partial");
+    path.with_file_name(file_name)
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -367,6 +512,31 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_database_download_log_accepts_disk_full_and_redirect_error_types() {
+        let db = Database::new_in_memory().await.unwrap();
+
+        let insert = |error_type: &'static str| {
+            sqlx::query(
+                "INSERT INTO download_log (url, status, started_at, error_type)
+                 VALUES ('https://example.com/a.pdf', 'failed', datetime('now'), ?)",
+            )
+            .bind(error_type)
+        };
+
+        assert!(insert("disk_full").execute(db.pool()).await.is_ok());
+        assert!(
+            insert("too_many_redirects")
+                .execute(db.pool())
+                .await
+                .is_ok()
+        );
+        assert!(
+            insert("bogus").execute(db.pool()).await.is_err(),
+            "error_type CHECK constraint should survive the table rebuild"
+        );
+    }
+
     #[tokio::test]
     async fn test_database_queue_parse_confidence_columns_exist() {
         let db = Database::new_in_memory().await.unwrap();
@@ -466,7 +636,8 @@ mod tests {
         let db_path = temp_dir.path().join("options.db");
         let options = DatabaseOptions {
             max_connections: 2,
-            busy_timeout_ms: 1000,
+            busy_timeout: Duration::from_secs(1),
+            synchronous: SynchronousMode::Normal,
         };
 
         let db = Database::new_with_options(&db_path, &options).await;
@@ -481,6 +652,173 @@ mod tests {
             .unwrap();
     }
 
+    #[tokio::test]
+    async fn test_database_backup_to_is_consistent_during_concurrent_writes() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = Database::new(&temp_dir.path().join("live.db"))
+            .await
+            .unwrap();
+        for i in 0..100 {
+            sqlx::query("INSERT INTO queue (url, source_type) VALUES (?, 'direct_url')")
+                .bind(format!("https://example.com/seed/{i}"))
+                .execute(db.pool())
+                .await
+                .unwrap();
+        }
+
+        let writer_db = db.clone();
+        let writer = tokio::spawn(async move {
+            for i in 0..200 {
+                sqlx::query("INSERT INTO queue (url, source_type) VALUES (?, 'direct_url')")
+                    .bind(format!("https://example.com/concurrent/{i}"))
+                    .execute(writer_db.pool())
+                    .await
+                    .unwrap();
+            }
+        });
+        let backup_path = temp_dir.path().join("snapshot.db");
+        db.backup_to(&backup_path).await.unwrap();
+        writer.await.unwrap();
+
+        assert!(backup_path.exists());
+        assert!(!partial_backup_path(&backup_path).exists());
+
+        let snapshot = Database::new(&backup_path).await.unwrap();
+        let (integrity,): (String,) = sqlx::query_as("PRAGMA integrity_check")
+            .fetch_one(snapshot.pool())
+            .await
+            .unwrap();
+        assert_eq!(integrity, "ok");
+        let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM queue")
+            .fetch_one(snapshot.pool())
+            .await
+            .unwrap();
+        assert!(
+            (100..=300).contains(&count),
+            "snapshot should hold the seeded rows plus a prefix of concurrent writes, got {count}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_database_backup_to_refuses_existing_target() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = Database::new(&temp_dir.path().join("live.db"))
+            .await
+            .unwrap();
+        let backup_path = temp_dir.path().join("existing.db");
+        std::fs::write(&backup_path, b"keep me").unwrap();
+
+        let result = db.backup_to(&backup_path).await;
+
+        assert!(matches!(result, Err(DbError::BackupTargetExists { .. })));
+        assert_eq!(std::fs::read(&backup_path).unwrap(), b"keep me");
+    }
+
+    #[tokio::test]
+    async fn test_database_backup_replacing_overwrites_existing_target() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = Database::new(&temp_dir.path().join("live.db"))
+            .await
+            .unwrap();
+        let backup_path = temp_dir.path().join("existing.db");
+        std::fs::write(&backup_path, b"old backup").unwrap();
+
+        db.backup_replacing(&backup_path).await.unwrap();
+
+        assert!(
+            std::fs::read(&backup_path)
+                .unwrap()
+                .starts_with(b"SQLite format 3")
+        );
+        assert!(!partial_backup_path(&backup_path).exists());
+    }
+
+    #[tokio::test]
+    async fn test_database_compact_shrinks_file_after_bulk_delete() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_path = temp_dir.path().join("bloated.db");
+        let db = Database::new(&db_path).await.unwrap();
+        let padding = "x".repeat(1024);
+        for i in 0..2000 {
+            sqlx::query("INSERT INTO queue (url, source_type) VALUES (?, 'direct_url')")
+                .bind(format!("https://example.com/{i}/{padding}"))
+                .execute(db.pool())
+                .await
+                .unwrap();
+        }
+        sqlx::query("PRAGMA wal_checkpoint(TRUNCATE)")
+            .execute(db.pool())
+            .await
+            .unwrap();
+        let bloated_size = std::fs::metadata(&db_path).unwrap().len();
+
+        sqlx::query("DELETE FROM queue")
+            .execute(db.pool())
+            .await
+            .unwrap();
+        db.compact().await.unwrap();
+
+        let compacted_size = std::fs::metadata(&db_path).unwrap().len();
+        assert!(
+            compacted_size < bloated_size / 2,
+            "expected compaction to shrink {bloated_size} bytes, got {compacted_size}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_database_options_apply_to_every_pooled_connection() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db_path = temp_dir.path().join("tuned.db");
+        let options = DatabaseOptions {
+            max_connections: 2,
+            busy_timeout: Duration::from_millis(50),
+            synchronous: SynchronousMode::Normal,
+        };
+        let db = Database::new_with_options(&db_path, &options)
+            .await
+            .unwrap();
+
+        // Hold both connections at once so the second is a fresh one.
+        let mut first = db.pool().acquire().await.unwrap();
+        let mut second = db.pool().acquire().await.unwrap();
+        for conn in [&mut first, &mut second] {
+            let (busy_timeout,): (i64,) = sqlx::query_as("PRAGMA busy_timeout")
+                .fetch_one(&mut **conn)
+                .await
+                .unwrap();
+            assert_eq!(busy_timeout, 50);
+            let (synchronous,): (i64,) = sqlx::query_as("PRAGMA synchronous")
+                .fetch_one(&mut **conn)
+                .await
+                .unwrap();
+            assert_eq!(synchronous, 1, "NORMAL is reported as 1");
+        }
+        drop((first, second));
+
+        // A competing writer holds the write lock; the tiny timeout must fail fast.
+        let competitor = Database::new(&db_path).await.unwrap();
+        let mut lock_holder = competitor.pool().acquire().await.unwrap();
+        sqlx::query("BEGIN IMMEDIATE")
+            .execute(&mut *lock_holder)
+            .await
+            .unwrap();
+
+        let started = std::time::Instant::now();
+        let result = sqlx::query("INSERT INTO queue (url, source_type) VALUES ('https://example.com/busy', 'direct_url')")
+            .execute(db.pool())
+            .await;
+        assert!(result.is_err(), "write should fail while the lock is held");
+        assert!(
+            started.elapsed() < Duration::from_secs(2),
+            "busy timeout of 50ms should fail well before the 5s default"
+        );
+
+        sqlx::query("ROLLBACK")
+            .execute(&mut *lock_holder)
+            .await
+            .unwrap();
+    }
+
     #[tokio::test]
     async fn test_database_queue_table_constraints() {
         let db = Database::new_in_memory().await.unwrap();
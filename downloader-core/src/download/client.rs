@@ -3,6 +3,7 @@
 //! This module provides the `HttpClient` struct which handles streaming
 //! downloads with proper timeout configuration and error handling.
 
+use std::io::Write;
 use std::panic::{AssertUnwindSafe, catch_unwind, set_hook, take_hook};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -10,10 +11,10 @@ use std::time::Duration;
 
 use futures_util::StreamExt;
 use reqwest::Client;
-use reqwest::cookie::Jar;
+use reqwest::cookie::{CookieStore, Jar};
 use reqwest::header::{
-    ACCEPT, ACCEPT_LANGUAGE, ACCEPT_RANGES, CONTENT_DISPOSITION, CONTENT_LENGTH, RANGE, REFERER,
-    RETRY_AFTER,
+    ACCEPT, ACCEPT_ENCODING, ACCEPT_LANGUAGE, ACCEPT_RANGES, CONTENT_DISPOSITION, CONTENT_ENCODING,
+    CONTENT_LENGTH, LOCATION, RANGE, REFERER, RETRY_AFTER,
 };
 use reqwest::{ClientBuilder, Proxy};
 use tokio::fs::File;
@@ -27,6 +28,12 @@ use super::filename::{
     extension_from_content_type, fallback_filename_from_url, parse_content_disposition,
     resolve_unique_path, resolve_unique_path_with_suffix_start, sanitize_filename,
 };
+use super::html_redirect::{HTML_REDIRECT_MAX_BYTES, saved_html_redirect};
+use super::redirect::{
+    RedirectHeaderScope, max_redirects, redirect_limit_exceeded, redirect_policy_with_headers,
+};
+use super::segmented::{MAX_DOWNLOAD_SEGMENTS, SegmentOutcome, download_segments};
+use crate::auth::{DomainHeaders, RecordingJar, SetCookieLog};
 use crate::user_agent;
 
 /// Browser User-Agent used as fallback when servers return 403.
@@ -73,6 +80,24 @@ pub struct HttpClient {
     /// `Referer`, `Sec-Fetch-*`) to mimic a real browser navigation.  Publishers like
     /// Wiley and ACM check these headers in addition to session cookies.
     has_auth_cookies: bool,
+    /// Per-domain headers (e.g. bearer tokens) attached to matching requests,
+    /// shared with the redirect policy so they are re-evaluated on every hop.
+    domain_headers: RedirectHeaderScope,
+    /// Request-scoped timeout replacing the client-wide read timeout.
+    read_timeout: Option<Duration>,
+    /// Parallel ranged downloads for large files, when enabled.
+    segmented: Option<SegmentedDownloads>,
+    /// Minimum accepted PDF size; also turns on the empty-body and `%PDF` checks.
+    min_file_size: Option<u64>,
+}
+
+/// Settings for [`HttpClient::with_segmented_downloads`].
+#[derive(Debug, Clone, Copy)]
+struct SegmentedDownloads {
+    /// Minimum `Content-Length` in bytes for a file to be split.
+    threshold: u64,
+    /// Number of concurrent range requests.
+    segments: usize,
 }
 
 /// Download metadata for progress reporting and resumable state persistence.
@@ -103,6 +128,7 @@ impl HttpClient {
     /// - Connect timeout: 30 seconds
     /// - Read timeout: 5 minutes (for large files)
     /// - Gzip decompression: enabled
+    /// - Redirects: up to [`max_redirects`](super::max_redirects) hops (10 unless configured)
     ///
     /// # Panics
     ///
@@ -123,11 +149,21 @@ impl HttpClient {
     #[must_use]
     #[allow(clippy::expect_used)]
     pub fn new_with_timeouts(connect_timeout_secs: u64, read_timeout_secs: u64) -> Self {
-        let client = build_client(None, connect_timeout_secs, read_timeout_secs)
-            .expect("failed to build HTTP client with static configuration");
+        let domain_headers = RedirectHeaderScope::default();
+        let client = build_client::<Jar>(
+            None,
+            &domain_headers,
+            connect_timeout_secs,
+            read_timeout_secs,
+        )
+        .expect("failed to build HTTP client with static configuration");
         Self {
             client,
             has_auth_cookies: false,
+            domain_headers,
+            read_timeout: None,
+            segmented: None,
+            min_file_size: None,
         }
     }
 
@@ -161,16 +197,144 @@ impl HttpClient {
         connect_timeout_secs: u64,
         read_timeout_secs: u64,
     ) -> Self {
-        let client = build_client(Some(cookie_jar), connect_timeout_secs, read_timeout_secs)
-            .expect("failed to build HTTP client with static configuration");
+        Self::with_cookie_store_and_timeouts(cookie_jar, connect_timeout_secs, read_timeout_secs)
+    }
+
+    /// Creates a new HTTP client with a cookie jar and explicit timeout values
+    /// that records every `Set-Cookie` header the servers send in
+    /// `set_cookie_log`, for writing cookies back to a cookie file.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client builder fails to build with the supplied
+    /// timeout configuration.
+    #[must_use]
+    #[instrument(level = "debug", skip(cookie_jar, set_cookie_log))]
+    pub fn with_recorded_cookie_jar_and_timeouts(
+        cookie_jar: Arc<Jar>,
+        set_cookie_log: Arc<SetCookieLog>,
+        connect_timeout_secs: u64,
+        read_timeout_secs: u64,
+    ) -> Self {
+        Self::with_cookie_store_and_timeouts(
+            Arc::new(RecordingJar::new(cookie_jar, set_cookie_log)),
+            connect_timeout_secs,
+            read_timeout_secs,
+        )
+    }
+
+    #[allow(clippy::expect_used)]
+    fn with_cookie_store_and_timeouts<C: CookieStore + 'static>(
+        cookie_store: Arc<C>,
+        connect_timeout_secs: u64,
+        read_timeout_secs: u64,
+    ) -> Self {
+        let domain_headers = RedirectHeaderScope::default();
+        let client = build_client(
+            Some(cookie_store),
+            &domain_headers,
+            connect_timeout_secs,
+            read_timeout_secs,
+        )
+        .expect("failed to build HTTP client with static configuration");
         Self {
             client,
             has_auth_cookies: true,
+            domain_headers,
+            read_timeout: None,
+            segmented: None,
+            min_file_size: None,
+        }
+    }
+
+    /// Wraps a caller-configured reqwest client.
+    ///
+    /// Use this to share a client's TLS settings, proxy, connection pool, or
+    /// middleware with the download engine. Everything the other constructors
+    /// configure then becomes the caller's responsibility: timeouts, the
+    /// redirect limit, decompression, the default User-Agent, and the cookie
+    /// jar all come from `client` as built. Browser-like navigation headers,
+    /// which cookie-jar clients send, are not added; the 403 browser
+    /// User-Agent retry still applies. Redirects also follow the caller's
+    /// policy, so [`HttpClient::with_domain_headers`] cannot re-evaluate
+    /// headers per hop unless `client` is built with
+    /// [`Policy::none`](reqwest::redirect::Policy::none).
+    #[must_use]
+    pub fn from_client(client: Client) -> Self {
+        Self {
+            client,
+            has_auth_cookies: false,
+            domain_headers: RedirectHeaderScope::default(),
+            read_timeout: None,
+            segmented: None,
+            min_file_size: None,
+        }
+    }
+
+    /// Attaches per-domain headers to requests whose host matches.
+    ///
+    /// Headers are added on top of any cookie jar, so token and cookie auth
+    /// compose. They are only sent over HTTPS (see
+    /// [`DomainHeaders::header_map_for_url`]) and are re-evaluated on every
+    /// redirect hop, so a redirect to another host never carries them. Copies
+    /// of this client share the headers. An empty store leaves the client
+    /// unchanged.
+    #[must_use]
+    pub fn with_domain_headers(self, domain_headers: DomainHeaders) -> Self {
+        if !domain_headers.is_empty() {
+            self.domain_headers.set(Some(Arc::new(domain_headers)));
+        }
+        self
+    }
+
+    /// Returns a copy of this client whose requests use `timeout` instead of
+    /// the read timeout it was built with.
+    ///
+    /// The copy shares the connection pool, cookie jar, and domain headers, so
+    /// it is cheap to create per download. `ftp://` and `file://` URLs ignore it.
+    #[must_use]
+    pub fn with_read_timeout(&self, timeout: Duration) -> Self {
+        Self {
+            read_timeout: Some(timeout),
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of this client that downloads files of at least
+    /// `threshold` bytes as `segments` parallel range requests.
+    ///
+    /// Only fresh HTTP(S) downloads are split, and only when a HEAD request
+    /// reports `Accept-Ranges: bytes`, an unencoded non-HTML body, and a
+    /// `Content-Length`. Anything else, including a server that ignores a
+    /// range request, falls back to a single stream. `segments` is capped at
+    /// [`MAX_DOWNLOAD_SEGMENTS`]; fewer than two turns segmenting off.
+    #[must_use]
+    pub fn with_segmented_downloads(&self, threshold: u64, segments: usize) -> Self {
+        Self {
+            segmented: (segments > 1).then_some(SegmentedDownloads {
+                threshold,
+                segments: segments.min(MAX_DOWNLOAD_SEGMENTS),
+            }),
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of this client that rejects suspicious bodies once
+    /// downloaded: empty files, and files saved as `.pdf` that are under
+    /// `min_bytes` or do not start with `%PDF` (typically an HTML "access
+    /// denied" page served with status 200).
+    ///
+    /// A rejected body fails with [`DownloadError::SuspiciousContent`] and its
+    /// partial file is removed.
+    #[must_use]
+    pub fn with_min_file_size(&self, min_bytes: u64) -> Self {
+        Self {
+            min_file_size: Some(min_bytes),
+            ..self.clone()
         }
     }
 
     /// Returns `true` if this client was built with an auth cookie jar.
-    #[cfg(test)]
     #[must_use]
     pub(crate) fn has_auth_cookies(&self) -> bool {
         self.has_auth_cookies
@@ -183,6 +347,15 @@ impl HttpClient {
     /// 2. URL path (last segment)
     /// 3. Timestamp-based fallback
     ///
+    /// The body streams into a sibling `<name>.part` file that is renamed to
+    /// the final name only once it is complete, so an interrupted download
+    /// never leaves a truncated file under the final name. Resume continues
+    /// the `.part` file.
+    ///
+    /// With the `file-urls` and `ftp` features, `file://` URLs are copied
+    /// from disk and `ftp://` URLs are fetched over FTP, using the same
+    /// naming rules but no resume.
+    ///
     /// # Arguments
     ///
     /// * `url` - The URL to download from
@@ -245,8 +418,15 @@ impl HttpClient {
         preferred_filename: Option<&str>,
         resume_bytes_hint: Option<u64>,
     ) -> Result<DownloadFileResult, DownloadError> {
-        self.download_to_file_inner(url, output_dir, None, preferred_filename, resume_bytes_hint)
-            .await
+        self.download_to_file_inner(
+            url,
+            output_dir,
+            None,
+            preferred_filename,
+            resume_bytes_hint,
+            true,
+        )
+        .await
     }
 
     /// Downloads a file using a custom User-Agent header.
@@ -291,11 +471,59 @@ impl HttpClient {
             Some(user_agent),
             preferred_filename,
             resume_bytes_hint,
+            true,
         )
         .await
     }
 
+    /// Downloads `url` and streams the body into `writer` instead of a file.
+    ///
+    /// Uses the same request headers and error handling as
+    /// [`download_to_file`](Self::download_to_file), without filename
+    /// resolution or resume support. Intended for piping a single download
+    /// into another program via stdout.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DownloadError` if:
+    /// - The URL is invalid
+    /// - The request fails (network error, timeout)
+    /// - The server returns an error status (4xx, 5xx)
+    /// - Writing to `writer` fails
+    #[instrument(skip(self, writer), fields(url = %url))]
+    pub async fn download_to_writer<W: Write>(
+        &self,
+        url: &str,
+        writer: &mut W,
+    ) -> Result<u64, DownloadError> {
+        debug!("starting download to writer");
+
+        Url::parse(url).map_err(|_| DownloadError::invalid_url(url.to_string()))?;
+        let response = self.send_request("GET", url, None, None).await?;
+
+        let bytes_written = stream_to_sink(
+            &mut WriterSink(writer),
+            response,
+            url,
+            Path::new("<writer>"),
+        )
+        .await?;
+
+        info!(bytes = bytes_written, "download to writer complete");
+        Ok(bytes_written)
+    }
+
     /// Inner implementation shared by both download methods.
+    ///
+    /// With `follow_html_redirect`, a small HTML body carrying a meta refresh
+    /// or `location.href` redirect is discarded and its target downloaded
+    /// instead. That follow-up never follows another HTML redirect, and its
+    /// HTTP redirects stay bounded by [`max_redirects`]; a limit of 0 disables
+    /// the follow entirely.
     #[allow(clippy::too_many_lines)]
     async fn download_to_file_inner(
         &self,
@@ -304,6 +532,7 @@ impl HttpClient {
         user_agent: Option<&str>,
         preferred_filename: Option<&str>,
         resume_bytes_hint: Option<u64>,
+        follow_html_redirect: bool,
     ) -> Result<DownloadFileResult, DownloadError> {
         debug!("starting download");
 
@@ -311,20 +540,34 @@ impl HttpClient {
         let parsed_url =
             Url::parse(url).map_err(|_| DownloadError::invalid_url(url.to_string()))?;
 
+        #[cfg(feature = "file-urls")]
+        if parsed_url.scheme() == "file" {
+            return super::sources::copy_file_url(&parsed_url, output_dir, preferred_filename)
+                .await;
+        }
+        #[cfg(feature = "ftp")]
+        if parsed_url.scheme() == "ftp" {
+            return super::ftp::download_ftp(&parsed_url, output_dir, preferred_filename).await;
+        }
+
         let url_filename = fallback_filename_from_url(&parsed_url);
+        let requested_filename = preferred_filename;
         let preferred_filename = preferred_filename
             .map(sanitize_filename)
             .filter(|name| !name.is_empty());
         let candidate_name = preferred_filename
             .clone()
             .unwrap_or_else(|| url_filename.clone());
-        let candidate_path = output_dir.join(&candidate_name);
+        // Bytes stream into `<name>.part` and are renamed into place once
+        // complete, so a killed run never leaves a truncated file under the
+        // final name. Resume continues the candidate's `.part` file.
+        let candidate_part_path = part_path_for(&output_dir.join(&candidate_name));
 
         let expected_partial_bytes = resume_bytes_hint.unwrap_or(0);
         let allow_resume = preferred_filename.is_none() || expected_partial_bytes > 0;
         let (existing_bytes, supports_ranges, resume_attempted) = self
             .determine_resume_state(
-                &candidate_path,
+                &candidate_part_path,
                 url,
                 user_agent,
                 preferred_filename.is_some(),
@@ -333,81 +576,110 @@ impl HttpClient {
             )
             .await;
 
-        let use_resume = supports_ranges && existing_bytes > 0;
+        if existing_bytes == 0
+            && let Some(segmented) = self.segmented
+            && let Some(download) = self
+                .try_segmented_download(
+                    url,
+                    &parsed_url,
+                    output_dir,
+                    user_agent,
+                    preferred_filename.as_deref(),
+                    segmented,
+                )
+                .await?
+        {
+            return Ok(download);
+        }
+
+        let mut use_resume = supports_ranges && existing_bytes > 0;
         let range_value = use_resume.then(|| format!("bytes={existing_bytes}-"));
 
         // Send GET request, optionally with Range and User-Agent overrides.
-        let response = self
+        let mut response = self
             .send_request("GET", url, user_agent, range_value.as_deref())
             .await?;
 
+        // The client drops Content-Length when it decodes a Content-Encoding,
+        // so a 206 without it was compressed despite `identity` and cannot be
+        // appended to the partial file. Restart the file from scratch instead.
+        let restart_in_place = use_resume
+            && response.status().as_u16() == 206
+            && response.headers().get(CONTENT_LENGTH).is_none();
+        if restart_in_place {
+            warn!(
+                url,
+                "encoded partial response; restarting download without resume"
+            );
+            use_resume = false;
+            response = self.send_request("GET", url, user_agent, None).await?;
+        }
+
         let response_status = response.status();
         let response_filename = extract_filename(&response, &parsed_url);
 
         // If preferred_filename has a .bin extension (unknown type at resolve time),
         // correct it using the actual Content-Type from the response.
-        let preferred_filename = preferred_filename.map(|pf| {
-            if std::path::Path::new(&pf)
-                .extension()
-                .is_some_and(|ext| ext.eq_ignore_ascii_case("bin"))
-            {
-                let ct_ext = response
-                    .headers()
-                    .get("content-type")
-                    .and_then(|ct| ct.to_str().ok())
-                    .map_or(".bin", extension_from_content_type);
-                if ct_ext == ".bin" {
-                    pf
-                } else {
-                    format!("{}{ct_ext}", &pf[..pf.len() - 4])
-                }
-            } else {
-                pf
-            }
-        });
+        let preferred_filename = preferred_filename.map(|pf| correct_bin_extension(pf, &response));
 
-        // For resume: use the existing partial file path directly.
+        // For resume: continue the candidate's partial file under the candidate name.
         // For fresh downloads: resolve a unique path from the response filename.
-        let file_path = if use_resume && response_status.as_u16() == 206 {
-            candidate_path
+        let continues_candidate =
+            (use_resume && response_status.as_u16() == 206) || restart_in_place;
+        let file_path = if continues_candidate {
+            if preferred_filename.is_some() {
+                resolve_unique_path_with_suffix_start(output_dir, &candidate_name, 2)
+            } else {
+                resolve_unique_path(output_dir, &candidate_name)
+            }
         } else if let Some(preferred) = preferred_filename {
             resolve_unique_path_with_suffix_start(output_dir, &preferred, 2)
         } else {
             resolve_unique_path(output_dir, &response_filename)
         };
+        let part_path = if continues_candidate {
+            candidate_part_path
+        } else {
+            part_path_for(&file_path)
+        };
         debug!(filename = %response_filename, path = %file_path.display(), "resolved output path");
 
-        // Open output file (append for true resume, create/truncate otherwise)
+        // Open the partial file (append for true resume, create/truncate otherwise)
         let mut file = if use_resume && response_status.as_u16() == 206 {
             let mut handle = tokio::fs::OpenOptions::new()
                 .create(true)
                 .append(true)
-                .open(&file_path)
+                .open(&part_path)
                 .await
-                .map_err(|e| DownloadError::io(file_path.clone(), e))?;
+                .map_err(|e| DownloadError::io(part_path.clone(), e))?;
             handle
                 .seek(std::io::SeekFrom::End(0))
                 .await
-                .map_err(|e| DownloadError::io(file_path.clone(), e))?;
+                .map_err(|e| DownloadError::io(part_path.clone(), e))?;
             handle
         } else {
-            File::create(&file_path)
+            File::create(&part_path)
                 .await
-                .map_err(|e| DownloadError::io(file_path.clone(), e))?
+                .map_err(|e| DownloadError::write_failed(part_path.clone(), e))?
         };
 
         let content_length = derive_total_content_length(&response, existing_bytes);
 
-        // Stream response body to file, with cleanup on error
-        let stream_result = stream_to_file(&mut file, response, url, &file_path).await;
-
-        if stream_result.is_err() {
-            // If the download failed and we weren't resuming (or the server didn't support resume),
-            // clean up the partial file to avoid leaving incomplete data.
-            if response_status.as_u16() != 206 {
-                debug!(path = %file_path.display(), "cleaning up partial file after error");
-                let _ = tokio::fs::remove_file(&file_path).await;
-            }
+        // HTML served for a binary URL may be a login page that carries no
+        // login pattern in its URL; its form is checked once it is on disk.
+        let login_form_domain = (is_expected_binary(url) && is_html_response(&response))
+            .then(|| response.url().host_str().unwrap_or("unknown").to_string());
+        let html_redirect_base =
+            (follow_html_redirect && max_redirects() > 0 && is_html_response(&response))
+                .then(|| response.url().clone());
+
+        // Stream response body to the partial file, with cleanup on error
+        let stream_result = stream_to_file(&mut file, response, url, &part_path).await;
+        // Close the handle first so a cleanup removal or rename also succeeds on Windows.
+        drop(file);
+
+        if let Err(error) = &stream_result {
+            cleanup_after_stream_error(&part_path, error, response_status.as_u16() == 206).await;
         }
 
         let bytes_written = stream_result?;
@@ -422,6 +694,9 @@ impl HttpClient {
             && response_status.as_u16() == 206
             && content_length.is_some_and(|expected| expected != final_size)
         {
+            // The partial no longer matches the server's copy; resuming it
+            // again would only repeat the mismatch.
+            let _ = tokio::fs::remove_file(&part_path).await;
             return Err(DownloadError::integrity(
                 file_path.clone(),
                 content_length.unwrap_or(0),
@@ -429,6 +704,39 @@ impl HttpClient {
             ));
         }
 
+        if let Some(domain) = login_form_domain
+            && saved_file_is_login_form(&part_path).await
+        {
+            debug!(url, domain = %domain, "saved HTML is a login form");
+            let _ = tokio::fs::remove_file(&part_path).await;
+            return Err(DownloadError::auth_required(url, 0, domain));
+        }
+
+        if let Some(base) = html_redirect_base
+            && final_size <= HTML_REDIRECT_MAX_BYTES
+            && let Some(target) = saved_html_redirect(&part_path, &base).await
+        {
+            debug!(url, target = %target, "following HTML redirect from landing page");
+            let _ = tokio::fs::remove_file(&part_path).await;
+            return Box::pin(self.download_to_file_inner(
+                target.as_str(),
+                output_dir,
+                user_agent,
+                requested_filename,
+                None,
+                false,
+            ))
+            .await;
+        }
+
+        self.check_content_guard(url, &part_path, &file_path, final_size)
+            .await?;
+
+        // Same directory, so the rename stays on one filesystem and is atomic.
+        tokio::fs::rename(&part_path, &file_path)
+            .await
+            .map_err(|e| DownloadError::io(file_path.clone(), e))?;
+
         info!(
             path = %file_path.display(),
             bytes = final_size,
@@ -445,7 +753,90 @@ impl HttpClient {
         })
     }
 
-    async fn send_request(
+    pub(super) async fn send_request(
+        &self,
+        method: &str,
+        url: &str,
+        user_agent: Option<&str>,
+        range_header: Option<&str>,
+    ) -> Result<reqwest::Response, DownloadError> {
+        let mut response = self
+            .dispatch_request(method, url, user_agent, range_header)
+            .await?;
+        // The redirect policy hands back hops that would change the domain
+        // headers, so they are followed here with headers for the new URL.
+        let mut hops = 0;
+        while let Some(next) = self.header_changing_redirect(&response) {
+            if hops == max_redirects() {
+                return Err(DownloadError::too_many_redirects(
+                    url,
+                    max_redirects(),
+                    vec![response.url().to_string(), next.to_string()],
+                ));
+            }
+            hops += 1;
+            debug!(
+                from = %response.url(),
+                to = %next,
+                "following redirect with re-evaluated domain headers"
+            );
+            response = self
+                .dispatch_request(method, next.as_str(), user_agent, range_header)
+                .await?;
+        }
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let status_code = status.as_u16();
+
+            // Promote auth-related status codes to AuthRequired
+            if matches!(status_code, 401 | 403 | 407) {
+                let domain = Url::parse(url)
+                    .ok()
+                    .and_then(|u| u.host_str().map(std::string::ToString::to_string))
+                    .unwrap_or_else(|| url.to_string());
+                return Err(DownloadError::auth_required(url, status_code, domain));
+            }
+
+            let retry_after = response
+                .headers()
+                .get(RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .map(std::string::ToString::to_string);
+            return Err(DownloadError::http_status_with_retry_after(
+                url,
+                status_code,
+                retry_after,
+            ));
+        }
+
+        // Detect login redirect: server returned 200 with HTML when a binary file
+        // was expected. Only flag when the URL path ends in a known binary extension.
+        if method == "GET" {
+            if let Some(auth_err) = detect_login_redirect(url, &response) {
+                return Err(auth_err);
+            }
+        }
+
+        Ok(response)
+    }
+
+    /// Returns the target of a redirect response that the redirect policy
+    /// stopped at because the target's domain headers differ.
+    fn header_changing_redirect(&self, response: &reqwest::Response) -> Option<Url> {
+        if !response.status().is_redirection() {
+            return None;
+        }
+        let location = response.headers().get(LOCATION)?.to_str().ok()?;
+        let next = response.url().join(location).ok()?;
+        self.domain_headers
+            .changes_headers(response.url(), &next)
+            .then_some(next)
+    }
+
+    /// Sends one request with this client's headers; redirects follow the
+    /// client's policy.
+    async fn dispatch_request(
         &self,
         method: &str,
         url: &str,
@@ -456,11 +847,23 @@ impl HttpClient {
             "HEAD" => self.client.head(url),
             _ => self.client.get(url),
         };
+        if let Some(timeout) = self.read_timeout {
+            request = request.timeout(timeout);
+        }
         if let Some(ua) = user_agent {
             request = request.header(reqwest::header::USER_AGENT, ua);
         }
+        if let Some(domain_headers) = self.domain_headers.get()
+            && let Ok(parsed_url) = Url::parse(url)
+        {
+            request = request.headers(domain_headers.header_map_for_url(&parsed_url));
+        }
         if let Some(range) = range_header {
-            request = request.header(RANGE, range);
+            // Byte offsets in a Range refer to the stored representation; a
+            // compressed 206 body would be decoded and appended as garbage.
+            request = request
+                .header(RANGE, range)
+                .header(ACCEPT_ENCODING, "identity");
         }
 
         // When auth cookies are present, or on a browser-UA retry, add browser-like
@@ -489,53 +892,131 @@ impl HttpClient {
             }
         }
 
-        let response = request.send().await.map_err(|e| {
+        request.send().await.map_err(|e| {
             if e.is_timeout() {
                 DownloadError::timeout(url)
+            } else if let Some(exceeded) = redirect_limit_exceeded(&e) {
+                DownloadError::too_many_redirects(
+                    url,
+                    exceeded.max_redirects,
+                    exceeded.hops.clone(),
+                )
             } else {
                 DownloadError::network(url, e)
             }
-        })?;
+        })
+    }
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let status_code = status.as_u16();
+    /// Downloads `url` as parallel range requests when the HEAD response
+    /// qualifies for [`with_segmented_downloads`](Self::with_segmented_downloads).
+    ///
+    /// Returns `Ok(None)` when the caller should use a single stream instead.
+    /// The `.part` file is removed on fallback and on error, since a
+    /// preallocated partial cannot be resumed.
+    async fn try_segmented_download(
+        &self,
+        url: &str,
+        parsed_url: &Url,
+        output_dir: &Path,
+        user_agent: Option<&str>,
+        preferred_filename: Option<&str>,
+        segmented: SegmentedDownloads,
+    ) -> Result<Option<DownloadFileResult>, DownloadError> {
+        let Ok(head) = self.send_request("HEAD", url, user_agent, None).await else {
+            return Ok(None);
+        };
+        let Some(total) = segmentable_length(&head, segmented.threshold) else {
+            return Ok(None);
+        };
 
-            // Promote auth-related status codes to AuthRequired
-            if matches!(status_code, 401 | 403 | 407) {
-                let domain = Url::parse(url)
-                    .ok()
-                    .and_then(|u| u.host_str().map(std::string::ToString::to_string))
-                    .unwrap_or_else(|| url.to_string());
-                return Err(DownloadError::auth_required(url, status_code, domain));
-            }
+        let file_path = match preferred_filename {
+            Some(preferred) => resolve_unique_path_with_suffix_start(
+                output_dir,
+                &correct_bin_extension(preferred.to_string(), &head),
+                2,
+            ),
+            None => resolve_unique_path(output_dir, &extract_filename(&head, parsed_url)),
+        };
+        let part_path = part_path_for(&file_path);
+        drop(head);
 
-            let retry_after = response
-                .headers()
-                .get(RETRY_AFTER)
-                .and_then(|v| v.to_str().ok())
-                .map(std::string::ToString::to_string);
-            return Err(DownloadError::http_status_with_retry_after(
-                url,
-                status_code,
-                retry_after,
-            ));
+        match download_segments(self, url, user_agent, &part_path, total, segmented.segments).await
+        {
+            Ok(SegmentOutcome::Complete) => {}
+            Ok(SegmentOutcome::RangesIgnored) => {
+                let _ = tokio::fs::remove_file(&part_path).await;
+                info!(
+                    url,
+                    "server ignored range requests; downloading as one stream"
+                );
+                return Ok(None);
+            }
+            Err(error) => {
+                let _ = tokio::fs::remove_file(&part_path).await;
+                return Err(error);
+            }
         }
 
-        // Detect login redirect: server returned 200 with HTML when a binary file
-        // was expected. Only flag when the URL path ends in a known binary extension.
-        if method == "GET" {
-            if let Some(auth_err) = detect_login_redirect(url, &response) {
-                return Err(auth_err);
+        self.check_content_guard(url, &part_path, &file_path, total)
+            .await?;
+
+        tokio::fs::rename(&part_path, &file_path)
+            .await
+            .map_err(|e| DownloadError::io(file_path.clone(), e))?;
+
+        info!(
+            path = %file_path.display(),
+            bytes = total,
+            segments = segmented.segments,
+            "segmented download complete"
+        );
+
+        Ok(Some(DownloadFileResult {
+            path: file_path,
+            bytes_downloaded: total,
+            content_length: Some(total),
+            resumed: false,
+            resume_attempted: false,
+        }))
+    }
+
+    /// Applies [`Self::with_min_file_size`] to a completed `.part` file,
+    /// removing it when the body is rejected.
+    async fn check_content_guard(
+        &self,
+        url: &str,
+        part_path: &Path,
+        file_path: &Path,
+        size: u64,
+    ) -> Result<(), DownloadError> {
+        let Some(min_file_size) = self.min_file_size else {
+            return Ok(());
+        };
+        let expects_pdf = is_pdf_path(file_path);
+        let reason = if size == 0 {
+            Some("body is empty".to_string())
+        } else if expects_pdf && size < min_file_size {
+            Some(format!(
+                "PDF body is {size} bytes, below the {min_file_size}-byte minimum"
+            ))
+        } else if expects_pdf && !starts_with_pdf_signature(part_path).await {
+            Some("expected a PDF but the body does not start with %PDF".to_string())
+        } else {
+            None
+        };
+        match reason {
+            Some(reason) => {
+                debug!(url, reason = %reason, "rejecting suspicious download");
+                let _ = tokio::fs::remove_file(part_path).await;
+                Err(DownloadError::suspicious_content(url, reason))
             }
+            None => Ok(()),
         }
-
-        Ok(response)
     }
 
     async fn determine_resume_state(
         &self,
-        candidate_path: &Path,
+        partial_path: &Path,
         url: &str,
         user_agent: Option<&str>,
         has_preferred_filename: bool,
@@ -543,7 +1024,7 @@ impl HttpClient {
         expected_partial_bytes: u64,
     ) -> (u64, bool, bool) {
         let existing_bytes = if allow_resume {
-            tokio::fs::metadata(candidate_path)
+            tokio::fs::metadata(partial_path)
                 .await
                 .map_or(0, |meta| meta.len())
         } else {
@@ -578,46 +1059,116 @@ impl HttpClient {
     }
 }
 
+/// Destination for streamed response bytes.
+///
+/// Lets the file and arbitrary-writer download paths share one streaming loop.
+trait ChunkSink {
+    async fn write_chunk(&mut self, chunk: &[u8]) -> std::io::Result<()>;
+    async fn finish(&mut self) -> std::io::Result<()>;
+}
+
+impl ChunkSink for BufWriter<&mut File> {
+    async fn write_chunk(&mut self, chunk: &[u8]) -> std::io::Result<()> {
+        self.write_all(chunk).await
+    }
+
+    async fn finish(&mut self) -> std::io::Result<()> {
+        self.flush().await
+    }
+}
+
+/// Adapts a blocking [`Write`] sink (stdout, an in-memory buffer) to [`ChunkSink`].
+struct WriterSink<'a, W: Write>(&'a mut W);
+
+impl<W: Write> ChunkSink for WriterSink<'_, W> {
+    async fn write_chunk(&mut self, chunk: &[u8]) -> std::io::Result<()> {
+        self.0.write_all(chunk)
+    }
+
+    async fn finish(&mut self) -> std::io::Result<()> {
+        self.0.flush()
+    }
+}
+
+/// Suffix of the sibling file a download streams into before it is complete.
+const PART_FILE_SUFFIX: &str = ".part";
+
+/// Returns the in-progress path for `file_path` (`paper.pdf` → `paper.pdf.part`).
+pub(super) fn part_path_for(file_path: &Path) -> PathBuf {
+    let mut part = file_path.as_os_str().to_owned();
+    part.push(PART_FILE_SUFFIX);
+    PathBuf::from(part)
+}
+
+/// Removes the partially written file after a streaming failure (best-effort).
+///
+/// A resumable partial (HTTP 206) is kept after network failures so a later
+/// run can continue it. Local write failures (disk full, I/O errors) always
+/// discard the file: it is truncated at an unknown point and, when the disk is
+/// full, holding on to it only keeps the volume full.
+async fn cleanup_after_stream_error(file_path: &Path, error: &DownloadError, resumable: bool) {
+    let write_failed = matches!(
+        error,
+        DownloadError::DiskFull { .. } | DownloadError::Io { .. }
+    );
+    if resumable && !write_failed {
+        return;
+    }
+    debug!(path = %file_path.display(), "cleaning up partial file after error");
+    let _ = tokio::fs::remove_file(file_path).await;
+}
+
 /// Streams response body to file, returning bytes written.
 ///
 /// This is extracted to enable cleanup on error in the caller.
-async fn stream_to_file(
+pub(super) async fn stream_to_file(
     file: &mut File,
     response: reqwest::Response,
     url: &str,
     file_path: &Path,
 ) -> Result<u64, DownloadError> {
-    let mut writer = BufWriter::new(file);
+    stream_to_sink(&mut BufWriter::new(file), response, url, file_path).await
+}
+
+/// Streams the response body into `sink`, flushing once the body is exhausted.
+///
+/// `sink_path` only labels I/O errors.
+async fn stream_to_sink(
+    sink: &mut impl ChunkSink,
+    response: reqwest::Response,
+    url: &str,
+    sink_path: &Path,
+) -> Result<u64, DownloadError> {
     let mut stream = response.bytes_stream();
     let mut bytes_written: u64 = 0;
 
     while let Some(chunk_result) = stream.next().await {
         let chunk = chunk_result.map_err(|e| DownloadError::network(url, e))?;
 
-        writer
-            .write_all(&chunk)
+        sink.write_chunk(&chunk)
             .await
-            .map_err(|e| DownloadError::io(file_path.to_path_buf(), e))?;
+            .map_err(|e| DownloadError::write_failed(sink_path, e))?;
 
         bytes_written += chunk.len() as u64;
     }
 
-    // Ensure all data is flushed to disk
-    writer
-        .flush()
+    // Ensure all data is flushed to the sink
+    sink.finish()
         .await
-        .map_err(|e| DownloadError::io(file_path.to_path_buf(), e))?;
+        .map_err(|e| DownloadError::write_failed(sink_path, e))?;
 
     Ok(bytes_written)
 }
 
-fn build_client(
-    cookie_jar: Option<Arc<Jar>>,
+fn build_client<C: CookieStore + 'static>(
+    cookie_jar: Option<Arc<C>>,
+    domain_headers: &RedirectHeaderScope,
     connect_timeout_secs: u64,
     read_timeout_secs: u64,
 ) -> Result<Client, reqwest::Error> {
     let initial = try_build_client(
         cookie_jar.clone(),
+        domain_headers,
         connect_timeout_secs,
         read_timeout_secs,
         false,
@@ -628,7 +1179,13 @@ fn build_client(
             warn!(
                 "HTTP client builder panicked while loading system proxy settings; retrying with env-proxy fallback"
             );
-            match try_build_client(cookie_jar, connect_timeout_secs, read_timeout_secs, true) {
+            match try_build_client(
+                cookie_jar,
+                domain_headers,
+                connect_timeout_secs,
+                read_timeout_secs,
+                true,
+            ) {
                 Ok(client) => Ok(client),
                 Err(BuildClientFailure::Build(error)) => Err(error),
                 Err(BuildClientFailure::Panic) => {
@@ -650,8 +1207,9 @@ enum BuildClientFailure {
 // briefly to keep CLI stderr deterministic for expected recovery paths.
 static CLIENT_BUILD_PANIC_HOOK_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
 
-fn try_build_client(
-    cookie_jar: Option<Arc<Jar>>,
+fn try_build_client<C: CookieStore + 'static>(
+    cookie_jar: Option<Arc<C>>,
+    domain_headers: &RedirectHeaderScope,
     connect_timeout_secs: u64,
     read_timeout_secs: u64,
     disable_system_proxy_lookup: bool,
@@ -660,7 +1218,12 @@ fn try_build_client(
         #[cfg(test)]
         maybe_inject_client_build_panic(disable_system_proxy_lookup);
 
-        let mut builder = base_client_builder(cookie_jar, connect_timeout_secs, read_timeout_secs);
+        let mut builder = base_client_builder(
+            cookie_jar,
+            domain_headers,
+            connect_timeout_secs,
+            read_timeout_secs,
+        );
         if disable_system_proxy_lookup {
             builder = apply_env_proxy_fallback(builder.no_proxy());
         }
@@ -683,8 +1246,9 @@ where
     outcome
 }
 
-fn base_client_builder(
-    cookie_jar: Option<Arc<Jar>>,
+fn base_client_builder<C: CookieStore + 'static>(
+    cookie_jar: Option<Arc<C>>,
+    domain_headers: &RedirectHeaderScope,
     connect_timeout_secs: u64,
     read_timeout_secs: u64,
 ) -> ClientBuilder {
@@ -692,6 +1256,12 @@ fn base_client_builder(
         .connect_timeout(Duration::from_secs(connect_timeout_secs))
         .timeout(Duration::from_secs(read_timeout_secs))
         .gzip(true)
+        .deflate(true)
+        .brotli(true)
+        .redirect(redirect_policy_with_headers(
+            max_redirects(),
+            domain_headers.clone(),
+        ))
         .user_agent(user_agent::default_download_user_agent());
     if let Some(jar) = cookie_jar {
         builder = builder.cookie_provider(jar);
@@ -759,9 +1329,10 @@ fn maybe_inject_client_build_panic(disable_system_proxy_lookup: bool) {
 
 /// Extracts filename from Content-Disposition header or URL path.
 fn extract_filename(response: &reqwest::Response, url: &Url) -> String {
-    // Try Content-Disposition header first
+    // Try Content-Disposition header first; some servers send raw UTF-8 bytes,
+    // which `HeaderValue::to_str` rejects.
     if let Some(cd) = response.headers().get(CONTENT_DISPOSITION) {
-        if let Ok(cd_str) = cd.to_str() {
+        if let Ok(cd_str) = std::str::from_utf8(cd.as_bytes()) {
             if let Some(filename) = parse_content_disposition(cd_str) {
                 return sanitize_filename(&filename);
             }
@@ -813,6 +1384,45 @@ fn extract_filename(response: &reqwest::Response, url: &Url) -> String {
     format!("download_{timestamp}{extension}")
 }
 
+/// Replaces a `.bin` extension (type unknown at resolve time) with one
+/// derived from the response `Content-Type`, when that is more specific.
+fn correct_bin_extension(preferred: String, response: &reqwest::Response) -> String {
+    if !Path::new(&preferred)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("bin"))
+    {
+        return preferred;
+    }
+    let ct_ext = response
+        .headers()
+        .get("content-type")
+        .and_then(|ct| ct.to_str().ok())
+        .map_or(".bin", extension_from_content_type);
+    if ct_ext == ".bin" {
+        preferred
+    } else {
+        format!("{}{ct_ext}", &preferred[..preferred.len() - 4])
+    }
+}
+
+/// Returns the body size when a HEAD response allows a segmented download of
+/// at least `threshold` bytes.
+fn segmentable_length(head: &reqwest::Response, threshold: u64) -> Option<u64> {
+    let headers = head.headers();
+    let accepts_ranges = headers
+        .get(ACCEPT_RANGES)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.eq_ignore_ascii_case("bytes"));
+    if !accepts_ranges || headers.contains_key(CONTENT_ENCODING) || is_html_response(head) {
+        return None;
+    }
+    headers
+        .get(CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .filter(|&length| length > 0 && length >= threshold)
+}
+
 fn derive_total_content_length(response: &reqwest::Response, existing_bytes: u64) -> Option<u64> {
     let current = response
         .headers()
@@ -844,8 +1454,17 @@ const LOGIN_PATTERNS: &[&str] = &[
     "/oauth",
     "/openid",
     "/idp/",
+    "/shibboleth.sso/",
 ];
 
+/// Host labels that identify institutional login/SSO servers (`idp.example.edu`).
+///
+/// Any host containing `ezproxy` is treated the same way.
+const AUTH_HOST_LABELS: &[&str] = &["idp", "login", "sso", "shibboleth", "wayf"];
+
+/// Bytes of a saved HTML response inspected for a login form.
+const LOGIN_FORM_SNIFF_BYTES: u64 = 64 * 1024;
+
 /// Derives a browser-navigation `Referer` URL from a publisher PDF download URL.
 ///
 /// Many publishers (Wiley, ACM, Springer, etc.) serve their PDF downloads
@@ -879,35 +1498,105 @@ fn is_expected_binary(url: &str) -> bool {
     BINARY_EXTENSIONS.iter().any(|ext| path.ends_with(ext))
 }
 
-/// Detects login redirect: HTML response returned when a binary file was expected.
+/// Returns true if the response declares an HTML body.
+fn is_html_response(response: &reqwest::Response) -> bool {
+    response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|content_type| content_type.to_ascii_lowercase().contains("text/html"))
+}
+
+/// Returns true if `url` looks like a login/SSO page: a login path pattern, an
+/// SSO host label (`idp.`, `login.`, ...), or an `EZproxy` host.
+fn is_auth_location(url: &Url) -> bool {
+    let path_and_query = url[url::Position::BeforePath..].to_lowercase();
+    if LOGIN_PATTERNS
+        .iter()
+        .any(|pattern| path_and_query.contains(pattern))
+    {
+        return true;
+    }
+    let host = url.host_str().unwrap_or_default().to_ascii_lowercase();
+    host.contains("ezproxy")
+        || host
+            .split('.')
+            .any(|label| AUTH_HOST_LABELS.contains(&label))
+}
+
+/// Returns true when an HTML document contains a password field inside a form.
+fn looks_like_login_form(html: &str) -> bool {
+    let lowered = html.to_ascii_lowercase();
+    lowered.contains("<form")
+        && ["type=\"password\"", "type='password'", "type=password"]
+            .iter()
+            .any(|marker| lowered.contains(marker))
+}
+
+/// Leading bytes of every PDF file.
+const PDF_SIGNATURE: &[u8] = b"%PDF";
+
+fn is_pdf_path(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("pdf"))
+}
+
+/// Returns true if the file starts with [`PDF_SIGNATURE`], allowing leading
+/// whitespace as PDF readers do.
+async fn starts_with_pdf_signature(path: &Path) -> bool {
+    use tokio::io::AsyncReadExt;
+
+    let Ok(file) = File::open(path).await else {
+        return false;
+    };
+    let mut head = Vec::new();
+    if file.take(1024).read_to_end(&mut head).await.is_err() {
+        return false;
+    }
+    head.trim_ascii_start().starts_with(PDF_SIGNATURE)
+}
+
+/// Reads the start of a saved HTML file and checks it for a login form.
+async fn saved_file_is_login_form(path: &Path) -> bool {
+    use tokio::io::AsyncReadExt;
+
+    let Ok(file) = File::open(path).await else {
+        return false;
+    };
+    let mut head = Vec::new();
+    if file
+        .take(LOGIN_FORM_SNIFF_BYTES)
+        .read_to_end(&mut head)
+        .await
+        .is_err()
+    {
+        return false;
+    }
+    looks_like_login_form(&String::from_utf8_lossy(&head))
+}
+
+/// Detects login redirect: an HTML response from a login/SSO location when a
+/// binary file was expected or the request was redirected there.
 /// Returns `Some(DownloadError::AuthRequired)` if a login redirect is detected.
 fn detect_login_redirect(
     original_url: &str,
     response: &reqwest::Response,
 ) -> Option<DownloadError> {
-    if !is_expected_binary(original_url) {
+    // A URL the user asked for directly is trusted unless it should have been
+    // a binary; a redirect away from it is what signals an SSO bounce.
+    let redirected = Url::parse(original_url).ok().as_ref() != Some(response.url());
+    if !is_expected_binary(original_url) && !redirected {
         return None;
     }
 
-    let content_type = response
-        .headers()
-        .get(reqwest::header::CONTENT_TYPE)
-        .and_then(|v| v.to_str().ok())
-        .unwrap_or("");
-
-    if !content_type.to_ascii_lowercase().contains("text/html") {
+    if !is_html_response(response) {
         return None;
     }
 
-    // Check if the final response URL has login/SSO patterns.
-    // Only classify as auth-required when a login pattern IS present.
-    // Without a pattern match, it may be a server error page — not auth.
+    // Only classify as auth-required when the final URL looks like a login page.
+    // Without a match, it may be a server error page or landing page — not auth.
     let response_url = response.url().as_str();
-    let has_login_pattern = LOGIN_PATTERNS
-        .iter()
-        .any(|pattern| response_url.to_lowercase().contains(pattern));
-
-    if !has_login_pattern {
+    if !is_auth_location(response.url()) {
         debug!(
             url = %original_url,
             response_url = %response_url,
@@ -1015,36 +1704,370 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_http_client_download_success() {
+    async fn test_http_client_download_success() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        let temp_dir = TempDir::new().unwrap();
+
+        Mock::given(method("GET"))
+            .and(path("/test.pdf"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"PDF content here"))
+            .mount(&mock_server)
+            .await;
+
+        let client = HttpClient::new();
+        let url = format!("{}/test.pdf", mock_server.uri());
+
+        let result = client.download_to_file(&url, temp_dir.path()).await;
+
+        assert!(result.is_ok(), "Expected Ok, got: {:?}", result);
+        let file_path = result.unwrap();
+        assert!(file_path.exists());
+        let contents = std::fs::read(&file_path).unwrap();
+        assert_eq!(contents, b"PDF content here");
+        assert!(
+            file_path
+                .file_name()
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .contains("test")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_http_client_from_client_uses_caller_configuration() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/custom.pdf"))
+            .and(wiremock::matchers::header("user-agent", "embedder/1.0"))
+            .and(wiremock::matchers::header("x-trace-id", "abc"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"PDF content here"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            "x-trace-id",
+            reqwest::header::HeaderValue::from_static("abc"),
+        );
+        let inner = Client::builder()
+            .user_agent("embedder/1.0")
+            .default_headers(headers)
+            .build()
+            .unwrap();
+        let client = HttpClient::from_client(inner);
+        let mut sink = Vec::new();
+
+        client
+            .download_to_writer(&format!("{}/custom.pdf", mock_server.uri()), &mut sink)
+            .await
+            .unwrap();
+
+        assert_eq!(sink, b"PDF content here");
+        assert!(!client.has_auth_cookies());
+    }
+
+    #[tokio::test]
+    async fn test_min_file_size_rejects_tiny_and_html_bodies_and_keeps_pdfs() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        let temp_dir = TempDir::new().unwrap();
+        let pdf = [b"%PDF-1.7\n".as_slice(), &[b'0'; 64]].concat();
+
+        Mock::given(method("GET"))
+            .and(path("/tiny.pdf"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"%PDF"))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/html.pdf"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(b"<html><body>Access denied, please subscribe</body></html>"),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/real.pdf"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(pdf.clone()))
+            .mount(&mock_server)
+            .await;
+
+        let client = HttpClient::new().with_min_file_size(16);
+        let base = mock_server.uri();
+
+        let tiny = client
+            .download_to_file(&format!("{base}/tiny.pdf"), temp_dir.path())
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&tiny, DownloadError::SuspiciousContent { reason, .. } if reason.contains("below")),
+            "{tiny:?}"
+        );
+        let html = client
+            .download_to_file(&format!("{base}/html.pdf"), temp_dir.path())
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&html, DownloadError::SuspiciousContent { reason, .. } if reason.contains("%PDF")),
+            "{html:?}"
+        );
+        assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+
+        let saved = client
+            .download_to_file(&format!("{base}/real.pdf"), temp_dir.path())
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read(saved).unwrap(), pdf);
+    }
+
+    #[tokio::test]
+    async fn test_meta_refresh_landing_page_is_followed_once() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        let temp_dir = TempDir::new().unwrap();
+        let html = |body: &str| {
+            ResponseTemplate::new(200)
+                .set_body_raw(body.as_bytes().to_vec(), "text/html; charset=utf-8")
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/article"))
+            .respond_with(html(
+                r#"<html><head><meta http-equiv="refresh" content="0; url=/files/paper.pdf"></head></html>"#,
+            ))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/files/paper.pdf"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"%PDF-1.7 paper"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = HttpClient::new();
+        let path = client
+            .download_to_file(&format!("{}/article", mock_server.uri()), temp_dir.path())
+            .await
+            .unwrap();
+
+        assert_eq!(path.file_name().unwrap(), "paper.pdf");
+        assert_eq!(std::fs::read(&path).unwrap(), b"%PDF-1.7 paper");
+        assert!(!temp_dir.path().join("article").exists());
+    }
+
+    #[tokio::test]
+    async fn test_js_location_landing_page_is_followed_but_not_chained() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        let temp_dir = TempDir::new().unwrap();
+        let html = |body: &str| {
+            ResponseTemplate::new(200).set_body_raw(body.as_bytes().to_vec(), "text/html")
+        };
+        let second_stub = r#"<meta http-equiv="refresh" content="0;url=/never">"#;
+
+        Mock::given(method("GET"))
+            .and(path("/landing"))
+            .respond_with(html(
+                "<script>window.location.href = '/stub.html';</script>",
+            ))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/stub.html"))
+            .respond_with(html(second_stub))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/never"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let client = HttpClient::new();
+        let path = client
+            .download_to_file(&format!("{}/landing", mock_server.uri()), temp_dir.path())
+            .await
+            .unwrap();
+
+        assert_eq!(path.file_name().unwrap(), "stub.html");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), second_stub);
+    }
+
+    #[tokio::test]
+    async fn test_http_client_download_to_writer_streams_body() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/stream.pdf"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"PDF content here"))
+            .mount(&mock_server)
+            .await;
+
+        let client = HttpClient::new();
+        let url = format!("{}/stream.pdf", mock_server.uri());
+        let mut sink = Vec::new();
+
+        let written = client.download_to_writer(&url, &mut sink).await.unwrap();
+
+        assert_eq!(written, 16);
+        assert_eq!(sink, b"PDF content here");
+    }
+
+    #[tokio::test]
+    async fn test_http_client_download_to_writer_404_writes_nothing() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/missing.pdf"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let client = HttpClient::new();
+        let url = format!("{}/missing.pdf", mock_server.uri());
+        let mut sink = Vec::new();
+
+        let result = client.download_to_writer(&url, &mut sink).await;
+
+        assert!(matches!(
+            result,
+            Err(DownloadError::HttpStatus { status: 404, .. })
+        ));
+        assert!(sink.is_empty());
+    }
+
+    /// Accepts `capacity` bytes, then fails every write as if the disk were full.
+    struct FullDiskWriter {
+        written: Vec<u8>,
+        capacity: usize,
+    }
+
+    impl Write for FullDiskWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            let room = self.capacity - self.written.len();
+            if room == 0 {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::StorageFull,
+                    "no space left on device",
+                ));
+            }
+            let accepted = buf.len().min(room);
+            self.written.extend_from_slice(&buf[..accepted]);
+            Ok(accepted)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_download_redirect_loop_fails_with_too_many_redirects() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        let temp_dir = TempDir::new().unwrap();
+        for (from, to) in [("/loop/a", "/loop/b"), ("/loop/b", "/loop/a")] {
+            Mock::given(method("GET"))
+                .and(path(from))
+                .respond_with(
+                    ResponseTemplate::new(302)
+                        .insert_header("Location", format!("{}{to}", mock_server.uri())),
+                )
+                .mount(&mock_server)
+                .await;
+        }
+
+        let url = format!("{}/loop/a", mock_server.uri());
+        let result = tokio::time::timeout(
+            Duration::from_secs(10),
+            HttpClient::new().download_to_file(&url, temp_dir.path()),
+        )
+        .await
+        .unwrap();
+
+        let Err(DownloadError::TooManyRedirects {
+            url: failed_url,
+            max_redirects: limit,
+            hops,
+        }) = result
+        else {
+            panic!("expected TooManyRedirects, got {result:?}");
+        };
+        assert_eq!(failed_url, url);
+        assert_eq!(limit, max_redirects());
+        assert_eq!(hops.len(), 3);
+        assert!(hops.iter().all(|hop| hop.contains("/loop/")), "{hops:?}");
+        assert!(std::fs::read_dir(temp_dir.path()).unwrap().next().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_download_to_writer_reports_disk_full_after_n_bytes() {
         let Some(mock_server) = start_mock_server_or_skip().await else {
             return;
         };
-        let temp_dir = TempDir::new().unwrap();
 
         Mock::given(method("GET"))
-            .and(path("/test.pdf"))
-            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"PDF content here"))
+            .and(path("/big.pdf"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![b'x'; 4096]))
             .mount(&mock_server)
             .await;
 
         let client = HttpClient::new();
-        let url = format!("{}/test.pdf", mock_server.uri());
+        let url = format!("{}/big.pdf", mock_server.uri());
+        let mut sink = FullDiskWriter {
+            written: Vec::new(),
+            capacity: 1000,
+        };
 
-        let result = client.download_to_file(&url, temp_dir.path()).await;
+        let error = client
+            .download_to_writer(&url, &mut sink)
+            .await
+            .unwrap_err();
 
-        assert!(result.is_ok(), "Expected Ok, got: {:?}", result);
-        let file_path = result.unwrap();
-        assert!(file_path.exists());
-        let contents = std::fs::read(&file_path).unwrap();
-        assert_eq!(contents, b"PDF content here");
+        assert!(matches!(error, DownloadError::DiskFull { .. }), "{error:?}");
+        assert!(error.to_string().starts_with("disk full writing"));
+        assert_eq!(sink.written.len(), 1000);
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_after_disk_full_removes_resumable_partial() {
+        let temp_dir = TempDir::new().unwrap();
+        let partial = temp_dir.path().join("paper.pdf");
+        std::fs::write(&partial, b"partial").unwrap();
+
+        let disk_full = DownloadError::write_failed(
+            &partial,
+            std::io::Error::new(std::io::ErrorKind::StorageFull, "no space left on device"),
+        );
+        cleanup_after_stream_error(&partial, &disk_full, true).await;
+        assert!(!partial.exists(), "disk-full partial must be removed");
+
+        std::fs::write(&partial, b"partial").unwrap();
+        let timeout = DownloadError::timeout("https://example.com/paper.pdf");
+        cleanup_after_stream_error(&partial, &timeout, true).await;
         assert!(
-            file_path
-                .file_name()
-                .unwrap()
-                .to_str()
-                .unwrap()
-                .contains("test")
+            partial.exists(),
+            "resumable partial survives network errors"
         );
+
+        cleanup_after_stream_error(&partial, &timeout, false).await;
+        assert!(!partial.exists(), "non-resumable partial is removed");
     }
 
     #[tokio::test]
@@ -1254,6 +2277,37 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_with_read_timeout_extends_request_timeout() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        let temp_dir = TempDir::new().unwrap();
+
+        Mock::given(method("GET"))
+            .and(path("/large.zip"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(b"data")
+                    .set_delay(Duration::from_secs(2)),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = HttpClient::new_with_timeouts(30, 1);
+        let url = format!("{}/large.zip", mock_server.uri());
+
+        let default_result = client.download_to_file(&url, temp_dir.path()).await;
+        assert!(default_result.is_err(), "default 1s timeout should expire");
+
+        let extended = client.with_read_timeout(Duration::from_secs(10));
+        let path = extended
+            .download_to_file(&url, temp_dir.path())
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read(path).unwrap(), b"data");
+    }
+
     #[tokio::test]
     async fn test_download_with_user_agent_sends_custom_header() {
         use wiremock::{Match, Request};
@@ -1568,15 +2622,14 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_extensionless_download_url_not_flagged_as_login_redirect() {
+    async fn test_extensionless_download_url_redirected_to_login_flagged_as_auth() {
         let Some(mock_server) = start_mock_server_or_skip().await else {
             return;
         };
         let temp_dir = TempDir::new().unwrap();
 
-        // Extensionless download URL redirects to login page — current behavior
-        // is to NOT flag this as auth_required (known limitation: login redirect
-        // detection only triggers for URLs with known binary extensions).
+        // Extensionless download URL redirects to a login page: the redirect
+        // itself is the signal, so no binary extension is needed.
         Mock::given(method("GET"))
             .and(path("/download"))
             .respond_with(ResponseTemplate::new(302).insert_header(
@@ -1599,14 +2652,96 @@ mod tests {
         let client = HttpClient::new();
         let url = format!("{}/download?id=123", mock_server.uri());
 
-        // This downloads the HTML as-is — login redirect is NOT detected for
-        // extensionless URLs. If this test starts failing, it means the detection
-        // was improved to cover extensionless URLs (which is desirable).
+        let error = client
+            .download_to_file(&url, temp_dir.path())
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(error, DownloadError::AuthRequired { status: 0, .. }),
+            "Expected AuthRequired with status 0, got: {error:?}"
+        );
+        let message = error.to_string();
+        let host = Url::parse(&mock_server.uri())
+            .unwrap()
+            .host_str()
+            .unwrap()
+            .to_string();
+        assert!(
+            message.starts_with(&format!("[AUTH] authentication required for {host} (HTTP")),
+            "message must name the auth domain in the failure-summary format: {message}"
+        );
+        assert_eq!(
+            std::fs::read_dir(temp_dir.path()).unwrap().count(),
+            0,
+            "login HTML must not be saved"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_html_login_form_for_pdf_flagged_as_auth() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        let temp_dir = TempDir::new().unwrap();
+
+        // No redirect and no login pattern in the URL, but the body is a login form.
+        Mock::given(method("GET"))
+            .and(path("/article/paper.pdf"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("Content-Type", "text/html")
+                    .set_body_bytes(
+                        r#"<html><form action="/session"><input type="password" name="pw"></form></html>"#
+                            .as_bytes(),
+                    ),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = HttpClient::new();
+        let url = format!("{}/article/paper.pdf", mock_server.uri());
         let result = client.download_to_file(&url, temp_dir.path()).await;
         assert!(
-            result.is_ok(),
-            "Extensionless URL should not trigger login redirect detection: {result:?}"
+            matches!(result, Err(DownloadError::AuthRequired { status: 0, .. })),
+            "login form should be classified as auth: {result:?}"
         );
+        assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_is_auth_location_matches_sso_hosts_and_paths() {
+        for auth_url in [
+            "https://idp.university.edu/profile/SAML2/Redirect/SSO",
+            "https://login.publisher.com/",
+            "https://login.ezproxy.lib.example.edu/login?url=https://x",
+            "https://www.example.org/Shibboleth.sso/Login?target=x",
+            "https://example.org/login?next=/paper.pdf",
+        ] {
+            assert!(
+                is_auth_location(&Url::parse(auth_url).unwrap()),
+                "{auth_url}"
+            );
+        }
+        for plain_url in [
+            "https://www.example.org/article/123",
+            "https://loginsights.example.org/report.pdf",
+        ] {
+            assert!(
+                !is_auth_location(&Url::parse(plain_url).unwrap()),
+                "{plain_url}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_looks_like_login_form_requires_password_field_in_form() {
+        assert!(looks_like_login_form(
+            "<FORM method=post><input TYPE='password'></FORM>"
+        ));
+        assert!(!looks_like_login_form("<html><body>Article</body></html>"));
+        assert!(!looks_like_login_form(
+            "<form><input type=\"search\"></form>"
+        ));
     }
 
     #[tokio::test]
@@ -1731,6 +2866,119 @@ mod tests {
         assert!(client.has_auth_cookies());
     }
 
+    // ==================== per-domain header tests ====================
+
+    #[tokio::test]
+    async fn test_domain_headers_compose_with_cookie_jar() {
+        use reqwest::cookie::Jar;
+        use wiremock::matchers::header;
+
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        let temp_dir = TempDir::new().unwrap();
+
+        Mock::given(method("GET"))
+            .and(path("/api/paper.pdf"))
+            .and(header("Authorization", "Bearer tok"))
+            .and(header("Cookie", "sid=1"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"PDF"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/api/paper.pdf", mock_server.uri());
+        let jar = std::sync::Arc::new(Jar::default());
+        jar.add_cookie_str("sid=1", &url.parse().unwrap());
+        let host = Url::parse(&url).unwrap().host_str().unwrap().to_string();
+        let mut domain_headers = DomainHeaders::new();
+        domain_headers.insert_bearer(&host, "tok").unwrap();
+        let client = HttpClient::with_cookie_jar(jar).with_domain_headers(domain_headers);
+
+        let result = client.download_to_file(&url, temp_dir.path()).await;
+        assert!(
+            result.is_ok(),
+            "token and cookie should both be sent: {result:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_domain_headers_not_sent_to_other_hosts() {
+        use wiremock::matchers::header_exists;
+
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        let temp_dir = TempDir::new().unwrap();
+
+        let _guard = Mock::given(method("GET"))
+            .and(header_exists("Authorization"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount_as_scoped(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"PDF"))
+            .mount(&mock_server)
+            .await;
+
+        let mut domain_headers = DomainHeaders::new();
+        domain_headers
+            .insert_bearer("publisher.example.org", "tok")
+            .unwrap();
+        let client = HttpClient::new().with_domain_headers(domain_headers);
+        let url = format!("{}/paper.pdf", mock_server.uri());
+
+        let result = client.download_to_file(&url, temp_dir.path()).await;
+        assert!(result.is_ok(), "unmatched host gets no token: {result:?}");
+    }
+
+    #[tokio::test]
+    async fn test_domain_headers_are_reevaluated_on_cross_host_redirects() {
+        use wiremock::matchers::header;
+
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        let temp_dir = TempDir::new().unwrap();
+        let port = mock_server.address().port();
+
+        // 127.0.0.1 carries a token and redirects to localhost, which has its own.
+        Mock::given(method("GET"))
+            .and(path("/start.pdf"))
+            .and(header("Authorization", "Bearer ip-token"))
+            .respond_with(
+                ResponseTemplate::new(302)
+                    .insert_header("Location", format!("http://localhost:{port}/final.pdf")),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/final.pdf"))
+            .and(header("Authorization", "Bearer localhost-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"PDF"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut domain_headers = DomainHeaders::new();
+        domain_headers
+            .insert_bearer("127.0.0.1", "ip-token")
+            .unwrap();
+        domain_headers
+            .insert_bearer("localhost", "localhost-token")
+            .unwrap();
+        let client = HttpClient::new().with_domain_headers(domain_headers);
+        let url = format!("http://127.0.0.1:{port}/start.pdf");
+
+        let result = client.download_to_file(&url, temp_dir.path()).await;
+        assert!(
+            result.is_ok(),
+            "redirect target gets its own token: {result:?}"
+        );
+    }
+
     // ==================== browser header integration tests ====================
 
     #[tokio::test]
@@ -1784,7 +3032,7 @@ mod tests {
 
         // A partial file on disk triggers a HEAD probe for Accept-Ranges.
         // The HEAD should NOT carry Sec-Fetch navigation headers.
-        let partial = temp_dir.path().join("doi_pdf_10.1002_test.002");
+        let partial = temp_dir.path().join("doi_pdf_10.1002_test.002.part");
         tokio::fs::write(&partial, b"partial").await.unwrap();
 
         // HEAD mock: must NOT have Sec-Fetch-Mode.
@@ -1945,4 +3193,162 @@ mod tests {
             "Sec-Fetch-Site should be 'none' when no Referer is derived: {result:?}"
         );
     }
+
+    fn gzip_bytes(data: &[u8]) -> Vec<u8> {
+        use flate2::Compression;
+        use flate2::write::GzEncoder;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_download_decodes_gzip_content_encoding() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        let temp_dir = TempDir::new().unwrap();
+        let body = b"%PDF-1.7 decoded body".repeat(20);
+
+        Mock::given(method("GET"))
+            .and(path("/paper.pdf"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("Content-Encoding", "gzip")
+                    .set_body_bytes(gzip_bytes(&body)),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = HttpClient::new();
+        let url = format!("{}/paper.pdf", mock_server.uri());
+        let result = client
+            .download_to_file_with_metadata(&url, temp_dir.path())
+            .await
+            .unwrap();
+
+        assert_eq!(tokio::fs::read(&result.path).await.unwrap(), body);
+        assert_eq!(result.bytes_downloaded, body.len() as u64);
+        assert_eq!(
+            result.content_length, None,
+            "encoded length must not be reported as the file size"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_encoded_partial_response_restarts_without_resume() {
+        use wiremock::matchers::{header, header_exists};
+
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        let temp_dir = TempDir::new().unwrap();
+        let partial = temp_dir.path().join("paper.pdf.part");
+        tokio::fs::write(&partial, b"%PDF-par").await.unwrap();
+        let body = b"%PDF-1.7 full body after restart".to_vec();
+
+        Mock::given(method("HEAD"))
+            .and(path("/paper.pdf"))
+            .respond_with(ResponseTemplate::new(200).insert_header("Accept-Ranges", "bytes"))
+            .mount(&mock_server)
+            .await;
+        // Misbehaving server: compresses the range despite `identity`.
+        Mock::given(method("GET"))
+            .and(path("/paper.pdf"))
+            .and(header_exists("range"))
+            .and(header("accept-encoding", "identity"))
+            .respond_with(
+                ResponseTemplate::new(206)
+                    .insert_header("Content-Encoding", "gzip")
+                    .insert_header("Content-Range", "bytes 8-31/32")
+                    .set_body_bytes(gzip_bytes(&body[8..])),
+            )
+            .with_priority(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/paper.pdf"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(body.clone()))
+            .mount(&mock_server)
+            .await;
+
+        let client = HttpClient::new();
+        let url = format!("{}/paper.pdf", mock_server.uri());
+        let result = client
+            .download_to_file_with_metadata(&url, temp_dir.path())
+            .await
+            .unwrap();
+
+        assert_eq!(result.path, temp_dir.path().join("paper.pdf"));
+        assert!(!result.resumed);
+        assert_eq!(tokio::fs::read(&result.path).await.unwrap(), body);
+        assert!(
+            !partial.exists(),
+            "completed download must not leave a .part file"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_download_streams_into_part_file_and_renames_on_completion() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        let temp_dir = TempDir::new().unwrap();
+        Mock::given(method("GET"))
+            .and(path("/paper.pdf"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"%PDF-1.7 complete"))
+            .mount(&mock_server)
+            .await;
+
+        let result = HttpClient::new()
+            .download_to_file(&format!("{}/paper.pdf", mock_server.uri()), temp_dir.path())
+            .await
+            .unwrap();
+
+        assert_eq!(result, temp_dir.path().join("paper.pdf"));
+        assert_eq!(std::fs::read(&result).unwrap(), b"%PDF-1.7 complete");
+        assert!(!temp_dir.path().join("paper.pdf.part").exists());
+    }
+
+    #[tokio::test]
+    async fn test_interrupted_resume_never_creates_final_file() {
+        use wiremock::matchers::header;
+
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        let temp_dir = TempDir::new().unwrap();
+        let partial = temp_dir.path().join("paper.pdf.part");
+        tokio::fs::write(&partial, b"AAA").await.unwrap();
+
+        Mock::given(method("HEAD"))
+            .and(path("/paper.pdf"))
+            .respond_with(ResponseTemplate::new(200).insert_header("Accept-Ranges", "bytes"))
+            .mount(&mock_server)
+            .await;
+        // The body ends well short of the advertised length, so the download
+        // fails after bytes were already appended to the partial file.
+        Mock::given(method("GET"))
+            .and(path("/paper.pdf"))
+            .and(header("Range", "bytes=3-"))
+            .respond_with(
+                ResponseTemplate::new(206)
+                    .set_body_bytes(b"BBB")
+                    .insert_header("Content-Length", "20"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let result = HttpClient::new()
+            .download_to_file(&format!("{}/paper.pdf", mock_server.uri()), temp_dir.path())
+            .await;
+
+        assert!(result.is_err(), "short body must fail: {result:?}");
+        assert!(
+            !temp_dir.path().join("paper.pdf").exists(),
+            "no final-named file may appear for an incomplete download"
+        );
+    }
 }
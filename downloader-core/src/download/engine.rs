@@ -36,9 +36,10 @@ mod error_mapping;
 mod persistence;
 mod task;
 
+use std::collections::BTreeMap;
 use std::path::Path;
-use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
 use std::time::Duration;
 
 use tokio::sync::Semaphore;
@@ -50,7 +51,10 @@ use super::retry::{FailureType, RetryDecision, RetryPolicy, classify_error};
 use super::{DownloadError, HttpClient};
 #[cfg(test)]
 use crate::queue::DownloadErrorType;
-use crate::queue::{Queue, QueueError, QueueItem, QueueRepository};
+use crate::queue::{
+    DomainStats, Queue, QueueError, QueueItem, QueueRepository, QueueStatus, ThroughputAccumulator,
+    ThroughputSummary, stats_host,
+};
 
 /// Minimum allowed concurrency value.
 const MIN_CONCURRENCY: usize = 1;
@@ -87,12 +91,39 @@ pub enum EngineError {
 /// Tracks the number of completed, failed, and retried downloads during a
 /// `process_queue()` invocation. Uses atomic counters for thread-safe
 /// updates from concurrent download tasks.
+///
+/// Dead-lettered items are a subset of failed items: they count towards
+/// both [`Self::failed`] and [`Self::dead_lettered`].
+///
+/// Completed items also record their byte count and duration, which
+/// [`Self::summary`] turns into throughput and latency figures.
 #[derive(Debug, Default)]
 pub struct DownloadStats {
     completed: AtomicUsize,
     failed: AtomicUsize,
+    dead_lettered: AtomicUsize,
     retried: AtomicUsize,
     interrupted: AtomicBool,
+    capped: AtomicBool,
+    budget_remaining: Mutex<Option<u64>>,
+    transfers: Mutex<Vec<TransferSample>>,
+}
+
+/// Bytes and elapsed time for one completed download.
+#[derive(Debug, Clone)]
+struct TransferSample {
+    host: String,
+    bytes: u64,
+    duration_ms: u64,
+}
+
+/// Transfer aggregates for one engine run; see [`DownloadStats::summary`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DownloadStatsSummary {
+    /// Total bytes, average throughput, and p50/p95 duration over completed items.
+    pub overall: ThroughputSummary,
+    /// Host with the lowest measured throughput, if any host had a measurable one.
+    pub slowest_host: Option<DomainStats>,
 }
 
 impl DownloadStats {
@@ -114,6 +145,12 @@ impl DownloadStats {
         self.failed.load(Ordering::SeqCst)
     }
 
+    /// Returns the number of failed downloads that were dead-lettered as permanent.
+    #[must_use]
+    pub fn dead_lettered(&self) -> usize {
+        self.dead_lettered.load(Ordering::SeqCst)
+    }
+
     /// Returns the total number of items processed (completed + failed).
     #[must_use]
     pub fn total(&self) -> usize {
@@ -132,6 +169,75 @@ impl DownloadStats {
         self.interrupted.load(Ordering::SeqCst)
     }
 
+    /// Returns true if [`QueueProcessingOptions::max_items`] stopped the run
+    /// while pending items remained.
+    #[must_use]
+    pub fn was_capped(&self) -> bool {
+        self.capped.load(Ordering::SeqCst)
+    }
+
+    /// Returns the bytes left in the budget when
+    /// [`QueueProcessingOptions::byte_budget`] stopped the run while pending
+    /// items remained; `None` otherwise.
+    #[must_use]
+    pub fn budget_exhausted(&self) -> Option<u64> {
+        *self
+            .budget_remaining
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Summarizes bytes, throughput, and per-item durations for completed downloads.
+    #[must_use]
+    pub fn summary(&self) -> DownloadStatsSummary {
+        let samples = self
+            .transfers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+
+        let mut overall = ThroughputAccumulator::default();
+        let mut by_host: BTreeMap<String, ThroughputAccumulator> = BTreeMap::new();
+        for sample in samples {
+            overall.record_success(Some(sample.bytes), Some(sample.duration_ms));
+            by_host
+                .entry(sample.host)
+                .or_default()
+                .record_success(Some(sample.bytes), Some(sample.duration_ms));
+        }
+
+        let slowest_host = by_host
+            .into_iter()
+            .map(|(domain, accumulator)| DomainStats {
+                domain,
+                summary: accumulator.summary(),
+            })
+            .filter(|stats| stats.summary.bytes_per_sec.is_some())
+            .min_by(|left, right| {
+                let left_rate = left.summary.bytes_per_sec.unwrap_or_default();
+                let right_rate = right.summary.bytes_per_sec.unwrap_or_default();
+                left_rate.total_cmp(&right_rate)
+            });
+
+        DownloadStatsSummary {
+            overall: overall.summary(),
+            slowest_host,
+        }
+    }
+
+    /// Records bytes and elapsed time for a completed download of `url`.
+    fn record_transfer(&self, url: &str, bytes: u64, elapsed: Duration) {
+        let sample = TransferSample {
+            host: stats_host(url),
+            bytes,
+            duration_ms: u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX),
+        };
+        self.transfers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(sample);
+    }
+
     /// Increments the completed counter.
     fn increment_completed(&self) {
         self.completed.fetch_add(1, Ordering::SeqCst);
@@ -142,6 +248,12 @@ impl DownloadStats {
         self.failed.fetch_add(1, Ordering::SeqCst);
     }
 
+    /// Records a dead-lettered failure (also increments the failed counter).
+    fn increment_dead_lettered(&self) {
+        self.dead_lettered.fetch_add(1, Ordering::SeqCst);
+        self.increment_failed();
+    }
+
     /// Increments the retried counter.
     fn increment_retried(&self) {
         self.retried.fetch_add(1, Ordering::SeqCst);
@@ -151,6 +263,19 @@ impl DownloadStats {
     fn set_interrupted(&self) {
         self.interrupted.store(true, Ordering::SeqCst);
     }
+
+    /// Marks processing as stopped by the item cap.
+    fn set_capped(&self) {
+        self.capped.store(true, Ordering::SeqCst);
+    }
+
+    /// Marks processing as stopped by the byte budget, with `remaining` bytes left.
+    fn set_budget_exhausted(&self, remaining: u64) {
+        *self
+            .budget_remaining
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(remaining);
+    }
 }
 
 /// Download engine for concurrent file downloads with retry support.
@@ -195,16 +320,62 @@ pub struct DownloadEngine {
 /// Options for queue processing behavior.
 #[derive(Debug, Clone, Default)]
 pub struct QueueProcessingOptions {
-    /// Generate JSON-LD sidecars immediately when an item download succeeds.
+    /// Generate sidecars immediately when an item download succeeds.
     pub generate_sidecars: bool,
+    /// Sidecar format written when [`Self::generate_sidecars`] is true.
+    pub sidecar_format: crate::SidecarFormat,
+    /// Subfolder layout for saved files beneath the output directory.
+    pub layout: super::LayoutMode,
     /// When set with [`Self::robots_cache`], skip downloads disallowed by robots.txt.
     pub check_robots: bool,
     /// Shared robots.txt cache; used when [`Self::check_robots`] is true.
     pub robots_cache: Option<Arc<super::RobotsCache>>,
+    /// Domains (and their subdomains) whose robots.txt disallows are
+    /// overridden, e.g. a repository the user has permission to fetch from.
+    pub robots_allow: Vec<String>,
     /// Optional queue project scope key.
     ///
     /// When set, only queue rows matching this key are dequeued/processed.
     pub project_scope: Option<String>,
+    /// Optional pause signal.
+    ///
+    /// While set, in-flight downloads run to completion but no new item is
+    /// dequeued until the flag is cleared. Interrupt still takes effect while paused.
+    pub paused: Option<Arc<AtomicBool>>,
+    /// When set, replace each downloaded PDF's topics with ones extracted from
+    /// its text (falling back to the title).
+    pub content_topics: Option<Arc<crate::ContentTopicExtractor>>,
+    /// Optional hard-abort signal for a two-stage shutdown.
+    ///
+    /// When set, an interrupt lets in-flight downloads run to completion
+    /// instead of cutting them off after 5 seconds; raising this flag cancels
+    /// them at once. Either way, cancelled items go back to pending.
+    pub abort: Option<Arc<AtomicBool>>,
+    /// Stop dequeuing after this many items have been started.
+    ///
+    /// Retries of a started item do not count. Items beyond the cap stay
+    /// pending for a later run, and [`DownloadStats::was_capped`] reports it.
+    pub max_items: Option<usize>,
+    /// Split files of at least this many bytes into [`Self::segments`]
+    /// parallel range requests, when the server supports ranges.
+    ///
+    /// `None` (the default) always downloads as a single stream.
+    pub segmented_threshold: Option<u64>,
+    /// Concurrent range requests per segmented file (2 to
+    /// [`MAX_DOWNLOAD_SEGMENTS`](super::MAX_DOWNLOAD_SEGMENTS)); see
+    /// [`HttpClient::with_segmented_downloads`].
+    pub segments: usize,
+    /// Fail empty downloads, and `.pdf` files below this many bytes or
+    /// without a `%PDF` signature, instead of completing them; see
+    /// [`HttpClient::with_min_file_size`]. `None` (the default) accepts any body.
+    pub min_file_size: Option<u64>,
+    /// Stop dequeuing once the queue's rolling byte counter (see
+    /// [`Queue::downloaded_bytes_in_window`]) reaches this many bytes.
+    ///
+    /// Checked before each item starts, so downloads already in flight
+    /// finish and may overshoot the budget. Items left over stay pending, and
+    /// [`DownloadStats::budget_exhausted`] reports the bytes remaining.
+    pub byte_budget: Option<u64>,
 }
 
 impl DownloadEngine {
@@ -321,7 +492,7 @@ impl DownloadEngine {
     /// When `interrupted` becomes `true`, the engine:
     /// - stops dequeuing new items
     /// - waits up to 5 seconds for in-flight tasks
-    /// - aborts any remaining tasks after timeout
+    /// - aborts any remaining tasks after timeout and returns their items to pending
     ///
     /// # Errors
     ///
@@ -349,7 +520,11 @@ impl DownloadEngine {
     /// Processes queue items and stops claiming new work when interrupted.
     ///
     /// Same behavior as [`Self::process_queue_interruptible`], with optional
-    /// feature toggles controlled via [`QueueProcessingOptions`].
+    /// feature toggles controlled via [`QueueProcessingOptions`]. When
+    /// [`QueueProcessingOptions::paused`] is set, the engine waits before
+    /// claiming the next item, so pausing never leaves an item half-written.
+    /// When [`QueueProcessingOptions::abort`] is set, an interrupt waits for
+    /// in-flight downloads until they finish or the abort flag is raised.
     ///
     /// # Errors
     ///
@@ -368,6 +543,16 @@ impl DownloadEngine {
         let stats = Arc::new(DownloadStats::new());
         let mut handles: Vec<(i64, tokio::task::JoinHandle<()>)> = Vec::new();
         let project_key = derive_project_key(output_dir);
+        let robots_allow: Arc<[String]> = options.robots_allow.clone().into();
+        let client = match options.segmented_threshold {
+            Some(threshold) => client.with_segmented_downloads(threshold, options.segments),
+            None => client.clone(),
+        };
+        let client = match options.min_file_size {
+            Some(min_bytes) => client.with_min_file_size(min_bytes),
+            None => client,
+        };
+        let mut started: usize = 0;
 
         info!("starting queue processing");
 
@@ -379,6 +564,32 @@ impl DownloadEngine {
             }
             drain_finished_download_tasks(&mut handles, queue, stats.as_ref()).await;
 
+            if let Some(max_items) = options.max_items
+                && started >= max_items
+            {
+                let remaining = queue
+                    .count_by_status_in_project(
+                        QueueStatus::Pending,
+                        options.project_scope.as_deref(),
+                    )
+                    .await?;
+                if remaining > 0 {
+                    info!(
+                        max_items,
+                        remaining, "item cap reached; leaving remaining items pending"
+                    );
+                    stats.set_capped();
+                }
+                break;
+            }
+
+            if let Some(paused) = options.paused.as_deref()
+                && !wait_while_paused(paused, &interrupted).await
+            {
+                stats.set_interrupted();
+                break;
+            }
+
             let Some(item) = queue
                 .dequeue_in_project(options.project_scope.as_deref())
                 .await?
@@ -412,6 +623,23 @@ impl DownloadEngine {
                 break; // Interrupted while waiting for permit
             };
 
+            // Checked once a permit is free, so the bytes of the download that
+            // released it already count.
+            if let Some(budget) = options.byte_budget {
+                let used = queue.downloaded_bytes_in_window().await?;
+                if used >= budget {
+                    if let Err(e) = queue.requeue(item.id).await {
+                        warn!(item_id = item.id, error = %e, "failed to requeue item over byte budget");
+                    }
+                    info!(
+                        budget,
+                        used, "byte budget exhausted; leaving remaining items pending"
+                    );
+                    stats.set_budget_exhausted(budget.saturating_sub(used));
+                    break;
+                }
+            }
+
             // Clone values for the spawned task
             let queue = queue.clone();
             let client = client.clone();
@@ -420,9 +648,12 @@ impl DownloadEngine {
             let retry_policy = self.retry_policy.clone();
             let rate_limiter = Arc::clone(&self.rate_limiter);
             let project_key = project_key.clone();
-            let generate_sidecars = options.generate_sidecars;
+            let sidecar_format = options.generate_sidecars.then_some(options.sidecar_format);
             let check_robots = options.check_robots;
+            let layout = options.layout;
             let robots_cache = options.robots_cache.clone();
+            let robots_allow = Arc::clone(&robots_allow);
+            let content_topics = options.content_topics.clone();
 
             // Spawn download task with retry logic
             let item_id = item.id;
@@ -440,13 +671,17 @@ impl DownloadEngine {
                         stats,
                         rate_limiter,
                         project_key,
-                        generate_sidecars,
+                        sidecar_format,
+                        layout,
                         check_robots,
                         robots_cache,
+                        robots_allow,
+                        content_topics,
                     )
                     .await;
                 }),
             ));
+            started += 1;
         }
         drain_finished_download_tasks(&mut handles, queue, stats.as_ref()).await;
 
@@ -460,22 +695,22 @@ impl DownloadEngine {
             stats.set_interrupted();
             let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
             for (id, mut handle) in handles {
-                let now = tokio::time::Instant::now();
-                if now >= deadline {
-                    handle.abort();
-                    continue;
-                }
-                let remaining = deadline - now;
-                match tokio::time::timeout(remaining, &mut handle).await {
-                    Ok(Ok(())) => {}
-                    Ok(Err(e)) => {
-                        task::handle_task_join_error(queue, id, e, stats.as_ref()).await;
+                let finished = if let Some(abort) = options.abort.as_deref() {
+                    // Graceful stage: wait for the download unless aborted.
+                    tokio::select! {
+                        result = &mut handle => Some(result),
+                        () = wait_for_flag(abort) => None,
                     }
-                    Err(_) => {
-                        // Timed out waiting for this task; abort and continue.
-                        // We keep queue state for resume on next run.
-                        handle.abort();
+                } else {
+                    let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+                    tokio::time::timeout(remaining, &mut handle).await.ok()
+                };
+                match finished {
+                    Some(Ok(())) => {}
+                    Some(Err(e)) => {
+                        task::handle_task_join_error(queue, id, e, stats.as_ref()).await;
                     }
+                    None => abort_and_release(queue, id, handle).await,
                 }
             }
         } else {
@@ -486,12 +721,22 @@ impl DownloadEngine {
             }
         }
 
+        // In-flight downloads may have finished past the budget; report what is left now.
+        if let Some(budget) = options.byte_budget
+            && stats.budget_exhausted().is_some()
+        {
+            let used = queue.downloaded_bytes_in_window().await?;
+            stats.set_budget_exhausted(budget.saturating_sub(used));
+        }
+
         let completed = stats.completed();
         let failed = stats.failed();
+        let dead_lettered = stats.dead_lettered();
         let retried = stats.retried();
         info!(
             completed,
             failed,
+            dead_lettered,
             retried,
             total = completed + failed,
             "queue processing complete"
@@ -510,12 +755,21 @@ impl DownloadEngine {
                     .completed
                     .store(arc_stats.completed(), Ordering::SeqCst);
                 new_stats.failed.store(arc_stats.failed(), Ordering::SeqCst);
+                new_stats
+                    .dead_lettered
+                    .store(arc_stats.dead_lettered(), Ordering::SeqCst);
                 new_stats
                     .retried
                     .store(arc_stats.retried(), Ordering::SeqCst);
                 if arc_stats.was_interrupted() {
                     new_stats.set_interrupted();
                 }
+                if arc_stats.was_capped() {
+                    new_stats.set_capped();
+                }
+                if let Some(remaining) = arc_stats.budget_exhausted() {
+                    new_stats.set_budget_exhausted(remaining);
+                }
                 Ok(new_stats)
             }
         }
@@ -558,6 +812,66 @@ fn extract_retry_after_delay(
     Some(delay)
 }
 
+/// Feeds 429/503 responses into the rate limiter's adaptive spacing, along
+/// with any `Retry-After` the server sent.
+fn record_throttling(error: &DownloadError, url: &str, rate_limiter: &RateLimiter) {
+    if let DownloadError::HttpStatus {
+        status: 429 | 503,
+        retry_after,
+        ..
+    } = error
+    {
+        let retry_after = retry_after.as_deref().and_then(parse_retry_after);
+        rate_limiter.record_throttled(url, retry_after);
+    }
+}
+
+/// Resolves once `flag` is set.
+async fn wait_for_flag(flag: &AtomicBool) {
+    while !flag.load(Ordering::SeqCst) {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+}
+
+/// Cancels an in-flight download and returns its item to pending, so the next
+/// run resumes it without relying on `reset_in_progress`.
+///
+/// Items the task already moved to a terminal status are left alone.
+async fn abort_and_release(queue: &Queue, id: i64, handle: tokio::task::JoinHandle<()>) {
+    handle.abort();
+    let _ = handle.await;
+    match queue.get(id).await {
+        Ok(Some(item)) if item.status() == QueueStatus::InProgress => {
+            if let Err(e) = queue.requeue(id).await {
+                warn!(item_id = id, error = %e, "failed to requeue aborted item");
+            } else {
+                debug!(
+                    item_id = id,
+                    "aborted in-flight download; item returned to pending"
+                );
+            }
+        }
+        Ok(_) => {}
+        Err(e) => warn!(item_id = id, error = %e, "failed to load aborted item"),
+    }
+}
+
+/// Blocks while `paused` is set. Returns `false` if interrupted while waiting.
+async fn wait_while_paused(paused: &AtomicBool, interrupted: &AtomicBool) -> bool {
+    if !paused.load(Ordering::SeqCst) {
+        return true;
+    }
+    info!("queue processing paused; waiting to resume");
+    while paused.load(Ordering::SeqCst) {
+        if interrupted.load(Ordering::SeqCst) {
+            return false;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    info!("queue processing resumed");
+    !interrupted.load(Ordering::SeqCst)
+}
+
 async fn drain_finished_download_tasks<Q: QueueRepository>(
     handles: &mut Vec<(i64, tokio::task::JoinHandle<()>)>,
     queue: &Q,
@@ -632,9 +946,13 @@ async fn download_with_retry(
             )
             .await
         {
-            Ok(download) => return Ok(download),
+            Ok(download) => {
+                rate_limiter.record_success(&item.url);
+                return Ok(download);
+            }
             Err(e) => {
-                let failure_type = classify_error(&e);
+                let failure_type = policy.classify(&e);
+                record_throttling(&e, &item.url, rate_limiter);
 
                 // Check for Retry-After header on 429 responses
                 let retry_after_delay = if failure_type == FailureType::RateLimited {
@@ -683,6 +1001,7 @@ async fn download_with_retry(
                                     .await
                                 {
                                     Ok(download) => {
+                                        rate_limiter.record_success(&item.url);
                                         let _ = queue
                                             .update_progress(
                                                 item.id,
@@ -799,6 +1118,18 @@ mod tests {
         assert_eq!(stats.total(), 3);
     }
 
+    #[test]
+    fn test_download_stats_dead_lettered_counts_as_failed() {
+        let stats = DownloadStats::new();
+
+        stats.increment_failed();
+        stats.increment_dead_lettered();
+
+        assert_eq!(stats.failed(), 2);
+        assert_eq!(stats.dead_lettered(), 1);
+        assert_eq!(stats.total(), 2);
+    }
+
     #[test]
     fn test_download_stats_thread_safe() {
         use std::thread;
@@ -829,6 +1160,45 @@ mod tests {
         assert_eq!(stats.total(), 2000);
     }
 
+    #[test]
+    fn test_download_stats_summary_reports_throughput_and_slowest_host() {
+        let stats = DownloadStats::new();
+        stats.record_transfer(
+            "https://fast.example.org/a.pdf",
+            4_000,
+            Duration::from_secs(1),
+        );
+        stats.record_transfer(
+            "https://fast.example.org/b.pdf",
+            4_000,
+            Duration::from_secs(1),
+        );
+        stats.record_transfer(
+            "https://slow.example.org/c.pdf",
+            1_000,
+            Duration::from_secs(4),
+        );
+
+        let summary = stats.summary();
+        assert_eq!(summary.overall.succeeded, 3);
+        assert_eq!(summary.overall.total_bytes, 9_000);
+        assert_eq!(summary.overall.bytes_per_sec, Some(1_500.0));
+        assert_eq!(summary.overall.p50_duration_ms, Some(1_000));
+        assert_eq!(summary.overall.p95_duration_ms, Some(4_000));
+        let slowest = summary.slowest_host.unwrap();
+        assert_eq!(slowest.domain, "slow.example.org");
+        assert_eq!(slowest.summary.bytes_per_sec, Some(250.0));
+    }
+
+    #[test]
+    fn test_download_stats_summary_empty_run() {
+        let summary = DownloadStats::new().summary();
+        assert_eq!(summary.overall.total_bytes, 0);
+        assert_eq!(summary.overall.bytes_per_sec, None);
+        assert_eq!(summary.overall.p50_duration_ms, None);
+        assert!(summary.slowest_host.is_none());
+    }
+
     #[test]
     fn test_engine_error_display() {
         let error = EngineError::InvalidConcurrency { value: 0 };
@@ -864,11 +1234,11 @@ mod tests {
     }
 
     #[test]
-    fn test_classify_download_error_type_timeout_maps_to_network() {
+    fn test_classify_download_error_type_timeout() {
         let error = DownloadError::timeout("https://example.com/slow.pdf");
         assert_eq!(
             classify_download_error_type(&error),
-            DownloadErrorType::Network
+            DownloadErrorType::Timeout
         );
     }
 
@@ -881,6 +1251,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_classify_download_error_type_redirect_limit_maps_to_too_many_redirects() {
+        let error = DownloadError::too_many_redirects("https://example.com/loop", 10, Vec::new());
+        assert_eq!(
+            classify_download_error_type(&error),
+            DownloadErrorType::TooManyRedirects
+        );
+    }
+
     #[test]
     fn test_build_actionable_error_message_adds_suggestion() {
         let error = DownloadError::http_status("https://example.com/missing.pdf", 404);
@@ -992,6 +1371,55 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_status_override_to_permanent_skips_retries() {
+        use tempfile::TempDir;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        use crate::queue::Queue;
+
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        let temp_dir = TempDir::new().unwrap();
+
+        Mock::given(method("GET"))
+            .and(path("/flaky.pdf"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&mock_server)
+            .await;
+
+        let db = crate::Database::new_in_memory().await.unwrap();
+        let queue = Queue::new(db);
+        let url = format!("{}/flaky.pdf", mock_server.uri());
+        queue.enqueue(&url, "direct_url", None).await.unwrap();
+        let dequeued = queue.dequeue().await.unwrap().unwrap();
+
+        let client = HttpClient::new();
+        let stats = DownloadStats::new();
+        let policy = RetryPolicy::default().with_status_override(500, FailureType::Permanent);
+        let rate_limiter = Arc::new(RateLimiter::new(Duration::from_millis(0)));
+
+        let result = download_with_retry(
+            &queue,
+            &client,
+            &dequeued,
+            temp_dir.path(),
+            &policy,
+            &stats,
+            &rate_limiter,
+        )
+        .await;
+
+        let (error, attempts) = result.unwrap_err();
+        assert!(error.to_string().contains("500"), "{error}");
+        assert_eq!(attempts, 1);
+        assert_eq!(stats.retried(), 0, "500 configured as permanent");
+        let requests = mock_server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+    }
+
     #[tokio::test]
     async fn test_401_fails_with_auth_error_prefix() {
         use tempfile::TempDir;
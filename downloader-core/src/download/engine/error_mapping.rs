@@ -1,6 +1,6 @@
 use crate::queue::DownloadErrorType;
 
-use super::DownloadError;
+use super::{DownloadError, FailureType, classify_error};
 
 pub(super) fn extract_http_status(error: &DownloadError) -> Option<i64> {
     match error {
@@ -17,21 +17,116 @@ pub(super) fn classify_download_error_type(error: &DownloadError) -> DownloadErr
         DownloadError::HttpStatus { status, .. } => match status {
             401 | 403 | 407 => DownloadErrorType::Auth,
             404 => DownloadErrorType::NotFound,
+            406 | 415 => DownloadErrorType::UnexpectedContentType,
+            408 => DownloadErrorType::Timeout,
+            413 => DownloadErrorType::TooLarge,
             _ => DownloadErrorType::Network,
         },
         DownloadError::InvalidUrl { .. } => DownloadErrorType::ParseError,
-        DownloadError::Timeout { .. }
-        | DownloadError::Network { .. }
-        | DownloadError::Io { .. }
-        | DownloadError::Integrity { .. } => DownloadErrorType::Network,
+        DownloadError::DiskFull { .. } => DownloadErrorType::DiskFull,
+        DownloadError::TooManyRedirects { .. } => DownloadErrorType::TooManyRedirects,
+        DownloadError::RobotsDisallowed { .. } => DownloadErrorType::RobotsDisallowed,
+        DownloadError::Integrity { .. } => DownloadErrorType::ChecksumMismatch,
+        DownloadError::SuspiciousContent { .. } => DownloadErrorType::UnexpectedContentType,
+        DownloadError::Ftp { reply_code, .. } => match reply_code {
+            Some(530 | 332) => DownloadErrorType::Auth,
+            Some(550) => DownloadErrorType::NotFound,
+            _ => DownloadErrorType::Network,
+        },
+        DownloadError::Timeout { .. } => DownloadErrorType::Timeout,
+        DownloadError::Network { source, .. } => {
+            if source.is_timeout() {
+                DownloadErrorType::Timeout
+            } else {
+                classify_transport_failure(source)
+            }
+        }
+        DownloadError::Io { .. } => DownloadErrorType::Network,
+    }
+}
+
+/// Narrows a transport failure to DNS, TLS, or connection-reset by walking
+/// its source chain; anything else stays [`DownloadErrorType::Network`].
+///
+/// reqwest only exposes coarse kinds (`is_connect`, `is_timeout`), so the
+/// finer cause comes from the hyper/rustls/io errors underneath it.
+fn classify_transport_failure(error: &(dyn std::error::Error + 'static)) -> DownloadErrorType {
+    let mut current = Some(error);
+    let mut chain_text = String::new();
+    while let Some(cause) = current {
+        if let Some(io_error) = cause.downcast_ref::<std::io::Error>()
+            && matches!(
+                io_error.kind(),
+                std::io::ErrorKind::ConnectionReset | std::io::ErrorKind::ConnectionAborted
+            )
+        {
+            return DownloadErrorType::ConnectionReset;
+        }
+        chain_text.push_str(&cause.to_string().to_ascii_lowercase());
+        chain_text.push('\n');
+        current = cause.source();
+    }
+
+    if chain_text.contains("dns error") || chain_text.contains("failed to lookup address") {
+        DownloadErrorType::Dns
+    } else if ["certificate", "tls", "ssl", "handshake"]
+        .iter()
+        .any(|needle| chain_text.contains(needle))
+    {
+        DownloadErrorType::Tls
+    } else if chain_text.contains("connection reset") {
+        DownloadErrorType::ConnectionReset
+    } else {
+        DownloadErrorType::Network
+    }
+}
+
+/// Returns true when a terminal failure should be dead-lettered rather than left
+/// as a plain (retryable) failure.
+///
+/// Permanence follows [`classify_error`]. Auth failures only count once captured
+/// cookies were already sent, since `downloader auth capture` can still fix them
+/// otherwise. Local I/O failures say nothing about the source and stay retryable,
+/// as do robots.txt refusals, which follow the site's policy rather than the source.
+pub(super) fn is_dead_letter_failure(error: &DownloadError, auth_attempted: bool) -> bool {
+    if matches!(
+        error,
+        DownloadError::Io { .. }
+            | DownloadError::DiskFull { .. }
+            | DownloadError::RobotsDisallowed { .. }
+    ) {
+        return false;
+    }
+    match classify_error(error) {
+        FailureType::Permanent => true,
+        FailureType::NeedsAuth => auth_attempted,
+        FailureType::Transient | FailureType::RateLimited => false,
     }
 }
 
+/// Renders the error with the causes reqwest keeps out of its `Display`, so
+/// the stored message still says whether DNS, TLS, or the connection failed.
+fn describe_with_causes(error: &DownloadError) -> String {
+    let mut message = error.to_string();
+    if let DownloadError::Network { source, .. } = error {
+        let mut cause = std::error::Error::source(source);
+        while let Some(inner) = cause {
+            let text = inner.to_string();
+            if !message.contains(&text) {
+                message.push_str(": ");
+                message.push_str(&text);
+            }
+            cause = inner.source();
+        }
+    }
+    message
+}
+
 pub(super) fn build_actionable_error_message(
     error: &DownloadError,
     error_type: DownloadErrorType,
 ) -> String {
-    let base = error.to_string();
+    let base = describe_with_causes(error);
     if base.contains("Suggestion:") {
         return base;
     }
@@ -49,7 +144,165 @@ pub(super) fn build_actionable_error_message(
         DownloadErrorType::ParseError => {
             "Check input formatting for URL/DOI/reference and rerun with a valid source string."
         }
+        DownloadErrorType::DiskFull => {
+            "Free up space on the output volume (or pick another --output-dir), then retry."
+        }
+        DownloadErrorType::TooManyRedirects => {
+            "Open the last hop in a browser to check for a redirect loop; raise `max_redirects` in config.toml if the chain is legitimate."
+        }
+        DownloadErrorType::Tls => {
+            "Check the system clock and any TLS-intercepting proxy; the server certificate could not be verified."
+        }
+        DownloadErrorType::Dns => "Check the host name and your DNS/VPN settings, then retry.",
+        DownloadErrorType::ConnectionReset => {
+            "The server dropped the connection; retry later or lower --concurrency for this host."
+        }
+        DownloadErrorType::Timeout => {
+            "The server did not respond in time; retry later or with --max-retries set higher."
+        }
+        DownloadErrorType::RobotsDisallowed => {
+            "The site's robots.txt forbids this URL; download it manually or rerun without `--check-robots`/`--respectful`."
+        }
+        DownloadErrorType::TooLarge => {
+            "The server refused the transfer as too large; download it manually."
+        }
+        DownloadErrorType::ChecksumMismatch => {
+            "The file arrived truncated or corrupted; retry the download."
+        }
+        DownloadErrorType::UnexpectedContentType => {
+            "The server did not serve this resource as a file (often an access-denied page); open the URL in a browser to find the download link."
+        }
     };
 
     format!("{base}\n  Suggestion: {suggestion}")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_dead_letter_failure_follows_failure_classification() {
+        assert!(is_dead_letter_failure(
+            &DownloadError::http_status("https://example.com/a.pdf", 404),
+            false
+        ));
+        assert!(is_dead_letter_failure(
+            &DownloadError::invalid_url("not a url"),
+            false
+        ));
+        assert!(!is_dead_letter_failure(
+            &DownloadError::http_status("https://example.com/a.pdf", 503),
+            false
+        ));
+        assert!(!is_dead_letter_failure(
+            &DownloadError::timeout("https://example.com/a.pdf"),
+            false
+        ));
+    }
+
+    #[test]
+    fn test_classify_download_error_type_maps_statuses_and_local_failures() {
+        let cases = [
+            (406, DownloadErrorType::UnexpectedContentType),
+            (408, DownloadErrorType::Timeout),
+            (413, DownloadErrorType::TooLarge),
+            (415, DownloadErrorType::UnexpectedContentType),
+            (503, DownloadErrorType::Network),
+        ];
+        for (status, expected) in cases {
+            let error = DownloadError::http_status("https://example.com/a.pdf", status);
+            assert_eq!(classify_download_error_type(&error), expected, "{status}");
+        }
+        assert_eq!(
+            classify_download_error_type(&DownloadError::integrity("/tmp/a.pdf", 10, 4)),
+            DownloadErrorType::ChecksumMismatch
+        );
+        assert_eq!(
+            classify_download_error_type(&DownloadError::suspicious_content(
+                "https://example.com/a.pdf",
+                "body is 12 bytes"
+            )),
+            DownloadErrorType::UnexpectedContentType
+        );
+        assert_eq!(
+            classify_download_error_type(&DownloadError::robots_disallowed(
+                "https://example.com/a.pdf"
+            )),
+            DownloadErrorType::RobotsDisallowed
+        );
+    }
+
+    #[derive(Debug)]
+    struct Wrapped(&'static str, std::io::Error);
+
+    impl std::fmt::Display for Wrapped {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.0)
+        }
+    }
+
+    impl std::error::Error for Wrapped {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.1)
+        }
+    }
+
+    #[test]
+    fn test_classify_transport_failure_reads_the_source_chain() {
+        let dns = Wrapped(
+            "client error (Connect)",
+            std::io::Error::other("dns error: failed to lookup address information"),
+        );
+        assert_eq!(classify_transport_failure(&dns), DownloadErrorType::Dns);
+
+        let tls = Wrapped(
+            "client error (Connect)",
+            std::io::Error::other("invalid peer certificate: UnknownIssuer"),
+        );
+        assert_eq!(classify_transport_failure(&tls), DownloadErrorType::Tls);
+
+        let reset = Wrapped(
+            "error reading a body from connection",
+            std::io::Error::from(std::io::ErrorKind::ConnectionReset),
+        );
+        assert_eq!(
+            classify_transport_failure(&reset),
+            DownloadErrorType::ConnectionReset
+        );
+
+        let refused = Wrapped(
+            "client error (Connect)",
+            std::io::Error::from(std::io::ErrorKind::ConnectionRefused),
+        );
+        assert_eq!(
+            classify_transport_failure(&refused),
+            DownloadErrorType::Network
+        );
+    }
+
+    #[test]
+    fn test_is_dead_letter_failure_auth_only_after_cookies_were_sent() {
+        let error = DownloadError::auth_required("https://example.com/a.pdf", 403, "example.com");
+        assert!(!is_dead_letter_failure(&error, false));
+        assert!(is_dead_letter_failure(&error, true));
+    }
+
+    #[test]
+    fn test_is_dead_letter_failure_ignores_local_io_errors() {
+        let error = DownloadError::io(
+            "/tmp/out.pdf",
+            std::io::Error::new(std::io::ErrorKind::StorageFull, "disk full"),
+        );
+        assert!(!is_dead_letter_failure(&error, false));
+        let error = DownloadError::write_failed(
+            "/tmp/out.pdf",
+            std::io::Error::new(std::io::ErrorKind::StorageFull, "disk full"),
+        );
+        assert!(!is_dead_letter_failure(&error, false));
+        assert!(!is_dead_letter_failure(
+            &DownloadError::robots_disallowed("https://example.com/a.pdf"),
+            false
+        ));
+    }
+}
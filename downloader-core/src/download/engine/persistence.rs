@@ -3,14 +3,15 @@ use std::time::{Duration, Instant};
 
 use tracing::{debug, info, warn};
 
-use crate::generate_sidecar;
 use crate::queue::{DownloadAttemptStatus, NewDownloadAttempt, QueueItem, QueueRepository};
+use crate::{SidecarFormat, generate_sidecar_with_format};
 
 use super::DownloadError;
 use super::DownloadFileResult;
 use super::DownloadStats;
 use super::error_mapping::{
     build_actionable_error_message, classify_download_error_type, extract_http_status,
+    is_dead_letter_failure,
 };
 
 pub(super) async fn persist_download_success(
@@ -19,7 +20,7 @@ pub(super) async fn persist_download_success(
     download: &DownloadFileResult,
     project_key: &str,
     attempt_started: Instant,
-    generate_sidecars: bool,
+    sidecar_format: Option<SidecarFormat>,
     stats: &DownloadStats,
 ) {
     if download.resume_attempted {
@@ -65,10 +66,10 @@ pub(super) async fn persist_download_success(
     let doi = extract_attempt_doi(item);
     let saved_path = download.path.to_string_lossy().to_string();
 
-    if generate_sidecars {
+    if let Some(format) = sidecar_format {
         let mut sidecar_item = item.clone();
         sidecar_item.saved_path = Some(saved_path.clone());
-        if let Err(error) = generate_sidecar(&sidecar_item) {
+        if let Err(error) = generate_sidecar_with_format(&sidecar_item, format) {
             warn!(
                 item_id = item.id,
                 ?error,
@@ -78,6 +79,7 @@ pub(super) async fn persist_download_success(
     }
 
     let original_input = item.original_input.as_deref().unwrap_or(item.url.as_str());
+    let elapsed = attempt_started.elapsed();
     let attempt = NewDownloadAttempt {
         url: &item.url,
         final_url: Some(&item.url),
@@ -91,13 +93,15 @@ pub(super) async fn persist_download_success(
         project: Some(project_key),
         original_input: Some(original_input),
         http_status: None,
-        duration_ms: Some(elapsed_ms_i64(attempt_started.elapsed())),
+        duration_ms: Some(elapsed_ms_i64(elapsed)),
         title: item.meta_title.as_deref(),
         authors: item.meta_authors.as_deref(),
         doi: doi.as_deref(),
         topics: item.topics.as_deref(),
         parse_confidence: item.parse_confidence.as_deref(),
         parse_confidence_factors: item.parse_confidence_factors.as_deref(),
+        resolver_path: item.resolver_path.as_deref(),
+        input_source_file: item.input_source_file.as_deref(),
     };
     if let Err(error) = queue.log_download_attempt(&attempt).await {
         warn!(
@@ -106,9 +110,21 @@ pub(super) async fn persist_download_success(
             "failed to persist download history row"
         );
     }
+    if let Err(error) = queue
+        .record_downloaded_bytes(download.bytes_downloaded)
+        .await
+    {
+        warn!(
+            item_id = item.id,
+            error = %error,
+            "failed to record downloaded bytes for the byte budget"
+        );
+    }
+    stats.record_transfer(&item.url, download.bytes_downloaded, elapsed);
     stats.increment_completed();
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(super) async fn persist_download_failure(
     queue: &impl QueueRepository,
     item: &QueueItem,
@@ -116,6 +132,7 @@ pub(super) async fn persist_download_failure(
     attempts: u32,
     project_key: &str,
     attempt_started: Instant,
+    auth_attempted: bool,
     stats: &DownloadStats,
 ) {
     let error_type = classify_download_error_type(error);
@@ -132,13 +149,21 @@ pub(super) async fn persist_download_failure(
     );
 
     let retry_count = i64::from(attempts.saturating_sub(1));
-    if let Err(queue_error) = queue
-        .mark_failed(item.id, &error_message, retry_count)
-        .await
-    {
+    let dead_letter = is_dead_letter_failure(error, auth_attempted);
+    let marked = if dead_letter {
+        queue
+            .mark_dead_letter(item.id, &error_message, retry_count)
+            .await
+    } else {
+        queue
+            .mark_failed(item.id, &error_message, retry_count)
+            .await
+    };
+    if let Err(queue_error) = marked {
         warn!(
             item_id = item.id,
             error = %queue_error,
+            dead_letter,
             "failed to mark item failed"
         );
     }
@@ -163,6 +188,8 @@ pub(super) async fn persist_download_failure(
         topics: item.topics.as_deref(),
         parse_confidence: item.parse_confidence.as_deref(),
         parse_confidence_factors: item.parse_confidence_factors.as_deref(),
+        resolver_path: item.resolver_path.as_deref(),
+        input_source_file: item.input_source_file.as_deref(),
     };
     if let Err(history_error) = queue.log_download_attempt(&attempt).await {
         warn!(
@@ -171,7 +198,11 @@ pub(super) async fn persist_download_failure(
             "failed to persist download history row"
         );
     }
-    stats.increment_failed();
+    if dead_letter {
+        stats.increment_dead_lettered();
+    } else {
+        stats.increment_failed();
+    }
 }
 
 pub(super) fn derive_project_key(output_dir: &Path) -> String {
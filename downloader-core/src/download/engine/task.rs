@@ -1,15 +1,88 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Instant;
 
-use tokio::task::JoinError;
-use tracing::{info, warn};
+use tokio::task::{JoinError, JoinHandle};
+use tracing::{debug, info, warn};
 
-use crate::queue::{Queue, QueueItem, QueueRepository};
+use crate::queue::{CLAIM_RENEW_INTERVAL, Queue, QueueItem, QueueRepository};
 
 use super::persistence::{persist_download_failure, persist_download_success};
-use super::{DownloadStats, HttpClient, RateLimiter, RetryPolicy, download_with_retry};
-use crate::{RobotsCache, RobotsDecision, origin_for_robots};
+use super::{
+    DownloadError, DownloadStats, HttpClient, RateLimiter, RetryPolicy, download_with_retry,
+};
+use crate::{
+    ContentTopicExtractor, LayoutMode, RobotsCache, RobotsDecision, SidecarFormat,
+    is_robots_allowlisted, layout_output_dir, origin_for_robots,
+};
+
+/// Creates the layout subfolder for `item`, falling back to `output_dir` when
+/// it cannot be created.
+async fn prepare_item_dir(output_dir: PathBuf, layout: LayoutMode, item: &QueueItem) -> PathBuf {
+    let item_dir = layout_output_dir(&output_dir, layout, item);
+    if item_dir != output_dir
+        && let Err(error) = tokio::fs::create_dir_all(&item_dir).await
+    {
+        warn!(
+            item_id = item.id,
+            path = %item_dir.display(),
+            %error,
+            "failed to create layout subfolder; saving to output directory"
+        );
+    }
+    if item_dir.is_dir() {
+        item_dir
+    } else {
+        output_dir
+    }
+}
+
+/// Applies the item's own read timeout to `client`, if it has one.
+fn client_for_item(client: HttpClient, item: &QueueItem) -> HttpClient {
+    match item.read_timeout() {
+        Some(timeout) => {
+            debug!(
+                item_id = item.id,
+                timeout_secs = timeout.as_secs(),
+                "using per-item read timeout"
+            );
+            client.with_read_timeout(timeout)
+        }
+        None => client,
+    }
+}
+
+/// Renews an item's claim lease every [`CLAIM_RENEW_INTERVAL`] until dropped,
+/// so a long download is not reclaimed by another process's recovery.
+struct ClaimHeartbeat(JoinHandle<()>);
+
+impl ClaimHeartbeat {
+    fn start(queue: &Queue, item_id: i64) -> Self {
+        let queue = queue.clone();
+        Self(tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(CLAIM_RENEW_INTERVAL);
+            // The first tick completes immediately; the claim was just stamped.
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                match queue.renew_claim(item_id).await {
+                    Ok(true) => {}
+                    Ok(false) => {
+                        warn!(item_id, "claim lease no longer held by this process");
+                        return;
+                    }
+                    Err(error) => warn!(item_id, %error, "failed to renew claim lease"),
+                }
+            }
+        }))
+    }
+}
+
+impl Drop for ClaimHeartbeat {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
 
 #[allow(clippy::too_many_arguments)]
 pub(super) async fn process_download_item(
@@ -21,9 +94,12 @@ pub(super) async fn process_download_item(
     stats: Arc<DownloadStats>,
     rate_limiter: Arc<RateLimiter>,
     project_key: String,
-    generate_sidecars: bool,
+    sidecar_format: Option<SidecarFormat>,
+    layout: LayoutMode,
     check_robots: bool,
     robots_cache: Option<Arc<RobotsCache>>,
+    robots_allow: Arc<[String]>,
+    content_topics: Option<Arc<ContentTopicExtractor>>,
 ) {
     let attempt_started = Instant::now();
 
@@ -31,15 +107,27 @@ pub(super) async fn process_download_item(
         if let Some(ref cache) = robots_cache {
             if let Some(origin) = origin_for_robots(&item.url) {
                 match cache.check_allowed(&item.url, &origin, &client).await {
+                    Ok(RobotsDecision::Disallowed)
+                        if is_robots_allowlisted(&item.url, &robots_allow) =>
+                    {
+                        warn!(
+                            url = %item.url,
+                            "robots.txt disallows this URL; downloading anyway because its host is on robots_allow"
+                        );
+                    }
                     Ok(RobotsDecision::Disallowed) => {
                         info!(url = %item.url, "skipping download: robots.txt disallows");
-                        stats.increment_failed();
-                        if let Err(e) = queue
-                            .mark_failed(item.id, "robots.txt disallows this URL", 0)
-                            .await
-                        {
-                            warn!(item_id = item.id, error = %e, "failed to mark robots-disallowed item");
-                        }
+                        persist_download_failure(
+                            &queue,
+                            &item,
+                            &DownloadError::robots_disallowed(&item.url),
+                            1,
+                            &project_key,
+                            attempt_started,
+                            false,
+                            stats.as_ref(),
+                        )
+                        .await;
                         return;
                     }
                     Ok(RobotsDecision::Allowed) => {}
@@ -51,26 +139,37 @@ pub(super) async fn process_download_item(
         }
     }
 
+    let item_dir = prepare_item_dir(output_dir, layout, &item).await;
+    let client = client_for_item(client, &item);
+
+    let heartbeat = ClaimHeartbeat::start(&queue, item.id);
     let result = download_with_retry(
         &queue,
         &client,
         &item,
-        &output_dir,
+        &item_dir,
         &retry_policy,
         &stats,
         &rate_limiter,
     )
     .await;
+    drop(heartbeat);
 
     match result {
         Ok(download) => {
+            let item = match content_topics {
+                Some(extractor) => {
+                    apply_content_topics(&queue, item, &download.path, extractor).await
+                }
+                None => item,
+            };
             persist_download_success(
                 &queue,
                 &item,
                 &download,
                 &project_key,
                 attempt_started,
-                generate_sidecars,
+                sidecar_format,
                 stats.as_ref(),
             )
             .await;
@@ -83,6 +182,7 @@ pub(super) async fn process_download_item(
                 attempts,
                 &project_key,
                 attempt_started,
+                client.has_auth_cookies(),
                 stats.as_ref(),
             )
             .await;
@@ -90,6 +190,44 @@ pub(super) async fn process_download_item(
     }
 }
 
+/// Replaces the item's topics with ones derived from the downloaded PDF, so
+/// the queue row, sidecar, and history row all carry them.
+async fn apply_content_topics(
+    queue: &Queue,
+    mut item: QueueItem,
+    path: &Path,
+    extractor: Arc<ContentTopicExtractor>,
+) -> QueueItem {
+    let is_pdf = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("pdf"));
+    if !is_pdf {
+        return item;
+    }
+
+    let pdf_path = path.to_path_buf();
+    let title = item.meta_title.clone();
+    let topics = match tokio::task::spawn_blocking(move || {
+        extractor.topics_for_file(&pdf_path, title.as_deref())
+    })
+    .await
+    {
+        Ok(Some(topics)) => topics,
+        Ok(None) => return item,
+        Err(error) => {
+            warn!(item_id = item.id, %error, "content topic extraction panicked");
+            return item;
+        }
+    };
+
+    debug!(item_id = item.id, topics = ?topics, "Extracted topics from PDF content");
+    match queue.set_topics(item.id, &topics).await {
+        Ok(()) => item.topics = QueueItem::serialize_topics(&topics),
+        Err(error) => warn!(item_id = item.id, %error, "failed to store content topics"),
+    }
+    item
+}
+
 pub(super) async fn handle_task_join_error(
     queue: &impl QueueRepository,
     item_id: i64,
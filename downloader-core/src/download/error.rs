@@ -48,6 +48,42 @@ pub enum DownloadError {
         source: std::io::Error,
     },
 
+    /// The output volume ran out of space (or quota) while writing.
+    #[error("disk full writing {path}")]
+    DiskFull {
+        /// The file path being written when space ran out.
+        path: PathBuf,
+        /// The underlying IO error.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The redirect chain exceeded the configured limit (usually a loop).
+    #[error(
+        "too many redirects downloading {url}: stopped after {max_redirects} (last hops: {})",
+        hops.join(" -> ")
+    )]
+    TooManyRedirects {
+        /// The URL that was requested.
+        url: String,
+        /// The redirect limit that was exceeded.
+        max_redirects: usize,
+        /// The last few URLs of the chain, ending with the refused target.
+        hops: Vec<String>,
+    },
+
+    /// An `ftp://` transfer failed, either on the connection or with a
+    /// negative server reply.
+    #[error("FTP error downloading {url}: {message}")]
+    Ftp {
+        /// The URL that failed to download.
+        url: String,
+        /// The server's reply code, or `None` for connection failures.
+        reply_code: Option<u16>,
+        /// The server's reply text or the connection error.
+        message: String,
+    },
+
     /// The provided URL is malformed or invalid.
     #[error("invalid URL: {url}")]
     InvalidUrl {
@@ -55,6 +91,13 @@ pub enum DownloadError {
         url: String,
     },
 
+    /// robots.txt on the source host disallows fetching the URL.
+    #[error("robots.txt disallows downloading {url}")]
+    RobotsDisallowed {
+        /// The disallowed URL.
+        url: String,
+    },
+
     /// Downloaded file size does not match expected server content length.
     #[error(
         "integrity check failed for {path}: expected {expected_bytes} bytes, got {actual_bytes}"
@@ -68,6 +111,16 @@ pub enum DownloadError {
         actual_bytes: u64,
     },
 
+    /// The body arrived but is not plausibly the requested file: smaller than
+    /// the configured minimum, or not a PDF where a PDF was expected.
+    #[error("suspicious content downloading {url}: {reason}")]
+    SuspiciousContent {
+        /// The URL that served the body.
+        url: String,
+        /// Why the body was rejected.
+        reason: String,
+    },
+
     /// Authentication or authorization required to access the resource.
     ///
     /// Suggestion text varies: 407 suggests proxy configuration,
@@ -123,6 +176,19 @@ impl DownloadError {
         Self::Timeout { url: url.into() }
     }
 
+    /// Creates a redirect-limit error naming the last hops of the chain.
+    pub fn too_many_redirects(
+        url: impl Into<String>,
+        max_redirects: usize,
+        hops: Vec<String>,
+    ) -> Self {
+        Self::TooManyRedirects {
+            url: url.into(),
+            max_redirects,
+            hops,
+        }
+    }
+
     /// Creates an IO error.
     pub fn io(path: impl Into<PathBuf>, source: std::io::Error) -> Self {
         Self::Io {
@@ -131,11 +197,45 @@ impl DownloadError {
         }
     }
 
+    /// Creates an error for a failed write to `path`.
+    ///
+    /// Out-of-space and quota errors become [`DownloadError::DiskFull`];
+    /// everything else stays a plain [`DownloadError::Io`].
+    pub fn write_failed(path: impl Into<PathBuf>, source: std::io::Error) -> Self {
+        if is_storage_full(&source) {
+            Self::DiskFull {
+                path: path.into(),
+                source,
+            }
+        } else {
+            Self::io(path, source)
+        }
+    }
+
+    /// Creates an FTP error from a negative reply (`Some(code)`) or a
+    /// connection failure (`None`).
+    pub fn ftp(
+        url: impl Into<String>,
+        reply_code: Option<u16>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self::Ftp {
+            url: url.into(),
+            reply_code,
+            message: message.into(),
+        }
+    }
+
     /// Creates an invalid URL error.
     pub fn invalid_url(url: impl Into<String>) -> Self {
         Self::InvalidUrl { url: url.into() }
     }
 
+    /// Creates a robots.txt disallow error.
+    pub fn robots_disallowed(url: impl Into<String>) -> Self {
+        Self::RobotsDisallowed { url: url.into() }
+    }
+
     /// Creates an integrity mismatch error.
     pub fn integrity(path: impl Into<PathBuf>, expected_bytes: u64, actual_bytes: u64) -> Self {
         Self::Integrity {
@@ -145,6 +245,14 @@ impl DownloadError {
         }
     }
 
+    /// Creates a suspicious-content error.
+    pub fn suspicious_content(url: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::SuspiciousContent {
+            url: url.into(),
+            reason: reason.into(),
+        }
+    }
+
     /// Creates an authentication-required error.
     ///
     /// The suggestion text is derived from the status code:
@@ -165,6 +273,13 @@ impl DownloadError {
     }
 }
 
+fn is_storage_full(error: &std::io::Error) -> bool {
+    matches!(
+        error.kind(),
+        std::io::ErrorKind::StorageFull | std::io::ErrorKind::QuotaExceeded
+    )
+}
+
 // Note on From trait implementations:
 // We intentionally do NOT implement `From<reqwest::Error>` or `From<std::io::Error>`
 // because our error variants require context (url, path) that the source errors
@@ -207,6 +322,43 @@ mod tests {
         assert!(msg.contains("/tmp/test.pdf"), "Expected path in: {msg}");
     }
 
+    #[test]
+    fn test_download_error_too_many_redirects_display_names_hops() {
+        let error = DownloadError::too_many_redirects(
+            "https://example.com/a",
+            2,
+            vec![
+                "https://example.com/b".to_string(),
+                "https://example.com/a".to_string(),
+            ],
+        );
+        assert_eq!(
+            error.to_string(),
+            "too many redirects downloading https://example.com/a: stopped after 2 \
+             (last hops: https://example.com/b -> https://example.com/a)"
+        );
+    }
+
+    #[test]
+    fn test_write_failed_classifies_storage_full_as_disk_full() {
+        let full = std::io::Error::new(std::io::ErrorKind::StorageFull, "no space left");
+        let error = DownloadError::write_failed("/tmp/test.pdf", full);
+        assert!(matches!(error, DownloadError::DiskFull { .. }));
+        assert_eq!(error.to_string(), "disk full writing /tmp/test.pdf");
+
+        let quota = std::io::Error::new(std::io::ErrorKind::QuotaExceeded, "quota");
+        assert!(matches!(
+            DownloadError::write_failed("/tmp/test.pdf", quota),
+            DownloadError::DiskFull { .. }
+        ));
+
+        let denied = std::io::Error::new(std::io::ErrorKind::PermissionDenied, "denied");
+        assert!(matches!(
+            DownloadError::write_failed("/tmp/test.pdf", denied),
+            DownloadError::Io { .. }
+        ));
+    }
+
     #[test]
     fn test_download_error_invalid_url_display() {
         let error = DownloadError::invalid_url("not-a-url");
@@ -130,49 +130,99 @@ pub(crate) fn extension_from_content_type(content_type: &str) -> &'static str {
 
 /// Parses Content-Disposition header to extract filename.
 ///
-/// Handles both:
+/// Handles:
 /// - `attachment; filename="example.pdf"`
 /// - `attachment; filename=example.pdf`
 /// - `attachment; filename*=UTF-8''example.pdf` (RFC 5987)
+///
+/// `filename*` wins over `filename` wherever it appears, since servers send the
+/// plain form as an ASCII fallback. Its value is percent-decoded as UTF-8 (or
+/// ISO-8859-1 when declared); an undecodable value falls back to `filename`.
+/// Parameter names are case-insensitive, and quoted values may contain `;`
+/// and backslash escapes. Any directory part is dropped, so the result is a
+/// bare file name (still to be passed through [`sanitize_filename`]).
 pub(crate) fn parse_content_disposition(header: &str) -> Option<String> {
-    // Try filename*= first (RFC 5987 encoded)
-    if let Some(pos) = header.find("filename*=") {
-        let start = pos + 10;
-        let value = header[start..].trim();
-        // Format: charset'language'encoded_value
-        if let Some(quote_pos) = value.find("''") {
-            let encoded = &value[quote_pos + 2..];
-            // Take until ; or end
-            let end = encoded.find(';').unwrap_or(encoded.len());
-            let encoded_name = &encoded[..end].trim();
-            // URL decode
-            if let Ok(decoded) = urlencoding::decode(encoded_name) {
-                return Some(decoded.into_owned());
-            }
+    let mut plain = None;
+    let mut extended = None;
+    for param in split_unquoted_semicolons(header) {
+        let Some((name, value)) = param.split_once('=') else {
+            continue;
+        };
+        let name = name.trim();
+        let value = unquote(value.trim());
+        if name.eq_ignore_ascii_case("filename*") {
+            extended = extended.or_else(|| decode_ext_value(&value));
+        } else if name.eq_ignore_ascii_case("filename") {
+            plain = plain.or(Some(value));
         }
     }
 
-    // Try regular filename=
-    if let Some(pos) = header.find("filename=") {
-        let start = pos + 9;
-        let value = header[start..].trim();
+    [extended, plain]
+        .into_iter()
+        .flatten()
+        .map(|name| strip_directories(&name).to_string())
+        .find(|name| !name.is_empty())
+}
 
-        // Handle quoted filename
-        if let Some(stripped) = value.strip_prefix('"') {
-            if let Some(end) = stripped.find('"') {
-                return Some(stripped[..end].to_string());
+/// Splits a header on `;` outside double-quoted strings.
+fn split_unquoted_semicolons(header: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut in_quotes = false;
+    let mut escaped = false;
+    for (index, c) in header.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match c {
+            '\\' if in_quotes => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            ';' if !in_quotes => {
+                parts.push(&header[start..index]);
+                start = index + 1;
             }
+            _ => {}
+        }
+    }
+    parts.push(&header[start..]);
+    parts
+}
+
+/// Removes surrounding double quotes and resolves backslash escapes.
+fn unquote(value: &str) -> String {
+    let Some(inner) = value.strip_prefix('"') else {
+        return value.to_string();
+    };
+    let inner = inner.strip_suffix('"').unwrap_or(inner);
+    let mut out = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            out.extend(chars.next());
         } else {
-            // Unquoted - take until ; or end
-            let end = value.find(';').unwrap_or(value.len());
-            let filename = value[..end].trim();
-            if !filename.is_empty() {
-                return Some(filename.to_string());
-            }
+            out.push(c);
         }
     }
+    out
+}
+
+/// Decodes an RFC 5987 `charset'language'percent-encoded` value.
+fn decode_ext_value(value: &str) -> Option<String> {
+    let mut parts = value.splitn(3, '\'');
+    let charset = parts.next()?.trim();
+    let _language = parts.next()?;
+    let bytes = urlencoding::decode_binary(parts.next()?.trim().as_bytes());
+    if charset.eq_ignore_ascii_case("iso-8859-1") || charset.eq_ignore_ascii_case("latin1") {
+        Some(bytes.iter().copied().map(char::from).collect())
+    } else {
+        String::from_utf8(bytes.into_owned()).ok()
+    }
+}
 
-    None
+/// Returns the part after the last `/` or `\`, trimmed.
+fn strip_directories(name: &str) -> &str {
+    name.rsplit(['/', '\\']).next().unwrap_or_default().trim()
 }
 
 /// Sanitizes filename for filesystem safety.
@@ -354,6 +404,79 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_content_disposition_rfc5987_utf8_non_ascii() {
+        let header = "attachment; filename*=UTF-8''%E8%AE%BA%E6%96%87.pdf";
+        assert_eq!(
+            parse_content_disposition(header),
+            Some("论文.pdf".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_content_disposition_prefers_extended_over_plain_fallback() {
+        // Typical server output: ASCII fallback first, then the UTF-8 form.
+        let header =
+            "attachment; filename=\"__.pdf\"; filename*=utf-8'zh-CN'%E8%AE%BA%E6%96%87.pdf";
+        assert_eq!(
+            parse_content_disposition(header),
+            Some("论文.pdf".to_string())
+        );
+        let header = "inline; FILENAME*=UTF-8''%E8%AE%BA%E6%96%87.pdf; Filename=\"fallback.pdf\"";
+        assert_eq!(
+            parse_content_disposition(header),
+            Some("论文.pdf".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_content_disposition_rfc5987_latin1() {
+        let header = "attachment; filename*=iso-8859-1'en'%A3%20rates.pdf";
+        assert_eq!(
+            parse_content_disposition(header),
+            Some("£ rates.pdf".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_content_disposition_invalid_utf8_falls_back_to_plain() {
+        let header = "attachment; filename=\"plain.pdf\"; filename*=UTF-8''%FF%FE.pdf";
+        assert_eq!(
+            parse_content_disposition(header),
+            Some("plain.pdf".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_content_disposition_strips_directories() {
+        let header = "attachment; filename*=UTF-8''..%2F..%2F%E8%AE%BA%E6%96%87.pdf";
+        assert_eq!(
+            parse_content_disposition(header),
+            Some("论文.pdf".to_string())
+        );
+        let header = "attachment; filename=../../etc/passwd";
+        assert_eq!(
+            parse_content_disposition(header),
+            Some("passwd".to_string())
+        );
+        let header = r#"attachment; filename="C:\\temp\\evil.exe""#;
+        assert_eq!(
+            parse_content_disposition(header),
+            Some("evil.exe".to_string())
+        );
+        let header = "attachment; filename*=UTF-8''%2F";
+        assert_eq!(parse_content_disposition(header), None);
+    }
+
+    #[test]
+    fn test_parse_content_disposition_quoted_semicolons_and_escapes() {
+        let header = r#"attachment; filename="a \"b\"; c.pdf"; size=3"#;
+        assert_eq!(
+            parse_content_disposition(header),
+            Some(r#"a "b"; c.pdf"#.to_string())
+        );
+    }
+
     #[test]
     fn test_parse_content_disposition_missing() {
         let header = "attachment";
@@ -0,0 +1,152 @@
+//! Detection of HTML landing pages that redirect without an HTTP redirect.
+//!
+//! Some publishers answer a download URL with a small HTML stub whose
+//! `<meta http-equiv="refresh">` tag or inline `location.href = '...'` script
+//! points at the real file. reqwest never sees those as redirects, so the stub
+//! would be saved in place of the document. This is a targeted heuristic over
+//! the saved body, not a JavaScript engine.
+
+use std::path::Path;
+use std::sync::LazyLock;
+
+use regex::Regex;
+use url::Url;
+
+/// HTML bodies larger than this are real pages, not redirect stubs.
+pub(super) const HTML_REDIRECT_MAX_BYTES: u64 = 16 * 1024;
+
+#[allow(clippy::expect_used)]
+static META_TAG: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?is)<meta\b[^>]*>").expect("meta tag regex is valid"));
+
+#[allow(clippy::expect_used)]
+static REFRESH_EQUIV: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?i)\bhttp-equiv\s*=\s*["']?\s*refresh\b"#).expect("http-equiv regex is valid")
+});
+
+#[allow(clippy::expect_used)]
+static CONTENT_ATTR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?is)\bcontent\s*=\s*(?:"([^"]*)"|'([^']*)')"#)
+        .expect("content attribute regex is valid")
+});
+
+#[allow(clippy::expect_used)]
+static JS_LOCATION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"(?i)\blocation(?:\.href)?\s*=\s*["']([^"']+)["']|\blocation\.(?:replace|assign)\(\s*["']([^"']+)["']\s*\)"#,
+    )
+    .expect("JS location regex is valid")
+});
+
+/// Returns the redirect target of an HTML stub, resolved against `base`.
+///
+/// A meta refresh wins over a script assignment. Only `http`/`https` targets
+/// other than `base` itself are returned.
+pub(super) fn find_html_redirect(html: &str, base: &Url) -> Option<Url> {
+    let target = meta_refresh_target(html).or_else(|| js_location_target(html))?;
+    let target = base.join(&target.replace("&amp;", "&")).ok()?;
+    (matches!(target.scheme(), "http" | "https") && &target != base).then_some(target)
+}
+
+/// Reads a saved HTML body and returns its redirect target, if any.
+pub(super) async fn saved_html_redirect(path: &Path, base: &Url) -> Option<Url> {
+    let body = tokio::fs::read(path).await.ok()?;
+    find_html_redirect(&String::from_utf8_lossy(&body), base)
+}
+
+/// `content="0; url=/files/paper.pdf"` → `/files/paper.pdf`.
+fn meta_refresh_target(html: &str) -> Option<String> {
+    META_TAG
+        .find_iter(html)
+        .map(|tag| regex::Match::as_str(&tag))
+        .filter(|tag| REFRESH_EQUIV.is_match(tag))
+        .find_map(|tag| {
+            let captures = CONTENT_ATTR.captures(tag)?;
+            let content = captures.get(1).or_else(|| captures.get(2))?.as_str();
+            let (_delay, rest) = content.split_once([';', ','])?;
+            let rest = rest.trim_start();
+            let target = match rest.get(..4) {
+                Some(prefix) if prefix.eq_ignore_ascii_case("url=") => &rest[4..],
+                _ => rest,
+            };
+            let target = target.trim().trim_matches(['"', '\'']).trim();
+            (!target.is_empty()).then(|| target.to_string())
+        })
+}
+
+/// `window.location.href = '/files/paper.pdf'` → `/files/paper.pdf`.
+fn js_location_target(html: &str) -> Option<String> {
+    let captures = JS_LOCATION.captures(html)?;
+    let target = captures.get(1).or_else(|| captures.get(2))?.as_str().trim();
+    (!target.is_empty()).then(|| target.to_string())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn base() -> Url {
+        Url::parse("https://publisher.example/article/42").unwrap()
+    }
+
+    #[test]
+    fn test_find_html_redirect_meta_refresh() {
+        let html = r#"<html><head>
+            <META HTTP-EQUIV="Refresh" CONTENT="0; URL='/files/42.pdf?a=1&amp;b=2'">
+            </head><body>Redirecting…</body></html>"#;
+        assert_eq!(
+            find_html_redirect(html, &base()).unwrap().as_str(),
+            "https://publisher.example/files/42.pdf?a=1&b=2"
+        );
+    }
+
+    #[test]
+    fn test_find_html_redirect_meta_refresh_without_url_prefix() {
+        let html = r#"<meta content='5;https://cdn.example/42.pdf' http-equiv=refresh>"#;
+        assert_eq!(
+            find_html_redirect(html, &base()).unwrap().as_str(),
+            "https://cdn.example/42.pdf"
+        );
+    }
+
+    #[test]
+    fn test_find_html_redirect_js_location() {
+        for script in [
+            "window.location.href = 'pdf/42.pdf';",
+            r#"location.href="pdf/42.pdf""#,
+            "window.location.replace('pdf/42.pdf')",
+        ] {
+            let html = format!("<html><script>{script}</script></html>");
+            assert_eq!(
+                find_html_redirect(&html, &base()).unwrap().as_str(),
+                "https://publisher.example/article/pdf/42.pdf",
+                "{script}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_html_redirect_ignores_plain_pages_and_non_http_targets() {
+        assert!(find_html_redirect("<html><body>Article</body></html>", &base()).is_none());
+        assert!(
+            find_html_redirect(r#"<meta http-equiv="refresh" content="30">"#, &base()).is_none(),
+            "a reload without a target is not a redirect"
+        );
+        assert!(
+            find_html_redirect(
+                "<script>location.href = 'javascript:void(0)'</script>",
+                &base()
+            )
+            .is_none()
+        );
+        assert!(
+            find_html_redirect(
+                r#"<meta http-equiv="refresh" content="0;url=/article/42">"#,
+                &base()
+            )
+            .is_none(),
+            "a page refreshing itself is not followed"
+        );
+    }
+}
@@ -0,0 +1,157 @@
+//! Output layout modes that bucket downloads into per-year, per-author, or
+//! per-topic subfolders beneath the output directory.
+
+use std::path::{Path, PathBuf};
+
+use super::filename::extract_primary_author;
+use crate::project::sanitize_project_segment;
+use crate::queue::QueueItem;
+
+/// Bucket used when the metadata a layout needs is missing or unusable.
+pub const UNKNOWN_LAYOUT_BUCKET: &str = "unknown";
+
+/// How downloaded files are arranged under the output directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LayoutMode {
+    /// Every file directly in the output directory.
+    #[default]
+    Flat,
+    /// `<year>/<file>`, using the four-digit publication year.
+    ByYear,
+    /// `<first author family name>/<file>`.
+    ByFirstAuthor,
+    /// `<first detected topic>/<file>`.
+    ByTopic,
+}
+
+/// Returns the subfolder `item` belongs in under `mode`, or `None` for [`LayoutMode::Flat`].
+///
+/// Bucket names go through [`sanitize_project_segment`], so they are safe
+/// single path components; anything that sanitises to nothing lands in
+/// [`UNKNOWN_LAYOUT_BUCKET`].
+#[must_use]
+pub fn layout_subfolder(mode: LayoutMode, item: &QueueItem) -> Option<String> {
+    let raw = match mode {
+        LayoutMode::Flat => return None,
+        LayoutMode::ByYear => item.meta_year.as_deref().and_then(publication_year),
+        LayoutMode::ByFirstAuthor => item
+            .meta_authors
+            .as_deref()
+            .and_then(extract_primary_author),
+        LayoutMode::ByTopic => item.parse_topics().into_iter().next(),
+    };
+    let bucket = raw
+        .and_then(|value| sanitize_project_segment(&value).ok())
+        .unwrap_or_else(|| UNKNOWN_LAYOUT_BUCKET.to_string());
+    Some(bucket)
+}
+
+/// Returns the directory `item` should be saved into under `mode`.
+#[must_use]
+pub fn layout_output_dir(output_dir: &Path, mode: LayoutMode, item: &QueueItem) -> PathBuf {
+    match layout_subfolder(mode, item) {
+        Some(bucket) => output_dir.join(bucket),
+        None => output_dir.to_path_buf(),
+    }
+}
+
+fn publication_year(value: &str) -> Option<String> {
+    let year: String = value.trim().chars().take(4).collect();
+    (year.len() == 4 && year.chars().all(|c| c.is_ascii_digit())).then_some(year)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn item(year: Option<&str>, authors: Option<&str>, topics: Option<&str>) -> QueueItem {
+        QueueItem {
+            id: 1,
+            url: "https://example.com/paper.pdf".to_string(),
+            source_type: "direct_url".to_string(),
+            original_input: None,
+            project: None,
+            status_str: "pending".to_string(),
+            priority: 0,
+            retry_count: 0,
+            last_error: None,
+            suggested_filename: None,
+            meta_title: None,
+            meta_authors: authors.map(String::from),
+            meta_author_orcids: None,
+            meta_abstract: None,
+            meta_year: year.map(String::from),
+            meta_doi: None,
+            topics: topics.map(String::from),
+            parse_confidence: None,
+            parse_confidence_factors: None,
+            saved_path: None,
+            bytes_downloaded: 0,
+            content_length: None,
+            dead_letter: false,
+            read_timeout_secs: None,
+            resolver_path: None,
+            input_source_file: None,
+            created_at: "2026-01-01".to_string(),
+            updated_at: "2026-01-01".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_layout_flat_has_no_subfolder() {
+        let item = item(Some("2024"), Some("Smith, J."), None);
+        assert_eq!(layout_subfolder(LayoutMode::Flat, &item), None);
+        assert_eq!(
+            layout_output_dir(Path::new("/out"), LayoutMode::Flat, &item),
+            PathBuf::from("/out")
+        );
+    }
+
+    #[test]
+    fn test_layout_by_year_uses_four_digit_year() {
+        let item = item(Some("2024-05-01"), None, None);
+        assert_eq!(
+            layout_output_dir(Path::new("/out"), LayoutMode::ByYear, &item),
+            PathBuf::from("/out/2024")
+        );
+    }
+
+    #[test]
+    fn test_layout_by_first_author_uses_family_name() {
+        let item = item(None, Some("Smith, John; Doe, Jane"), None);
+        assert_eq!(
+            layout_subfolder(LayoutMode::ByFirstAuthor, &item).as_deref(),
+            Some("Smith")
+        );
+    }
+
+    #[test]
+    fn test_layout_by_topic_sanitizes_first_topic() {
+        let item = item(None, None, Some(r#"["machine learning", "vision"]"#));
+        assert_eq!(
+            layout_subfolder(LayoutMode::ByTopic, &item).as_deref(),
+            Some("machine-learning")
+        );
+    }
+
+    #[test]
+    fn test_layout_missing_or_unusable_metadata_falls_back_to_unknown() {
+        let bare = item(Some("n.d."), None, None);
+        for mode in [
+            LayoutMode::ByYear,
+            LayoutMode::ByFirstAuthor,
+            LayoutMode::ByTopic,
+        ] {
+            assert_eq!(
+                layout_subfolder(mode, &bare).as_deref(),
+                Some(UNKNOWN_LAYOUT_BUCKET)
+            );
+        }
+        let traversal = item(None, None, Some(r#"[".."]"#));
+        assert_eq!(
+            layout_subfolder(LayoutMode::ByTopic, &traversal).as_deref(),
+            Some(UNKNOWN_LAYOUT_BUCKET)
+        );
+    }
+}
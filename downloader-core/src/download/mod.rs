@@ -1,13 +1,16 @@
 //! HTTP download engine for streaming files to disk.
 //!
 //! This module provides functionality for downloading files from HTTP/HTTPS URLs
-//! with streaming support to handle large files efficiently.
+//! with streaming support to handle large files efficiently. `ftp://` and
+//! `file://` sources are supported behind the `ftp` and `file-urls` features.
 //!
 //! # Features
 //!
 //! - Streaming downloads (memory-efficient for large files)
 //! - Automatic filename extraction from Content-Disposition headers
 //! - Configurable timeouts (30s connect, 5min read by default)
+//! - Configurable redirect limit (10 by default) with the last hops reported
+//! - One-time follow of meta-refresh / `location.href` HTML landing stubs
 //! - Structured error types with full context
 //! - Duplicate filename handling (adds numeric suffix)
 //!
@@ -32,19 +35,36 @@ mod constants;
 mod engine;
 mod error;
 mod filename;
+#[cfg(feature = "ftp")]
+mod ftp;
+mod html_redirect;
+mod layout;
+mod preflight;
 pub mod rate_limiter;
+mod redirect;
 mod retry;
 mod robots;
+mod segmented;
+mod sources;
 
 pub use client::{BROWSER_USER_AGENT, DownloadFileResult, HttpClient};
 pub use engine::{
-    DEFAULT_CONCURRENCY, DownloadEngine, DownloadStats, EngineError, QueueProcessingOptions,
+    DEFAULT_CONCURRENCY, DownloadEngine, DownloadStats, DownloadStatsSummary, EngineError,
+    QueueProcessingOptions,
 };
 pub use error::DownloadError;
 pub use filename::build_preferred_filename;
+pub use layout::{LayoutMode, UNKNOWN_LAYOUT_BUCKET, layout_output_dir, layout_subfolder};
+pub use preflight::{PreflightResult, PreflightStatus, UrlPreflight};
 pub use rate_limiter::{RateLimiter, extract_domain, parse_retry_after};
+pub(crate) use redirect::redirect_policy;
+pub use redirect::{DEFAULT_MAX_REDIRECTS, configure_max_redirects, max_redirects};
 pub use retry::{DEFAULT_MAX_RETRIES, FailureType, RetryDecision, RetryPolicy, classify_error};
-pub use robots::{RobotsCache, RobotsDecision, RobotsError, origin_for_robots};
+pub use robots::{
+    RobotsCache, RobotsDecision, RobotsError, is_robots_allowlisted, origin_for_robots,
+};
+pub use segmented::MAX_DOWNLOAD_SEGMENTS;
+pub use sources::is_supported_scheme;
 
 // Note: Per project-context.md, we do NOT define module-local Result aliases.
 // Use `Result<T, DownloadError>` explicitly in function signatures.
@@ -0,0 +1,364 @@
+//! Pre-flight checks: is a resolved URL fetchable, without downloading it?
+//!
+//! [`UrlPreflight`] sends one HEAD request per URL through the download
+//! [`HttpClient`], so cookies, domain headers, and timeouts match a real
+//! download, after the same robots.txt check and rate-limit wait the engine
+//! applies. Servers that reject HEAD get a ranged `GET bytes=0-0` instead, and
+//! only the response headers are read.
+
+use std::sync::Arc;
+
+use reqwest::header::{CONTENT_LENGTH, CONTENT_RANGE, CONTENT_TYPE};
+use tracing::{instrument, warn};
+
+use super::{
+    DownloadError, HttpClient, RateLimiter, RobotsCache, RobotsDecision, is_robots_allowlisted,
+    origin_for_robots,
+};
+
+/// HEAD responses that suggest the server rejects HEAD rather than the URL.
+///
+/// 403 is included because signed URLs (S3, CDNs) often sign the method too.
+const HEAD_REJECTED_STATUSES: &[u16] = &[400, 403, 405, 501];
+
+/// Outcome class of a [`UrlPreflight::check`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreflightStatus {
+    /// The server answered with a success status.
+    Ok,
+    /// The server asked for credentials (401/403/407 or a login redirect).
+    Auth,
+    /// The server reported the resource missing (404/410).
+    NotFound,
+    /// robots.txt disallows the URL; no request was sent.
+    RobotsDisallowed,
+    /// The URL scheme has no HEAD equivalent (`ftp://`, `file://`).
+    Skipped,
+    /// Any other HTTP status or transport error.
+    Failed,
+}
+
+impl PreflightStatus {
+    /// Stable lowercase label for output and summaries.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Auth => "auth",
+            Self::NotFound => "not_found",
+            Self::RobotsDisallowed => "robots_disallowed",
+            Self::Skipped => "skipped",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+/// What a pre-flight learned about one URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreflightResult {
+    /// Outcome class.
+    pub status: PreflightStatus,
+    /// HTTP status code, when the server answered.
+    pub http_status: Option<u16>,
+    /// `Content-Type` response header.
+    pub content_type: Option<String>,
+    /// Full resource size from `Content-Length` or a `Content-Range` total.
+    pub content_length: Option<u64>,
+    /// `"HEAD"`, or `"GET"` for the ranged fallback; `None` when nothing was sent.
+    pub method: Option<&'static str>,
+    /// Error text for non-`Ok` outcomes.
+    pub error: Option<String>,
+}
+
+impl PreflightResult {
+    fn without_request(status: PreflightStatus, error: impl Into<String>) -> Self {
+        Self {
+            status,
+            http_status: None,
+            content_type: None,
+            content_length: None,
+            method: None,
+            error: Some(error.into()),
+        }
+    }
+
+    fn from_response(method: &'static str, response: &reqwest::Response) -> Self {
+        let header = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+        };
+        let content_length = if response.status().as_u16() == 206 {
+            header(CONTENT_RANGE)
+                .and_then(|range| range.rsplit('/').next())
+                .and_then(|total| total.trim().parse().ok())
+        } else {
+            header(CONTENT_LENGTH).and_then(|length| length.trim().parse().ok())
+        };
+        Self {
+            status: PreflightStatus::Ok,
+            http_status: Some(response.status().as_u16()),
+            content_type: header(CONTENT_TYPE).map(str::to_string),
+            content_length,
+            method: Some(method),
+            error: None,
+        }
+    }
+
+    fn from_error(method: &'static str, error: &DownloadError) -> Self {
+        let (status, http_status) = match error {
+            DownloadError::AuthRequired { status, .. } => {
+                (PreflightStatus::Auth, (*status != 0).then_some(*status))
+            }
+            DownloadError::HttpStatus { status, .. } => {
+                let class = match status {
+                    401 | 403 | 407 => PreflightStatus::Auth,
+                    404 | 410 => PreflightStatus::NotFound,
+                    _ => PreflightStatus::Failed,
+                };
+                (class, Some(*status))
+            }
+            _ => (PreflightStatus::Failed, None),
+        };
+        Self {
+            status,
+            http_status,
+            content_type: None,
+            content_length: None,
+            method: Some(method),
+            error: Some(error.to_string()),
+        }
+    }
+}
+
+/// Checks URLs with the same client, rate limiter, and robots policy as a download run.
+#[derive(Debug, Clone)]
+pub struct UrlPreflight {
+    client: HttpClient,
+    rate_limiter: Arc<RateLimiter>,
+    robots_cache: Option<Arc<RobotsCache>>,
+    robots_allow: Vec<String>,
+}
+
+impl UrlPreflight {
+    /// Creates a checker that sends requests through `client`, pacing them with `rate_limiter`.
+    #[must_use]
+    pub fn new(client: HttpClient, rate_limiter: Arc<RateLimiter>) -> Self {
+        Self {
+            client,
+            rate_limiter,
+            robots_cache: None,
+            robots_allow: Vec::new(),
+        }
+    }
+
+    /// Consults robots.txt before each check, like `check_robots` downloads.
+    ///
+    /// Hosts in `robots_allow` are checked anyway, with a warning.
+    #[must_use]
+    pub fn with_robots(
+        mut self,
+        robots_cache: Arc<RobotsCache>,
+        robots_allow: Vec<String>,
+    ) -> Self {
+        self.robots_cache = Some(robots_cache);
+        self.robots_allow = robots_allow;
+        self
+    }
+
+    /// Probes `url` with HEAD, falling back to `GET bytes=0-0` when HEAD is rejected.
+    #[instrument(skip(self))]
+    pub async fn check(&self, url: &str) -> PreflightResult {
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            return PreflightResult::without_request(
+                PreflightStatus::Skipped,
+                "pre-flight checks only HTTP(S) URLs",
+            );
+        }
+        if self.robots_disallows(url).await {
+            return PreflightResult::without_request(
+                PreflightStatus::RobotsDisallowed,
+                DownloadError::robots_disallowed(url).to_string(),
+            );
+        }
+
+        self.rate_limiter.acquire(url).await;
+        let (method, result) = match self.client.send_request("HEAD", url, None, None).await {
+            Err(
+                DownloadError::HttpStatus { status, .. }
+                | DownloadError::AuthRequired { status, .. },
+            ) if HEAD_REJECTED_STATUSES.contains(&status) => (
+                "GET",
+                self.client
+                    .send_request("GET", url, None, Some("bytes=0-0"))
+                    .await,
+            ),
+            result => ("HEAD", result),
+        };
+        match result {
+            Ok(response) => PreflightResult::from_response(method, &response),
+            Err(error) => PreflightResult::from_error(method, &error),
+        }
+    }
+
+    async fn robots_disallows(&self, url: &str) -> bool {
+        let (Some(cache), Some(origin)) = (&self.robots_cache, origin_for_robots(url)) else {
+            return false;
+        };
+        match cache.check_allowed(url, &origin, &self.client).await {
+            Ok(RobotsDecision::Disallowed) if is_robots_allowlisted(url, &self.robots_allow) => {
+                warn!(
+                    url,
+                    "robots.txt disallows this URL; checking anyway because its host is on robots_allow"
+                );
+                false
+            }
+            Ok(RobotsDecision::Disallowed) => true,
+            Ok(RobotsDecision::Allowed) => false,
+            Err(error) => {
+                warn!(url, %error, "robots.txt check failed; proceeding with pre-flight");
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::time::Duration;
+
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    use super::*;
+    use crate::test_support::socket_guard::start_mock_server_or_skip;
+
+    fn preflight() -> UrlPreflight {
+        UrlPreflight::new(HttpClient::new(), Arc::new(RateLimiter::disabled()))
+    }
+
+    #[tokio::test]
+    async fn test_check_reports_head_headers() {
+        let Some(server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("HEAD"))
+            .and(path("/paper.pdf"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "application/pdf")
+                    .insert_header("content-length", "12345"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let result = preflight()
+            .check(&format!("{}/paper.pdf", server.uri()))
+            .await;
+
+        assert_eq!(result.status, PreflightStatus::Ok);
+        assert_eq!(result.http_status, Some(200));
+        assert_eq!(result.method, Some("HEAD"));
+        assert_eq!(result.content_type.as_deref(), Some("application/pdf"));
+        assert_eq!(result.content_length, Some(12345));
+    }
+
+    #[tokio::test]
+    async fn test_check_falls_back_to_ranged_get_when_head_rejected() {
+        let Some(server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("HEAD"))
+            .and(path("/file"))
+            .respond_with(ResponseTemplate::new(405))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/file"))
+            .and(header("range", "bytes=0-0"))
+            .respond_with(
+                ResponseTemplate::new(206)
+                    .insert_header("content-type", "application/zip")
+                    .insert_header("content-range", "bytes 0-0/987654")
+                    .set_body_bytes(b"P"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let result = preflight().check(&format!("{}/file", server.uri())).await;
+
+        assert_eq!(result.status, PreflightStatus::Ok);
+        assert_eq!(result.method, Some("GET"));
+        assert_eq!(result.http_status, Some(206));
+        assert_eq!(result.content_length, Some(987_654));
+    }
+
+    #[tokio::test]
+    async fn test_check_classifies_auth_and_not_found() {
+        let Some(server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("HEAD"))
+            .and(path("/gone.pdf"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        Mock::given(method("HEAD"))
+            .and(path("/login.pdf"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        let preflight = preflight();
+        let missing = preflight.check(&format!("{}/gone.pdf", server.uri())).await;
+        let auth = preflight
+            .check(&format!("{}/login.pdf", server.uri()))
+            .await;
+
+        assert_eq!(missing.status, PreflightStatus::NotFound);
+        assert_eq!(missing.http_status, Some(404));
+        assert_eq!(auth.status, PreflightStatus::Auth);
+        assert_eq!(auth.http_status, Some(401));
+    }
+
+    #[tokio::test]
+    async fn test_check_honors_robots_disallow_without_probing() {
+        let Some(server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/robots.txt"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string("User-agent: *\nDisallow: /private/\n"),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("HEAD"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let preflight = UrlPreflight::new(
+            HttpClient::new(),
+            Arc::new(RateLimiter::new(Duration::from_millis(1))),
+        )
+        .with_robots(Arc::new(RobotsCache::new()), Vec::new());
+        let result = preflight
+            .check(&format!("{}/private/a.pdf", server.uri()))
+            .await;
+
+        assert_eq!(result.status, PreflightStatus::RobotsDisallowed);
+        assert_eq!(result.method, None);
+    }
+
+    #[tokio::test]
+    async fn test_check_skips_non_http_schemes() {
+        let result = preflight().check("ftp://ftp.example.com/a.pdf").await;
+        assert_eq!(result.status, PreflightStatus::Skipped);
+    }
+}
@@ -14,6 +14,11 @@
 //! regular spacing and fingerprinting), use [`RateLimiter::new_with_jitter`]. The
 //! download orchestrator uses jitter when CLI `--rate-limit-jitter` is set.
 //!
+//! [`RateLimiter::with_adaptive`] turns on adaptive spacing (CLI `--rate-adaptive`):
+//! each 429/503 from a domain doubles its spacing, and every
+//! [`ADAPTIVE_DECAY_STREAK`] consecutive successes halve it again until it is
+//! back at the configured delay.
+//!
 //! # Example
 //!
 //! ```
@@ -51,6 +56,18 @@ use super::constants::{CUMULATIVE_DELAY_WARNING_THRESHOLD, MAX_RETRY_AFTER};
 const DEFAULT_STALE_DOMAIN_TTL: Duration = Duration::from_secs(30 * 60);
 const DEFAULT_CLEANUP_INTERVAL_OPS: u64 = 256;
 
+/// Spacing applied after the first 429/503 when the configured delay is zero.
+pub const ADAPTIVE_INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Upper bound for adaptive spacing between requests to one domain.
+pub const ADAPTIVE_MAX_DELAY: Duration = Duration::from_secs(120);
+
+/// Consecutive successes after which adaptive spacing is halved.
+pub const ADAPTIVE_DECAY_STREAK: u64 = 5;
+
+/// Factor applied to a domain's spacing on each 429/503.
+const ADAPTIVE_BACKOFF_FACTOR: u32 = 2;
+
 /// Per-domain rate limiter for download requests.
 ///
 /// This struct is designed to be wrapped in `Arc` and shared across multiple
@@ -91,6 +108,9 @@ pub struct RateLimiter {
     /// Whether rate limiting is disabled (for `--rate-limit 0`).
     disabled: bool,
 
+    /// Whether per-domain spacing adapts to 429/503 responses (`--rate-adaptive`).
+    adaptive: bool,
+
     /// Per-domain state tracking.
     /// Uses Arc to allow cloning the state and releasing the `DashMap` lock
     /// before awaiting on the inner Mutex (prevents shard lock across await).
@@ -120,6 +140,12 @@ struct DomainState {
 
     /// Last unix timestamp (seconds) when this domain was touched.
     last_seen_secs: AtomicU64,
+
+    /// Adaptive spacing in milliseconds; `0` means the configured delay applies.
+    adaptive_delay_ms: AtomicU64,
+
+    /// Successful requests since the last 429/503 or decay step.
+    success_streak: AtomicU64,
 }
 
 impl DomainState {
@@ -130,6 +156,8 @@ impl DomainState {
             last_request: Mutex::new(None),
             cumulative_delay_ms: AtomicU64::new(0),
             last_seen_secs: AtomicU64::new(now_secs),
+            adaptive_delay_ms: AtomicU64::new(0),
+            success_streak: AtomicU64::new(0),
         }
     }
 
@@ -233,6 +261,7 @@ impl RateLimiter {
             default_delay,
             max_jitter,
             disabled,
+            adaptive: false,
             domains: DashMap::new(),
             stale_domain_ttl,
             cleanup_interval_ops: cleanup_interval_ops.max(1),
@@ -240,6 +269,41 @@ impl RateLimiter {
         }
     }
 
+    /// Enables adaptive spacing driven by [`RateLimiter::record_throttled`]
+    /// and [`RateLimiter::record_success`].
+    ///
+    /// Has no effect on a [`RateLimiter::disabled`] limiter; use
+    /// `RateLimiter::new(Duration::ZERO)` for adaptive spacing from a zero baseline.
+    #[must_use]
+    pub fn with_adaptive(mut self) -> Self {
+        self.adaptive = true;
+        self
+    }
+
+    /// Returns whether adaptive spacing is enabled.
+    #[must_use]
+    pub fn is_adaptive(&self) -> bool {
+        self.adaptive
+    }
+
+    /// Returns the spacing currently enforced for `url`'s domain, before jitter.
+    ///
+    /// This is the configured delay unless adaptive spacing has raised it.
+    #[must_use]
+    pub fn effective_delay(&self, url: &str) -> Duration {
+        if self.disabled {
+            return Duration::ZERO;
+        }
+        self.domains
+            .get(&extract_domain(url))
+            .map_or(self.default_delay, |state| self.domain_delay(&state))
+    }
+
+    fn domain_delay(&self, state: &DomainState) -> Duration {
+        let adaptive = Duration::from_millis(state.adaptive_delay_ms.load(Ordering::SeqCst));
+        self.default_delay.max(adaptive)
+    }
+
     /// Returns whether rate limiting is disabled.
     #[must_use]
     pub fn is_disabled(&self) -> bool {
@@ -301,9 +365,10 @@ impl RateLimiter {
         // Check if this is the first request (None) or a subsequent request
         if let Some(last_request) = *last_request_guard {
             let elapsed = last_request.elapsed();
+            let spacing = self.domain_delay(&state);
 
-            if elapsed < self.default_delay {
-                let mut delay = self.default_delay.saturating_sub(elapsed);
+            if elapsed < spacing {
+                let mut delay = spacing.saturating_sub(elapsed);
                 if let Some(jitter) = self.max_jitter {
                     let jitter_cap = u64::try_from(jitter.as_millis().min(u128::from(u64::MAX)))
                         .unwrap_or(u64::MAX);
@@ -380,6 +445,92 @@ impl RateLimiter {
         self.maybe_cleanup_stale_domains(now_secs);
     }
 
+    /// Widens the domain's spacing after a 429 or 503 response (adaptive mode only).
+    ///
+    /// The spacing is multiplied by two (starting from [`ADAPTIVE_INITIAL_BACKOFF`]
+    /// when the configured delay is zero), raised to `retry_after` when the server
+    /// asked for more, and capped at [`ADAPTIVE_MAX_DELAY`]. The success streak
+    /// is reset.
+    #[instrument(skip(self), fields(domain))]
+    pub fn record_throttled(&self, url: &str, retry_after: Option<Duration>) {
+        if !self.adaptive || self.disabled {
+            return;
+        }
+        let now_secs = unix_timestamp_secs();
+        let domain = extract_domain(url);
+        tracing::Span::current().record("domain", &domain);
+
+        let state = self
+            .domains
+            .entry(domain.clone())
+            .or_insert_with(|| Arc::new(DomainState::new(now_secs)))
+            .clone();
+        state.touch(now_secs);
+        state.success_streak.store(0, Ordering::SeqCst);
+
+        let current = self.domain_delay(&state);
+        let widened = if current.is_zero() {
+            ADAPTIVE_INITIAL_BACKOFF
+        } else {
+            current.saturating_mul(ADAPTIVE_BACKOFF_FACTOR)
+        };
+        let spacing = widened
+            .max(retry_after.unwrap_or_default())
+            .min(ADAPTIVE_MAX_DELAY);
+        state
+            .adaptive_delay_ms
+            .store(duration_millis(spacing), Ordering::SeqCst);
+
+        debug!(
+            domain = %domain,
+            spacing_ms = spacing.as_millis(),
+            "widened adaptive rate limit spacing"
+        );
+        self.maybe_cleanup_stale_domains(now_secs);
+    }
+
+    /// Counts a successful request; every [`ADAPTIVE_DECAY_STREAK`] successes in a
+    /// row halve the domain's adaptive spacing until it is back at the configured
+    /// delay (adaptive mode only).
+    #[instrument(skip(self), fields(domain))]
+    pub fn record_success(&self, url: &str) {
+        if !self.adaptive || self.disabled {
+            return;
+        }
+        let domain = extract_domain(url);
+        tracing::Span::current().record("domain", &domain);
+        let Some(state) = self
+            .domains
+            .get(&domain)
+            .map(|entry| Arc::clone(entry.value()))
+        else {
+            return;
+        };
+
+        let current_ms = state.adaptive_delay_ms.load(Ordering::SeqCst);
+        if current_ms == 0 {
+            return;
+        }
+        let streak = state.success_streak.fetch_add(1, Ordering::SeqCst) + 1;
+        if streak < ADAPTIVE_DECAY_STREAK {
+            return;
+        }
+        state.success_streak.store(0, Ordering::SeqCst);
+
+        let halved_ms = current_ms / 2;
+        let next_ms = if halved_ms <= duration_millis(self.default_delay) {
+            0
+        } else {
+            halved_ms
+        };
+        state.adaptive_delay_ms.store(next_ms, Ordering::SeqCst);
+        debug!(
+            domain = %domain,
+            spacing_ms = Duration::from_millis(next_ms).max(self.default_delay).as_millis(),
+            "decayed adaptive rate limit spacing"
+        );
+    }
+
     fn maybe_cleanup_stale_domains(&self, now_secs: u64) {
         let op = self.cleanup_ops.fetch_add(1, Ordering::Relaxed) + 1;
         if op % self.cleanup_interval_ops != 0 {
@@ -401,6 +552,10 @@ impl RateLimiter {
     }
 }
 
+fn duration_millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
 fn unix_timestamp_secs() -> u64 {
     std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
@@ -764,6 +919,69 @@ mod tests {
         );
     }
 
+    // ==================== Adaptive Spacing Tests ====================
+
+    #[test]
+    fn test_adaptive_spacing_doubles_on_throttle_and_decays_after_streak() {
+        let limiter = RateLimiter::new(Duration::from_millis(100)).with_adaptive();
+        let url = "https://example.com/file.pdf";
+        assert_eq!(limiter.effective_delay(url), Duration::from_millis(100));
+
+        let mut observed = Vec::new();
+        for _ in 0..3 {
+            limiter.record_throttled(url, None);
+            observed.push(limiter.effective_delay(url));
+        }
+        assert_eq!(
+            observed,
+            [200, 400, 800].map(Duration::from_millis),
+            "spacing grows multiplicatively"
+        );
+        assert_eq!(
+            limiter.effective_delay("https://other.com/x"),
+            Duration::from_millis(100)
+        );
+
+        for _ in 0..ADAPTIVE_DECAY_STREAK - 1 {
+            limiter.record_success(url);
+        }
+        assert_eq!(limiter.effective_delay(url), Duration::from_millis(800));
+        limiter.record_success(url);
+        assert_eq!(limiter.effective_delay(url), Duration::from_millis(400));
+        for _ in 0..2 * ADAPTIVE_DECAY_STREAK {
+            limiter.record_success(url);
+        }
+        assert_eq!(
+            limiter.effective_delay(url),
+            Duration::from_millis(100),
+            "decays back to the baseline"
+        );
+    }
+
+    #[test]
+    fn test_adaptive_spacing_honours_retry_after_and_cap() {
+        let limiter = RateLimiter::new(Duration::ZERO).with_adaptive();
+        let url = "https://example.com/file.pdf";
+
+        limiter.record_throttled(url, None);
+        assert_eq!(limiter.effective_delay(url), ADAPTIVE_INITIAL_BACKOFF);
+        limiter.record_throttled(url, Some(Duration::from_secs(30)));
+        assert_eq!(limiter.effective_delay(url), Duration::from_secs(30));
+        limiter.record_throttled(url, Some(Duration::from_secs(3600)));
+        assert_eq!(limiter.effective_delay(url), ADAPTIVE_MAX_DELAY);
+    }
+
+    #[test]
+    fn test_fixed_mode_ignores_throttle_signals() {
+        let limiter = RateLimiter::new(Duration::from_millis(100));
+        limiter.record_throttled("https://example.com/a", None);
+        assert!(!limiter.is_adaptive());
+        assert_eq!(
+            limiter.effective_delay("https://example.com/a"),
+            Duration::from_millis(100)
+        );
+    }
+
     // ==================== record_rate_limit Tests ====================
 
     #[test]
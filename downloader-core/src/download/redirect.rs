@@ -0,0 +1,189 @@
+//! Redirect policy shared by the download and resolver HTTP clients.
+//!
+//! Both clients let reqwest follow redirects, so a redirect loop used to
+//! surface only as a generic network error once reqwest's built-in limit ran
+//! out. The limit here is configured once per run and, when a chain exceeds
+//! it, the request fails with a [`RedirectLimitExceeded`] that names the last
+//! few hops.
+//!
+//! The download client's policy also hands back any hop that would change
+//! which [`DomainHeaders`] apply, so the client can follow it itself with the
+//! headers re-evaluated for the new URL instead of leaking them cross-host.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
+
+use reqwest::redirect::{Attempt, Policy};
+use thiserror::Error;
+use url::Url;
+
+use crate::auth::DomainHeaders;
+
+/// Default number of redirects an HTTP client follows before giving up.
+pub const DEFAULT_MAX_REDIRECTS: usize = 10;
+
+/// Trailing hops recorded when the redirect limit is exceeded.
+const REPORTED_HOPS: usize = 3;
+
+static MAX_REDIRECTS: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_REDIRECTS);
+
+/// Sets the redirect limit for HTTP clients built afterwards and for
+/// [`ResolveContext::new`](crate::resolver::ResolveContext::new).
+///
+/// Intended for CLI/runtime configuration before download clients and
+/// resolvers are constructed, like
+/// [`configure_resolver_http_timeouts`](crate::resolver::configure_resolver_http_timeouts).
+pub fn configure_max_redirects(max_redirects: usize) {
+    MAX_REDIRECTS.store(max_redirects, Ordering::Relaxed);
+}
+
+/// Returns the configured redirect limit.
+#[must_use]
+pub fn max_redirects() -> usize {
+    MAX_REDIRECTS.load(Ordering::Relaxed)
+}
+
+/// Error raised by the redirect policy once a chain exceeds its limit.
+#[derive(Debug, Error)]
+#[error("stopped after {max_redirects} redirects (last hops: {})", hops.join(" -> "))]
+pub(crate) struct RedirectLimitExceeded {
+    /// Limit that was exceeded.
+    pub(crate) max_redirects: usize,
+    /// The last few URLs of the chain, ending with the refused target.
+    pub(crate) hops: Vec<String>,
+}
+
+/// Builds a policy that follows up to `max_redirects` hops, then fails with
+/// [`RedirectLimitExceeded`].
+pub(crate) fn redirect_policy(max_redirects: usize) -> Policy {
+    Policy::custom(move |attempt| follow_within_limit(attempt, max_redirects))
+}
+
+/// Like [`redirect_policy`], but stops at hops where `scope` would attach
+/// different domain headers, returning the redirect response to the caller.
+pub(crate) fn redirect_policy_with_headers(
+    max_redirects: usize,
+    scope: RedirectHeaderScope,
+) -> Policy {
+    Policy::custom(move |attempt| {
+        let changes_headers = attempt
+            .previous()
+            .last()
+            .is_some_and(|from| scope.changes_headers(from, attempt.url()));
+        if changes_headers {
+            attempt.stop()
+        } else {
+            follow_within_limit(attempt, max_redirects)
+        }
+    })
+}
+
+fn follow_within_limit(attempt: Attempt<'_>, max_redirects: usize) -> reqwest::redirect::Action {
+    let previous = attempt.previous();
+    if previous.len() <= max_redirects {
+        return attempt.follow();
+    }
+    let hops = previous
+        .iter()
+        .chain([attempt.url()])
+        .skip((previous.len() + 1).saturating_sub(REPORTED_HOPS))
+        .map(ToString::to_string)
+        .collect();
+    attempt.error(RedirectLimitExceeded {
+        max_redirects,
+        hops,
+    })
+}
+
+/// Domain headers of an [`HttpClient`](super::HttpClient), shared with the
+/// redirect policy of the reqwest client it wraps.
+///
+/// Headers are configured after the reqwest client is built, so the policy
+/// reads them through this handle. Copies of an `HttpClient` share it.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RedirectHeaderScope(Arc<RwLock<Option<Arc<DomainHeaders>>>>);
+
+impl RedirectHeaderScope {
+    /// Replaces the headers attached to requests.
+    pub(crate) fn set(&self, headers: Option<Arc<DomainHeaders>>) {
+        *self.0.write().unwrap_or_else(PoisonError::into_inner) = headers;
+    }
+
+    /// Returns the headers attached to requests, if any are configured.
+    pub(crate) fn get(&self) -> Option<Arc<DomainHeaders>> {
+        self.0
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Returns true when a request to `to` would carry different domain
+    /// headers than one to `from`.
+    pub(crate) fn changes_headers(&self, from: &Url, to: &Url) -> bool {
+        self.get().is_some_and(|headers| {
+            headers.header_map_for_url(from) != headers.header_map_for_url(to)
+        })
+    }
+}
+
+/// Returns the [`RedirectLimitExceeded`] behind a reqwest redirect error, if any.
+pub(crate) fn redirect_limit_exceeded(error: &reqwest::Error) -> Option<&RedirectLimitExceeded> {
+    if !error.is_redirect() {
+        return None;
+    }
+    let mut source = std::error::Error::source(error);
+    while let Some(current) = source {
+        if let Some(exceeded) = current.downcast_ref::<RedirectLimitExceeded>() {
+            return Some(exceeded);
+        }
+        source = current.source();
+    }
+    None
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::test_support::socket_guard::start_mock_server_or_skip;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_redirect_policy_stops_after_limit_and_reports_last_hops() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        for hop in 0..5 {
+            Mock::given(method("GET"))
+                .and(path(format!("/hop/{hop}")))
+                .respond_with(
+                    ResponseTemplate::new(302).insert_header(
+                        "Location",
+                        format!("{}/hop/{}", mock_server.uri(), hop + 1),
+                    ),
+                )
+                .mount(&mock_server)
+                .await;
+        }
+        let client = reqwest::Client::builder()
+            .redirect(redirect_policy(2))
+            .build()
+            .unwrap();
+
+        let error = client
+            .get(format!("{}/hop/0", mock_server.uri()))
+            .send()
+            .await
+            .unwrap_err();
+
+        let exceeded = redirect_limit_exceeded(&error).unwrap();
+        assert_eq!(exceeded.max_redirects, 2);
+        let hops: Vec<_> = exceeded
+            .hops
+            .iter()
+            .map(|hop| hop.trim_start_matches(&mock_server.uri()))
+            .collect();
+        assert_eq!(hops, ["/hop/1", "/hop/2", "/hop/3"]);
+    }
+}
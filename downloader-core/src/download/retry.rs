@@ -14,6 +14,11 @@
 //! The [`RetryPolicy`] then determines whether to retry based on failure type
 //! and attempt count, calculating exponential backoff delays with jitter.
 //!
+//! Policies can override the built-in HTTP status classification
+//! ([`RetryPolicy::with_status_override`]) and cap backoff per failure type
+//! ([`RetryPolicy::with_max_delay_for`]); use [`RetryPolicy::classify`] so
+//! those overrides apply.
+//!
 //! # Example
 //!
 //! ```
@@ -35,6 +40,7 @@
 //! }
 //! ```
 
+use std::collections::HashMap;
 use std::time::Duration;
 
 use rand::Rng;
@@ -60,7 +66,7 @@ const MAX_JITTER: Duration = Duration::from_millis(500);
 /// Classification of download failure types.
 ///
 /// Used to determine whether a failed download should be retried.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum FailureType {
     /// Temporary failure that may succeed on retry.
     ///
@@ -118,6 +124,13 @@ pub enum RetryDecision {
 /// ```
 ///
 /// With defaults, delays are approximately: 1s, 2s, 4s (before hitting max attempts).
+///
+/// # Overrides
+///
+/// Status overrides replace the [`classify_error`] result for matching HTTP
+/// statuses, and per-type delay caps replace `max_delay` for that failure type.
+/// Both are empty by default, so an unconfigured policy behaves exactly like
+/// the built-in classification.
 #[derive(Debug, Clone)]
 pub struct RetryPolicy {
     /// Maximum number of attempts (including the initial attempt).
@@ -131,6 +144,12 @@ pub struct RetryPolicy {
 
     /// Multiplier applied each attempt (typically 2.0 for doubling).
     backoff_multiplier: f32,
+
+    /// HTTP status → failure type, consulted before the built-in table.
+    status_overrides: HashMap<u16, FailureType>,
+
+    /// Per-failure-type delay caps replacing `max_delay`.
+    max_delay_overrides: HashMap<FailureType, Duration>,
 }
 
 impl Default for RetryPolicy {
@@ -140,6 +159,8 @@ impl Default for RetryPolicy {
             base_delay: DEFAULT_BASE_DELAY,
             max_delay: DEFAULT_MAX_DELAY,
             backoff_multiplier: DEFAULT_BACKOFF_MULTIPLIER,
+            status_overrides: HashMap::new(),
+            max_delay_overrides: HashMap::new(),
         }
     }
 }
@@ -165,6 +186,8 @@ impl RetryPolicy {
             base_delay,
             max_delay,
             backoff_multiplier,
+            status_overrides: HashMap::new(),
+            max_delay_overrides: HashMap::new(),
         }
     }
 
@@ -177,12 +200,53 @@ impl RetryPolicy {
         }
     }
 
+    /// Classifies `status` as `failure_type`, overriding the built-in table.
+    ///
+    /// Applies to both plain HTTP status errors and the auth statuses (401,
+    /// 403, 407) that the client promotes to [`DownloadError::AuthRequired`].
+    #[must_use]
+    pub fn with_status_override(mut self, status: u16, failure_type: FailureType) -> Self {
+        self.status_overrides.insert(status, failure_type);
+        self
+    }
+
+    /// Caps the backoff delay for `failure_type` at `max_delay` instead of the
+    /// policy-wide cap.
+    #[must_use]
+    pub fn with_max_delay_for(mut self, failure_type: FailureType, max_delay: Duration) -> Self {
+        self.max_delay_overrides.insert(failure_type, max_delay);
+        self
+    }
+
     /// Returns the maximum number of attempts configured.
     #[must_use]
     pub fn max_attempts(&self) -> u32 {
         self.max_attempts
     }
 
+    /// Returns the backoff cap applied to `failure_type`.
+    #[must_use]
+    pub fn max_delay_for(&self, failure_type: FailureType) -> Duration {
+        self.max_delay_overrides
+            .get(&failure_type)
+            .copied()
+            .unwrap_or(self.max_delay)
+    }
+
+    /// Classifies `error` like [`classify_error`], honouring status overrides.
+    #[must_use]
+    pub fn classify(&self, error: &DownloadError) -> FailureType {
+        let status = match error {
+            DownloadError::HttpStatus { status, .. } => Some(*status),
+            // Status 0 marks a login redirect, not a real HTTP status.
+            DownloadError::AuthRequired { status, .. } if *status != 0 => Some(*status),
+            _ => None,
+        };
+        status
+            .and_then(|status| self.status_overrides.get(&status).copied())
+            .unwrap_or_else(|| classify_error(error))
+    }
+
     /// Determines whether to retry a failed download.
     ///
     /// # Arguments
@@ -222,7 +286,7 @@ impl RetryPolicy {
         }
 
         // Calculate delay with exponential backoff
-        let delay = self.calculate_delay(attempt);
+        let delay = self.calculate_delay(failure_type, attempt);
 
         debug!(
             attempt,
@@ -239,13 +303,13 @@ impl RetryPolicy {
 
     /// Calculates the delay for a retry attempt with exponential backoff and jitter.
     ///
-    /// Formula: `min(base_delay * multiplier^attempt, max_delay) + jitter`
+    /// Formula: `min(base_delay * multiplier^attempt, max_delay_for(failure_type)) + jitter`
     #[allow(
         clippy::cast_possible_truncation,
         clippy::cast_precision_loss,
         clippy::cast_sign_loss
     )]
-    fn calculate_delay(&self, attempt: u32) -> Duration {
+    fn calculate_delay(&self, failure_type: FailureType, attempt: u32) -> Duration {
         let base_ms = self.base_delay.as_millis() as f64;
         let multiplier = f64::from(self.backoff_multiplier);
 
@@ -254,8 +318,8 @@ impl RetryPolicy {
         let exponent = f64::from(attempt - 1);
         let delay_ms = base_ms * multiplier.powf(exponent);
 
-        // Cap at max_delay
-        let capped_ms = delay_ms.min(self.max_delay.as_millis() as f64);
+        // Cap at the failure type's max delay
+        let capped_ms = delay_ms.min(self.max_delay_for(failure_type).as_millis() as f64);
 
         // Add jitter
         let jitter = Self::calculate_jitter();
@@ -301,8 +365,15 @@ impl RetryPolicy {
 /// | Timeout | Transient | Network may recover |
 /// | Network (most) | Transient | Server may come back |
 /// | Network (TLS) | Permanent | Certificate/config issue |
+/// | FTP 530/332 | `NeedsAuth` | Login required |
+/// | FTP 4xx, connection | Transient | Server may recover |
+/// | FTP other 5xx | Permanent | Won't succeed |
 /// | IO | Permanent | Local file system issue |
-/// | InvalidUrl | Permanent | Won't succeed |
+/// | `InvalidUrl` | Permanent | Won't succeed |
+/// | `RobotsDisallowed` | Permanent | Site policy forbids the fetch |
+///
+/// This is the built-in classification; [`RetryPolicy::classify`] consults the
+/// policy's status overrides first and falls back to this function.
 #[instrument]
 pub fn classify_error(error: &DownloadError) -> FailureType {
     match error {
@@ -321,9 +392,19 @@ pub fn classify_error(error: &DownloadError) -> FailureType {
             }
         }
 
+        DownloadError::Ftp { reply_code, .. } => match reply_code {
+            Some(530 | 332) => FailureType::NeedsAuth,
+            Some(code) if *code >= 500 => FailureType::Permanent,
+            _ => FailureType::Transient,
+        },
+
         DownloadError::Io { .. }
+        | DownloadError::DiskFull { .. }
+        | DownloadError::TooManyRedirects { .. }
         | DownloadError::InvalidUrl { .. }
-        | DownloadError::Integrity { .. } => FailureType::Permanent,
+        | DownloadError::RobotsDisallowed { .. }
+        | DownloadError::Integrity { .. }
+        | DownloadError::SuspiciousContent { .. } => FailureType::Permanent,
     }
 }
 
@@ -421,7 +502,7 @@ mod tests {
     fn test_delay_calculation_first_attempt() {
         let policy = RetryPolicy::new(5, Duration::from_secs(1), Duration::from_secs(32), 2.0);
         // First attempt (attempt=1): base * 2^0 = 1s + jitter
-        let delay = policy.calculate_delay(1);
+        let delay = policy.calculate_delay(FailureType::Transient, 1);
         // Should be between 1000ms and 1500ms (base + up to 500ms jitter)
         assert!(delay >= Duration::from_secs(1));
         assert!(delay <= Duration::from_millis(1500));
@@ -431,7 +512,7 @@ mod tests {
     fn test_delay_calculation_second_attempt() {
         let policy = RetryPolicy::new(5, Duration::from_secs(1), Duration::from_secs(32), 2.0);
         // Second attempt (attempt=2): base * 2^1 = 2s + jitter
-        let delay = policy.calculate_delay(2);
+        let delay = policy.calculate_delay(FailureType::Transient, 2);
         assert!(delay >= Duration::from_secs(2));
         assert!(delay <= Duration::from_millis(2500));
     }
@@ -440,7 +521,7 @@ mod tests {
     fn test_delay_calculation_third_attempt() {
         let policy = RetryPolicy::new(5, Duration::from_secs(1), Duration::from_secs(32), 2.0);
         // Third attempt (attempt=3): base * 2^2 = 4s + jitter
-        let delay = policy.calculate_delay(3);
+        let delay = policy.calculate_delay(FailureType::Transient, 3);
         assert!(delay >= Duration::from_secs(4));
         assert!(delay <= Duration::from_millis(4500));
     }
@@ -454,11 +535,32 @@ mod tests {
             2.0,
         );
         // 6th attempt would be 1 * 2^5 = 32s, but capped at 5s
-        let delay = policy.calculate_delay(6);
+        let delay = policy.calculate_delay(FailureType::Transient, 6);
         assert!(delay >= Duration::from_secs(5));
         assert!(delay <= Duration::from_millis(5500));
     }
 
+    #[test]
+    fn test_delay_calculation_uses_per_type_max_delay() {
+        let policy = RetryPolicy::new(10, Duration::from_secs(1), Duration::from_secs(5), 2.0)
+            .with_max_delay_for(FailureType::RateLimited, Duration::from_secs(120));
+        assert_eq!(
+            policy.max_delay_for(FailureType::RateLimited),
+            Duration::from_secs(120)
+        );
+        assert_eq!(
+            policy.max_delay_for(FailureType::Transient),
+            Duration::from_secs(5)
+        );
+
+        // 1 * 2^5 = 32s: capped for transient failures, not for rate limiting
+        let transient = policy.calculate_delay(FailureType::Transient, 6);
+        assert!(transient <= Duration::from_millis(5500));
+        let rate_limited = policy.calculate_delay(FailureType::RateLimited, 6);
+        assert!(rate_limited >= Duration::from_secs(32));
+        assert!(rate_limited <= Duration::from_millis(32_500));
+    }
+
     // ==================== Jitter Tests ====================
 
     #[test]
@@ -531,6 +633,19 @@ mod tests {
         assert_eq!(classify_error(&error), FailureType::NeedsAuth);
     }
 
+    #[test]
+    fn test_classify_ftp_replies() {
+        let url = "ftp://ftp.example.org/pub/data.csv";
+        let login = DownloadError::ftp(url, Some(530), "530 Login incorrect.");
+        assert_eq!(classify_error(&login), FailureType::NeedsAuth);
+        let missing = DownloadError::ftp(url, Some(550), "550 No such file.");
+        assert_eq!(classify_error(&missing), FailureType::Permanent);
+        let busy = DownloadError::ftp(url, Some(421), "421 Too many users.");
+        assert_eq!(classify_error(&busy), FailureType::Transient);
+        let dropped = DownloadError::ftp(url, None, "connection reset");
+        assert_eq!(classify_error(&dropped), FailureType::Transient);
+    }
+
     #[test]
     fn test_classify_http_404_permanent() {
         let error = DownloadError::http_status("http://example.com", 404);
@@ -604,6 +719,36 @@ mod tests {
         assert_eq!(classify_error(&error), FailureType::Permanent);
     }
 
+    #[test]
+    fn test_policy_classify_without_overrides_matches_classify_error() {
+        let policy = RetryPolicy::default();
+        for status in [400, 404, 408, 429, 451, 500, 503, 599] {
+            let error = DownloadError::http_status("http://example.com", status);
+            assert_eq!(policy.classify(&error), classify_error(&error), "{status}");
+        }
+        let auth = DownloadError::auth_required("http://example.com", 403, "example.com");
+        assert_eq!(policy.classify(&auth), FailureType::NeedsAuth);
+    }
+
+    #[test]
+    fn test_policy_classify_applies_status_overrides() {
+        let policy = RetryPolicy::default()
+            .with_status_override(500, FailureType::Permanent)
+            .with_status_override(403, FailureType::Permanent)
+            .with_status_override(404, FailureType::Transient);
+
+        let server_error = DownloadError::http_status("http://example.com", 500);
+        assert_eq!(policy.classify(&server_error), FailureType::Permanent);
+        let forbidden = DownloadError::auth_required("http://example.com", 403, "example.com");
+        assert_eq!(policy.classify(&forbidden), FailureType::Permanent);
+        let not_found = DownloadError::http_status("http://example.com", 404);
+        assert_eq!(policy.classify(&not_found), FailureType::Transient);
+
+        // Statuses without an override keep the built-in classification
+        let unavailable = DownloadError::http_status("http://example.com", 503);
+        assert_eq!(policy.classify(&unavailable), FailureType::Transient);
+    }
+
     // ==================== Should Retry Decision Tests ====================
 
     #[test]
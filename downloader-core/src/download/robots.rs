@@ -178,10 +178,15 @@ pub enum RobotsError {
 }
 
 /// Builds the origin string (scheme + host) from a URL for robots.txt lookup.
+///
+/// Returns `None` for non-HTTP URLs (`ftp://`, `file://`), which have no robots.txt.
 #[must_use]
 pub fn origin_for_robots(url: &str) -> Option<String> {
     let parsed = url::Url::parse(url).ok()?;
     let scheme = parsed.scheme();
+    if !matches!(scheme, "http" | "https") {
+        return None;
+    }
     let host = parsed.host_str()?;
     let port = parsed.port();
     let origin = if let Some(p) = port {
@@ -192,6 +197,26 @@ pub fn origin_for_robots(url: &str) -> Option<String> {
     Some(origin)
 }
 
+/// Returns true when the URL's host is, or is a subdomain of, a domain on the
+/// `robots_allow` list, so a robots.txt disallow may be overridden for it.
+#[must_use]
+pub fn is_robots_allowlisted(url: &str, robots_allow: &[String]) -> bool {
+    let Some(host) = url::Url::parse(url)
+        .ok()
+        .and_then(|parsed| parsed.host_str().map(str::to_ascii_lowercase))
+    else {
+        return false;
+    };
+    robots_allow.iter().any(|domain| {
+        let domain = domain.trim().trim_start_matches('.').to_ascii_lowercase();
+        !domain.is_empty()
+            && (host == domain
+                || host
+                    .strip_suffix(domain.as_str())
+                    .is_some_and(|prefix| prefix.ends_with('.')))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -231,5 +256,28 @@ mod tests {
             origin_for_robots("http://localhost:8080/file"),
             Some("http://localhost:8080".to_string())
         );
+        assert_eq!(origin_for_robots("ftp://ftp.example.org/pub/a.csv"), None);
+    }
+
+    #[test]
+    fn test_is_robots_allowlisted_matches_host_and_subdomains() {
+        let allow = vec!["Repo.Example.edu".to_string()];
+        assert!(is_robots_allowlisted(
+            "https://repo.example.edu/bitstream/1.pdf",
+            &allow
+        ));
+        assert!(is_robots_allowlisted(
+            "https://files.repo.example.edu/a.pdf",
+            &allow
+        ));
+        assert!(!is_robots_allowlisted(
+            "https://badrepo.example.edu/a.pdf",
+            &allow
+        ));
+        assert!(!is_robots_allowlisted("https://example.edu/a.pdf", &allow));
+        assert!(!is_robots_allowlisted(
+            "https://repo.example.edu/a.pdf",
+            &[]
+        ));
     }
 }
@@ -0,0 +1,183 @@
+//! Parallel ranged downloads for very large files.
+//!
+//! [`download_segments`] splits a file into contiguous byte ranges, fetches
+//! them concurrently, and writes each at its offset in one preallocated
+//! `.part` file. The caller renames that file into place exactly as for a
+//! single-stream download, so readers never see a partially assembled file.
+//! A preallocated `.part` has the final length before its contents are
+//! complete, so callers must delete it on any failure rather than resume it.
+
+use std::path::Path;
+
+use futures_util::future::try_join_all;
+use reqwest::header::{CONTENT_LENGTH, CONTENT_RANGE};
+use tokio::fs::{File, OpenOptions};
+use tokio::io::AsyncSeekExt;
+use tracing::{debug, instrument};
+
+use super::client::{HttpClient, stream_to_file};
+use super::error::DownloadError;
+
+/// Upper bound on concurrent range requests for one file.
+pub const MAX_DOWNLOAD_SEGMENTS: usize = 16;
+
+/// Result of a segmented download attempt that did not fail outright.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum SegmentOutcome {
+    /// Every range arrived and the `.part` file holds the whole resource.
+    Complete,
+    /// The server answered a range request with a full or encoded body, so
+    /// the caller should fall back to a single stream.
+    RangesIgnored,
+}
+
+/// Splits `total` bytes into at most `segments` inclusive `(start, end)` ranges.
+///
+/// Earlier ranges take the remainder, so sizes differ by at most one byte.
+pub(super) fn segment_ranges(total: u64, segments: usize) -> Vec<(u64, u64)> {
+    let count = u64::try_from(segments.clamp(1, MAX_DOWNLOAD_SEGMENTS))
+        .unwrap_or(1)
+        .min(total);
+    if count == 0 {
+        return Vec::new();
+    }
+    let base = total / count;
+    let remainder = total % count;
+    let mut start = 0;
+    (0..count)
+        .map(|index| {
+            let len = base + u64::from(index < remainder);
+            let range = (start, start + len - 1);
+            start += len;
+            range
+        })
+        .collect()
+}
+
+/// Downloads `total` bytes of `url` into `part_path` using parallel range requests.
+///
+/// The file is created (or truncated) and preallocated to `total` bytes. After
+/// all ranges complete, its length is checked against `total`.
+///
+/// # Errors
+///
+/// Returns the first range request or write error, or
+/// [`DownloadError::Integrity`] when a range or the assembled file has the
+/// wrong size. Remaining range requests are cancelled on the first error.
+#[instrument(skip(client, part_path), fields(part = %part_path.display()))]
+pub(super) async fn download_segments(
+    client: &HttpClient,
+    url: &str,
+    user_agent: Option<&str>,
+    part_path: &Path,
+    total: u64,
+    segments: usize,
+) -> Result<SegmentOutcome, DownloadError> {
+    let file = File::create(part_path)
+        .await
+        .map_err(|e| DownloadError::write_failed(part_path, e))?;
+    file.set_len(total)
+        .await
+        .map_err(|e| DownloadError::write_failed(part_path, e))?;
+    drop(file);
+
+    let ranges = segment_ranges(total, segments);
+    debug!(
+        total,
+        segments = ranges.len(),
+        "starting segmented download"
+    );
+    let honored = try_join_all(
+        ranges
+            .iter()
+            .map(|&(start, end)| fetch_segment(client, url, user_agent, part_path, start, end)),
+    )
+    .await?;
+    if honored.contains(&false) {
+        return Ok(SegmentOutcome::RangesIgnored);
+    }
+
+    let assembled = tokio::fs::metadata(part_path)
+        .await
+        .map_err(|e| DownloadError::io(part_path, e))?
+        .len();
+    if assembled != total {
+        return Err(DownloadError::integrity(part_path, total, assembled));
+    }
+    Ok(SegmentOutcome::Complete)
+}
+
+/// Fetches bytes `start..=end` into `part_path` at offset `start`.
+///
+/// Returns `Ok(false)` without writing when the server ignores the range.
+async fn fetch_segment(
+    client: &HttpClient,
+    url: &str,
+    user_agent: Option<&str>,
+    part_path: &Path,
+    start: u64,
+    end: u64,
+) -> Result<bool, DownloadError> {
+    let range = format!("bytes={start}-{end}");
+    let response = client
+        .send_request("GET", url, user_agent, Some(&range))
+        .await?;
+
+    // A decoded body loses Content-Length and would not match the offsets.
+    let expected_range = format!("bytes {start}-{end}/");
+    let range_honored = response.status().as_u16() == 206
+        && response.headers().get(CONTENT_LENGTH).is_some()
+        && response
+            .headers()
+            .get(CONTENT_RANGE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.trim().starts_with(&expected_range));
+    if !range_honored {
+        debug!(
+            url,
+            range,
+            status = response.status().as_u16(),
+            "server ignored range request"
+        );
+        return Ok(false);
+    }
+
+    let mut file = OpenOptions::new()
+        .write(true)
+        .open(part_path)
+        .await
+        .map_err(|e| DownloadError::io(part_path, e))?;
+    file.seek(std::io::SeekFrom::Start(start))
+        .await
+        .map_err(|e| DownloadError::io(part_path, e))?;
+    let written = stream_to_file(&mut file, response, url, part_path).await?;
+
+    let expected = end - start + 1;
+    if written != expected {
+        return Err(DownloadError::integrity(part_path, expected, written));
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segment_ranges_cover_file_without_gaps() {
+        assert_eq!(
+            segment_ranges(10, 3),
+            vec![(0, 3), (4, 6), (7, 9)],
+            "earlier ranges take the remainder"
+        );
+        assert_eq!(segment_ranges(8, 4), vec![(0, 1), (2, 3), (4, 5), (6, 7)]);
+    }
+
+    #[test]
+    fn test_segment_ranges_clamps_segment_count() {
+        assert_eq!(segment_ranges(3, 8), vec![(0, 0), (1, 1), (2, 2)]);
+        assert_eq!(segment_ranges(100, 0), vec![(0, 99)]);
+        assert_eq!(segment_ranges(1000, 64).len(), MAX_DOWNLOAD_SEGMENTS);
+        assert!(segment_ranges(0, 4).is_empty());
+    }
+}
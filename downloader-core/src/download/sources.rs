@@ -0,0 +1,160 @@
+//! Non-HTTP download sources: `file://` copies and `ftp://` fetches.
+//!
+//! Each is compiled in through its cargo feature (`file-urls`, `ftp`), so
+//! http/https-only builds carry neither. [`is_supported_scheme`] is the
+//! capability check the parser uses to decide which URLs to accept.
+
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "file-urls")]
+use url::Url;
+
+use super::filename::{
+    resolve_unique_path, resolve_unique_path_with_suffix_start, sanitize_filename,
+};
+#[cfg(feature = "file-urls")]
+use super::{DownloadError, DownloadFileResult};
+
+/// Returns true when this build can download URLs with `scheme`.
+#[must_use]
+pub fn is_supported_scheme(scheme: &str) -> bool {
+    let scheme = scheme.to_ascii_lowercase();
+    matches!(scheme.as_str(), "http" | "https")
+        || (cfg!(feature = "ftp") && scheme == "ftp")
+        || (cfg!(feature = "file-urls") && scheme == "file")
+}
+
+/// Resolves the save path for a non-HTTP source.
+///
+/// Follows the HTTP rules: a preferred name wins with duplicate suffixes
+/// starting at `_2`, otherwise `fallback_name` gets suffixes from `_1`.
+#[cfg_attr(not(any(feature = "ftp", feature = "file-urls")), allow(dead_code))]
+pub(super) fn resolve_output_path(
+    output_dir: &Path,
+    fallback_name: &str,
+    preferred_filename: Option<&str>,
+) -> PathBuf {
+    match preferred_filename
+        .map(sanitize_filename)
+        .filter(|name| !name.is_empty())
+    {
+        Some(preferred) => resolve_unique_path_with_suffix_start(output_dir, &preferred, 2),
+        None => resolve_unique_path(output_dir, fallback_name),
+    }
+}
+
+/// Copies the local file behind a `file://` URL into `output_dir`.
+///
+/// The copy goes through a `.part` file and is renamed into place, like an
+/// HTTP download, so a failed copy never leaves a truncated file behind.
+#[cfg(feature = "file-urls")]
+pub(super) async fn copy_file_url(
+    url: &Url,
+    output_dir: &Path,
+    preferred_filename: Option<&str>,
+) -> Result<DownloadFileResult, DownloadError> {
+    let source = url
+        .to_file_path()
+        .map_err(|()| DownloadError::invalid_url(url.as_str()))?;
+    let metadata = tokio::fs::metadata(&source)
+        .await
+        .map_err(|e| DownloadError::io(source.clone(), e))?;
+    if !metadata.is_file() {
+        return Err(DownloadError::io(
+            source,
+            std::io::Error::new(std::io::ErrorKind::InvalidInput, "not a regular file"),
+        ));
+    }
+
+    let fallback_name = source.file_name().map_or_else(
+        || super::filename::fallback_filename_from_url(url),
+        |name| sanitize_filename(&name.to_string_lossy()),
+    );
+    let file_path = resolve_output_path(output_dir, &fallback_name, preferred_filename);
+    let part_path = super::client::part_path_for(&file_path);
+
+    let bytes = match tokio::fs::copy(&source, &part_path).await {
+        Ok(bytes) => bytes,
+        Err(error) => {
+            let _ = tokio::fs::remove_file(&part_path).await;
+            return Err(DownloadError::write_failed(part_path, error));
+        }
+    };
+    tokio::fs::rename(&part_path, &file_path)
+        .await
+        .map_err(|e| DownloadError::io(file_path.clone(), e))?;
+
+    tracing::info!(
+        source = %source.display(),
+        path = %file_path.display(),
+        bytes,
+        "local file copied"
+    );
+    Ok(DownloadFileResult {
+        path: file_path,
+        bytes_downloaded: bytes,
+        content_length: Some(metadata.len()),
+        resumed: false,
+        resume_attempted: false,
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_supported_scheme_follows_features() {
+        assert!(is_supported_scheme("https"));
+        assert!(is_supported_scheme("HTTP"));
+        assert_eq!(is_supported_scheme("ftp"), cfg!(feature = "ftp"));
+        assert_eq!(is_supported_scheme("file"), cfg!(feature = "file-urls"));
+        assert!(!is_supported_scheme("mailto"));
+    }
+
+    #[cfg(feature = "file-urls")]
+    #[tokio::test]
+    async fn test_file_url_is_copied_into_output_dir() {
+        let source_dir = tempfile::TempDir::new().unwrap();
+        let output_dir = tempfile::TempDir::new().unwrap();
+        let source = source_dir.path().join("legacy_scan.pdf");
+        std::fs::write(&source, b"%PDF-1.4 local").unwrap();
+        let url = Url::from_file_path(&source).unwrap();
+
+        let result = crate::download::HttpClient::new()
+            .download_to_file_with_metadata(url.as_str(), output_dir.path())
+            .await
+            .unwrap();
+
+        assert_eq!(result.path, output_dir.path().join("legacy_scan.pdf"));
+        assert_eq!(result.bytes_downloaded, 14);
+        assert_eq!(result.content_length, Some(14));
+        assert_eq!(std::fs::read(&result.path).unwrap(), b"%PDF-1.4 local");
+        assert!(source.exists(), "the source file must be left in place");
+
+        let again = copy_file_url(&url, output_dir.path(), Some("Smith_2020_Scan.pdf"))
+            .await
+            .unwrap();
+        assert_eq!(again.path, output_dir.path().join("Smith_2020_Scan.pdf"));
+    }
+
+    #[cfg(feature = "file-urls")]
+    #[tokio::test]
+    async fn test_missing_file_url_fails_without_output() {
+        let source_dir = tempfile::TempDir::new().unwrap();
+        let output_dir = tempfile::TempDir::new().unwrap();
+        let url = Url::from_file_path(source_dir.path().join("missing.pdf")).unwrap();
+
+        let error = copy_file_url(&url, output_dir.path(), None)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, DownloadError::Io { .. }));
+        assert_eq!(
+            crate::download::classify_error(&error),
+            crate::download::FailureType::Permanent
+        );
+        assert_eq!(std::fs::read_dir(output_dir.path()).unwrap().count(), 0);
+    }
+}
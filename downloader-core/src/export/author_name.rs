@@ -0,0 +1,271 @@
+//! Splits author display names into family and given parts.
+//!
+//! Metadata arrives in several shapes (`"Family, Given"`, `"Given Family"`,
+//! PubMed-style `"Family GA"`, BibTeX `and`-lists). CSL, RIS and BibTeX want
+//! structured names, so [`parse_author_name`] normalizes each shape and flags
+//! the splits it had to guess at with [`NameConfidence::Ambiguous`].
+
+/// Lowercased surname particles that start the family part (`van der Berg`).
+const PARTICLES: &[&str] = &[
+    "al", "bin", "da", "das", "de", "del", "della", "den", "der", "di", "do", "dos", "du", "el",
+    "ibn", "la", "le", "ten", "ter", "van", "von", "zu",
+];
+
+/// Generational suffixes that may follow a comma (`John Smith, Jr.`).
+const SUFFIXES: &[&str] = &["jr", "sr", "ii", "iii", "iv"];
+
+/// How sure [`parse_author_name`] is about a family/given split.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameConfidence {
+    /// The input shape determines the split.
+    Confident,
+    /// Several splits were plausible; the family part is a best guess.
+    Ambiguous,
+}
+
+/// An author name split into family and given parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorName {
+    /// Family name, including particles (`"van der Berg"`).
+    pub family: String,
+    /// Given names and initials, if any.
+    pub given: Option<String>,
+    /// Whether the split can be trusted for structured output.
+    pub confidence: NameConfidence,
+}
+
+impl AuthorName {
+    /// Returns `true` when the split is a best guess.
+    #[must_use]
+    pub fn is_ambiguous(&self) -> bool {
+        self.confidence == NameConfidence::Ambiguous
+    }
+
+    /// Formats the name as `"Family, Given"` (or just `"Family"`).
+    #[must_use]
+    pub fn citation_form(&self) -> String {
+        match &self.given {
+            Some(given) => format!("{}, {given}", self.family),
+            None => self.family.clone(),
+        }
+    }
+}
+
+/// Parses one author name into family and given parts.
+///
+/// Handles `"Family, Given"`, `"Given Family"`, `"Given M. Family"`,
+/// `"Family GA"` and surname particles (`"Jan van der Berg"`). Single-word
+/// names become a family-only name. Returns `None` for blank input.
+#[must_use]
+pub fn parse_author_name(input: &str) -> Option<AuthorName> {
+    let name = input.split_whitespace().collect::<Vec<_>>().join(" ");
+    if name.is_empty() {
+        return None;
+    }
+
+    let parts: Vec<&str> = name
+        .split(',')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect();
+    match parts.as_slice() {
+        [] => None,
+        [single] => Some(parse_given_first(single)),
+        [head, suffix] if is_suffix(suffix) => Some(AuthorName {
+            confidence: NameConfidence::Ambiguous,
+            ..parse_given_first(head)
+        }),
+        [family, given] => Some(AuthorName {
+            family: (*family).to_string(),
+            given: Some((*given).to_string()),
+            confidence: NameConfidence::Confident,
+        }),
+        // BibTeX `von Last, Jr, First`; anything longer is likely a list.
+        [family, rest @ ..] => {
+            let given = match rest {
+                [_, first] => (*first).to_string(),
+                _ => rest.join(", "),
+            };
+            Some(AuthorName {
+                family: (*family).to_string(),
+                given: Some(given),
+                confidence: NameConfidence::Ambiguous,
+            })
+        }
+    }
+}
+
+/// Parses a list of names separated by BibTeX-style `and` or by `;`.
+///
+/// Separators are matched as whole words, so names containing "and"
+/// (`"Anderson"`) are left intact. Blank entries are dropped.
+#[must_use]
+pub fn parse_author_names(input: &str) -> Vec<AuthorName> {
+    input
+        .split(';')
+        .flat_map(split_on_and)
+        .filter_map(|name| parse_author_name(&name))
+        .collect()
+}
+
+fn split_on_and(input: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    for word in input.split_whitespace() {
+        if word.eq_ignore_ascii_case("and") {
+            names.push(current.join(" "));
+            current.clear();
+        } else {
+            current.push(word);
+        }
+    }
+    names.push(current.join(" "));
+    names
+}
+
+/// Parses a name without commas (`"Given [Middle] Family"` or `"Family GA"`).
+fn parse_given_first(name: &str) -> AuthorName {
+    let tokens: Vec<&str> = name.split_whitespace().collect();
+    let confident = |family: &[&str], given: &[&str]| AuthorName {
+        family: family.join(" "),
+        given: (!given.is_empty()).then(|| given.join(" ")),
+        confidence: NameConfidence::Confident,
+    };
+
+    match tokens.as_slice() {
+        [] | [_] => confident(&tokens, &[]),
+        // PubMed style: "Smith JA".
+        [family, initials] if is_bare_initials(initials) && !is_initial(family) => {
+            confident(&[*family], &[*initials])
+        }
+        _ => {
+            let last = tokens.len() - 1;
+            if let Some(start) = (1..last).find(|&index| is_particle(tokens[index])) {
+                return confident(&tokens[start..], &tokens[..start]);
+            }
+            let (given, family) = tokens.split_at(last);
+            let mut parsed = confident(family, given);
+            if !given[1..].iter().all(|token| is_initial(token)) {
+                // "Maria Garcia Lopez": middle name or double surname.
+                parsed.confidence = NameConfidence::Ambiguous;
+            }
+            parsed
+        }
+    }
+}
+
+fn is_particle(token: &str) -> bool {
+    PARTICLES.contains(&token.to_lowercase().as_str())
+}
+
+fn is_suffix(token: &str) -> bool {
+    SUFFIXES.contains(&token.trim_end_matches('.').to_lowercase().as_str())
+}
+
+/// `"M."`, `"M"`, `"J.-P."`: a single letter per hyphen-separated segment.
+fn is_initial(token: &str) -> bool {
+    token.split('-').all(|segment| {
+        let letters = segment.trim_end_matches('.');
+        letters.chars().count() == 1 && letters.chars().all(char::is_alphabetic)
+    })
+}
+
+/// Undotted `PubMed` initials such as `"JA"`: one to three capitals.
+fn is_bare_initials(token: &str) -> bool {
+    (1..=3).contains(&token.chars().count()) && token.chars().all(char::is_uppercase)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn split(input: &str) -> (String, Option<String>, NameConfidence) {
+        let name = parse_author_name(input).unwrap();
+        (name.family, name.given, name.confidence)
+    }
+
+    fn confident(family: &str, given: Option<&str>) -> (String, Option<String>, NameConfidence) {
+        (
+            family.to_string(),
+            given.map(String::from),
+            NameConfidence::Confident,
+        )
+    }
+
+    #[test]
+    fn test_parse_author_name_family_comma_given() {
+        assert_eq!(split("Smith, John"), confident("Smith", Some("John")));
+        assert_eq!(
+            split("  Smith ,  J. R. "),
+            confident("Smith", Some("J. R."))
+        );
+    }
+
+    #[test]
+    fn test_parse_author_name_given_family_and_initials() {
+        assert_eq!(split("John Smith"), confident("Smith", Some("John")));
+        assert_eq!(split("John M. Smith"), confident("Smith", Some("John M.")));
+        assert_eq!(
+            split("J. R. R. Tolkien"),
+            confident("Tolkien", Some("J. R. R."))
+        );
+        assert_eq!(split("J.-P. Sartre"), confident("Sartre", Some("J.-P.")));
+        assert_eq!(split("Smith JA"), confident("Smith", Some("JA")));
+    }
+
+    #[test]
+    fn test_parse_author_name_keeps_particles_with_family() {
+        assert_eq!(
+            split("Jan van der Berg"),
+            confident("van der Berg", Some("Jan"))
+        );
+        assert_eq!(
+            split("Ludwig van Beethoven"),
+            confident("van Beethoven", Some("Ludwig"))
+        );
+        assert_eq!(
+            split("van der Berg, Jan"),
+            confident("van der Berg", Some("Jan"))
+        );
+        assert_eq!(
+            split("Vincent Van Gogh"),
+            confident("Van Gogh", Some("Vincent"))
+        );
+    }
+
+    #[test]
+    fn test_parse_author_name_single_word_is_family_only() {
+        assert_eq!(split("Aristotle"), confident("Aristotle", None));
+        assert_eq!(split("Consortium,"), confident("Consortium", None));
+        assert!(parse_author_name("   ").is_none());
+    }
+
+    #[test]
+    fn test_parse_author_name_flags_ambiguous_splits() {
+        let name = parse_author_name("Maria Garcia Lopez").unwrap();
+        assert!(name.is_ambiguous());
+        assert_eq!(name.family, "Lopez");
+
+        let name = parse_author_name("John Smith, Jr.").unwrap();
+        assert!(name.is_ambiguous());
+        assert_eq!(name.family, "Smith");
+
+        let name = parse_author_name("Smith, J., Doe, R.").unwrap();
+        assert!(name.is_ambiguous());
+    }
+
+    #[test]
+    fn test_parse_author_names_splits_bibtex_and_lists() {
+        let names = parse_author_names("Smith, J. and Anderson, Kim AND  Jan van der Berg");
+        let citation: Vec<String> = names.iter().map(AuthorName::citation_form).collect();
+        assert_eq!(
+            citation,
+            vec!["Smith, J.", "Anderson, Kim", "van der Berg, Jan"]
+        );
+
+        let names = parse_author_names("Doe, R.; Lee, M.");
+        assert_eq!(names.len(), 2);
+        assert!(parse_author_names(" and ").is_empty());
+    }
+}
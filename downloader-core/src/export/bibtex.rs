@@ -4,9 +4,12 @@
 //! standard BibTeX conventions. The output is importable by Zotero, Mendeley, `JabRef`,
 //! and any other reference manager that supports BibTeX.
 
+use std::collections::HashSet;
+
 use tracing::instrument;
 
-use super::sidecar_reader::SidecarEntry;
+use super::citation_key::{generate_citation_key, unique_citation_key};
+use super::sidecar_reader::{SidecarAuthor, SidecarEntry};
 
 /// Generates a BibTeX bibliography string from a slice of sidecar entries.
 ///
@@ -15,25 +18,25 @@ use super::sidecar_reader::SidecarEntry;
 ///
 /// # Citation key format
 ///
-/// `{first_author_lastname}{year}` — for example `vaswani2017`.
-/// Falls back to a sanitized path stem when either component is absent.
+/// See [`generate_citation_key`] — for example `vaswani2017attention`. Keys
+/// that repeat within `entries` get `a`, `b`, … suffixes in entry order.
 #[must_use]
 #[instrument(skip(entries), fields(entry_count = entries.len()))]
 pub fn generate_bibtex(entries: &[SidecarEntry]) -> String {
+    let mut seen = HashSet::new();
     let mut output = String::new();
     for entry in entries {
         if !output.is_empty() {
             output.push_str("\n\n");
         }
-        output.push_str(&entry_to_bibtex(entry));
+        let key = unique_citation_key(citation_key(entry), &mut seen);
+        output.push_str(&entry_to_bibtex(entry, &key));
     }
     output
 }
 
-/// Converts a single [`SidecarEntry`] to a BibTeX `@article` block.
-fn entry_to_bibtex(entry: &SidecarEntry) -> String {
-    let key = citation_key(entry);
-
+/// Converts a single [`SidecarEntry`] to a BibTeX `@article` block keyed `key`.
+fn entry_to_bibtex(entry: &SidecarEntry, key: &str) -> String {
     let mut fields = Vec::new();
 
     if let Some(title) = &entry.title {
@@ -68,46 +71,24 @@ fn entry_to_bibtex(entry: &SidecarEntry) -> String {
 
 /// Builds the BibTeX `author` field value from the authors list.
 ///
-/// Authors are joined with ` and ` as required by BibTeX convention.
-fn authors_bibtex(authors: &[super::sidecar_reader::SidecarAuthor]) -> String {
+/// Authors are joined with ` and ` as required by BibTeX convention. Structured
+/// names are written as `Family, Given` so BibTeX never has to guess the split.
+fn authors_bibtex(authors: &[SidecarAuthor]) -> String {
     authors
         .iter()
-        .map(|a| escape_bibtex(&a.name))
+        .map(|a| escape_bibtex(&a.citation_name()))
         .collect::<Vec<_>>()
         .join(" and ")
 }
 
-/// Derives a citation key from the entry metadata.
-///
-/// Format: `{first_author_lastname}{year}` (all lowercase, alphanumeric only).
-/// Falls back to the sanitized path stem if metadata is insufficient.
+/// Derives the entry's base citation key (before collision suffixes).
 fn citation_key(entry: &SidecarEntry) -> String {
-    let lastname = entry
-        .authors
-        .first()
-        .map(|a| extract_lastname(&a.name))
-        .filter(|s| !s.is_empty());
-
-    let year = entry
-        .date_published
-        .as_deref()
-        .and_then(extract_year)
-        .map(str::to_string);
-
-    match (lastname, year) {
-        (Some(ln), Some(yr)) => sanitize_key(&format!("{ln}{yr}")),
-        (Some(ln), None) => sanitize_key(&ln),
-        (None, Some(yr)) => sanitize_key(&yr),
-        (None, None) => {
-            // Fall back to path stem
-            let stem = entry
-                .path
-                .file_stem()
-                .and_then(|s| s.to_str())
-                .unwrap_or("unknown");
-            sanitize_key(stem)
-        }
-    }
+    let first_author = entry.authors.first().map(SidecarAuthor::citation_name);
+    generate_citation_key(
+        first_author.as_deref(),
+        entry.date_published.as_deref(),
+        entry.title.as_deref(),
+    )
 }
 
 /// Extracts the year portion from an ISO 8601 date or bare year string.
@@ -123,35 +104,6 @@ fn extract_year(date: &str) -> Option<&str> {
     }
 }
 
-/// Extracts the last name from an author name string.
-///
-/// Handles:
-/// - `"First Last"` → `"last"`
-/// - `"Last, First"` → `"last"`
-/// - Single-token names → returned as-is (lowercased)
-fn extract_lastname(name: &str) -> String {
-    let name = name.trim();
-    if name.contains(',') {
-        // "Last, First" form
-        let part = name.split(',').next().unwrap_or(name).trim();
-        part.to_lowercase()
-    } else {
-        // "First Last" or "First Middle Last" — take the last token
-        name.split_whitespace()
-            .next_back()
-            .unwrap_or(name)
-            .to_lowercase()
-    }
-}
-
-/// Sanitizes a string for use as a BibTeX citation key (ASCII alphanumeric + `-` + `_`).
-fn sanitize_key(s: &str) -> String {
-    s.chars()
-        .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
-        .collect::<String>()
-        .to_lowercase()
-}
-
 /// Escapes special BibTeX characters in field values.
 ///
 /// Escapes: `&`, `%`, `$`, `#`, `_`, `{`, `}`, `~`, `^`, `\`.
@@ -196,6 +148,7 @@ mod tests {
                 .iter()
                 .map(|a| SidecarAuthor {
                     name: a.to_string(),
+                    ..SidecarAuthor::default()
                 })
                 .collect(),
             date_published: year.map(String::from),
@@ -227,28 +180,6 @@ mod tests {
         assert_eq!(extract_year(""), None);
     }
 
-    // ── extract_lastname ──────────────────────────────────────────────────────
-
-    #[test]
-    fn test_extract_lastname_first_last() {
-        assert_eq!(extract_lastname("Ashish Vaswani"), "vaswani");
-    }
-
-    #[test]
-    fn test_extract_lastname_last_comma_first() {
-        assert_eq!(extract_lastname("Vaswani, Ashish"), "vaswani");
-    }
-
-    #[test]
-    fn test_extract_lastname_single_name() {
-        assert_eq!(extract_lastname("Plato"), "plato");
-    }
-
-    #[test]
-    fn test_extract_lastname_three_parts() {
-        assert_eq!(extract_lastname("Mary Ann Evans"), "evans");
-    }
-
     // ── citation_key ──────────────────────────────────────────────────────────
 
     #[test]
@@ -261,11 +192,11 @@ mod tests {
             None,
             None,
         );
-        assert_eq!(citation_key(&entry), "vaswani2017");
+        assert_eq!(citation_key(&entry), "vaswani2017attention");
     }
 
     #[test]
-    fn test_citation_key_no_authors_uses_year() {
+    fn test_citation_key_no_authors_uses_unknown_and_year() {
         let entry = make_entry(
             "/corpus/paper.json",
             Some("Title"),
@@ -274,7 +205,7 @@ mod tests {
             None,
             None,
         );
-        assert_eq!(citation_key(&entry), "2021");
+        assert_eq!(citation_key(&entry), "unknown2021title");
     }
 
     #[test]
@@ -291,9 +222,9 @@ mod tests {
     }
 
     #[test]
-    fn test_citation_key_no_metadata_uses_path_stem() {
+    fn test_citation_key_no_metadata_uses_noauthor() {
         let entry = make_entry("/corpus/my_paper.json", None, &[], None, None, None);
-        assert_eq!(citation_key(&entry), "my_paper");
+        assert_eq!(citation_key(&entry), "noauthor");
     }
 
     // ── entry_to_bibtex ───────────────────────────────────────────────────────
@@ -308,9 +239,9 @@ mod tests {
             Some("10.48550/arXiv.1706.03762"),
             Some("https://arxiv.org/abs/1706.03762"),
         );
-        let bib = entry_to_bibtex(&entry);
+        let bib = entry_to_bibtex(&entry, &citation_key(&entry));
         assert!(
-            bib.starts_with("@article{vaswani2017,"),
+            bib.starts_with("@article{vaswani2017attention,"),
             "key mismatch: {bib}"
         );
         assert!(
@@ -332,6 +263,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_bibtex_structured_authors_use_family_given_form() {
+        let mut entry = make_entry(
+            "/corpus/berg.json",
+            None,
+            &["Jan van der Berg", "Legacy Name"],
+            Some("2020"),
+            None,
+            None,
+        );
+        entry.authors[0].family_name = Some("van der Berg".to_string());
+        entry.authors[0].given_name = Some("Jan".to_string());
+
+        let bib = entry_to_bibtex(&entry, &citation_key(&entry));
+        assert!(bib.starts_with("@article{vanderberg2020,"), "{bib}");
+        assert!(
+            bib.contains("author    = {van der Berg, Jan and Legacy Name}"),
+            "{bib}"
+        );
+    }
+
     #[test]
     fn test_bibtex_partial_entry_omits_missing_fields() {
         let entry = make_entry(
@@ -342,9 +294,12 @@ mod tests {
             None,
             Some("https://example.com"),
         );
-        let bib = entry_to_bibtex(&entry);
+        let bib = entry_to_bibtex(&entry, &citation_key(&entry));
         assert!(bib.contains("title     = {Minimal Paper}"), "{bib}");
-        assert!(!bib.contains("author"), "author should be absent: {bib}");
+        assert!(
+            !bib.contains("author    ="),
+            "author should be absent: {bib}"
+        );
         assert!(!bib.contains("year"), "year should be absent: {bib}");
         assert!(!bib.contains("doi"), "doi should be absent: {bib}");
         assert!(bib.contains("url       = {https://example.com}"), "{bib}");
@@ -353,7 +308,7 @@ mod tests {
     #[test]
     fn test_bibtex_empty_entry_produces_valid_block() {
         let entry = make_entry("/corpus/empty.json", None, &[], None, None, None);
-        let bib = entry_to_bibtex(&entry);
+        let bib = entry_to_bibtex(&entry, &citation_key(&entry));
         assert!(bib.starts_with("@article{"), "{bib}");
         assert!(bib.ends_with('}'), "{bib}");
     }
@@ -368,7 +323,7 @@ mod tests {
             None,
             None,
         );
-        let bib = entry_to_bibtex(&entry);
+        let bib = entry_to_bibtex(&entry, &citation_key(&entry));
         assert!(bib.contains("Foo \\& Bar"), "{bib}");
     }
 
@@ -410,4 +365,21 @@ mod tests {
         assert!(blocks[0].contains("Paper A"), "{bib}");
         assert!(blocks[1].contains("Paper B"), "{bib}");
     }
+
+    #[test]
+    fn test_generate_bibtex_suffixes_colliding_keys() {
+        let entry = |path| {
+            make_entry(
+                path,
+                Some("Attention Is All You Need"),
+                &["Ashish Vaswani"],
+                Some("2017"),
+                None,
+                None,
+            )
+        };
+        let bib = generate_bibtex(&[entry("/corpus/a.json"), entry("/corpus/b.json")]);
+        assert!(bib.contains("@article{vaswani2017attention,"), "{bib}");
+        assert!(bib.contains("@article{vaswani2017attentiona,"), "{bib}");
+    }
 }
@@ -0,0 +1,258 @@
+//! Deterministic BibTeX citation keys shared by every bibliography-style output.
+//!
+//! Keys have the shape `{family}{year}{titleword}` (`vaswani2017attention`):
+//! ASCII-only, lowercase, and derived from metadata alone so the BibTeX export
+//! and the project `manifest.json` agree. [`unique_citation_key`] resolves
+//! collisions with `a`, `b`, … suffixes, so callers that assign keys in the
+//! same order get the same keys.
+
+use std::collections::HashSet;
+use std::hash::BuildHasher;
+
+use super::author_name::parse_author_name;
+
+/// Author part used when the first author is missing but the year is known.
+const UNKNOWN_AUTHOR: &str = "unknown";
+
+/// Key used when neither an author nor a year is known.
+const NO_AUTHOR: &str = "noauthor";
+
+/// Lowercased title words skipped when picking the key's title word.
+const TITLE_STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "by", "do", "for", "from", "how", "in", "into", "is",
+    "it", "of", "on", "or", "the", "to", "via", "what", "when", "why", "with",
+];
+
+/// Builds a citation key from the first author, year, and title.
+///
+/// `first_author` may be in any shape [`parse_author_name`] accepts; only the
+/// family name is used. `year` may be a bare year or an ISO date. The title
+/// contributes its first word that is not a stopword. Non-ASCII letters are
+/// transliterated (`Müller` → `muller`) and other characters dropped.
+///
+/// Missing parts degrade predictably: no author gives `unknown2024…`, and no
+/// author or year gives `noauthor…`. The key is never empty.
+#[must_use]
+pub fn generate_citation_key(
+    first_author: Option<&str>,
+    year: Option<&str>,
+    title: Option<&str>,
+) -> String {
+    let family = first_author
+        .and_then(parse_author_name)
+        .map(|name| key_fragment(&name.family))
+        .filter(|family| !family.is_empty());
+    let year = year.and_then(leading_year);
+    let word = title.and_then(title_word);
+
+    let mut key = match (family, year) {
+        (Some(family), Some(year)) => format!("{family}{year}"),
+        (Some(family), None) => family,
+        (None, Some(year)) => format!("{UNKNOWN_AUTHOR}{year}"),
+        (None, None) => NO_AUTHOR.to_string(),
+    };
+    if let Some(word) = word {
+        key.push_str(&word);
+    }
+    key
+}
+
+/// Returns `key`, or `key` plus the first free `a`, `b`, … `z`, `aa`, … suffix,
+/// and records the result in `seen`.
+pub fn unique_citation_key<S: BuildHasher>(key: String, seen: &mut HashSet<String, S>) -> String {
+    if seen.insert(key.clone()) {
+        return key;
+    }
+    let mut index = 0;
+    loop {
+        let candidate = format!("{key}{}", alphabetic_suffix(index));
+        if seen.insert(candidate.clone()) {
+            return candidate;
+        }
+        index += 1;
+    }
+}
+
+/// `0` → `a`, `25` → `z`, `26` → `aa`, …
+fn alphabetic_suffix(mut index: usize) -> String {
+    let mut suffix = Vec::new();
+    loop {
+        suffix.push(b'a' + u8::try_from(index % 26).unwrap_or(0));
+        if index < 26 {
+            break;
+        }
+        index = index / 26 - 1;
+    }
+    suffix.reverse();
+    String::from_utf8(suffix).unwrap_or_default()
+}
+
+/// The four-digit year at the start of `value` (`"2017"`, `"2017-06-12"`).
+fn leading_year(value: &str) -> Option<&str> {
+    let value = value.trim();
+    let year = value.get(..4)?;
+    let next_is_digit = value[4..].starts_with(|c: char| c.is_ascii_digit());
+    (year.chars().all(|c| c.is_ascii_digit()) && !next_is_digit).then_some(year)
+}
+
+/// The first title word that is not a stopword, as a key fragment.
+fn title_word(title: &str) -> Option<String> {
+    title
+        .split(|c: char| c.is_whitespace() || c == '-' || c == '/')
+        .map(key_fragment)
+        .find(|word| {
+            word.chars().any(|c| c.is_ascii_alphabetic())
+                && !TITLE_STOPWORDS.contains(&word.as_str())
+        })
+}
+
+/// Lowercases, transliterates, and keeps only ASCII letters and digits.
+fn key_fragment(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars().flat_map(char::to_lowercase) {
+        if c.is_ascii_alphanumeric() {
+            out.push(c);
+        } else if let Some(ascii) = transliterate(c) {
+            out.push_str(ascii);
+        }
+    }
+    out
+}
+
+/// ASCII spelling of common accented Latin letters (lowercase input).
+fn transliterate(c: char) -> Option<&'static str> {
+    let ascii = match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'ĉ' | 'č' => "c",
+        'ď' | 'đ' | 'ð' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => "e",
+        'ğ' | 'ģ' => "g",
+        'ì' | 'í' | 'î' | 'ï' | 'ī' | 'į' | 'ı' => "i",
+        'ķ' => "k",
+        'ĺ' | 'ļ' | 'ľ' | 'ł' => "l",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => "o",
+        'œ' => "oe",
+        'ŕ' | 'ř' => "r",
+        'ś' | 'ş' | 'š' | 'ș' => "s",
+        'ß' => "ss",
+        'ť' | 'ţ' | 'ț' => "t",
+        'þ' => "th",
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' | 'ų' => "u",
+        'ý' | 'ÿ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_citation_key_full_metadata() {
+        assert_eq!(
+            generate_citation_key(
+                Some("Ashish Vaswani"),
+                Some("2017"),
+                Some("Attention Is All You Need")
+            ),
+            "vaswani2017attention"
+        );
+    }
+
+    #[test]
+    fn test_generate_citation_key_skips_title_stopwords() {
+        assert_eq!(
+            generate_citation_key(
+                Some("Smith, J."),
+                Some("2024-03-01"),
+                Some("On the Origin of Keys")
+            ),
+            "smith2024origin"
+        );
+        assert_eq!(
+            generate_citation_key(Some("Smith"), Some("2024"), Some("The A of")),
+            "smith2024"
+        );
+    }
+
+    #[test]
+    fn test_generate_citation_key_transliterates_non_ascii() {
+        assert_eq!(
+            generate_citation_key(Some("Jürgen Müller"), Some("2019"), Some("Über Größe")),
+            "muller2019uber"
+        );
+        assert_eq!(
+            generate_citation_key(Some("Łukasz Żółć"), None, None),
+            "zolc"
+        );
+    }
+
+    #[test]
+    fn test_generate_citation_key_keeps_surname_particles() {
+        assert_eq!(
+            generate_citation_key(Some("van der Berg, Jan"), Some("2020"), None),
+            "vanderberg2020"
+        );
+        assert_eq!(
+            generate_citation_key(Some("Jan van der Berg"), Some("2020"), None),
+            "vanderberg2020"
+        );
+    }
+
+    #[test]
+    fn test_generate_citation_key_missing_metadata_placeholders() {
+        assert_eq!(
+            generate_citation_key(None, Some("2021"), Some("Title")),
+            "unknown2021title"
+        );
+        assert_eq!(
+            generate_citation_key(Some("Alice Smith"), None, None),
+            "smith"
+        );
+        assert_eq!(generate_citation_key(None, None, None), "noauthor");
+        assert_eq!(
+            generate_citation_key(Some("  "), Some("n.d."), Some("")),
+            "noauthor"
+        );
+    }
+
+    #[test]
+    fn test_generate_citation_key_rejects_non_year_prefixes() {
+        assert_eq!(generate_citation_key(None, Some("20171"), None), "noauthor");
+        assert_eq!(generate_citation_key(None, Some("17"), None), "noauthor");
+    }
+
+    #[test]
+    fn test_unique_citation_key_appends_letter_suffixes() {
+        let mut seen = HashSet::new();
+        let keys: Vec<String> = (0..3)
+            .map(|_| unique_citation_key("smith2024".to_string(), &mut seen))
+            .collect();
+        assert_eq!(keys, ["smith2024", "smith2024a", "smith2024b"]);
+    }
+
+    #[test]
+    fn test_unique_citation_key_skips_taken_suffixes() {
+        let mut seen = HashSet::from(["smith2024a".to_string()]);
+        assert_eq!(
+            unique_citation_key("smith2024".to_string(), &mut seen),
+            "smith2024"
+        );
+        assert_eq!(
+            unique_citation_key("smith2024".to_string(), &mut seen),
+            "smith2024b"
+        );
+    }
+
+    #[test]
+    fn test_alphabetic_suffix_rolls_over_after_z() {
+        assert_eq!(alphabetic_suffix(0), "a");
+        assert_eq!(alphabetic_suffix(25), "z");
+        assert_eq!(alphabetic_suffix(26), "aa");
+        assert_eq!(alphabetic_suffix(27), "ab");
+    }
+}
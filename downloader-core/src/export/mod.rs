@@ -15,12 +15,16 @@
 //! std::fs::write("bibliography.bib", bib).unwrap();
 //! ```
 
+mod author_name;
 mod bibtex;
+mod citation_key;
 mod error;
 mod ris;
 mod sidecar_reader;
 
+pub use author_name::{AuthorName, NameConfidence, parse_author_name, parse_author_names};
 pub use bibtex::generate_bibtex;
+pub use citation_key::{generate_citation_key, unique_citation_key};
 pub use error::ExportError;
 pub use ris::generate_ris;
 pub use sidecar_reader::{SidecarAuthor, SidecarEntry, SidecarIdentifier, scan_corpus};
@@ -36,7 +36,7 @@ fn entry_to_ris(entry: &SidecarEntry) -> String {
     }
 
     for author in &entry.authors {
-        lines.push(format!("AU  - {}", author.name));
+        lines.push(format!("AU  - {}", author.citation_name()));
     }
 
     if let Some(year) = entry.date_published.as_deref().and_then(extract_year) {
@@ -94,6 +94,7 @@ mod tests {
                 .iter()
                 .map(|a| SidecarAuthor {
                     name: a.to_string(),
+                    ..SidecarAuthor::default()
                 })
                 .collect(),
             date_published: year.map(String::from),
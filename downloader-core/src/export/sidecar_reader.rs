@@ -8,10 +8,29 @@ use tracing::{debug, instrument, warn};
 use super::error::ExportError;
 
 /// A Schema.org `Person` (or `Organization`) author entry.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize)]
 pub struct SidecarAuthor {
     /// Display name of the author.
     pub name: String,
+    /// Family name (`familyName`), present when the name split was unambiguous.
+    #[serde(rename = "familyName")]
+    pub family_name: Option<String>,
+    /// Given names (`givenName`).
+    #[serde(rename = "givenName")]
+    pub given_name: Option<String>,
+}
+
+impl SidecarAuthor {
+    /// Returns `"Family, Given"` when the sidecar carries a structured name,
+    /// otherwise the display name unchanged.
+    #[must_use]
+    pub fn citation_name(&self) -> String {
+        match (&self.family_name, &self.given_name) {
+            (Some(family), Some(given)) => format!("{family}, {given}"),
+            (Some(family), None) => family.clone(),
+            (None, _) => self.name.clone(),
+        }
+    }
 }
 
 /// A Schema.org `PropertyValue` identifier (e.g. a DOI).
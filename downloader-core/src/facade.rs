@@ -0,0 +1,240 @@
+//! One-call download API for embedders.
+//!
+//! [`download_one`] runs the same pipeline as the CLI for a single input:
+//! parse, resolve through the default resolver registry, enqueue, and download
+//! with [`DownloadEngine`]. The queue lives in an in-memory database that is
+//! dropped when the call returns, so callers never manage a [`Queue`].
+//!
+//! Batch runs, progress reporting, projects, and persistent history still need
+//! the granular APIs ([`parse_input`], [`ResolverRegistry`](crate::ResolverRegistry),
+//! [`Queue`], [`DownloadEngine`]).
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::cookie::Jar;
+use tracing::debug;
+
+use crate::db::{Database, DbError};
+use crate::download::{
+    DownloadEngine, EngineError, HttpClient, RateLimiter, RetryPolicy, build_preferred_filename,
+};
+use crate::parser::{InputType, extract_reference_confidence, parse_input};
+use crate::queue::{Queue, QueueError, QueueMetadata, QueueStatus};
+use crate::resolver::{
+    CrossrefConfig, ResolveContext, ResolveError, build_default_resolver_registry,
+};
+
+/// Settings for [`download_one`].
+#[derive(Debug, Clone, Default)]
+pub struct DownloadOptions {
+    /// Cookies sent by resolvers and the download client.
+    pub cookie_jar: Option<Arc<Jar>>,
+    /// Crossref polite-pool contact and optional Plus API token.
+    pub crossref: CrossrefConfig,
+    /// Resolver names to skip (see [`build_default_resolver_registry`]).
+    pub disabled_resolvers: Vec<String>,
+    /// Retry policy for the download itself.
+    pub retry_policy: RetryPolicy,
+    /// Download client to use instead of one built from `cookie_jar`, e.g.
+    /// [`HttpClient::from_client`] around the embedder's own reqwest client.
+    /// `cookie_jar` then only applies to resolvers.
+    pub http_client: Option<HttpClient>,
+}
+
+/// A finished [`download_one`] call.
+#[derive(Debug, Clone)]
+pub struct DownloadOutcome {
+    /// Where the file was saved.
+    pub path: PathBuf,
+    /// The resolved URL that was downloaded.
+    pub url: String,
+    /// Name of the resolver that produced `url`.
+    pub resolver: Option<String>,
+    /// Metadata found during resolution (see [`STANDARD_METADATA_KEYS`](crate::STANDARD_METADATA_KEYS)).
+    pub metadata: HashMap<String, String>,
+    /// Bytes written to `path`.
+    pub bytes_downloaded: u64,
+}
+
+/// Errors returned by [`download_one`].
+#[derive(Debug, thiserror::Error)]
+pub enum DownloadOneError {
+    /// The input contained nothing the parser recognizes.
+    #[error("no URL, DOI, or reference found in input")]
+    NoInput,
+    /// The input parsed into several items; use the granular APIs for batches.
+    #[error("input contains {count} items; download_one takes exactly one")]
+    MultipleInputs {
+        /// Number of parsed items.
+        count: usize,
+    },
+    /// No resolver could turn the input into a downloadable URL.
+    #[error(transparent)]
+    Resolve(#[from] ResolveError),
+    /// The output directory could not be created.
+    #[error("cannot create output directory '{path}': {source}")]
+    OutputDir {
+        /// The directory that could not be created.
+        path: PathBuf,
+        /// The underlying I/O error.
+        source: std::io::Error,
+    },
+    /// The in-memory queue database could not be opened.
+    #[error(transparent)]
+    Database(#[from] DbError),
+    /// A queue operation failed.
+    #[error(transparent)]
+    Queue(#[from] QueueError),
+    /// The download engine could not run.
+    #[error(transparent)]
+    Engine(#[from] EngineError),
+    /// The download ran and failed.
+    #[error("download of {url} failed: {message}")]
+    Download {
+        /// The resolved URL.
+        url: String,
+        /// The engine's final error message.
+        message: String,
+    },
+}
+
+/// Parses, resolves, and downloads a single input into `output_dir`.
+///
+/// `input` is anything the parser accepts as one item: a URL, DOI, `PubMed` or
+/// `arXiv` id, a free-text reference, or a single BibTeX entry.
+///
+/// # Errors
+///
+/// Returns [`DownloadOneError::NoInput`] or [`DownloadOneError::MultipleInputs`]
+/// when `input` does not parse to exactly one item,
+/// [`DownloadOneError::Resolve`] when no resolver succeeds, and
+/// [`DownloadOneError::Download`] when the download fails after retries.
+/// The remaining variants report setup failures.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::path::Path;
+/// use downloader_core::{DownloadOptions, download_one};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let outcome = download_one(
+///     "10.48550/arXiv.1706.03762",
+///     Path::new("./papers"),
+///     DownloadOptions::default(),
+/// )
+/// .await?;
+/// println!("Saved {}", outcome.path.display());
+/// # Ok(())
+/// # }
+/// ```
+#[tracing::instrument(skip(opts), fields(output_dir = %output_dir.display()))]
+pub async fn download_one(
+    input: &str,
+    output_dir: &Path,
+    opts: DownloadOptions,
+) -> Result<DownloadOutcome, DownloadOneError> {
+    let mut items = parse_input(input).items;
+    let item = match items.len() {
+        0 => return Err(DownloadOneError::NoInput),
+        1 => items.remove(0),
+        count => return Err(DownloadOneError::MultipleInputs { count }),
+    };
+
+    let registry = build_default_resolver_registry(
+        opts.cookie_jar.clone(),
+        &opts.crossref,
+        &opts.disabled_resolvers,
+    );
+    let resolver_input = if item.input_type == InputType::BibTex {
+        item.raw.as_str()
+    } else {
+        item.value.as_str()
+    };
+    let mut resolved = registry
+        .resolve_to_url(resolver_input, item.input_type, &ResolveContext::default())
+        .await?;
+    if let Some(hint) = &item.title_hint {
+        resolved
+            .metadata
+            .entry("title".to_string())
+            .or_insert_with(|| hint.clone());
+    }
+    debug!(url = %resolved.url, resolver = ?resolved.resolver, "input resolved");
+
+    tokio::fs::create_dir_all(output_dir)
+        .await
+        .map_err(|source| DownloadOneError::OutputDir {
+            path: output_dir.to_path_buf(),
+            source,
+        })?;
+
+    let reference_confidence =
+        (item.input_type == InputType::Reference).then(|| extract_reference_confidence(&item.raw));
+    let metadata = QueueMetadata {
+        suggested_filename: Some(build_preferred_filename(&resolved.url, &resolved.metadata)),
+        title: resolved.metadata.get("title").cloned(),
+        authors: resolved.metadata.get("authors").cloned(),
+        author_orcids: resolved.metadata.get("author_orcids").cloned(),
+        abstract_text: resolved.metadata.get("abstract").cloned(),
+        year: resolved.metadata.get("year").cloned(),
+        doi: resolved.metadata.get("doi").cloned(),
+        topics: None,
+        parse_confidence: reference_confidence.map(|details| details.level.to_string()),
+        parse_confidence_factors: reference_confidence
+            .and_then(|details| serde_json::to_string(&details.factors).ok()),
+        read_timeout_secs: resolved.read_timeout_secs,
+        resolver_path: resolved.resolver_path.clone(),
+        input_source_file: None,
+    };
+
+    let queue = Queue::new(Database::new_in_memory().await?);
+    let id = queue
+        .enqueue_with_metadata(
+            &resolved.url,
+            item.input_type.queue_source_type(),
+            Some(&item.raw),
+            Some(&metadata),
+        )
+        .await?;
+
+    let client = match (&opts.http_client, &opts.cookie_jar) {
+        (Some(client), _) => client.clone(),
+        (None, Some(jar)) => HttpClient::with_cookie_jar(Arc::clone(jar)),
+        (None, None) => HttpClient::new(),
+    };
+    let engine = DownloadEngine::new(
+        1,
+        opts.retry_policy,
+        Arc::new(RateLimiter::new(Duration::ZERO)),
+    )?;
+    engine.process_queue(&queue, &client, output_dir).await?;
+
+    let finished = queue.get(id).await?;
+    match finished {
+        Some(item) if item.status() == QueueStatus::Completed => {
+            let Some(path) = item.saved_path else {
+                return Err(DownloadOneError::Download {
+                    url: resolved.url,
+                    message: "engine did not record a saved path".to_string(),
+                });
+            };
+            Ok(DownloadOutcome {
+                path: PathBuf::from(path),
+                url: resolved.url,
+                resolver: resolved.resolver,
+                metadata: resolved.metadata,
+                bytes_downloaded: u64::try_from(item.bytes_downloaded).unwrap_or(0),
+            })
+        }
+        other => Err(DownloadOneError::Download {
+            url: resolved.url,
+            message: other
+                .and_then(|item| item.last_error)
+                .unwrap_or_else(|| "download did not complete".to_string()),
+        }),
+    }
+}
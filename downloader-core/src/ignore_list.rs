@@ -0,0 +1,276 @@
+//! User-maintained skip list of domains and URL patterns.
+//!
+//! Read from `.downloader/ignore` (or `--ignore-file`). Inputs matching a rule
+//! are skipped before any request is made. Unlike `robots.txt`, this is the
+//! user's own policy, e.g. a mirror that is known to be broken.
+//!
+//! One pattern per line; blank lines and `#` comments (whole-line, or after
+//! whitespace) are skipped:
+//! - `example.com` matches that host exactly.
+//! - `*.example.com` matches any subdomain of `example.com` (not the apex).
+//! - A pattern containing `/` is a glob over the whole URL, where `*` matches
+//!   any run of characters and `?` a single one. Without a `scheme://` prefix
+//!   the glob is matched against the URL with its scheme removed.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+use tracing::instrument;
+
+use crate::download::rate_limiter::extract_domain;
+
+/// File name of the ignore list inside a `.downloader/` directory.
+pub const IGNORE_FILENAME: &str = "ignore";
+
+/// Errors raised while loading an ignore list.
+#[derive(Debug, Error)]
+pub enum IgnoreListError {
+    /// The file could not be read.
+    #[error("cannot read ignore file '{path}': {source}")]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    /// A line is not a valid pattern.
+    #[error("invalid ignore pattern '{pattern}' on line {line}: {reason}")]
+    InvalidPattern {
+        line: usize,
+        pattern: String,
+        reason: &'static str,
+    },
+}
+
+#[derive(Debug, Clone)]
+enum IgnoreRule {
+    /// Exact host.
+    Host(String),
+    /// Any subdomain of the host.
+    Subdomains(String),
+    /// Glob over the full URL (with scheme) or the URL without its scheme.
+    Url { glob: String, with_scheme: bool },
+}
+
+#[derive(Debug, Clone)]
+struct IgnoreEntry {
+    pattern: String,
+    rule: IgnoreRule,
+}
+
+/// Parsed ignore list.
+#[derive(Debug, Clone, Default)]
+pub struct IgnoreList {
+    entries: Vec<IgnoreEntry>,
+}
+
+impl IgnoreList {
+    /// Parses ignore-list content.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IgnoreListError::InvalidPattern`] for host patterns that are
+    /// empty after `*.` or use wildcards anywhere but the leading `*.`.
+    pub fn parse(content: &str) -> Result<Self, IgnoreListError> {
+        let mut entries = Vec::new();
+        for (index, line) in content.lines().enumerate() {
+            let pattern = strip_comment(line).trim();
+            if pattern.is_empty() {
+                continue;
+            }
+            let invalid = |reason| IgnoreListError::InvalidPattern {
+                line: index + 1,
+                pattern: pattern.to_string(),
+                reason,
+            };
+
+            let rule = if pattern.contains('/') {
+                IgnoreRule::Url {
+                    glob: pattern.to_string(),
+                    with_scheme: pattern.contains("://"),
+                }
+            } else {
+                let host = pattern.to_ascii_lowercase();
+                let (subdomains, host) = match host.strip_prefix("*.") {
+                    Some(rest) => (true, rest.to_string()),
+                    None => (false, host),
+                };
+                if host.is_empty() {
+                    return Err(invalid("missing domain"));
+                }
+                if host.contains(['*', '?']) {
+                    return Err(invalid(
+                        "host patterns only support a leading `*.`; use a URL glob with `/` instead",
+                    ));
+                }
+                if subdomains {
+                    IgnoreRule::Subdomains(host)
+                } else {
+                    IgnoreRule::Host(host)
+                }
+            };
+            entries.push(IgnoreEntry {
+                pattern: pattern.to_string(),
+                rule,
+            });
+        }
+        Ok(Self { entries })
+    }
+
+    /// Loads and parses the ignore list at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IgnoreListError`] if the file cannot be read or holds an
+    /// invalid pattern.
+    #[instrument]
+    pub fn load(path: &Path) -> Result<Self, IgnoreListError> {
+        let content = std::fs::read_to_string(path).map_err(|source| IgnoreListError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::parse(&content)
+    }
+
+    /// Number of patterns in the list.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true when the list has no patterns.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the first pattern matching `url`, if any.
+    #[must_use]
+    pub fn matching_pattern(&self, url: &str) -> Option<&str> {
+        let host = extract_domain(url);
+        let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+        self.entries
+            .iter()
+            .find(|entry| match &entry.rule {
+                IgnoreRule::Host(pattern) => host == *pattern,
+                IgnoreRule::Subdomains(pattern) => host
+                    .strip_suffix(pattern.as_str())
+                    .is_some_and(|prefix| prefix.len() > 1 && prefix.ends_with('.')),
+                IgnoreRule::Url { glob, with_scheme } => {
+                    glob_matches(glob, if *with_scheme { url } else { without_scheme })
+                }
+            })
+            .map(|entry| entry.pattern.as_str())
+    }
+}
+
+/// Drops a `#` comment that starts the line or follows whitespace, so URL
+/// fragments (`page#section`) survive.
+fn strip_comment(line: &str) -> &str {
+    let mut previous_is_space = true;
+    for (index, c) in line.char_indices() {
+        if c == '#' && previous_is_space {
+            return &line[..index];
+        }
+        previous_is_space = c.is_whitespace();
+    }
+    line
+}
+
+/// Matches `text` against a glob where `*` is any run and `?` one character.
+fn glob_matches(glob: &str, text: &str) -> bool {
+    let pattern: Vec<char> = glob.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` and the text index it is currently absorbing up to.
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star, absorbed)) = backtrack {
+            p = star + 1;
+            t = absorbed + 1;
+            backtrack = Some((star, t));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_host_pattern_matches_exact_host_only() {
+        let list = IgnoreList::parse("mirror.example.com\n").unwrap();
+        assert_eq!(
+            list.matching_pattern("https://Mirror.Example.com/a.pdf"),
+            Some("mirror.example.com")
+        );
+        assert!(
+            list.matching_pattern("https://cdn.mirror.example.com/a.pdf")
+                .is_none()
+        );
+        assert!(list.matching_pattern("https://example.com/a.pdf").is_none());
+    }
+
+    #[test]
+    fn test_wildcard_host_pattern_matches_subdomains_not_apex() {
+        let list = IgnoreList::parse("*.example.com").unwrap();
+        assert!(list.matching_pattern("https://a.example.com/x").is_some());
+        assert!(list.matching_pattern("https://a.b.example.com/x").is_some());
+        assert!(list.matching_pattern("https://example.com/x").is_none());
+        assert!(list.matching_pattern("https://badexample.com/x").is_none());
+    }
+
+    #[test]
+    fn test_url_glob_patterns() {
+        let list = IgnoreList::parse(
+            "# broken PDFs\n\nexample.org/broken/*.pdf  # old mirror\nhttps://*.test/files/??.bin\n",
+        )
+        .unwrap();
+        assert_eq!(list.len(), 2);
+        assert_eq!(
+            list.matching_pattern("http://example.org/broken/paper.pdf"),
+            Some("example.org/broken/*.pdf")
+        );
+        assert!(
+            list.matching_pattern("https://example.org/ok/paper.pdf")
+                .is_none()
+        );
+        assert!(
+            list.matching_pattern("https://cdn.test/files/ab.bin")
+                .is_some()
+        );
+        assert!(
+            list.matching_pattern("http://cdn.test/files/ab.bin")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_invalid_host_patterns_are_rejected() {
+        let error = IgnoreList::parse("ok.com\n*.\n").unwrap_err();
+        assert!(
+            matches!(error, IgnoreListError::InvalidPattern { line: 2, .. }),
+            "{error}"
+        );
+        assert!(IgnoreList::parse("cdn*.example.com").is_err());
+    }
+
+    #[test]
+    fn test_glob_matches_backtracks() {
+        assert!(glob_matches("a*b*c", "axxbyybc"));
+        assert!(glob_matches("*", ""));
+        assert!(!glob_matches("a*b", "axxbc"));
+    }
+}
@@ -17,6 +17,8 @@
 //!
 //! - [`auth`] - Cookie/credential management
 //! - [`export`] - BibTeX/RIS bibliography export from corpus sidecar files
+//! - [`facade`] - [`download_one`], a single-call parse/resolve/download API for embedders
+//! - [`ignore_list`] - User skip list of domains and URL patterns
 
 // Clippy lints - strict for library code
 #![deny(clippy::unwrap_used)]
@@ -29,6 +31,8 @@ pub mod auth;
 pub mod db;
 pub mod download;
 pub mod export;
+pub mod facade;
+pub mod ignore_list;
 pub mod parser;
 pub mod project;
 pub mod project_registry;
@@ -42,27 +46,37 @@ pub(crate) mod user_agent;
 
 // Re-export commonly used types
 pub use auth::{
-    CaptureError, CapturedCookieFormat, CapturedCookies, CookieError, CookieLine,
-    RuntimeCookieError, StorageError, clear_persisted_cookies, load_cookies_into_jar,
-    load_persisted_cookies, load_runtime_cookie_jar, parse_captured_cookies,
-    parse_netscape_cookies, persisted_cookie_path, rotate_key, store_persisted_cookies,
+    AUTH_BEARER_ENV, BrowserCookieStore, CaptureError, CapturedCookieFormat, CapturedCookies,
+    CookieError, CookieLine, DomainHeaders, DomainHeadersError, RecordingJar, ReplayHeaders,
+    RuntimeCookieError, SetCookieLog, StorageError, clear_persisted_cookies, cookie_in_scope,
+    load_cookies_into_jar, load_persisted_cookies, load_persisted_domain_headers,
+    load_runtime_cookie_jar, load_runtime_domain_headers, parse_captured_cookies,
+    parse_har_cookies, parse_netscape_cookies, persisted_cookie_path, read_browser_cookies,
+    rotate_key, serialize_jar_to_netscape, store_persisted_cookies, store_persisted_domain_headers,
     unique_domain_count,
 };
-pub use db::{Database, DatabaseOptions};
+pub use db::{Database, DatabaseOptions, SynchronousMode};
 pub use download::{
-    DEFAULT_CONCURRENCY, DEFAULT_MAX_RETRIES, DownloadEngine, DownloadFileResult, DownloadStats,
-    EngineError, FailureType, HttpClient, QueueProcessingOptions, RateLimiter, RetryDecision,
-    RetryPolicy, RobotsCache, RobotsDecision, RobotsError, build_preferred_filename,
-    classify_error, origin_for_robots,
+    DEFAULT_CONCURRENCY, DEFAULT_MAX_REDIRECTS, DEFAULT_MAX_RETRIES, DownloadEngine,
+    DownloadFileResult, DownloadStats, DownloadStatsSummary, EngineError, FailureType, HttpClient,
+    LayoutMode, MAX_DOWNLOAD_SEGMENTS, PreflightResult, PreflightStatus, QueueProcessingOptions,
+    RateLimiter, RetryDecision, RetryPolicy, RobotsCache, RobotsDecision, RobotsError,
+    UNKNOWN_LAYOUT_BUCKET, UrlPreflight, build_preferred_filename, classify_error,
+    configure_max_redirects, is_robots_allowlisted, is_supported_scheme, layout_output_dir,
+    layout_subfolder, origin_for_robots,
 };
 pub use export::{
-    ExportError, ExportFormat, SidecarAuthor, SidecarEntry, SidecarIdentifier, generate_bibtex,
-    generate_ris, scan_corpus,
+    AuthorName, ExportError, ExportFormat, NameConfidence, SidecarAuthor, SidecarEntry,
+    SidecarIdentifier, generate_bibtex, generate_citation_key, generate_ris, parse_author_name,
+    parse_author_names, scan_corpus, unique_citation_key,
 };
+pub use facade::{DownloadOneError, DownloadOptions, DownloadOutcome, download_one};
+pub use ignore_list::{IGNORE_FILENAME, IgnoreList, IgnoreListError};
 pub use parser::{
-    Confidence, ConfidenceFactors, InputType, ParseResult, ParseTypeCounts, ParsedItem,
-    ReferenceConfidence, ReferenceMetadata, RisEntry, RisParseResult, extract_reference_confidence,
-    parse_input, parse_ris_content,
+    Confidence, ConfidenceFactors, EndnoteEntry, EndnoteParseResult, InputType, ParseResult,
+    ParseTypeCounts, ParsedItem, ReferenceConfidence, ReferenceMetadata, RisEntry, RisParseResult,
+    extract_reference_confidence, looks_like_endnote_xml, looks_like_ris, parse_endnote_xml,
+    parse_input, parse_reader, parse_ris_content,
 };
 pub use project::{
     ProjectError, escape_markdown_cell, project_history_key, resolve_project_output_dir,
@@ -72,18 +86,26 @@ pub use project_registry::{
     DownloadedRegistry, RegistryLookup, canonicalize_url, dedup_key_for, normalize_doi,
 };
 pub use queue::{
-    DownloadAttempt, DownloadAttemptQuery, DownloadAttemptStatus, DownloadErrorType,
-    DownloadSearchCandidate, DownloadSearchQuery, NewDownloadAttempt, Queue, QueueError, QueueItem,
-    QueueMetadata, QueueStatus,
+    BYTE_BUDGET_WINDOW_DAYS, CLAIM_RENEW_INTERVAL, DEFAULT_CLAIM_LEASE_TIMEOUT, DomainStats,
+    DomainStatsCollector, DownloadAttempt, DownloadAttemptQuery, DownloadAttemptStatus,
+    DownloadErrorType, DownloadSearchCandidate, DownloadSearchQuery, NewDownloadAttempt, Queue,
+    QueueError, QueueItem, QueueMetadata, QueueStatus, ThroughputSummary, UNKNOWN_STATS_HOST,
 };
 pub use resolver::{
-    AcmResolver, ArxivResolver, CrossrefResolver, DirectResolver, IeeeResolver, MdpiResolver,
-    PubMedResolver, ResolveContext, ResolveError, ResolveStep, ResolvedUrl, Resolver,
-    ResolverPriority, ResolverRegistry, STANDARD_METADATA_KEYS, ScienceDirectResolver,
-    SpringerResolver, YouTubeResolver, build_default_resolver_registry,
-    configure_resolver_http_timeouts,
+    AcmResolver, ArxivResolver, ArxivWork, CitationMetaResolver, CrossrefApiToken, CrossrefConfig,
+    CrossrefResolver, DEFAULT_CROSSREF_MAILTO, DirectResolver, HalResolver, HandleResolver,
+    IeeeResolver, JstorResolver, MdpiResolver, OpenAlexResolver, OsfResolver, PubMedResolver,
+    RESOLVER_PATH_SEPARATOR, ResolveContext, ResolveError, ResolveStep, ResolvedUrl, Resolver,
+    ResolverPriority, ResolverRegistry, ResolverTimeoutOverride, STANDARD_METADATA_KEYS,
+    ScienceDirectResolver, SemanticScholarResolver, SpringerResolver, SsrnResolver,
+    UnpaywallResolver, YouTubeResolver, ZenodoResolver, arxiv_id_from_doi,
+    build_default_resolver_registry, configure_resolver_http_timeouts,
+    configure_resolver_timeout_overrides, enable_crossref_metadata_enrichment, lookup_arxiv_works,
+};
+pub use sidecar::{
+    SidecarConfig, SidecarError, SidecarFormat, generate_sidecar, generate_sidecar_with_format,
 };
-pub use sidecar::{SidecarConfig, SidecarError, generate_sidecar};
 pub use topics::{
-    TopicExtractor, extract_keywords, load_custom_topics, match_custom_topics, normalize_topics,
+    ContentTopicExtractor, DEFAULT_CONTENT_TOPIC_PAGES, PdfTextError, TopicExtractor,
+    extract_keywords, extract_pdf_text, load_custom_topics, match_custom_topics, normalize_topics,
 };
@@ -0,0 +1,141 @@
+//! Bare arXiv identifier detection from text input.
+//!
+//! Recognizes new-style (`2301.00001`) and old-style (`hep-th/9901001`) IDs,
+//! each with an optional `vN` version suffix. A bare ID must stand alone on
+//! its line, since a lone `YYMM.NNNNN` inside prose is as likely a number as
+//! an identifier; with an explicit `arXiv:` prefix the ID is also found inside
+//! longer lines such as references. arXiv DOIs (`10.48550/arXiv.…`) are left
+//! to the DOI extractor.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use super::input::{InputType, ParsedItem};
+
+/// Normalized prefix of extracted values; `ArxivResolver` accepts `arXiv:<id>` directly.
+pub const ARXIV_ID_PREFIX: &str = "arXiv:";
+
+/// Matches an arXiv ID alone on a trimmed line.
+/// Pattern is aligned with `ArxivResolver::ARXIV_ID_RE` to ensure consistent handling.
+#[allow(clippy::expect_used)]
+static ARXIV_ID_LINE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(?:\d{4}\.\d{4,5}|[a-z\-]+(?:\.[a-z]{2})?/\d{7})(?:v\d+)?$")
+        .expect("arXiv bare ID line regex valid")
+});
+
+/// Matches an `arXiv:`-prefixed ID anywhere in a line (e.g. `arXiv:1706.03762v2`
+/// or `arXiv: 2301.01234`).  Capture group 1 is the ID without the prefix.
+#[allow(clippy::expect_used)]
+static ARXIV_PREFIX_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\barXiv:\s*((?:\d{4}\.\d{4,5}|[a-z\-]+(?:\.[a-z]{2})?/\d{7})(?:v\d+)?)\b")
+        .expect("arXiv prefix regex valid")
+});
+
+/// Extracts arXiv IDs from text input, one pass per line.
+///
+/// Each item has [`InputType::Unknown`] (like the other structured IDs), the
+/// matched text as `raw`, and `arXiv:<id>` as `value`, keeping the ID's own
+/// spelling and version suffix. Duplicates are not removed here.
+///
+/// # Examples
+///
+/// ```
+/// use downloader_core::parser::extract_arxiv_ids;
+///
+/// let items = extract_arxiv_ids("2301.00001\nVaswani et al. (2017), arXiv: 1706.03762v2.");
+/// let values: Vec<_> = items.iter().map(|item| item.value.as_str()).collect();
+/// assert_eq!(values, ["arXiv:2301.00001", "arXiv:1706.03762v2"]);
+/// ```
+#[must_use]
+pub fn extract_arxiv_ids(input: &str) -> Vec<ParsedItem> {
+    let mut items = Vec::new();
+    for line in input.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        if ARXIV_ID_LINE_RE.is_match(trimmed) {
+            items.push(arxiv_item(trimmed, trimmed));
+            continue;
+        }
+        for caps in ARXIV_PREFIX_RE.captures_iter(trimmed) {
+            if let (Some(raw), Some(id)) = (caps.get(0), caps.get(1)) {
+                items.push(arxiv_item(raw.as_str(), id.as_str()));
+            }
+        }
+    }
+    items
+}
+
+/// The arXiv DOI (`10.48550/arxiv.<id>`, lowercased as the DOI extractor
+/// normalizes it) registered for an extracted `arXiv:<id>` value.
+pub(super) fn arxiv_doi_for_value(value: &str) -> Option<String> {
+    let id = value.strip_prefix(ARXIV_ID_PREFIX)?;
+    let id = match id.rfind(['v', 'V']) {
+        Some(pos) if id[pos + 1..].bytes().all(|byte| byte.is_ascii_digit()) => &id[..pos],
+        _ => id,
+    };
+    Some(format!("10.48550/arxiv.{}", id.to_ascii_lowercase()))
+}
+
+fn arxiv_item(raw: &str, id: &str) -> ParsedItem {
+    ParsedItem::new(raw, InputType::Unknown, format!("{ARXIV_ID_PREFIX}{id}"))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn values(input: &str) -> Vec<String> {
+        extract_arxiv_ids(input)
+            .into_iter()
+            .map(|item| item.value)
+            .collect()
+    }
+
+    #[test]
+    fn test_extract_arxiv_ids_bare_new_and_old_style() {
+        assert_eq!(
+            values("2301.00001\n  1706.03762v2  \nhep-th/9901001\nmath.AG/0309136v3"),
+            [
+                "arXiv:2301.00001",
+                "arXiv:1706.03762v2",
+                "arXiv:hep-th/9901001",
+                "arXiv:math.AG/0309136v3",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_arxiv_ids_prefixed_inside_reference_line() {
+        let items = extract_arxiv_ids(
+            "Vaswani, A. et al. (2017). Attention is all you need. ARXIV:1706.03762v5, see also arXiv: hep-th/9901001",
+        );
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].raw, "ARXIV:1706.03762v5");
+        assert_eq!(items[0].value, "arXiv:1706.03762v5");
+        assert_eq!(items[1].value, "arXiv:hep-th/9901001");
+    }
+
+    #[test]
+    fn test_extract_arxiv_ids_ignores_numbers_in_prose_and_arxiv_dois() {
+        assert!(values("We measured 2301.00001 units").is_empty());
+        assert!(values("10.48550/arXiv.1706.03762").is_empty());
+        assert!(values("arXiv:2301.000012").is_empty());
+    }
+
+    #[test]
+    fn test_arxiv_doi_for_value_strips_version_and_lowercases() {
+        assert_eq!(
+            arxiv_doi_for_value("arXiv:1706.03762v2").as_deref(),
+            Some("10.48550/arxiv.1706.03762")
+        );
+        assert_eq!(
+            arxiv_doi_for_value("arXiv:hep-th/9901001").as_deref(),
+            Some("10.48550/arxiv.hep-th/9901001")
+        );
+        assert!(arxiv_doi_for_value("PMC123456").is_none());
+    }
+}
@@ -1,8 +1,10 @@
 //! BibTeX parsing helpers for supported entry types.
 
+use std::collections::HashMap;
 use std::sync::LazyLock;
 
 use regex::Regex;
+use tracing::debug;
 
 use super::doi::extract_dois;
 use super::input::ParsedItem;
@@ -59,15 +61,20 @@ impl BibtexParseResult {
 }
 
 /// Parses BibTeX entries from input text.
+///
+/// `@string` macros are collected from the whole input before any entry is
+/// read, so a field may use a macro defined further down the file. Field values
+/// are expanded through those macros, including `#` concatenation.
 #[tracing::instrument(skip(input), fields(input_len = input.len()))]
 #[must_use]
 pub fn parse_bibtex_entries(input: &str) -> BibtexParseResult {
     let mut result = BibtexParseResult::new();
     let segments = segment_entries(input);
     result.total_found = segments.len();
+    let macros = collect_string_macros(&segments);
 
     for raw_entry in &segments {
-        match parse_entry(raw_entry) {
+        match parse_entry(raw_entry, &macros) {
             EntryOutcome::Parsed(entry) => {
                 result
                     .items
@@ -200,7 +207,35 @@ fn segment_entries(input: &str) -> Vec<String> {
     entries
 }
 
-fn parse_entry(raw_entry: &str) -> EntryOutcome {
+/// Collects `@string{name = value}` definitions, keyed by lowercase name.
+///
+/// Definitions may build on earlier ones; the first definition of a name wins.
+fn collect_string_macros(segments: &[String]) -> HashMap<String, String> {
+    let mut macros = HashMap::new();
+    for segment in segments {
+        let Some((name, value_raw)) = string_definition(segment) else {
+            continue;
+        };
+        if let Some(value) = expand_bibtex_value(value_raw, &macros) {
+            macros.entry(name).or_insert(value);
+        }
+    }
+    macros
+}
+
+fn string_definition(segment: &str) -> Option<(String, &str)> {
+    let after_at = segment.trim().strip_prefix('@')?;
+    let brace_pos = after_at.find('{')?;
+    if !after_at[..brace_pos].trim().eq_ignore_ascii_case("string") {
+        return None;
+    }
+    let body = after_at[brace_pos + 1..].strip_suffix('}')?;
+    let (name, value) = body.split_once('=')?;
+    let name = name.trim().to_ascii_lowercase();
+    (!name.is_empty()).then_some((name, value))
+}
+
+fn parse_entry(raw_entry: &str, macros: &HashMap<String, String>) -> EntryOutcome {
     let trimmed = raw_entry.trim();
     let Some(at_pos) = trimmed.find('@') else {
         return EntryOutcome::Skip(
@@ -250,7 +285,7 @@ fn parse_entry(raw_entry: &str) -> EntryOutcome {
         ));
     }
 
-    let fields = match parse_fields(fields_raw) {
+    let fields = match parse_fields(fields_raw, macros) {
         Ok(fields) => fields,
         Err(reason) => {
             return EntryOutcome::Skip(format!(
@@ -285,7 +320,10 @@ fn parse_entry(raw_entry: &str) -> EntryOutcome {
     })
 }
 
-fn parse_fields(input: &str) -> Result<std::collections::HashMap<String, String>, String> {
+fn parse_fields(
+    input: &str,
+    macros: &HashMap<String, String>,
+) -> Result<HashMap<String, String>, String> {
     let mut pairs = Vec::new();
     let mut current = String::new();
     let mut depth = 0usize;
@@ -340,7 +378,7 @@ fn parse_fields(input: &str) -> Result<std::collections::HashMap<String, String>
         pairs.push(tail.to_string());
     }
 
-    let mut fields = std::collections::HashMap::new();
+    let mut fields = HashMap::new();
     for pair in pairs {
         let Some((name, value_raw)) = pair.split_once('=') else {
             return Err(format!("missing '=' in field segment `{pair}`"));
@@ -349,7 +387,7 @@ fn parse_fields(input: &str) -> Result<std::collections::HashMap<String, String>
         if field_name.is_empty() {
             return Err("empty field name".to_string());
         }
-        let value = strip_bibtex_value(value_raw.trim())
+        let value = expand_bibtex_value(value_raw, macros)
             .ok_or_else(|| format!("invalid value in field `{field_name}`"))?;
         // First-value-wins per standard BibTeX convention.
         fields.entry(field_name).or_insert(value);
@@ -358,21 +396,74 @@ fn parse_fields(input: &str) -> Result<std::collections::HashMap<String, String>
     Ok(fields)
 }
 
-fn strip_bibtex_value(value: &str) -> Option<String> {
+/// Expands a raw field value: strips delimiters, substitutes `@string` macros,
+/// and joins `#`-concatenated parts.
+fn expand_bibtex_value(value: &str, macros: &HashMap<String, String>) -> Option<String> {
     let trimmed = value.trim().trim_end_matches(',').trim();
     if trimmed.is_empty() {
         return None;
     }
 
-    if trimmed.starts_with('{') && trimmed.ends_with('}') && trimmed.len() >= 2 {
-        return Some(trimmed[1..trimmed.len() - 1].trim().to_string());
+    let mut expanded = String::new();
+    for part in split_concatenation(trimmed) {
+        expanded.push_str(&expand_value_part(part, macros)?);
+    }
+    Some(expanded.trim().to_string())
+}
+
+/// Splits a value on `#` operators that sit outside braces and quotes.
+fn split_concatenation(value: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut in_quotes = false;
+    let mut escape = false;
+    let mut start = 0usize;
+
+    for (index, ch) in value.char_indices() {
+        if escape {
+            escape = false;
+            continue;
+        }
+        match ch {
+            '\\' => escape = true,
+            '"' if depth == 0 => in_quotes = !in_quotes,
+            '{' if !in_quotes => depth += 1,
+            '}' if !in_quotes => depth = depth.saturating_sub(1),
+            '#' if depth == 0 && !in_quotes => {
+                parts.push(&value[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&value[start..]);
+    parts
+}
+
+fn expand_value_part(part: &str, macros: &HashMap<String, String>) -> Option<String> {
+    let part = part.trim();
+    if part.is_empty() {
+        return None;
+    }
+
+    if part.starts_with('{') && part.ends_with('}') && part.len() >= 2 {
+        return Some(part[1..part.len() - 1].to_string());
     }
-    if trimmed.starts_with('"') && trimmed.ends_with('"') && trimmed.len() >= 2 {
-        let inner = &trimmed[1..trimmed.len() - 1];
-        return Some(inner.replace("\\\"", "\"").trim().to_string());
+    if part.starts_with('"') && part.ends_with('"') && part.len() >= 2 {
+        return Some(part[1..part.len() - 1].replace("\\\"", "\""));
+    }
+    if part.chars().all(|ch| ch.is_ascii_digit()) {
+        return Some(part.to_string());
+    }
+    if let Some(expansion) = macros.get(&part.to_ascii_lowercase()) {
+        return Some(expansion.clone());
     }
 
-    Some(trimmed.to_string())
+    debug!(
+        name = part,
+        "Undefined BibTeX @string macro left unexpanded"
+    );
+    Some(part.to_string())
 }
 
 fn normalize_doi_field(value: &str) -> Option<String> {
@@ -515,6 +606,67 @@ mod tests {
         assert!(result.skipped.is_empty());
     }
 
+    #[test]
+    fn test_parse_bibtex_string_macro_defined_before_use_expands_with_concatenation() {
+        let input = r#"
+@string{ieee = "IEEE Transactions"}
+@article{k, title={A}, journal = ieee # " on Software Engineering", author={Smith, J.}, year={2024}}
+"#;
+        let macros = collect_string_macros(&segment_entries(input));
+        let fields = parse_fields(
+            r#"journal = ieee # " on Software Engineering", year = {2024}"#,
+            &macros,
+        )
+        .unwrap();
+        assert_eq!(
+            fields.get("journal").map(String::as_str),
+            Some("IEEE Transactions on Software Engineering")
+        );
+        let result = parse_bibtex_entries(input);
+        assert_eq!(result.entries.len(), 1);
+        assert!(result.skipped.is_empty());
+    }
+
+    #[test]
+    fn test_parse_bibtex_string_macro_forward_reference_resolves() {
+        let input = r"
+@article{k, title = prefix # {: A Survey}, author = {Smith, J.}, year = {2024}}
+@STRING{prefix = {Deep Learning}}
+";
+        let result = parse_bibtex_entries(input);
+        assert_eq!(result.entries.len(), 1);
+        assert_eq!(
+            result.entries[0].title.as_deref(),
+            Some("Deep Learning: A Survey")
+        );
+    }
+
+    #[test]
+    fn test_parse_bibtex_string_macro_can_build_on_earlier_macro() {
+        let input = r#"
+@string{ieee = "IEEE"}
+@string{tse = ieee # " Transactions on Software Engineering"}
+@article{k, title = tse, year = 2020}
+"#;
+        let result = parse_bibtex_entries(input);
+        assert_eq!(
+            result.entries[0].title.as_deref(),
+            Some("IEEE Transactions on Software Engineering")
+        );
+        assert_eq!(result.entries[0].year, Some(2020));
+    }
+
+    #[test]
+    fn test_parse_bibtex_undefined_macro_left_as_is() {
+        let input = r#"@article{k, title = unknownmacro # " Title", year = {2024}}"#;
+        let result = parse_bibtex_entries(input);
+        assert_eq!(result.entries.len(), 1);
+        assert_eq!(
+            result.entries[0].title.as_deref(),
+            Some("unknownmacro Title")
+        );
+    }
+
     #[test]
     fn test_parse_bibtex_unsupported_type_is_skipped_with_message() {
         let input = r#"@misc{k, title={A}, year={2024}}"#;
@@ -7,7 +7,6 @@ use tracing::{debug, trace};
 
 use super::error::ParseError;
 use super::input::ParsedItem;
-use super::url::clean_url_trailing;
 
 /// Regex pattern for bare DOIs: `10.XXXX/suffix`
 /// Handles nested registrants like `10.1000.10/example`.
@@ -50,29 +49,34 @@ pub type DoiExtractionResult = Result<ParsedItem, ParseError>;
 /// - `Ok(ParsedItem)` - A valid DOI was extracted and normalized
 /// - `Err(ParseError)` - The DOI was invalid (malformed, missing suffix, etc.)
 ///
+/// DOIs are ASCII case-insensitive, so each item's `value` is the lowercased
+/// canonical form (`10.1234/ABC` and `10.1234/abc` compare equal); `raw` keeps
+/// the text as written. Results follow the order the DOIs appear in `input`.
+///
 /// # Examples
 ///
 /// ```
 /// use downloader_core::parser::extract_dois;
 ///
-/// let results = extract_dois("See DOI: 10.1234/example for details");
+/// let results = extract_dois("See DOI: 10.1234/Example for details");
 /// assert_eq!(results.len(), 1);
-/// assert!(results[0].is_ok());
+/// assert_eq!(results[0].as_ref().unwrap().value, "10.1234/example");
 /// ```
 #[tracing::instrument(skip(input), fields(input_len = input.len()))]
 #[must_use]
 pub fn extract_dois(input: &str) -> Vec<DoiExtractionResult> {
-    let mut results = Vec::new();
+    // (start offset, raw match, DOI part) per candidate, processed in input order.
+    let mut candidates: Vec<(usize, &str, &str)> = Vec::new();
     let mut seen_ranges: Vec<(usize, usize)> = Vec::new();
 
     // Extract DOI URLs first (most specific pattern)
     for cap in DOI_URL_PATTERN.captures_iter(input) {
         if let Some(full_match) = cap.get(0) {
             let raw = full_match.as_str();
-            let doi_part = &cap[1];
+            let doi_part = cap.get(1).map_or(raw, |m| m.as_str());
             seen_ranges.push((full_match.start(), full_match.end()));
             trace!(raw = %raw, "found DOI URL candidate");
-            process_doi(raw, doi_part, &mut results);
+            candidates.push((full_match.start(), raw, doi_part));
         }
     }
 
@@ -83,10 +87,10 @@ pub fn extract_dois(input: &str) -> Vec<DoiExtractionResult> {
                 continue;
             }
             let raw = full_match.as_str();
-            let doi_part = &cap[1];
+            let doi_part = cap.get(1).map_or(raw, |m| m.as_str());
             seen_ranges.push((full_match.start(), full_match.end()));
             trace!(raw = %raw, "found DOI prefix candidate");
-            process_doi(raw, doi_part, &mut results);
+            candidates.push((full_match.start(), raw, doi_part));
         }
     }
 
@@ -110,9 +114,14 @@ pub fn extract_dois(input: &str) -> Vec<DoiExtractionResult> {
         let raw = m.as_str();
         seen_ranges.push((m.start(), m.end()));
         trace!(raw = %raw, "found bare DOI candidate");
-        process_doi(raw, raw, &mut results);
+        candidates.push((m.start(), raw, raw));
     }
 
+    candidates.sort_by_key(|&(start, _, _)| start);
+    let mut results = Vec::new();
+    for (_, raw, doi_part) in candidates {
+        process_doi(raw, doi_part, &mut results);
+    }
     results
 }
 
@@ -127,21 +136,21 @@ fn appears_inside_url_token(input: &str, start: usize) -> bool {
 }
 
 /// Check if a range overlaps with any already-seen range.
-fn overlaps(seen: &[(usize, usize)], start: usize, end: usize) -> bool {
+pub(super) fn overlaps(seen: &[(usize, usize)], start: usize, end: usize) -> bool {
     seen.iter().any(|&(s, e)| start < e && end > s)
 }
 
 /// Process a DOI candidate through normalize → clean → validate pipeline.
 fn process_doi(raw: &str, doi_part: &str, results: &mut Vec<DoiExtractionResult>) {
     let normalized = normalize_doi(doi_part);
-    let cleaned = clean_url_trailing(&normalized);
-    let cleaned = clean_doi_parens(cleaned);
-    let cleaned = clean_doi_braces(&cleaned);
+    let cleaned = clean_doi_braces(clean_doi_trailing(&normalized));
+    let cleaned = clean_doi_trailing(&cleaned);
 
-    match validate_doi(&cleaned) {
+    match validate_doi(cleaned) {
         Ok(validated) => {
-            debug!(doi = %validated, "DOI validated");
-            results.push(Ok(ParsedItem::doi(raw, validated)));
+            let canonical = validated.to_ascii_lowercase();
+            debug!(doi = %canonical, "DOI validated");
+            results.push(Ok(ParsedItem::doi(raw, canonical)));
         }
         Err(e) => {
             debug!(doi = %cleaned, error = %e, "DOI validation failed");
@@ -218,37 +227,51 @@ fn validate_doi(doi: &str) -> Result<String, ParseError> {
         ));
     }
 
-    // Suffix must be non-empty
+    // Suffix must be non-empty and not punctuation alone
     let suffix = &doi[slash_pos + 1..];
-    if suffix.is_empty() {
+    if !suffix.chars().any(char::is_alphanumeric) {
         return Err(ParseError::doi_no_suffix(doi));
     }
 
     Ok(doi.to_string())
 }
 
-/// Cleans unmatched trailing parentheses from DOI suffix.
+/// Strips sentence punctuation that prose attaches to the end of a DOI.
 ///
-/// DOIs can contain parentheses in their suffix (e.g., `10.1002/(SICI)1097-4636`),
-/// but are often wrapped in parentheses in text (e.g., `(10.1234/example)`).
-/// This strips trailing `)` only if unmatched.
-fn clean_doi_parens(doi: &str) -> String {
-    let mut result = doi.to_string();
-
-    // Find suffix (after first /)
-    if let Some(slash_pos) = result.find('/') {
-        // Strip trailing ) while unbalanced
-        while result.ends_with(')') && {
-            let s = &result[slash_pos + 1..];
-            s.chars().filter(|&c| c == ')').count() > s.chars().filter(|&c| c == '(').count()
-        } {
-            result.pop();
+/// DOI suffixes may contain dots, semicolons, and balanced brackets
+/// (e.g. `10.1002/(SICI)1097-4636(199601)30:1<...>3.0.CO;2-#`), so a trailing
+/// character is removed only when it leaves the suffix unbalanced or ends it
+/// in sentence punctuation, and only while the shortened DOI still validates.
+/// `(see 10.1234/abc).` therefore yields `10.1234/abc`, while
+/// `10.1002/(SICI)1097-4636` keeps its parentheses.
+fn clean_doi_trailing(doi: &str) -> &str {
+    let mut result = doi;
+    while let Some(stripped) = strip_sentence_tail(result) {
+        if validate_doi(stripped).is_err() {
+            break;
         }
+        result = stripped;
     }
-
     result
 }
 
+/// Returns `doi` without its last character if that character cannot end a DOI.
+fn strip_sentence_tail(doi: &str) -> Option<&str> {
+    let (_, suffix) = doi.split_once('/')?;
+    let last = suffix.chars().last()?;
+    let unmatched = |open: char| {
+        suffix.chars().filter(|&c| c == last).count()
+            > suffix.chars().filter(|&c| c == open).count()
+    };
+    let strip = match last {
+        '.' | ',' | ';' | ':' | '!' | '?' => true,
+        ')' => unmatched('('),
+        ']' => unmatched('['),
+        _ => false,
+    };
+    strip.then(|| &doi[..doi.len() - last.len_utf8()])
+}
+
 /// Cleans unmatched trailing braces from DOI suffix.
 fn clean_doi_braces(doi: &str) -> String {
     let mut result = doi.to_string();
@@ -447,12 +470,66 @@ mod tests {
         assert_eq!(item.value, "10.1234/example");
     }
 
+    #[test]
+    fn test_extract_dois_parenthesized_at_sentence_end() {
+        let results = extract_dois("Prior work (see 10.1234/abc). Later work agrees.");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].as_ref().unwrap().value, "10.1234/abc");
+    }
+
+    #[test]
+    fn test_extract_dois_sentence_end_keeps_internal_dots() {
+        let results = extract_dois("The protocol is described in 10.1016/j.cell.2024.01.001.");
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].as_ref().unwrap().value,
+            "10.1016/j.cell.2024.01.001"
+        );
+    }
+
+    #[test]
+    fn test_extract_dois_semicolon_separated_reference_list() {
+        let results = extract_dois("Smith 2020, 10.1234/abc; Doe 2021, 10.5678/def.v2;");
+        let values: Vec<_> = results
+            .iter()
+            .map(|r| r.as_ref().unwrap().value.as_str())
+            .collect();
+        assert_eq!(values, vec!["10.1234/abc", "10.5678/def.v2"]);
+    }
+
+    #[test]
+    fn test_extract_dois_sentence_end_keeps_balanced_parens_and_internal_semicolon() {
+        let results = extract_dois("cited as 10.1002/(SICI)1097-4636(199601)30:1;2-K.");
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].as_ref().unwrap().value,
+            "10.1002/(sici)1097-4636(199601)30:1;2-k"
+        );
+    }
+
+    #[test]
+    fn test_extract_dois_punctuation_only_suffix_rejected() {
+        let results = extract_dois("10.1234/.");
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_err());
+    }
+
     #[test]
     fn test_extract_dois_parens_in_suffix_preserved() {
         let results = extract_dois("10.1002/(SICI)1097-4636");
         assert_eq!(results.len(), 1);
         let item = results[0].as_ref().unwrap();
-        assert_eq!(item.value, "10.1002/(SICI)1097-4636");
+        assert_eq!(item.value, "10.1002/(sici)1097-4636");
+        assert_eq!(item.raw, "10.1002/(SICI)1097-4636");
+    }
+
+    #[test]
+    fn test_extract_dois_lowercases_value_and_keeps_raw() {
+        let results = extract_dois("https://doi.org/10.1234/ABC.Def");
+        assert_eq!(results.len(), 1);
+        let item = results[0].as_ref().unwrap();
+        assert_eq!(item.value, "10.1234/abc.def");
+        assert_eq!(item.raw, "https://doi.org/10.1234/ABC.Def");
     }
 
     #[test]
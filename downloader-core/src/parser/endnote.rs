@@ -0,0 +1,561 @@
+//! `EndNote` XML export parser.
+//!
+//! `EndNote`'s "XML" export wraps every reference in `<records><record>…</record></records>`
+//! and wraps most leaf values in `<style>` elements carrying font information.
+//! Only the handful of elements the download pipeline needs are read, so this
+//! is a small tag scanner rather than a general XML parser.
+//!
+//! # Supported elements
+//!
+//! | Element                              | Field   |
+//! |--------------------------------------|---------|
+//! | `<titles><title>`                    | Title   |
+//! | `<contributors><authors><author>`    | Authors |
+//! | `<dates><year>`                      | Year    |
+//! | `<electronic-resource-num>`          | DOI     |
+//! | `<urls>…<url>`                       | URLs    |
+//!
+//! # Example
+//!
+//! ```
+//! use downloader_core::parser::parse_endnote_xml;
+//!
+//! let xml = r#"<?xml version="1.0"?><xml><records><record>
+//!   <titles><title><style>A Title</style></title></titles>
+//!   <electronic-resource-num><style>10.1234/example</style></electronic-resource-num>
+//! </record></records></xml>"#;
+//! let result = parse_endnote_xml(xml);
+//! assert_eq!(result.entries.len(), 1);
+//! assert_eq!(result.entries[0].doi.as_deref(), Some("10.1234/example"));
+//! ```
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+use tracing::{debug, warn};
+
+use super::doi::extract_dois;
+use super::input::{InputType, ParsedItem};
+
+#[allow(clippy::expect_used)]
+static YEAR_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b(?:18|19|20)\d{2}\b").expect("endnote year regex is valid"));
+
+/// A parsed `EndNote` XML record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndnoteEntry {
+    /// Extracted DOI, normalized to bare format when present.
+    pub doi: Option<String>,
+    /// URLs from `<urls>` in document order (related, then PDF, as exported).
+    pub urls: Vec<String>,
+    /// Title from `<titles><title>` when present.
+    pub title: Option<String>,
+    /// Semicolon-joined authors from `<contributors><authors>` when present.
+    pub authors: Option<String>,
+    /// Four-digit year from `<dates><year>` when present.
+    pub year: Option<u16>,
+    /// Raw `<record>…</record>` text for this entry.
+    pub raw: String,
+}
+
+/// Batch parse result for `EndNote` XML input.
+#[derive(Debug, Clone, Default)]
+pub struct EndnoteParseResult {
+    /// Structured parsed entries.
+    pub entries: Vec<EndnoteEntry>,
+    /// Items mapped for the download pipeline.
+    pub items: Vec<ParsedItem>,
+    /// Actionable skip / error messages (What/Why/Fix format).
+    pub skipped: Vec<String>,
+    /// Total `<record>` elements found before validation.
+    pub total_found: usize,
+}
+
+impl EndnoteParseResult {
+    /// Creates a new empty result.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Returns true when `input` looks like an `EndNote` XML export.
+///
+/// The input must open with `<?xml`, `<xml>`, or `<records>` (after an
+/// optional BOM and whitespace) and contain a `<records>` element, so that
+/// unrelated XML pasted into stdin still goes through the line-based parsers.
+#[must_use]
+pub fn looks_like_endnote_xml(input: &str) -> bool {
+    has_endnote_xml_prolog(input) && input.contains("<records>")
+}
+
+/// Returns true when `input` opens like an `EndNote` XML export (see [`looks_like_endnote_xml`]).
+pub(crate) fn has_endnote_xml_prolog(input: &str) -> bool {
+    let head = input.trim_start_matches('\u{feff}').trim_start();
+    head.starts_with("<?xml") || head.starts_with("<xml>") || head.starts_with("<records>")
+}
+
+/// Parses an `EndNote` XML export and extracts entries with DOIs, URLs, and metadata.
+///
+/// Empty input returns an empty result. Records that are not well-formed
+/// (unterminated `<record>`, mismatched tags) or carry no usable identifier
+/// produce a skip message in `result.skipped`; parsing never panics.
+///
+/// DOI takes priority over URL: when both are present, the entry yields a DOI
+/// item. The first URL item is only emitted when no DOI is available.
+#[tracing::instrument(skip(input), fields(input_len = input.len()))]
+#[must_use]
+pub fn parse_endnote_xml(input: &str) -> EndnoteParseResult {
+    let mut result = EndnoteParseResult::new();
+
+    if input.trim().is_empty() {
+        debug!("Empty EndNote XML input");
+        return result;
+    }
+
+    let (segments, unterminated) = segment_records(input);
+    result.total_found = segments.len() + usize::from(unterminated);
+
+    if segments.is_empty() && !unterminated {
+        result.skipped.push(
+            "What: EndNote XML contained no <record> elements. \
+             Why: the export may be empty or not an EndNote XML file. \
+             Fix: re-export from EndNote via File > Export with 'XML' as the file type."
+                .to_string(),
+        );
+    }
+
+    for raw in segments {
+        match parse_record(raw) {
+            RecordOutcome::Parsed(entry) => emit_items_for_entry(&mut result, entry),
+            RecordOutcome::Skip(message) => {
+                warn!(message = %message, "Skipped EndNote record");
+                result.skipped.push(message);
+            }
+        }
+    }
+
+    if unterminated {
+        let message = "What: EndNote XML ends inside an unterminated <record>. \
+                       Why: the file is truncated or malformed. \
+                       Fix: re-export the library, or check the file was copied completely."
+            .to_string();
+        warn!(message = %message, "Skipped EndNote record");
+        result.skipped.push(message);
+    }
+
+    debug!(
+        total_found = result.total_found,
+        entries = result.entries.len(),
+        items = result.items.len(),
+        skipped = result.skipped.len(),
+        "EndNote XML parsing complete"
+    );
+
+    result
+}
+
+// ---------------------------------------------------------------------------
+// Internal helpers
+// ---------------------------------------------------------------------------
+
+#[derive(Debug)]
+enum RecordOutcome {
+    Parsed(EndnoteEntry),
+    Skip(String),
+}
+
+/// Splits input into `<record>…</record>` slices; the flag reports a trailing
+/// record that never closes.
+fn segment_records(input: &str) -> (Vec<&str>, bool) {
+    let mut segments = Vec::new();
+    let mut rest = input;
+    while let Some(start) = find_open_tag(rest, "record") {
+        let from_start = &rest[start..];
+        let Some(end) = from_start.find("</record>") else {
+            return (segments, true);
+        };
+        let end = end + "</record>".len();
+        segments.push(&from_start[..end]);
+        rest = &from_start[end..];
+    }
+    (segments, false)
+}
+
+fn parse_record(raw: &str) -> RecordOutcome {
+    if let Err(reason) = check_balanced(raw) {
+        return RecordOutcome::Skip(format!(
+            "What: malformed EndNote XML record ({reason}). \
+             Why: the export is corrupted or was hand-edited. \
+             Fix: re-export the record from EndNote, or supply its DOI/URL directly."
+        ));
+    }
+
+    let title = first_element(raw, "titles")
+        .and_then(|titles| first_element(titles, "title"))
+        .map(text_content)
+        .filter(|value| !value.is_empty());
+
+    let authors: Vec<String> = first_element(raw, "contributors")
+        .and_then(|contributors| first_element(contributors, "authors"))
+        .map(|authors| {
+            all_elements(authors, "author")
+                .into_iter()
+                .map(text_content)
+                .filter(|value| !value.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let year = first_element(raw, "dates")
+        .and_then(|dates| first_element(dates, "year"))
+        .map(text_content)
+        .and_then(|value| normalize_year(&value));
+
+    let doi = first_element(raw, "electronic-resource-num")
+        .map(text_content)
+        .and_then(|value| normalize_doi(&value));
+
+    let urls: Vec<String> = first_element(raw, "urls")
+        .map(|urls| {
+            all_elements(urls, "url")
+                .into_iter()
+                .map(text_content)
+                .filter(|value| value.starts_with("http://") || value.starts_with("https://"))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if doi.is_none() && urls.is_empty() && title.is_none() {
+        return RecordOutcome::Skip(
+            "What: EndNote record has no DOI, URL, or title. \
+             Why: there is nothing to resolve a download from. \
+             Fix: add an electronic resource number (DOI) or URL to the record in EndNote."
+                .to_string(),
+        );
+    }
+
+    RecordOutcome::Parsed(EndnoteEntry {
+        doi,
+        urls,
+        title,
+        authors: (!authors.is_empty()).then(|| authors.join("; ")),
+        year,
+        raw: raw.to_string(),
+    })
+}
+
+/// Emits `ParsedItem`s for the entry into the result, following the DOI-over-URL priority rule.
+fn emit_items_for_entry(result: &mut EndnoteParseResult, entry: EndnoteEntry) {
+    if let Some(ref doi) = entry.doi {
+        result.items.push(ParsedItem::new(
+            entry.raw.clone(),
+            InputType::Doi,
+            doi.clone(),
+        ));
+    } else if let Some(url) = entry.urls.first() {
+        result.items.push(ParsedItem::new(
+            entry.raw.clone(),
+            InputType::Url,
+            url.clone(),
+        ));
+    }
+
+    if let Some(reference) = build_reference_value(&entry) {
+        result
+            .items
+            .push(ParsedItem::reference(entry.raw.clone(), reference));
+    }
+
+    result.entries.push(entry);
+}
+
+/// Returns the byte offset of the first `<name>` or `<name …>` open tag.
+fn find_open_tag(haystack: &str, name: &str) -> Option<usize> {
+    let needle = format!("<{name}");
+    let mut offset = 0;
+    while let Some(found) = haystack[offset..].find(&needle) {
+        let start = offset + found;
+        let after = start + needle.len();
+        match haystack[after..].chars().next() {
+            Some('>' | ' ' | '\t' | '\r' | '\n' | '/') => return Some(start),
+            _ => offset = after,
+        }
+    }
+    None
+}
+
+/// Returns the inner text of every non-nested `<name>…</name>` element.
+fn all_elements<'a>(haystack: &'a str, name: &str) -> Vec<&'a str> {
+    let close = format!("</{name}>");
+    let mut found = Vec::new();
+    let mut rest = haystack;
+    while let Some(start) = find_open_tag(rest, name) {
+        let from_start = &rest[start..];
+        let Some(open_end) = from_start.find('>') else {
+            break;
+        };
+        if from_start[..open_end].ends_with('/') {
+            rest = &from_start[open_end + 1..];
+            continue;
+        }
+        let body = &from_start[open_end + 1..];
+        let Some(close_at) = body.find(&close) else {
+            break;
+        };
+        found.push(&body[..close_at]);
+        rest = &body[close_at + close.len()..];
+    }
+    found
+}
+
+fn first_element<'a>(haystack: &'a str, name: &str) -> Option<&'a str> {
+    all_elements(haystack, name).into_iter().next()
+}
+
+/// Checks that every open tag in the record is closed in order.
+fn check_balanced(raw: &str) -> Result<(), String> {
+    let mut stack: Vec<&str> = Vec::new();
+    let mut rest = raw;
+    while let Some(lt) = rest.find('<') {
+        let Some(gt) = rest[lt..].find('>') else {
+            return Err("unclosed '<'".to_string());
+        };
+        let tag = &rest[lt + 1..lt + gt];
+        rest = &rest[lt + gt + 1..];
+
+        if tag.starts_with('?') || tag.starts_with('!') || tag.ends_with('/') {
+            continue;
+        }
+        if let Some(name) = tag.strip_prefix('/') {
+            let name = name.trim();
+            match stack.pop() {
+                Some(open) if open == name => {}
+                Some(open) => return Err(format!("</{name}> closes <{open}>")),
+                None => return Err(format!("unexpected </{name}>")),
+            }
+        } else {
+            let name = tag.split_whitespace().next().unwrap_or_default();
+            if name.is_empty() {
+                return Err("empty tag".to_string());
+            }
+            stack.push(name);
+        }
+    }
+    match stack.last() {
+        Some(open) => Err(format!("<{open}> is never closed")),
+        None => Ok(()),
+    }
+}
+
+/// Strips nested markup (e.g. `<style>`), decodes entities, and collapses whitespace.
+fn text_content(fragment: &str) -> String {
+    let mut text = String::with_capacity(fragment.len());
+    let mut in_tag = false;
+    for ch in fragment.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    decode_entities(&text)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        let after = &rest[amp..];
+        let decoded = after.find(';').and_then(|semi| {
+            let entity = &after[1..semi];
+            let ch = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                    .and_then(char::from_u32),
+            };
+            ch.map(|c| (c, semi + 1))
+        });
+        if let Some((ch, consumed)) = decoded {
+            out.push(ch);
+            rest = &after[consumed..];
+        } else {
+            out.push('&');
+            rest = &after[1..];
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn normalize_doi(value: &str) -> Option<String> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+    extract_dois(trimmed)
+        .into_iter()
+        .find_map(|r| r.ok().map(|item| item.value))
+}
+
+fn normalize_year(value: &str) -> Option<u16> {
+    YEAR_PATTERN
+        .find(value)
+        .and_then(|m| m.as_str().parse::<u16>().ok())
+}
+
+fn build_reference_value(entry: &EndnoteEntry) -> Option<String> {
+    let mut parts = Vec::new();
+
+    if let Some(ref authors) = entry.authors {
+        parts.push(authors.clone());
+    }
+    if let Some(year) = entry.year {
+        parts.push(format!("({year})"));
+    }
+    if let Some(ref title) = entry.title {
+        if title.ends_with('.') {
+            parts.push(title.clone());
+        } else {
+            parts.push(format!("{title}."));
+        }
+    }
+
+    if parts.is_empty() {
+        return None;
+    }
+    Some(parts.join(" "))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"<?xml version="1.0" encoding="UTF-8" ?><xml><records>
+<record><database name="Lib.enl" path="Lib.enl">Lib.enl</database>
+<ref-type name="Journal Article">17</ref-type>
+<contributors><authors>
+<author><style face="normal" font="default" size="100%">Smith, John</style></author>
+<author><style face="normal" font="default" size="100%">Doe, Jane</style></author>
+</authors></contributors>
+<titles><title><style face="normal" font="default" size="100%">Deep &amp; Wide Networks</style></title>
+<secondary-title><style face="normal" font="default" size="100%">Nature</style></secondary-title></titles>
+<dates><year><style face="normal" font="default" size="100%">2021</style></year></dates>
+<electronic-resource-num><style face="normal" font="default" size="100%">https://doi.org/10.1038/s41586-021-00001-x</style></electronic-resource-num>
+<urls><related-urls><url><style face="normal" font="default" size="100%">https://www.nature.com/articles/s41586-021-00001-x</style></url></related-urls></urls>
+</record>
+<record><ref-type name="Web Page">12</ref-type>
+<titles><title>Only a URL</title></titles>
+<urls><pdf-urls><url>https://example.com/a.pdf</url></pdf-urls></urls>
+</record>
+</records></xml>"#;
+
+    #[test]
+    fn test_endnote_sniff_accepts_export_headers() {
+        assert!(looks_like_endnote_xml(SAMPLE));
+        assert!(looks_like_endnote_xml("<xml><records></records></xml>"));
+        assert!(looks_like_endnote_xml(
+            "\u{feff}  <records><record/></records>"
+        ));
+    }
+
+    #[test]
+    fn test_endnote_sniff_rejects_other_input() {
+        assert!(!looks_like_endnote_xml("https://example.com/<records>"));
+        assert!(!looks_like_endnote_xml(
+            "<?xml version=\"1.0\"?><feed></feed>"
+        ));
+        assert!(!looks_like_endnote_xml("10.1234/abc"));
+    }
+
+    #[test]
+    fn test_parse_endnote_maps_record_fields() {
+        let result = parse_endnote_xml(SAMPLE);
+        assert_eq!(result.total_found, 2);
+        assert!(result.skipped.is_empty(), "{:?}", result.skipped);
+
+        let entry = &result.entries[0];
+        assert_eq!(entry.title.as_deref(), Some("Deep & Wide Networks"));
+        assert_eq!(entry.authors.as_deref(), Some("Smith, John; Doe, Jane"));
+        assert_eq!(entry.year, Some(2021));
+        assert_eq!(entry.doi.as_deref(), Some("10.1038/s41586-021-00001-x"));
+        assert_eq!(
+            entry.urls,
+            vec!["https://www.nature.com/articles/s41586-021-00001-x"]
+        );
+    }
+
+    #[test]
+    fn test_parse_endnote_prefers_doi_then_url() {
+        let result = parse_endnote_xml(SAMPLE);
+        let primary: Vec<_> = result
+            .items
+            .iter()
+            .filter(|item| item.input_type != InputType::Reference)
+            .map(|item| (item.input_type, item.value.as_str()))
+            .collect();
+        assert_eq!(
+            primary,
+            vec![
+                (InputType::Doi, "10.1038/s41586-021-00001-x"),
+                (InputType::Url, "https://example.com/a.pdf"),
+            ]
+        );
+        assert!(
+            result
+                .items
+                .iter()
+                .any(|item| item.input_type == InputType::Reference
+                    && item.value == "Smith, John; Doe, Jane (2021) Deep & Wide Networks.")
+        );
+    }
+
+    #[test]
+    fn test_parse_endnote_mismatched_tags_are_skipped_not_panicking() {
+        let xml = "<xml><records><record><titles><title>Broken</titles></title></record>\
+                   <record><electronic-resource-num>10.1234/ok</electronic-resource-num></record>\
+                   </records></xml>";
+        let result = parse_endnote_xml(xml);
+        assert_eq!(result.entries.len(), 1);
+        assert_eq!(result.entries[0].doi.as_deref(), Some("10.1234/ok"));
+        assert_eq!(result.skipped.len(), 1);
+        assert!(result.skipped[0].contains("malformed EndNote XML record"));
+    }
+
+    #[test]
+    fn test_parse_endnote_unterminated_record_is_skipped() {
+        let result = parse_endnote_xml("<xml><records><record><titles><title>Cut");
+        assert!(result.entries.is_empty());
+        assert_eq!(result.total_found, 1);
+        assert!(result.skipped[0].contains("unterminated <record>"));
+    }
+
+    #[test]
+    fn test_parse_endnote_without_records_reports_skip() {
+        let result = parse_endnote_xml("<?xml version=\"1.0\"?><xml><records></records></xml>");
+        assert_eq!(result.total_found, 0);
+        assert!(result.skipped[0].contains("no <record> elements"));
+    }
+
+    #[test]
+    fn test_decode_entities_handles_numeric_and_unknown() {
+        assert_eq!(
+            decode_entities("caf&#233; &#x2014; &nbsp;"),
+            "café — &nbsp;"
+        );
+    }
+}
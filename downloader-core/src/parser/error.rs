@@ -50,6 +50,17 @@ pub enum ParseError {
         suggestion: String,
     },
 
+    /// ISBN has the wrong length or a failing check digit
+    #[error("invalid ISBN '{isbn}': {reason}\n  Suggestion: {suggestion}")]
+    InvalidIsbn {
+        /// The ISBN as written
+        isbn: String,
+        /// Why the ISBN is invalid
+        reason: String,
+        /// How to fix the issue
+        suggestion: String,
+    },
+
     /// Reference-like line could not be parsed into useful metadata
     #[error("could not parse reference '{reference}': {reason}\n  Suggestion: {suggestion}")]
     UnparseableReference {
@@ -123,6 +134,17 @@ impl ParseError {
         }
     }
 
+    /// Creates an `InvalidIsbn` error for a malformed ISBN.
+    #[must_use]
+    pub fn invalid_isbn(isbn: &str, reason: &str) -> Self {
+        Self::InvalidIsbn {
+            isbn: isbn.to_string(),
+            reason: reason.to_string(),
+            suggestion: "Copy the ISBN again; it needs 10 or 13 digits with a matching check digit"
+                .to_string(),
+        }
+    }
+
     /// Creates an `UnparseableReference` error for reference-like lines.
     #[must_use]
     pub fn unparseable_reference(reference: &str) -> Self {
@@ -203,6 +225,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_error_invalid_isbn_message() {
+        let err = ParseError::invalid_isbn("978-3-16-148410-1", "check digit should be 0");
+        let msg = err.to_string();
+        assert!(msg.contains("978-3-16-148410-1"), "should contain ISBN");
+        assert!(msg.contains("check digit"), "should contain reason");
+        assert!(
+            msg.contains("10 or 13 digits"),
+            "suggestion should mention length"
+        );
+    }
+
     #[test]
     fn test_parse_error_clone() {
         let err = ParseError::malformed("bad-url", "parse error");
@@ -31,6 +31,20 @@ impl InputType {
             Self::BibTex => "bibtex",
         }
     }
+
+    /// Returns the default queue priority for items of this type.
+    ///
+    /// DOIs resolve to canonical publisher copies, so they are dequeued before
+    /// structured IDs and BibTeX entries, which in turn go before raw URLs and
+    /// free-text references.
+    #[must_use]
+    pub fn default_queue_priority(self) -> i64 {
+        match self {
+            Self::Doi => 20,
+            Self::BibTex | Self::Unknown => 10,
+            Self::Url | Self::Reference => 0,
+        }
+    }
 }
 
 /// Per-type counts for parsed input items.
@@ -77,6 +91,10 @@ pub struct ParsedItem {
     pub input_type: InputType,
     /// Extracted/normalized value (e.g., validated URL)
     pub value: String,
+    /// Title suggested by the input itself (e.g., Markdown or HTML link text)
+    pub title_hint: Option<String>,
+    /// Input file the item was read from (`stdin` for `-`), if any
+    pub source_file: Option<String>,
 }
 
 impl ParsedItem {
@@ -87,9 +105,25 @@ impl ParsedItem {
             raw: raw.into(),
             input_type,
             value: value.into(),
+            title_hint: None,
+            source_file: None,
         }
     }
 
+    /// Attaches a title hint taken from the surrounding input.
+    #[must_use]
+    pub fn with_title_hint(mut self, title_hint: impl Into<String>) -> Self {
+        self.title_hint = Some(title_hint.into());
+        self
+    }
+
+    /// Records the input file the item was read from.
+    #[must_use]
+    pub fn with_source_file(mut self, source_file: impl Into<String>) -> Self {
+        self.source_file = Some(source_file.into());
+        self
+    }
+
     /// Creates a URL item.
     #[must_use]
     pub fn url(raw: impl Into<String>, normalized: impl Into<String>) -> Self {
@@ -121,6 +155,10 @@ impl fmt::Display for ParsedItem {
     }
 }
 
+/// Start of the `skipped` message for a fragment that looks like a URL or DOI
+/// but failed to parse; see [`ParseResult::likely_malformed_count`].
+pub(crate) const LIKELY_MALFORMED_PREFIX: &str = "What: likely-malformed input";
+
 /// Collection of parsed items from input.
 #[derive(Debug, Default)]
 pub struct ParseResult {
@@ -165,6 +203,16 @@ impl ParseResult {
         self.skipped.len()
     }
 
+    /// Returns how many skipped entries looked like intended URLs or DOIs
+    /// (e.g. `htps://...`) rather than plain text.
+    #[must_use]
+    pub fn likely_malformed_count(&self) -> usize {
+        self.skipped
+            .iter()
+            .filter(|line| line.starts_with(LIKELY_MALFORMED_PREFIX))
+            .count()
+    }
+
     /// Returns an iterator over URL items only.
     pub fn urls(&self) -> impl Iterator<Item = &ParsedItem> {
         self.items
@@ -253,6 +301,18 @@ mod tests {
         assert_eq!(InputType::Unknown.queue_source_type(), "reference");
     }
 
+    #[test]
+    fn test_input_type_default_queue_priority_puts_dois_before_urls() {
+        assert!(InputType::Doi.default_queue_priority() > InputType::Url.default_queue_priority());
+        assert!(
+            InputType::Unknown.default_queue_priority() > InputType::Url.default_queue_priority()
+        );
+        assert_eq!(
+            InputType::Reference.default_queue_priority(),
+            InputType::Url.default_queue_priority()
+        );
+    }
+
     #[test]
     fn test_parsed_item_url() {
         let item = ParsedItem::url("http://example.com", "http://example.com/");
@@ -295,6 +355,16 @@ mod tests {
         assert!(result.skipped.contains(&"some text".to_string()));
     }
 
+    #[test]
+    fn test_parse_result_likely_malformed_count_matches_prefix_only() {
+        let mut result = ParseResult::new();
+        result.add_skipped("not a url");
+        result.add_skipped(format!("{LIKELY_MALFORMED_PREFIX} `htps://x`."));
+
+        assert_eq!(result.skipped_count(), 2);
+        assert_eq!(result.likely_malformed_count(), 1);
+    }
+
     #[test]
     fn test_parse_result_urls_iterator() {
         let mut result = ParseResult::new();
@@ -0,0 +1,272 @@
+//! ISBN detection, validation, and normalization from text input.
+//!
+//! ISBNs are recognized after an `ISBN` label (`ISBN 978-3-16-148410-0`,
+//! `ISBN-10: 0-306-40615-2`) anywhere in a line, and without a label only
+//! when a line holds nothing but a `978`/`979` ISBN-13. Unlabelled digit runs
+//! inside prose are never taken as ISBNs. No resolver handles books yet; the
+//! items carry a normalized ISBN-13 so one can be added without re-parsing.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+use tracing::debug;
+
+use super::error::ParseError;
+use super::input::{InputType, ParsedItem};
+
+/// Normalized prefix of extracted ISBNs (`isbn:9783161484100`).
+pub const ISBN_PREFIX: &str = "isbn:";
+
+/// Matches an `ISBN` label, optionally `ISBN-10`/`ISBN-13` and a colon.  The
+/// number itself is read by [`scan_isbn`], which knows where an ISBN ends.
+#[allow(clippy::expect_used)]
+static ISBN_LABEL_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\bISBN(?:-1[03])?:?\s*").expect("ISBN label regex is valid") // Static pattern, safe to panic
+});
+
+/// Extracts and validates ISBNs from text input.
+///
+/// Each result is either an [`InputType::Unknown`] item whose `value` is the
+/// ISBN-13 as `isbn:<13 digits>` (ISBN-10s are converted; hyphens and spaces
+/// dropped) and whose `raw` keeps the text as written, label included, or a
+/// [`ParseError::InvalidIsbn`] for a wrong length or failing check digit.
+/// Duplicates are not removed here.
+///
+/// # Examples
+///
+/// ```
+/// use downloader_core::parser::extract_isbns;
+///
+/// let results = extract_isbns("Knuth, D. (1997). Fundamental Algorithms. ISBN 0-201-89683-4.");
+/// assert_eq!(results.len(), 1);
+/// let item = results[0].as_ref().unwrap();
+/// assert_eq!(item.raw, "ISBN 0-201-89683-4");
+/// assert_eq!(item.value, "isbn:9780201896831");
+/// ```
+#[tracing::instrument(skip(input), fields(input_len = input.len()))]
+#[must_use]
+pub fn extract_isbns(input: &str) -> Vec<Result<ParsedItem, ParseError>> {
+    let mut results = Vec::new();
+    for line in input.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        let mut labelled = false;
+        for label in ISBN_LABEL_RE.find_iter(trimmed) {
+            let Some(scan) = scan_isbn(&trimmed[label.end()..]) else {
+                continue;
+            };
+            labelled = true;
+            let raw = &trimmed[label.start()..label.end() + scan.len];
+            results.push(isbn_result(raw, scan.outcome));
+        }
+
+        if !labelled
+            && (trimmed.starts_with("978") || trimmed.starts_with("979"))
+            && let Some(scan) = scan_isbn(trimmed)
+            && scan.len == trimmed.len()
+        {
+            results.push(isbn_result(trimmed, scan.outcome));
+        }
+    }
+    results
+}
+
+/// An ISBN read from the start of some text.
+struct IsbnScan {
+    /// Bytes of the text the ISBN spans, separators included.
+    len: usize,
+    /// The normalized ISBN-13, or why the digits are not a valid ISBN.
+    outcome: Result<String, String>,
+}
+
+/// Reads up to 13 ISBN digits (and a final `X` of an ISBN-10), allowing
+/// hyphens and single spaces between them.
+///
+/// A valid ISBN-13 wins; otherwise the first ten digits are tried as an
+/// ISBN-10, so `ISBN 0-306-40615-2 1998` stops before the year. Returns
+/// `None` when no digit follows.
+fn scan_isbn(text: &str) -> Option<IsbnScan> {
+    let mut digits = String::with_capacity(13);
+    let mut end = 0;
+    let mut ten: Option<(usize, String)> = None;
+    let mut previous_space = false;
+    for (index, ch) in text.char_indices() {
+        match ch {
+            '0'..='9' => digits.push(ch),
+            'X' | 'x' if digits.len() == 9 => digits.push('X'),
+            '-' if !digits.is_empty() => {
+                previous_space = false;
+                continue;
+            }
+            ' ' if !digits.is_empty() && !previous_space => {
+                previous_space = true;
+                continue;
+            }
+            _ => break,
+        }
+        previous_space = false;
+        end = index + ch.len_utf8();
+        if digits.len() == 10 {
+            ten = Some((end, digits.clone()));
+        }
+        if digits.len() == 13 || digits.ends_with('X') {
+            break;
+        }
+    }
+    if digits.is_empty() {
+        return None;
+    }
+
+    if digits.len() == 13 {
+        let expected = isbn13_check_digit(&digits[..12]);
+        if expected.is_some_and(|check| digits.ends_with(check)) {
+            return Some(IsbnScan {
+                len: end,
+                outcome: Ok(digits),
+            });
+        }
+        if digits.starts_with("978") || digits.starts_with("979") {
+            return Some(IsbnScan {
+                len: end,
+                outcome: Err(check_digit_reason(&digits, expected)),
+            });
+        }
+    }
+    if let Some((ten_end, ten_digits)) = ten {
+        let outcome = if isbn10_is_valid(&ten_digits) {
+            isbn13_check_digit(&format!("978{}", &ten_digits[..9]))
+                .map(|check| format!("978{}{check}", &ten_digits[..9]))
+                .ok_or_else(|| "not a valid ISBN-10".to_string())
+        } else {
+            Err(check_digit_reason(
+                &ten_digits,
+                isbn10_check_digit(&ten_digits[..9]),
+            ))
+        };
+        return Some(IsbnScan {
+            len: ten_end,
+            outcome,
+        });
+    }
+    Some(IsbnScan {
+        len: end,
+        outcome: Err(format!("has {} digits; an ISBN has 10 or 13", digits.len())),
+    })
+}
+
+fn isbn_result(raw: &str, outcome: Result<String, String>) -> Result<ParsedItem, ParseError> {
+    match outcome {
+        Ok(isbn13) => Ok(ParsedItem::new(
+            raw,
+            InputType::Unknown,
+            format!("{ISBN_PREFIX}{isbn13}"),
+        )),
+        Err(reason) => {
+            debug!(isbn = raw, reason = %reason, "Invalid ISBN");
+            Err(ParseError::invalid_isbn(raw, &reason))
+        }
+    }
+}
+
+fn check_digit_reason(digits: &str, expected: Option<char>) -> String {
+    match expected {
+        Some(expected) => format!("check digit should be {expected}"),
+        None => format!("'{digits}' is not a valid ISBN"),
+    }
+}
+
+/// Check digit for the first 12 digits of an ISBN-13.
+fn isbn13_check_digit(first_twelve: &str) -> Option<char> {
+    let mut sum = 0;
+    for (index, ch) in first_twelve.chars().enumerate() {
+        let digit = ch.to_digit(10)?;
+        sum += if index % 2 == 0 { digit } else { digit * 3 };
+    }
+    char::from_digit((10 - sum % 10) % 10, 10)
+}
+
+/// Check digit (`0`-`9` or `X`) for the first 9 digits of an ISBN-10.
+fn isbn10_check_digit(first_nine: &str) -> Option<char> {
+    let mut sum = 0;
+    for (ch, weight) in first_nine.chars().zip((2..=10).rev()) {
+        sum += ch.to_digit(10)? * weight;
+    }
+    match (11 - sum % 11) % 11 {
+        10 => Some('X'),
+        check => char::from_digit(check, 10),
+    }
+}
+
+fn isbn10_is_valid(digits: &str) -> bool {
+    digits.len() == 10 && isbn10_check_digit(&digits[..9]).is_some_and(|c| digits.ends_with(c))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn values(input: &str) -> Vec<String> {
+        extract_isbns(input)
+            .into_iter()
+            .map(|result| result.unwrap().value)
+            .collect()
+    }
+
+    #[test]
+    fn test_extract_isbns_labelled_isbn13_and_isbn10() {
+        assert_eq!(
+            values("ISBN 978-3-16-148410-0\nISBN-10: 0-306-40615-2\nisbn:080442957X"),
+            [
+                "isbn:9783161484100",
+                "isbn:9780306406157",
+                "isbn:9780804429573",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_isbns_keeps_raw_and_stops_before_trailing_numbers() {
+        let results = extract_isbns(
+            "Stevens, W. (1990). UNIX Network Programming. Prentice Hall. ISBN 0 13 949876 1 1990 edition",
+        );
+        assert_eq!(results.len(), 1);
+        let item = results[0].as_ref().unwrap();
+        assert_eq!(item.raw, "ISBN 0 13 949876 1");
+        assert_eq!(item.value, "isbn:9780139498763");
+    }
+
+    #[test]
+    fn test_extract_isbns_bare_isbn13_only_alone_on_a_line() {
+        assert_eq!(values("9783161484100"), ["isbn:9783161484100"]);
+        assert!(extract_isbns("Sold 9783161484100 copies").is_empty());
+        assert!(
+            extract_isbns("0306406152").is_empty(),
+            "bare ISBN-10s are not taken"
+        );
+    }
+
+    #[test]
+    fn test_extract_isbns_rejects_bad_check_digits_and_lengths() {
+        let results = extract_isbns("ISBN 978-3-16-148410-1\nISBN 0-306-40615-3\nISBN 12345");
+        assert_eq!(results.len(), 3);
+        let messages: Vec<String> = results
+            .into_iter()
+            .map(|result| result.unwrap_err().to_string())
+            .collect();
+        assert!(
+            messages[0].contains("check digit should be 0"),
+            "{}",
+            messages[0]
+        );
+        assert!(
+            messages[1].contains("check digit should be 2"),
+            "{}",
+            messages[1]
+        );
+        assert!(messages[2].contains("has 5 digits"), "{}", messages[2]);
+    }
+}
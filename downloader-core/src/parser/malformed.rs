@@ -0,0 +1,136 @@
+//! Detection of fragments that look like intended URLs or DOIs but failed to parse.
+//!
+//! Extractors only report candidates their patterns match, so a typo such as
+//! `htps://` or `10.1234-abc` would otherwise vanish with the surrounding prose.
+//! [`likely_malformed_fragments`] scans residual text for such near-misses so
+//! they can be surfaced in [`ParseResult::skipped`](super::ParseResult::skipped).
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+use tracing::debug;
+
+use super::BibtexBlockTracker;
+use super::doi::extract_dois;
+use super::input::LIKELY_MALFORMED_PREFIX;
+use super::url::extract_urls;
+
+/// A DOI-shaped prefix: `10.` followed by a registrant code of three or more digits.
+#[allow(clippy::expect_used)]
+static DOI_LIKE_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^10\.\d{3,}").expect("DOI-like regex is valid") // Static pattern, safe to panic
+});
+
+/// Hosts that only appear in text as part of a link to a paper.
+const KNOWN_SCHOLARLY_HOSTS: &[&str] = &[
+    "doi.org/",
+    "arxiv.org/",
+    "ncbi.nlm.nih.gov/",
+    "hdl.handle.net/",
+    "hal.science/",
+    "zenodo.org/",
+    "osf.io/",
+    "ssrn.com/",
+    "dl.acm.org/",
+    "sciencedirect.com/",
+    "springer.com/",
+    "wiley.com/",
+];
+
+/// Characters trimmed from token edges before classification.
+const TOKEN_EDGE_PUNCTUATION: &[char] = &[
+    '(', ')', '[', ']', '{', '}', '<', '>', '"', '\'', ',', ';', '.', ':',
+];
+
+/// Returns a What/Why/Fix message for each token in `residual` that looks like
+/// a URL or DOI but was not extracted.
+///
+/// `residual` is the text left after valid URLs and DOIs were stripped.
+/// BibTeX entries are skipped (the BibTeX parser reports its own errors), and
+/// tokens that the URL or DOI extractors recognise at all are left alone:
+/// invalid ones are already reported by those extractors.
+pub(super) fn likely_malformed_fragments(residual: &str) -> Vec<String> {
+    let mut messages = Vec::new();
+    let mut tracker = BibtexBlockTracker::default();
+
+    for line in residual.lines() {
+        let line = line.trim();
+        if line.is_empty() || tracker.observe(line) {
+            continue;
+        }
+        for token in line.split_whitespace() {
+            let token = token.trim_matches(TOKEN_EDGE_PUNCTUATION);
+            if looks_like_intended_link(token) && !is_handled_by_extractors(token) {
+                debug!(fragment = %token, "likely-malformed URL/DOI fragment");
+                messages.push(malformed_message(token));
+            }
+        }
+    }
+
+    messages
+}
+
+fn looks_like_intended_link(token: &str) -> bool {
+    if token.is_empty() {
+        return false;
+    }
+    let lower = token.to_ascii_lowercase();
+    lower.contains("://")
+        || lower.starts_with("www.")
+        || (lower.starts_with("doi:") && lower.len() > "doi:".len())
+        || DOI_LIKE_PATTERN.is_match(&lower)
+        || KNOWN_SCHOLARLY_HOSTS
+            .iter()
+            .any(|host| lower.contains(host))
+}
+
+fn is_handled_by_extractors(token: &str) -> bool {
+    !extract_urls(token).is_empty() || !extract_dois(token).is_empty()
+}
+
+fn malformed_message(fragment: &str) -> String {
+    format!(
+        "{LIKELY_MALFORMED_PREFIX} `{fragment}`. Why: it looks like a URL or DOI but is not valid. Fix: correct the typo (e.g. `https://`, `10.XXXX/suffix`) and re-run."
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_likely_malformed_flags_scheme_typo_and_doi_without_slash() {
+        let messages =
+            likely_malformed_fragments("htps://example.com/paper.pdf\nsee 10.1234-abc for details");
+
+        assert_eq!(messages.len(), 2);
+        assert!(messages[0].contains("`htps://example.com/paper.pdf`"));
+        assert!(messages[1].contains("`10.1234-abc`"));
+        assert!(
+            messages
+                .iter()
+                .all(|m| m.starts_with(LIKELY_MALFORMED_PREFIX))
+        );
+    }
+
+    #[test]
+    fn test_likely_malformed_flags_known_host_without_scheme() {
+        let messages = likely_malformed_fragments("(arxiv.org/abs/2301.01234)");
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].contains("`arxiv.org/abs/2301.01234`"));
+    }
+
+    #[test]
+    fn test_likely_malformed_ignores_prose_and_bibtex() {
+        let residual = "We saw a 10.5 percent gain at www and on arxiv.\n\
+                        @article{k,\n  url = {htps://broken.example},\n}";
+        assert!(likely_malformed_fragments(residual).is_empty());
+    }
+
+    #[test]
+    fn test_likely_malformed_leaves_extractor_candidates_alone() {
+        // Matched by the URL pattern; its validation error is reported by the URL phase.
+        let too_long = format!("https://example.com/{}", "a".repeat(2500));
+        assert!(likely_malformed_fragments(&too_long).is_empty());
+    }
+}
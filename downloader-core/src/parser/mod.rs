@@ -8,8 +8,17 @@
 //! - HTTP/HTTPS URLs
 //! - DOIs (10.xxxx/...)
 //! - Reference strings (Author, Year, Title format)
+//! - arXiv IDs (`2301.00001`, `hep-th/9901001v2` alone on a line, or `arXiv:`-prefixed anywhere)
+//! - `PubMed` identifiers (`PMID:`/`PMCID:`-labelled or bare `PMC` IDs, also inside references)
+//! - Handle System identifiers (`hdl:` prefixes; `hdl.handle.net` links are normalized to HTTPS)
+//! - ISBNs (`ISBN`-labelled ISBN-10/13 with valid check digits, or a bare ISBN-13 line)
+//! - HAL document IDs (`hal-`, `halshs-`, `tel-`, `inria-` plus eight digits, optional `vN`, one per line)
 //! - Multi-line bibliography extraction (segmented into per-entry references)
 //! - BibTeX entries (`@article`, `@book`, `@inproceedings`)
+//! - `EndNote` XML exports (`<records><record>…`), detected up front and parsed as a whole
+//!
+//! Very large inputs can be streamed through [`parse_reader`], which parses
+//! bounded chunks and yields the same items as [`parse_input`].
 //!
 //! # Example
 //!
@@ -21,104 +30,101 @@
 //! assert_eq!(result.items[0].input_type, InputType::Url);
 //! ```
 
+mod arxiv_id;
 mod bibliography;
 mod bibtex;
 mod doi;
+mod endnote;
 mod error;
 mod input;
+mod isbn;
+mod malformed;
+mod pubmed_id;
 mod reference;
 mod ris;
 mod url;
 
+pub use arxiv_id::{ARXIV_ID_PREFIX, extract_arxiv_ids};
 pub use bibliography::{
     BibliographyParseResult, BibliographySummary, extract_bibliography_entries, parse_bibliography,
     summarize_bibliography,
 };
 pub use bibtex::{BibtexEntry, BibtexParseResult, parse_bibtex_entries};
 pub use doi::extract_dois;
+pub use endnote::{EndnoteEntry, EndnoteParseResult, looks_like_endnote_xml, parse_endnote_xml};
 pub use error::ParseError;
 pub use input::{InputType, ParseResult, ParseTypeCounts, ParsedItem};
+pub use isbn::{ISBN_PREFIX, extract_isbns};
+pub use pubmed_id::{BARE_PMC_ID_PATTERN, PMCID_PREFIX, PMID_PREFIX, extract_pubmed_ids};
 pub use reference::{
-    Confidence, ConfidenceFactors, ReferenceConfidence, ReferenceMetadata,
-    extract_reference_confidence, extract_references, parse_reference_metadata,
+    Confidence, ConfidenceFactors, HIGH_CONFIDENCE_SCORE, MEDIUM_CONFIDENCE_SCORE,
+    ReferenceConfidence, ReferenceMetadata, extract_reference_confidence, extract_references,
+    parse_reference_metadata,
 };
-pub use ris::{RisEntry, RisParseResult, parse_ris_content};
+pub use ris::{RisEntry, RisParseResult, looks_like_ris, parse_ris_content};
 pub use url::extract_urls;
 
 use std::collections::HashSet;
+use std::io::BufRead;
 use std::sync::LazyLock;
 
 use regex::Regex;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
-// ── Known structured IDs ──────────────────────────────────────────────────────
-// Matched against whole trimmed lines so that fragments inside longer sentences
-// (e.g. an arXiv URL) are never double-extracted.
+use arxiv_id::arxiv_doi_for_value;
+use endnote::has_endnote_xml_prolog;
+use malformed::likely_malformed_fragments;
+use pubmed_id::pubmed_ids_in_url;
 
-/// Shared PMC bare-ID pattern used by the parser and `PubMedResolver`.
-pub const BARE_PMC_ID_PATTERN: &str = r"(?i)^PMC\d{4,}$";
+/// Raw input buffered per chunk by [`parse_reader`] before it cuts at the next blank line.
+pub const STREAM_CHUNK_BYTES: usize = 256 * 1024;
 
-#[allow(clippy::expect_used)]
-static PMC_ID_LINE_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(BARE_PMC_ID_PATTERN).expect("PMC ID line regex valid"));
+/// Chunk size at which [`parse_reader`] cuts at any line boundary outside a BibTeX entry.
+pub const STREAM_CHUNK_MAX_BYTES: usize = 4 * 1024 * 1024;
 
-/// Matches a bare arXiv ID (new-style `YYMM.NNNNN` or old-style `cat/NNNNNNN`).
-/// Pattern is aligned with `ArxivResolver::ARXIV_ID_RE` to ensure consistent handling.
-#[allow(clippy::expect_used)]
-static ARXIV_ID_LINE_RE: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r"(?i)^(?:\d{4}\.\d{4,5}|[a-z\-]+(?:\.[a-z]{2})?/\d{7})(?:v\d+)?$")
-        .expect("arXiv bare ID line regex valid")
-});
+/// Multiple of [`STREAM_CHUNK_MAX_BYTES`] after which even an unterminated BibTeX entry is cut.
+const STREAM_BIBTEX_CAP_FACTOR: usize = 4;
+
+// ── Known structured IDs ──────────────────────────────────────────────────────
+// Matched against whole trimmed lines so that fragments inside longer sentences
+// (e.g. an arXiv URL) are never double-extracted.
 
-/// Matches an arXiv ID with an explicit `arXiv:` prefix (e.g. `arXiv:2301.01234v2`
-/// or `arXiv: 2301.01234`).  Capture group 1 is the bare ID (without the prefix).
+/// Matches a Handle System identifier with an explicit `hdl:` prefix (e.g.
+/// `hdl:2027/mdp.39015012345678`).  Capture group 1 is the handle itself:
+/// a dotted numeric naming authority, a slash, and a non-empty local name.
 #[allow(clippy::expect_used)]
-static ARXIV_PREFIX_LINE_RE: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r"(?i)^arXiv:\s*((?:\d{4}\.\d{4,5}|[a-z\-]+(?:\.[a-z]{2})?/\d{7})(?:v\d+)?)$")
-        .expect("arXiv prefix line regex valid")
+static HDL_PREFIX_LINE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^hdl:\s*(\d+(?:\.\d+)*/\S+)$").expect("hdl prefix line regex valid")
 });
 
+/// Matches a bare HAL document ID from the main portals (e.g. `hal-01234567v2`).
 #[allow(clippy::expect_used)]
-static PMID_PREFIX_LINE_RE: LazyLock<Regex> = LazyLock::new(|| {
-    // Requires at least one separator (colon or whitespace) between "PMID" and the digits
-    // so that "PMID12345678" (no separator) is not matched.
-    Regex::new(r"(?i)^PMID[:\s]\s*\d{1,9}$").expect("PMID prefix line regex valid")
+static HAL_ID_LINE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(?:hal|halshs|tel|inria)-\d{8}(?:v\d+)?$").expect("HAL ID line regex valid")
 });
 
-/// Extracts known structured identifiers (PMC IDs, arXiv IDs, PMIDs) from
+/// Extracts known structured identifiers (handles, HAL IDs) from
 /// whole-line matches.  Returns `ParsedItem`s with `InputType::Unknown` and
-/// the normalized identifier as the value.  Duplicate normalized values are
-/// silently dropped so repeated lines don't enqueue the same item twice.
-fn extract_known_ids(input: &str) -> Vec<ParsedItem> {
+/// the normalized identifier as the value.  Normalized values already in `seen`
+/// are silently dropped so repeated lines don't enqueue the same item twice.
+fn extract_known_ids(input: &str, seen: &mut HashSet<String>) -> Vec<ParsedItem> {
     let mut items = Vec::new();
-    let mut seen: HashSet<String> = HashSet::new();
     for line in input.lines() {
         let trimmed = line.trim();
         if trimmed.is_empty() {
             continue;
         }
-        if PMC_ID_LINE_RE.is_match(trimmed) {
-            let normalized = trimmed.to_ascii_uppercase();
-            if seen.insert(normalized.clone()) {
-                items.push(ParsedItem::new(trimmed, InputType::Unknown, normalized));
-            }
-        } else if let Some(caps) = ARXIV_PREFIX_LINE_RE.captures(trimmed) {
-            // `arXiv:2301.01234` — emit the bare ID (capture group 1) as the value.
-            if let Some(id_match) = caps.get(1) {
-                let normalized = id_match.as_str().to_string();
+        if let Some(caps) = HDL_PREFIX_LINE_RE.captures(trimmed) {
+            // Normalize to "hdl:<handle>" so `HandleResolver` can detect it unambiguously.
+            if let Some(handle) = caps.get(1) {
+                let normalized = format!("hdl:{}", handle.as_str());
                 if seen.insert(normalized.clone()) {
                     items.push(ParsedItem::new(trimmed, InputType::Unknown, normalized));
                 }
             }
-        } else if ARXIV_ID_LINE_RE.is_match(trimmed) {
-            let normalized = trimmed.to_string();
-            if seen.insert(normalized.clone()) {
-                items.push(ParsedItem::new(trimmed, InputType::Unknown, normalized));
-            }
-        } else if PMID_PREFIX_LINE_RE.is_match(trimmed) {
-            // Normalize to "PMID:<digits>" so resolvers can detect it unambiguously.
-            let digits: String = trimmed.chars().filter(char::is_ascii_digit).collect();
-            let normalized = format!("PMID:{digits}");
+        } else if HAL_ID_LINE_RE.is_match(trimmed) {
+            // Lowercase so `HalResolver` and de-duplication see one spelling.
+            let normalized = trimmed.to_ascii_lowercase();
             if seen.insert(normalized.clone()) {
                 items.push(ParsedItem::new(trimmed, InputType::Unknown, normalized));
             }
@@ -167,201 +173,471 @@ fn extract_known_ids(input: &str) -> Vec<ParsedItem> {
 #[tracing::instrument(skip(input), fields(input_len = input.len()))]
 #[must_use]
 pub fn parse_input(input: &str) -> ParseResult {
-    let mut result = ParseResult::new();
-
     // Handle empty input gracefully
     if input.trim().is_empty() {
         debug!("Empty input provided");
-        return result;
+        return ParseResult::new();
+    }
+
+    // EndNote XML is markup, not lines; the line-based extractors would pull
+    // stray URLs out of it, so it is routed wholesale.
+    if looks_like_endnote_xml(input) {
+        return parse_endnote_input(input, ParseResult::new());
     }
 
-    // Extract DOIs first
-    let doi_results = extract_dois(input);
+    let mut phased = PhasedParse::default();
+    phased.ingest(input);
+    phased.finish()
+}
 
-    let mut doi_count = 0;
-    let mut url_count = 0;
-    let mut ref_count = 0;
-    let mut bibtex_count = 0;
-    let mut error_count = 0;
+/// Parses input from a reader in bounded chunks.
+///
+/// Produces the same result as [`parse_input`] on the whole text while
+/// buffering only a few hundred kilobytes of raw input at a time. Chunks end
+/// on a blank line outside any BibTeX entry (tracked the same way as in
+/// `build_residual_input`), so multi-line entries and bibliography blocks are
+/// never split; input without blank lines is cut at a line boundary once a
+/// chunk reaches [`STREAM_CHUNK_MAX_BYTES`]. Items are merged phase by phase
+/// across chunks, so merge order and DOI de-duplication match a single pass.
+///
+/// `EndNote` XML is detected from the start of the input and, as in
+/// [`parse_input`], parsed as a whole document.
+///
+/// A read error (including invalid UTF-8) stops reading: input read so far is
+/// still parsed and the error is recorded in `skipped`.
+///
+/// # Example
+///
+/// ```
+/// use downloader_core::parser::parse_reader;
+///
+/// let input = "https://example.com/a.pdf\n\n10.1234/example\n";
+/// let result = parse_reader(input.as_bytes());
+/// assert_eq!(result.len(), 2);
+/// ```
+#[tracing::instrument(skip(reader))]
+#[must_use]
+pub fn parse_reader<R: BufRead>(reader: R) -> ParseResult {
+    parse_reader_with_chunk_sizes(reader, STREAM_CHUNK_BYTES, STREAM_CHUNK_MAX_BYTES)
+}
 
-    let mut seen_dois: HashSet<String> = HashSet::new();
-    for doi_result in doi_results {
-        match doi_result {
-            Ok(item) => {
-                if !seen_dois.insert(item.value.clone()) {
-                    continue;
-                }
-                doi_count += 1;
-                result.add_item(item);
-            }
-            Err(e) => {
-                error_count += 1;
-                debug!(error = %e, "DOI extraction error");
-                if let ParseError::InvalidDoi { doi, .. } = &e {
-                    result.add_skipped(doi.clone());
-                }
+fn parse_reader_with_chunk_sizes<R: BufRead>(
+    mut reader: R,
+    chunk_bytes: usize,
+    max_chunk_bytes: usize,
+) -> ParseResult {
+    let mut phased = PhasedParse::default();
+    let mut tracker = BibtexBlockTracker::default();
+    let mut chunk = String::new();
+    let mut line = String::new();
+    let mut saw_content = false;
+
+    loop {
+        line.clear();
+        match reader.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(error) => {
+                warn!(error = %error, "Stopped reading input");
+                phased.read_error = Some(format!("input read error: {error}"));
+                break;
             }
         }
-    }
 
-    // Extract URLs from the input
-    let url_results = extract_urls(input);
+        let trimmed = line.trim();
+        if !trimmed.is_empty() {
+            tracker.observe(trimmed);
+        }
+        chunk.push_str(&line);
 
-    // Post-filter: DOIs win over doi.org URLs
-    let url_results: Vec<_> = url_results
-        .into_iter()
-        .filter(|r| match r {
-            Ok(item) => {
-                let parsed = ::url::Url::parse(&item.value).ok();
-                !parsed.is_some_and(|u| matches!(u.host_str(), Some("doi.org" | "dx.doi.org")))
-            }
-            Err(_) => true, // Keep errors for skipped list
-        })
-        .collect();
+        let at_blank_line = trimmed.is_empty() && !tracker.in_block;
+        let flush = (chunk.len() >= chunk_bytes && at_blank_line)
+            || (chunk.len() >= max_chunk_bytes && !tracker.in_block)
+            || chunk.len() >= max_chunk_bytes.saturating_mul(STREAM_BIBTEX_CAP_FACTOR);
+        if !flush {
+            continue;
+        }
 
-    for url_result in url_results {
-        match url_result {
-            Ok(item) => {
-                url_count += 1;
-                result.add_item(item);
-            }
-            Err(e) => {
-                error_count += 1;
-                debug!(error = %e, "URL extraction error");
-                match &e {
-                    ParseError::InvalidUrl { url, .. } => {
-                        result.add_skipped(url.clone());
-                    }
-                    ParseError::UrlTooLong { url_preview, .. } => {
-                        result.add_skipped(url_preview.clone());
-                    }
-                    ParseError::InvalidDoi { .. } | ParseError::UnparseableReference { .. } => {}
-                }
+        if !saw_content && !chunk.trim().is_empty() {
+            saw_content = true;
+            if has_endnote_xml_prolog(&chunk) {
+                return parse_remaining_as_document(reader, chunk);
             }
         }
+        phased.ingest(&chunk);
+        chunk.clear();
+        // A forced cut inside an unterminated BibTeX entry starts the next chunk fresh.
+        tracker = BibtexBlockTracker::default();
     }
 
-    // Extract known structured IDs (PMC, arXiv, PMID) before residual processing
-    // so they are not silently dropped by the bibliography parser.
-    let (id_count, residual_input) = collect_ids_and_residual(input, &mut result);
+    if !saw_content && has_endnote_xml_prolog(&chunk) {
+        let mut result = parse_input(&chunk);
+        if let Some(message) = phased.read_error {
+            result.add_skipped(message);
+        }
+        return result;
+    }
+    phased.ingest(&chunk);
+    phased.finish()
+}
 
-    if residual_input.lines().any(|line| !line.trim().is_empty()) {
-        let residual_stats = process_residual_content(&mut result, &residual_input);
-        ref_count += residual_stats.references;
-        bibtex_count += residual_stats.bibtex;
-        error_count += residual_stats.errors;
+/// Reads the rest of `reader` after `head` and parses it in one pass.
+fn parse_remaining_as_document<R: BufRead>(mut reader: R, mut head: String) -> ParseResult {
+    if let Err(error) = reader.read_to_string(&mut head) {
+        warn!(error = %error, "Stopped reading input");
+        let mut result = parse_input(&head);
+        result.add_skipped(format!("input read error: {error}"));
+        return result;
+    }
+    parse_input(&head)
+}
+
+fn parse_endnote_input(input: &str, mut result: ParseResult) -> ParseResult {
+    let endnote = parse_endnote_xml(input);
+    let mut seen_dois: HashSet<String> = HashSet::new();
+    for item in endnote.items {
+        if item.input_type == InputType::Doi && !seen_dois.insert(item.value.clone()) {
+            continue;
+        }
+        result.add_item(item);
+    }
+    for message in endnote.skipped {
+        result.add_skipped(message);
     }
 
     info!(
-        urls = url_count,
-        dois = doi_count,
-        ids = id_count,
-        references = ref_count,
-        bibtex = bibtex_count,
-        errors = error_count,
+        records = endnote.total_found,
+        entries = endnote.entries.len(),
         total = result.len(),
         skipped = result.skipped_count(),
-        "Parsing complete"
+        "Parsing complete (EndNote XML)"
     );
-
     result
 }
 
-/// Adds known structured IDs to `result` and returns `(count, residual_text)` with
-/// matched lines blanked out so the bibliography parser does not re-process them.
-fn collect_ids_and_residual(input: &str, result: &mut ParseResult) -> (usize, String) {
-    let known_id_items = extract_known_ids(input);
-    let known_id_raws: HashSet<String> = known_id_items
-        .iter()
-        .map(|i| i.raw.trim().to_string())
-        .collect();
-    let count = known_id_items.len();
-    for item in known_id_items {
-        result.add_item(item);
-    }
+/// Parser output buffered per extraction phase.
+///
+/// Holding each phase separately lets [`parse_reader`] feed input in several
+/// chunks and still emit items in single-pass order: every DOI before every
+/// URL, and so on. Only extracted items are retained, never raw input.
+#[derive(Debug, Default)]
+struct PhasedParse {
+    dois: Vec<ParsedItem>,
+    arxiv_ids: Vec<ParsedItem>,
+    urls: Vec<ParsedItem>,
+    pubmed_ids: Vec<ParsedItem>,
+    isbns: Vec<ParsedItem>,
+    known_ids: Vec<ParsedItem>,
+    references: Vec<ParsedItem>,
+    bibtex: Vec<ParsedItem>,
+    doi_skipped: Vec<String>,
+    url_skipped: Vec<String>,
+    isbn_skipped: Vec<String>,
+    reference_skipped: Vec<String>,
+    bibtex_skipped: Vec<String>,
+    malformed_skipped: Vec<String>,
+    read_error: Option<String>,
+    seen_dois: HashSet<String>,
+    seen_known_ids: HashSet<String>,
+    known_id_raws: HashSet<String>,
+}
 
-    let residual = build_residual_input(input)
-        .lines()
-        .map(|line| {
-            if known_id_raws.contains(line.trim()) {
-                String::new()
-            } else {
-                line.to_string()
+impl PhasedParse {
+    fn ingest(&mut self, input: &str) {
+        if input.trim().is_empty() {
+            return;
+        }
+
+        // Extract DOIs first
+        for doi_result in extract_dois(input) {
+            match doi_result {
+                Ok(item) => {
+                    if self.seen_dois.insert(item.value.clone()) {
+                        self.dois.push(item);
+                    }
+                }
+                Err(e) => {
+                    debug!(error = %e, "DOI extraction error");
+                    if let ParseError::InvalidDoi { doi, .. } = &e {
+                        self.doi_skipped.push(doi.clone());
+                    }
+                }
             }
-        })
-        .collect::<Vec<_>>()
-        .join("\n");
-    (count, residual)
-}
+        }
 
-#[derive(Debug, Clone, Copy, Default)]
-struct ResidualMergeStats {
-    references: usize,
-    bibtex: usize,
-    errors: usize,
-}
+        self.collect_arxiv_ids(input);
 
-fn process_residual_content(result: &mut ParseResult, residual_input: &str) -> ResidualMergeStats {
-    // Deterministic merge-order contract for mixed parser output:
-    // 1) DOI extractor results
-    // 2) URL extractor results
-    // 3) bibliography/reference residual parsing
-    // 4) BibTeX residual parsing (per-entry order; DOI then mapped reference)
-    //
-    // DOI de-duplication contract across extractors:
-    // - Canonical winner: first DOI extracted in earlier phase order
-    // - Later extractors (BibTeX) may emit DOI candidates, but duplicates are dropped
-    //   using normalized DOI value equality.
-    let mut stats = ResidualMergeStats::default();
-    let mut residual_for_bibliography = residual_input.to_string();
-    let bibtex_result = parse_bibtex_entries(residual_input);
-    let mut seen_dois: HashSet<String> = result
-        .items
-        .iter()
-        .filter(|item| item.input_type == InputType::Doi)
-        .map(|item| item.value.clone())
-        .collect();
+        // Extract URLs; DOIs win over doi.org URLs
+        for url_result in extract_urls(input) {
+            match url_result {
+                Ok(item) => {
+                    if !is_doi_resolver_url(&item) {
+                        self.urls.push(item);
+                    }
+                }
+                Err(e) => {
+                    debug!(error = %e, "URL extraction error");
+                    match &e {
+                        ParseError::InvalidUrl { url, .. } => {
+                            self.url_skipped.push(url.clone());
+                        }
+                        ParseError::UrlTooLong { url_preview, .. } => {
+                            self.url_skipped.push(url_preview.clone());
+                        }
+                        ParseError::InvalidDoi { .. }
+                        | ParseError::InvalidIsbn { .. }
+                        | ParseError::UnparseableReference { .. } => {}
+                    }
+                }
+            }
+        }
+
+        self.collect_pubmed_ids(input);
+        self.collect_isbns(input);
+
+        // Extract known structured IDs (hdl, HAL) before residual processing
+        // so they are not silently dropped by the bibliography parser.
+        let residual_input = self.collect_ids_and_residual(input);
+        self.malformed_skipped
+            .extend(likely_malformed_fragments(&residual_input));
 
-    for segment in &bibtex_result.consumed_segments {
-        residual_for_bibliography = residual_for_bibliography.replacen(segment, " ", 1);
+        if residual_input.lines().any(|line| !line.trim().is_empty()) {
+            self.process_residual_content(&residual_input);
+        }
     }
 
-    let bibliography_result = parse_bibliography(&residual_for_bibliography);
-    let bibliography_summary = summarize_bibliography(&bibliography_result);
-    stats.references += bibliography_summary.parsed;
-    stats.errors += bibliography_summary.uncertain;
+    /// Records arXiv IDs ahead of the URL and reference phases.
+    ///
+    /// Lines inside BibTeX entries are skipped (the entry carries its own
+    /// identifiers), as is an ID whose arXiv DOI was already extracted, so
+    /// `10.48550/arXiv.1706.03762` and `arXiv:1706.03762` enqueue one item.
+    fn collect_arxiv_ids(&mut self, input: &str) {
+        let mut tracker = BibtexBlockTracker::default();
+        for line in input.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || tracker.observe(trimmed) {
+                continue;
+            }
+            for item in extract_arxiv_ids(trimmed) {
+                let has_doi = arxiv_doi_for_value(&item.value)
+                    .is_some_and(|doi| self.seen_dois.contains(&doi));
+                if !has_doi && self.seen_known_ids.insert(item.value.clone()) {
+                    self.arxiv_ids.push(item);
+                }
+            }
+        }
+    }
 
-    for item in bibliography_result.parsed {
-        result.add_item(item);
+    /// Records PMIDs and PMC IDs written outside links and BibTeX entries.
+    ///
+    /// IDs that a `PubMed`/PMC URL in the input already points at are dropped
+    /// in `finish`, once every chunk's URLs are known.
+    fn collect_pubmed_ids(&mut self, input: &str) {
+        let mut tracker = BibtexBlockTracker::default();
+        for line in input.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || tracker.observe(trimmed) {
+                continue;
+            }
+            for item in extract_pubmed_ids(&strip_urls(trimmed)) {
+                if self.seen_known_ids.insert(item.value.clone()) {
+                    self.pubmed_ids.push(item);
+                }
+            }
+        }
     }
-    for uncertain in bibliography_result.uncertain {
-        result.add_skipped(uncertain);
+
+    /// Records ISBNs written outside links and BibTeX entries; ones with a bad
+    /// check digit or length are skipped.
+    fn collect_isbns(&mut self, input: &str) {
+        let mut tracker = BibtexBlockTracker::default();
+        for line in input.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || tracker.observe(trimmed) {
+                continue;
+            }
+            for isbn_result in extract_isbns(&strip_urls(trimmed)) {
+                match isbn_result {
+                    Ok(item) => {
+                        if self.seen_known_ids.insert(item.value.clone()) {
+                            self.isbns.push(item);
+                        }
+                    }
+                    Err(ParseError::InvalidIsbn { isbn, .. }) => self.isbn_skipped.push(isbn),
+                    Err(e) => debug!(error = %e, "ISBN extraction error"),
+                }
+            }
+        }
     }
 
-    for item in bibtex_result.items {
-        if item.input_type == InputType::Doi && !seen_dois.insert(item.value.clone()) {
-            continue;
+    /// Records known structured IDs and returns the residual text with matched
+    /// lines blanked out so the bibliography parser does not re-process them.
+    fn collect_ids_and_residual(&mut self, input: &str) -> String {
+        for item in extract_known_ids(input, &mut self.seen_known_ids) {
+            self.known_id_raws.insert(item.raw.trim().to_string());
+            self.known_ids.push(item);
         }
-        match item.input_type {
-            InputType::Reference => stats.references += 1,
-            InputType::BibTex => stats.bibtex += 1,
-            _ => {}
+
+        build_residual_input(input)
+            .lines()
+            .map(|line| {
+                if self.known_id_raws.contains(line.trim()) {
+                    String::new()
+                } else {
+                    line.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn process_residual_content(&mut self, residual_input: &str) {
+        // Deterministic merge-order contract for mixed parser output:
+        // 1) DOI extractor results
+        // 2) arXiv ID extractor results
+        // 3) URL extractor results
+        // 4) PubMed IDs, minus those a PubMed/PMC URL from (3) points at
+        // 5) ISBNs
+        // 6) other structured IDs (hdl, HAL)
+        // 7) bibliography/reference residual parsing
+        // 8) BibTeX residual parsing (per-entry order; DOI then mapped reference)
+        //
+        // DOI de-duplication contract across extractors:
+        // - Canonical winner: first DOI extracted in earlier phase order
+        // - Later extractors (BibTeX) may emit DOI candidates, but duplicates are dropped
+        //   using normalized DOI value equality.
+        //
+        // Phases are buffered separately and the BibTeX DOI filter runs in `finish`,
+        // once every chunk's DOI extractor results are known.
+        let mut residual_for_bibliography = residual_input.to_string();
+        let bibtex_result = parse_bibtex_entries(residual_input);
+
+        for segment in &bibtex_result.consumed_segments {
+            residual_for_bibliography = residual_for_bibliography.replacen(segment, " ", 1);
         }
-        result.add_item(item);
+
+        let bibliography_result = parse_bibliography(&residual_for_bibliography);
+        self.references.extend(bibliography_result.parsed);
+        self.reference_skipped.extend(bibliography_result.uncertain);
+
+        self.bibtex.extend(bibtex_result.items);
+        self.bibtex_skipped.extend(bibtex_result.skipped);
     }
 
-    stats.errors += bibtex_result.skipped.len();
-    for message in bibtex_result.skipped {
-        result.add_skipped(message);
+    fn finish(self) -> ParseResult {
+        let mut result = ParseResult::new();
+        let mut seen_dois = self.seen_dois;
+        let doi_count = self.dois.len();
+        let url_count = self.urls.len();
+        let linked_pubmed_ids: HashSet<String> = self
+            .urls
+            .iter()
+            .flat_map(|item| pubmed_ids_in_url(&item.value))
+            .collect();
+        let pubmed_ids: Vec<ParsedItem> = self
+            .pubmed_ids
+            .into_iter()
+            .filter(|item| !linked_pubmed_ids.contains(&item.value))
+            .collect();
+        let id_count =
+            self.arxiv_ids.len() + pubmed_ids.len() + self.isbns.len() + self.known_ids.len();
+        let mut ref_count = self.references.len();
+        let mut bibtex_count = 0;
+        let error_count = self.doi_skipped.len()
+            + self.url_skipped.len()
+            + self.isbn_skipped.len()
+            + self.reference_skipped.len()
+            + self.bibtex_skipped.len()
+            + self.malformed_skipped.len();
+
+        for item in self
+            .dois
+            .into_iter()
+            .chain(self.arxiv_ids)
+            .chain(self.urls)
+            .chain(pubmed_ids)
+            .chain(self.isbns)
+            .chain(self.known_ids)
+            .chain(self.references)
+        {
+            result.add_item(item);
+        }
+        for item in self.bibtex {
+            if item.input_type == InputType::Doi && !seen_dois.insert(item.value.clone()) {
+                continue;
+            }
+            match item.input_type {
+                InputType::Reference => ref_count += 1,
+                InputType::BibTex => bibtex_count += 1,
+                _ => {}
+            }
+            result.add_item(item);
+        }
+
+        for message in self
+            .doi_skipped
+            .into_iter()
+            .chain(self.url_skipped)
+            .chain(self.isbn_skipped)
+            .chain(self.reference_skipped)
+            .chain(self.bibtex_skipped)
+            .chain(self.malformed_skipped)
+            .chain(self.read_error)
+        {
+            result.add_skipped(message);
+        }
+
+        info!(
+            urls = url_count,
+            dois = doi_count,
+            ids = id_count,
+            references = ref_count,
+            bibtex = bibtex_count,
+            errors = error_count,
+            total = result.len(),
+            skipped = result.skipped_count(),
+            "Parsing complete"
+        );
+
+        result
     }
+}
+
+fn is_doi_resolver_url(item: &ParsedItem) -> bool {
+    ::url::Url::parse(&item.value)
+        .ok()
+        .is_some_and(|u| matches!(u.host_str(), Some("doi.org" | "dx.doi.org")))
+}
 
-    stats
+/// Tracks whether consecutive trimmed lines sit inside a multi-line BibTeX entry.
+#[derive(Debug, Clone, Copy, Default)]
+struct BibtexBlockTracker {
+    in_block: bool,
+    brace_depth: i32,
+}
+
+impl BibtexBlockTracker {
+    /// Feeds one trimmed, non-empty line; returns true when it belongs to a BibTeX entry.
+    fn observe(&mut self, line: &str) -> bool {
+        if self.in_block {
+            self.brace_depth += bibtex_brace_delta(line);
+        } else if looks_like_bibtex_line(line) {
+            self.in_block = true;
+            self.brace_depth = bibtex_brace_delta(line);
+        } else {
+            return false;
+        }
+        if self.brace_depth <= 0 {
+            self.in_block = false;
+            self.brace_depth = 0;
+        }
+        true
+    }
 }
 
 fn build_residual_input(input: &str) -> String {
     let mut residual_lines = Vec::new();
-    let mut in_bibtex_block = false;
-    let mut bibtex_brace_depth = 0i32;
+    let mut tracker = BibtexBlockTracker::default();
 
     for raw_line in input.lines() {
         let line = raw_line.trim();
@@ -370,24 +646,8 @@ fn build_residual_input(input: &str) -> String {
             continue;
         }
 
-        if in_bibtex_block {
-            residual_lines.push(line.to_string());
-            bibtex_brace_depth += bibtex_brace_delta(line);
-            if bibtex_brace_depth <= 0 {
-                in_bibtex_block = false;
-                bibtex_brace_depth = 0;
-            }
-            continue;
-        }
-
-        if looks_like_bibtex_line(line) {
-            in_bibtex_block = true;
-            bibtex_brace_depth = bibtex_brace_delta(line);
+        if tracker.observe(line) {
             residual_lines.push(line.to_string());
-            if bibtex_brace_depth <= 0 {
-                in_bibtex_block = false;
-                bibtex_brace_depth = 0;
-            }
             continue;
         }
 
@@ -433,6 +693,16 @@ fn bibtex_brace_delta(line: &str) -> i32 {
 
 fn strip_matched_fragments(line: &str) -> String {
     let mut residual = line.to_string();
+    let url_items: Vec<ParsedItem> = extract_urls(line)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|item| !item.raw.is_empty())
+        .collect();
+
+    // Link markup first: its raw spans the link text and any DOI in the target.
+    for item in url_items.iter().filter(|item| is_link_markup(item)) {
+        residual = residual.replacen(&item.raw, " ", 1);
+    }
 
     for doi_result in extract_dois(line) {
         if let Ok(item) = doi_result
@@ -442,28 +712,49 @@ fn strip_matched_fragments(line: &str) -> String {
         }
     }
 
-    let url_results: Vec<_> = extract_urls(line)
-        .into_iter()
-        .filter(|result| match result {
-            Ok(item) => {
-                let parsed = ::url::Url::parse(&item.value).ok();
-                !parsed.is_some_and(|u| matches!(u.host_str(), Some("doi.org" | "dx.doi.org")))
-            }
-            Err(_) => true,
-        })
-        .collect();
-
-    for url_result in url_results {
-        if let Ok(item) = url_result
-            && !item.raw.is_empty()
-        {
+    for item in &url_items {
+        if !is_link_markup(item) && !is_doi_resolver_url(item) {
             residual = residual.replacen(&item.raw, " ", 1);
         }
     }
 
+    for item in extract_arxiv_ids(line) {
+        residual = residual.replacen(&item.raw, " ", 1);
+    }
+
+    for item in extract_pubmed_ids(&residual) {
+        residual = residual.replacen(&item.raw, " ", 1);
+    }
+
+    // Invalid ISBNs are already reported as skipped; drop them too.
+    for isbn_result in extract_isbns(&residual) {
+        let raw = match isbn_result {
+            Ok(item) => item.raw,
+            Err(ParseError::InvalidIsbn { isbn, .. }) => isbn,
+            Err(_) => continue,
+        };
+        residual = residual.replacen(&raw, " ", 1);
+    }
+
+    residual
+}
+
+/// `line` with every URL blanked out.
+fn strip_urls(line: &str) -> String {
+    let mut residual = line.to_string();
+    for item in extract_urls(line).into_iter().filter_map(Result::ok) {
+        if !item.raw.is_empty() {
+            residual = residual.replacen(&item.raw, " ", 1);
+        }
+    }
     residual
 }
 
+/// True for URL items unwrapped from Markdown/HTML link markup or an autolink.
+fn is_link_markup(item: &ParsedItem) -> bool {
+    item.raw.starts_with(['[', '<'])
+}
+
 fn looks_like_bibtex_line(line: &str) -> bool {
     let trimmed = line.trim_start();
     if !trimmed.starts_with('@') {
@@ -509,6 +800,17 @@ mod tests {
         assert_eq!(result.len(), 1);
     }
 
+    #[test]
+    fn test_parse_input_reports_likely_malformed_url_but_not_prose() {
+        let result = parse_input(
+            "htps://example.com/paper.pdf\nhttps://example.com/good.pdf\nJust some notes here.",
+        );
+        assert_eq!(result.len(), 1);
+        assert_eq!(result.likely_malformed_count(), 1);
+        assert!(result.skipped[0].contains("`htps://example.com/paper.pdf`"));
+        assert!(result.skipped[0].contains("Fix:"));
+    }
+
     // ==================== AC3: Non-URL Text Handling ====================
 
     #[test]
@@ -685,6 +987,61 @@ mod tests {
         assert_eq!(result.dois().count(), 1);
     }
 
+    #[test]
+    fn test_parse_input_dedups_mixed_case_dois() {
+        let result = parse_input("10.1234/ABC\n10.1234/abc\nDOI: 10.1234/AbC\n");
+        let dois: Vec<_> = result.dois().collect();
+        assert_eq!(dois.len(), 1);
+        assert_eq!(dois[0].value, "10.1234/abc");
+        assert_eq!(
+            dois[0].raw, "10.1234/ABC",
+            "first spelling kept for display"
+        );
+    }
+
+    #[test]
+    fn test_parse_input_doi_url_and_bare_doi_collapse_across_case() {
+        let result = parse_input("https://doi.org/10.1234/Paper.X\n10.1234/paper.x\n");
+        assert_eq!(result.dois().count(), 1);
+        assert_eq!(
+            result.urls().count(),
+            0,
+            "doi.org URLs defer to the DOI item"
+        );
+
+        // Chunked reading shares the same seen set across chunks.
+        let streamed = parse_reader_with_chunk_sizes(
+            "https://dx.doi.org/10.1234/PAPER.X\n\n10.1234/paper.x\n".as_bytes(),
+            8,
+            16,
+        );
+        assert_eq!(streamed.dois().count(), 1);
+    }
+
+    #[test]
+    fn test_parse_input_markdown_reading_list_leaves_no_residual_references() {
+        let input = "\
+- [Attention Is All You Need](https://arxiv.org/pdf/1706.03762.pdf)
+- [A Relational Model of Data](https://doi.org/10.1145/362384.362685)
+- <a href=\"https://example.com/paper.pdf\">Example Paper</a>
+";
+        let result = parse_input(input);
+
+        let urls: Vec<_> = result.urls().collect();
+        assert_eq!(urls.len(), 2);
+        assert_eq!(
+            urls[0].title_hint.as_deref(),
+            Some("Attention Is All You Need")
+        );
+        assert_eq!(urls[1].value, "https://example.com/paper.pdf");
+        assert_eq!(result.dois().count(), 1, "doi.org link defers to the DOI");
+        assert_eq!(
+            result.references().count(),
+            0,
+            "link text is not a reference"
+        );
+    }
+
     #[test]
     fn test_parse_input_bibtex_malformed_isolated_from_valid_neighbor() {
         let input = r#"
@@ -749,7 +1106,7 @@ mod tests {
             .filter(|i| i.input_type == InputType::Unknown)
             .collect();
         assert_eq!(unknowns.len(), 1);
-        assert_eq!(unknowns[0].value, "PMC1234567");
+        assert_eq!(unknowns[0].value, "pmcid:PMC1234567");
         assert_eq!(result.skipped_count(), 0);
     }
 
@@ -761,7 +1118,7 @@ mod tests {
             .iter()
             .find(|i| i.input_type == InputType::Unknown)
             .unwrap();
-        assert_eq!(id.value, "PMC9876543");
+        assert_eq!(id.value, "pmcid:PMC9876543");
     }
 
     #[test]
@@ -772,7 +1129,7 @@ mod tests {
             .iter()
             .find(|i| i.input_type == InputType::Unknown)
             .unwrap();
-        assert_eq!(id.value, "2301.01234");
+        assert_eq!(id.value, "arXiv:2301.01234");
         assert_eq!(result.skipped_count(), 0);
     }
 
@@ -784,7 +1141,7 @@ mod tests {
             .iter()
             .find(|i| i.input_type == InputType::Unknown)
             .unwrap();
-        assert_eq!(id.value, "2301.01234v2");
+        assert_eq!(id.value, "arXiv:2301.01234v2");
     }
 
     #[test]
@@ -795,7 +1152,7 @@ mod tests {
             .iter()
             .find(|i| i.input_type == InputType::Unknown)
             .unwrap();
-        assert_eq!(id.value, "PMID:12345678");
+        assert_eq!(id.value, "pmid:12345678");
         assert_eq!(result.skipped_count(), 0);
     }
 
@@ -807,7 +1164,24 @@ mod tests {
             .iter()
             .find(|i| i.input_type == InputType::Unknown)
             .unwrap();
-        assert_eq!(id.value, "PMID:99999");
+        assert_eq!(id.value, "pmid:99999");
+    }
+
+    #[test]
+    fn test_parse_input_pmid_and_pmcid_prefix_forms() {
+        for (input, expected) in [
+            ("PMID: 31452104", "pmid:31452104"),
+            ("pmid:31452104", "pmid:31452104"),
+            ("PMCID: PMC6716437", "pmcid:PMC6716437"),
+            ("pmcid:6716437", "pmcid:PMC6716437"),
+            ("pmc6716437", "pmcid:PMC6716437"),
+        ] {
+            let result = parse_input(input);
+            assert_eq!(result.len(), 1, "{input}");
+            assert_eq!(result.items[0].input_type, InputType::Unknown, "{input}");
+            assert_eq!(result.items[0].value, expected, "{input}");
+            assert_eq!(result.skipped_count(), 0, "{input}");
+        }
     }
 
     #[test]
@@ -825,6 +1199,108 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_input_pubmed_ids_inside_reference_line() {
+        let result = parse_input(
+            "Smith J, Doe A (2019). Trial results in 12345678 patients. Lancet 394:1021-1030. PMID: 31452104",
+        );
+        let ids: Vec<_> = result
+            .items
+            .iter()
+            .filter(|i| i.input_type == InputType::Unknown)
+            .map(|i| i.value.as_str())
+            .collect();
+        assert_eq!(ids, ["pmid:31452104"], "unlabelled numbers are not PMIDs");
+        assert!(
+            result.references().all(|item| !item.value.contains("PMID")),
+            "the ID is stripped from the residual reference"
+        );
+    }
+
+    #[test]
+    fn test_parse_input_pubmed_ids_skip_ids_already_linked() {
+        let result = parse_input(
+            "https://pubmed.ncbi.nlm.nih.gov/31452104/\n\
+             PMID: 31452104\n\
+             https://pmc.ncbi.nlm.nih.gov/articles/PMC6716437/ (PMC6716437)\n\
+             PMCID: PMC1111111",
+        );
+        let values: Vec<_> = result.items.iter().map(|i| i.value.as_str()).collect();
+        assert_eq!(
+            values,
+            [
+                "https://pubmed.ncbi.nlm.nih.gov/31452104/",
+                "https://pmc.ncbi.nlm.nih.gov/articles/PMC6716437/",
+                "pmcid:PMC1111111",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_input_isbn_in_reference_line() {
+        let result = parse_input(
+            "Knuth, D. E. (1997). The Art of Computer Programming, Vol. 1. Addison-Wesley. ISBN 0-201-89683-4",
+        );
+        let isbns: Vec<_> = result
+            .items
+            .iter()
+            .filter(|item| item.value.starts_with(ISBN_PREFIX))
+            .collect();
+        assert_eq!(isbns.len(), 1);
+        assert_eq!(isbns[0].input_type, InputType::Unknown);
+        assert_eq!(isbns[0].value, "isbn:9780201896831");
+        assert!(
+            result
+                .references()
+                .all(|reference| !reference.raw.contains("ISBN")),
+            "the ISBN is stripped from the reference residual"
+        );
+    }
+
+    #[test]
+    fn test_parse_input_invalid_isbn_is_skipped() {
+        let result = parse_input("ISBN 978-3-16-148410-1\n9783161484100");
+        let values: Vec<_> = result.items.iter().map(|i| i.value.as_str()).collect();
+        assert_eq!(values, ["isbn:9783161484100"]);
+        assert_eq!(result.skipped, ["ISBN 978-3-16-148410-1"]);
+    }
+
+    #[test]
+    fn test_parse_input_recognizes_bare_hal_ids() {
+        let result = parse_input("HAL-01234567\nhalshs-00012345v2\nhal-01234567\nhal-123");
+        let values: Vec<_> = result
+            .items
+            .iter()
+            .filter(|i| i.input_type == InputType::Unknown)
+            .map(|i| i.value.as_str())
+            .collect();
+        assert_eq!(values, vec!["hal-01234567", "halshs-00012345v2"]);
+    }
+
+    #[test]
+    fn test_parse_input_recognizes_hdl_prefix() {
+        let result = parse_input("HDL: 2027/mdp.39015012345678\nhdl:20.500.12345/678");
+        let values: Vec<_> = result
+            .items
+            .iter()
+            .filter(|i| i.input_type == InputType::Unknown)
+            .map(|i| i.value.as_str())
+            .collect();
+        assert_eq!(
+            values,
+            vec!["hdl:2027/mdp.39015012345678", "hdl:20.500.12345/678"]
+        );
+        assert_eq!(result.skipped_count(), 0);
+    }
+
+    #[test]
+    fn test_parse_input_handle_proxy_url_stays_a_url() {
+        let result = parse_input("http://hdl.handle.net/1721.1/12345");
+        assert_eq!(result.len(), 1);
+        assert_eq!(result.items[0].input_type, InputType::Url);
+        assert_eq!(result.items[0].value, "https://hdl.handle.net/1721.1/12345");
+    }
+
     #[test]
     fn test_parse_input_known_id_does_not_appear_in_skipped() {
         // PMC IDs must not be counted as uncertain references.
@@ -840,7 +1316,7 @@ mod tests {
             .iter()
             .find(|i| i.input_type == InputType::Unknown)
             .unwrap();
-        assert_eq!(id.value, "2301.01234");
+        assert_eq!(id.value, "arXiv:2301.01234");
         assert_eq!(result.skipped_count(), 0);
     }
 
@@ -852,7 +1328,7 @@ mod tests {
             .iter()
             .find(|i| i.input_type == InputType::Unknown)
             .unwrap();
-        assert_eq!(id.value, "2301.01234");
+        assert_eq!(id.value, "arXiv:2301.01234");
     }
 
     #[test]
@@ -868,4 +1344,187 @@ mod tests {
             .collect();
         assert_eq!(unknowns.len(), 2, "PMC ID + arXiv bare ID");
     }
+
+    #[test]
+    fn test_parse_input_arxiv_prefix_inside_reference_line() {
+        let result = parse_input(
+            "Vaswani, A., Shazeer, N. (2017). Attention is all you need. arXiv:1706.03762v2",
+        );
+        let id = result
+            .items
+            .iter()
+            .find(|i| i.input_type == InputType::Unknown)
+            .unwrap();
+        assert_eq!(id.raw, "arXiv:1706.03762v2");
+        assert_eq!(id.value, "arXiv:1706.03762v2");
+        assert!(
+            result
+                .references()
+                .all(|item| !item.value.contains("1706.03762")),
+            "the ID is stripped from the residual reference"
+        );
+    }
+
+    #[test]
+    fn test_parse_input_arxiv_ids_precede_urls_and_skip_arxiv_dois() {
+        let result = parse_input(
+            "https://example.com/paper.pdf\n\
+             hep-th/9901001\n\
+             10.48550/arXiv.1706.03762\n\
+             arXiv:1706.03762v2\n\
+             2301.00001\n\
+             arXiv: 2301.00001",
+        );
+        let values: Vec<&str> = result.items.iter().map(|i| i.value.as_str()).collect();
+        assert_eq!(
+            values,
+            [
+                "10.48550/arxiv.1706.03762",
+                "arXiv:hep-th/9901001",
+                "arXiv:2301.00001",
+                "https://example.com/paper.pdf",
+            ]
+        );
+        assert_eq!(result.skipped_count(), 0);
+    }
+
+    #[test]
+    fn test_parse_input_arxiv_id_inside_bibtex_entry_is_not_extracted() {
+        let result = parse_input(
+            "@misc{vaswani2017,\n  title={Attention Is All You Need},\n  note={arXiv:1706.03762},\n  year={2017}\n}",
+        );
+        assert!(
+            result
+                .items
+                .iter()
+                .all(|i| i.input_type != InputType::Unknown),
+            "{:?}",
+            result.items
+        );
+    }
+
+    // ==================== Streaming parse ====================
+
+    fn item_tuples(result: &ParseResult) -> Vec<(String, InputType, String)> {
+        result
+            .items
+            .iter()
+            .map(|item| (item.raw.clone(), item.input_type, item.value.clone()))
+            .collect()
+    }
+
+    fn synthetic_bibliography_block(index: usize) -> String {
+        format!(
+            "https://example.com/papers/{index}.pdf\n\
+             10.1234/shared see also 10.5555/item.{index}\n\
+             PMC{pmc}\n\
+             \n\
+             {index}. Smith, J. ({year}). Streaming Study Number {index}. Journal of Tests.\n\
+             \n\
+             @article{{entry{index},\n\
+             \x20 title={{Chunked {{Parsing}} {index}}},\n\
+             \n\
+             \x20 author={{Doe, Jane}},\n\
+             \x20 year={{2024}},\n\
+             \x20 doi={{10.5555/item.{index}}}\n\
+             }}\n\
+             \n",
+            pmc = 100_000 + index,
+            year = 1990 + index % 30,
+        )
+    }
+
+    #[test]
+    fn test_parse_reader_matches_parse_input_on_large_synthetic_input() {
+        let mut input = String::new();
+        let mut index = 0;
+        while input.len() < 3 * STREAM_CHUNK_BYTES {
+            input.push_str(&synthetic_bibliography_block(index));
+            index += 1;
+        }
+
+        let all_at_once = parse_input(&input);
+        let streamed = parse_reader(input.as_bytes());
+
+        assert_eq!(streamed.len(), all_at_once.len());
+        assert_eq!(streamed.dois().count(), all_at_once.dois().count());
+        assert_eq!(streamed.urls().count(), all_at_once.urls().count());
+        assert_eq!(streamed.bibtex().count(), index);
+        assert_eq!(item_tuples(&streamed), item_tuples(&all_at_once));
+        assert_eq!(streamed.skipped, all_at_once.skipped);
+    }
+
+    #[test]
+    fn test_parse_reader_small_chunks_keep_bibtex_entries_whole() {
+        let input: String = (0..40).map(synthetic_bibliography_block).collect();
+
+        let all_at_once = parse_input(&input);
+        let streamed = parse_reader_with_chunk_sizes(input.as_bytes(), 64, 512);
+
+        assert_eq!(streamed.bibtex().count(), 40);
+        assert_eq!(item_tuples(&streamed), item_tuples(&all_at_once));
+        assert_eq!(streamed.skipped, all_at_once.skipped);
+    }
+
+    #[test]
+    fn test_parse_reader_cuts_input_without_blank_lines() {
+        use std::fmt::Write as _;
+
+        let input = (0..500).fold(String::new(), |mut input, index| {
+            let _ = writeln!(input, "https://example.com/{index}.pdf");
+            input
+        });
+
+        let streamed = parse_reader_with_chunk_sizes(input.as_bytes(), 64, 256);
+
+        assert_eq!(streamed.urls().count(), 500);
+        assert_eq!(item_tuples(&streamed), item_tuples(&parse_input(&input)));
+    }
+
+    #[test]
+    fn test_parse_reader_records_read_error_and_keeps_earlier_items() {
+        let mut bytes = b"https://example.com/a.pdf\n\n".to_vec();
+        bytes.extend_from_slice(&[0xff, 0xfe, b'\n']);
+
+        let result = parse_reader(bytes.as_slice());
+
+        assert_eq!(result.urls().count(), 1);
+        assert_eq!(result.skipped_count(), 1);
+        assert!(result.skipped[0].starts_with("input read error:"));
+    }
+
+    #[test]
+    fn test_parse_input_routes_endnote_xml_without_line_extraction() {
+        let input = r#"<?xml version="1.0" encoding="UTF-8"?><xml><records><record>
+<titles><title><style face="normal">Routed Paper</style></title></titles>
+<electronic-resource-num><style face="normal">10.1234/endnote</style></electronic-resource-num>
+<urls><related-urls><url><style face="normal">https://example.com/landing</style></url></related-urls></urls>
+</record><record><titles><title>Bad</record></records></xml>"#;
+        let result = parse_input(input);
+
+        assert_eq!(result.dois().count(), 1);
+        assert_eq!(result.dois().next().unwrap().value, "10.1234/endnote");
+        assert_eq!(
+            result.urls().count(),
+            0,
+            "URL inside a DOI-bearing record must not be extracted separately"
+        );
+        assert_eq!(result.references().count(), 1);
+        assert_eq!(result.skipped_count(), 1);
+    }
+
+    #[test]
+    fn test_parse_reader_routes_endnote_xml_as_whole_document() {
+        let input = r#"<?xml version="1.0" encoding="UTF-8"?><xml><records><record>
+<electronic-resource-num><style face="normal">10.1234/endnote</style></electronic-resource-num>
+<urls><related-urls><url><style face="normal">https://example.com/landing</style></url></related-urls></urls>
+</record>
+
+<record><urls><related-urls><url>https://example.com/second</url></related-urls></urls></record></records></xml>"#;
+
+        let streamed = parse_reader_with_chunk_sizes(input.as_bytes(), 16, 64);
+
+        assert_eq!(item_tuples(&streamed), item_tuples(&parse_input(input)));
+        assert_eq!(streamed.dois().count(), 1);
+    }
 }
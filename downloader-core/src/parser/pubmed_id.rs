@@ -0,0 +1,200 @@
+//! `PubMed` identifier (PMID / PMCID) detection from text input.
+//!
+//! PMIDs need an explicit `PMID` label: a bare run of digits inside a
+//! reference is far more often a year, page range, or article number than a
+//! record ID. PMC IDs carry their own `PMC` prefix, so `PMC1234567` is
+//! recognized anywhere, as is a `PMCID:`-labelled one.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+use url::Url;
+
+use super::input::{InputType, ParsedItem};
+
+/// Shared PMC bare-ID pattern used by the parser and `PubMedResolver`.
+pub const BARE_PMC_ID_PATTERN: &str = r"(?i)^PMC\d{4,}$";
+
+/// Normalized prefix of extracted PMIDs (`pmid:12345678`).
+pub const PMID_PREFIX: &str = "pmid:";
+
+/// Normalized prefix of extracted PMC IDs (`pmcid:PMC1234567`).
+pub const PMCID_PREFIX: &str = "pmcid:";
+
+/// Matches a labelled PMID (`PMID: 31452104`, `pmid 31452104`).  A separator
+/// is required, so `PMID12345678` is not matched.  Capture group 1 is the number.
+#[allow(clippy::expect_used)]
+static PMID_PREFIX_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\bPMID[:\s]\s*(\d{1,9})\b").expect("PMID prefix regex valid")
+});
+
+/// Matches a labelled PMC ID (`PMCID: PMC6716437`, `pmcid:6716437`).  Capture
+/// group 1 is the numeric part.
+#[allow(clippy::expect_used)]
+static PMCID_PREFIX_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\bPMCID[:\s]\s*(?:PMC)?(\d{4,})\b").expect("PMCID prefix regex valid")
+});
+
+/// Matches a whole PMC ID, e.g. one URL path segment.
+#[allow(clippy::expect_used)]
+static BARE_PMC_ID_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(BARE_PMC_ID_PATTERN).expect("PMC ID line regex valid"));
+
+/// Matches a PMC ID on its own (`PMC6716437`).  Capture group 1 is the numeric part.
+#[allow(clippy::expect_used)]
+static PMC_ID_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\bPMC(\d{4,})\b").expect("PMC ID regex valid"));
+
+/// Extracts PMIDs and PMC IDs from text input, one pass per line.
+///
+/// Items have [`InputType::Unknown`], the matched text as `raw`, and a
+/// normalized `value` of `pmid:<digits>` or `pmcid:PMC<digits>`, which
+/// `PubMedResolver` accepts. Within a line items follow their position;
+/// duplicates are not removed here. Callers should pass text with URLs
+/// already removed, since a PMC ID inside a link is part of the link.
+///
+/// # Examples
+///
+/// ```
+/// use downloader_core::parser::extract_pubmed_ids;
+///
+/// let items = extract_pubmed_ids("Smith J (2019). A study. 12:345. PMID: 31452104; PMC6716437");
+/// let values: Vec<_> = items.iter().map(|item| item.value.as_str()).collect();
+/// assert_eq!(values, ["pmid:31452104", "pmcid:PMC6716437"]);
+/// ```
+#[must_use]
+pub fn extract_pubmed_ids(input: &str) -> Vec<ParsedItem> {
+    let mut items = Vec::new();
+    for line in input.lines() {
+        let mut found: Vec<(usize, usize, ParsedItem)> = Vec::new();
+        for caps in PMID_PREFIX_RE.captures_iter(line) {
+            if let (Some(raw), Some(digits)) = (caps.get(0), caps.get(1)) {
+                let value = format!("{PMID_PREFIX}{}", digits.as_str());
+                found.push((raw.start(), raw.end(), pubmed_item(raw.as_str(), value)));
+            }
+        }
+        for caps in PMCID_PREFIX_RE
+            .captures_iter(line)
+            .chain(PMC_ID_RE.captures_iter(line))
+        {
+            let (Some(raw), Some(digits)) = (caps.get(0), caps.get(1)) else {
+                continue;
+            };
+            // `PMCID: PMC6716437` also contains a bare `PMC6716437`.
+            if found
+                .iter()
+                .any(|(start, end, _)| raw.start() < *end && *start < raw.end())
+            {
+                continue;
+            }
+            let value = format!("{PMCID_PREFIX}PMC{}", digits.as_str());
+            found.push((raw.start(), raw.end(), pubmed_item(raw.as_str(), value)));
+        }
+        found.sort_by_key(|(start, _, _)| *start);
+        items.extend(found.into_iter().map(|(_, _, item)| item));
+    }
+    items
+}
+
+/// Normalized `PubMed` IDs a `PubMed` or PMC link points at, for de-duplicating
+/// against IDs written next to it.
+pub(super) fn pubmed_ids_in_url(url: &str) -> Vec<String> {
+    let Ok(parsed) = Url::parse(url) else {
+        return Vec::new();
+    };
+    let host = parsed.host_str().unwrap_or_default().to_ascii_lowercase();
+    let segments: Vec<&str> = parsed
+        .path_segments()
+        .map(Iterator::collect)
+        .unwrap_or_default();
+
+    let mut ids = Vec::new();
+    if host == "pubmed.ncbi.nlm.nih.gov"
+        && let Some(pmid) = segments.first()
+        && !pmid.is_empty()
+        && pmid.len() <= 9
+        && pmid.bytes().all(|byte| byte.is_ascii_digit())
+    {
+        ids.push(format!("{PMID_PREFIX}{pmid}"));
+    }
+    if host.ends_with("ncbi.nlm.nih.gov") {
+        ids.extend(
+            segments
+                .iter()
+                .filter(|segment| BARE_PMC_ID_RE.is_match(segment))
+                .map(|segment| format!("{PMCID_PREFIX}{}", segment.to_ascii_uppercase())),
+        );
+    }
+    ids
+}
+
+fn pubmed_item(raw: &str, value: String) -> ParsedItem {
+    ParsedItem::new(raw, InputType::Unknown, value)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn values(input: &str) -> Vec<String> {
+        extract_pubmed_ids(input)
+            .into_iter()
+            .map(|item| item.value)
+            .collect()
+    }
+
+    #[test]
+    fn test_extract_pubmed_ids_prefixed_forms() {
+        assert_eq!(
+            values("PMID: 31452104\npmid 99999\nPMCID: PMC6716437\npmcid:6716437"),
+            [
+                "pmid:31452104",
+                "pmid:99999",
+                "pmcid:PMC6716437",
+                "pmcid:PMC6716437",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_pubmed_ids_bare_pmc_id() {
+        let items = extract_pubmed_ids("pmc6716437");
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].raw, "pmc6716437");
+        assert_eq!(items[0].value, "pmcid:PMC6716437");
+        assert!(values("PMID12345678").is_empty(), "a separator is required");
+        assert!(values("PMC123").is_empty(), "too short for a PMC ID");
+    }
+
+    #[test]
+    fn test_extract_pubmed_ids_in_reference_ignores_unlabelled_numbers() {
+        let items = extract_pubmed_ids(
+            "Smith J, Doe A (20190101). Trial results. Lancet 12345678:1021-1030. PMID: 31452104. PMCID: PMC6716437",
+        );
+        let found: Vec<(&str, &str)> = items
+            .iter()
+            .map(|item| (item.raw.as_str(), item.value.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("PMID: 31452104", "pmid:31452104"),
+                ("PMCID: PMC6716437", "pmcid:PMC6716437"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pubmed_ids_in_url() {
+        assert_eq!(
+            pubmed_ids_in_url("https://pubmed.ncbi.nlm.nih.gov/31452104/"),
+            ["pmid:31452104"]
+        );
+        assert_eq!(
+            pubmed_ids_in_url("https://www.ncbi.nlm.nih.gov/pmc/articles/PMC6716437/"),
+            ["pmcid:PMC6716437"]
+        );
+        assert!(pubmed_ids_in_url("https://example.com/PMC6716437/").is_empty());
+    }
+}
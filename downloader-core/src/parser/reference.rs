@@ -78,26 +78,73 @@ pub struct ConfidenceFactors {
     pub author_count: usize,
 }
 
+/// Score weight, in hundredths, of an extracted author.
+const AUTHORS_WEIGHT: u32 = 30;
+/// Score weight, in hundredths, of an extracted year.
+const YEAR_WEIGHT: u32 = 25;
+/// Score weight, in hundredths, of an extracted title.
+const TITLE_WEIGHT: u32 = 35;
+/// Score weight, in hundredths, of each author after the first (at most two count).
+const EXTRA_AUTHOR_WEIGHT: u32 = 5;
+
+/// Lowest score rated [`Confidence::High`]: authors, year, and title all found.
+pub const HIGH_CONFIDENCE_SCORE: f64 = 0.9;
+/// Lowest score rated [`Confidence::Medium`]: any two of authors/year/title found.
+pub const MEDIUM_CONFIDENCE_SCORE: f64 = 0.5;
+
 impl ConfidenceFactors {
-    /// Returns the derived confidence level for these factors.
+    /// Returns a confidence score from 0.0 to 1.0 for ranking references.
+    ///
+    /// Weights: `has_authors` 0.30, `has_year` 0.25, `has_title` 0.35, and
+    /// 0.05 for each author beyond the first, counting at most two (only when
+    /// `has_authors` is set). Any two of authors/year/title score at least
+    /// 0.55 and a single one at most 0.40, so the thresholds behind
+    /// [`Self::level`] reproduce the present-field count. Detecting another
+    /// factor or author never lowers the score.
+    #[must_use]
+    pub fn score(self) -> f64 {
+        let mut points = 0;
+        if self.has_authors {
+            let extra_authors = match self.author_count {
+                0 | 1 => 0,
+                2 => 1,
+                _ => 2,
+            };
+            points += AUTHORS_WEIGHT + EXTRA_AUTHOR_WEIGHT * extra_authors;
+        }
+        if self.has_year {
+            points += YEAR_WEIGHT;
+        }
+        if self.has_title {
+            points += TITLE_WEIGHT;
+        }
+        f64::from(points) / 100.0
+    }
+
+    /// Returns the derived confidence level for these factors, from
+    /// [`Self::score`] against [`HIGH_CONFIDENCE_SCORE`] and
+    /// [`MEDIUM_CONFIDENCE_SCORE`].
     #[must_use]
     pub fn level(self) -> Confidence {
-        let present = usize::from(self.has_authors)
-            + usize::from(self.has_year)
-            + usize::from(self.has_title);
-        match present {
-            3 => Confidence::High,
-            2 => Confidence::Medium,
-            _ => Confidence::Low,
+        let score = self.score();
+        if score >= HIGH_CONFIDENCE_SCORE {
+            Confidence::High
+        } else if score >= MEDIUM_CONFIDENCE_SCORE {
+            Confidence::Medium
+        } else {
+            Confidence::Low
         }
     }
 }
 
 /// Stable confidence payload for downstream persistence.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ReferenceConfidence {
-    /// Computed confidence level.
+    /// Computed confidence level, derived from `score`.
     pub level: Confidence,
+    /// Confidence score from 0.0 to 1.0 (see [`ConfidenceFactors::score`]);
+    /// lower scores are the references most in need of checking.
+    pub score: f64,
     /// Deterministic confidence factors.
     pub factors: ConfidenceFactors,
 }
@@ -146,6 +193,7 @@ impl ReferenceMetadata {
     pub fn confidence_details(&self) -> ReferenceConfidence {
         ReferenceConfidence {
             level: self.confidence,
+            score: self.confidence_factors.score(),
             factors: self.confidence_factors,
         }
     }
@@ -663,6 +711,82 @@ mod tests {
         );
     }
 
+    fn all_factor_combinations() -> Vec<ConfidenceFactors> {
+        let mut combinations = Vec::new();
+        for bits in 0..8_u8 {
+            for author_count in 0..=4 {
+                let has_authors = bits & 1 != 0;
+                combinations.push(ConfidenceFactors {
+                    has_authors,
+                    has_year: bits & 2 != 0,
+                    has_title: bits & 4 != 0,
+                    author_count: if has_authors { author_count.max(1) } else { 0 },
+                });
+            }
+        }
+        combinations
+    }
+
+    #[test]
+    fn test_confidence_score_never_drops_when_a_factor_is_added() {
+        for factors in all_factor_combinations() {
+            let score = factors.score();
+            assert!((0.0..=1.0).contains(&score), "{factors:?} scored {score}");
+
+            let mut upgrades = vec![
+                ConfidenceFactors {
+                    has_year: true,
+                    ..factors
+                },
+                ConfidenceFactors {
+                    has_title: true,
+                    ..factors
+                },
+                ConfidenceFactors {
+                    has_authors: true,
+                    author_count: factors.author_count.max(1),
+                    ..factors
+                },
+            ];
+            if factors.has_authors {
+                upgrades.push(ConfidenceFactors {
+                    author_count: factors.author_count + 1,
+                    ..factors
+                });
+            }
+            for upgraded in upgrades {
+                assert!(
+                    upgraded.score() >= score,
+                    "{upgraded:?} scored below {factors:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_confidence_level_thresholds_match_present_field_count() {
+        for factors in all_factor_combinations() {
+            let present = usize::from(factors.has_authors)
+                + usize::from(factors.has_year)
+                + usize::from(factors.has_title);
+            let expected = match present {
+                3 => Confidence::High,
+                2 => Confidence::Medium,
+                _ => Confidence::Low,
+            };
+            assert_eq!(factors.level(), expected, "{factors:?}");
+        }
+
+        let full = ConfidenceFactors {
+            has_authors: true,
+            has_year: true,
+            has_title: true,
+            author_count: 3,
+        };
+        assert!((full.score() - 1.0).abs() < f64::EPSILON);
+        assert!(ConfidenceFactors::default().score().abs() < f64::EPSILON);
+    }
+
     #[test]
     fn test_parse_reference_metadata_logs_structured_confidence_fields() {
         let captured = Arc::new(Mutex::new(Vec::new()));
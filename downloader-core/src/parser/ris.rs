@@ -79,6 +79,20 @@ impl RisParseResult {
     }
 }
 
+/// Returns true when `input` looks like a RIS export.
+///
+/// The first non-blank line (after an optional BOM) must be a `TY` tag, which
+/// every RIS record opens with. Used to route files without a `.ris` extension.
+#[must_use]
+pub fn looks_like_ris(input: &str) -> bool {
+    input
+        .trim_start_matches('\u{feff}')
+        .lines()
+        .map(str::trim_end)
+        .find(|line| !line.trim().is_empty())
+        .is_some_and(|line| is_tag(line, "TY"))
+}
+
 /// Parses RIS format content and extracts entries with DOIs, URLs, and metadata.
 ///
 /// Empty input returns an empty result (not an error). Records that cannot be
@@ -349,6 +363,14 @@ mod tests {
     use super::*;
     use crate::parser::InputType;
 
+    #[test]
+    fn test_looks_like_ris_requires_leading_ty_tag() {
+        assert!(looks_like_ris("\u{feff}\n\nTY  - JOUR\nER  - \n"));
+        assert!(!looks_like_ris("https://example.com/TY  - JOUR\n"));
+        assert!(!looks_like_ris("@article{k, title={TY  - x}}"));
+        assert!(!looks_like_ris(""));
+    }
+
     // ==================== AC: Valid .ris file ====================
 
     #[test]
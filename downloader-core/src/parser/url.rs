@@ -1,23 +1,67 @@
 //! URL extraction and validation from text input.
 
+use std::collections::HashSet;
 use std::sync::LazyLock;
 
 use regex::Regex;
 use tracing::{debug, trace};
 use url::Url;
 
+use super::doi::overlaps;
 use super::error::{MAX_FILE_EXTENSION_LEN, MAX_URL_LENGTH, MIN_FILE_EXTENSION_LEN, ParseError};
 use super::input::ParsedItem;
+use crate::download::is_supported_scheme;
+
+/// Schemes recognised in free text. `ftp` and `file` are only matched when
+/// this build can download them, so http/https-only builds ignore them as before.
+const URL_SCHEME_PATTERN: &str = if cfg!(all(feature = "ftp", feature = "file-urls")) {
+    r"(?:https?|\bftp|\bfile)"
+} else if cfg!(feature = "ftp") {
+    r"(?:https?|\bftp)"
+} else if cfg!(feature = "file-urls") {
+    r"(?:https?|\bfile)"
+} else {
+    "https?"
+};
 
 /// Regex pattern for finding URLs in text.
-/// Matches http:// and https:// URLs, capturing until whitespace or common delimiters.
+/// Matches http:// and https:// URLs (plus enabled `ftp://`/`file://`), capturing
+/// until whitespace or common delimiters.
 #[allow(clippy::expect_used)]
 static URL_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
-    // Match http:// or https:// followed by non-whitespace, non-angle-bracket, non-quote chars
+    // Match the scheme followed by non-whitespace, non-angle-bracket, non-quote chars
     // This handles URLs embedded in text, HTML, markdown, etc.
-    Regex::new(r#"https?://[^\s<>"'\]]+"#).expect("URL regex is valid") // Static pattern, safe to panic
+    Regex::new(&format!(r#"{URL_SCHEME_PATTERN}://[^\s<>"'\]]+"#)).expect("URL regex is valid") // Static pattern, safe to panic
 });
 
+/// Opening `[text](` of a Markdown link; the target is scanned separately so
+/// URLs with balanced parentheses survive.
+#[allow(clippy::expect_used)]
+static MARKDOWN_LINK_START: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\[([^\[\]\n]*)\]\(").expect("markdown link regex is valid") // Static pattern, safe to panic
+});
+
+/// HTML anchor with a quoted `href`, capturing the href and inner HTML.
+#[allow(clippy::expect_used)]
+static HTML_ANCHOR_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?is)<a\s[^>]*?\bhref\s*=\s*(?:"([^"]*)"|'([^']*)')[^>]*>(.*?)</a\s*>"#)
+        .expect("HTML anchor regex is valid") // Static pattern, safe to panic
+});
+
+/// Angle-bracketed autolink such as `<https://example.com>`.
+#[allow(clippy::expect_used)]
+static AUTOLINK_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"<(https?://[^\s<>]+)>").expect("autolink regex is valid") // Static pattern, safe to panic
+});
+
+/// Any HTML tag, stripped from anchor text.
+#[allow(clippy::expect_used)]
+static HTML_TAG_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<[^>]*>").expect("HTML tag regex is valid")); // Static pattern, safe to panic
+
+/// Host of the Handle System's public HTTP proxy.
+const HANDLE_PROXY_HOST: &str = "hdl.handle.net";
+
 /// Result type for URL extraction operations.
 pub type UrlExtractionResult = Result<ParsedItem, ParseError>;
 
@@ -27,6 +71,11 @@ pub type UrlExtractionResult = Result<ParsedItem, ParseError>;
 /// and returns a list of results. Each URL is individually validated, so some
 /// may succeed while others fail.
 ///
+/// Markdown links (`[text](url)`), HTML anchors (`<a href="url">text</a>`),
+/// and autolinks (`<url>`) are unwrapped first. Their `raw` is the whole link
+/// markup, and non-empty link text becomes the item's `title_hint`. A bare URL
+/// repeating a link target is dropped, so the linked item keeps its hint.
+///
 /// # Arguments
 ///
 /// * `input` - Text input that may contain URLs mixed with other content
@@ -49,9 +98,35 @@ pub type UrlExtractionResult = Result<ParsedItem, ParseError>;
 #[tracing::instrument(skip(input), fields(input_len = input.len()))]
 #[must_use]
 pub fn extract_urls(input: &str) -> Vec<UrlExtractionResult> {
-    let mut results = Vec::new();
+    // (start offset, result) so link markup and bare URLs keep input order.
+    let mut positioned: Vec<(usize, UrlExtractionResult)> = Vec::new();
+    let mut seen_ranges: Vec<(usize, usize)> = Vec::new();
+    let mut link_targets: HashSet<String> = HashSet::new();
+
+    for link in find_link_markup(input) {
+        seen_ranges.push((link.start, link.end));
+        let raw = &input[link.start..link.end];
+        trace!(url = %link.url, "found link markup");
+
+        let result = validate_url(&strip_backslash_escapes(&link.url)).map(|validated| {
+            debug!(url = %validated, "URL validated");
+            link_targets.insert(validated.clone());
+            let item = ParsedItem::url(raw, validated);
+            match link.text {
+                Some(text) => item.with_title_hint(text),
+                None => item,
+            }
+        });
+        if let Err(e) = &result {
+            debug!(url = %link.url, error = %e, "URL validation failed");
+        }
+        positioned.push((link.start, result));
+    }
 
     for url_match in URL_PATTERN.find_iter(input) {
+        if overlaps(&seen_ranges, url_match.start(), url_match.end()) {
+            continue;
+        }
         let raw_url = url_match.as_str();
         // Clean up trailing punctuation that might have been captured
         let cleaned = clean_url_trailing(raw_url);
@@ -59,18 +134,156 @@ pub fn extract_urls(input: &str) -> Vec<UrlExtractionResult> {
         trace!(url = %sanitized, "found URL candidate");
 
         match validate_url(&sanitized) {
+            Ok(validated) if link_targets.contains(&validated) => {
+                trace!(url = %validated, "skipping bare URL already found as a link target");
+            }
             Ok(validated) => {
                 debug!(url = %validated, "URL validated");
-                results.push(Ok(ParsedItem::url(raw_url, validated)));
+                positioned.push((url_match.start(), Ok(ParsedItem::url(raw_url, validated))));
             }
             Err(e) => {
                 debug!(url = %sanitized, error = %e, "URL validation failed");
-                results.push(Err(e));
+                positioned.push((url_match.start(), Err(e)));
             }
         }
     }
 
-    results
+    positioned.sort_by_key(|(start, _)| *start);
+    positioned.into_iter().map(|(_, result)| result).collect()
+}
+
+/// A URL found inside Markdown or HTML link markup.
+struct LinkMarkup {
+    /// Byte range of the whole markup in the input.
+    start: usize,
+    end: usize,
+    url: String,
+    /// Visible link text, if any.
+    text: Option<String>,
+}
+
+/// Finds Markdown links, HTML anchors, and autolinks whose target is an
+/// http(s) URL. Later patterns skip ranges claimed by earlier ones.
+fn find_link_markup(input: &str) -> Vec<LinkMarkup> {
+    let mut links: Vec<LinkMarkup> = Vec::new();
+    let mut seen_ranges: Vec<(usize, usize)> = Vec::new();
+    let mut push = |link: LinkMarkup, links: &mut Vec<LinkMarkup>| {
+        if !overlaps(&seen_ranges, link.start, link.end) {
+            seen_ranges.push((link.start, link.end));
+            links.push(link);
+        }
+    };
+
+    for caps in MARKDOWN_LINK_START.captures_iter(input) {
+        let (Some(whole), Some(text)) = (caps.get(0), caps.get(1)) else {
+            continue;
+        };
+        let target_start = whole.end();
+        let Some(target_len) = balanced_paren_len(&input[target_start..]) else {
+            continue;
+        };
+        // Targets may be `<url>` and may carry a `"title"` after whitespace.
+        let target = input[target_start..target_start + target_len].trim();
+        let url = target
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .trim_start_matches('<')
+            .trim_end_matches('>');
+        if !is_http_url(url) {
+            continue;
+        }
+        push(
+            LinkMarkup {
+                start: whole.start(),
+                end: target_start + target_len + 1,
+                url: url.to_string(),
+                text: link_text(text.as_str()),
+            },
+            &mut links,
+        );
+    }
+
+    for caps in HTML_ANCHOR_PATTERN.captures_iter(input) {
+        let Some(whole) = caps.get(0) else {
+            continue;
+        };
+        let href = caps
+            .get(1)
+            .or_else(|| caps.get(2))
+            .map_or("", |m| m.as_str());
+        let url = decode_html_entities(href.trim());
+        if !is_http_url(&url) {
+            continue;
+        }
+        let inner = caps.get(3).map_or("", |m| m.as_str());
+        push(
+            LinkMarkup {
+                start: whole.start(),
+                end: whole.end(),
+                url,
+                text: link_text(&decode_html_entities(
+                    &HTML_TAG_PATTERN.replace_all(inner, " "),
+                )),
+            },
+            &mut links,
+        );
+    }
+
+    for caps in AUTOLINK_PATTERN.captures_iter(input) {
+        let (Some(whole), Some(url)) = (caps.get(0), caps.get(1)) else {
+            continue;
+        };
+        push(
+            LinkMarkup {
+                start: whole.start(),
+                end: whole.end(),
+                url: url.as_str().to_string(),
+                text: None,
+            },
+            &mut links,
+        );
+    }
+
+    links
+}
+
+/// Returns the length of `s` up to the `)` closing an already-open paren,
+/// or `None` if the line ends first.
+fn balanced_paren_len(s: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (index, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some(index),
+            ')' => depth -= 1,
+            '\n' => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn is_http_url(candidate: &str) -> bool {
+    let lower = candidate.to_ascii_lowercase();
+    lower.starts_with("http://") || lower.starts_with("https://")
+}
+
+/// Collapses whitespace in link text; empty text and text that merely repeats
+/// a URL carry no title.
+fn link_text(text: &str) -> Option<String> {
+    let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!collapsed.is_empty() && !is_http_url(&collapsed)).then_some(collapsed)
+}
+
+/// Decodes the handful of HTML entities common in copied hrefs and link text.
+fn decode_html_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
 }
 
 /// Returns true if the string ends with a segment that looks like a file extension (after last dot).
@@ -168,8 +381,8 @@ pub(crate) fn clean_url_trailing(url: &str) -> &str {
 /// # Validation rules:
 /// - Must not exceed `MAX_URL_LENGTH` (2000 chars)
 /// - Must be parseable by the `url` crate
-/// - Must use http or https scheme (no ftp, file, etc.)
-/// - Must have a host (domain or IP)
+/// - Must use a scheme this build can download (see [`is_supported_scheme`])
+/// - Must have a host (domain or IP), except for `file://` URLs
 fn validate_url(raw: &str) -> Result<String, ParseError> {
     // Check URL length first (prevents memory issues with very long URLs)
     if raw.len() > MAX_URL_LENGTH {
@@ -179,19 +392,32 @@ fn validate_url(raw: &str) -> Result<String, ParseError> {
     // Parse with url crate for full validation
     let parsed = Url::parse(raw).map_err(|e| ParseError::malformed(raw, &e.to_string()))?;
 
-    // Only allow http and https
-    match parsed.scheme() {
-        "http" | "https" => {}
-        scheme => return Err(ParseError::unsupported_scheme(raw, scheme)),
+    // Only allow schemes the download layer can fetch
+    if !is_supported_scheme(parsed.scheme()) {
+        return Err(ParseError::unsupported_scheme(raw, parsed.scheme()));
     }
 
-    // Must have a host
-    if parsed.host().is_none() {
+    // Must have a host (local files have none)
+    if parsed.scheme() != "file" && parsed.host().is_none() {
         return Err(ParseError::no_host(raw));
     }
 
     // Return the parsed URL as string (normalized)
-    Ok(parsed.to_string())
+    Ok(normalize_handle_proxy_url(parsed).to_string())
+}
+
+/// Rewrites Handle System proxy links to the canonical `https://hdl.handle.net/<handle>`
+/// form so `http://` and `https://` copies of the same handle compare equal.
+fn normalize_handle_proxy_url(mut parsed: Url) -> Url {
+    if parsed
+        .host_str()
+        .is_some_and(|host| host.eq_ignore_ascii_case(HANDLE_PROXY_HOST))
+        && parsed.scheme() == "http"
+    {
+        // http -> https is always a permitted scheme change.
+        let _ = parsed.set_scheme("https");
+    }
+    parsed
 }
 
 #[cfg(test)]
@@ -238,6 +464,7 @@ mod tests {
     // ==================== AC2: Invalid URL Reporting ====================
 
     #[test]
+    #[cfg(not(feature = "ftp"))]
     fn test_validate_url_rejects_ftp() {
         let result = validate_url("ftp://files.example.com/file.pdf");
         assert!(result.is_err());
@@ -254,11 +481,30 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(feature = "file-urls"))]
     fn test_validate_url_rejects_file() {
         let result = validate_url("file:///home/user/doc.pdf");
         assert!(result.is_err());
     }
 
+    #[test]
+    #[cfg(all(feature = "ftp", feature = "file-urls"))]
+    fn test_extract_urls_accepts_ftp_and_file_sources() {
+        let input = "Data: ftp://ftp.example.org/pub/set.csv and file:///srv/papers/a.pdf; \
+                     not sftp://host/x";
+        let values: Vec<_> = extract_urls(input)
+            .into_iter()
+            .map(|result| result.unwrap().value)
+            .collect();
+        assert_eq!(
+            values,
+            [
+                "ftp://ftp.example.org/pub/set.csv",
+                "file:///srv/papers/a.pdf"
+            ]
+        );
+    }
+
     #[test]
     fn test_validate_url_rejects_mailto() {
         let result = validate_url("mailto:user@example.com");
@@ -340,6 +586,83 @@ mod tests {
         assert_eq!(urls[2], "https://third.com/");
     }
 
+    // ==================== Link Markup ====================
+
+    #[test]
+    fn test_extract_urls_markdown_link_uses_text_as_title_hint() {
+        let input = "- [Attention Is All You Need](https://arxiv.org/pdf/1706.03762.pdf).";
+        let results = extract_urls(input);
+        assert_eq!(results.len(), 1);
+        let item = results[0].as_ref().unwrap();
+        assert_eq!(item.value, "https://arxiv.org/pdf/1706.03762.pdf");
+        assert_eq!(
+            item.raw,
+            "[Attention Is All You Need](https://arxiv.org/pdf/1706.03762.pdf)"
+        );
+        assert_eq!(
+            item.title_hint.as_deref(),
+            Some("Attention Is All You Need")
+        );
+    }
+
+    #[test]
+    fn test_extract_urls_markdown_link_and_bare_repeat_yield_one_item() {
+        let input = "[Paper](https://x.org/a.pdf) and https://x.org/a.pdf";
+        let results = extract_urls(input);
+        assert_eq!(results.len(), 1);
+        let item = results[0].as_ref().unwrap();
+        assert_eq!(item.value, "https://x.org/a.pdf");
+        assert_eq!(item.title_hint.as_deref(), Some("Paper"));
+    }
+
+    #[test]
+    fn test_extract_urls_markdown_link_keeps_balanced_parens_and_drops_title() {
+        let input = r#"[Wiki](https://en.wikipedia.org/wiki/Rust_(language) "tooltip")"#;
+        let results = extract_urls(input);
+        assert_eq!(results.len(), 1);
+        let item = results[0].as_ref().unwrap();
+        assert_eq!(item.value, "https://en.wikipedia.org/wiki/Rust_(language)");
+        assert_eq!(item.title_hint.as_deref(), Some("Wiki"));
+    }
+
+    #[test]
+    fn test_extract_urls_html_anchor_decodes_href_and_text() {
+        let input = r#"<p><a class="x" href="https://example.com/get?id=1&amp;fmt=pdf"><b>Deep</b> Learning &amp; You</a></p>"#;
+        let results = extract_urls(input);
+        assert_eq!(results.len(), 1);
+        let item = results[0].as_ref().unwrap();
+        assert_eq!(item.value, "https://example.com/get?id=1&fmt=pdf");
+        assert_eq!(item.title_hint.as_deref(), Some("Deep Learning & You"));
+    }
+
+    #[test]
+    fn test_extract_urls_autolink_is_unwrapped_without_hint() {
+        let results = extract_urls("See <https://example.com/paper.pdf>.");
+        assert_eq!(results.len(), 1);
+        let item = results[0].as_ref().unwrap();
+        assert_eq!(item.value, "https://example.com/paper.pdf");
+        assert_eq!(item.raw, "<https://example.com/paper.pdf>");
+        assert!(item.title_hint.is_none());
+    }
+
+    #[test]
+    fn test_extract_urls_link_markup_not_double_extracted() {
+        let input =
+            "[https://example.com/a.pdf](https://example.com/a.pdf) https://example.com/b.pdf";
+        let results = extract_urls(input);
+        let urls: Vec<_> = results
+            .iter()
+            .map(|r| r.as_ref().unwrap().value.as_str())
+            .collect();
+        assert_eq!(
+            urls,
+            vec!["https://example.com/a.pdf", "https://example.com/b.pdf"]
+        );
+        // Link text that merely repeats a URL is not a title.
+        assert!(results[0].as_ref().unwrap().title_hint.is_none());
+        assert!(results[1].as_ref().unwrap().title_hint.is_none());
+    }
+
     // ==================== Edge Cases ====================
 
     #[test]
@@ -581,6 +904,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_extract_urls_normalizes_handle_proxy_links_to_https() {
+        let results = extract_urls("See http://hdl.handle.net/2027/mdp.39015012345678 for scans");
+        let item = results[0].as_ref().unwrap();
+        assert_eq!(item.value, "https://hdl.handle.net/2027/mdp.39015012345678");
+        assert_eq!(item.raw, "http://hdl.handle.net/2027/mdp.39015012345678");
+    }
+
     #[test]
     fn test_clean_url_trailing_strips_unmatched_closing_brackets() {
         // Trailing ) with no matching ( is stripped
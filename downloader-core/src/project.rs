@@ -1,20 +1,27 @@
 //! Project folder utilities shared between the CLI and desktop app.
 //!
 //! Provides path sanitisation, output directory resolution, and helpers for
-//! generating per-project artefacts (index.md, download.log, JSON-LD sidecars).
+//! generating per-project artefacts (index.md, topics.md, download.log,
+//! manifest.json, JSON-LD sidecars).
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fmt::Write as _;
 use std::fs;
 use std::hash::BuildHasher;
+use std::io;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
 
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use tracing::{info, warn};
 
 use crate::{
     DownloadAttempt, DownloadAttemptQuery, DownloadAttemptStatus, Queue, QueueError, QueueItem,
-    QueueStatus, atomic_write::atomic_write, generate_sidecar, normalize_topics,
+    QueueStatus, SidecarFormat,
+    atomic_write::{atomic_write, atomic_write_json},
+    generate_citation_key, generate_sidecar_with_format, match_custom_topics, normalize_topics,
+    unique_citation_key,
 };
 
 // ---------------------------------------------------------------------------
@@ -27,9 +34,21 @@ pub const MAX_PROJECT_FOLDER_CHARS: usize = 80;
 /// Maximum nesting depth for project path segments (e.g. "A/B/C" = 3).
 pub const MAX_PROJECT_SEGMENTS: usize = 10;
 
-/// Page size for paged history queries in [`append_project_download_log`].
+/// Page size for paged history queries in [`query_session_attempts`].
 pub const PROJECT_LOG_QUERY_PAGE_SIZE: usize = 10_000;
 
+/// File name of the machine-readable project manifest.
+pub const PROJECT_MANIFEST_FILENAME: &str = "manifest.json";
+
+/// Schema version written to [`ProjectManifest::version`].
+pub const PROJECT_MANIFEST_VERSION: u32 = 1;
+
+/// File name of the topic-grouped project index.
+pub const PROJECT_TOPICS_INDEX_FILENAME: &str = "topics.md";
+
+/// Heading for items without any topic in [`render_project_topics_index`].
+pub const UNCATEGORIZED_TOPIC_HEADING: &str = "Uncategorized";
+
 // Process-lifetime counter: ensures session labels are unique even when two
 // sessions complete within the same wall-clock second.
 static SESSION_SEQ: AtomicU64 = AtomicU64::new(0);
@@ -302,12 +321,42 @@ fn download_log_filename(attempt: &DownloadAttempt) -> String {
     attempt
         .file_path
         .as_deref()
-        .and_then(|path| Path::new(path).file_name().and_then(|name| name.to_str()))
-        .map(ToString::to_string)
+        .and_then(display_saved_path)
         .or_else(|| attempt.title.clone())
         .unwrap_or_else(|| "n/a".to_string())
 }
 
+/// Renders a saved path for `index.md` / `download.log`.
+///
+/// Relative paths (already made relative to the project folder, e.g.
+/// `2024/Smith_2024_Title.pdf` under a year layout) are shown whole with `/`
+/// separators; absolute paths fall back to the bare file name.
+fn display_saved_path(path: &str) -> Option<String> {
+    let path = Path::new(path);
+    if path.is_absolute() {
+        return path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map(ToString::to_string);
+    }
+    let parts: Vec<&str> = path
+        .components()
+        .filter_map(|component| match component {
+            std::path::Component::Normal(part) => part.to_str(),
+            _ => None,
+        })
+        .collect();
+    (!parts.is_empty()).then(|| parts.join("/"))
+}
+
+/// Rewrites `path` relative to `output_dir` when it lives underneath it.
+fn relative_to_output_dir(path: &str, output_dir: &Path) -> String {
+    Path::new(path).strip_prefix(output_dir).map_or_else(
+        |_| path.to_string(),
+        |relative| relative.to_string_lossy().to_string(),
+    )
+}
+
 fn download_log_source(attempt: &DownloadAttempt) -> &str {
     attempt
         .original_input
@@ -319,27 +368,23 @@ fn download_log_source(attempt: &DownloadAttempt) -> &str {
 // Project artefact generators
 // ---------------------------------------------------------------------------
 
-/// Appends a new session section to the project's `download.log` file.
-///
-/// Queries `queue` for all [`DownloadAttempt`]s belonging to the project derived from
-/// `output_dir`, filtering to rows with `id > history_start_id` (pass `None` for all rows).
+/// Returns every [`DownloadAttempt`] for `project_key` with `id > history_start_id`
+/// (pass `None` for all rows), oldest first, paging past the history query limit.
 ///
 /// # Errors
 ///
-/// Returns [`ProjectError::Queue`] on database errors or
-/// [`ProjectError::Io`] on filesystem errors.
-pub async fn append_project_download_log(
+/// Returns [`QueueError`] on database errors.
+pub async fn query_session_attempts(
     queue: &Queue,
-    output_dir: &Path,
+    project_key: &str,
     history_start_id: Option<i64>,
-) -> Result<(), ProjectError> {
-    let project_key = project_history_key(output_dir);
+) -> Result<Vec<DownloadAttempt>, QueueError> {
     let mut attempts = Vec::new();
     let mut page_count = 0usize;
     let mut before_id = None;
     loop {
         let query = DownloadAttemptQuery {
-            project: Some(project_key.clone()),
+            project: Some(project_key.to_string()),
             after_id: history_start_id,
             before_id,
             limit: PROJECT_LOG_QUERY_PAGE_SIZE,
@@ -354,11 +399,6 @@ pub async fn append_project_download_log(
         attempts.append(&mut page);
     }
 
-    if attempts.is_empty() {
-        return Ok(());
-    }
-    attempts.sort_by_key(|attempt| attempt.id);
-
     if page_count > 1 {
         info!(
             page_count,
@@ -367,6 +407,34 @@ pub async fn append_project_download_log(
             "Processed new history rows across multiple pages"
         );
     }
+    attempts.sort_by_key(|attempt| attempt.id);
+    Ok(attempts)
+}
+
+/// Appends a new session section to the project's `download.log` file.
+///
+/// Queries `queue` for all [`DownloadAttempt`]s belonging to the project derived from
+/// `output_dir`, filtering to rows with `id > history_start_id` (pass `None` for all rows).
+///
+/// # Errors
+///
+/// Returns [`ProjectError::Queue`] on database errors or
+/// [`ProjectError::Io`] on filesystem errors.
+pub async fn append_project_download_log(
+    queue: &Queue,
+    output_dir: &Path,
+    history_start_id: Option<i64>,
+) -> Result<(), ProjectError> {
+    let project_key = project_history_key(output_dir);
+    let mut attempts = query_session_attempts(queue, &project_key, history_start_id).await?;
+    if attempts.is_empty() {
+        return Ok(());
+    }
+    for attempt in &mut attempts {
+        if let Some(file_path) = attempt.file_path.as_mut() {
+            *file_path = relative_to_output_dir(file_path, output_dir);
+        }
+    }
 
     let session_label = make_session_label();
     let section = render_project_download_log_section(&session_label, &attempts);
@@ -388,7 +456,6 @@ pub async fn append_project_download_log(
     info!(
         path = %log_path.display(),
         entries = attempts.len(),
-        page_count,
         "Project download log updated"
     );
     Ok(())
@@ -461,6 +528,11 @@ pub async fn append_project_index<S: BuildHasher>(
     }
 
     new_items.sort_by_key(|item| item.id);
+    for item in &mut new_items {
+        if let Some(saved_path) = item.saved_path.as_mut() {
+            *saved_path = relative_to_output_dir(saved_path, output_dir);
+        }
+    }
     let session_label = make_session_label();
 
     let section = render_project_index_section(&session_label, &new_items);
@@ -486,13 +558,277 @@ pub async fn append_project_index<S: BuildHasher>(
     Ok(())
 }
 
-/// Generates JSON-LD sidecars for completed items not yet seen before this run.
+/// Rewrites the project's `topics.md`, grouping every completed item by topic.
+///
+/// Unlike `index.md`, which grows one session at a time, the topics index is
+/// regenerated from the whole project on each call. `custom_topics` (from
+/// `--topics-file`) collapse matching keywords into the custom topic, as
+/// [`match_custom_topics`] does. Nothing is written before the first item
+/// completes.
+///
+/// Returns the number of topic sections written (excluding "Uncategorized").
+///
+/// # Errors
+///
+/// Returns [`ProjectError::Queue`] on database errors or
+/// [`ProjectError::Io`] on filesystem errors.
+pub async fn write_project_topics_index(
+    queue: &Queue,
+    output_dir: &Path,
+    custom_topics: &[String],
+) -> Result<usize, ProjectError> {
+    let project_key = project_history_key(output_dir);
+    let mut items = queue
+        .list_by_status_in_project(QueueStatus::Completed, Some(&project_key))
+        .await?;
+    if items.is_empty() {
+        return Ok(0);
+    }
+
+    items.sort_by_key(|item| item.id);
+    for item in &mut items {
+        if let Some(saved_path) = item.saved_path.as_mut() {
+            *saved_path = relative_to_output_dir(saved_path, output_dir);
+        }
+    }
+
+    let topic_count = group_items_by_topic(&items, custom_topics).0.len();
+    let content = render_project_topics_index(&items, custom_topics);
+    let topics_path = output_dir.join(PROJECT_TOPICS_INDEX_FILENAME);
+    atomic_write(&topics_path, content.as_bytes())?;
+
+    info!(
+        path = %topics_path.display(),
+        topics = topic_count,
+        entries = items.len(),
+        "Project topics index updated"
+    );
+    Ok(topic_count)
+}
+
+/// Machine-readable listing of every completed item in a project folder.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectManifest {
+    /// Manifest schema version ([`PROJECT_MANIFEST_VERSION`]).
+    pub version: u32,
+    /// Completed items, ordered by queue id.
+    pub items: Vec<ManifestEntry>,
+    /// Files found in the project folder by [`reindex_project`] that no
+    /// completed item points at, ordered by path.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub untracked: Vec<UntrackedFile>,
+}
+
+/// One completed download in `manifest.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// Queue item id (the merge key across runs).
+    pub id: i64,
+    /// Resolved download URL.
+    pub url: String,
+    /// DOI captured at enqueue time.
+    pub doi: Option<String>,
+    /// Title captured at enqueue time.
+    pub title: Option<String>,
+    /// `"; "`-separated author list, as stored in the queue.
+    pub authors: Option<String>,
+    /// Publication year captured at enqueue time.
+    pub year: Option<String>,
+    /// BibTeX citation key, matching the `export --format bibtex` key for the
+    /// same metadata (see [`generate_citation_key`]).
+    #[serde(default)]
+    pub citation_key: String,
+    /// Extracted topics.
+    #[serde(default)]
+    pub topics: Vec<String>,
+    /// `->`-joined resolvers that produced `url` (`crossref->direct`).
+    #[serde(default)]
+    pub resolver_path: Option<String>,
+    /// Input file the item was read from, when it came from one.
+    #[serde(default)]
+    pub input_source_file: Option<String>,
+    /// Saved path relative to the project folder.
+    pub file: Option<String>,
+    /// Lowercase hex SHA-256 of the saved file.
+    pub sha256: Option<String>,
+    /// Size of the saved file in bytes.
+    pub bytes: Option<u64>,
+    /// When the item was marked completed.
+    pub downloaded_at: String,
+}
+
+/// Regenerates the project's `manifest.json` from completed queue items.
+///
+/// Existing entries are merged by `id`: completed items overwrite their entry,
+/// and entries for items no longer in the queue are kept. File hashes are
+/// reused when the saved file's path and size are unchanged, so re-runs only
+/// hash new downloads. The file is rewritten atomically, and only when its
+/// content changes. An unreadable manifest is rebuilt with a warning.
+/// Citation keys are reassigned in `id` order on every write, so colliding
+/// keys get stable `a`, `b`, … suffixes.
+///
+/// Returns the number of entries in the manifest.
+///
+/// # Errors
+///
+/// Returns [`ProjectError::Queue`] on database errors or
+/// [`ProjectError::Io`] on filesystem errors.
+pub async fn write_project_manifest(
+    queue: &Queue,
+    output_dir: &Path,
+) -> Result<usize, ProjectError> {
+    let project_key = project_history_key(output_dir);
+    let completed = queue
+        .list_by_status_in_project(QueueStatus::Completed, Some(&project_key))
+        .await?;
+
+    let manifest_path = output_dir.join(PROJECT_MANIFEST_FILENAME);
+    let previous = read_project_manifest(&manifest_path);
+    let mut entries: BTreeMap<i64, ManifestEntry> = previous
+        .iter()
+        .flat_map(|manifest| manifest.items.iter().cloned())
+        .map(|entry| (entry.id, entry))
+        .collect();
+
+    for item in &completed {
+        let entry = manifest_entry(item, output_dir, entries.get(&item.id));
+        entries.insert(item.id, entry);
+    }
+    assign_citation_keys(entries.values_mut());
+
+    let manifest = ProjectManifest {
+        version: PROJECT_MANIFEST_VERSION,
+        items: entries.into_values().collect(),
+        untracked: previous
+            .as_ref()
+            .map(|manifest| manifest.untracked.clone())
+            .unwrap_or_default(),
+    };
+    write_manifest_if_changed(&manifest_path, previous.as_ref(), &manifest)?;
+    Ok(manifest.items.len())
+}
+
+/// Reassigns citation keys in iteration (`id`) order.
+fn assign_citation_keys<'a>(entries: impl Iterator<Item = &'a mut ManifestEntry>) {
+    let mut seen_keys = HashSet::new();
+    for entry in entries {
+        let key = generate_citation_key(
+            entry
+                .authors
+                .as_deref()
+                .and_then(|authors| authors.split(';').next()),
+            entry.year.as_deref(),
+            entry.title.as_deref(),
+        );
+        entry.citation_key = unique_citation_key(key, &mut seen_keys);
+    }
+}
+
+/// Writes `manifest` unless it equals `previous`, or is empty with no file on disk yet.
+fn write_manifest_if_changed(
+    manifest_path: &Path,
+    previous: Option<&ProjectManifest>,
+    manifest: &ProjectManifest,
+) -> io::Result<()> {
+    if previous == Some(manifest) {
+        return Ok(());
+    }
+    if manifest.items.is_empty() && manifest.untracked.is_empty() && !manifest_path.exists() {
+        return Ok(());
+    }
+    atomic_write_json(manifest_path, manifest)?;
+
+    info!(
+        path = %manifest_path.display(),
+        entries = manifest.items.len(),
+        untracked = manifest.untracked.len(),
+        "Project manifest updated"
+    );
+    Ok(())
+}
+
+fn read_project_manifest(path: &Path) -> Option<ProjectManifest> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return None,
+        Err(error) => {
+            warn!(path = %path.display(), %error, "Cannot read project manifest; rebuilding it");
+            return None;
+        }
+    };
+    match serde_json::from_str(&content) {
+        Ok(manifest) => Some(manifest),
+        Err(error) => {
+            warn!(path = %path.display(), %error, "Project manifest is not valid JSON; rebuilding it");
+            None
+        }
+    }
+}
+
+fn manifest_entry(
+    item: &QueueItem,
+    output_dir: &Path,
+    previous: Option<&ManifestEntry>,
+) -> ManifestEntry {
+    let file = item
+        .saved_path
+        .as_deref()
+        .map(|path| relative_to_output_dir(path, output_dir))
+        .and_then(|path| display_saved_path(&path));
+    let saved_path = item.saved_path.as_deref().map(Path::new);
+    let bytes = saved_path
+        .and_then(|path| fs::metadata(path).ok())
+        .map(|metadata| metadata.len());
+
+    let unchanged = previous.filter(|previous| previous.file == file && previous.bytes == bytes);
+    let sha256 = unchanged
+        .and_then(|previous| previous.sha256.clone())
+        .or_else(|| {
+            let path = saved_path?;
+            sha256_file(path)
+                .map_err(|error| {
+                    warn!(item_id = item.id, path = %path.display(), %error, "Cannot hash saved file");
+                })
+                .ok()
+        });
+
+    ManifestEntry {
+        id: item.id,
+        url: item.url.clone(),
+        doi: item.meta_doi.clone(),
+        title: item.meta_title.clone(),
+        authors: item.meta_authors.clone(),
+        year: item.meta_year.clone(),
+        citation_key: String::new(),
+        topics: item.parse_topics(),
+        resolver_path: item.resolver_path.clone(),
+        input_source_file: item.input_source_file.clone(),
+        file,
+        sha256,
+        bytes,
+        downloaded_at: item.updated_at.clone(),
+    }
+}
+
+/// Streams the file at `path` through SHA-256 and returns lowercase hex.
+fn sha256_file(path: &Path) -> io::Result<String> {
+    let mut hasher = Sha256::new();
+    io::copy(&mut fs::File::open(path)?, &mut hasher)?;
+    let mut hex = String::with_capacity(64);
+    for byte in hasher.finalize() {
+        let _ = write!(hex, "{byte:02x}");
+    }
+    Ok(hex)
+}
+
+/// Generates `format` sidecars for completed items not yet seen before this run.
 ///
 /// Returns the number of sidecars successfully created.
 pub async fn generate_sidecars_for_completed<S: BuildHasher>(
     queue: &Queue,
     output_dir: &Path,
     completed_before: &HashSet<i64, S>,
+    format: SidecarFormat,
 ) -> usize {
     let project_key = project_history_key(output_dir);
     let items = match queue
@@ -514,7 +850,7 @@ pub async fn generate_sidecars_for_completed<S: BuildHasher>(
         .into_iter()
         .filter(|item| item.saved_path.is_some() && !completed_before.contains(&item.id))
     {
-        match generate_sidecar(&item) {
+        match generate_sidecar_with_format(&item, format) {
             Ok(Some(_)) => created += 1,
             Ok(None) => {}
             Err(err) => {
@@ -553,15 +889,15 @@ pub fn render_project_index_section(session_label: &str, items: &[QueueItem]) ->
         let filename = item
             .saved_path
             .as_deref()
-            .and_then(|path| Path::new(path).file_name().and_then(|name| name.to_str()))
-            .unwrap_or("unknown");
+            .and_then(display_saved_path)
+            .unwrap_or_else(|| "unknown".to_string());
         let title = item.meta_title.as_deref().unwrap_or("n/a");
         let authors = item.meta_authors.as_deref().unwrap_or("n/a");
 
         let _ = writeln!(
             out,
             "| `{}` | {} | {} | <{}> |",
-            escape_markdown_cell(filename),
+            escape_markdown_cell(&filename),
             escape_markdown_cell(title),
             escape_markdown_cell(authors),
             escape_markdown_cell(item.url.as_str())
@@ -571,6 +907,414 @@ pub fn render_project_index_section(session_label: &str, items: &[QueueItem]) ->
     out
 }
 
+/// Renders the `topics.md` file: one `## topic` section per normalized topic,
+/// listing the files tagged with it, then an "Uncategorized" section.
+///
+/// An item appears under every topic it carries. Topics are normalized with
+/// [`normalize_topics`], or mapped through [`match_custom_topics`] when
+/// `custom_topics` is non-empty.
+#[must_use]
+pub fn render_project_topics_index(items: &[QueueItem], custom_topics: &[String]) -> String {
+    let (by_topic, uncategorized) = group_items_by_topic(items, custom_topics);
+    let sections = by_topic
+        .iter()
+        .map(|(topic, topic_items)| (topic.as_str(), topic_items))
+        .chain(
+            (!uncategorized.is_empty()).then_some((UNCATEGORIZED_TOPIC_HEADING, &uncategorized)),
+        );
+
+    let mut out = String::from("# Project Topics\n");
+    for (heading, topic_items) in sections {
+        let _ = write!(out, "\n## {heading}\n\n");
+        for item in topic_items {
+            let filename = item
+                .saved_path
+                .as_deref()
+                .and_then(display_saved_path)
+                .unwrap_or_else(|| "unknown".to_string());
+            let title = item.meta_title.as_deref().unwrap_or("n/a");
+            let _ = writeln!(
+                out,
+                "- `{}` — {}",
+                escape_markdown_cell(&filename),
+                escape_markdown_cell(title)
+            );
+        }
+    }
+    out
+}
+
+/// Groups items by topic, returning the topic sections and the items without
+/// any topic.
+fn group_items_by_topic<'a>(
+    items: &'a [QueueItem],
+    custom_topics: &[String],
+) -> (BTreeMap<String, Vec<&'a QueueItem>>, Vec<&'a QueueItem>) {
+    let mut by_topic: BTreeMap<String, Vec<&QueueItem>> = BTreeMap::new();
+    let mut uncategorized = Vec::new();
+    for item in items {
+        let topics = if custom_topics.is_empty() {
+            normalize_topics(item.parse_topics())
+        } else {
+            match_custom_topics(item.parse_topics(), custom_topics.to_vec())
+        };
+        if topics.is_empty() {
+            uncategorized.push(item);
+        }
+        for topic in topics {
+            by_topic.entry(topic).or_default().push(item);
+        }
+    }
+    (by_topic, uncategorized)
+}
+
+// ---------------------------------------------------------------------------
+// Reindex
+// ---------------------------------------------------------------------------
+
+/// Top-level files that hold project outputs rather than downloads.
+const PROJECT_OUTPUT_FILENAMES: &[&str] = &[
+    "index.md",
+    "download.log",
+    PROJECT_MANIFEST_FILENAME,
+    PROJECT_TOPICS_INDEX_FILENAME,
+];
+
+/// Extensions of sidecars and unfinished downloads, never listed as downloads.
+const REINDEX_SKIPPED_EXTENSIONS: &[&str] = &["json", "ris", "part"];
+
+/// A file in the project folder that no completed queue item points at.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UntrackedFile {
+    /// Path relative to the project folder.
+    pub file: String,
+    /// Title from download history or an adjacent JSON-LD sidecar.
+    pub title: Option<String>,
+    /// `"; "`-separated author list.
+    pub authors: Option<String>,
+    /// Publication year (sidecar only; history rows carry no year).
+    pub year: Option<String>,
+    /// DOI.
+    pub doi: Option<String>,
+    /// Where the metadata came from (`history` or `sidecar`); `None` when nothing matched.
+    pub metadata_source: Option<String>,
+    /// Lowercase hex SHA-256 of the file.
+    pub sha256: Option<String>,
+    /// Size of the file in bytes.
+    pub bytes: Option<u64>,
+}
+
+/// Outcome counts of [`reindex_project`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReindexSummary {
+    /// Completed items whose file is still at its recorded path.
+    pub tracked: usize,
+    /// Completed items whose file moved and was re-linked by file name.
+    pub relinked: usize,
+    /// Completed items whose file is no longer in the project folder.
+    pub missing: usize,
+    /// Files on disk that no completed item points at.
+    pub untracked: usize,
+}
+
+/// Rebuilds `index.md`, `topics.md`, and `manifest.json` from the files in
+/// `output_dir` and the project's queue.
+///
+/// Files are collected recursively, skipping dot-entries (`.downloader`),
+/// project outputs, sidecars, and `.part` files, and matched to completed
+/// items by saved path. An item whose file moved is re-linked in the queue
+/// when exactly one unmatched file has the same name; items whose file is
+/// gone are counted as missing and left out. Remaining files are listed as
+/// untracked, with metadata from a successful history row for the same path
+/// or file name, else from an adjacent `<stem>.json` sidecar.
+///
+/// All three outputs are regenerated rather than merged: `index.md` becomes a
+/// single section plus an "Untracked files" table, and the manifest drops
+/// entries for items that are no longer present. Safe to re-run.
+///
+/// # Errors
+///
+/// Returns [`ProjectError::Queue`] on database errors or
+/// [`ProjectError::Io`] on filesystem errors.
+pub async fn reindex_project(
+    queue: &Queue,
+    output_dir: &Path,
+    custom_topics: &[String],
+) -> Result<ReindexSummary, ProjectError> {
+    let root = fs::canonicalize(output_dir)?;
+    let project_key = project_history_key(output_dir);
+    let mut unmatched = BTreeSet::new();
+    collect_project_files(&root, &root, &mut unmatched)?;
+
+    let mut completed = queue
+        .list_by_status_in_project(QueueStatus::Completed, Some(&project_key))
+        .await?;
+    completed.sort_by_key(|item| item.id);
+
+    let mut summary = ReindexSummary::default();
+    let mut present = Vec::new();
+    let mut moved = Vec::new();
+    for mut item in completed {
+        let on_disk = item
+            .saved_path
+            .as_deref()
+            .and_then(|path| fs::canonicalize(path).ok())
+            .filter(|path| unmatched.remove(path));
+        if let Some(path) = on_disk {
+            item.saved_path = Some(path.to_string_lossy().to_string());
+            summary.tracked += 1;
+            present.push(item);
+        } else {
+            moved.push(item);
+        }
+    }
+
+    for mut item in moved {
+        let Some(path) = unique_file_named(&unmatched, item.saved_path.as_deref()) else {
+            warn!(
+                item_id = item.id,
+                saved_path = item.saved_path.as_deref().unwrap_or("n/a"),
+                "Downloaded file is missing from the project folder"
+            );
+            summary.missing += 1;
+            continue;
+        };
+        unmatched.remove(&path);
+        queue.set_saved_path(item.id, &path).await?;
+        info!(item_id = item.id, path = %path.display(), "Re-linked moved download");
+        item.saved_path = Some(path.to_string_lossy().to_string());
+        summary.relinked += 1;
+        present.push(item);
+    }
+    present.sort_by_key(|item| item.id);
+
+    let history: Vec<DownloadAttempt> = query_session_attempts(queue, &project_key, None)
+        .await?
+        .into_iter()
+        .filter(|attempt| {
+            attempt.status() == DownloadAttemptStatus::Success && attempt.file_path.is_some()
+        })
+        .collect();
+    let previous = read_project_manifest(&root.join(PROJECT_MANIFEST_FILENAME));
+    let untracked: Vec<UntrackedFile> = unmatched
+        .iter()
+        .map(|path| untracked_file(path, &root, &history, previous.as_ref()))
+        .collect();
+    summary.untracked = untracked.len();
+
+    write_reindexed_outputs(&root, &present, untracked, previous.as_ref(), custom_topics)?;
+
+    info!(
+        path = %root.display(),
+        tracked = summary.tracked,
+        relinked = summary.relinked,
+        missing = summary.missing,
+        untracked = summary.untracked,
+        "Project reindexed"
+    );
+    Ok(summary)
+}
+
+/// Writes the regenerated `index.md`, `topics.md`, and `manifest.json` for [`reindex_project`].
+fn write_reindexed_outputs(
+    root: &Path,
+    present: &[QueueItem],
+    untracked: Vec<UntrackedFile>,
+    previous: Option<&ProjectManifest>,
+    custom_topics: &[String],
+) -> io::Result<()> {
+    let mut display_items = present.to_vec();
+    for item in &mut display_items {
+        if let Some(saved_path) = item.saved_path.as_mut() {
+            *saved_path = relative_to_output_dir(saved_path, root);
+        }
+    }
+    let mut index = String::from("# Project Index\n\n");
+    index.push_str(&render_project_index_section(
+        &format!("{} (reindex)", make_session_label()),
+        &display_items,
+    ));
+    if !untracked.is_empty() {
+        index.push_str(&render_untracked_files_section(&untracked));
+    }
+    atomic_write(&root.join("index.md"), index.as_bytes())?;
+    let topics = render_project_topics_index(&display_items, custom_topics);
+    atomic_write(&root.join(PROJECT_TOPICS_INDEX_FILENAME), topics.as_bytes())?;
+
+    let mut items: Vec<ManifestEntry> = present
+        .iter()
+        .map(|item| {
+            let previous_entry = previous
+                .into_iter()
+                .flat_map(|manifest| &manifest.items)
+                .find(|entry| entry.id == item.id);
+            manifest_entry(item, root, previous_entry)
+        })
+        .collect();
+    assign_citation_keys(items.iter_mut());
+    let manifest = ProjectManifest {
+        version: PROJECT_MANIFEST_VERSION,
+        items,
+        untracked,
+    };
+    write_manifest_if_changed(&root.join(PROJECT_MANIFEST_FILENAME), previous, &manifest)
+}
+
+/// Adds every candidate download under `dir` to `files`, recursing into subfolders.
+fn collect_project_files(root: &Path, dir: &Path, files: &mut BTreeSet<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name.starts_with('.') {
+            continue;
+        }
+        let path = entry.path();
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            collect_project_files(root, &path, files)?;
+            continue;
+        }
+        let skipped_extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| {
+                REINDEX_SKIPPED_EXTENSIONS
+                    .iter()
+                    .any(|skipped| extension.eq_ignore_ascii_case(skipped))
+            });
+        let project_output = dir == root && PROJECT_OUTPUT_FILENAMES.contains(&name.as_ref());
+        if file_type.is_file() && !skipped_extension && !project_output {
+            files.insert(path);
+        }
+    }
+    Ok(())
+}
+
+/// Returns the only file in `files` named like `saved_path`, if exactly one matches.
+fn unique_file_named(files: &BTreeSet<PathBuf>, saved_path: Option<&str>) -> Option<PathBuf> {
+    let name = Path::new(saved_path?).file_name()?;
+    let mut matches = files.iter().filter(|path| path.file_name() == Some(name));
+    let first = matches.next()?;
+    matches.next().is_none().then(|| first.clone())
+}
+
+fn untracked_file(
+    path: &Path,
+    root: &Path,
+    history: &[DownloadAttempt],
+    previous: Option<&ProjectManifest>,
+) -> UntrackedFile {
+    let relative = relative_to_output_dir(&path.to_string_lossy(), root);
+    let file = display_saved_path(&relative).unwrap_or(relative);
+    let bytes = fs::metadata(path).ok().map(|metadata| metadata.len());
+    let sha256 = previous
+        .iter()
+        .flat_map(|manifest| &manifest.untracked)
+        .find(|entry| entry.file == file && entry.bytes == bytes)
+        .and_then(|entry| entry.sha256.clone())
+        .or_else(|| {
+            sha256_file(path)
+                .map_err(
+                    |error| warn!(path = %path.display(), %error, "Cannot hash untracked file"),
+                )
+                .ok()
+        });
+
+    let mut entry = UntrackedFile {
+        file,
+        title: None,
+        authors: None,
+        year: None,
+        doi: None,
+        metadata_source: None,
+        sha256,
+        bytes,
+    };
+    let same_path = |attempt: &&DownloadAttempt| {
+        attempt
+            .file_path
+            .as_deref()
+            .is_some_and(|file_path| Path::new(file_path) == path)
+    };
+    let same_name = |attempt: &&DownloadAttempt| {
+        attempt
+            .file_path
+            .as_deref()
+            .is_some_and(|file_path| Path::new(file_path).file_name() == path.file_name())
+    };
+    let attempt = history
+        .iter()
+        .rev()
+        .find(same_path)
+        .or_else(|| history.iter().rev().find(same_name));
+    if let Some(attempt) = attempt {
+        entry.title.clone_from(&attempt.title);
+        entry.authors.clone_from(&attempt.authors);
+        entry.doi.clone_from(&attempt.doi);
+        entry.metadata_source = Some("history".to_string());
+    } else if apply_sidecar_metadata(&mut entry, &path.with_extension("json")) {
+        entry.metadata_source = Some("sidecar".to_string());
+    }
+    entry
+}
+
+/// Copies title, authors, year, and DOI from a JSON-LD sidecar into `entry`.
+///
+/// Returns `false` when the sidecar is missing, unreadable, or has none of them.
+fn apply_sidecar_metadata(entry: &mut UntrackedFile, sidecar_path: &Path) -> bool {
+    let Ok(content) = fs::read_to_string(sidecar_path) else {
+        return false;
+    };
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(&content) else {
+        warn!(path = %sidecar_path.display(), "Sidecar is not valid JSON; ignoring it");
+        return false;
+    };
+    let text = |value: Option<&serde_json::Value>| {
+        value
+            .and_then(serde_json::Value::as_str)
+            .map(str::trim)
+            .filter(|text| !text.is_empty())
+            .map(ToString::to_string)
+    };
+
+    entry.title = text(value.get("name"));
+    entry.authors = value
+        .get("author")
+        .and_then(serde_json::Value::as_array)
+        .map(|authors| {
+            authors
+                .iter()
+                .filter_map(|author| text(author.get("name")))
+                .collect::<Vec<_>>()
+                .join("; ")
+        })
+        .filter(|authors| !authors.is_empty());
+    entry.year = text(value.get("datePublished"));
+    entry.doi = text(value.get("identifier").and_then(|id| id.get("value")));
+    entry.title.is_some() || entry.authors.is_some() || entry.year.is_some() || entry.doi.is_some()
+}
+
+/// Renders the "Untracked files" section that [`reindex_project`] appends to `index.md`.
+#[must_use]
+pub fn render_untracked_files_section(files: &[UntrackedFile]) -> String {
+    let mut out = String::from("## Untracked files\n\n");
+    out.push_str("| Filename | Title | Authors | Metadata |\n");
+    out.push_str("| --- | --- | --- | --- |\n");
+    for file in files {
+        let _ = writeln!(
+            out,
+            "| `{}` | {} | {} | {} |",
+            escape_markdown_cell(&file.file),
+            escape_markdown_cell(file.title.as_deref().unwrap_or("n/a")),
+            escape_markdown_cell(file.authors.as_deref().unwrap_or("n/a")),
+            file.metadata_source.as_deref().unwrap_or("none")
+        );
+    }
+    out.push('\n');
+    out
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -711,6 +1455,8 @@ mod tests {
             original_input: Some("https://example.com/paper.pdf".to_string()),
             http_status: None,
             duration_ms: None,
+            resolver_path: None,
+            input_source_file: None,
         }
     }
 
@@ -728,6 +1474,8 @@ mod tests {
             suggested_filename: None,
             meta_title: Some("Test Paper".to_string()),
             meta_authors: Some("Smith, J.".to_string()),
+            meta_author_orcids: None,
+            meta_abstract: None,
             meta_year: None,
             meta_doi: None,
             topics: topics.map(ToString::to_string),
@@ -736,6 +1484,10 @@ mod tests {
             saved_path: Some("/tmp/Climate-Research/paper.pdf".to_string()),
             bytes_downloaded: 0,
             content_length: None,
+            dead_letter: false,
+            read_timeout_secs: None,
+            resolver_path: None,
+            input_source_file: None,
             created_at: "2026-02-28T10:00:00Z".to_string(),
             updated_at: "2026-02-28T10:00:00Z".to_string(),
         }
@@ -779,6 +1531,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_render_project_index_section_shows_layout_subfolder() {
+        let mut item = make_test_item(7, None);
+        item.saved_path = Some(relative_to_output_dir(
+            "/tmp/Climate-Research/2024/paper.pdf",
+            Path::new("/tmp/Climate-Research"),
+        ));
+        let output = render_project_index_section("2026-03-08_14h05m30s", &[item]);
+        assert!(output.contains("| `2024/paper.pdf` |"), "got: {output}");
+    }
+
+    #[test]
+    fn test_render_project_download_log_section_shows_layout_subfolder() {
+        let mut attempt = make_test_attempt(42, "success", None);
+        attempt.file_path = Some(relative_to_output_dir(
+            "/tmp/out/Smith/paper.pdf",
+            Path::new("/tmp/out"),
+        ));
+        let output = render_project_download_log_section("2026-03-08_14h05m30s", &[attempt]);
+        assert!(output.contains("file=Smith/paper.pdf"), "got: {output}");
+    }
+
     #[test]
     fn test_render_project_index_section_structure() {
         let item = make_test_item(7, None);
@@ -879,4 +1653,258 @@ mod tests {
             "pipe in URL should be escaped in markdown table"
         );
     }
+
+    #[test]
+    fn test_render_project_topics_index_groups_by_topic_with_uncategorized_last() {
+        let mut tagged = make_test_item(1, Some(r#"["Neural Networks","climate"]"#));
+        tagged.saved_path = Some("2024/a.pdf".to_string());
+        let mut untagged = make_test_item(2, None);
+        untagged.meta_title = Some("Untagged Paper".to_string());
+
+        let output = render_project_topics_index(&[tagged, untagged], &[]);
+
+        let climate = output.find("## climate").unwrap();
+        let neural = output.find("## neural networks").unwrap();
+        let uncategorized = output.find("## Uncategorized").unwrap();
+        assert!(climate < neural && neural < uncategorized, "got: {output}");
+        assert_eq!(output.matches("- `2024/a.pdf` — Test Paper").count(), 2);
+        assert!(output[uncategorized..].contains("Untagged Paper"));
+    }
+
+    #[test]
+    fn test_render_project_topics_index_collapses_custom_topic_synonyms() {
+        let first = make_test_item(1, Some(r#"["deep learning"]"#));
+        let second = make_test_item(2, Some(r#"["deep learning models"]"#));
+
+        let output = render_project_topics_index(&[first, second], &["Deep Learning".to_string()]);
+
+        assert_eq!(output.matches("\n## ").count(), 1, "got: {output}");
+        assert!(output.contains("## deep learning\n"));
+    }
+
+    async fn completed_item(queue: &Queue, output_dir: &Path, name: &str, body: &[u8]) -> i64 {
+        let metadata = crate::QueueMetadata {
+            title: Some(format!("Title {name}")),
+            authors: Some("Smith, Jane".to_string()),
+            doi: Some(format!("10.1000/{name}")),
+            topics: Some(vec!["climate".to_string()]),
+            resolver_path: Some("crossref->direct".to_string()),
+            ..crate::QueueMetadata::default()
+        };
+        let id = queue
+            .enqueue_with_metadata_in_project(
+                &format!("https://example.com/{name}.pdf"),
+                "doi",
+                None,
+                Some(&metadata),
+                Some(&project_history_key(output_dir)),
+            )
+            .await
+            .unwrap();
+        let saved_path = output_dir.join(format!("{name}.pdf"));
+        fs::write(&saved_path, body).unwrap();
+        queue
+            .mark_completed_with_path(id, Some(&saved_path))
+            .await
+            .unwrap();
+        id
+    }
+
+    #[tokio::test]
+    async fn test_write_project_manifest_lists_completed_items_with_hashes() {
+        let queue = Queue::new(crate::Database::new_in_memory().await.unwrap());
+        let output_dir = tempfile::TempDir::new().unwrap();
+        let id = completed_item(&queue, output_dir.path(), "paper", b"abc").await;
+
+        let count = write_project_manifest(&queue, output_dir.path())
+            .await
+            .unwrap();
+        assert_eq!(count, 1);
+
+        let manifest: ProjectManifest = serde_json::from_str(
+            &fs::read_to_string(output_dir.path().join(PROJECT_MANIFEST_FILENAME)).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(manifest.version, PROJECT_MANIFEST_VERSION);
+        let entry = &manifest.items[0];
+        assert_eq!(entry.id, id);
+        assert_eq!(entry.url, "https://example.com/paper.pdf");
+        assert_eq!(entry.doi.as_deref(), Some("10.1000/paper"));
+        assert_eq!(entry.citation_key, "smithtitle");
+        assert_eq!(entry.topics, vec!["climate".to_string()]);
+        assert_eq!(entry.resolver_path.as_deref(), Some("crossref->direct"));
+        assert_eq!(entry.file.as_deref(), Some("paper.pdf"));
+        assert_eq!(entry.bytes, Some(3));
+        assert_eq!(
+            entry.sha256.as_deref(),
+            Some("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+        );
+        assert!(!entry.downloaded_at.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_write_project_topics_index_writes_topics_md() {
+        let queue = Queue::new(crate::Database::new_in_memory().await.unwrap());
+        let output_dir = tempfile::TempDir::new().unwrap();
+        let topics_path = output_dir.path().join(PROJECT_TOPICS_INDEX_FILENAME);
+
+        assert_eq!(
+            write_project_topics_index(&queue, output_dir.path(), &[])
+                .await
+                .unwrap(),
+            0
+        );
+        assert!(!topics_path.exists());
+
+        completed_item(&queue, output_dir.path(), "paper", b"abc").await;
+        assert_eq!(
+            write_project_topics_index(&queue, output_dir.path(), &[])
+                .await
+                .unwrap(),
+            1
+        );
+        let content = fs::read_to_string(&topics_path).unwrap();
+        assert!(content.starts_with("# Project Topics\n"));
+        assert!(content.contains("## climate\n\n- `paper.pdf` — Title paper\n"));
+    }
+
+    #[tokio::test]
+    async fn test_write_project_manifest_merges_by_id_and_is_idempotent() {
+        let queue = Queue::new(crate::Database::new_in_memory().await.unwrap());
+        let output_dir = tempfile::TempDir::new().unwrap();
+        let manifest_path = output_dir.path().join(PROJECT_MANIFEST_FILENAME);
+        fs::write(
+            &manifest_path,
+            r#"{"version": 1, "items": [{"id": 999, "url": "https://old.example/x.pdf",
+                "doi": null, "title": null, "authors": null, "year": null, "file": "x.pdf",
+                "sha256": null, "bytes": null, "downloaded_at": "2020-01-01 00:00:00"}]}"#,
+        )
+        .unwrap();
+        completed_item(&queue, output_dir.path(), "first", b"one").await;
+
+        assert_eq!(
+            write_project_manifest(&queue, output_dir.path())
+                .await
+                .unwrap(),
+            2
+        );
+        let first_run = fs::read_to_string(&manifest_path).unwrap();
+        assert_eq!(
+            write_project_manifest(&queue, output_dir.path())
+                .await
+                .unwrap(),
+            2
+        );
+        assert_eq!(fs::read_to_string(&manifest_path).unwrap(), first_run);
+
+        completed_item(&queue, output_dir.path(), "second", b"two").await;
+        write_project_manifest(&queue, output_dir.path())
+            .await
+            .unwrap();
+        let manifest: ProjectManifest =
+            serde_json::from_str(&fs::read_to_string(&manifest_path).unwrap()).unwrap();
+        let ids: Vec<i64> = manifest.items.iter().map(|entry| entry.id).collect();
+        assert_eq!(ids.len(), 3);
+        assert_eq!(ids.last(), Some(&999), "entries stay ordered by id");
+        let keys: Vec<&str> = manifest
+            .items
+            .iter()
+            .map(|entry| entry.citation_key.as_str())
+            .collect();
+        assert_eq!(keys, ["smithtitle", "smithtitlea", "noauthor"]);
+        assert!(
+            manifest
+                .items
+                .iter()
+                .all(|entry| entry.id == 999 || entry.sha256.is_some())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reindex_project_relinks_moved_files_and_lists_untracked() {
+        let queue = Queue::new(crate::Database::new_in_memory().await.unwrap());
+        let output_dir = tempfile::TempDir::new().unwrap();
+        let root = output_dir.path();
+        let kept = completed_item(&queue, root, "kept", b"kept").await;
+        let moved = completed_item(&queue, root, "moved", b"moved").await;
+        completed_item(&queue, root, "gone", b"gone").await;
+        fs::create_dir(root.join("sub")).unwrap();
+        fs::rename(root.join("moved.pdf"), root.join("sub").join("moved.pdf")).unwrap();
+        fs::remove_file(root.join("gone.pdf")).unwrap();
+        fs::write(root.join("extra.pdf"), b"extra").unwrap();
+        fs::write(
+            root.join("extra.json"),
+            r#"{"@type": "ScholarlyArticle", "name": "Dropped Paper",
+                "author": [{"@type": "Person", "name": "Doe, John"}],
+                "datePublished": "2021",
+                "identifier": {"@type": "PropertyValue", "propertyID": "DOI", "value": "10.1000/extra"}}"#,
+        )
+        .unwrap();
+        fs::write(root.join("extra.pdf.part"), b"partial").unwrap();
+
+        let summary = reindex_project(&queue, root, &[]).await.unwrap();
+        assert_eq!(
+            summary,
+            ReindexSummary {
+                tracked: 1,
+                relinked: 1,
+                missing: 1,
+                untracked: 1,
+            }
+        );
+        let relinked_path = queue.get(moved).await.unwrap().unwrap().saved_path.unwrap();
+        assert!(relinked_path.ends_with("moved.pdf") && relinked_path.contains("sub"));
+
+        let manifest: ProjectManifest = serde_json::from_str(
+            &fs::read_to_string(root.join(PROJECT_MANIFEST_FILENAME)).unwrap(),
+        )
+        .unwrap();
+        let ids: Vec<i64> = manifest.items.iter().map(|entry| entry.id).collect();
+        assert_eq!(ids, [kept, moved]);
+        assert_eq!(manifest.items[1].file.as_deref(), Some("sub/moved.pdf"));
+        assert_eq!(manifest.untracked.len(), 1);
+        let extra = &manifest.untracked[0];
+        assert_eq!(extra.file, "extra.pdf");
+        assert_eq!(extra.title.as_deref(), Some("Dropped Paper"));
+        assert_eq!(extra.authors.as_deref(), Some("Doe, John"));
+        assert_eq!(extra.year.as_deref(), Some("2021"));
+        assert_eq!(extra.doi.as_deref(), Some("10.1000/extra"));
+        assert_eq!(extra.metadata_source.as_deref(), Some("sidecar"));
+        assert_eq!(extra.bytes, Some(5));
+
+        let index = fs::read_to_string(root.join("index.md")).unwrap();
+        assert!(index.contains("`sub/moved.pdf`"));
+        assert!(index.contains("## Untracked files"));
+        assert!(index.contains("| `extra.pdf` | Dropped Paper | Doe, John | sidecar |"));
+        assert!(!index.contains("gone.pdf"));
+        let topics = fs::read_to_string(root.join(PROJECT_TOPICS_INDEX_FILENAME)).unwrap();
+        assert!(topics.contains("`kept.pdf`") && topics.contains("`sub/moved.pdf`"));
+
+        let rerun = reindex_project(&queue, root, &[]).await.unwrap();
+        assert_eq!((rerun.tracked, rerun.relinked), (2, 0));
+    }
+
+    #[tokio::test]
+    async fn test_write_project_manifest_keeps_untracked_files() {
+        let queue = Queue::new(crate::Database::new_in_memory().await.unwrap());
+        let output_dir = tempfile::TempDir::new().unwrap();
+        fs::write(output_dir.path().join("dropped.pdf"), b"x").unwrap();
+        reindex_project(&queue, output_dir.path(), &[])
+            .await
+            .unwrap();
+
+        completed_item(&queue, output_dir.path(), "paper", b"abc").await;
+        write_project_manifest(&queue, output_dir.path())
+            .await
+            .unwrap();
+
+        let manifest: ProjectManifest = serde_json::from_str(
+            &fs::read_to_string(output_dir.path().join(PROJECT_MANIFEST_FILENAME)).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(manifest.items.len(), 1);
+        assert_eq!(manifest.untracked.len(), 1);
+        assert_eq!(manifest.untracked[0].file, "dropped.pdf");
+        assert_eq!(manifest.untracked[0].metadata_source, None);
+    }
 }
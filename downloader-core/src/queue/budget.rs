@@ -0,0 +1,90 @@
+//! Rolling totals of downloaded bytes for the download byte budget.
+//!
+//! Completed downloads add their size to a per-day bucket in `byte_usage`.
+//! The budget window is the last [`BYTE_BUDGET_WINDOW_DAYS`] UTC days, today
+//! included; older buckets are pruned whenever bytes are recorded.
+
+use tracing::instrument;
+
+use super::{Queue, Result};
+
+/// Length of the rolling byte-budget window in days.
+pub const BYTE_BUDGET_WINDOW_DAYS: u32 = 30;
+
+/// `SQLite` date modifier selecting the first day outside the window.
+fn window_start_modifier() -> String {
+    format!("-{BYTE_BUDGET_WINDOW_DAYS} days")
+}
+
+impl Queue {
+    /// Adds `bytes` to today's downloaded-bytes bucket and drops buckets that
+    /// fell out of the budget window.
+    ///
+    /// # Errors
+    ///
+    /// Returns database errors when the update fails.
+    #[instrument(skip(self))]
+    pub async fn record_downloaded_bytes(&self, bytes: u64) -> Result<()> {
+        let bytes = i64::try_from(bytes).unwrap_or(i64::MAX);
+        sqlx::query(
+            r"INSERT INTO byte_usage (day, bytes) VALUES (date('now'), ?1)
+              ON CONFLICT(day) DO UPDATE SET bytes = bytes + excluded.bytes",
+        )
+        .bind(bytes)
+        .execute(self.db.pool())
+        .await?;
+        sqlx::query("DELETE FROM byte_usage WHERE day <= date('now', ?1)")
+            .bind(window_start_modifier())
+            .execute(self.db.pool())
+            .await?;
+        Ok(())
+    }
+
+    /// Returns the bytes downloaded in the last [`BYTE_BUDGET_WINDOW_DAYS`] days.
+    ///
+    /// # Errors
+    ///
+    /// Returns database errors when the query fails.
+    #[instrument(skip(self))]
+    pub async fn downloaded_bytes_in_window(&self) -> Result<u64> {
+        let total = sqlx::query_scalar::<_, i64>(
+            "SELECT COALESCE(SUM(bytes), 0) FROM byte_usage WHERE day > date('now', ?1)",
+        )
+        .bind(window_start_modifier())
+        .fetch_one(self.db.pool())
+        .await?;
+        Ok(u64::try_from(total).unwrap_or(0))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::Database;
+
+    #[tokio::test]
+    async fn test_downloaded_bytes_accumulate_within_window_and_old_days_expire() {
+        let db = Database::new_in_memory().await.unwrap();
+        let queue = Queue::new(db.clone());
+        assert_eq!(queue.downloaded_bytes_in_window().await.unwrap(), 0);
+
+        sqlx::query("INSERT INTO byte_usage (day, bytes) VALUES (date('now', '-29 days'), 7)")
+            .execute(db.pool())
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO byte_usage (day, bytes) VALUES (date('now', '-30 days'), 1000)")
+            .execute(db.pool())
+            .await
+            .unwrap();
+        queue.record_downloaded_bytes(100).await.unwrap();
+        queue.record_downloaded_bytes(50).await.unwrap();
+
+        assert_eq!(queue.downloaded_bytes_in_window().await.unwrap(), 157);
+        let buckets: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM byte_usage")
+            .fetch_one(db.pool())
+            .await
+            .unwrap();
+        assert_eq!(buckets, 2, "the expired day is pruned");
+    }
+}
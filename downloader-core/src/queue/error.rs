@@ -120,6 +120,12 @@ pub enum QueueError {
         /// Why it's invalid
         reason: String,
     },
+
+    /// Queue export/import payload could not be written or parsed.
+    #[error(
+        "queue transfer failed: {0}\n  Suggestion: Check that the file is a queue export produced by `downloader queue export`"
+    )]
+    Transfer(String),
 }
 
 impl From<sqlx::Error> for QueueError {
@@ -146,7 +152,7 @@ impl QueueError {
     pub fn database_kind(&self) -> Option<QueueDbErrorKind> {
         match self {
             Self::Database { kind, .. } => Some(*kind),
-            Self::ItemNotFound(_) | Self::InvalidStatus { .. } => None,
+            Self::ItemNotFound(_) | Self::InvalidStatus { .. } | Self::Transfer(_) => None,
         }
     }
 
@@ -202,6 +208,15 @@ mod tests {
         assert!(msg.contains("pending"));
     }
 
+    #[test]
+    fn test_queue_error_transfer_message() {
+        let err = QueueError::Transfer("expected value at line 1".to_string());
+        let msg = err.to_string();
+        assert!(msg.contains("queue transfer failed"));
+        assert!(msg.contains("line 1"));
+        assert!(err.database_kind().is_none());
+    }
+
     #[test]
     fn test_queue_error_clone() {
         let err = QueueError::ItemNotFound(123);
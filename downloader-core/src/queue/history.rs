@@ -67,6 +67,27 @@ pub enum DownloadErrorType {
     NotFound,
     /// Input parsing/validation failures.
     ParseError,
+    /// The output volume ran out of space while writing the file.
+    DiskFull,
+    /// The redirect chain exceeded the configured limit.
+    TooManyRedirects,
+    /// TLS handshake or certificate validation failed.
+    Tls,
+    /// The host name could not be resolved.
+    Dns,
+    /// The peer reset or aborted the connection mid-request.
+    ConnectionReset,
+    /// The request timed out.
+    Timeout,
+    /// robots.txt disallows fetching the URL.
+    RobotsDisallowed,
+    /// The server refused the transfer as too large (HTTP 413).
+    TooLarge,
+    /// The downloaded bytes failed the integrity check.
+    ChecksumMismatch,
+    /// The server could not serve an acceptable content type (HTTP 406/415), or
+    /// the body failed the minimum-size / PDF signature check.
+    UnexpectedContentType,
 }
 
 impl DownloadErrorType {
@@ -78,6 +99,16 @@ impl DownloadErrorType {
             Self::Auth => "auth",
             Self::NotFound => "not_found",
             Self::ParseError => "parse_error",
+            Self::DiskFull => "disk_full",
+            Self::TooManyRedirects => "too_many_redirects",
+            Self::Tls => "tls",
+            Self::Dns => "dns",
+            Self::ConnectionReset => "connection_reset",
+            Self::Timeout => "timeout",
+            Self::RobotsDisallowed => "robots_disallowed",
+            Self::TooLarge => "too_large",
+            Self::ChecksumMismatch => "checksum_mismatch",
+            Self::UnexpectedContentType => "unexpected_content_type",
         }
     }
 }
@@ -97,6 +128,16 @@ impl std::str::FromStr for DownloadErrorType {
             "auth" => Ok(Self::Auth),
             "not_found" => Ok(Self::NotFound),
             "parse_error" => Ok(Self::ParseError),
+            "disk_full" => Ok(Self::DiskFull),
+            "too_many_redirects" => Ok(Self::TooManyRedirects),
+            "tls" => Ok(Self::Tls),
+            "dns" => Ok(Self::Dns),
+            "connection_reset" => Ok(Self::ConnectionReset),
+            "timeout" => Ok(Self::Timeout),
+            "robots_disallowed" => Ok(Self::RobotsDisallowed),
+            "too_large" => Ok(Self::TooLarge),
+            "checksum_mismatch" => Ok(Self::ChecksumMismatch),
+            "unexpected_content_type" => Ok(Self::UnexpectedContentType),
             _ => Err(format!("invalid download error type: {value}")),
         }
     }
@@ -143,6 +184,10 @@ pub struct NewDownloadAttempt<'a> {
     pub parse_confidence: Option<&'a str>,
     /// JSON payload of parser confidence factors.
     pub parse_confidence_factors: Option<&'a str>,
+    /// `->`-joined resolvers that produced the URL (`crossref->direct`).
+    pub resolver_path: Option<&'a str>,
+    /// Input file the item was read from.
+    pub input_source_file: Option<&'a str>,
 }
 
 /// Query filters for download history reads.
@@ -162,6 +207,8 @@ pub struct DownloadAttemptQuery {
     pub before_id: Option<i64>,
     /// Optional domain filter (case-insensitive host match).
     pub domain: Option<String>,
+    /// Optional failure category filter.
+    pub error_type: Option<DownloadErrorType>,
     /// Restrict rows to low-confidence reference parses.
     pub uncertain_only: bool,
     /// Max rows to return (0 uses default).
@@ -178,6 +225,7 @@ impl Default for DownloadAttemptQuery {
             after_id: None,
             before_id: None,
             domain: None,
+            error_type: None,
             uncertain_only: false,
             limit: DEFAULT_HISTORY_LIMIT,
         }
@@ -224,6 +272,10 @@ pub struct DownloadAttempt {
     pub http_status: Option<i64>,
     /// Duration in milliseconds.
     pub duration_ms: Option<i64>,
+    /// `->`-joined resolvers that produced the URL; `None` for legacy rows.
+    pub resolver_path: Option<String>,
+    /// Input file the item was read from; `None` for direct inputs and legacy rows.
+    pub input_source_file: Option<String>,
 }
 
 impl DownloadAttempt {
@@ -291,6 +343,8 @@ pub struct DownloadSearchCandidate {
     pub doi: Option<String>,
     /// Start timestamp in `SQLite` datetime text format.
     pub started_at: String,
+    /// `->`-joined resolvers that produced the URL; `None` for legacy rows.
+    pub resolver_path: Option<String>,
 }
 
 impl DownloadSearchCandidate {
@@ -347,13 +401,15 @@ impl Queue {
                 doi,
                 topics,
                 parse_confidence,
-                parse_confidence_factors
+                parse_confidence_factors,
+                resolver_path,
+                input_source_file
               )
               VALUES (
                 ?, ?, ?, ?, ?, ?, datetime('now'), datetime('now'),
                 ?, ?, ?,
                 CASE WHEN ? = 'failed' AND ? > 0 THEN datetime('now') ELSE NULL END,
-                ?, ?, ?, ?, ?, ?, ?, ?, ?, ?
+                ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?
               )
               RETURNING id",
         )
@@ -378,6 +434,8 @@ impl Queue {
         .bind(attempt.topics)
         .bind(attempt.parse_confidence)
         .bind(attempt.parse_confidence_factors)
+        .bind(attempt.resolver_path)
+        .bind(attempt.input_source_file)
         .fetch_one(self.db.pool())
         .await?;
 
@@ -419,6 +477,7 @@ impl Queue {
                     query.until.as_deref(),
                     query.after_id,
                     cursor_before,
+                    query.error_type,
                     query.uncertain_only,
                     page_size,
                 )
@@ -454,6 +513,7 @@ impl Queue {
             query.until.as_deref(),
             query.after_id,
             query.before_id,
+            query.error_type,
             query.uncertain_only,
             requested_limit,
         )
@@ -487,7 +547,8 @@ impl Queue {
                 title,
                 authors,
                 doi,
-                started_at
+                started_at,
+                resolver_path
               FROM download_log
               WHERE (?1 IS NULL OR project = ?1)
                 AND (?2 IS NULL OR started_at >= ?2)
@@ -507,6 +568,58 @@ impl Queue {
 
         Ok(candidates)
     }
+
+    /// Counts history rows started strictly before `before`, optionally only those with `status`.
+    ///
+    /// `before` is compared as `SQLite` datetime text, so a bare date
+    /// (`2025-01-01`) means the start of that day. This is the dry-run
+    /// counterpart of [`Queue::prune_download_attempts`].
+    ///
+    /// # Errors
+    ///
+    /// Returns database errors when query execution fails.
+    #[instrument(skip(self))]
+    pub async fn count_download_attempts_before(
+        &self,
+        before: &str,
+        status: Option<DownloadAttemptStatus>,
+    ) -> Result<u64> {
+        let count = sqlx::query_scalar::<_, i64>(
+            r"SELECT COUNT(*) FROM download_log
+              WHERE started_at < ?1 AND (?2 IS NULL OR status = ?2)",
+        )
+        .bind(before)
+        .bind(status.map(|value| value.as_str()))
+        .fetch_one(self.db.pool())
+        .await?;
+        Ok(u64::try_from(count).unwrap_or(0))
+    }
+
+    /// Deletes history rows started strictly before `before`, optionally only those with `status`.
+    ///
+    /// Only `download_log` is touched; queue items are left as they are.
+    /// Returns the number of rows removed. The freed pages stay in the file
+    /// until [`crate::Database::compact`] runs.
+    ///
+    /// # Errors
+    ///
+    /// Returns database errors when the delete fails.
+    #[instrument(skip(self))]
+    pub async fn prune_download_attempts(
+        &self,
+        before: &str,
+        status: Option<DownloadAttemptStatus>,
+    ) -> Result<u64> {
+        let result = sqlx::query(
+            r"DELETE FROM download_log
+              WHERE started_at < ?1 AND (?2 IS NULL OR status = ?2)",
+        )
+        .bind(before)
+        .bind(status.map(|value| value.as_str()))
+        .execute(self.db.pool())
+        .await?;
+        Ok(result.rows_affected())
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -518,6 +631,7 @@ async fn query_download_attempts_page(
     until: Option<&str>,
     after_id: Option<i64>,
     before_id: Option<i64>,
+    error_type: Option<DownloadErrorType>,
     uncertain_only: bool,
     limit: i64,
 ) -> Result<Vec<DownloadAttempt>> {
@@ -541,7 +655,9 @@ async fn query_download_attempts_page(
                 last_retry_at,
                 original_input,
                 http_status,
-                duration_ms
+                duration_ms,
+                resolver_path,
+                input_source_file
               FROM download_log
               WHERE (?1 IS NULL OR status = ?1)
                 AND (?2 IS NULL OR project = ?2)
@@ -549,9 +665,10 @@ async fn query_download_attempts_page(
                 AND (?4 IS NULL OR started_at <= ?4)
                 AND (?5 IS NULL OR id > ?5)
                 AND (?6 IS NULL OR id < ?6)
+                AND (?7 IS NULL OR error_type = ?7)
                 AND parse_confidence = 'low'
               ORDER BY id DESC
-              LIMIT ?7",
+              LIMIT ?8",
         )
         .bind(status.map(|value| value.as_str()))
         .bind(project)
@@ -559,6 +676,7 @@ async fn query_download_attempts_page(
         .bind(until)
         .bind(after_id)
         .bind(before_id)
+        .bind(error_type.map(|value| value.as_str()))
         .bind(limit)
         .fetch_all(queue.db.pool())
         .await?
@@ -582,7 +700,9 @@ async fn query_download_attempts_page(
                 last_retry_at,
                 original_input,
                 http_status,
-                duration_ms
+                duration_ms,
+                resolver_path,
+                input_source_file
               FROM download_log
               WHERE (?1 IS NULL OR status = ?1)
                 AND (?2 IS NULL OR project = ?2)
@@ -590,8 +710,9 @@ async fn query_download_attempts_page(
                 AND (?4 IS NULL OR started_at <= ?4)
                 AND (?5 IS NULL OR id > ?5)
                 AND (?6 IS NULL OR id < ?6)
+                AND (?7 IS NULL OR error_type = ?7)
               ORDER BY id DESC
-              LIMIT ?7",
+              LIMIT ?8",
         )
         .bind(status.map(|value| value.as_str()))
         .bind(project)
@@ -599,6 +720,7 @@ async fn query_download_attempts_page(
         .bind(until)
         .bind(after_id)
         .bind(before_id)
+        .bind(error_type.map(|value| value.as_str()))
         .bind(limit)
         .fetch_all(queue.db.pool())
         .await?
@@ -672,6 +794,16 @@ mod tests {
         assert_eq!(DownloadErrorType::Auth.as_str(), "auth");
         assert_eq!(DownloadErrorType::NotFound.as_str(), "not_found");
         assert_eq!(DownloadErrorType::ParseError.as_str(), "parse_error");
+        assert_eq!(DownloadErrorType::DiskFull.as_str(), "disk_full");
+        assert_eq!(
+            DownloadErrorType::TooManyRedirects.as_str(),
+            "too_many_redirects"
+        );
+        assert_eq!(DownloadErrorType::Dns.as_str(), "dns");
+        assert_eq!(
+            DownloadErrorType::UnexpectedContentType.as_str(),
+            "unexpected_content_type"
+        );
     }
 
     #[test]
@@ -692,6 +824,26 @@ mod tests {
             "parse_error".parse::<DownloadErrorType>().ok(),
             Some(DownloadErrorType::ParseError)
         );
+        assert_eq!(
+            "disk_full".parse::<DownloadErrorType>().ok(),
+            Some(DownloadErrorType::DiskFull)
+        );
+        assert_eq!(
+            "too_many_redirects".parse::<DownloadErrorType>().ok(),
+            Some(DownloadErrorType::TooManyRedirects)
+        );
+        for error_type in [
+            DownloadErrorType::Tls,
+            DownloadErrorType::Dns,
+            DownloadErrorType::ConnectionReset,
+            DownloadErrorType::Timeout,
+            DownloadErrorType::RobotsDisallowed,
+            DownloadErrorType::TooLarge,
+            DownloadErrorType::ChecksumMismatch,
+            DownloadErrorType::UnexpectedContentType,
+        ] {
+            assert_eq!(error_type.as_str().parse().ok(), Some(error_type));
+        }
         assert!("other".parse::<DownloadErrorType>().is_err());
     }
 
@@ -1,6 +1,7 @@
 //! Queue item types and status definitions.
 
 use std::fmt;
+use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
@@ -61,6 +62,10 @@ pub struct QueueMetadata {
     pub title: Option<String>,
     /// Resolved authors metadata.
     pub authors: Option<String>,
+    /// Per-author ORCID iDs aligned with [`Self::authors`] (`;`-separated, empty slot = none).
+    pub author_orcids: Option<String>,
+    /// Plain-text abstract from the resolver's `abstract` metadata key.
+    pub abstract_text: Option<String>,
     /// Resolved publication year metadata.
     pub year: Option<String>,
     /// Resolved DOI metadata.
@@ -82,10 +87,24 @@ pub struct QueueMetadata {
     /// Used for debugging parsing quality and informational display only —
     /// not used in any download control logic.
     pub parse_confidence_factors: Option<String>,
+    /// Read timeout override in seconds for this item's download.
+    ///
+    /// For large files that legitimately outlast the client's default read
+    /// timeout; `None` keeps the default.
+    pub read_timeout_secs: Option<u64>,
+    /// Resolvers that produced the URL (see [`ResolvedUrl::resolver_path`](crate::ResolvedUrl::resolver_path)).
+    pub resolver_path: Option<String>,
+    /// Input file the item was read from (`-` input is recorded as `stdin`).
+    ///
+    /// `None` for items given directly on the command line or via the GUI.
+    pub input_source_file: Option<String>,
 }
 
 /// A single item in the download queue.
-#[derive(Debug, Clone, FromRow)]
+///
+/// Serializes with the database column names (`status` for [`Self::status_str`])
+/// so exported queue files mirror the on-disk schema.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
 pub struct QueueItem {
     /// Unique identifier.
     pub id: i64,
@@ -99,6 +118,7 @@ pub struct QueueItem {
     pub project: Option<String>,
     /// Current processing status (stored as text, parsed via `status()`).
     #[sqlx(rename = "status")]
+    #[serde(rename = "status")]
     pub status_str: String,
     /// Higher priority items processed first (default 0).
     pub priority: i64,
@@ -112,6 +132,12 @@ pub struct QueueItem {
     pub meta_title: Option<String>,
     /// Metadata authors captured at enqueue time.
     pub meta_authors: Option<String>,
+    /// Per-author ORCID iDs captured at enqueue time, aligned with `meta_authors`.
+    #[serde(default)]
+    pub meta_author_orcids: Option<String>,
+    /// Plain-text abstract captured at enqueue time.
+    #[serde(default)]
+    pub meta_abstract: Option<String>,
     /// Metadata year captured at enqueue time.
     pub meta_year: Option<String>,
     /// Metadata DOI captured at enqueue time.
@@ -134,9 +160,25 @@ pub struct QueueItem {
     /// Final saved path when download completes.
     pub saved_path: Option<String>,
     /// Bytes currently written for this item (supports resume).
+    #[serde(default)]
     pub bytes_downloaded: i64,
     /// Expected total bytes when known (from Content-Length).
     pub content_length: Option<i64>,
+    /// Set when the item failed permanently and must be requeued explicitly.
+    ///
+    /// Dead-lettered rows keep the `failed` status; see
+    /// [`crate::Queue::list_dead_letter`] and [`crate::Queue::requeue_dead_letter`].
+    #[serde(default)]
+    pub dead_letter: bool,
+    /// Read timeout override in seconds (see [`QueueMetadata::read_timeout_secs`]).
+    #[serde(default)]
+    pub read_timeout_secs: Option<i64>,
+    /// Resolvers that produced the URL (see [`QueueMetadata::resolver_path`]).
+    #[serde(default)]
+    pub resolver_path: Option<String>,
+    /// Input file the item was read from (see [`QueueMetadata::input_source_file`]).
+    #[serde(default)]
+    pub input_source_file: Option<String>,
     /// When the item was created.
     pub created_at: String,
     /// When the item was last updated.
@@ -182,6 +224,15 @@ impl QueueItem {
 
         serde_json::to_string(topics).ok()
     }
+
+    /// Returns the item's read timeout override, if one is set and positive.
+    #[must_use]
+    pub fn read_timeout(&self) -> Option<Duration> {
+        self.read_timeout_secs
+            .and_then(|secs| u64::try_from(secs).ok())
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs)
+    }
 }
 
 impl fmt::Display for QueueItem {
@@ -285,6 +336,8 @@ mod tests {
             suggested_filename: None,
             meta_title: None,
             meta_authors: None,
+            meta_author_orcids: None,
+            meta_abstract: None,
             meta_year: None,
             meta_doi: None,
             topics: None,
@@ -293,6 +346,10 @@ mod tests {
             saved_path: None,
             bytes_downloaded: 0,
             content_length: None,
+            dead_letter: false,
+            read_timeout_secs: None,
+            resolver_path: None,
+            input_source_file: None,
             created_at: "2026-01-01".to_string(),
             updated_at: "2026-01-01".to_string(),
         };
@@ -315,6 +372,8 @@ mod tests {
             suggested_filename: None,
             meta_title: None,
             meta_authors: None,
+            meta_author_orcids: None,
+            meta_abstract: None,
             meta_year: None,
             meta_doi: None,
             topics: None,
@@ -323,6 +382,10 @@ mod tests {
             saved_path: None,
             bytes_downloaded: 0,
             content_length: None,
+            dead_letter: false,
+            read_timeout_secs: None,
+            resolver_path: None,
+            input_source_file: None,
             created_at: "2026-01-01".to_string(),
             updated_at: "2026-01-01".to_string(),
         };
@@ -345,6 +408,8 @@ mod tests {
             suggested_filename: None,
             meta_title: None,
             meta_authors: None,
+            meta_author_orcids: None,
+            meta_abstract: None,
             meta_year: None,
             meta_doi: None,
             topics: None,
@@ -353,6 +418,10 @@ mod tests {
             saved_path: None,
             bytes_downloaded: 0,
             content_length: None,
+            dead_letter: false,
+            read_timeout_secs: None,
+            resolver_path: None,
+            input_source_file: None,
             created_at: "2026-01-01".to_string(),
             updated_at: "2026-01-01".to_string(),
         };
@@ -393,6 +462,8 @@ mod tests {
             suggested_filename: None,
             meta_title: None,
             meta_authors: None,
+            meta_author_orcids: None,
+            meta_abstract: None,
             meta_year: None,
             meta_doi: None,
             topics: None,
@@ -401,6 +472,10 @@ mod tests {
             saved_path: None,
             bytes_downloaded: 0,
             content_length: None,
+            dead_letter: false,
+            read_timeout_secs: None,
+            resolver_path: None,
+            input_source_file: None,
             created_at: "2026-01-01".to_string(),
             updated_at: "2026-01-01".to_string(),
         };
@@ -424,6 +499,8 @@ mod tests {
             suggested_filename: None,
             meta_title: None,
             meta_authors: None,
+            meta_author_orcids: None,
+            meta_abstract: None,
             meta_year: None,
             meta_doi: None,
             topics: Some(json),
@@ -432,6 +509,10 @@ mod tests {
             saved_path: None,
             bytes_downloaded: 0,
             content_length: None,
+            dead_letter: false,
+            read_timeout_secs: None,
+            resolver_path: None,
+            input_source_file: None,
             created_at: "2026-01-01".to_string(),
             updated_at: "2026-01-01".to_string(),
         };
@@ -453,6 +534,8 @@ mod tests {
             suggested_filename: None,
             meta_title: None,
             meta_authors: None,
+            meta_author_orcids: None,
+            meta_abstract: None,
             meta_year: None,
             meta_doi: None,
             topics: Some("not json".to_string()),
@@ -461,9 +544,35 @@ mod tests {
             saved_path: None,
             bytes_downloaded: 0,
             content_length: None,
+            dead_letter: false,
+            read_timeout_secs: None,
+            resolver_path: None,
+            input_source_file: None,
             created_at: "2026-01-01".to_string(),
             updated_at: "2026-01-01".to_string(),
         };
         assert!(item.parse_topics().is_empty());
     }
+
+    #[test]
+    fn test_read_timeout_ignores_missing_and_non_positive_values() {
+        let mut item: QueueItem = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "url": "https://example.com/big.zip",
+            "source_type": "direct_url",
+            "status": "pending",
+            "priority": 0,
+            "retry_count": 0,
+            "created_at": "2026-01-01",
+            "updated_at": "2026-01-01",
+        }))
+        .unwrap();
+        assert_eq!(item.read_timeout(), None);
+
+        item.read_timeout_secs = Some(0);
+        assert_eq!(item.read_timeout(), None);
+
+        item.read_timeout_secs = Some(900);
+        assert_eq!(item.read_timeout(), Some(Duration::from_secs(900)));
+    }
 }
@@ -2,6 +2,14 @@
 //!
 //! This module provides `SQLite`-backed queue management for tracking
 //! download items through their lifecycle (pending → `in_progress` → completed/failed).
+//! Permanent failures are additionally flagged as dead-lettered and are only
+//! retried after an explicit requeue.
+//!
+//! Claimed rows carry a lease (`claimed_by`, `claimed_at`) so several processes
+//! can share one database: running downloads renew their lease every
+//! [`CLAIM_RENEW_INTERVAL`], and crash recovery only reclaims rows claimed by
+//! this process, by a process that has exited, or whose lease is older than
+//! [`DEFAULT_CLAIM_LEASE_TIMEOUT`].
 //!
 //! # Overview
 //!
@@ -10,6 +18,7 @@
 //! - [`QueueItem`] - Individual queue entry with metadata
 //! - [`QueueStatus`] - Item lifecycle states
 //! - [`QueueError`] - Operation error types
+//! - [`Queue::export_json`] / [`Queue::import_json`] - Portable JSON queue snapshots
 //!
 //! # Example
 //!
@@ -31,11 +40,15 @@
 //! }
 //! ```
 
+mod budget;
 mod error;
 mod history;
 mod item;
 mod repository;
+mod stats;
+mod transfer;
 
+pub use budget::BYTE_BUDGET_WINDOW_DAYS;
 pub use error::QueueError;
 pub use history::{
     DownloadAttempt, DownloadAttemptQuery, DownloadAttemptStatus, DownloadErrorType,
@@ -43,6 +56,11 @@ pub use history::{
 };
 pub use item::{QueueItem, QueueMetadata, QueueStatus};
 pub use repository::QueueRepository;
+pub use stats::{DomainStats, DomainStatsCollector, ThroughputSummary, UNKNOWN_STATS_HOST};
+pub(crate) use stats::{ThroughputAccumulator, stats_host};
+
+use std::sync::LazyLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use crate::db::Database;
 use sqlx::Row;
@@ -63,6 +81,65 @@ const DEFAULT_PRIORITY: i64 = 0;
 /// Default retry count for new queue items.
 const DEFAULT_RETRY_COUNT: i64 = 0;
 
+/// Age after which another process's claim on an `in_progress` row is
+/// considered abandoned by [`Queue::reset_in_progress`].
+///
+/// Leases are stamped at claim time and renewed every [`CLAIM_RENEW_INTERVAL`]
+/// while the download runs, so this only needs to exceed a few missed renewals.
+pub const DEFAULT_CLAIM_LEASE_TIMEOUT: Duration = Duration::from_secs(60 * 60);
+
+/// How often a running download renews its claim lease via [`Queue::renew_claim`].
+pub const CLAIM_RENEW_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// Lease owner stamped on rows claimed by this process.
+///
+/// The process start time is included so a recycled PID is not mistaken for
+/// the process that originally claimed a row.
+static PROCESS_CLAIMANT: LazyLock<String> = LazyLock::new(|| {
+    let started = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos())
+        .unwrap_or_default();
+    format!("pid-{}-{started}", std::process::id())
+});
+
+/// Extracts the PID from a `pid-{pid}-{started}` claimant, if it has that shape.
+fn claimant_pid(claimant: &str) -> Option<u32> {
+    claimant
+        .strip_prefix("pid-")?
+        .split('-')
+        .next()?
+        .parse()
+        .ok()
+}
+
+/// Returns whether a process with `pid` still exists on this host.
+#[cfg(unix)]
+fn process_is_alive(pid: u32) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+    if pid <= 0 {
+        // 0 and negative values address process groups, not a single process.
+        return true;
+    }
+    // SAFETY: signal 0 delivers nothing; it only checks existence and permission.
+    let result = unsafe { libc::kill(pid, 0) };
+    result == 0 || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+/// Without a portable liveness check, every claimant is assumed alive and only
+/// the lease timeout applies.
+#[cfg(not(unix))]
+fn process_is_alive(_pid: u32) -> bool {
+    true
+}
+
+/// `SQLite` `datetime('now', ?)` modifier selecting leases older than `age`.
+fn lease_cutoff_modifier(age: Duration) -> String {
+    format!("-{} seconds", age.as_secs())
+}
+
 /// Result type for queue operations.
 pub type Result<T> = std::result::Result<T, QueueError>;
 
@@ -155,10 +232,40 @@ impl Queue {
         original_input: Option<&str>,
         metadata: Option<&QueueMetadata>,
         project: Option<&str>,
+    ) -> Result<i64> {
+        self.enqueue_with_priority(
+            url,
+            source_type,
+            original_input,
+            metadata,
+            project,
+            DEFAULT_PRIORITY,
+        )
+        .await
+    }
+
+    /// Adds a new pending item with an explicit priority.
+    ///
+    /// Higher priorities are dequeued first; ties are served oldest-first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueError::Database`] if the insert fails.
+    #[instrument(skip(self, metadata), fields(url = %url, source_type = %source_type, project = project.unwrap_or("<none>")))]
+    pub async fn enqueue_with_priority(
+        &self,
+        url: &str,
+        source_type: &str,
+        original_input: Option<&str>,
+        metadata: Option<&QueueMetadata>,
+        project: Option<&str>,
+        priority: i64,
     ) -> Result<i64> {
         let suggested_filename = metadata.and_then(|m| m.suggested_filename.as_deref());
         let title = metadata.and_then(|m| m.title.as_deref());
         let authors = metadata.and_then(|m| m.authors.as_deref());
+        let author_orcids = metadata.and_then(|m| m.author_orcids.as_deref());
+        let abstract_text = metadata.and_then(|m| m.abstract_text.as_deref());
         let year = metadata.and_then(|m| m.year.as_deref());
         let doi = metadata.and_then(|m| m.doi.as_deref());
         let topics_json = metadata
@@ -166,6 +273,11 @@ impl Queue {
             .and_then(|t| QueueItem::serialize_topics(t));
         let parse_confidence = metadata.and_then(|m| m.parse_confidence.as_deref());
         let parse_confidence_factors = metadata.and_then(|m| m.parse_confidence_factors.as_deref());
+        let read_timeout_secs = metadata
+            .and_then(|m| m.read_timeout_secs)
+            .and_then(|secs| i64::try_from(secs).ok());
+        let resolver_path = metadata.and_then(|m| m.resolver_path.as_deref());
+        let input_source_file = metadata.and_then(|m| m.input_source_file.as_deref());
 
         let result = sqlx::query(
             r"INSERT INTO queue (
@@ -179,13 +291,18 @@ impl Queue {
                 suggested_filename,
                 meta_title,
                 meta_authors,
+                meta_author_orcids,
+                meta_abstract,
                 meta_year,
                 meta_doi,
                 topics,
                 parse_confidence,
-                parse_confidence_factors
+                parse_confidence_factors,
+                read_timeout_secs,
+                resolver_path,
+                input_source_file
               )
-              VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+              VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
               RETURNING id",
         )
         .bind(url)
@@ -193,16 +310,21 @@ impl Queue {
         .bind(original_input)
         .bind(project)
         .bind(QueueStatus::Pending.as_str())
-        .bind(DEFAULT_PRIORITY)
+        .bind(priority)
         .bind(DEFAULT_RETRY_COUNT)
         .bind(suggested_filename)
         .bind(title)
         .bind(authors)
+        .bind(author_orcids)
+        .bind(abstract_text)
         .bind(year)
         .bind(doi)
         .bind(topics_json)
         .bind(parse_confidence)
         .bind(parse_confidence_factors)
+        .bind(read_timeout_secs)
+        .bind(resolver_path)
+        .bind(input_source_file)
         .fetch_one(self.db.pool())
         .await?;
 
@@ -211,8 +333,9 @@ impl Queue {
 
     /// Retrieves and claims the next pending item for processing.
     ///
-    /// Atomically transitions the highest-priority pending item to `in_progress`
-    /// and returns it. Returns None if no pending items exist.
+    /// Atomically transitions the highest-priority pending item to `in_progress`,
+    /// stamps this process's claim lease on it, and returns it. Returns None if
+    /// no pending items exist.
     ///
     /// # Errors
     ///
@@ -232,7 +355,8 @@ impl Queue {
         // Atomic UPDATE...RETURNING ensures no race condition between select and update
         let item = sqlx::query_as::<_, QueueItem>(
             r"UPDATE queue
-              SET status = ?, updated_at = datetime('now')
+              SET status = ?, claimed_by = ?, claimed_at = datetime('now'),
+                  updated_at = datetime('now')
               WHERE id = (
                   SELECT id FROM queue
                   WHERE status = ?
@@ -243,6 +367,7 @@ impl Queue {
               RETURNING *",
         )
         .bind(QueueStatus::InProgress.as_str())
+        .bind(PROCESS_CLAIMANT.as_str())
         .bind(QueueStatus::Pending.as_str())
         .bind(project)
         .bind(project)
@@ -316,8 +441,41 @@ impl Queue {
         check_affected(id, result.rows_affected())
     }
 
+    /// Marks an item as permanently failed and moves it to the dead-letter set.
+    ///
+    /// The row keeps the `failed` status with `dead_letter` set, so crash
+    /// recovery and retry flows leave it alone until [`Queue::requeue_dead_letter`]
+    /// is called explicitly.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueError::ItemNotFound`] if no item exists with the given ID.
+    /// Returns [`QueueError::Database`] if the update fails.
+    #[instrument(skip(self), fields(error = %error, retry_count))]
+    pub async fn mark_dead_letter(&self, id: i64, error: &str, retry_count: i64) -> Result<()> {
+        let result = sqlx::query(
+            r"UPDATE queue
+              SET status = ?,
+                  dead_letter = 1,
+                  retry_count = ?,
+                  last_error = ?,
+                  updated_at = datetime('now')
+              WHERE id = ?",
+        )
+        .bind(QueueStatus::Failed.as_str())
+        .bind(retry_count)
+        .bind(error)
+        .bind(id)
+        .execute(self.db.pool())
+        .await?;
+
+        check_affected(id, result.rows_affected())
+    }
+
     /// Updates partial download progress metadata for resumable downloads.
     ///
+    /// Also renews the claim lease when this process holds it.
+    ///
     /// # Errors
     ///
     /// Returns [`QueueError::ItemNotFound`] if no item exists with the given ID.
@@ -331,11 +489,13 @@ impl Queue {
     ) -> Result<()> {
         let result = sqlx::query(
             r"UPDATE queue
-              SET bytes_downloaded = ?, content_length = ?, updated_at = datetime('now')
+              SET bytes_downloaded = ?, content_length = ?, updated_at = datetime('now'),
+                  claimed_at = CASE WHEN claimed_by = ? THEN datetime('now') ELSE claimed_at END
               WHERE id = ?",
         )
         .bind(bytes_downloaded)
         .bind(content_length)
+        .bind(PROCESS_CLAIMANT.as_str())
         .bind(id)
         .execute(self.db.pool())
         .await?;
@@ -343,6 +503,71 @@ impl Queue {
         check_affected(id, result.rows_affected())
     }
 
+    /// Renews this process's claim lease on an in-progress item.
+    ///
+    /// Returns `false` when the row is no longer claimed by this process (for
+    /// example because another process's recovery reclaimed it).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueError::Database`] if the update fails.
+    #[instrument(skip(self))]
+    pub async fn renew_claim(&self, id: i64) -> Result<bool> {
+        let result = sqlx::query(
+            r"UPDATE queue
+              SET claimed_at = datetime('now')
+              WHERE id = ? AND status = ? AND claimed_by = ?",
+        )
+        .bind(id)
+        .bind(QueueStatus::InProgress.as_str())
+        .bind(PROCESS_CLAIMANT.as_str())
+        .execute(self.db.pool())
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Replaces an item's topics (stored as a JSON array; empty clears them).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueError::ItemNotFound`] if no item exists with the given ID.
+    /// Returns [`QueueError::Database`] if the update fails.
+    #[instrument(skip(self, topics))]
+    pub async fn set_topics(&self, id: i64, topics: &[String]) -> Result<()> {
+        let result = sqlx::query(
+            r"UPDATE queue
+              SET topics = ?, updated_at = datetime('now')
+              WHERE id = ?",
+        )
+        .bind(QueueItem::serialize_topics(topics))
+        .bind(id)
+        .execute(self.db.pool())
+        .await?;
+
+        check_affected(id, result.rows_affected())
+    }
+
+    /// Points a completed item at a file that was moved after download.
+    ///
+    /// Unlike [`Queue::mark_completed_with_path`], status and `updated_at`
+    /// (the completion time) are left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueError::ItemNotFound`] if no item exists with the given ID.
+    /// Returns [`QueueError::Database`] if the update fails.
+    #[instrument(skip(self))]
+    pub async fn set_saved_path(&self, id: i64, saved_path: &std::path::Path) -> Result<()> {
+        let result = sqlx::query("UPDATE queue SET saved_path = ? WHERE id = ?")
+            .bind(saved_path.to_str())
+            .bind(id)
+            .execute(self.db.pool())
+            .await?;
+
+        check_affected(id, result.rows_affected())
+    }
+
     /// Returns an item to pending status for retry.
     ///
     /// Used when an item needs to be reprocessed (e.g., after transient failure).
@@ -355,7 +580,7 @@ impl Queue {
     pub async fn requeue(&self, id: i64) -> Result<()> {
         let result = sqlx::query(
             r"UPDATE queue
-              SET status = ?, updated_at = datetime('now')
+              SET status = ?, claimed_by = NULL, claimed_at = NULL, updated_at = datetime('now')
               WHERE id = ?",
         )
         .bind(QueueStatus::Pending.as_str())
@@ -366,6 +591,30 @@ impl Queue {
         check_affected(id, result.rows_affected())
     }
 
+    /// Changes the priority of an existing item.
+    ///
+    /// `dequeue` reads priorities at claim time, so raising a pending item's
+    /// priority moves it ahead of other pending work even mid-run.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueError::ItemNotFound`] if no item exists with the given ID.
+    /// Returns [`QueueError::Database`] if the update fails.
+    #[instrument(skip(self))]
+    pub async fn set_priority(&self, id: i64, priority: i64) -> Result<()> {
+        let result = sqlx::query(
+            r"UPDATE queue
+              SET priority = ?, updated_at = datetime('now')
+              WHERE id = ?",
+        )
+        .bind(priority)
+        .bind(id)
+        .execute(self.db.pool())
+        .await?;
+
+        check_affected(id, result.rows_affected())
+    }
+
     /// Gets a queue item by ID.
     ///
     /// # Errors
@@ -422,6 +671,32 @@ impl Queue {
         Ok(result.get("count"))
     }
 
+    /// Returns how many seconds ago the oldest item with `status` was enqueued,
+    /// under an optional project scope, or `None` when no item has that status.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueError::Database`] if the query fails.
+    #[instrument(skip(self), fields(project = project.unwrap_or("<none>"), status = %status))]
+    pub async fn oldest_item_age_secs_in_project(
+        &self,
+        status: QueueStatus,
+        project: Option<&str>,
+    ) -> Result<Option<i64>> {
+        let age = sqlx::query_scalar::<_, Option<i64>>(
+            r"SELECT CAST((julianday('now') - julianday(MIN(created_at))) * 86400 AS INTEGER)
+              FROM queue
+              WHERE status = ?1
+                AND (?2 IS NULL OR project = ?2)",
+        )
+        .bind(status.as_str())
+        .bind(project)
+        .fetch_one(self.db.pool())
+        .await?;
+
+        Ok(age.map(|secs| secs.max(0)))
+    }
+
     /// Checks whether a URL already has a pending or in-progress queue entry.
     ///
     /// Used to avoid duplicate enqueue when resuming with the same input.
@@ -508,10 +783,14 @@ impl Queue {
         Ok(items)
     }
 
-    /// Resets all in-progress items back to pending status.
+    /// Resets abandoned in-progress items back to pending status.
     ///
-    /// Called at startup for crash recovery - any items left `in_progress`
-    /// from a previous session are returned to the queue for reprocessing.
+    /// Called at startup for crash recovery. Items claimed by this process, by
+    /// a process that is no longer running on this host, or without a lease
+    /// (claimed before leases existed), are always reset; items claimed by a
+    /// live process are reset only once their lease is older than
+    /// [`DEFAULT_CLAIM_LEASE_TIMEOUT`], so a process sharing the database keeps
+    /// its work.
     ///
     /// # Returns
     ///
@@ -525,23 +804,109 @@ impl Queue {
         self.reset_in_progress_in_project(None).await
     }
 
-    /// Resets in-progress rows back to pending status under an optional project scope.
+    /// Resets abandoned in-progress rows back to pending status under an
+    /// optional project scope. See [`Queue::reset_in_progress`].
     ///
     /// # Errors
     ///
     /// Returns [`QueueError::Database`] if the update fails.
     #[instrument(skip(self), fields(project = project.unwrap_or("<none>")))]
     pub async fn reset_in_progress_in_project(&self, project: Option<&str>) -> Result<u64> {
+        let mut reset = self
+            .reset_claims_in_project(
+                project,
+                Some(PROCESS_CLAIMANT.as_str()),
+                DEFAULT_CLAIM_LEASE_TIMEOUT,
+            )
+            .await?;
+        for claimant in self.dead_claimants_in_project(project).await? {
+            reset += self
+                .reset_claims_in_project(project, Some(&claimant), DEFAULT_CLAIM_LEASE_TIMEOUT)
+                .await?;
+        }
+        Ok(reset)
+    }
+
+    /// Lists claimants of in-progress rows whose process has exited.
+    ///
+    /// Claimants that do not carry a PID are never reported.
+    async fn dead_claimants_in_project(&self, project: Option<&str>) -> Result<Vec<String>> {
+        let claimants: Vec<String> = sqlx::query_scalar(
+            r"SELECT DISTINCT claimed_by FROM queue
+              WHERE status = ? AND claimed_by IS NOT NULL
+                AND (? IS NULL OR project = ?)",
+        )
+        .bind(QueueStatus::InProgress.as_str())
+        .bind(project)
+        .bind(project)
+        .fetch_all(self.db.pool())
+        .await?;
+
+        Ok(claimants
+            .into_iter()
+            .filter(|claimant| claimant_pid(claimant).is_some_and(|pid| !process_is_alive(pid)))
+            .collect())
+    }
+
+    /// Resets in-progress items whose claim lease is older than `older_than`,
+    /// whichever process holds it.
+    ///
+    /// Use a shorter timeout than [`DEFAULT_CLAIM_LEASE_TIMEOUT`] when the other
+    /// processes sharing the database are known to have exited.
+    ///
+    /// # Returns
+    ///
+    /// The number of items that were reset.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueError::Database`] if the update fails.
+    #[instrument(skip(self))]
+    pub async fn reset_stale_in_progress(&self, older_than: Duration) -> Result<u64> {
+        self.reset_stale_in_progress_in_project(None, older_than)
+            .await
+    }
+
+    /// Resets in-progress rows with stale claim leases under an optional project scope.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueError::Database`] if the update fails.
+    #[instrument(skip(self), fields(project = project.unwrap_or("<none>")))]
+    pub async fn reset_stale_in_progress_in_project(
+        &self,
+        project: Option<&str>,
+        older_than: Duration,
+    ) -> Result<u64> {
+        self.reset_claims_in_project(project, None, older_than)
+            .await
+    }
+
+    /// Returns in-progress rows to pending when they have no lease, a lease
+    /// held by `own_claimant`, or a lease at least `older_than` old.
+    async fn reset_claims_in_project(
+        &self,
+        project: Option<&str>,
+        own_claimant: Option<&str>,
+        older_than: Duration,
+    ) -> Result<u64> {
         let result = sqlx::query(
             r"UPDATE queue
-              SET status = ?, updated_at = datetime('now')
+              SET status = ?, claimed_by = NULL, claimed_at = NULL, updated_at = datetime('now')
               WHERE status = ?
-                AND (? IS NULL OR project = ?)",
+                AND (? IS NULL OR project = ?)
+                AND (
+                    claimed_at IS NULL
+                    OR claimed_by = ?
+                    OR claimed_at <= datetime('now', ?)
+                )",
         )
         .bind(QueueStatus::Pending.as_str())
         .bind(QueueStatus::InProgress.as_str())
         .bind(project)
         .bind(project)
+        .bind(own_claimant)
+        .bind(lease_cutoff_modifier(older_than))
         .execute(self.db.pool())
         .await?;
 
@@ -588,6 +953,89 @@ impl Queue {
         Ok(items)
     }
 
+    /// Lists dead-lettered items (permanent failures awaiting explicit requeue).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueError::Database`] if the query fails.
+    #[instrument(skip(self))]
+    pub async fn list_dead_letter(&self) -> Result<Vec<QueueItem>> {
+        self.list_dead_letter_in_project(None).await
+    }
+
+    /// Lists dead-lettered items under an optional project scope.
+    ///
+    /// Returns items ordered by last update (oldest first).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueError::Database`] if the query fails.
+    #[instrument(skip(self), fields(project = project.unwrap_or("<none>")))]
+    pub async fn list_dead_letter_in_project(
+        &self,
+        project: Option<&str>,
+    ) -> Result<Vec<QueueItem>> {
+        let items = sqlx::query_as::<_, QueueItem>(
+            r"SELECT * FROM queue
+              WHERE dead_letter = 1
+                AND (? IS NULL OR project = ?)
+              ORDER BY updated_at ASC, id ASC",
+        )
+        .bind(project)
+        .bind(project)
+        .fetch_all(self.db.pool())
+        .await?;
+
+        Ok(items)
+    }
+
+    /// Returns a dead-lettered item to pending status with a fresh retry budget.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueError::ItemNotFound`] if no dead-lettered item exists with the given ID.
+    /// Returns [`QueueError::Database`] if the update fails.
+    #[instrument(skip(self))]
+    pub async fn requeue_dead_letter(&self, id: i64) -> Result<()> {
+        let result = sqlx::query(
+            r"UPDATE queue
+              SET status = ?, dead_letter = 0, retry_count = 0, updated_at = datetime('now')
+              WHERE id = ? AND dead_letter = 1",
+        )
+        .bind(QueueStatus::Pending.as_str())
+        .bind(id)
+        .execute(self.db.pool())
+        .await?;
+
+        check_affected(id, result.rows_affected())
+    }
+
+    /// Returns every dead-lettered item under an optional project scope to pending.
+    ///
+    /// # Returns
+    ///
+    /// The number of items requeued.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueError::Database`] if the update fails.
+    #[instrument(skip(self), fields(project = project.unwrap_or("<none>")))]
+    pub async fn requeue_all_dead_letter_in_project(&self, project: Option<&str>) -> Result<u64> {
+        let result = sqlx::query(
+            r"UPDATE queue
+              SET status = ?, dead_letter = 0, retry_count = 0, updated_at = datetime('now')
+              WHERE dead_letter = 1
+                AND (? IS NULL OR project = ?)",
+        )
+        .bind(QueueStatus::Pending.as_str())
+        .bind(project)
+        .bind(project)
+        .execute(self.db.pool())
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
     /// Returns the number of legacy queue rows without project scope.
     ///
     /// This is used for diagnostics when transitioning from unscoped queue state.
@@ -30,6 +30,9 @@ pub trait QueueRepository {
     /// Marks an item failed with message and retry count.
     async fn mark_failed(&self, id: i64, error: &str, retry_count: i64) -> Result<()>;
 
+    /// Marks an item permanently failed (dead-lettered) with message and retry count.
+    async fn mark_dead_letter(&self, id: i64, error: &str, retry_count: i64) -> Result<()>;
+
     /// Updates bytes/content-length progress metadata.
     async fn update_progress(
         &self,
@@ -64,6 +67,9 @@ pub trait QueueRepository {
     /// Persists a terminal download attempt history row.
     async fn log_download_attempt(&self, attempt: &NewDownloadAttempt<'_>) -> Result<i64>;
 
+    /// Adds completed-download bytes to the rolling byte-budget counter.
+    async fn record_downloaded_bytes(&self, bytes: u64) -> Result<()>;
+
     /// Reads paginated download attempts.
     async fn query_download_attempts(
         &self,
@@ -99,6 +105,10 @@ impl QueueRepository for Queue {
         Queue::mark_failed(self, id, error, retry_count).await
     }
 
+    async fn mark_dead_letter(&self, id: i64, error: &str, retry_count: i64) -> Result<()> {
+        Queue::mark_dead_letter(self, id, error, retry_count).await
+    }
+
     async fn update_progress(
         &self,
         id: i64,
@@ -144,6 +154,10 @@ impl QueueRepository for Queue {
         Queue::log_download_attempt(self, attempt).await
     }
 
+    async fn record_downloaded_bytes(&self, bytes: u64) -> Result<()> {
+        Queue::record_downloaded_bytes(self, bytes).await
+    }
+
     async fn query_download_attempts(
         &self,
         query: &DownloadAttemptQuery,
@@ -214,6 +228,8 @@ mod tests {
             topics: None,
             parse_confidence: None,
             parse_confidence_factors: None,
+            resolver_path: None,
+            input_source_file: None,
         };
         QueueRepository::log_download_attempt(&queue, &attempt)
             .await
@@ -0,0 +1,284 @@
+//! Throughput and duration aggregates over download attempts.
+//!
+//! The same accumulator backs [`crate::DownloadStats::summary`] for a single
+//! engine run and [`Queue::query_domain_stats`] for lifetime history, so both
+//! report throughput and percentiles identically.
+
+use std::collections::BTreeMap;
+
+use sqlx::FromRow;
+use tracing::instrument;
+use url::Url;
+
+use super::{Queue, Result};
+
+/// Host label used when an attempt URL has no parseable host.
+pub const UNKNOWN_STATS_HOST: &str = "unknown";
+
+/// Aggregated transfer figures for a set of download attempts.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ThroughputSummary {
+    /// Attempts included in the aggregate (successful and failed).
+    pub attempts: u64,
+    /// Successful attempts.
+    pub succeeded: u64,
+    /// Bytes across successful attempts that recorded a size.
+    pub total_bytes: u64,
+    /// Average throughput in bytes per second, computed only from successful
+    /// attempts that recorded both a size and a non-zero duration.
+    pub bytes_per_sec: Option<f64>,
+    /// Median duration of successful attempts, in milliseconds.
+    pub p50_duration_ms: Option<u64>,
+    /// 95th percentile duration of successful attempts, in milliseconds.
+    pub p95_duration_ms: Option<u64>,
+}
+
+/// Aggregates for a single host.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DomainStats {
+    /// Lowercased host name, or [`UNKNOWN_STATS_HOST`].
+    pub domain: String,
+    /// Transfer figures for attempts against this host.
+    pub summary: ThroughputSummary,
+}
+
+/// Running totals that produce a [`ThroughputSummary`].
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ThroughputAccumulator {
+    attempts: u64,
+    succeeded: u64,
+    total_bytes: u64,
+    timed_bytes: u64,
+    timed_ms: u64,
+    durations_ms: Vec<u64>,
+}
+
+impl ThroughputAccumulator {
+    /// Records a failed attempt; it counts towards `attempts` only.
+    pub(crate) fn record_failure(&mut self) {
+        self.attempts = self.attempts.saturating_add(1);
+    }
+
+    /// Records a successful attempt. Missing sizes or durations are tolerated:
+    /// the attempt still counts, but is left out of the throughput average.
+    pub(crate) fn record_success(&mut self, bytes: Option<u64>, duration_ms: Option<u64>) {
+        self.attempts = self.attempts.saturating_add(1);
+        self.succeeded = self.succeeded.saturating_add(1);
+        if let Some(bytes) = bytes {
+            self.total_bytes = self.total_bytes.saturating_add(bytes);
+        }
+        if let Some(duration_ms) = duration_ms {
+            self.durations_ms.push(duration_ms);
+            if let Some(bytes) = bytes
+                && duration_ms > 0
+            {
+                self.timed_bytes = self.timed_bytes.saturating_add(bytes);
+                self.timed_ms = self.timed_ms.saturating_add(duration_ms);
+            }
+        }
+    }
+
+    pub(crate) fn summary(&self) -> ThroughputSummary {
+        let mut durations = self.durations_ms.clone();
+        durations.sort_unstable();
+        ThroughputSummary {
+            attempts: self.attempts,
+            succeeded: self.succeeded,
+            total_bytes: self.total_bytes,
+            bytes_per_sec: bytes_per_sec(self.timed_bytes, self.timed_ms),
+            p50_duration_ms: percentile(&durations, 50),
+            p95_duration_ms: percentile(&durations, 95),
+        }
+    }
+}
+
+/// Collects per-host aggregates, possibly across several history databases.
+#[derive(Debug, Clone, Default)]
+pub struct DomainStatsCollector {
+    by_host: BTreeMap<String, ThroughputAccumulator>,
+}
+
+impl DomainStatsCollector {
+    /// Creates an empty collector.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true when no attempts have been collected.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.by_host.is_empty()
+    }
+
+    pub(crate) fn record_success(
+        &mut self,
+        url: &str,
+        bytes: Option<u64>,
+        duration_ms: Option<u64>,
+    ) {
+        self.by_host
+            .entry(stats_host(url))
+            .or_default()
+            .record_success(bytes, duration_ms);
+    }
+
+    pub(crate) fn record_failure(&mut self, url: &str) {
+        self.by_host
+            .entry(stats_host(url))
+            .or_default()
+            .record_failure();
+    }
+
+    /// Returns one [`DomainStats`] per host, sorted by host name.
+    #[must_use]
+    pub fn finish(&self) -> Vec<DomainStats> {
+        self.by_host
+            .iter()
+            .map(|(domain, accumulator)| DomainStats {
+                domain: domain.clone(),
+                summary: accumulator.summary(),
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct DomainStatsRow {
+    url: String,
+    status: String,
+    file_size: Option<i64>,
+    duration_ms: Option<i64>,
+}
+
+impl Queue {
+    /// Aggregates success/failure attempts from `download_log` into `collector`,
+    /// grouped by host. Skipped rows are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns database errors when query execution fails.
+    #[instrument(skip(self, collector))]
+    pub async fn collect_domain_stats(
+        &self,
+        project: Option<&str>,
+        since: Option<&str>,
+        collector: &mut DomainStatsCollector,
+    ) -> Result<()> {
+        let rows: Vec<DomainStatsRow> = sqlx::query_as(
+            r"SELECT url, status, file_size, duration_ms
+              FROM download_log
+              WHERE status != 'skipped'
+                AND (?1 IS NULL OR project = ?1)
+                AND (?2 IS NULL OR started_at >= ?2)",
+        )
+        .bind(project)
+        .bind(since)
+        .fetch_all(self.db.pool())
+        .await?;
+
+        for row in rows {
+            if row.status == "success" {
+                collector.record_success(
+                    &row.url,
+                    row.file_size.and_then(|value| u64::try_from(value).ok()),
+                    row.duration_ms.and_then(|value| u64::try_from(value).ok()),
+                );
+            } else {
+                collector.record_failure(&row.url);
+            }
+        }
+        Ok(())
+    }
+
+    /// Lifetime per-host aggregates for this database, sorted by host name.
+    ///
+    /// # Errors
+    ///
+    /// Returns database errors when query execution fails.
+    pub async fn query_domain_stats(
+        &self,
+        project: Option<&str>,
+        since: Option<&str>,
+    ) -> Result<Vec<DomainStats>> {
+        let mut collector = DomainStatsCollector::new();
+        self.collect_domain_stats(project, since, &mut collector)
+            .await?;
+        Ok(collector.finish())
+    }
+}
+
+/// Lowercased host of `url`, or [`UNKNOWN_STATS_HOST`] when it has none.
+pub(crate) fn stats_host(url: &str) -> String {
+    Url::parse(url)
+        .ok()
+        .and_then(|parsed| parsed.host_str().map(str::to_ascii_lowercase))
+        .unwrap_or_else(|| UNKNOWN_STATS_HOST.to_string())
+}
+
+/// Nearest-rank percentile over an ascending slice.
+fn percentile(sorted: &[u64], pct: usize) -> Option<u64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = (sorted.len() * pct).div_ceil(100).max(1);
+    sorted.get(rank - 1).copied()
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn bytes_per_sec(bytes: u64, duration_ms: u64) -> Option<f64> {
+    (duration_ms > 0).then(|| bytes as f64 * 1000.0 / duration_ms as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentile_nearest_rank() {
+        let values: Vec<u64> = (1..=20).collect();
+        assert_eq!(percentile(&values, 50), Some(10));
+        assert_eq!(percentile(&values, 95), Some(19));
+        assert_eq!(percentile(&[7], 95), Some(7));
+        assert_eq!(percentile(&[], 50), None);
+    }
+
+    #[test]
+    fn test_accumulator_excludes_missing_sizes_from_throughput() {
+        let mut accumulator = ThroughputAccumulator::default();
+        accumulator.record_success(Some(2_000), Some(1_000));
+        accumulator.record_success(None, Some(5_000));
+        accumulator.record_success(Some(500), None);
+        accumulator.record_failure();
+
+        let summary = accumulator.summary();
+        assert_eq!(summary.attempts, 4);
+        assert_eq!(summary.succeeded, 3);
+        assert_eq!(summary.total_bytes, 2_500);
+        assert_eq!(summary.bytes_per_sec, Some(2_000.0));
+        assert_eq!(summary.p50_duration_ms, Some(1_000));
+        assert_eq!(summary.p95_duration_ms, Some(5_000));
+    }
+
+    #[test]
+    fn test_accumulator_without_timed_sizes_has_no_throughput() {
+        let mut accumulator = ThroughputAccumulator::default();
+        accumulator.record_success(None, Some(100));
+        accumulator.record_success(Some(10), Some(0));
+        assert_eq!(accumulator.summary().bytes_per_sec, None);
+    }
+
+    #[test]
+    fn test_collector_groups_by_lowercased_host() {
+        let mut collector = DomainStatsCollector::new();
+        collector.record_success("https://Example.org/a.pdf", Some(10), Some(10));
+        collector.record_failure("https://example.org/b.pdf");
+        collector.record_success("not a url", None, None);
+
+        let stats = collector.finish();
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].domain, "example.org");
+        assert_eq!(stats[0].summary.attempts, 2);
+        assert_eq!(stats[1].domain, UNKNOWN_STATS_HOST);
+    }
+}
@@ -0,0 +1,196 @@
+//! Queue export/import as JSON for moving a queue between machines.
+//!
+//! The export format is a JSON array of [`QueueItem`] rows using database
+//! column names. Import re-inserts each row with a fresh ID; the original
+//! `id` field is ignored.
+
+use std::io::{Read, Write};
+
+use tracing::{debug, instrument};
+
+use super::{Queue, QueueError, QueueItem, QueueStatus, Result};
+
+impl Queue {
+    /// Serializes every queue item (status and metadata included) as a JSON array.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueError::Database`] if the queue cannot be read.
+    /// Returns [`QueueError::Transfer`] if writing the JSON payload fails.
+    #[instrument(skip(self, writer))]
+    pub async fn export_json(&self, writer: impl Write) -> Result<()> {
+        let items = self.list_all().await?;
+        serde_json::to_writer_pretty(writer, &items)
+            .map_err(|error| QueueError::Transfer(error.to_string()))
+    }
+
+    /// Re-inserts queue items from a JSON array produced by [`Queue::export_json`].
+    ///
+    /// Each row keeps its original project scope. See
+    /// [`Queue::import_json_into_project`] to re-scope rows on import.
+    ///
+    /// # Returns
+    ///
+    /// The number of rows inserted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueError::Transfer`] if the payload is not a valid queue export.
+    /// Returns [`QueueError::Database`] if an insert fails.
+    #[instrument(skip(self, reader))]
+    pub async fn import_json(&self, reader: impl Read) -> Result<usize> {
+        self.import_json_into_project(reader, None).await
+    }
+
+    /// Re-inserts queue items from a JSON export, optionally re-scoping every
+    /// row to `project`.
+    ///
+    /// IDs are reassigned by the database. Rows whose URL already has an
+    /// active (pending or in-progress) entry in the target scope are skipped.
+    /// Rows exported while in progress are imported as pending, since nothing
+    /// is downloading them on this machine; completed rows stay completed with
+    /// their `saved_path` and are not downloaded again.
+    ///
+    /// # Returns
+    ///
+    /// The number of rows inserted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueError::Transfer`] if the payload is not a valid queue export.
+    /// Returns [`QueueError::Database`] if an insert fails.
+    #[instrument(skip(self, reader), fields(project = project.unwrap_or("<none>")))]
+    pub async fn import_json_into_project(
+        &self,
+        reader: impl Read,
+        project: Option<&str>,
+    ) -> Result<usize> {
+        let items: Vec<QueueItem> = serde_json::from_reader(reader)
+            .map_err(|error| QueueError::Transfer(error.to_string()))?;
+
+        let mut imported = 0_usize;
+        for item in &items {
+            let scope = project.or(item.project.as_deref());
+            if self.has_active_url_in_project(&item.url, scope).await? {
+                debug!(url = %item.url, "skipping import of already-active queue url");
+                continue;
+            }
+            self.insert_imported_item(item, scope).await?;
+            imported += 1;
+        }
+
+        Ok(imported)
+    }
+
+    async fn insert_imported_item(&self, item: &QueueItem, project: Option<&str>) -> Result<()> {
+        let status = match item.status() {
+            QueueStatus::InProgress => QueueStatus::Pending,
+            other => other,
+        };
+
+        sqlx::query(
+            r"INSERT INTO queue (
+                url,
+                source_type,
+                original_input,
+                project,
+                status,
+                priority,
+                retry_count,
+                last_error,
+                suggested_filename,
+                meta_title,
+                meta_authors,
+                meta_author_orcids,
+                meta_abstract,
+                meta_year,
+                meta_doi,
+                topics,
+                parse_confidence,
+                parse_confidence_factors,
+                saved_path,
+                bytes_downloaded,
+                content_length,
+                dead_letter,
+                read_timeout_secs,
+                resolver_path,
+                input_source_file,
+                created_at,
+                updated_at
+              )
+              VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&item.url)
+        .bind(&item.source_type)
+        .bind(&item.original_input)
+        .bind(project)
+        .bind(status.as_str())
+        .bind(item.priority)
+        .bind(item.retry_count)
+        .bind(&item.last_error)
+        .bind(&item.suggested_filename)
+        .bind(&item.meta_title)
+        .bind(&item.meta_authors)
+        .bind(&item.meta_author_orcids)
+        .bind(&item.meta_abstract)
+        .bind(&item.meta_year)
+        .bind(&item.meta_doi)
+        .bind(&item.topics)
+        .bind(&item.parse_confidence)
+        .bind(&item.parse_confidence_factors)
+        .bind(&item.saved_path)
+        .bind(item.bytes_downloaded)
+        .bind(item.content_length)
+        .bind(item.dead_letter)
+        .bind(item.read_timeout_secs)
+        .bind(&item.resolver_path)
+        .bind(&item.input_source_file)
+        .bind(&item.created_at)
+        .bind(&item.updated_at)
+        .execute(self.db.pool())
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::Database;
+
+    #[tokio::test]
+    async fn test_import_json_rejects_malformed_payload() {
+        let queue = Queue::new(Database::new_in_memory().await.unwrap());
+        let result = queue.import_json(&b"{not json"[..]).await;
+        assert!(matches!(result, Err(QueueError::Transfer(_))));
+    }
+
+    #[tokio::test]
+    async fn test_import_json_resets_in_progress_rows_to_pending() {
+        let source = Queue::new(Database::new_in_memory().await.unwrap());
+        source
+            .enqueue("https://example.com/a.pdf", "direct_url", None)
+            .await
+            .unwrap();
+        source.dequeue().await.unwrap();
+
+        let mut payload = Vec::new();
+        source.export_json(&mut payload).await.unwrap();
+
+        let target = Queue::new(Database::new_in_memory().await.unwrap());
+        assert_eq!(target.import_json(payload.as_slice()).await.unwrap(), 1);
+        assert_eq!(
+            target.count_by_status(QueueStatus::Pending).await.unwrap(),
+            1
+        );
+        assert_eq!(
+            target
+                .count_by_status(QueueStatus::InProgress)
+                .await
+                .unwrap(),
+            0
+        );
+    }
+}
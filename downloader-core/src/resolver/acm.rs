@@ -1,9 +1,9 @@
 //! ACM Digital Library resolver for `dl.acm.org` URLs and `10.1145/*` DOI inputs.
 //!
-//! `dl.acm.org` is behind Cloudflare bot detection that blocks all programmatic
-//! clients with HTTP 403. This resolver intercepts ACM inputs, queries the
-//! Semantic Scholar API for open-access alternatives (e.g. arXiv preprints),
-//! and returns the best available download URL.
+//! `dl.acm.org` is behind Cloudflare bot detection and a paywall, so this
+//! resolver first queries the Semantic Scholar API for open-access
+//! alternatives (e.g. arXiv preprints) and only falls back to ACM itself when
+//! none exist.
 //!
 //! # Resolution strategy
 //!
@@ -11,43 +11,66 @@
 //!    use that directly (typically an arXiv preprint).
 //! 2. If Semantic Scholar has an `ArXiv` external ID but no usable OA PDF, construct
 //!    the arXiv PDF URL directly.
-//! 3. Otherwise, return the ACM PDF URL (`dl.acm.org/doi/pdf/{doi}`), which may
-//!    require authentication (run `downloader auth capture` to capture browser cookies).
+//! 3. Otherwise, without a `dl.acm.org` session cookie, return
+//!    [`ResolveStep::NeedsAuth`] so the user is told to run `downloader auth capture`.
+//! 4. With a session, rewrite the DOI or landing URL (`/doi/<doi>`, `/doi/abs/<doi>`)
+//!    to the PDF endpoint (`dl.acm.org/doi/pdf/<doi>`) and enrich the metadata from
+//!    the landing page's `citation_*` meta tags.
+
+use std::collections::HashMap;
+use std::sync::Arc;
 
 use async_trait::async_trait;
 use reqwest::Client;
+use reqwest::cookie::{CookieStore, Jar};
+use reqwest::header::ACCEPT;
+use tracing::debug;
 use url::Url;
 
 use crate::parser::InputType;
 
+use super::citation_meta::citation_metadata;
 use super::http_client::{build_resolver_http_client, standard_user_agent};
+use super::meta::collect_meta_tags;
 use super::semantic_scholar::{self, DEFAULT_S2_BASE_URL, S2ResolveConfig};
 use super::utils::looks_like_doi;
-use super::{ResolveContext, ResolveError, ResolveStep, Resolver, ResolverPriority};
+use super::{
+    AuthRequirement, ResolveContext, ResolveError, ResolveStep, ResolvedUrl, Resolver,
+    ResolverPriority,
+};
 
 // ==================== Constants ====================
 
 const ACM_DOI_PREFIX: &str = "10.1145/";
+const ACM_BASE_URL: &str = "https://dl.acm.org";
+const ACM_HOST: &str = "dl.acm.org";
+
+/// Landing-page metadata keys that override the Semantic Scholar values.
+const LANDING_METADATA_KEYS: &[&str] = &["title", "authors", "year", "doi"];
 
 // ==================== AcmResolver ====================
 
-/// Resolves ACM Digital Library inputs via the Semantic Scholar open-access API.
+/// Resolves ACM Digital Library inputs via the Semantic Scholar open-access API,
+/// falling back to the authenticated ACM PDF endpoint.
 pub struct AcmResolver {
     client: Client,
+    cookie_jar: Option<Arc<Jar>>,
     s2_base_url: String,
+    acm_base_url: String,
 }
 
 impl AcmResolver {
-    /// Creates a new `AcmResolver` using the production Semantic Scholar API.
+    /// Creates a new `AcmResolver` that checks `cookie_jar` for a `dl.acm.org` session.
     ///
     /// # Errors
     ///
     /// Returns [`ResolveError`] if HTTP client construction fails.
-    pub fn new() -> Result<Self, ResolveError> {
-        Self::build(DEFAULT_S2_BASE_URL.to_string())
+    pub fn new(cookie_jar: Option<Arc<Jar>>) -> Result<Self, ResolveError> {
+        Self::with_base_urls(cookie_jar, DEFAULT_S2_BASE_URL, ACM_BASE_URL)
     }
 
-    /// Creates an `AcmResolver` with a custom Semantic Scholar API base URL.
+    /// Creates an `AcmResolver` with a custom Semantic Scholar API base URL
+    /// and no cookie jar.
     ///
     /// Intended for use in tests with a wiremock server.
     ///
@@ -55,23 +78,86 @@ impl AcmResolver {
     ///
     /// Returns [`ResolveError`] if HTTP client construction fails.
     pub fn with_base_url(s2_base_url: impl Into<String>) -> Result<Self, ResolveError> {
-        Self::build(s2_base_url.into())
+        Self::with_base_urls(None, s2_base_url, ACM_BASE_URL)
     }
 
-    fn build(s2_base_url: String) -> Result<Self, ResolveError> {
+    /// Creates an `AcmResolver` with custom Semantic Scholar and ACM base URLs.
+    ///
+    /// Intended for use in tests with a wiremock server.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResolveError`] if HTTP client construction fails.
+    pub fn with_base_urls(
+        cookie_jar: Option<Arc<Jar>>,
+        s2_base_url: impl Into<String>,
+        acm_base_url: impl Into<String>,
+    ) -> Result<Self, ResolveError> {
         let user_agent = standard_user_agent("acm");
-        let client = build_resolver_http_client("acm", user_agent, None)?;
+        let client = build_resolver_http_client("acm", user_agent, cookie_jar.clone())?;
         Ok(Self {
             client,
-            s2_base_url,
+            cookie_jar,
+            s2_base_url: s2_base_url.into(),
+            acm_base_url: acm_base_url.into().trim_end_matches('/').to_string(),
         })
     }
+
+    /// Returns true when the jar holds any cookie sent to the ACM base URL.
+    fn has_session_cookie(&self) -> bool {
+        let Ok(base_url) = Url::parse(&self.acm_base_url) else {
+            return false;
+        };
+        self.cookie_jar
+            .as_ref()
+            .and_then(|jar| jar.cookies(&base_url))
+            .is_some_and(|header| !header.is_empty())
+    }
+
+    /// Fetches the ACM landing page and returns its `citation_*` metadata.
+    ///
+    /// Best effort: any network or status failure yields an empty map so the
+    /// PDF URL is still returned.
+    async fn fetch_landing_metadata(
+        &self,
+        doi: &str,
+        ctx: &ResolveContext,
+    ) -> HashMap<String, String> {
+        let landing_url = format!("{}/doi/{doi}", self.acm_base_url);
+        ctx.throttle(&landing_url).await;
+        let response = match self
+            .client
+            .get(&landing_url)
+            .header(ACCEPT, "text/html,application/xhtml+xml;q=0.9,*/*;q=0.5")
+            .send()
+            .await
+        {
+            Ok(response) if response.status().is_success() => response,
+            Ok(response) => {
+                debug!(
+                    status = response.status().as_u16(),
+                    "ACM landing page unavailable"
+                );
+                return HashMap::new();
+            }
+            Err(error) => {
+                debug!(error = %error, "ACM landing page request failed");
+                return HashMap::new();
+            }
+        };
+        match response.text().await {
+            Ok(html) => citation_metadata(&collect_meta_tags(&html)),
+            Err(_) => HashMap::new(),
+        }
+    }
 }
 
 impl std::fmt::Debug for AcmResolver {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("AcmResolver")
             .field("s2_base_url", &self.s2_base_url)
+            .field("acm_base_url", &self.acm_base_url)
+            .field("has_cookie_jar", &self.cookie_jar.is_some())
             .finish_non_exhaustive()
     }
 }
@@ -96,11 +182,11 @@ impl Resolver for AcmResolver {
         }
     }
 
-    #[tracing::instrument(skip(self, _ctx), fields(resolver = "acm", input = %input))]
+    #[tracing::instrument(skip(self, ctx), fields(resolver = "acm", input = %input))]
     async fn resolve(
         &self,
         input: &str,
-        _ctx: &ResolveContext,
+        ctx: &ResolveContext,
     ) -> Result<ResolveStep, ResolveError> {
         let doi = if looks_like_doi(input, ACM_DOI_PREFIX) {
             input.trim().to_string()
@@ -118,17 +204,60 @@ impl Resolver for AcmResolver {
             }
         };
 
-        let fallback_url = format!("https://dl.acm.org/doi/pdf/{doi}");
+        let pdf_url = acm_pdf_url(&self.acm_base_url, &doi);
         let config = S2ResolveConfig {
-            fallback_pdf_url: &fallback_url,
-            publisher_domains: &["dl.acm.org"],
+            fallback_pdf_url: &pdf_url,
+            publisher_domains: &[ACM_HOST],
             resolver_name: self.name(),
         };
-        semantic_scholar::resolve_via_s2(&self.client, &self.s2_base_url, &doi, input, &config)
-            .await
+        let has_session = self.has_session_cookie();
+        let mut metadata = match semantic_scholar::resolve_via_s2(
+            &self.client,
+            &self.s2_base_url,
+            &doi,
+            input,
+            &config,
+        )
+        .await?
+        {
+            ResolveStep::Url(resolved) if resolved.url != pdf_url => {
+                return Ok(ResolveStep::Url(resolved));
+            }
+            ResolveStep::Url(resolved) => resolved.metadata,
+            // Without an ACM session the S2 failure is the most useful answer;
+            // with one, the ACM PDF endpoint is still worth trying.
+            other if !has_session => return Ok(other),
+            _ => HashMap::from([
+                ("doi".to_string(), doi.clone()),
+                ("source_url".to_string(), format!("https://doi.org/{doi}")),
+            ]),
+        };
+
+        if !has_session {
+            return Ok(ResolveStep::NeedsAuth(AuthRequirement::new(
+                ACM_HOST,
+                "No open-access copy was found and ACM Digital Library PDFs require a subscription, but no dl.acm.org session cookies were found. Run `downloader auth capture --save-cookies` after signing in to ACM, then retry.",
+            )));
+        }
+
+        let landing = self.fetch_landing_metadata(&doi, ctx).await;
+        for key in LANDING_METADATA_KEYS {
+            if let Some(value) = landing.get(*key) {
+                metadata.insert((*key).to_string(), value.clone());
+            }
+        }
+
+        Ok(ResolveStep::Url(ResolvedUrl::with_metadata(
+            pdf_url, metadata,
+        )))
     }
 }
 
+/// Returns the ACM PDF endpoint for `doi`.
+fn acm_pdf_url(acm_base_url: &str, doi: &str) -> String {
+    format!("{acm_base_url}/doi/pdf/{doi}")
+}
+
 // ==================== DOI extraction from ACM URLs ====================
 
 /// Extracts the ACM DOI from an ACM Digital Library URL.
@@ -186,24 +315,60 @@ mod tests {
     use wiremock::matchers::{method, path, query_param};
     use wiremock::{Mock, ResponseTemplate};
 
+    const ACM_LANDING_PAGE: &str = r#"<html><head>
+        <meta name="citation_title" content="Landing Page Title">
+        <meta name="citation_author" content="Smith, Alice">
+        <meta name="citation_author" content="Jones, Bob">
+        <meta name="citation_doi" content="10.1145/3460418.3479327">
+        <meta name="citation_publication_date" content="2021/09/21">
+        </head></html>"#;
+
+    /// Resolver whose S2 and ACM endpoints both point at `server_uri`, with a
+    /// session cookie for the ACM side.
+    fn session_resolver(server_uri: &str) -> AcmResolver {
+        let jar = Arc::new(Jar::default());
+        jar.add_cookie_str("JSESSIONID=abc", &Url::parse(server_uri).unwrap());
+        AcmResolver::with_base_urls(Some(jar), server_uri, server_uri).unwrap()
+    }
+
+    async fn mount_closed_access_paper(server: &wiremock::MockServer) {
+        Mock::given(method("GET"))
+            .and(path("/graph/v1/paper/DOI:10.1145/3460418.3479327"))
+            .and(query_param("fields", S2_FIELDS))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "title": "S2 Title",
+                "authors": [{"name": "Alice Smith"}],
+                "year": 2021,
+                "externalIds": {},
+                "openAccessPdf": null
+            })))
+            .mount(server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/doi/10.1145/3460418.3479327"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(ACM_LANDING_PAGE, "text/html"))
+            .mount(server)
+            .await;
+    }
+
     // ==================== can_handle ====================
 
     #[test]
     fn test_name_and_priority() {
-        let resolver = AcmResolver::new().unwrap();
+        let resolver = AcmResolver::new(None).unwrap();
         assert_eq!(resolver.name(), "acm");
         assert_eq!(resolver.priority(), ResolverPriority::Specialized);
     }
 
     #[test]
     fn test_can_handle_acm_doi() {
-        let resolver = AcmResolver::new().unwrap();
+        let resolver = AcmResolver::new(None).unwrap();
         assert!(resolver.can_handle("10.1145/3460418.3479327", InputType::Doi));
     }
 
     #[test]
     fn test_cannot_handle_non_acm_doi() {
-        let resolver = AcmResolver::new().unwrap();
+        let resolver = AcmResolver::new(None).unwrap();
         assert!(!resolver.can_handle("10.3390/electronics13132567", InputType::Doi));
         assert!(!resolver.can_handle("10.1109/5.771073", InputType::Doi));
         assert!(!resolver.can_handle("10.1007/s10618-021-00787-x", InputType::Doi));
@@ -211,13 +376,13 @@ mod tests {
 
     #[test]
     fn test_cannot_handle_reference_input() {
-        let resolver = AcmResolver::new().unwrap();
+        let resolver = AcmResolver::new(None).unwrap();
         assert!(!resolver.can_handle("10.1145/3460418.3479327", InputType::Reference));
     }
 
     #[test]
     fn test_can_handle_acm_url() {
-        let resolver = AcmResolver::new().unwrap();
+        let resolver = AcmResolver::new(None).unwrap();
         assert!(resolver.can_handle(
             "https://dl.acm.org/doi/10.1145/3460418.3479327",
             InputType::Url
@@ -226,16 +391,25 @@ mod tests {
 
     #[test]
     fn test_can_handle_acm_url_with_pdf_prefix() {
-        let resolver = AcmResolver::new().unwrap();
+        let resolver = AcmResolver::new(None).unwrap();
         assert!(resolver.can_handle(
             "https://dl.acm.org/doi/pdf/10.1145/3460418.3479327",
             InputType::Url
         ));
     }
 
+    #[test]
+    fn test_can_handle_acm_abs_url() {
+        let resolver = AcmResolver::new(None).unwrap();
+        assert!(resolver.can_handle(
+            "https://dl.acm.org/doi/abs/10.1145/3460418.3479327",
+            InputType::Url
+        ));
+    }
+
     #[test]
     fn test_cannot_handle_non_doi_acm_url() {
-        let resolver = AcmResolver::new().unwrap();
+        let resolver = AcmResolver::new(None).unwrap();
         assert!(!resolver.can_handle("https://dl.acm.org/conference/chi", InputType::Url));
         assert!(!resolver.can_handle("https://dl.acm.org/", InputType::Url));
     }
@@ -378,13 +552,10 @@ mod tests {
             .unwrap();
 
         match result {
-            ResolveStep::Url(resolved) => {
-                assert_eq!(
-                    resolved.url,
-                    "https://dl.acm.org/doi/pdf/10.1145/3460418.3479327"
-                );
+            ResolveStep::NeedsAuth(requirement) => {
+                assert_eq!(requirement.domain, "dl.acm.org");
             }
-            other => panic!("Expected Url fallback, got: {other:?}"),
+            other => panic!("Expected NeedsAuth without ACM cookies, got: {other:?}"),
         }
     }
 
@@ -452,6 +623,7 @@ mod tests {
         };
 
         // All nullable fields are null — should still produce ACM fallback URL
+        // (the landing page is not mounted, so its metadata is skipped)
         Mock::given(method("GET"))
             .and(path("/graph/v1/paper/DOI:10.1145/3460418.3479327"))
             .and(query_param("fields", S2_FIELDS))
@@ -465,7 +637,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let resolver = AcmResolver::with_base_url(mock_server.uri()).unwrap();
+        let resolver = session_resolver(&mock_server.uri());
         let ctx = ResolveContext::default();
         let result = resolver
             .resolve("10.1145/3460418.3479327", &ctx)
@@ -476,7 +648,7 @@ mod tests {
             ResolveStep::Url(resolved) => {
                 assert_eq!(
                     resolved.url,
-                    "https://dl.acm.org/doi/pdf/10.1145/3460418.3479327"
+                    format!("{}/doi/pdf/10.1145/3460418.3479327", mock_server.uri())
                 );
                 // Metadata should still have doi and source_url
                 assert_eq!(
@@ -578,7 +750,8 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let resolver = AcmResolver::with_base_url(mock_server.uri()).unwrap();
+        // Closed access: the S2 metadata is only returned with an ACM session.
+        let resolver = session_resolver(&mock_server.uri());
         let ctx = ResolveContext::default();
         let result = resolver
             .resolve("10.1145/3460418.3479327", &ctx)
@@ -637,13 +810,61 @@ mod tests {
             .unwrap();
 
         match result {
-            ResolveStep::Url(resolved) => {
-                assert_eq!(
-                    resolved.url,
-                    "https://dl.acm.org/doi/pdf/10.1145/3460418.3479327"
-                );
+            ResolveStep::NeedsAuth(requirement) => {
+                assert_eq!(requirement.domain, "dl.acm.org");
+                assert!(requirement.message.contains("auth capture"));
+            }
+            other => panic!("Expected NeedsAuth without ACM cookies, got: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_doi_input_with_session_rewrites_to_pdf_endpoint() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        mount_closed_access_paper(&mock_server).await;
+
+        let result = session_resolver(&mock_server.uri())
+            .resolve("10.1145/3460418.3479327", &ResolveContext::default())
+            .await
+            .unwrap();
+
+        let ResolveStep::Url(resolved) = result else {
+            panic!("Expected Url with ACM session, got: {result:?}");
+        };
+        assert_eq!(
+            resolved.url,
+            format!("{}/doi/pdf/10.1145/3460418.3479327", mock_server.uri())
+        );
+        let m = &resolved.metadata;
+        assert_eq!(m["title"], "Landing Page Title");
+        assert_eq!(m["authors"], "Smith, Alice; Jones, Bob");
+        assert_eq!(m["year"], "2021");
+        assert_eq!(m["doi"], "10.1145/3460418.3479327");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_landing_url_inputs_normalize_to_pdf_endpoint() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        mount_closed_access_paper(&mock_server).await;
+        let resolver = session_resolver(&mock_server.uri());
+        let expected = format!("{}/doi/pdf/10.1145/3460418.3479327", mock_server.uri());
+
+        for input in [
+            "https://dl.acm.org/doi/10.1145/3460418.3479327",
+            "https://dl.acm.org/doi/abs/10.1145/3460418.3479327?casa_token=x",
+        ] {
+            let result = resolver
+                .resolve(input, &ResolveContext::default())
+                .await
+                .unwrap();
+            match result {
+                ResolveStep::Url(resolved) => assert_eq!(resolved.url, expected, "{input}"),
+                other => panic!("Expected Url for {input}, got: {other:?}"),
             }
-            other => panic!("Expected Url fallback, got: {other:?}"),
         }
     }
 }
@@ -1,14 +1,21 @@
 //! arXiv resolver for normalizing article and DOI inputs into canonical PDF URLs.
+//!
+//! Resolution itself is offline. [`lookup_arxiv_works`] separately queries the
+//! arXiv API for titles and journal DOIs, for callers that need to match a
+//! preprint with its published version.
 
 use std::collections::HashMap;
 use std::sync::LazyLock;
 
 use async_trait::async_trait;
 use regex::Regex;
+use tracing::debug;
 use url::Url;
 
-use crate::parser::InputType;
+use crate::parser::{ARXIV_ID_PREFIX, InputType};
 
+use super::http_client::{build_resolver_http_client, send_with_retry, standard_user_agent};
+use super::meta::html_unescape_basic;
 use super::utils::{canonical_host, compile_static_regex};
 use super::{ResolveContext, ResolveError, ResolveStep, ResolvedUrl, Resolver, ResolverPriority};
 
@@ -16,10 +23,31 @@ const ARXIV_BASE_URL: &str = "https://arxiv.org";
 const ARXIV_HOST: &str = "arxiv.org";
 const DOI_HOST: &str = "doi.org";
 const ARXIV_DOI_PREFIX: &str = "10.48550/";
+const DEFAULT_ARXIV_API_URL: &str = "https://export.arxiv.org/api/query";
+/// IDs per arXiv API request; the API pages results beyond this anyway.
+const ARXIV_API_BATCH_SIZE: usize = 100;
 
 static ARXIV_ID_RE: LazyLock<Regex> = LazyLock::new(|| {
     compile_static_regex(r"(?i)^(?:\d{4}\.\d{4,5}|[a-z\-]+(?:\.[a-z]{2})?/\d{7})(?:v\d+)?$")
 });
+static ARXIV_VERSION_SUFFIX_RE: LazyLock<Regex> = LazyLock::new(|| compile_static_regex(r"v\d+$"));
+static ATOM_ENTRY_RE: LazyLock<Regex> =
+    LazyLock::new(|| compile_static_regex(r"(?s)<entry>(.*?)</entry>"));
+static ATOM_ID_RE: LazyLock<Regex> =
+    LazyLock::new(|| compile_static_regex(r"<id>\s*https?://arxiv\.org/abs/([^<\s]+)\s*</id>"));
+static ATOM_TITLE_RE: LazyLock<Regex> =
+    LazyLock::new(|| compile_static_regex(r"(?s)<title[^>]*>(.*?)</title>"));
+static ATOM_DOI_RE: LazyLock<Regex> =
+    LazyLock::new(|| compile_static_regex(r"<arxiv:doi[^>]*>\s*([^<\s]+)\s*</arxiv:doi>"));
+
+/// What the arXiv API reports about one preprint.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ArxivWork {
+    /// Preprint title, whitespace-collapsed.
+    pub title: Option<String>,
+    /// DOI of the published version, when the authors recorded one.
+    pub published_doi: Option<String>,
+}
 
 /// Specialized resolver for arXiv URLs and DOI signals.
 #[derive(Debug, Default)]
@@ -75,12 +103,105 @@ impl Resolver for ArxivResolver {
     }
 }
 
+/// Returns the arXiv ID in an arXiv DOI (`10.48550/arXiv.2301.01234v2` → `2301.01234v2`).
+#[must_use]
+pub fn arxiv_id_from_doi(doi: &str) -> Option<String> {
+    extract_from_doi(doi)
+}
+
+/// Looks up titles and published-version DOIs for `ids` through the arXiv API.
+///
+/// IDs are sent in batched `id_list` queries. Keys of the returned map are
+/// the IDs without version suffix; IDs the API does not know are absent.
+/// `api_url` overrides the API endpoint (for tests).
+///
+/// # Errors
+///
+/// Returns [`ResolveError`] when the client cannot be built, a request fails,
+/// or the API answers with a non-success status.
+pub async fn lookup_arxiv_works(
+    ids: &[String],
+    api_url: Option<&str>,
+) -> Result<HashMap<String, ArxivWork>, ResolveError> {
+    let mut works = HashMap::new();
+    if ids.is_empty() {
+        return Ok(works);
+    }
+    let client = build_resolver_http_client("arxiv", standard_user_agent("arxiv"), None)?;
+    let api_url = api_url.unwrap_or(DEFAULT_ARXIV_API_URL);
+
+    for batch in ids.chunks(ARXIV_API_BATCH_SIZE) {
+        let id_list = batch.join(",");
+        let url = format!(
+            "{api_url}?id_list={}&max_results={}",
+            urlencoding::encode(&id_list),
+            batch.len()
+        );
+        let request = client.get(&url);
+        let response = send_with_retry(request, "arxiv")
+            .await
+            .map_err(|error| ResolveError::resolution_failed(&id_list, &error.to_string()))?;
+        if !response.status().is_success() {
+            return Err(ResolveError::resolution_failed(
+                &id_list,
+                &format!("arXiv API returned HTTP {}", response.status().as_u16()),
+            ));
+        }
+        let body = response
+            .text()
+            .await
+            .map_err(|error| ResolveError::resolution_failed(&id_list, &error.to_string()))?;
+        works.extend(parse_arxiv_atom(&body));
+    }
+
+    debug!(
+        requested = ids.len(),
+        found = works.len(),
+        "arXiv API lookup"
+    );
+    Ok(works)
+}
+
+/// Extracts `(id without version, work)` pairs from an arXiv API Atom feed.
+fn parse_arxiv_atom(feed: &str) -> HashMap<String, ArxivWork> {
+    ATOM_ENTRY_RE
+        .captures_iter(feed)
+        .filter_map(|entry| {
+            let entry = entry.get(1)?.as_str();
+            let id = ATOM_ID_RE.captures(entry)?.get(1)?.as_str();
+            let id = ARXIV_VERSION_SUFFIX_RE.replace(id, "").into_owned();
+            let title = ATOM_TITLE_RE
+                .captures(entry)
+                .and_then(|captures| captures.get(1))
+                .map(|title| {
+                    html_unescape_basic(title.as_str())
+                        .split_whitespace()
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .filter(|title| !title.is_empty());
+            let published_doi = ATOM_DOI_RE
+                .captures(entry)
+                .and_then(|captures| captures.get(1))
+                .map(|doi| doi.as_str().to_string());
+            Some((
+                id,
+                ArxivWork {
+                    title,
+                    published_doi,
+                },
+            ))
+        })
+        .collect()
+}
+
 fn extract_arxiv_id(input: &str, input_type: InputType) -> Option<String> {
     match input_type {
         InputType::Doi => extract_from_doi(input),
         InputType::Url => extract_from_url(input),
-        // Bare arXiv IDs emitted by the parser as InputType::Unknown (e.g. "2301.01234").
-        InputType::Unknown => normalize_arxiv_id(input.trim()),
+        // IDs emitted by the parser as InputType::Unknown ("arXiv:2301.01234"),
+        // or typed bare ("2301.01234").
+        InputType::Unknown => normalize_arxiv_id(strip_arxiv_prefix(input.trim())),
         _ => None,
     }
 }
@@ -125,6 +246,15 @@ fn extract_from_url(input: &str) -> Option<String> {
     None
 }
 
+fn strip_arxiv_prefix(value: &str) -> &str {
+    match value.get(..ARXIV_ID_PREFIX.len()) {
+        Some(prefix) if prefix.eq_ignore_ascii_case(ARXIV_ID_PREFIX) => {
+            &value[ARXIV_ID_PREFIX.len()..]
+        }
+        _ => value,
+    }
+}
+
 fn strip_pdf_suffix(value: &str) -> &str {
     value.strip_suffix(".pdf").unwrap_or(value)
 }
@@ -194,6 +324,9 @@ mod tests {
         let resolver = ArxivResolver::new();
         assert!(resolver.can_handle("2301.01234", InputType::Unknown));
         assert!(resolver.can_handle("2301.01234v3", InputType::Unknown));
+        assert!(resolver.can_handle("arXiv:2301.01234v3", InputType::Unknown));
+        assert!(resolver.can_handle("ARXIV:hep-th/9901001", InputType::Unknown));
+        assert!(!resolver.can_handle("arXiv:", InputType::Unknown));
         assert!(!resolver.can_handle("not-an-id", InputType::Unknown));
         assert!(!resolver.can_handle("2301.01234", InputType::Reference));
     }
@@ -216,6 +349,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_arxiv_atom_reads_title_and_published_doi() {
+        let feed = r#"<feed><title>ArXiv Query</title>
+            <entry><id>http://arxiv.org/abs/2301.01234v2</id>
+              <title>Deep Learning for
+                 Cats &amp; Dogs</title>
+              <arxiv:doi xmlns:arxiv="http://arxiv.org/schemas/atom">10.1000/journal.5</arxiv:doi>
+            </entry>
+            <entry><id>http://arxiv.org/abs/hep-th/9901001v1</id><title>Old Paper</title></entry>
+        </feed>"#;
+
+        let works = parse_arxiv_atom(feed);
+
+        assert_eq!(
+            works["2301.01234"],
+            ArxivWork {
+                title: Some("Deep Learning for Cats & Dogs".to_string()),
+                published_doi: Some("10.1000/journal.5".to_string()),
+            }
+        );
+        assert_eq!(works["hep-th/9901001"].published_doi, None);
+    }
+
+    #[tokio::test]
+    async fn test_lookup_arxiv_works_queries_id_list() {
+        use crate::test_support::socket_guard::start_mock_server_or_skip;
+        use wiremock::matchers::{method, query_param};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(query_param("id_list", "2301.01234,2302.00001"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                "<feed><entry><id>http://arxiv.org/abs/2301.01234v1</id><title>A</title></entry></feed>",
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let ids = vec!["2301.01234".to_string(), "2302.00001".to_string()];
+        let works = lookup_arxiv_works(&ids, Some(&mock_server.uri()))
+            .await
+            .unwrap();
+
+        assert_eq!(works.len(), 1);
+        assert_eq!(works["2301.01234"].title.as_deref(), Some("A"));
+    }
+
     #[tokio::test]
     async fn test_arxiv_resolve_bare_id_with_version() {
         let resolver = ArxivResolver::new();
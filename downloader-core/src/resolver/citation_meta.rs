@@ -0,0 +1,518 @@
+//! Generic landing-page resolver driven by Highwire `citation_*` meta tags.
+//!
+//! Many publishers (Wiley, Springer Nature, university presses, OJS journals)
+//! put the PDF link in `<meta name="citation_pdf_url">` on the article
+//! landing page. This resolver fetches HTML landing pages, follows redirects
+//! hop by hop (bounded by [`ResolveContext::max_redirects`]), and returns the
+//! PDF link with `citation_title`/`citation_author`/`citation_doi` metadata.
+//! When the page has no `citation_pdf_url`, FAIR signposting is consulted as
+//! complementary discovery: a `Link: <...>; rel="item"` response header, then
+//! an HTML `<link rel="alternate" type="application/pdf">` tag.
+//! Non-HTML responses are dropped after the headers (unless those headers
+//! signpost a PDF), so PDFs are never read here, and pages without any PDF
+//! link fail softly to [`DirectResolver`].
+//!
+//! [`DirectResolver`]: super::DirectResolver
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use reqwest::Client;
+use reqwest::cookie::Jar;
+use reqwest::header::{ACCEPT, CONTENT_TYPE, LOCATION};
+use tracing::debug;
+use url::Url;
+
+use crate::parser::InputType;
+
+use super::http_client::{build_non_redirecting_resolver_http_client, standard_user_agent};
+use super::meta::{
+    MetaTag, all_meta_values, collect_meta_tags, first_meta_value, plain_text_abstract,
+};
+use super::signposting::{pdf_link_from_headers, pdf_link_from_html};
+use super::utils::{absolutize_url, extract_year_from_str};
+use super::{ResolveContext, ResolveError, ResolveStep, ResolvedUrl, Resolver, ResolverPriority};
+
+const PDF_URL_KEYS: &[&str] = &["citation_pdf_url"];
+const TITLE_KEYS: &[&str] = &["citation_title", "dc.title"];
+const AUTHOR_KEYS: &[&str] = &["citation_author"];
+const DOI_KEYS: &[&str] = &["citation_doi", "dc.identifier"];
+const ABSTRACT_KEYS: &[&str] = &["citation_abstract", "dc.description"];
+const DATE_KEYS: &[&str] = &[
+    "citation_publication_date",
+    "citation_date",
+    "citation_online_date",
+];
+
+/// Path extensions that still denote an HTML page; any other extension is
+/// treated as a direct file link and left to [`super::DirectResolver`].
+const PAGE_EXTENSIONS: &[&str] = &["html", "htm", "xhtml", "php", "asp", "aspx", "jsp", "cfm"];
+
+/// General resolver for publisher landing pages carrying `citation_pdf_url`.
+pub struct CitationMetaResolver {
+    client: Client,
+}
+
+impl CitationMetaResolver {
+    /// Creates a new `CitationMetaResolver`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResolveError`] if HTTP client construction fails.
+    pub fn new(cookie_jar: Option<Arc<Jar>>) -> Result<Self, ResolveError> {
+        Ok(Self {
+            client: build_non_redirecting_resolver_http_client(
+                "citation_meta",
+                standard_user_agent("citation_meta"),
+                cookie_jar,
+            )?,
+        })
+    }
+}
+
+impl std::fmt::Debug for CitationMetaResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CitationMetaResolver")
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl Resolver for CitationMetaResolver {
+    fn name(&self) -> &'static str {
+        "citation_meta"
+    }
+
+    fn priority(&self) -> ResolverPriority {
+        ResolverPriority::General
+    }
+
+    fn can_handle(&self, input: &str, input_type: InputType) -> bool {
+        input_type == InputType::Url
+            && Url::parse(input.trim())
+                .ok()
+                .is_some_and(|url| matches!(url.scheme(), "http" | "https") && !is_file_link(&url))
+    }
+
+    #[tracing::instrument(skip(self, ctx), fields(resolver = "citation_meta", input = %input))]
+    async fn resolve(
+        &self,
+        input: &str,
+        ctx: &ResolveContext,
+    ) -> Result<ResolveStep, ResolveError> {
+        let Ok(mut current) = Url::parse(input.trim()) else {
+            return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                "Input is not a valid URL",
+            )));
+        };
+
+        let mut hops: usize = 0;
+        let response = loop {
+            ctx.throttle(current.as_str()).await;
+            let Ok(response) = self
+                .client
+                .get(current.clone())
+                .header(ACCEPT, "text/html,application/xhtml+xml;q=0.9,*/*;q=0.5")
+                .send()
+                .await
+            else {
+                return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                    input,
+                    "Cannot reach the landing page",
+                )));
+            };
+
+            if !response.status().is_redirection() {
+                break response;
+            }
+            let Some(next) = response
+                .headers()
+                .get(LOCATION)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|location| current.join(location.trim()).ok())
+            else {
+                return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                    input,
+                    "Landing page redirect has no usable Location header",
+                )));
+            };
+            hops += 1;
+            if hops > ctx.max_redirects {
+                return Ok(ResolveStep::Failed(ResolveError::too_many_redirects(
+                    input, hops,
+                )));
+            }
+            debug!(hops, "Following landing-page redirect");
+            current = next;
+        };
+
+        let status = response.status();
+        if !status.is_success() {
+            return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                &format!("Landing page returned HTTP {}", status.as_u16()),
+            )));
+        }
+        let signposted_pdf = pdf_link_from_headers(response.headers(), &current);
+        if !is_html_response(&response) {
+            if let Some(pdf_url) = signposted_pdf {
+                debug!("Non-HTML response signposts a PDF via its Link header");
+                return Ok(ResolveStep::Url(ResolvedUrl::with_metadata(
+                    pdf_url,
+                    HashMap::from([("source_url".to_string(), current.to_string())]),
+                )));
+            }
+            debug!("Response is not HTML; leaving it to the direct resolver");
+            return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                "Response is not an HTML landing page",
+            )));
+        }
+
+        let Ok(html) = response.text().await else {
+            return Ok(ResolveStep::body_parse_failed(input, "Landing page"));
+        };
+        match citation_meta_to_resolved(&html, &current, signposted_pdf) {
+            Some(resolved) => Ok(ResolveStep::Url(resolved)),
+            None => Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                "Landing page has no citation_pdf_url meta tag or signposted PDF",
+            ))),
+        }
+    }
+}
+
+/// Returns true when the last path segment has a non-page file extension.
+fn is_file_link(url: &Url) -> bool {
+    url.path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .and_then(|segment| segment.rsplit_once('.'))
+        .is_some_and(|(stem, extension)| {
+            !stem.is_empty()
+                && !extension.is_empty()
+                && !PAGE_EXTENSIONS
+                    .iter()
+                    .any(|page| extension.eq_ignore_ascii_case(page))
+        })
+}
+
+fn is_html_response(response: &reqwest::Response) -> bool {
+    response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.to_ascii_lowercase().contains("html"))
+}
+
+/// Builds the resolved PDF URL and metadata from a landing page's meta tags.
+///
+/// The PDF link comes from `citation_pdf_url`, falling back to the
+/// `Link`-header PDF (`signposted_pdf`) and then to `<link rel>` tags.
+fn citation_meta_to_resolved(
+    html: &str,
+    page_url: &Url,
+    signposted_pdf: Option<String>,
+) -> Option<ResolvedUrl> {
+    let meta_tags = collect_meta_tags(html);
+    let pdf_url = first_meta_value(&meta_tags, PDF_URL_KEYS)
+        .and_then(|value| absolutize_url(&value, page_url))
+        .or(signposted_pdf)
+        .or_else(|| pdf_link_from_html(html, page_url))?;
+
+    let mut metadata = citation_metadata(&meta_tags);
+    metadata.insert("source_url".to_string(), page_url.to_string());
+    Some(ResolvedUrl::with_metadata(pdf_url, metadata))
+}
+
+/// Collects title, authors, DOI, year, and abstract from `citation_*` meta tags.
+pub(super) fn citation_metadata(meta_tags: &[MetaTag]) -> HashMap<String, String> {
+    let mut metadata = HashMap::new();
+    if let Some(title) = first_meta_value(meta_tags, TITLE_KEYS) {
+        metadata.insert("title".to_string(), title);
+    }
+    let authors = all_meta_values(meta_tags, AUTHOR_KEYS);
+    if !authors.is_empty() {
+        metadata.insert("authors".to_string(), authors.join("; "));
+    }
+    if let Some(doi) = all_meta_values(meta_tags, DOI_KEYS)
+        .iter()
+        .find_map(|value| normalize_meta_doi(value))
+    {
+        metadata.insert("doi".to_string(), doi);
+    }
+    if let Some(year) =
+        first_meta_value(meta_tags, DATE_KEYS).and_then(|date| extract_year_from_str(&date))
+    {
+        metadata.insert("year".to_string(), year);
+    }
+    if let Some(text) =
+        first_meta_value(meta_tags, ABSTRACT_KEYS).and_then(|raw| plain_text_abstract(&raw))
+    {
+        metadata.insert("abstract".to_string(), text);
+    }
+    metadata
+}
+
+/// Strips `doi:` and `doi.org` prefixes; returns `None` for non-DOI identifiers.
+fn normalize_meta_doi(value: &str) -> Option<String> {
+    let trimmed = value.trim();
+    let lower = trimmed.to_ascii_lowercase();
+    let start = [
+        "https://doi.org/",
+        "http://doi.org/",
+        "https://dx.doi.org/",
+        "doi:",
+    ]
+    .iter()
+    .find(|prefix| lower.starts_with(*prefix))
+    .map_or(0, |prefix| prefix.len());
+    let doi = trimmed[start..].trim();
+    doi.starts_with("10.").then(|| doi.to_string())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::test_support::socket_guard::start_mock_server_or_skip;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    const LANDING_PAGE: &str = r#"<html><head>
+        <meta name="citation_title" content="Generic Landing Page &amp; PDFs">
+        <meta name="citation_author" content="Smith, Jane">
+        <meta name="citation_author" content="Doe, John">
+        <meta name="citation_doi" content="doi:10.1234/landing">
+        <meta name="citation_publication_date" content="2021/03/04">
+        <meta name="citation_abstract" content="&lt;p&gt;Landing pages carry abstracts.&lt;/p&gt;">
+        <meta name="citation_pdf_url" content="/content/paper.pdf">
+        </head><body></body></html>"#;
+
+    fn html(body: &str) -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_raw(body, "text/html; charset=utf-8")
+    }
+
+    #[test]
+    fn test_can_handle_skips_direct_file_links() {
+        let resolver = CitationMetaResolver::new(None).unwrap();
+        assert!(resolver.can_handle("https://example.com/article/123", InputType::Url));
+        assert!(resolver.can_handle("https://example.com/view.php?id=1", InputType::Url));
+        assert!(resolver.can_handle("https://example.com/", InputType::Url));
+        assert!(!resolver.can_handle("https://example.com/paper.pdf", InputType::Url));
+        assert!(!resolver.can_handle("https://example.com/data.tar.gz", InputType::Url));
+        assert!(!resolver.can_handle("10.1234/abc", InputType::Doi));
+    }
+
+    #[test]
+    fn test_normalize_meta_doi_strips_prefixes() {
+        assert_eq!(
+            normalize_meta_doi("doi:10.1234/x").as_deref(),
+            Some("10.1234/x")
+        );
+        assert_eq!(
+            normalize_meta_doi("https://doi.org/10.1234/x").as_deref(),
+            Some("10.1234/x")
+        );
+        assert_eq!(normalize_meta_doi("ISBN 978-0"), None);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_extracts_pdf_url_and_metadata_after_redirect() {
+        let Some(server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/doi/10.1234/landing"))
+            .respond_with(ResponseTemplate::new(301).insert_header("Location", "/article/landing"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/article/landing"))
+            .respond_with(html(LANDING_PAGE))
+            .mount(&server)
+            .await;
+
+        let resolver = CitationMetaResolver::new(None).unwrap();
+        let step = resolver
+            .resolve(
+                &format!("{}/doi/10.1234/landing", server.uri()),
+                &ResolveContext::default(),
+            )
+            .await
+            .unwrap();
+
+        let ResolveStep::Url(resolved) = step else {
+            panic!("expected Url, got {step:?}");
+        };
+        assert_eq!(resolved.url, format!("{}/content/paper.pdf", server.uri()));
+        assert_eq!(resolved.metadata["title"], "Generic Landing Page & PDFs");
+        assert_eq!(resolved.metadata["authors"], "Smith, Jane; Doe, John");
+        assert_eq!(resolved.metadata["doi"], "10.1234/landing");
+        assert_eq!(
+            resolved.metadata["abstract"],
+            "Landing pages carry abstracts."
+        );
+        assert_eq!(resolved.metadata["year"], "2021");
+        assert_eq!(
+            resolved.metadata["source_url"],
+            format!("{}/article/landing", server.uri())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_fails_for_non_html_and_pages_without_meta() {
+        let Some(server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/download"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw("%PDF-1.5", "application/pdf"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/plain"))
+            .respond_with(html("<html><head><title>No meta</title></head></html>"))
+            .mount(&server)
+            .await;
+
+        let resolver = CitationMetaResolver::new(None).unwrap();
+        for route in ["/download", "/plain"] {
+            let step = resolver
+                .resolve(
+                    &format!("{}{route}", server.uri()),
+                    &ResolveContext::default(),
+                )
+                .await
+                .unwrap();
+            assert!(matches!(step, ResolveStep::Failed(_)), "{route}: {step:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_uses_link_header_signposting() {
+        let Some(server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/records/42"))
+            .respond_with(
+                html(r#"<html><head><meta name="citation_title" content="Signposted"></head></html>"#)
+                    .insert_header(
+                        "Link",
+                        r#"<https://doi.org/10.1234/sp>; rel="cite-as", </records/42/files/paper.pdf>; rel="item"; type="application/pdf""#,
+                    ),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/records/43"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_raw("{}", "application/json")
+                    .insert_header(
+                        "Link",
+                        r#"</records/43/files/paper.pdf>; rel="item"; type="application/pdf""#,
+                    ),
+            )
+            .mount(&server)
+            .await;
+
+        let resolver = CitationMetaResolver::new(None).unwrap();
+        let step = resolver
+            .resolve(
+                &format!("{}/records/42", server.uri()),
+                &ResolveContext::default(),
+            )
+            .await
+            .unwrap();
+        let ResolveStep::Url(resolved) = step else {
+            panic!("expected Url, got {step:?}");
+        };
+        assert_eq!(
+            resolved.url,
+            format!("{}/records/42/files/paper.pdf", server.uri())
+        );
+        assert_eq!(resolved.metadata["title"], "Signposted");
+
+        let step = resolver
+            .resolve(
+                &format!("{}/records/43", server.uri()),
+                &ResolveContext::default(),
+            )
+            .await
+            .unwrap();
+        let ResolveStep::Url(resolved) = step else {
+            panic!("expected Url for non-HTML signposted response, got {step:?}");
+        };
+        assert_eq!(
+            resolved.url,
+            format!("{}/records/43/files/paper.pdf", server.uri())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_uses_html_link_alternate_signposting() {
+        let Some(server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/handle/1/2"))
+            .respond_with(html(
+                r#"<html><head>
+                <meta name="citation_title" content="Repository Item">
+                <link rel="alternate" type="application/pdf" href="/bitstream/1/2/item.pdf">
+                </head></html>"#,
+            ))
+            .mount(&server)
+            .await;
+
+        let step = CitationMetaResolver::new(None)
+            .unwrap()
+            .resolve(
+                &format!("{}/handle/1/2", server.uri()),
+                &ResolveContext::default(),
+            )
+            .await
+            .unwrap();
+        let ResolveStep::Url(resolved) = step else {
+            panic!("expected Url, got {step:?}");
+        };
+        assert_eq!(
+            resolved.url,
+            format!("{}/bitstream/1/2/item.pdf", server.uri())
+        );
+        assert_eq!(resolved.metadata["title"], "Repository Item");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_respects_max_redirects() {
+        let Some(server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/loop"))
+            .respond_with(ResponseTemplate::new(302).insert_header("Location", "/loop"))
+            .mount(&server)
+            .await;
+
+        let ctx = ResolveContext {
+            max_redirects: 2,
+            ..ResolveContext::default()
+        };
+        let step = CitationMetaResolver::new(None)
+            .unwrap()
+            .resolve(&format!("{}/loop", server.uri()), &ctx)
+            .await
+            .unwrap();
+        let ResolveStep::Failed(error) = step else {
+            panic!("expected Failed, got {step:?}");
+        };
+        assert!(
+            matches!(error, ResolveError::TooManyRedirects { .. }),
+            "{error}"
+        );
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+    }
+}
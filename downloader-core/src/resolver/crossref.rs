@@ -3,22 +3,149 @@
 //! The [`CrossrefResolver`] calls the Crossref REST API to look up metadata for DOIs
 //! and extract PDF URLs from the response. When no PDF link is available, it redirects
 //! to the `doi.org` URL for the `DirectResolver` to handle.
+//!
+//! Free-text references are matched with a `query.bibliographic` search; only a
+//! clear, high-scoring top match is accepted.
+//!
+//! When the REST API is unreachable or rate-limited, DOI metadata falls back to
+//! `doi.org` content negotiation (see [`super::doi_metadata`]).
+//!
+//! [`CrossrefConfig`] carries the polite-pool contact address and an optional
+//! Crossref Plus API token, sent as the `Crossref-Plus-API-Token` header.
 
 use async_trait::async_trait;
-use reqwest::Client;
+use reqwest::header::HeaderValue;
+use reqwest::{Client, RequestBuilder};
 use serde::Deserialize;
 use std::collections::HashMap;
 use tracing::{debug, warn};
 
-use crate::parser::InputType;
+use crate::parser::{Confidence, InputType, extract_reference_confidence};
 
-use super::http_client::{build_resolver_http_client, standard_user_agent};
-use super::utils::validate_crossref_mailto;
+use super::doi_metadata::{DEFAULT_DOI_BASE_URL, fetch_csl_metadata};
+use super::http_client::{build_resolver_http_client, send_with_retry, standard_user_agent};
+use super::meta::plain_text_abstract;
+use super::utils::{looks_like_doi, looks_like_email, validate_crossref_mailto};
 use super::{ResolveContext, ResolveError, ResolveStep, ResolvedUrl, Resolver, ResolverPriority};
 
 /// Default Crossref API base URL.
 const DEFAULT_BASE_URL: &str = "https://api.crossref.org";
 
+/// Polite-pool contact used when none is configured or the configured one is invalid.
+pub const DEFAULT_CROSSREF_MAILTO: &str = "downloader@example.com";
+
+/// Header carrying a Crossref Plus API token.
+const CROSSREF_PLUS_TOKEN_HEADER: &str = "Crossref-Plus-API-Token";
+
+/// Number of candidates requested for a `query.bibliographic` search.
+const BIBLIOGRAPHIC_ROWS: u8 = 5;
+
+/// Minimum Crossref relevance score for a bibliographic match to be trusted.
+///
+/// Crossref scores are unnormalized; full, correct citations typically score
+/// well above this, while partial or garbled ones fall below it.
+const MIN_BIBLIOGRAPHIC_SCORE: f64 = 60.0;
+
+/// A runner-up scoring at least this fraction of the top score is a near-tie.
+const BIBLIOGRAPHIC_TIE_RATIO: f64 = 0.9;
+
+// ==================== Configuration ====================
+
+/// A Crossref Plus API token.
+///
+/// The value is redacted from `Debug` output so it cannot leak into logs.
+#[derive(Clone, PartialEq, Eq)]
+pub struct CrossrefApiToken(String);
+
+impl CrossrefApiToken {
+    /// Wraps a token, trimming surrounding whitespace.
+    ///
+    /// Returns `None` when the token is empty or contains characters that are
+    /// not visible ASCII (and so cannot be sent as a header value).
+    #[must_use]
+    pub fn new(token: &str) -> Option<Self> {
+        let token = token.trim();
+        if token.is_empty() || !token.bytes().all(|b| b.is_ascii_graphic()) {
+            return None;
+        }
+        Some(Self(token.to_string()))
+    }
+
+    /// Returns the raw token for sending to Crossref.
+    #[must_use]
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+// Custom Debug impl that redacts the token.
+impl std::fmt::Debug for CrossrefApiToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CrossrefApiToken([REDACTED])")
+    }
+}
+
+/// Crossref polite-pool settings shared by the Crossref-backed resolvers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrossrefConfig {
+    mailto: String,
+    api_token: Option<CrossrefApiToken>,
+}
+
+impl CrossrefConfig {
+    /// Creates settings for the given polite-pool contact address.
+    ///
+    /// An address that does not look like an email is replaced by
+    /// [`DEFAULT_CROSSREF_MAILTO`] with a warning, so a config typo degrades
+    /// rate limits instead of disabling Crossref.
+    #[must_use]
+    pub fn new(mailto: &str) -> Self {
+        let mailto = mailto.trim();
+        let mailto = if looks_like_email(mailto) {
+            mailto.to_string()
+        } else {
+            warn!(
+                mailto = %mailto.escape_debug(),
+                default = DEFAULT_CROSSREF_MAILTO,
+                "crossref_mailto does not look like an email address; using the default"
+            );
+            DEFAULT_CROSSREF_MAILTO.to_string()
+        };
+        Self {
+            mailto,
+            api_token: None,
+        }
+    }
+
+    /// Sets the Crossref Plus API token sent with every Crossref request.
+    #[must_use]
+    pub fn with_api_token(mut self, api_token: Option<CrossrefApiToken>) -> Self {
+        self.api_token = api_token;
+        self
+    }
+
+    /// Contact address sent as the `mailto` query parameter.
+    #[must_use]
+    pub fn mailto(&self) -> &str {
+        &self.mailto
+    }
+
+    /// Crossref Plus API token, if configured.
+    #[must_use]
+    pub fn api_token(&self) -> Option<&CrossrefApiToken> {
+        self.api_token.as_ref()
+    }
+}
+
+impl Default for CrossrefConfig {
+    fn default() -> Self {
+        Self {
+            mailto: DEFAULT_CROSSREF_MAILTO.to_string(),
+            api_token: None,
+        }
+    }
+}
+
 // ==================== Crossref API Response Types ====================
 
 /// Top-level Crossref API response.
@@ -48,6 +175,11 @@ pub(crate) struct CrossrefMessage {
     /// Full journal name, e.g. `["Sensors"]` or `["Applied Sciences"]`.
     /// Used by the MDPI resolver to derive the CDN path slug.
     pub container_title: Option<Vec<String>>,
+    /// Relevance score; only present on search results.
+    pub score: Option<f64>,
+    /// Abstract as deposited by the publisher, usually JATS XML.
+    #[serde(rename = "abstract")]
+    pub abstract_text: Option<String>,
 }
 
 impl CrossrefMessage {
@@ -65,6 +197,9 @@ impl CrossrefMessage {
 pub(crate) struct CrossrefAuthor {
     pub given: Option<String>,
     pub family: Option<String>,
+    /// ORCID iD, usually as an `http(s)://orcid.org/...` URL.
+    #[serde(rename = "ORCID")]
+    pub orcid: Option<String>,
 }
 
 /// A resource link from the Crossref response.
@@ -109,14 +244,22 @@ pub(crate) struct CrossrefSearchMessage {
 /// PDF URLs from the `message.link` array. When no PDF link is found, it
 /// redirects to `https://doi.org/{doi}` for fallback handling.
 ///
+/// Reference inputs are searched with `query.bibliographic` and resolved
+/// through the DOI of the top match, provided the reference itself has at
+/// least medium [`Confidence`] and the match is unambiguous.
+///
 /// # Polite Pool
 ///
 /// All requests include a `mailto` query parameter to access Crossref's
 /// polite pool, which provides higher rate limits (10 req/s vs 5 req/s).
+/// With a Crossref Plus token (see [`CrossrefResolver::with_api_token`]),
+/// requests also carry the `Crossref-Plus-API-Token` header.
 pub struct CrossrefResolver {
     client: Client,
     base_url: String,
+    doi_base_url: String,
     mailto: String,
+    api_token: Option<HeaderValue>,
 }
 
 impl CrossrefResolver {
@@ -134,6 +277,19 @@ impl CrossrefResolver {
         Self::build(mailto.into(), DEFAULT_BASE_URL.to_string())
     }
 
+    /// Creates a `CrossrefResolver` from shared [`CrossrefConfig`] settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResolveError`] if HTTP client construction fails.
+    pub fn from_config(config: &CrossrefConfig) -> Result<Self, ResolveError> {
+        let resolver = Self::new(config.mailto())?;
+        Ok(match config.api_token() {
+            Some(token) => resolver.with_api_token(token),
+            None => resolver,
+        })
+    }
+
     /// Creates a `CrossrefResolver` with a custom base URL (for testing with wiremock).
     ///
     /// # Errors
@@ -155,16 +311,49 @@ impl CrossrefResolver {
         Ok(Self {
             client,
             base_url,
+            doi_base_url: DEFAULT_DOI_BASE_URL.to_string(),
             mailto,
+            api_token: None,
         })
     }
+
+    /// Sends `token` as the `Crossref-Plus-API-Token` header on API requests.
+    #[must_use]
+    pub fn with_api_token(mut self, token: &CrossrefApiToken) -> Self {
+        if let Ok(mut value) = HeaderValue::from_str(token.expose()) {
+            value.set_sensitive(true);
+            self.api_token = Some(value);
+        } else {
+            warn!("Crossref API token is not a valid header value; ignoring it");
+        }
+        self
+    }
+
+    /// Builds a GET request for a Crossref API URL, attaching the Plus token if set.
+    fn api_get(&self, url: &str) -> RequestBuilder {
+        let request = self.client.get(url);
+        match &self.api_token {
+            Some(token) => request.header(CROSSREF_PLUS_TOKEN_HEADER, token.clone()),
+            None => request,
+        }
+    }
+
+    /// Overrides the `doi.org` base URL used for the content-negotiation
+    /// metadata fallback (for testing with wiremock).
+    #[must_use]
+    pub fn with_doi_base_url(mut self, doi_base_url: impl Into<String>) -> Self {
+        self.doi_base_url = doi_base_url.into();
+        self
+    }
 }
 
 impl std::fmt::Debug for CrossrefResolver {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CrossrefResolver")
             .field("base_url", &self.base_url)
+            .field("doi_base_url", &self.doi_base_url)
             .field("mailto", &self.mailto)
+            .field("api_token", &self.api_token.as_ref().map(|_| "[REDACTED]"))
             .finish_non_exhaustive()
     }
 }
@@ -180,15 +369,100 @@ impl Resolver for CrossrefResolver {
     }
 
     fn can_handle(&self, _input: &str, input_type: InputType) -> bool {
-        input_type == InputType::Doi
+        matches!(input_type, InputType::Doi | InputType::Reference)
     }
 
-    #[tracing::instrument(skip(self, _ctx), fields(resolver = "crossref", doi = %input))]
+    #[tracing::instrument(skip(self, ctx), fields(resolver = "crossref", input = %input))]
     async fn resolve(
         &self,
         input: &str,
-        _ctx: &ResolveContext,
+        ctx: &ResolveContext,
     ) -> Result<ResolveStep, ResolveError> {
+        if looks_like_doi(input, "10.") {
+            self.resolve_doi(input, ctx).await
+        } else {
+            self.resolve_reference(input, ctx).await
+        }
+    }
+}
+
+impl CrossrefResolver {
+    /// Fetches Crossref metadata for `doi` without resolving a download URL.
+    ///
+    /// Returns the same keys the resolver attaches to a [`ResolvedUrl`]
+    /// (`doi`, `title`, `authors`, `year`, `journal`, ...). Used by
+    /// [`ResolverRegistry`](super::ResolverRegistry) to enrich results from
+    /// other resolvers. When the REST API is unreachable or rate-limited, the
+    /// metadata comes from `doi.org` content negotiation instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResolveError::ResolutionFailed`] when Crossref does not know
+    /// the DOI, or when neither Crossref nor `doi.org` returns usable metadata.
+    #[tracing::instrument(skip(self, ctx), fields(resolver = "crossref"))]
+    pub async fn fetch_metadata(
+        &self,
+        doi: &str,
+        ctx: &ResolveContext,
+    ) -> Result<HashMap<String, String>, ResolveError> {
+        match self.fetch_work(doi, ctx).await {
+            Ok(work) => Ok(extract_metadata(&work, doi)),
+            Err(failure) if failure.api_unavailable => self
+                .fetch_doi_org_metadata(doi, ctx)
+                .await
+                .map_err(|_| failure.error),
+            Err(failure) => Err(failure.error),
+        }
+    }
+
+    /// Looks up a DOI via `/works/{doi}`.
+    ///
+    /// If the API is unreachable or rate-limited, the `doi.org` URL is returned
+    /// with content-negotiated metadata, so the item still reaches the queue
+    /// with a title even when no PDF link is known.
+    async fn resolve_doi(
+        &self,
+        input: &str,
+        ctx: &ResolveContext,
+    ) -> Result<ResolveStep, ResolveError> {
+        match self.fetch_work(input, ctx).await {
+            Ok(work) => Ok(step_for_work(&work, input)),
+            Err(failure) if failure.api_unavailable => {
+                match self.fetch_doi_org_metadata(input, ctx).await {
+                    Ok(metadata) => {
+                        let doi_url = format!("{}/{input}", self.doi_base_url);
+                        debug!(%doi_url, "Crossref unavailable; using doi.org metadata");
+                        Ok(ResolveStep::Url(ResolvedUrl::with_metadata(
+                            doi_url, metadata,
+                        )))
+                    }
+                    Err(_) => Ok(ResolveStep::Failed(failure.error)),
+                }
+            }
+            Err(failure) => Ok(ResolveStep::Failed(failure.error)),
+        }
+    }
+
+    /// Content-negotiation fallback for when the REST API cannot answer.
+    async fn fetch_doi_org_metadata(
+        &self,
+        doi: &str,
+        ctx: &ResolveContext,
+    ) -> Result<HashMap<String, String>, ResolveError> {
+        let result = fetch_csl_metadata(&self.client, &self.doi_base_url, doi, ctx).await;
+        if let Err(error) = &result {
+            debug!(error = %error, "doi.org metadata fallback failed");
+        }
+        result
+    }
+
+    /// Fetches the `/works/{doi}` record, mapping every failure to a
+    /// user-facing [`ResolveError::ResolutionFailed`].
+    async fn fetch_work(
+        &self,
+        input: &str,
+        ctx: &ResolveContext,
+    ) -> Result<CrossrefMessage, WorkFetchFailure> {
         let encoded_doi = urlencoding::encode(input);
         let encoded_mailto = urlencoding::encode(&self.mailto);
         let url = format!(
@@ -196,16 +470,19 @@ impl Resolver for CrossrefResolver {
             self.base_url, encoded_doi, encoded_mailto
         );
 
+        ctx.throttle(&url).await;
         debug!(api_url = %url, "Calling Crossref API");
 
-        let response = match self.client.get(&url).send().await {
+        let response = match send_with_retry(self.api_get(&url), "crossref").await {
             Ok(resp) => resp,
             Err(e) => {
                 warn!(error = %e, "Crossref API request failed");
-                return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
-                    input,
-                    "Cannot reach Crossref API. Check your network connection.",
-                )));
+                return Err(WorkFetchFailure::unavailable(
+                    ResolveError::resolution_failed(
+                        input,
+                        "Cannot reach Crossref API. Check your network connection.",
+                    ),
+                ));
             }
         };
 
@@ -226,16 +503,19 @@ impl Resolver for CrossrefResolver {
                 s => format!("Crossref API returned HTTP {s}"),
             };
             debug!(status = status.as_u16(), %reason, "Crossref API error");
-            return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
-                input, &reason,
-            )));
+            let error = ResolveError::resolution_failed(input, &reason);
+            return Err(if status.as_u16() == 429 || status.is_server_error() {
+                WorkFetchFailure::unavailable(error)
+            } else {
+                WorkFetchFailure::rejected(error)
+            });
         }
 
         let body = match response.json::<CrossrefResponse>().await {
             Ok(parsed) => parsed,
             Err(e) => {
                 warn!(error = %e, "Failed to parse Crossref response JSON");
-                return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                return Err(WorkFetchFailure::rejected(ResolveError::resolution_failed(
                     input,
                     "Unexpected Crossref API response format",
                 )));
@@ -244,28 +524,168 @@ impl Resolver for CrossrefResolver {
 
         if !body.status.eq_ignore_ascii_case("ok") {
             warn!(status = %body.status, "Crossref response status was not ok");
-            return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+            return Err(WorkFetchFailure::rejected(ResolveError::resolution_failed(
                 input,
                 "Unexpected Crossref response status",
             )));
         }
 
-        let metadata = extract_metadata(&body.message, input);
-        let links = body.message.link.as_deref().unwrap_or(&[]);
+        Ok(body.message)
+    }
 
-        if let Some(pdf_url) = extract_pdf_url(links) {
-            debug!(pdf_url = %pdf_url, "Found PDF URL in Crossref response");
-            Ok(ResolveStep::Url(ResolvedUrl::with_metadata(
-                pdf_url, metadata,
-            )))
-        } else {
-            let doi_url = format!("https://doi.org/{input}");
-            debug!(redirect_url = %doi_url, "No PDF link found, redirecting to doi.org");
-            Ok(ResolveStep::Redirect(doi_url))
+    /// Matches a free-text reference via `/works?query.bibliographic=...`.
+    async fn resolve_reference(
+        &self,
+        input: &str,
+        ctx: &ResolveContext,
+    ) -> Result<ResolveStep, ResolveError> {
+        let confidence = extract_reference_confidence(input);
+        if confidence.level == Confidence::Low {
+            debug!(confidence = %confidence.level, "Reference too sparse for Crossref search");
+            return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                "Reference is too incomplete to search Crossref. \
+                 Why: fewer than two of author, year, and title were recognized. \
+                 Fix: paste the full citation or provide the DOI directly.",
+            )));
+        }
+
+        let url = format!(
+            "{}/works?query.bibliographic={}&rows={BIBLIOGRAPHIC_ROWS}&mailto={}",
+            self.base_url,
+            urlencoding::encode(input),
+            urlencoding::encode(&self.mailto)
+        );
+        ctx.throttle(&url).await;
+        debug!(api_url = %url, "Calling Crossref bibliographic search");
+
+        let response = match send_with_retry(self.api_get(&url), "crossref").await {
+            Ok(resp) => resp,
+            Err(e) => {
+                warn!(error = %e, "Crossref search request failed");
+                return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                    input,
+                    "Cannot reach Crossref API. Check your network connection.",
+                )));
+            }
+        };
+
+        let status = response.status();
+        if !status.is_success() {
+            debug!(status = status.as_u16(), "Crossref search error");
+            return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                &format!("Crossref search returned HTTP {}", status.as_u16()),
+            )));
+        }
+
+        let body = match response.json::<CrossrefSearchResponse>().await {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                warn!(error = %e, "Failed to parse Crossref search JSON");
+                return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                    input,
+                    "Unexpected Crossref API response format",
+                )));
+            }
+        };
+
+        let items = body.message.items.unwrap_or_default();
+        match select_bibliographic_match(&items) {
+            Ok(work) => {
+                let Some(doi) = work.doi.as_deref() else {
+                    return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                        input,
+                        "Crossref match has no DOI",
+                    )));
+                };
+                debug!(doi, score = ?work.score, "Accepted Crossref bibliographic match");
+                Ok(step_for_work(work, doi))
+            }
+            Err(reason) => {
+                debug!(%reason, "Rejected Crossref bibliographic matches");
+                Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                    input, &reason,
+                )))
+            }
         }
     }
 }
 
+/// Why a `/works/{doi}` lookup produced no record.
+struct WorkFetchFailure {
+    error: ResolveError,
+    /// The API was unreachable, rate-limited, or erroring (5xx), as opposed
+    /// to answering that it has no usable record.
+    api_unavailable: bool,
+}
+
+impl WorkFetchFailure {
+    fn unavailable(error: ResolveError) -> Self {
+        Self {
+            error,
+            api_unavailable: true,
+        }
+    }
+
+    fn rejected(error: ResolveError) -> Self {
+        Self {
+            error,
+            api_unavailable: false,
+        }
+    }
+}
+
+/// Builds the resolve step for a Crossref work: its PDF link, or a `doi.org` redirect.
+fn step_for_work(work: &CrossrefMessage, doi: &str) -> ResolveStep {
+    let metadata = extract_metadata(work, doi);
+    let links = work.link.as_deref().unwrap_or(&[]);
+
+    if let Some(pdf_url) = extract_pdf_url(links) {
+        debug!(pdf_url = %pdf_url, "Found PDF URL in Crossref response");
+        ResolveStep::Url(ResolvedUrl::with_metadata(pdf_url, metadata))
+    } else {
+        let doi_url = format!("https://doi.org/{doi}");
+        debug!(redirect_url = %doi_url, "No PDF link found, redirecting to doi.org");
+        ResolveStep::Redirect(doi_url)
+    }
+}
+
+/// Picks the top-scoring search result, rejecting weak or ambiguous matches.
+///
+/// Returns a user-facing reason when no result is trustworthy: nothing
+/// matched, the best score is below [`MIN_BIBLIOGRAPHIC_SCORE`], or the
+/// runner-up is within [`BIBLIOGRAPHIC_TIE_RATIO`] of the best.
+fn select_bibliographic_match(items: &[CrossrefMessage]) -> Result<&CrossrefMessage, String> {
+    let mut ranked: Vec<&CrossrefMessage> = items.iter().collect();
+    ranked.sort_by(|a, b| b.score.unwrap_or(0.0).total_cmp(&a.score.unwrap_or(0.0)));
+
+    let Some(best) = ranked.first() else {
+        return Err("No Crossref works match this reference. \
+                    Fix: verify the citation manually and provide the DOI."
+            .to_string());
+    };
+    let best_score = best.score.unwrap_or(0.0);
+    if best_score < MIN_BIBLIOGRAPHIC_SCORE {
+        return Err(format!(
+            "Best Crossref match is uncertain (score {best_score:.1}). \
+             Why: the reference may be incomplete or not indexed. \
+             Fix: verify the citation manually and provide the DOI."
+        ));
+    }
+    if let Some(runner_up) = ranked.get(1) {
+        let runner_up_score = runner_up.score.unwrap_or(0.0);
+        if runner_up_score >= best_score * BIBLIOGRAPHIC_TIE_RATIO {
+            return Err(format!(
+                "Multiple Crossref works match this reference closely \
+                 (scores {best_score:.1} and {runner_up_score:.1}). \
+                 Fix: verify the citation manually and provide the DOI."
+            ));
+        }
+    }
+    Ok(best)
+}
+
 // ==================== Extraction Helpers ====================
 
 /// Extracts the best PDF URL from Crossref link entries.
@@ -327,20 +747,28 @@ pub(crate) fn extract_metadata(message: &CrossrefMessage, doi: &str) -> HashMap<
         }
     }
 
-    // Authors
+    // Authors, with ORCID iDs kept positionally aligned with the names.
     if let Some(authors) = &message.author {
-        let formatted: Vec<String> = authors
+        let formatted: Vec<(String, &str)> = authors
             .iter()
-            .map(|a| match (&a.family, &a.given) {
-                (Some(f), Some(g)) => format!("{f}, {g}"),
-                (Some(f), None) => f.clone(),
-                (None, Some(g)) => g.clone(),
-                (None, None) => String::new(),
+            .map(|a| {
+                let name = match (&a.family, &a.given) {
+                    (Some(f), Some(g)) => format!("{f}, {g}"),
+                    (Some(f), None) => f.clone(),
+                    (None, Some(g)) => g.clone(),
+                    (None, None) => String::new(),
+                };
+                (name, a.orcid.as_deref().map_or("", str::trim))
             })
-            .filter(|s| !s.is_empty())
+            .filter(|(name, _)| !name.is_empty())
             .collect();
         if !formatted.is_empty() {
-            metadata.insert("authors".to_string(), formatted.join("; "));
+            let names: Vec<&str> = formatted.iter().map(|(name, _)| name.as_str()).collect();
+            metadata.insert("authors".to_string(), names.join("; "));
+            if formatted.iter().any(|(_, orcid)| !orcid.is_empty()) {
+                let orcids: Vec<&str> = formatted.iter().map(|(_, orcid)| *orcid).collect();
+                metadata.insert("author_orcids".to_string(), orcids.join(";"));
+            }
         }
     }
 
@@ -352,6 +780,23 @@ pub(crate) fn extract_metadata(message: &CrossrefMessage, doi: &str) -> HashMap<
         metadata.insert("year".to_string(), y);
     }
 
+    if let Some(journal) = message.container_title_str() {
+        metadata.insert("journal".to_string(), journal.to_string());
+    }
+    if let Some(volume) = &message.volume {
+        metadata.insert("volume".to_string(), volume.clone());
+    }
+    if let Some(issue) = &message.issue {
+        metadata.insert("issue".to_string(), issue.clone());
+    }
+    if let Some(text) = message
+        .abstract_text
+        .as_deref()
+        .and_then(plain_text_abstract)
+    {
+        metadata.insert("abstract".to_string(), text);
+    }
+
     metadata
 }
 
@@ -613,10 +1058,12 @@ mod tests {
                 CrossrefAuthor {
                     given: Some("John".to_string()),
                     family: Some("Smith".to_string()),
+                    orcid: None,
                 },
                 CrossrefAuthor {
                     given: Some("Jane".to_string()),
                     family: Some("Doe".to_string()),
+                    orcid: None,
                 },
             ]),
             link: None,
@@ -630,6 +1077,8 @@ mod tests {
             issue: None,
             article_number: None,
             container_title: None,
+            score: None,
+            abstract_text: None,
         };
 
         let meta = extract_metadata(&message, "10.1234/test");
@@ -639,6 +1088,41 @@ mod tests {
         assert_eq!(meta.get("doi").unwrap(), "10.1234/test");
     }
 
+    #[test]
+    fn test_extract_metadata_includes_journal_volume_issue() {
+        let message = CrossrefMessage {
+            title: None,
+            author: None,
+            link: None,
+            published: None,
+            published_print: None,
+            published_online: None,
+            doi: None,
+            volume: Some("7".to_string()),
+            issue: Some("3".to_string()),
+            article_number: None,
+            container_title: Some(vec!["Journal of Tests".to_string()]),
+            score: None,
+            abstract_text: None,
+        };
+
+        let meta = extract_metadata(&message, "10.1234/test");
+        assert_eq!(meta.get("journal").unwrap(), "Journal of Tests");
+        assert_eq!(meta.get("volume").unwrap(), "7");
+        assert_eq!(meta.get("issue").unwrap(), "3");
+    }
+
+    #[test]
+    fn test_extract_metadata_plain_text_abstract_from_jats() {
+        let message: CrossrefMessage = serde_json::from_str(
+            r#"{"abstract": "<jats:title>Abstract</jats:title><jats:p>Rivers &amp; lakes warm.</jats:p>"}"#,
+        )
+        .unwrap();
+
+        let meta = extract_metadata(&message, "10.1234/test");
+        assert_eq!(meta.get("abstract").unwrap(), "Rivers & lakes warm.");
+    }
+
     #[test]
     fn test_extract_metadata_missing_title() {
         let message = CrossrefMessage {
@@ -653,12 +1137,15 @@ mod tests {
             issue: None,
             article_number: None,
             container_title: None,
+            score: None,
+            abstract_text: None,
         };
 
         let meta = extract_metadata(&message, "10.1234/test");
         assert!(!meta.contains_key("title"));
         assert!(!meta.contains_key("authors"));
         assert!(!meta.contains_key("year"));
+        assert!(!meta.contains_key("abstract"));
         assert_eq!(meta.get("doi").unwrap(), "10.1234/test");
     }
 
@@ -670,14 +1157,17 @@ mod tests {
                 CrossrefAuthor {
                     given: Some("A".to_string()),
                     family: Some("First".to_string()),
+                    orcid: None,
                 },
                 CrossrefAuthor {
                     given: None,
                     family: Some("Consortium".to_string()),
+                    orcid: None,
                 },
                 CrossrefAuthor {
                     given: Some("C".to_string()),
                     family: Some("Third".to_string()),
+                    orcid: None,
                 },
             ]),
             link: None,
@@ -689,6 +1179,8 @@ mod tests {
             issue: None,
             article_number: None,
             container_title: None,
+            score: None,
+            abstract_text: None,
         };
 
         let meta = extract_metadata(&message, "10.1234/test");
@@ -698,6 +1190,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_extract_metadata_aligns_partial_orcids_with_authors() {
+        let message = CrossrefMessage {
+            title: None,
+            author: Some(vec![
+                CrossrefAuthor {
+                    given: Some("A".to_string()),
+                    family: Some("First".to_string()),
+                    orcid: Some("http://orcid.org/0000-0002-1825-0097".to_string()),
+                },
+                CrossrefAuthor {
+                    given: None,
+                    family: None,
+                    orcid: Some("http://orcid.org/0000-0001-5109-3700".to_string()),
+                },
+                CrossrefAuthor {
+                    given: Some("B".to_string()),
+                    family: Some("Second".to_string()),
+                    orcid: None,
+                },
+                CrossrefAuthor {
+                    given: Some("C".to_string()),
+                    family: Some("Third".to_string()),
+                    orcid: Some("https://orcid.org/0000-0003-1415-9269".to_string()),
+                },
+            ]),
+            link: None,
+            published: None,
+            published_print: None,
+            published_online: None,
+            doi: None,
+            volume: None,
+            issue: None,
+            article_number: None,
+            container_title: None,
+            score: None,
+            abstract_text: None,
+        };
+
+        let meta = extract_metadata(&message, "10.1234/test");
+        assert_eq!(
+            meta.get("authors").unwrap(),
+            "First, A; Second, B; Third, C"
+        );
+        // The nameless author is dropped together with its ORCID.
+        assert_eq!(
+            meta.get("author_orcids").unwrap(),
+            "http://orcid.org/0000-0002-1825-0097;;https://orcid.org/0000-0003-1415-9269"
+        );
+    }
+
+    #[test]
+    fn test_extract_metadata_omits_author_orcids_when_none_present() {
+        let message = CrossrefMessage {
+            title: None,
+            author: Some(vec![CrossrefAuthor {
+                given: Some("A".to_string()),
+                family: Some("First".to_string()),
+                orcid: None,
+            }]),
+            link: None,
+            published: None,
+            published_print: None,
+            published_online: None,
+            doi: None,
+            volume: None,
+            issue: None,
+            article_number: None,
+            container_title: None,
+            score: None,
+            abstract_text: None,
+        };
+
+        let meta = extract_metadata(&message, "10.1234/test");
+        assert!(!meta.contains_key("author_orcids"));
+    }
+
     #[test]
     fn test_extract_metadata_year_from_published_print() {
         let message = CrossrefMessage {
@@ -714,6 +1283,8 @@ mod tests {
             issue: None,
             article_number: None,
             container_title: None,
+            score: None,
+            abstract_text: None,
         };
 
         let meta = extract_metadata(&message, "10.1234/test");
@@ -736,6 +1307,8 @@ mod tests {
             issue: None,
             article_number: None,
             container_title: None,
+            score: None,
+            abstract_text: None,
         };
 
         let meta = extract_metadata(&message, "10.1234/test");
@@ -756,6 +1329,8 @@ mod tests {
             issue: None,
             article_number: None,
             container_title: None,
+            score: None,
+            abstract_text: None,
         };
 
         let meta = extract_metadata(&message, "10.1234/test");
@@ -934,8 +1509,10 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let resolver =
-            CrossrefResolver::with_base_url("test@example.com", mock_server.uri()).unwrap();
+        // doi.org fallback hits the same server and finds nothing (404).
+        let resolver = CrossrefResolver::with_base_url("test@example.com", mock_server.uri())
+            .unwrap()
+            .with_doi_base_url(mock_server.uri());
         let ctx = ResolveContext::default();
         let result = resolver.resolve("10.1234/test", &ctx).await.unwrap();
 
@@ -963,8 +1540,10 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let resolver =
-            CrossrefResolver::with_base_url("test@example.com", mock_server.uri()).unwrap();
+        // doi.org fallback hits the same server and finds nothing (404).
+        let resolver = CrossrefResolver::with_base_url("test@example.com", mock_server.uri())
+            .unwrap()
+            .with_doi_base_url(mock_server.uri());
         let ctx = ResolveContext::default();
         let result = resolver.resolve("10.1234/test", &ctx).await.unwrap();
 
@@ -980,6 +1559,74 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_crossref_resolver_unavailable_falls_back_to_doi_org_metadata() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+
+        Mock::given(method("GET"))
+            .and(path_regex(r"/works/10\..+"))
+            .respond_with(ResponseTemplate::new(429))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/10.1234/test"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                r#"{"title": "Negotiated Title", "author": [{"family": "Doe", "given": "Jo"}]}"#,
+                "application/vnd.citationstyles.csl+json",
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = CrossrefResolver::with_base_url("test@example.com", mock_server.uri())
+            .unwrap()
+            .with_doi_base_url(mock_server.uri());
+        let ctx = ResolveContext::default();
+
+        match resolver.resolve("10.1234/test", &ctx).await.unwrap() {
+            ResolveStep::Url(resolved) => {
+                assert_eq!(resolved.url, format!("{}/10.1234/test", mock_server.uri()));
+                assert_eq!(resolved.metadata["title"], "Negotiated Title");
+                assert_eq!(resolved.metadata["authors"], "Doe, Jo");
+            }
+            other => panic!("Expected ResolveStep::Url, got: {other:?}"),
+        }
+
+        let metadata = resolver.fetch_metadata("10.1234/test", &ctx).await.unwrap();
+        assert_eq!(metadata["title"], "Negotiated Title");
+    }
+
+    #[tokio::test]
+    async fn test_crossref_resolver_retries_transient_503() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+
+        Mock::given(method("GET"))
+            .and(path_regex(r"/works/10\..+"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path_regex(r"/works/10\..+"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(crossref_success_json()))
+            .mount(&mock_server)
+            .await;
+
+        let resolver =
+            CrossrefResolver::with_base_url("test@example.com", mock_server.uri()).unwrap();
+        let ctx = ResolveContext::default();
+        let result = resolver.resolve("10.1234/test", &ctx).await.unwrap();
+
+        assert!(
+            matches!(result, ResolveStep::Url(_)),
+            "expected success after retry, got {result:?}"
+        );
+    }
+
     #[tokio::test]
     async fn test_crossref_resolver_resolve_includes_metadata() {
         let Some(mock_server) = start_mock_server_or_skip().await else {
@@ -1065,6 +1712,57 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_crossref_resolver_sends_plus_api_token_header() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+
+        Mock::given(method("GET"))
+            .and(path_regex(r"/works/10\..+"))
+            .and(header("crossref-plus-api-token", "Bearer-secret-123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(crossref_success_json()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let token = CrossrefApiToken::new(" Bearer-secret-123 ").unwrap();
+        let resolver = CrossrefResolver::with_base_url("test@example.com", mock_server.uri())
+            .unwrap()
+            .with_api_token(&token);
+
+        let result = resolver
+            .resolve("10.1234/test", &ResolveContext::default())
+            .await
+            .unwrap();
+        assert!(matches!(result, ResolveStep::Url(_)));
+        assert!(!format!("{resolver:?}").contains("secret"));
+    }
+
+    #[test]
+    fn test_crossref_config_falls_back_to_default_mailto_when_not_an_email() {
+        assert_eq!(
+            CrossrefConfig::new(" me@lab.example.org ").mailto(),
+            "me@lab.example.org"
+        );
+        for invalid in ["", "not-an-email", "a@b@c", "invalid\nmailto@example.com"] {
+            assert_eq!(
+                CrossrefConfig::new(invalid).mailto(),
+                DEFAULT_CROSSREF_MAILTO,
+                "{invalid:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_crossref_api_token_rejects_blank_and_redacts_debug() {
+        assert!(CrossrefApiToken::new("   ").is_none());
+        assert!(CrossrefApiToken::new("has space").is_none());
+        let config = CrossrefConfig::default().with_api_token(CrossrefApiToken::new("s3cr3t"));
+        assert_eq!(config.api_token().unwrap().expose(), "s3cr3t");
+        assert!(!format!("{config:?}").contains("s3cr3t"));
+    }
+
     #[tokio::test]
     async fn test_crossref_resolver_sends_url_encoded_doi_path() {
         let Some(mock_server) = start_mock_server_or_skip().await else {
@@ -1153,4 +1851,148 @@ mod tests {
             other => panic!("Expected ResolveStep::Failed, got: {other:?}"),
         }
     }
+
+    // ==================== Bibliographic Reference Search ====================
+
+    const FULL_REFERENCE: &str =
+        "Smith, J., Doe, A. (2024). Deep learning for citation parsing. Journal of AI.";
+
+    fn scored_work(doi: &str, score: f64) -> CrossrefMessage {
+        serde_json::from_value(serde_json::json!({"DOI": doi, "score": score})).unwrap()
+    }
+
+    #[test]
+    fn test_select_bibliographic_match_accepts_clear_winner() {
+        let items = vec![
+            scored_work("10.1234/runner-up", 40.0),
+            scored_work("10.1234/best", 95.0),
+        ];
+        let best = select_bibliographic_match(&items).unwrap();
+        assert_eq!(best.doi.as_deref(), Some("10.1234/best"));
+    }
+
+    #[test]
+    fn test_select_bibliographic_match_rejects_low_score() {
+        let items = vec![scored_work("10.1234/weak", 12.5)];
+        let reason = select_bibliographic_match(&items).unwrap_err();
+        assert!(reason.contains("uncertain"), "{reason}");
+        assert!(reason.contains("verify the citation manually"), "{reason}");
+    }
+
+    #[test]
+    fn test_select_bibliographic_match_rejects_near_tie() {
+        let items = vec![
+            scored_work("10.1234/a", 90.0),
+            scored_work("10.1234/b", 88.0),
+        ];
+        let reason = select_bibliographic_match(&items).unwrap_err();
+        assert!(reason.contains("Multiple Crossref works"), "{reason}");
+    }
+
+    #[test]
+    fn test_select_bibliographic_match_rejects_empty_results() {
+        assert!(select_bibliographic_match(&[]).is_err());
+    }
+
+    #[test]
+    fn test_crossref_resolver_can_handle_references() {
+        let resolver = CrossrefResolver::new("test@example.com").unwrap();
+        assert!(resolver.can_handle(FULL_REFERENCE, InputType::Reference));
+        assert!(!resolver.can_handle("https://example.com", InputType::Url));
+    }
+
+    #[tokio::test]
+    async fn test_crossref_resolver_reference_uses_top_match() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/works"))
+            .and(query_param("query.bibliographic", FULL_REFERENCE))
+            .and(query_param("mailto", "test@example.com"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "ok",
+                "message": {
+                    "items": [
+                        {
+                            "DOI": "10.1234/citation-parsing",
+                            "score": 112.4,
+                            "title": ["Deep Learning for Citation Parsing"],
+                            "link": [{
+                                "URL": "https://publisher.com/citation.pdf",
+                                "content-type": "application/pdf"
+                            }]
+                        },
+                        {"DOI": "10.1234/unrelated", "score": 31.0}
+                    ]
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let resolver =
+            CrossrefResolver::with_base_url("test@example.com", mock_server.uri()).unwrap();
+        let ctx = ResolveContext::default();
+        let result = resolver.resolve(FULL_REFERENCE, &ctx).await.unwrap();
+
+        match result {
+            ResolveStep::Url(matched) => {
+                assert_eq!(matched.url, "https://publisher.com/citation.pdf");
+                assert_eq!(
+                    matched.metadata.get("doi").unwrap(),
+                    "10.1234/citation-parsing"
+                );
+            }
+            other => panic!("Expected ResolveStep::Url, got: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_crossref_resolver_reference_ambiguous_match_fails() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/works"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "ok",
+                "message": {
+                    "items": [
+                        {"DOI": "10.1234/a", "score": 80.0},
+                        {"DOI": "10.1234/b", "score": 79.0}
+                    ]
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let resolver =
+            CrossrefResolver::with_base_url("test@example.com", mock_server.uri()).unwrap();
+        let ctx = ResolveContext::default();
+        let result = resolver.resolve(FULL_REFERENCE, &ctx).await.unwrap();
+
+        match result {
+            ResolveStep::Failed(err) => {
+                assert!(err.to_string().contains("verify the citation manually"));
+            }
+            other => panic!("Expected ResolveStep::Failed, got: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_crossref_resolver_low_confidence_reference_skips_search() {
+        let resolver =
+            CrossrefResolver::with_base_url("test@example.com", "http://127.0.0.1:9").unwrap();
+        let ctx = ResolveContext::default();
+        let result = resolver.resolve("Smith", &ctx).await.unwrap();
+
+        match result {
+            ResolveStep::Failed(err) => {
+                assert!(err.to_string().contains("too incomplete"));
+            }
+            other => panic!("Expected ResolveStep::Failed, got: {other:?}"),
+        }
+    }
 }
@@ -0,0 +1,399 @@
+//! Publisher-independent DOI metadata via `doi.org` content negotiation.
+//!
+//! `https://doi.org/<doi>` requested with `Accept: application/vnd.citationstyles.csl+json`
+//! redirects to the registration agency (Crossref, `DataCite`, mEDRA, ...), which
+//! answers with CSL-JSON. [`CrossrefResolver`](super::CrossrefResolver) uses this
+//! as a metadata fallback when the Crossref REST API is unreachable or
+//! rate-limited.
+
+use std::collections::HashMap;
+
+use reqwest::Client;
+use reqwest::header::{ACCEPT, CONTENT_TYPE};
+use serde::Deserialize;
+use tracing::{debug, warn};
+
+use super::http_client::send_with_retry;
+use super::meta::plain_text_abstract;
+use super::{ResolveContext, ResolveError};
+
+/// Default DOI proxy base URL.
+pub(crate) const DEFAULT_DOI_BASE_URL: &str = "https://doi.org";
+
+/// Media type requested from `doi.org` content negotiation.
+const CSL_JSON_MEDIA_TYPE: &str = "application/vnd.citationstyles.csl+json";
+
+// ==================== CSL-JSON Types ====================
+
+/// The subset of a CSL-JSON item used for metadata.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct CslItem {
+    title: Option<CslText>,
+    author: Option<Vec<CslName>>,
+    issued: Option<CslDate>,
+    container_title: Option<CslText>,
+    volume: Option<CslScalar>,
+    issue: Option<CslScalar>,
+    #[serde(rename = "abstract")]
+    abstract_text: Option<CslText>,
+}
+
+/// CSL text fields are a string for some agencies and an array for others.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum CslText {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl CslText {
+    fn first(&self) -> Option<&str> {
+        match self {
+            Self::One(text) => Some(text.as_str()),
+            Self::Many(texts) => texts.first().map(String::as_str),
+        }
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+    }
+}
+
+/// CSL numbers (volume, date parts) arrive as either JSON numbers or strings.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum CslScalar {
+    Number(i64),
+    Text(String),
+}
+
+impl CslScalar {
+    fn to_text(&self) -> Option<String> {
+        match self {
+            Self::Number(number) => Some(number.to_string()),
+            Self::Text(text) => {
+                let trimmed = text.trim();
+                (!trimmed.is_empty()).then(|| trimmed.to_string())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CslName {
+    family: Option<String>,
+    given: Option<String>,
+    /// Institutional or single-field names.
+    literal: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct CslDate {
+    date_parts: Option<Vec<Vec<Option<CslScalar>>>>,
+}
+
+// ==================== Fetch ====================
+
+/// Fetches CSL-JSON metadata for `doi` from `doi.org` content negotiation.
+///
+/// Returns the standard metadata keys (`doi`, `title`, `authors`, `year`) plus
+/// `journal`, `volume`, and `issue` when present. The negotiation redirect to
+/// the registration agency is followed by `client`.
+///
+/// # Errors
+///
+/// Returns [`ResolveError::ResolutionFailed`] when the DOI is not registered
+/// (404), the agency does not offer CSL-JSON, or the response holds neither a
+/// title nor authors.
+pub(crate) async fn fetch_csl_metadata(
+    client: &Client,
+    doi_base_url: &str,
+    doi: &str,
+    ctx: &ResolveContext,
+) -> Result<HashMap<String, String>, ResolveError> {
+    let url = format!("{doi_base_url}/{}", encode_doi_path(doi));
+    ctx.throttle(&url).await;
+    debug!(doi_url = %url, "Requesting CSL-JSON via DOI content negotiation");
+
+    let request = client.get(&url).header(ACCEPT, CSL_JSON_MEDIA_TYPE);
+    let response = match send_with_retry(request, "doi.org").await {
+        Ok(response) => response,
+        Err(error) => {
+            warn!(error = %error, "DOI content negotiation request failed");
+            return Err(ResolveError::resolution_failed(
+                doi,
+                "Cannot reach doi.org. Check your network connection.",
+            ));
+        }
+    };
+
+    let status = response.status();
+    if status.as_u16() == 404 {
+        return Err(ResolveError::resolution_failed(
+            doi,
+            "DOI is not registered at doi.org",
+        ));
+    }
+    if !status.is_success() {
+        return Err(ResolveError::resolution_failed(
+            doi,
+            &format!(
+                "doi.org content negotiation returned HTTP {}",
+                status.as_u16()
+            ),
+        ));
+    }
+
+    // Agencies without CSL support make doi.org fall through to the landing page.
+    let is_json = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.to_ascii_lowercase().contains("json"));
+    if !is_json {
+        debug!(final_url = %response.url(), "DOI content negotiation returned non-JSON");
+        return Err(ResolveError::resolution_failed(
+            doi,
+            "DOI registration agency does not offer CSL-JSON metadata",
+        ));
+    }
+
+    let item = match response.json::<CslItem>().await {
+        Ok(item) => item,
+        Err(error) => {
+            warn!(error = %error, "Failed to parse CSL-JSON response");
+            return Err(ResolveError::resolution_failed(
+                doi,
+                "Unexpected CSL-JSON response format",
+            ));
+        }
+    };
+
+    let metadata = csl_to_metadata(&item, doi);
+    if !metadata.contains_key("title") && !metadata.contains_key("authors") {
+        return Err(ResolveError::resolution_failed(
+            doi,
+            "CSL-JSON response has no title or authors",
+        ));
+    }
+    Ok(metadata)
+}
+
+/// Percent-encodes each `/`-separated DOI segment, keeping the separators.
+fn encode_doi_path(doi: &str) -> String {
+    doi.split('/')
+        .map(|segment| urlencoding::encode(segment).into_owned())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Maps a CSL item onto the resolver metadata keys.
+fn csl_to_metadata(item: &CslItem, doi: &str) -> HashMap<String, String> {
+    let mut metadata = HashMap::new();
+    metadata.insert("doi".to_string(), doi.to_string());
+
+    if let Some(title) = item.title.as_ref().and_then(CslText::first) {
+        metadata.insert("title".to_string(), title.to_string());
+    }
+
+    let authors: Vec<String> = item
+        .author
+        .iter()
+        .flatten()
+        .filter_map(format_csl_name)
+        .collect();
+    if !authors.is_empty() {
+        metadata.insert("authors".to_string(), authors.join("; "));
+    }
+
+    if let Some(year) = item
+        .issued
+        .as_ref()
+        .and_then(|issued| issued.date_parts.as_ref())
+        .and_then(|parts| parts.first())
+        .and_then(|first| first.first())
+        .and_then(|year| year.as_ref())
+        .and_then(CslScalar::to_text)
+    {
+        metadata.insert("year".to_string(), year);
+    }
+
+    if let Some(journal) = item.container_title.as_ref().and_then(CslText::first) {
+        metadata.insert("journal".to_string(), journal.to_string());
+    }
+    if let Some(volume) = item.volume.as_ref().and_then(CslScalar::to_text) {
+        metadata.insert("volume".to_string(), volume);
+    }
+    if let Some(issue) = item.issue.as_ref().and_then(CslScalar::to_text) {
+        metadata.insert("issue".to_string(), issue);
+    }
+    if let Some(text) = item
+        .abstract_text
+        .as_ref()
+        .and_then(CslText::first)
+        .and_then(plain_text_abstract)
+    {
+        metadata.insert("abstract".to_string(), text);
+    }
+
+    metadata
+}
+
+/// Formats a CSL name as `Family, Given`, matching the Crossref resolver.
+fn format_csl_name(name: &CslName) -> Option<String> {
+    let family = name
+        .family
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty());
+    let given = name
+        .given
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty());
+    match (family, given) {
+        (Some(family), Some(given)) => Some(format!("{family}, {given}")),
+        (Some(family), None) => Some(family.to_string()),
+        (None, Some(given)) => Some(given.to_string()),
+        (None, None) => name
+            .literal
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::resolver::http_client::build_resolver_http_client;
+    use crate::test_support::socket_guard::start_mock_server_or_skip;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    fn test_client() -> Client {
+        build_resolver_http_client("doi.org", "test-agent", None).unwrap()
+    }
+
+    #[test]
+    fn test_csl_to_metadata_maps_standard_fields() {
+        let item: CslItem = serde_json::from_value(serde_json::json!({
+            "title": "Negotiated Paper",
+            "author": [
+                {"family": "Smith", "given": "Jane"},
+                {"literal": "The Consortium"}
+            ],
+            "issued": {"date-parts": [["2019", 4]]},
+            "container-title": ["Journal of Tests"],
+            "volume": 12,
+            "issue": "3",
+            "abstract": "<p>Negotiated findings.</p>"
+        }))
+        .unwrap();
+
+        let meta = csl_to_metadata(&item, "10.1234/csl");
+        assert_eq!(meta["doi"], "10.1234/csl");
+        assert_eq!(meta["title"], "Negotiated Paper");
+        assert_eq!(meta["authors"], "Smith, Jane; The Consortium");
+        assert_eq!(meta["year"], "2019");
+        assert_eq!(meta["journal"], "Journal of Tests");
+        assert_eq!(meta["volume"], "12");
+        assert_eq!(meta["issue"], "3");
+        assert_eq!(meta["abstract"], "Negotiated findings.");
+    }
+
+    #[test]
+    fn test_encode_doi_path_keeps_slashes() {
+        assert_eq!(
+            encode_doi_path("10.1002/(SICI)1097"),
+            "10.1002/%28SICI%291097"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_csl_metadata_follows_negotiation_redirect() {
+        let Some(server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/10.1234/csl"))
+            .and(header("accept", CSL_JSON_MEDIA_TYPE))
+            .respond_with(
+                ResponseTemplate::new(303)
+                    .insert_header("location", format!("{}/agency/10.1234/csl", server.uri())),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/agency/10.1234/csl"))
+            .and(header("accept", CSL_JSON_MEDIA_TYPE))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                r#"{"title": "Negotiated Paper", "author": [{"family": "Smith", "given": "Jane"}],
+                    "issued": {"date-parts": [[2021]]}}"#,
+                CSL_JSON_MEDIA_TYPE,
+            ))
+            .mount(&server)
+            .await;
+
+        let meta = fetch_csl_metadata(
+            &test_client(),
+            &server.uri(),
+            "10.1234/csl",
+            &ResolveContext::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(meta["title"], "Negotiated Paper");
+        assert_eq!(meta["authors"], "Smith, Jane");
+        assert_eq!(meta["year"], "2021");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_csl_metadata_unregistered_doi_fails_cleanly() {
+        let Some(server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/10.1234/missing"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let error = fetch_csl_metadata(
+            &test_client(),
+            &server.uri(),
+            "10.1234/missing",
+            &ResolveContext::default(),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(error.to_string().contains("not registered"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_csl_metadata_rejects_html_landing_page() {
+        let Some(server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/10.1234/html"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw("<html></html>", "text/html"))
+            .mount(&server)
+            .await;
+
+        let error = fetch_csl_metadata(
+            &test_client(),
+            &server.uri(),
+            "10.1234/html",
+            &ResolveContext::default(),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(error.to_string().contains("CSL-JSON"), "{error}");
+    }
+}
@@ -0,0 +1,513 @@
+//! HAL (French open archive) resolver for deposited documents.
+//!
+//! Recognizes `hal.science` / `hal.archives-ouvertes.fr` URLs (including portal
+//! subdomains such as `theses.hal.science` and `halshs.archives-ouvertes.fr`)
+//! and the bare `hal-01234567` IDs the parser emits, with or without a version
+//! suffix (`hal-01234567v2`). The record is looked up through the HAL search
+//! API: its main file becomes the download URL and any other attached files go
+//! to [`ResolvedUrl::fallback_urls`].
+//!
+//! The search index only holds each document's latest version, so an explicit
+//! older version resolves to that version's `/document` link instead of the
+//! indexed files. Notice-only records (no public file) are a soft failure so
+//! the chain moves on.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use async_trait::async_trait;
+use regex::Regex;
+use reqwest::Client;
+use serde::Deserialize;
+use tracing::{debug, warn};
+use url::Url;
+
+use crate::parser::InputType;
+
+use super::http_client::{build_resolver_http_client, send_with_retry, standard_user_agent};
+use super::utils::{canonical_host, compile_static_regex};
+use super::{ResolveContext, ResolveError, ResolveStep, ResolvedUrl, Resolver, ResolverPriority};
+
+// ==================== Constants ====================
+
+const DEFAULT_HAL_API_BASE_URL: &str = "https://api.archives-ouvertes.fr";
+/// Base for per-version `/<id>v<N>/document` links.
+const HAL_DOCUMENT_BASE_URL: &str = "https://hal.science";
+/// Root domains whose subdomains serve HAL portals.
+const HAL_DOMAINS: &[&str] = &["hal.science", "archives-ouvertes.fr"];
+/// `archives-ouvertes.fr` hosts that are not document portals.
+const NON_PORTAL_HOSTS: &[&str] = &["api.archives-ouvertes.fr", "aurehal.archives-ouvertes.fr"];
+/// Fields requested from the search API.
+const SEARCH_FIELDS: &str =
+    "halId_s,version_i,title_s,authFullName_s,producedDateY_i,doiId_s,uri_s,fileMain_s,files_s";
+
+/// A HAL document ID as a whole path segment or input: portal prefix, eight
+/// digits, optional version. Group 1 is the ID, group 2 the version number.
+static HAL_ID_RE: LazyLock<Regex> = LazyLock::new(|| {
+    compile_static_regex(r"(?i)^([a-z][a-z0-9]*(?:-[a-z0-9]+)*-\d{8})(?:v(\d+))?$")
+});
+
+// ==================== API response types ====================
+
+#[derive(Debug, Deserialize)]
+struct HalSearchResponse {
+    response: HalSearchResults,
+}
+
+#[derive(Debug, Deserialize)]
+struct HalSearchResults {
+    #[serde(default)]
+    docs: Vec<HalDocument>,
+}
+
+#[derive(Debug, Deserialize)]
+struct HalDocument {
+    #[serde(default, rename = "version_i")]
+    version: Option<u32>,
+    #[serde(default, rename = "title_s")]
+    titles: Vec<String>,
+    #[serde(default, rename = "authFullName_s")]
+    authors: Vec<String>,
+    #[serde(default, rename = "producedDateY_i")]
+    year: Option<i32>,
+    #[serde(default, rename = "doiId_s")]
+    doi: Option<String>,
+    #[serde(default, rename = "uri_s")]
+    uri: Option<String>,
+    #[serde(default, rename = "fileMain_s")]
+    main_file: Option<String>,
+    #[serde(default, rename = "files_s")]
+    files: Vec<String>,
+}
+
+/// A HAL ID split from its optional version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct HalId {
+    id: String,
+    version: Option<u32>,
+}
+
+// ==================== HalResolver ====================
+
+/// Specialized resolver for HAL open-archive documents.
+pub struct HalResolver {
+    client: Client,
+    api_base_url: String,
+}
+
+impl HalResolver {
+    /// Creates a new `HalResolver` using the production HAL API.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResolveError`] if HTTP client construction fails.
+    pub fn new() -> Result<Self, ResolveError> {
+        Self::build(DEFAULT_HAL_API_BASE_URL.to_string())
+    }
+
+    /// Creates a `HalResolver` with a custom API base URL.
+    ///
+    /// Intended for use in tests with a wiremock server.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResolveError`] if HTTP client construction fails.
+    pub fn with_base_url(api_base_url: impl Into<String>) -> Result<Self, ResolveError> {
+        Self::build(api_base_url.into().trim_end_matches('/').to_string())
+    }
+
+    fn build(api_base_url: String) -> Result<Self, ResolveError> {
+        let user_agent = standard_user_agent("hal");
+        let client = build_resolver_http_client("hal", user_agent, None)?;
+        Ok(Self {
+            client,
+            api_base_url,
+        })
+    }
+
+    async fn fetch_document(
+        &self,
+        hal_id: &HalId,
+        input: &str,
+        ctx: &ResolveContext,
+    ) -> Result<HalDocument, ResolveStep> {
+        let query = format!("halId_s:{}", hal_id.id);
+        let url = format!(
+            "{}/search/?q={}&fl={}&wt=json",
+            self.api_base_url,
+            urlencoding::encode(&query),
+            urlencoding::encode(SEARCH_FIELDS)
+        );
+        ctx.throttle(&url).await;
+        debug!(api_url = %url, hal_id = %hal_id.id, "Calling HAL search API");
+
+        let request = self.client.get(&url);
+        let response = match send_with_retry(request, "hal").await {
+            Ok(resp) => resp,
+            Err(e) => {
+                warn!(error = %e, "HAL API request failed");
+                return Err(ResolveStep::Failed(ResolveError::resolution_failed(
+                    input,
+                    "Cannot reach the HAL API. Check your network connection.",
+                )));
+            }
+        };
+
+        let status = response.status();
+        if !status.is_success() {
+            let reason = match status.as_u16() {
+                429 => "HAL rate limit exceeded. Try again in a few seconds.".to_string(),
+                s if s >= 500 => "HAL API unavailable. Try again later.".to_string(),
+                s => format!("HAL API returned HTTP {s}"),
+            };
+            debug!(status = status.as_u16(), %reason, "HAL API error");
+            return Err(ResolveStep::Failed(ResolveError::resolution_failed(
+                input, &reason,
+            )));
+        }
+
+        let results = response.json::<HalSearchResponse>().await.map_err(|e| {
+            warn!(error = %e, "Failed to parse HAL response");
+            ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                "Unexpected HAL API response format",
+            ))
+        })?;
+        results.response.docs.into_iter().next().ok_or_else(|| {
+            ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                &format!("HAL record {} not found", hal_id.id),
+            ))
+        })
+    }
+}
+
+impl std::fmt::Debug for HalResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HalResolver")
+            .field("api_base_url", &self.api_base_url)
+            .finish_non_exhaustive()
+    }
+}
+
+// ==================== Resolver trait ====================
+
+#[async_trait]
+impl Resolver for HalResolver {
+    fn name(&self) -> &'static str {
+        "hal"
+    }
+
+    fn priority(&self) -> ResolverPriority {
+        ResolverPriority::Specialized
+    }
+
+    fn can_handle(&self, input: &str, input_type: InputType) -> bool {
+        matches!(input_type, InputType::Url | InputType::Unknown) && extract_hal_id(input).is_some()
+    }
+
+    #[tracing::instrument(skip(self, ctx), fields(resolver = "hal", input = %input))]
+    async fn resolve(
+        &self,
+        input: &str,
+        ctx: &ResolveContext,
+    ) -> Result<ResolveStep, ResolveError> {
+        let Some(hal_id) = extract_hal_id(input) else {
+            return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                "Input is not a recognized HAL URL or ID",
+            )));
+        };
+
+        let document = match self.fetch_document(&hal_id, input, ctx).await {
+            Ok(document) => document,
+            Err(step) => return Ok(step),
+        };
+
+        let older_version = hal_id
+            .version
+            .filter(|requested| document.version.is_some_and(|latest| latest != *requested));
+        let mut file_urls = match older_version {
+            Some(version) => vec![format!(
+                "{HAL_DOCUMENT_BASE_URL}/{}v{version}/document",
+                hal_id.id
+            )],
+            None => document_file_urls(&document),
+        };
+        if file_urls.is_empty() {
+            debug!(hal_id = %hal_id.id, "HAL record has no public file");
+            return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                &format!(
+                    "HAL record {} has no public file. \
+                     Why: it is a notice-only deposit or its file is under embargo. \
+                     Fix: check the record page for a publisher link.",
+                    hal_id.id
+                ),
+            )));
+        }
+        let primary = file_urls.remove(0);
+
+        Ok(ResolveStep::Url(
+            ResolvedUrl::with_metadata(primary, document_metadata(&document))
+                .with_fallback_urls(file_urls),
+        ))
+    }
+}
+
+// ==================== Helpers ====================
+
+/// Extracts the HAL ID from a HAL portal URL or a bare ID.
+fn extract_hal_id(input: &str) -> Option<HalId> {
+    let trimmed = input.trim();
+    if let Some(hal_id) = parse_hal_id(trimmed) {
+        return Some(hal_id);
+    }
+
+    let parsed = Url::parse(trimmed).ok()?;
+    let host = canonical_host(parsed.host_str()?);
+    let is_portal = HAL_DOMAINS
+        .iter()
+        .any(|domain| host == *domain || host.ends_with(&format!(".{domain}")))
+        && !NON_PORTAL_HOSTS.contains(&host.as_str());
+    if !is_portal {
+        return None;
+    }
+    parsed
+        .path_segments()?
+        .find(|segment| !segment.is_empty())
+        .and_then(parse_hal_id)
+}
+
+fn parse_hal_id(candidate: &str) -> Option<HalId> {
+    let caps = HAL_ID_RE.captures(candidate)?;
+    Some(HalId {
+        id: caps.get(1)?.as_str().to_ascii_lowercase(),
+        version: caps
+            .get(2)
+            .and_then(|version| version.as_str().parse().ok()),
+    })
+}
+
+/// The main file first, then the other attached files, without duplicates.
+fn document_file_urls(document: &HalDocument) -> Vec<String> {
+    let mut urls: Vec<String> = Vec::new();
+    for url in document.main_file.iter().chain(&document.files) {
+        let url = url.trim();
+        if !url.is_empty() && !urls.iter().any(|seen| seen == url) {
+            urls.push(url.to_string());
+        }
+    }
+    urls
+}
+
+fn document_metadata(document: &HalDocument) -> HashMap<String, String> {
+    let mut metadata = HashMap::new();
+
+    if let Some(title) = document.titles.first().map(|title| title.trim())
+        && !title.is_empty()
+    {
+        metadata.insert("title".to_string(), title.to_string());
+    }
+    let authors: Vec<&str> = document
+        .authors
+        .iter()
+        .map(|name| name.trim())
+        .filter(|name| !name.is_empty())
+        .collect();
+    if !authors.is_empty() {
+        metadata.insert("authors".to_string(), authors.join("; "));
+    }
+    if let Some(year) = document.year {
+        metadata.insert("year".to_string(), year.to_string());
+    }
+    if let Some(doi) = document.doi.as_deref().map(str::trim)
+        && doi.starts_with("10.")
+    {
+        metadata.insert("doi".to_string(), doi.to_string());
+    }
+    if let Some(uri) = document.uri.as_deref() {
+        metadata.insert("source_url".to_string(), uri.to_string());
+    }
+
+    metadata
+}
+
+// ==================== Tests ====================
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::test_support::socket_guard::start_mock_server_or_skip;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    fn hal_id(id: &str, version: Option<u32>) -> HalId {
+        HalId {
+            id: id.to_string(),
+            version,
+        }
+    }
+
+    #[test]
+    fn test_extract_hal_id_from_supported_inputs() {
+        for input in [
+            "hal-01234567",
+            "HAL-01234567",
+            "https://hal.science/hal-01234567",
+            "https://hal.science/hal-01234567/document",
+            "https://hal.archives-ouvertes.fr/hal-01234567/file/paper.pdf",
+        ] {
+            assert_eq!(
+                extract_hal_id(input),
+                Some(hal_id("hal-01234567", None)),
+                "{input}"
+            );
+        }
+        assert_eq!(
+            extract_hal_id("https://hal.science/hal-01234567v2/document"),
+            Some(hal_id("hal-01234567", Some(2)))
+        );
+        assert_eq!(
+            extract_hal_id("https://theses.hal.science/tel-00012345v1"),
+            Some(hal_id("tel-00012345", Some(1)))
+        );
+        assert_eq!(
+            extract_hal_id("https://halshs.archives-ouvertes.fr/halshs-00012345"),
+            Some(hal_id("halshs-00012345", None))
+        );
+    }
+
+    #[test]
+    fn test_extract_hal_id_rejects_other_inputs() {
+        for input in [
+            "hal-123",
+            "https://hal.science/",
+            "https://hal.science/search/index",
+            "https://api.archives-ouvertes.fr/hal-01234567",
+            "https://example.com/hal-01234567",
+            "10.1000/hal-01234567",
+        ] {
+            assert!(extract_hal_id(input).is_none(), "{input}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_returns_main_file_with_fallbacks_and_metadata() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/search/"))
+            .and(query_param("q", "halId_s:hal-01234567"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "response": {"numFound": 1, "docs": [{
+                    "version_i": 2,
+                    "title_s": ["Apprentissage profond"],
+                    "authFullName_s": ["Marie Curie", "Pierre Curie"],
+                    "producedDateY_i": 2021,
+                    "doiId_s": "10.1000/xyz123",
+                    "uri_s": "https://hal.science/hal-01234567v2",
+                    "fileMain_s": "https://hal.science/hal-01234567/document",
+                    "files_s": [
+                        "https://hal.science/hal-01234567/document",
+                        "https://hal.science/hal-01234567/file/supplement.pdf"
+                    ]
+                }]}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = HalResolver::with_base_url(mock_server.uri()).unwrap();
+        let step = resolver
+            .resolve(
+                "https://hal.science/hal-01234567v2",
+                &ResolveContext::default(),
+            )
+            .await
+            .unwrap();
+
+        let ResolveStep::Url(result) = step else {
+            panic!("expected ResolveStep::Url, got {step:?}");
+        };
+        assert_eq!(result.url, "https://hal.science/hal-01234567/document");
+        assert_eq!(
+            result.fallback_urls,
+            vec!["https://hal.science/hal-01234567/file/supplement.pdf"]
+        );
+        assert_eq!(result.metadata["title"], "Apprentissage profond");
+        assert_eq!(result.metadata["authors"], "Marie Curie; Pierre Curie");
+        assert_eq!(result.metadata["year"], "2021");
+        assert_eq!(result.metadata["doi"], "10.1000/xyz123");
+        assert_eq!(
+            result.metadata["source_url"],
+            "https://hal.science/hal-01234567v2"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_older_version_uses_versioned_document_link() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/search/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "response": {"numFound": 1, "docs": [{
+                    "version_i": 3,
+                    "title_s": ["Latest title"],
+                    "fileMain_s": "https://hal.science/hal-01234567/document"
+                }]}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = HalResolver::with_base_url(mock_server.uri()).unwrap();
+        let step = resolver
+            .resolve("hal-01234567v1", &ResolveContext::default())
+            .await
+            .unwrap();
+
+        let ResolveStep::Url(result) = step else {
+            panic!("expected ResolveStep::Url, got {step:?}");
+        };
+        assert_eq!(result.url, "https://hal.science/hal-01234567v1/document");
+        assert!(result.fallback_urls.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_notice_only_and_missing_records_fail_softly() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/search/"))
+            .and(query_param("q", "halId_s:hal-00000001"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "response": {"numFound": 1, "docs": [{"title_s": ["Notice only"]}]}
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/search/"))
+            .and(query_param("q", "halId_s:hal-00000002"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "response": {"numFound": 0, "docs": []}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = HalResolver::with_base_url(mock_server.uri()).unwrap();
+        for input in ["hal-00000001", "hal-00000002"] {
+            let step = resolver
+                .resolve(input, &ResolveContext::default())
+                .await
+                .unwrap();
+            assert!(
+                matches!(step, ResolveStep::Failed(_)),
+                "expected soft failure for {input}, got {step:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,450 @@
+//! Handle System and PURL resolver for persistent-identifier links.
+//!
+//! Recognizes `hdl:<handle>` identifiers, `hdl.handle.net/<handle>` proxy
+//! links, and `purl.org` PURLs. Both services answer with an HTTP redirect to
+//! the registered target, so the chain is walked hop by hop (bounded by
+//! [`ResolveContext::max_redirects`]) and the target is handed back to the
+//! registry as a [`ResolveStep::Redirect`] for site-specific resolvers. A
+//! chain that lands on a login host yields [`ResolveStep::NeedsAuth`].
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use reqwest::Client;
+use reqwest::cookie::Jar;
+use reqwest::header::{ACCEPT, LOCATION};
+use tracing::{debug, warn};
+use url::Url;
+
+use crate::parser::InputType;
+
+use super::http_client::{build_non_redirecting_resolver_http_client, standard_user_agent};
+use super::utils::{auth_requirement, canonical_host, hosts_match, is_auth_required_status};
+use super::{
+    AuthRequirement, ResolveContext, ResolveError, ResolveStep, Resolver, ResolverPriority,
+};
+
+// ==================== Constants ====================
+
+const DEFAULT_HANDLE_PROXY_URL: &str = "https://hdl.handle.net";
+const HDL_PREFIX: &str = "hdl:";
+const PURL_HOSTS: &[&str] = &["purl.org", "purl.oclc.org"];
+
+/// Host labels that identify a sign-in service (`login.example.edu`, `idp.example.org`).
+const LOGIN_HOST_LABELS: &[&str] = &["login", "signin", "sso", "idp", "shibboleth", "auth"];
+
+/// Path segments that identify a sign-in or institutional-access page.
+const LOGIN_PATH_SEGMENTS: &[&str] = &[
+    "login",
+    "signin",
+    "sign-in",
+    "sso",
+    "shibboleth.sso",
+    "wayf",
+    "saml",
+    "idp",
+];
+
+// ==================== HandleResolver ====================
+
+/// Specialized resolver for Handle System identifiers and PURLs.
+pub struct HandleResolver {
+    client: Client,
+    proxy_url: String,
+    proxy_host: String,
+}
+
+impl HandleResolver {
+    /// Creates a new `HandleResolver` using the public `hdl.handle.net` proxy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResolveError`] if HTTP client construction fails.
+    pub fn new(cookie_jar: Option<Arc<Jar>>) -> Result<Self, ResolveError> {
+        Self::with_proxy_url(cookie_jar, DEFAULT_HANDLE_PROXY_URL)
+    }
+
+    /// Creates a `HandleResolver` with a custom Handle System proxy URL.
+    ///
+    /// Intended for use in tests with a wiremock server.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResolveError`] if HTTP client construction fails.
+    pub fn with_proxy_url(
+        cookie_jar: Option<Arc<Jar>>,
+        proxy_url: impl Into<String>,
+    ) -> Result<Self, ResolveError> {
+        let proxy_url = proxy_url.into().trim_end_matches('/').to_string();
+        let client = build_non_redirecting_resolver_http_client(
+            "handle",
+            standard_user_agent("handle"),
+            cookie_jar,
+        )?;
+        Ok(Self {
+            client,
+            proxy_host: Url::parse(&proxy_url)
+                .ok()
+                .and_then(|url| url.host_str().map(canonical_host))
+                .unwrap_or_default(),
+            proxy_url,
+        })
+    }
+
+    /// Returns the URL to start the redirect walk from, or `None` for other inputs.
+    fn start_url(&self, input: &str) -> Option<Url> {
+        let trimmed = input.trim();
+        if let Some(handle) = strip_hdl_prefix(trimmed) {
+            return Url::parse(&format!("{}/{handle}", self.proxy_url)).ok();
+        }
+
+        let parsed = Url::parse(trimmed).ok()?;
+        let host = canonical_host(parsed.host_str()?);
+        let is_proxy = hosts_match(&host, &self.proxy_host);
+        let is_purl = PURL_HOSTS.contains(&host.as_str());
+        let has_identifier = !parsed.path().trim_matches('/').is_empty();
+        ((is_proxy || is_purl) && has_identifier).then_some(parsed)
+    }
+}
+
+impl std::fmt::Debug for HandleResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HandleResolver")
+            .field("proxy_url", &self.proxy_url)
+            .finish_non_exhaustive()
+    }
+}
+
+// ==================== Resolver trait ====================
+
+#[async_trait]
+impl Resolver for HandleResolver {
+    fn name(&self) -> &'static str {
+        "handle"
+    }
+
+    fn priority(&self) -> ResolverPriority {
+        ResolverPriority::Specialized
+    }
+
+    fn can_handle(&self, input: &str, input_type: InputType) -> bool {
+        match input_type {
+            InputType::Unknown => strip_hdl_prefix(input.trim()).is_some(),
+            InputType::Url => self.start_url(input).is_some(),
+            _ => false,
+        }
+    }
+
+    #[tracing::instrument(skip(self, ctx), fields(resolver = "handle", input = %input))]
+    async fn resolve(
+        &self,
+        input: &str,
+        ctx: &ResolveContext,
+    ) -> Result<ResolveStep, ResolveError> {
+        let Some(mut current) = self.start_url(input) else {
+            return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                "Input is not a recognized handle or PURL",
+            )));
+        };
+
+        let mut hops: usize = 0;
+        loop {
+            ctx.throttle(current.as_str()).await;
+            debug!(url = %current, hops, "Following persistent-identifier redirect");
+
+            let response = match self
+                .client
+                .get(current.clone())
+                .header(
+                    ACCEPT,
+                    "text/html,application/xhtml+xml,application/pdf;q=0.9,*/*;q=0.8",
+                )
+                .send()
+                .await
+            {
+                Ok(resp) => resp,
+                Err(error) => {
+                    warn!(error = %error, url = %current, "Handle/PURL request failed");
+                    return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                        input,
+                        "Cannot reach the handle or PURL target. Check your network connection.",
+                    )));
+                }
+            };
+
+            let status = response.status();
+            let host = current.host_str().unwrap_or_default().to_string();
+
+            if status.is_redirection() {
+                let Some(next) = redirect_target(&response, &current) else {
+                    return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                        input,
+                        &format!("{host} returned a redirect without a usable Location header"),
+                    )));
+                };
+                hops += 1;
+                if hops > ctx.max_redirects {
+                    return Ok(ResolveStep::Failed(ResolveError::too_many_redirects(
+                        input, hops,
+                    )));
+                }
+                if is_login_url(&next) {
+                    return Ok(ResolveStep::NeedsAuth(login_requirement(&next)));
+                }
+                current = next;
+                continue;
+            }
+
+            if is_auth_required_status(status.as_u16()) {
+                return Ok(ResolveStep::NeedsAuth(auth_requirement(
+                    &host,
+                    &self.proxy_host,
+                    format!(
+                        "{host} returned HTTP {} for the handle target. Refresh cookies with `downloader auth capture --save-cookies` and retry.",
+                        status.as_u16()
+                    ),
+                )));
+            }
+
+            if !status.is_success() {
+                let reason = match (hops, status.as_u16()) {
+                    (0, 404) => "Handle or PURL is not registered".to_string(),
+                    (_, s) => format!("{host} returned HTTP {s}"),
+                };
+                debug!(status = status.as_u16(), %reason, "Handle/PURL resolution error");
+                return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                    input, &reason,
+                )));
+            }
+
+            if hops == 0 {
+                return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                    input,
+                    "Handle proxy answered without redirecting to a target URL",
+                )));
+            }
+            if is_login_url(&current) {
+                return Ok(ResolveStep::NeedsAuth(login_requirement(&current)));
+            }
+
+            debug!(target = %current, hops, "Handle/PURL resolved to target");
+            return Ok(ResolveStep::Redirect(current.to_string()));
+        }
+    }
+}
+
+// ==================== Helpers ====================
+
+/// Returns the handle from an `hdl:`-prefixed identifier.
+fn strip_hdl_prefix(value: &str) -> Option<&str> {
+    let prefix = value.get(..HDL_PREFIX.len())?;
+    if !prefix.eq_ignore_ascii_case(HDL_PREFIX) {
+        return None;
+    }
+    let handle = value[HDL_PREFIX.len()..].trim();
+    handle
+        .split_once('/')
+        .is_some_and(|(authority, local)| !authority.is_empty() && !local.is_empty())
+        .then_some(handle)
+}
+
+fn redirect_target(response: &reqwest::Response, current: &Url) -> Option<Url> {
+    let location = response.headers().get(LOCATION)?.to_str().ok()?;
+    current.join(location.trim()).ok()
+}
+
+/// Returns true when `url` points at a sign-in service rather than content.
+fn is_login_url(url: &Url) -> bool {
+    let host_is_login = url
+        .host_str()
+        .and_then(|host| host.split('.').next())
+        .is_some_and(|label| {
+            LOGIN_HOST_LABELS
+                .iter()
+                .any(|marker| label.eq_ignore_ascii_case(marker))
+        });
+    if host_is_login {
+        return true;
+    }
+
+    url.path_segments().is_some_and(|mut segments| {
+        segments.any(|segment| {
+            LOGIN_PATH_SEGMENTS
+                .iter()
+                .any(|marker| segment.eq_ignore_ascii_case(marker))
+        })
+    })
+}
+
+fn login_requirement(login_url: &Url) -> AuthRequirement {
+    let host = login_url.host_str().unwrap_or_default();
+    auth_requirement(
+        host,
+        "hdl.handle.net",
+        format!(
+            "Handle target redirected to the sign-in page at {host}. Refresh cookies with `downloader auth capture --save-cookies` and retry."
+        ),
+    )
+}
+
+// ==================== Tests ====================
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::test_support::socket_guard::start_mock_server_or_skip;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    fn redirect_to(location: &str) -> ResponseTemplate {
+        ResponseTemplate::new(302).insert_header("Location", location)
+    }
+
+    #[test]
+    fn test_can_handle_hdl_prefix_proxy_links_and_purls() {
+        let resolver = HandleResolver::new(None).unwrap();
+        assert!(resolver.can_handle("hdl:2027/mdp.39015012345678", InputType::Unknown));
+        assert!(resolver.can_handle("HDL:1721.1/12345", InputType::Unknown));
+        assert!(resolver.can_handle("https://hdl.handle.net/1721.1/12345", InputType::Url));
+        assert!(resolver.can_handle("http://purl.org/dc/terms/", InputType::Url));
+        assert!(resolver.can_handle("https://purl.oclc.org/NET/example", InputType::Url));
+    }
+
+    #[test]
+    fn test_can_handle_rejects_other_inputs() {
+        let resolver = HandleResolver::new(None).unwrap();
+        assert!(!resolver.can_handle("hdl:12345", InputType::Unknown));
+        assert!(!resolver.can_handle("PMID:12345678", InputType::Unknown));
+        assert!(!resolver.can_handle("https://hdl.handle.net/", InputType::Url));
+        assert!(!resolver.can_handle("https://www.handle.net/faq", InputType::Url));
+        assert!(!resolver.can_handle("https://example.com/1721.1/12345", InputType::Url));
+        assert!(!resolver.can_handle("hdl:1721.1/12345", InputType::Doi));
+    }
+
+    #[test]
+    fn test_is_login_url_detects_login_hosts_and_paths() {
+        for url in [
+            "https://login.library.example.edu/?url=https://publisher.example.com",
+            "https://idp.example.org/profile/SAML2/Redirect/SSO",
+            "https://publisher.example.com/action/ssostart/login?redirect=x",
+            "https://repository.example.edu/Shibboleth.sso/Login",
+        ] {
+            assert!(is_login_url(&Url::parse(url).unwrap()), "{url}");
+        }
+        for url in [
+            "https://repository.example.edu/handle/1721.1/12345",
+            "https://publisher.example.com/articles/login-free-access.pdf",
+        ] {
+            assert!(!is_login_url(&Url::parse(url).unwrap()), "{url}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_hdl_follows_proxy_redirect_to_target() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        let target = format!("{}/bitstream/1721.1/12345/paper.pdf", mock_server.uri());
+        Mock::given(method("GET"))
+            .and(path("/1721.1/12345"))
+            .respond_with(redirect_to(&target))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/bitstream/1721.1/12345/paper.pdf"))
+            .respond_with(
+                ResponseTemplate::new(200).insert_header("Content-Type", "application/pdf"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let resolver = HandleResolver::with_proxy_url(None, mock_server.uri()).unwrap();
+        let step = resolver
+            .resolve("hdl:1721.1/12345", &ResolveContext::default())
+            .await
+            .unwrap();
+
+        let ResolveStep::Redirect(url) = step else {
+            panic!("expected ResolveStep::Redirect, got {step:?}");
+        };
+        assert_eq!(url, target);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_redirect_to_login_host_needs_auth() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/2027/mdp.1"))
+            .respond_with(redirect_to("/landing/mdp.1"))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/landing/mdp.1"))
+            .respond_with(redirect_to("/login?target=/landing/mdp.1"))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = HandleResolver::with_proxy_url(None, mock_server.uri()).unwrap();
+        let step = resolver
+            .resolve("hdl:2027/mdp.1", &ResolveContext::default())
+            .await
+            .unwrap();
+
+        assert!(
+            matches!(step, ResolveStep::NeedsAuth(_)),
+            "expected NeedsAuth, got {step:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_stops_after_max_redirects() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/1721.1/loop"))
+            .respond_with(redirect_to("/1721.1/loop"))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = HandleResolver::with_proxy_url(None, mock_server.uri()).unwrap();
+        let mut ctx = ResolveContext::default();
+        ctx.max_redirects = 2;
+        let step = resolver.resolve("hdl:1721.1/loop", &ctx).await.unwrap();
+
+        let ResolveStep::Failed(error) = step else {
+            panic!("expected ResolveStep::Failed, got {step:?}");
+        };
+        assert!(matches!(
+            error,
+            ResolveError::TooManyRedirects { count: 3, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_unregistered_handle_fails_softly() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/1721.1/missing"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = HandleResolver::with_proxy_url(None, mock_server.uri()).unwrap();
+        let step = resolver
+            .resolve("hdl:1721.1/missing", &ResolveContext::default())
+            .await
+            .unwrap();
+
+        assert!(
+            matches!(step, ResolveStep::Failed(_)),
+            "expected soft failure, got {step:?}"
+        );
+    }
+}
@@ -4,16 +4,20 @@
 //! consistent on timeout, user-agent, compression, proxy compatibility, and
 //! cookie support.
 
+use std::collections::BTreeMap;
 use std::panic::{AssertUnwindSafe, catch_unwind};
 use std::sync::Arc;
 use std::sync::RwLock;
 use std::time::Duration;
 
-use reqwest::Client;
 use reqwest::cookie::Jar;
+use reqwest::header::RETRY_AFTER;
+use reqwest::redirect::Policy;
+use reqwest::{Client, RequestBuilder, Response};
 use reqwest::{ClientBuilder, Proxy};
-use tracing::warn;
+use tracing::{debug, warn};
 
+use crate::download::{max_redirects, parse_retry_after, redirect_policy};
 use crate::user_agent;
 
 use super::ResolveError;
@@ -21,6 +25,12 @@ use super::ResolveError;
 const CONNECT_TIMEOUT_SECS: u64 = 10;
 const READ_TIMEOUT_SECS: u64 = 30;
 
+/// Retries allowed after the first attempt of an idempotent resolver GET.
+const RESOLVER_MAX_RETRIES: u32 = 2;
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(250);
+/// Upper bound on any single back-off, including server-sent `Retry-After`.
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(2);
+
 #[derive(Debug, Clone, Copy)]
 struct ResolverHttpTimeouts {
     connect_timeout_secs: u64,
@@ -53,6 +63,31 @@ pub fn configure_resolver_http_timeouts(connect_timeout_secs: u64, read_timeout_
     }
 }
 
+/// Per-resolver timeout override, keyed by [`Resolver::name`](super::Resolver::name).
+///
+/// Unset fields fall back to the global values from
+/// [`configure_resolver_http_timeouts`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResolverTimeoutOverride {
+    /// Connect timeout in seconds for this resolver's client.
+    pub connect_secs: Option<u64>,
+    /// Read timeout in seconds for each request this resolver makes.
+    pub read_secs: Option<u64>,
+}
+
+static RESOLVER_TIMEOUT_OVERRIDES: RwLock<BTreeMap<String, ResolverTimeoutOverride>> =
+    RwLock::new(BTreeMap::new());
+
+/// Replaces the per-resolver timeout overrides.
+///
+/// Like [`configure_resolver_http_timeouts`], this must run before resolvers
+/// are constructed; the registry also reads it to bound each resolver call.
+pub fn configure_resolver_timeout_overrides(overrides: BTreeMap<String, ResolverTimeoutOverride>) {
+    if let Ok(mut guard) = RESOLVER_TIMEOUT_OVERRIDES.write() {
+        *guard = overrides;
+    }
+}
+
 fn resolver_http_timeouts() -> ResolverHttpTimeouts {
     RESOLVER_HTTP_TIMEOUTS
         .read()
@@ -60,6 +95,35 @@ fn resolver_http_timeouts() -> ResolverHttpTimeouts {
         .unwrap_or_default()
 }
 
+fn resolver_timeout_override(resolver_name: &str) -> Option<ResolverTimeoutOverride> {
+    RESOLVER_TIMEOUT_OVERRIDES
+        .read()
+        .ok()
+        .and_then(|guard| guard.get(resolver_name).copied())
+}
+
+fn effective_timeouts(resolver_name: &str) -> ResolverHttpTimeouts {
+    let global = resolver_http_timeouts();
+    let Some(over) = resolver_timeout_override(resolver_name) else {
+        return global;
+    };
+    ResolverHttpTimeouts {
+        connect_timeout_secs: over.connect_secs.unwrap_or(global.connect_timeout_secs),
+        read_timeout_secs: over.read_secs.unwrap_or(global.read_timeout_secs),
+    }
+}
+
+/// Returns the wall-clock budget for one `resolve` call of `resolver_name`,
+/// or `None` when no read-timeout override is configured for it.
+///
+/// The budget covers every attempt the retry policy may make, so a resolver
+/// is only cut off once it could no longer succeed within its own limits.
+pub(crate) fn resolver_call_budget(resolver_name: &str) -> Option<Duration> {
+    let read_secs = resolver_timeout_override(resolver_name)?.read_secs?;
+    let attempts = RESOLVER_MAX_RETRIES + 1;
+    Some(Duration::from_secs(read_secs) * attempts + RETRY_MAX_DELAY * RESOLVER_MAX_RETRIES)
+}
+
 /// Builds a single shared resolver user-agent string (no per-resolver name in header).
 ///
 /// Use this for all resolvers so traffic is not trivially fingerprintable per site.
@@ -71,6 +135,10 @@ pub fn standard_user_agent(_resolver_name: &str) -> String {
 
 /// Builds a resolver HTTP client using shared project policy.
 ///
+/// Redirects are followed up to the configured
+/// [`max_redirects`](crate::download::max_redirects), the same limit
+/// [`ResolveContext::new`](super::ResolveContext::new) starts from.
+///
 /// `resolver_name` is used only for error messages and logging (e.g. proxy panic
 /// warning), not in the User-Agent header.
 ///
@@ -82,9 +150,40 @@ pub fn build_resolver_http_client(
     user_agent: impl Into<String>,
     cookie_jar: Option<Arc<Jar>>,
 ) -> Result<Client, ResolveError> {
-    let user_agent = user_agent.into();
+    build_client_with_redirects(resolver_name, &user_agent.into(), cookie_jar, true)
+}
+
+/// Builds a resolver HTTP client that returns 3xx responses instead of following them.
+///
+/// For resolvers that walk a redirect chain hop by hop, so they can apply
+/// `ResolveContext::max_redirects` and inspect every intermediate host.
+///
+/// # Errors
+///
+/// Returns [`ResolveError`] when client construction fails.
+pub fn build_non_redirecting_resolver_http_client(
+    resolver_name: &str,
+    user_agent: impl Into<String>,
+    cookie_jar: Option<Arc<Jar>>,
+) -> Result<Client, ResolveError> {
+    build_client_with_redirects(resolver_name, &user_agent.into(), cookie_jar, false)
+}
 
-    let initial = try_build_client(&user_agent, cookie_jar.clone(), false);
+fn build_client_with_redirects(
+    resolver_name: &str,
+    user_agent: &str,
+    cookie_jar: Option<Arc<Jar>>,
+    follow_redirects: bool,
+) -> Result<Client, ResolveError> {
+    let timeouts = effective_timeouts(resolver_name);
+
+    let initial = try_build_client(
+        user_agent,
+        cookie_jar.clone(),
+        timeouts,
+        follow_redirects,
+        false,
+    );
     match initial {
         Ok(client) => Ok(client),
         Err(BuildClientFailure::Panic) => {
@@ -95,7 +194,7 @@ pub fn build_resolver_http_client(
                 resolver = resolver_name,
                 "Resolver client hit system proxy panic; using env-proxy fallback builder"
             );
-            match try_build_client(&user_agent, cookie_jar, true) {
+            match try_build_client(user_agent, cookie_jar, timeouts, follow_redirects, true) {
                 Ok(client) => Ok(client),
                 Err(BuildClientFailure::Panic) => Err(ResolveError::resolution_failed(
                     resolver_name,
@@ -122,11 +221,16 @@ enum BuildClientFailure {
 fn try_build_client(
     user_agent: &str,
     cookie_jar: Option<Arc<Jar>>,
+    timeouts: ResolverHttpTimeouts,
+    follow_redirects: bool,
     disable_system_proxy_lookup: bool,
 ) -> Result<Client, BuildClientFailure> {
     let user_agent = user_agent.to_string();
     catch_unwind(AssertUnwindSafe(move || {
-        let mut builder = base_builder(user_agent, cookie_jar);
+        let mut builder = base_builder(user_agent, cookie_jar, timeouts);
+        if !follow_redirects {
+            builder = builder.redirect(Policy::none());
+        }
         if disable_system_proxy_lookup {
             builder = apply_env_proxy_fallback(builder.no_proxy());
         }
@@ -135,13 +239,19 @@ fn try_build_client(
     .map_err(|_| BuildClientFailure::Panic)?
 }
 
-fn base_builder(user_agent: String, cookie_jar: Option<Arc<Jar>>) -> ClientBuilder {
-    let timeouts = resolver_http_timeouts();
+fn base_builder(
+    user_agent: String,
+    cookie_jar: Option<Arc<Jar>>,
+    timeouts: ResolverHttpTimeouts,
+) -> ClientBuilder {
     let mut builder = Client::builder()
         .connect_timeout(Duration::from_secs(timeouts.connect_timeout_secs))
         .timeout(Duration::from_secs(timeouts.read_timeout_secs))
         .user_agent(user_agent)
-        .gzip(true);
+        .gzip(true)
+        .deflate(true)
+        .brotli(true)
+        .redirect(redirect_policy(max_redirects()));
 
     if let Some(jar) = cookie_jar {
         builder = builder.cookie_provider(jar);
@@ -150,6 +260,62 @@ fn base_builder(user_agent: String, cookie_jar: Option<Arc<Jar>>) -> ClientBuild
     builder
 }
 
+/// Sends an idempotent resolver request, retrying 429/5xx responses and
+/// timeout/connect errors up to [`RESOLVER_MAX_RETRIES`] times.
+///
+/// Back-off doubles from 250ms, or follows `Retry-After`, capped at 2s so a
+/// throttled API cannot stall the batch. The last response or error is
+/// returned unchanged, so callers keep mapping exhausted retries to
+/// [`ResolveStep::Failed`](super::ResolveStep::Failed).
+///
+/// # Errors
+///
+/// Returns the transport error of the final attempt.
+pub(crate) async fn send_with_retry(
+    request: RequestBuilder,
+    resolver_name: &str,
+) -> Result<Response, reqwest::Error> {
+    let mut attempt: u32 = 0;
+    loop {
+        let Some(retry_request) = request.try_clone() else {
+            return request.send().await;
+        };
+        let outcome = retry_request.send().await;
+        if attempt >= RESOLVER_MAX_RETRIES {
+            return outcome;
+        }
+
+        let delay = match &outcome {
+            Ok(response) if is_retryable_status(response.status().as_u16()) => response
+                .headers()
+                .get(RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(parse_retry_after)
+                .unwrap_or_else(|| backoff_delay(attempt)),
+            Err(error) if error.is_timeout() || error.is_connect() => backoff_delay(attempt),
+            _ => return outcome,
+        }
+        .min(RETRY_MAX_DELAY);
+
+        attempt += 1;
+        debug!(
+            resolver = resolver_name,
+            attempt,
+            delay_ms = delay.as_millis(),
+            "Retrying resolver request"
+        );
+        tokio::time::sleep(delay).await;
+    }
+}
+
+fn is_retryable_status(status: u16) -> bool {
+    status == 429 || (500..600).contains(&status)
+}
+
+fn backoff_delay(attempt: u32) -> Duration {
+    RETRY_BASE_DELAY.saturating_mul(1 << attempt.min(8))
+}
+
 fn apply_env_proxy_fallback(mut builder: ClientBuilder) -> ClientBuilder {
     if let Some(proxy) = env_proxy_for_scheme("https")
         && let Ok(resolved) = Proxy::https(&proxy)
@@ -192,14 +358,21 @@ mod tests {
     const NON_CROSSREF_RESOLVER_NAMES: &[&str] = &[
         "acm",
         "arxiv",
+        "hal",
+        "handle",
         "pubmed",
         "ieee",
         "mdpi",
+        "openalex",
+        "osf",
         "oxford",
         "springer",
         "sciencedirect",
+        "semantic_scholar",
+        "unpaywall",
         "wiley",
         "youtube",
+        "zenodo",
     ];
 
     #[test]
@@ -223,4 +396,47 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_is_retryable_status_covers_429_and_5xx_only() {
+        assert!(is_retryable_status(429));
+        assert!(is_retryable_status(500));
+        assert!(is_retryable_status(503));
+        assert!(!is_retryable_status(404));
+        assert!(!is_retryable_status(200));
+    }
+
+    #[test]
+    fn test_backoff_delay_doubles_per_attempt() {
+        assert_eq!(backoff_delay(0), Duration::from_millis(250));
+        assert_eq!(backoff_delay(1), Duration::from_millis(500));
+        assert_eq!(backoff_delay(2), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_timeout_override_applies_only_to_named_resolver() {
+        let mut overrides = BTreeMap::new();
+        overrides.insert(
+            "override-test".to_string(),
+            ResolverTimeoutOverride {
+                connect_secs: None,
+                read_secs: Some(5),
+            },
+        );
+        configure_resolver_timeout_overrides(overrides);
+
+        let global = resolver_http_timeouts();
+        let tuned = effective_timeouts("override-test");
+        assert_eq!(tuned.read_timeout_secs, 5);
+        assert_eq!(tuned.connect_timeout_secs, global.connect_timeout_secs);
+        assert_eq!(
+            effective_timeouts("other").read_timeout_secs,
+            global.read_timeout_secs
+        );
+        assert_eq!(
+            resolver_call_budget("override-test"),
+            Some(Duration::from_secs(19))
+        );
+        assert_eq!(resolver_call_budget("other"), None);
+    }
 }
@@ -0,0 +1,249 @@
+//! JSTOR resolver for `stable/<id>` links and `10.2307/*` DOIs.
+//!
+//! JSTOR PDFs almost always sit behind institutional access, and an
+//! unauthenticated request lands on a sign-in page rather than failing with
+//! a clear status. The resolver therefore checks the cookie jar for a
+//! `jstor.org` session first and asks for `downloader auth capture` without
+//! contacting JSTOR. With cookies present it maps the input straight to the
+//! `stable/pdf/<id>.pdf` download endpoint.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use reqwest::cookie::{CookieStore, Jar};
+use url::Url;
+
+use crate::parser::InputType;
+
+use super::utils::{canonical_host, looks_like_doi};
+use super::{
+    AuthRequirement, ResolveContext, ResolveError, ResolveStep, ResolvedUrl, Resolver,
+    ResolverPriority,
+};
+
+const JSTOR_BASE_URL: &str = "https://www.jstor.org";
+const JSTOR_HOST: &str = "jstor.org";
+const DOI_HOST: &str = "doi.org";
+const JSTOR_DOI_PREFIX: &str = "10.2307/";
+
+/// Specialized resolver for JSTOR stable URLs and DOIs.
+pub struct JstorResolver {
+    cookie_jar: Option<Arc<Jar>>,
+    base_url: String,
+}
+
+impl JstorResolver {
+    /// Creates a resolver that checks `cookie_jar` for a JSTOR session.
+    #[must_use]
+    pub fn new(cookie_jar: Option<Arc<Jar>>) -> Self {
+        Self::with_base_url(cookie_jar, JSTOR_BASE_URL)
+    }
+
+    /// Creates a resolver with a custom JSTOR base URL (for tests).
+    #[must_use]
+    pub fn with_base_url(cookie_jar: Option<Arc<Jar>>, base_url: impl Into<String>) -> Self {
+        Self {
+            cookie_jar,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Returns true when the jar holds any cookie sent to the JSTOR base URL.
+    fn has_session_cookie(&self) -> bool {
+        let Ok(base_url) = Url::parse(&self.base_url) else {
+            return false;
+        };
+        self.cookie_jar
+            .as_ref()
+            .and_then(|jar| jar.cookies(&base_url))
+            .is_some_and(|header| !header.is_empty())
+    }
+}
+
+impl std::fmt::Debug for JstorResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JstorResolver")
+            .field("base_url", &self.base_url)
+            .field("has_cookie_jar", &self.cookie_jar.is_some())
+            .finish()
+    }
+}
+
+#[async_trait]
+impl Resolver for JstorResolver {
+    fn name(&self) -> &'static str {
+        "jstor"
+    }
+
+    fn priority(&self) -> ResolverPriority {
+        ResolverPriority::Specialized
+    }
+
+    fn can_handle(&self, input: &str, input_type: InputType) -> bool {
+        extract_stable_id(input, input_type).is_some()
+    }
+
+    #[tracing::instrument(skip(self, _ctx), fields(resolver = "jstor", input = %input))]
+    async fn resolve(
+        &self,
+        input: &str,
+        _ctx: &ResolveContext,
+    ) -> Result<ResolveStep, ResolveError> {
+        let Some(stable_id) = extract_stable_id(input, InputType::Url)
+            .or_else(|| extract_stable_id(input, InputType::Doi))
+        else {
+            return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                "Input is not a recognized JSTOR stable URL or DOI",
+            )));
+        };
+
+        if !self.has_session_cookie() {
+            return Ok(ResolveStep::NeedsAuth(AuthRequirement::new(
+                JSTOR_HOST,
+                "JSTOR PDFs require institutional access and no jstor.org session cookies were found. Run `downloader auth capture --save-cookies` after signing in to JSTOR, then retry.",
+            )));
+        }
+
+        let mut metadata = HashMap::new();
+        metadata.insert("doi".to_string(), format!("{JSTOR_DOI_PREFIX}{stable_id}"));
+        metadata.insert(
+            "source_url".to_string(),
+            format!("{}/stable/{stable_id}", self.base_url),
+        );
+
+        Ok(ResolveStep::Url(ResolvedUrl::with_metadata(
+            format!("{}/stable/pdf/{stable_id}.pdf", self.base_url),
+            metadata,
+        )))
+    }
+}
+
+/// Extracts the JSTOR stable ID from a `10.2307/<id>` DOI, a `doi.org` link to
+/// one, or a `jstor.org/stable/[pdf/]<id>[.pdf]` URL.
+fn extract_stable_id(input: &str, input_type: InputType) -> Option<String> {
+    match input_type {
+        InputType::Doi => stable_id_from_doi(input),
+        InputType::Url => {
+            let parsed = Url::parse(input.trim()).ok()?;
+            let host = canonical_host(parsed.host_str()?);
+            let path = parsed.path().trim_start_matches('/');
+            if host == DOI_HOST {
+                return stable_id_from_doi(&urlencoding::decode(path).ok()?);
+            }
+            if host != JSTOR_HOST {
+                return None;
+            }
+            let rest = path.strip_prefix("stable/")?;
+            let rest = rest.strip_prefix("pdf/").unwrap_or(rest);
+            let id = rest.strip_suffix(".pdf").unwrap_or(rest);
+            normalize_stable_id(id)
+        }
+        _ => None,
+    }
+}
+
+fn stable_id_from_doi(doi: &str) -> Option<String> {
+    let trimmed = doi.trim();
+    if !looks_like_doi(trimmed, JSTOR_DOI_PREFIX) {
+        return None;
+    }
+    normalize_stable_id(&trimmed[JSTOR_DOI_PREFIX.len()..])
+}
+
+/// Accepts a single path segment of ID characters (`1234567`, `j.ctt1234`).
+fn normalize_stable_id(id: &str) -> Option<String> {
+    let id = id.trim().trim_end_matches('/');
+    let valid = !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'));
+    valid.then(|| id.to_string())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn jar_with_jstor_cookie() -> Arc<Jar> {
+        let jar = Jar::default();
+        jar.add_cookie_str(
+            "UUID=abc123; Domain=.jstor.org; Path=/",
+            &Url::parse("https://www.jstor.org/").unwrap(),
+        );
+        Arc::new(jar)
+    }
+
+    #[test]
+    fn test_jstor_can_handle_stable_urls_and_dois() {
+        let resolver = JstorResolver::new(None);
+        assert!(resolver.can_handle("https://www.jstor.org/stable/1234567", InputType::Url));
+        assert!(resolver.can_handle(
+            "https://www.jstor.org/stable/pdf/1234567.pdf",
+            InputType::Url
+        ));
+        assert!(resolver.can_handle("https://doi.org/10.2307/1234567", InputType::Url));
+        assert!(resolver.can_handle("10.2307/j.ctt1234", InputType::Doi));
+        assert!(!resolver.can_handle("10.1109/5.771073", InputType::Doi));
+        assert!(!resolver.can_handle("https://www.jstor.org/action/doBasicSearch", InputType::Url));
+    }
+
+    #[test]
+    fn test_extract_stable_id_variants() {
+        for (input, input_type) in [
+            ("https://www.jstor.org/stable/1234567", InputType::Url),
+            ("https://jstor.org/stable/pdf/1234567.pdf", InputType::Url),
+            ("10.2307/1234567", InputType::Doi),
+        ] {
+            assert_eq!(
+                extract_stable_id(input, input_type).as_deref(),
+                Some("1234567"),
+                "{input}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_without_jstor_cookies_needs_auth() {
+        let other_jar = Jar::default();
+        other_jar.add_cookie_str(
+            "session=x; Domain=example.com",
+            &Url::parse("https://example.com/").unwrap(),
+        );
+
+        for jar in [None, Some(Arc::new(other_jar))] {
+            let step = JstorResolver::new(jar)
+                .resolve("10.2307/1234567", &ResolveContext::default())
+                .await
+                .unwrap();
+            let ResolveStep::NeedsAuth(requirement) = step else {
+                panic!("expected NeedsAuth, got {step:?}");
+            };
+            assert_eq!(requirement.domain, "jstor.org");
+            assert!(requirement.message.contains("auth capture"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_with_jstor_cookies_returns_pdf_endpoint() {
+        let resolver = JstorResolver::new(Some(jar_with_jstor_cookie()));
+        let step = resolver
+            .resolve(
+                "https://www.jstor.org/stable/1234567",
+                &ResolveContext::default(),
+            )
+            .await
+            .unwrap();
+        let ResolveStep::Url(resolved) = step else {
+            panic!("expected Url, got {step:?}");
+        };
+        assert_eq!(resolved.url, "https://www.jstor.org/stable/pdf/1234567.pdf");
+        assert_eq!(resolved.metadata["doi"], "10.2307/1234567");
+        assert_eq!(
+            resolved.metadata["source_url"],
+            "https://www.jstor.org/stable/1234567"
+        );
+    }
+}
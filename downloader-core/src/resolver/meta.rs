@@ -16,6 +16,11 @@ static META_TAG_RE: LazyLock<Regex> =
 static META_ATTR_RE: LazyLock<Regex> = LazyLock::new(|| {
     compile_static_regex(r#"([a-zA-Z_:][-a-zA-Z0-9_:.]*)\s*=\s*(?:"([^"]*)"|'([^']*)')"#)
 });
+static MARKUP_TAG_RE: LazyLock<Regex> = LazyLock::new(|| compile_static_regex(r"<[^>]*>"));
+static ABSTRACT_HEADING_RE: LazyLock<Regex> =
+    LazyLock::new(|| compile_static_regex(r"(?i)^abstract\b[\s:.\u{2014}-]*"));
+static SPACE_BEFORE_PUNCTUATION_RE: LazyLock<Regex> =
+    LazyLock::new(|| compile_static_regex(r"\s+([.,;:!?)])"));
 
 /// A parsed `<meta>` tag reduced to its name/property and content value.
 #[derive(Debug, Clone)]
@@ -104,3 +109,40 @@ pub(crate) fn html_unescape_basic(value: &str) -> String {
         .trim()
         .to_string()
 }
+
+/// Reduce a JATS/HTML abstract to plain text.
+///
+/// Tags are dropped, entities decoded, whitespace collapsed, and a leading
+/// "Abstract" heading removed. Returns `None` when nothing is left.
+pub(crate) fn plain_text_abstract(raw: &str) -> Option<String> {
+    let without_tags = MARKUP_TAG_RE.replace_all(raw, " ");
+    let decoded = html_unescape_basic(&without_tags).replace('\u{00a0}', " ");
+    let collapsed = decoded.split_whitespace().collect::<Vec<_>>().join(" ");
+    // Tags are replaced by a space, which would otherwise detach closing punctuation.
+    let tightened = SPACE_BEFORE_PUNCTUATION_RE.replace_all(&collapsed, "$1");
+    let text = ABSTRACT_HEADING_RE
+        .replace(&tightened, "")
+        .trim()
+        .to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_text_abstract_strips_jats_markup_and_heading() {
+        let raw = "<jats:title>Abstract</jats:title>\n<jats:p>We measure &amp; model\n   <jats:italic>drift</jats:italic>.</jats:p>";
+        assert_eq!(
+            plain_text_abstract(raw).as_deref(),
+            Some("We measure & model drift.")
+        );
+    }
+
+    #[test]
+    fn test_plain_text_abstract_empty_markup_returns_none() {
+        assert_eq!(plain_text_abstract("<jats:p> </jats:p>"), None);
+        assert_eq!(plain_text_abstract("Abstract"), None);
+    }
+}
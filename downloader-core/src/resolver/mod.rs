@@ -9,26 +9,36 @@
 //! - [`Resolver`] - Async trait that individual resolvers implement
 //! - [`ResolverRegistry`] - Priority-ordered collection of resolvers with resolution loop
 //! - [`ResolveStep`] - Result enum from individual resolve operations
-//! - [`AcmResolver`] - Site-specific resolver for ACM Digital Library URLs/DOIs via Semantic Scholar
+//! - [`AcmResolver`] - Site-specific resolver for ACM Digital Library URLs/DOIs via Semantic Scholar, then the auth-gated ACM PDF endpoint
 //! - [`WileyResolver`] - Site-specific resolver for Wiley Online Library URLs/DOIs via Semantic Scholar
 //! - [`ArxivResolver`] - Site-specific resolver for `arXiv` URLs/DOIs
 //! - [`PubMedResolver`] - Site-specific resolver for PubMed/PMC URL resolution
 //! - [`IeeeResolver`] - Site-specific resolver for IEEE Xplore and `10.1109/*` DOI inputs
+//! - [`JstorResolver`] - Site-specific resolver for JSTOR stable URLs and `10.2307/*` DOIs (auth-gated)
 //! - [`MdpiResolver`] - Site-specific resolver for MDPI URLs and `10.3390/*` DOI inputs
 //! - [`OxfordAcademicResolver`] - Site-specific resolver for Oxford Academic URLs and `10.1093/*` DOI inputs
+//! - [`SsrnResolver`] - Site-specific resolver for SSRN abstract pages and `10.2139/ssrn.*` DOIs (auth-gated downloads)
 //! - [`SpringerResolver`] - Site-specific resolver for Springer article/chapter URL inputs
+//! - [`SemanticScholarResolver`] - General resolver for DOIs and reference titles via the Semantic Scholar Graph API
+//! - [`OpenAlexResolver`] - General resolver for open-access copies of DOIs (and `doi.org` links) via the `OpenAlex` API, after Crossref
+//! - [`UnpaywallResolver`] - General resolver for open-access PDFs of DOIs via the Unpaywall API
 //! - [`ScienceDirectResolver`] - Site-specific resolver for `ScienceDirect` URLs/DOIs
 //! - [`YouTubeResolver`] - Site-specific resolver for `YouTube` watch URLs (oEmbed + transcript)
+//! - [`ZenodoResolver`] - Site-specific resolver for Zenodo record URLs and `10.5281/zenodo.*` DOIs
+//! - [`OsfResolver`] - Site-specific resolver for OSF files, projects, and OSF-hosted preprint servers
+//! - [`HalResolver`] - Site-specific resolver for HAL open-archive URLs and bare `hal-*` IDs
+//! - [`CitationMetaResolver`] - General resolver for publisher landing pages carrying `citation_pdf_url` meta tags or FAIR signposting links
+//! - [`HandleResolver`] - Follows `hdl:` handles, `hdl.handle.net` links, and PURLs to their targets
 //! - [`DirectResolver`] - Reference implementation (URL passthrough)
 //!
 //! # Example
 //!
 //! ```no_run
-//! use downloader_core::resolver::{build_default_resolver_registry, ResolveContext};
+//! use downloader_core::resolver::{build_default_resolver_registry, CrossrefConfig, ResolveContext};
 //! use downloader_core::parser::InputType;
 //!
 //! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-//! let registry = build_default_resolver_registry(None, "downloader@example.com");
+//! let registry = build_default_resolver_registry(None, &CrossrefConfig::default(), &[]);
 //!
 //! let ctx = ResolveContext::default();
 //! let resolved = registry
@@ -41,117 +51,261 @@
 
 mod acm;
 mod arxiv;
+mod citation_meta;
 mod crossref;
 mod direct;
+mod doi_metadata;
 mod error;
+mod hal;
+mod handle;
 mod http_client;
 mod ieee;
+mod jstor;
 mod mdpi;
 mod meta;
+mod openalex;
+mod osf;
 mod oxford;
 mod pubmed;
 mod registry;
 mod sciencedirect;
 mod semantic_scholar;
+mod semantic_scholar_resolver;
+mod signposting;
 mod springer;
+mod ssrn;
+mod unpaywall;
 mod utils;
 mod wiley;
 mod youtube;
+mod zenodo;
 
 pub use acm::AcmResolver;
-pub use arxiv::ArxivResolver;
-pub use crossref::CrossrefResolver;
+pub use arxiv::{ArxivResolver, ArxivWork, arxiv_id_from_doi, lookup_arxiv_works};
+pub use citation_meta::CitationMetaResolver;
+pub use crossref::{CrossrefApiToken, CrossrefConfig, CrossrefResolver, DEFAULT_CROSSREF_MAILTO};
 pub use direct::DirectResolver;
 pub use error::ResolveError;
-pub use http_client::configure_resolver_http_timeouts;
+pub use hal::HalResolver;
+pub use handle::HandleResolver;
+pub use http_client::{
+    ResolverTimeoutOverride, configure_resolver_http_timeouts, configure_resolver_timeout_overrides,
+};
 pub use ieee::IeeeResolver;
+pub use jstor::JstorResolver;
 pub use mdpi::MdpiResolver;
+pub use openalex::OpenAlexResolver;
+pub use osf::OsfResolver;
 pub use oxford::OxfordAcademicResolver;
 pub use pubmed::PubMedResolver;
 pub use registry::ResolverRegistry;
 pub use sciencedirect::ScienceDirectResolver;
+pub use semantic_scholar_resolver::SemanticScholarResolver;
 pub use springer::SpringerResolver;
+pub use ssrn::SsrnResolver;
+pub use unpaywall::UnpaywallResolver;
 pub use wiley::WileyResolver;
 pub use youtube::YouTubeResolver;
+pub use zenodo::ZenodoResolver;
 
 use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
 use reqwest::cookie::Jar;
-use tracing::warn;
+use tracing::{debug, warn};
 
+use crate::download::{RateLimiter, max_redirects};
 use crate::parser::InputType;
 
 /// Shared metadata contract keys expected across site resolvers.
-pub const STANDARD_METADATA_KEYS: [&str; 5] = ["title", "authors", "doi", "year", "source_url"];
+///
+/// `authors` is a `"; "`-separated name list. The optional `author_orcids` key
+/// is a `;`-separated list of ORCID iDs (bare or `orcid.org` URLs) aligned
+/// positionally with `authors`, with an empty slot for authors without one.
+/// `abstract` is plain text with markup stripped.
+pub const STANDARD_METADATA_KEYS: [&str; 7] = [
+    "title",
+    "authors",
+    "author_orcids",
+    "doi",
+    "year",
+    "source_url",
+    "abstract",
+];
+
+/// Separator between resolver names in [`ResolvedUrl::resolver_path`].
+pub const RESOLVER_PATH_SEPARATOR: &str = "->";
 
 /// Builds the default resolver registry used by CLI execution flows.
 ///
 /// Order is deterministic and preserves site-specific priority before
-/// general and fallback handlers.
+/// general and fallback handlers. Resolvers named in `disabled_resolvers` are
+/// registered but skipped by the resolution loop (see
+/// [`ResolverRegistry::disable`]); unknown names are logged and ignored.
+/// `crossref` supplies the polite-pool contact and optional Plus token for the
+/// Crossref and MDPI resolvers; the contact is also sent to Unpaywall and `OpenAlex`.
 #[must_use]
 pub fn build_default_resolver_registry(
     cookie_jar: Option<Arc<Jar>>,
-    crossref_mailto: &str,
+    crossref: &CrossrefConfig,
+    disabled_resolvers: &[String],
 ) -> ResolverRegistry {
     let mut registry = ResolverRegistry::new();
 
+    register_specialized_resolvers(&mut registry, cookie_jar.as_ref(), crossref);
+    register_general_resolvers(&mut registry, cookie_jar, crossref);
+    registry.register(Box::new(DirectResolver::new()));
+
+    for name in disabled_resolvers {
+        if registry.has_resolver(name) {
+            registry.disable(name);
+        } else {
+            warn!(
+                resolver = %name,
+                "Unknown resolver in disabled list; ignoring it"
+            );
+        }
+    }
+    debug!(
+        resolvers = ?registry.active_resolver_names(),
+        disabled = ?disabled_resolvers,
+        "Active resolvers"
+    );
+    registry
+}
+
+/// Registers the site-specific resolvers, in the order they should be tried.
+fn register_specialized_resolvers(
+    registry: &mut ResolverRegistry,
+    cookie_jar: Option<&Arc<Jar>>,
+    crossref: &CrossrefConfig,
+) {
     registry.register(Box::new(ArxivResolver::new()));
+    register_or_warn(
+        registry,
+        ZenodoResolver::new(),
+        "Zenodo resolver unavailable; continuing with remaining resolvers",
+    );
+    register_or_warn(
+        registry,
+        OsfResolver::new(),
+        "OSF resolver unavailable; continuing with remaining resolvers",
+    );
+    register_or_warn(
+        registry,
+        HalResolver::new(),
+        "HAL resolver unavailable; continuing with remaining resolvers",
+    );
+    register_or_warn(
+        registry,
+        HandleResolver::new(cookie_jar.cloned()),
+        "Handle resolver unavailable; continuing with remaining resolvers",
+    );
 
     register_or_warn(
-        &mut registry,
-        PubMedResolver::new(cookie_jar.clone()),
+        registry,
+        PubMedResolver::new(cookie_jar.cloned()),
         "PubMed resolver unavailable; continuing with remaining resolvers",
     );
     register_or_warn(
-        &mut registry,
-        IeeeResolver::new(cookie_jar.clone()),
+        registry,
+        IeeeResolver::new(cookie_jar.cloned()),
         "IEEE resolver unavailable; continuing with remaining resolvers",
     );
     register_or_warn(
-        &mut registry,
-        OxfordAcademicResolver::new(cookie_jar.clone()),
+        registry,
+        OxfordAcademicResolver::new(cookie_jar.cloned()),
         "Oxford Academic resolver unavailable; continuing with remaining resolvers",
     );
     register_or_warn(
-        &mut registry,
-        SpringerResolver::new(cookie_jar.clone()),
+        registry,
+        SpringerResolver::new(cookie_jar.cloned()),
         "Springer resolver unavailable; continuing with remaining resolvers",
     );
+    registry.register(Box::new(JstorResolver::new(cookie_jar.cloned())));
+    register_or_warn(
+        registry,
+        SsrnResolver::new(cookie_jar.cloned()),
+        "SSRN resolver unavailable; continuing with remaining resolvers",
+    );
     register_or_warn(
-        &mut registry,
-        ScienceDirectResolver::new(cookie_jar),
+        registry,
+        ScienceDirectResolver::new(cookie_jar.cloned()),
         "ScienceDirect resolver unavailable; continuing with generic resolvers",
     );
     register_or_warn(
-        &mut registry,
-        AcmResolver::new(),
+        registry,
+        AcmResolver::new(cookie_jar.cloned()),
         "ACM resolver unavailable; continuing with remaining resolvers",
     );
     register_or_warn(
-        &mut registry,
+        registry,
         WileyResolver::new(),
         "Wiley resolver unavailable; continuing with remaining resolvers",
     );
     register_or_warn(
-        &mut registry,
-        MdpiResolver::new(crossref_mailto),
+        registry,
+        MdpiResolver::new(crossref.mailto()),
         "MDPI resolver unavailable; continuing with remaining resolvers",
     );
     register_or_warn(
-        &mut registry,
+        registry,
         YouTubeResolver::new(),
         "YouTube resolver unavailable; continuing with remaining resolvers",
     );
+}
+
+/// Registers the general DOI and reference resolvers ahead of the direct fallback.
+fn register_general_resolvers(
+    registry: &mut ResolverRegistry,
+    cookie_jar: Option<Arc<Jar>>,
+    crossref: &CrossrefConfig,
+) {
+    register_or_warn(
+        registry,
+        SemanticScholarResolver::new(),
+        "Semantic Scholar resolver unavailable; continuing with remaining resolvers",
+    );
+    // Before Crossref, whose DOI step redirects to the landing page and ends the chain.
     register_or_warn(
-        &mut registry,
-        CrossrefResolver::new(crossref_mailto),
+        registry,
+        UnpaywallResolver::new(crossref.mailto()),
+        "Unpaywall resolver unavailable; continuing with remaining resolvers",
+    );
+    register_or_warn(
+        registry,
+        CrossrefResolver::from_config(crossref),
         "Crossref resolver unavailable; continuing with direct fallback only",
     );
+    // After Crossref (canonical metadata); also takes Crossref's doi.org redirect.
+    register_or_warn(
+        registry,
+        OpenAlexResolver::new(crossref.mailto()),
+        "OpenAlex resolver unavailable; continuing with remaining resolvers",
+    );
+    register_or_warn(
+        registry,
+        CitationMetaResolver::new(cookie_jar),
+        "Citation meta resolver unavailable; landing pages will pass through unchanged",
+    );
+}
 
-    registry.register(Box::new(DirectResolver::new()));
-    registry
+/// Turns on Crossref metadata enrichment for `registry`.
+///
+/// See [`ResolverRegistry::set_metadata_enricher`]. When the Crossref client
+/// cannot be built, a warning is logged and enrichment stays off.
+pub fn enable_crossref_metadata_enrichment(
+    registry: &mut ResolverRegistry,
+    crossref: &CrossrefConfig,
+) {
+    match CrossrefResolver::from_config(crossref) {
+        Ok(enricher) => registry.set_metadata_enricher(enricher),
+        Err(error) => warn!(
+            error = %error,
+            "Crossref metadata enrichment unavailable; continuing without it"
+        ),
+    }
 }
 
 /// Registers a fallibly-constructed resolver, logging a warning (and skipping
@@ -191,6 +345,17 @@ pub struct ResolvedUrl {
     pub url: String,
     /// Optional metadata discovered during resolution (title, authors, etc.)
     pub metadata: HashMap<String, String>,
+    /// Name of the resolver that produced the URL, filled in by [`ResolverRegistry`].
+    pub resolver: Option<String>,
+    /// Every resolver that fired on the way to `url`, joined with
+    /// [`RESOLVER_PATH_SEPARATOR`] (`crossref->direct`), filled in by [`ResolverRegistry`].
+    pub resolver_path: Option<String>,
+    /// Alternative URLs for the same item (other files of a record, mirrors),
+    /// in preference order after `url`.
+    pub fallback_urls: Vec<String>,
+    /// Read timeout override in seconds for resources known to be large
+    /// (carried into [`QueueMetadata::read_timeout_secs`](crate::QueueMetadata::read_timeout_secs)).
+    pub read_timeout_secs: Option<u64>,
 }
 
 impl ResolvedUrl {
@@ -200,6 +365,10 @@ impl ResolvedUrl {
         Self {
             url: url.into(),
             metadata: HashMap::new(),
+            resolver: None,
+            resolver_path: None,
+            fallback_urls: Vec::new(),
+            read_timeout_secs: None,
         }
     }
 
@@ -209,8 +378,27 @@ impl ResolvedUrl {
         Self {
             url: url.into(),
             metadata,
+            resolver: None,
+            resolver_path: None,
+            fallback_urls: Vec::new(),
+            read_timeout_secs: None,
         }
     }
+
+    /// Attaches alternative URLs to try after the primary one.
+    #[must_use]
+    pub fn with_fallback_urls(mut self, fallback_urls: Vec<String>) -> Self {
+        self.fallback_urls = fallback_urls;
+        self
+    }
+
+    /// Asks the download engine to allow `secs` of read time for this URL
+    /// instead of the client default.
+    #[must_use]
+    pub fn with_read_timeout_secs(mut self, secs: u64) -> Self {
+        self.read_timeout_secs = Some(secs);
+        self
+    }
 }
 
 /// Authentication requirement returned when a resolver detects auth is needed.
@@ -263,14 +451,37 @@ impl ResolveStep {
 #[derive(Debug)]
 pub struct ResolveContext {
     /// Maximum number of redirect hops allowed.
+    ///
+    /// Starts from [`max_redirects`](crate::download::max_redirects), which
+    /// also bounds the redirects resolver HTTP clients follow internally.
     pub max_redirects: usize,
+    /// Per-host limiter shared by API-backed resolvers when resolving concurrently.
+    pub rate_limiter: Option<Arc<RateLimiter>>,
 }
 
 impl ResolveContext {
     /// Creates a new context with default settings.
     #[must_use]
     pub fn new() -> Self {
-        Self { max_redirects: 10 }
+        Self {
+            max_redirects: max_redirects(),
+            rate_limiter: None,
+        }
+    }
+
+    /// Attaches a per-host rate limiter that API-backed resolvers wait on
+    /// before each request.
+    #[must_use]
+    pub fn with_rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Waits for `url`'s host slot on the shared rate limiter, if one is attached.
+    pub async fn throttle(&self, url: &str) {
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.acquire(url).await;
+        }
     }
 }
 
@@ -338,6 +549,7 @@ mod tests {
     fn test_resolve_context_default() {
         let ctx = ResolveContext::default();
         assert_eq!(ctx.max_redirects, 10);
+        assert!(ctx.rate_limiter.is_none());
     }
 
     #[test]
@@ -0,0 +1,398 @@
+//! `OpenAlex` resolver: open-access copies and metadata for DOIs Crossref cannot download.
+//!
+//! Looks up `https://api.openalex.org/works/doi:{doi}` and returns
+//! `open_access.oa_url` with `title`/`authors`/`year`/`source_url` metadata.
+//! Registered at [`ResolverPriority::General`] after Crossref, so Crossref
+//! stays the canonical source for DOI metadata. `OpenAlex` gets its turn when
+//! Crossref fails (DOIs Crossref does not index, such as `DataCite` ones, or an
+//! unreachable API) and when Crossref finds no PDF link and redirects to
+//! `https://doi.org/{doi}`: that redirect is handled here before the landing
+//! page falls through to `citation_meta` and `direct`.
+//!
+//! Works without an open-access URL, unknown DOIs, API errors, and network
+//! failures all return [`ResolveStep::Failed`] so the chain continues.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+use tracing::{debug, warn};
+use url::Url;
+
+use crate::parser::InputType;
+
+use super::http_client::{build_resolver_http_client, send_with_retry, standard_user_agent};
+use super::utils::{hosts_match, looks_like_doi, validate_crossref_mailto};
+use super::{ResolveContext, ResolveError, ResolveStep, ResolvedUrl, Resolver, ResolverPriority};
+
+// ==================== Constants ====================
+
+const DEFAULT_OPENALEX_BASE_URL: &str = "https://api.openalex.org";
+/// DOI proxy hosts whose URLs (e.g. Crossref's no-PDF redirect) carry a DOI path.
+const DOI_PROXY_HOSTS: &[&str] = &["doi.org", "dx.doi.org"];
+
+// ==================== API response types ====================
+
+#[derive(Debug, Deserialize)]
+struct OpenAlexWork {
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    publication_year: Option<i32>,
+    #[serde(default)]
+    doi: Option<String>,
+    #[serde(default)]
+    authorships: Vec<OpenAlexAuthorship>,
+    #[serde(default)]
+    open_access: Option<OpenAlexOpenAccess>,
+    #[serde(default)]
+    primary_location: Option<OpenAlexLocation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAlexAuthorship {
+    #[serde(default)]
+    author: Option<OpenAlexAuthor>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAlexAuthor {
+    #[serde(default)]
+    display_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAlexOpenAccess {
+    #[serde(default)]
+    oa_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAlexLocation {
+    #[serde(default)]
+    landing_page_url: Option<String>,
+}
+
+// ==================== OpenAlexResolver ====================
+
+/// Resolves DOIs to open-access copies via the `OpenAlex` works API.
+pub struct OpenAlexResolver {
+    client: Client,
+    base_url: String,
+    mailto: String,
+}
+
+impl OpenAlexResolver {
+    /// Creates a new `OpenAlexResolver` using the production API.
+    ///
+    /// `mailto` is sent with every request to join `OpenAlex`'s polite pool.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResolveError`] if HTTP client construction fails or `mailto` is invalid.
+    pub fn new(mailto: impl Into<String>) -> Result<Self, ResolveError> {
+        Self::build(mailto.into(), DEFAULT_OPENALEX_BASE_URL.to_string())
+    }
+
+    /// Creates an `OpenAlexResolver` with a custom API base URL.
+    ///
+    /// Intended for use in tests with a wiremock server.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResolveError`] if HTTP client construction fails or `mailto` is invalid.
+    pub fn with_base_url(
+        mailto: impl Into<String>,
+        base_url: impl Into<String>,
+    ) -> Result<Self, ResolveError> {
+        Self::build(
+            mailto.into(),
+            base_url.into().trim_end_matches('/').to_string(),
+        )
+    }
+
+    fn build(mailto: String, base_url: String) -> Result<Self, ResolveError> {
+        validate_crossref_mailto(&mailto)?;
+        let client = build_resolver_http_client("openalex", standard_user_agent("openalex"), None)?;
+        Ok(Self {
+            client,
+            base_url,
+            mailto,
+        })
+    }
+
+    async fn fetch_work(
+        &self,
+        doi: &str,
+        input: &str,
+        ctx: &ResolveContext,
+    ) -> Result<OpenAlexWork, ResolveStep> {
+        let url = format!(
+            "{}/works/doi:{doi}?mailto={}",
+            self.base_url,
+            urlencoding::encode(&self.mailto)
+        );
+        ctx.throttle(&url).await;
+        debug!(api_url = %url, "Calling OpenAlex works API");
+
+        let request = self.client.get(&url);
+        let response = match send_with_retry(request, "openalex").await {
+            Ok(resp) => resp,
+            Err(e) => {
+                warn!(error = %e, "OpenAlex request failed");
+                return Err(ResolveStep::Failed(ResolveError::resolution_failed(
+                    input,
+                    "Cannot reach the OpenAlex API. \
+                     Why: network error, timeout, or API unavailable. \
+                     Fix: check your internet connection; other resolvers are tried meanwhile.",
+                )));
+            }
+        };
+
+        let status = response.status();
+        if !status.is_success() {
+            let reason = match status.as_u16() {
+                404 => "OpenAlex has no work for this DOI".to_string(),
+                429 => "OpenAlex rate limit exceeded. Try again later.".to_string(),
+                s if s >= 500 => "OpenAlex API unavailable. Try again later.".to_string(),
+                s => format!("OpenAlex API returned HTTP {s}"),
+            };
+            debug!(status = status.as_u16(), %reason, "OpenAlex API error");
+            return Err(ResolveStep::Failed(ResolveError::resolution_failed(
+                input, &reason,
+            )));
+        }
+
+        response.json::<OpenAlexWork>().await.map_err(|e| {
+            warn!(error = %e, "Failed to parse OpenAlex response");
+            ResolveStep::body_parse_failed(input, "OpenAlex")
+        })
+    }
+}
+
+impl std::fmt::Debug for OpenAlexResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OpenAlexResolver")
+            .field("base_url", &self.base_url)
+            .field("mailto", &self.mailto)
+            .finish_non_exhaustive()
+    }
+}
+
+// ==================== Resolver trait ====================
+
+#[async_trait]
+impl Resolver for OpenAlexResolver {
+    fn name(&self) -> &'static str {
+        "openalex"
+    }
+
+    fn priority(&self) -> ResolverPriority {
+        ResolverPriority::General
+    }
+
+    fn can_handle(&self, input: &str, input_type: InputType) -> bool {
+        extract_doi(input, input_type).is_some()
+    }
+
+    #[tracing::instrument(skip(self, ctx), fields(resolver = "openalex", input = %input))]
+    async fn resolve(
+        &self,
+        input: &str,
+        ctx: &ResolveContext,
+    ) -> Result<ResolveStep, ResolveError> {
+        let doi = extract_doi(input, InputType::Doi).or_else(|| extract_doi(input, InputType::Url));
+        let Some(doi) = doi else {
+            return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                "Input is not a DOI or doi.org link",
+            )));
+        };
+
+        let work = match self.fetch_work(&doi, input, ctx).await {
+            Ok(work) => work,
+            Err(step) => return Ok(step),
+        };
+
+        let oa_url = work
+            .open_access
+            .as_ref()
+            .and_then(|open_access| open_access.oa_url.as_deref())
+            .map(str::trim)
+            .filter(|url| url.starts_with("http://") || url.starts_with("https://"));
+        let Some(oa_url) = oa_url else {
+            debug!(%doi, "OpenAlex lists no open-access URL");
+            return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                "OpenAlex lists no open-access copy of this work. \
+                 Why: the work may be paywalled. \
+                 Fix: the landing page is tried next; log in with `downloader auth` if it needs a subscription.",
+            )));
+        };
+
+        Ok(ResolveStep::Url(ResolvedUrl::with_metadata(
+            oa_url,
+            work_metadata(&work, &doi),
+        )))
+    }
+}
+
+// ==================== Helpers ====================
+
+/// The DOI in a bare DOI input or a `doi.org` URL.
+fn extract_doi(input: &str, input_type: InputType) -> Option<String> {
+    let trimmed = input.trim();
+    match input_type {
+        InputType::Doi => looks_like_doi(trimmed, "10.").then(|| trimmed.to_string()),
+        InputType::Url => {
+            let url = Url::parse(trimmed).ok()?;
+            let host = url.host_str()?;
+            if !DOI_PROXY_HOSTS.iter().any(|proxy| hosts_match(host, proxy)) {
+                return None;
+            }
+            let doi = urlencoding::decode(url.path().trim_start_matches('/')).ok()?;
+            looks_like_doi(&doi, "10.").then(|| doi.into_owned())
+        }
+        _ => None,
+    }
+}
+
+fn work_metadata(work: &OpenAlexWork, doi: &str) -> HashMap<String, String> {
+    let mut metadata = HashMap::new();
+    metadata.insert("doi".to_string(), doi.to_string());
+
+    if let Some(title) = work.title.as_deref().map(str::trim)
+        && !title.is_empty()
+    {
+        metadata.insert("title".to_string(), title.to_string());
+    }
+    let authors: Vec<&str> = work
+        .authorships
+        .iter()
+        .filter_map(|authorship| authorship.author.as_ref()?.display_name.as_deref())
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .collect();
+    if !authors.is_empty() {
+        metadata.insert("authors".to_string(), authors.join("; "));
+    }
+    if let Some(year) = work.publication_year {
+        metadata.insert("year".to_string(), year.to_string());
+    }
+    let source_url = work
+        .primary_location
+        .as_ref()
+        .and_then(|location| location.landing_page_url.as_deref())
+        .or(work.doi.as_deref());
+    if let Some(source_url) = source_url {
+        metadata.insert("source_url".to_string(), source_url.to_string());
+    }
+    metadata
+}
+
+// ==================== Tests ====================
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::test_support::socket_guard::start_mock_server_or_skip;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    const MAILTO: &str = "team@example.org";
+
+    #[test]
+    fn test_name_priority_and_can_handle() {
+        let resolver = OpenAlexResolver::new(MAILTO).unwrap();
+        assert_eq!(resolver.name(), "openalex");
+        assert_eq!(resolver.priority(), ResolverPriority::General);
+        assert!(resolver.can_handle("10.1234/example", InputType::Doi));
+        assert!(resolver.can_handle("https://doi.org/10.1234/example", InputType::Url));
+        assert!(resolver.can_handle("https://dx.doi.org/10.1234/example", InputType::Url));
+        assert!(!resolver.can_handle("https://example.com/10.1234/example", InputType::Url));
+        assert!(!resolver.can_handle("Smith (2020). Title.", InputType::Reference));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_returns_oa_url_with_metadata() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/works/doi:10.5281/zenodo.123"))
+            .and(query_param("mailto", MAILTO))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "https://openalex.org/W123",
+                "doi": "https://doi.org/10.5281/zenodo.123",
+                "title": "A DataCite Dataset",
+                "publication_year": 2022,
+                "authorships": [
+                    {"author": {"display_name": "Ada Lovelace"}},
+                    {"author": {"display_name": "Charles Babbage"}}
+                ],
+                "primary_location": {"landing_page_url": "https://zenodo.org/records/123"},
+                "open_access": {"is_oa": true, "oa_url": "https://zenodo.org/records/123/files/data.pdf"}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = OpenAlexResolver::with_base_url(MAILTO, mock_server.uri()).unwrap();
+        let step = resolver
+            .resolve(
+                "https://doi.org/10.5281/zenodo.123",
+                &ResolveContext::default(),
+            )
+            .await
+            .unwrap();
+
+        let ResolveStep::Url(result) = step else {
+            panic!("expected Url step, got {step:?}");
+        };
+        assert_eq!(result.url, "https://zenodo.org/records/123/files/data.pdf");
+        assert_eq!(result.metadata.get("title").unwrap(), "A DataCite Dataset");
+        assert_eq!(
+            result.metadata.get("authors").unwrap(),
+            "Ada Lovelace; Charles Babbage"
+        );
+        assert_eq!(result.metadata.get("year").unwrap(), "2022");
+        assert_eq!(result.metadata.get("doi").unwrap(), "10.5281/zenodo.123");
+        assert_eq!(
+            result.metadata.get("source_url").unwrap(),
+            "https://zenodo.org/records/123"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_without_oa_url_or_work_fails_softly() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/works/doi:10.1234/closed"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "title": "Closed Paper",
+                "open_access": {"is_oa": false, "oa_url": null}
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/works/doi:10.1234/missing"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = OpenAlexResolver::with_base_url(MAILTO, mock_server.uri()).unwrap();
+        for doi in ["10.1234/closed", "10.1234/missing"] {
+            let step = resolver
+                .resolve(doi, &ResolveContext::default())
+                .await
+                .unwrap();
+            assert!(
+                matches!(step, ResolveStep::Failed(_)),
+                "expected soft failure for {doi}, got {step:?}"
+            );
+        }
+    }
+}
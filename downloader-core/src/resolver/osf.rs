@@ -0,0 +1,757 @@
+//! OSF (Open Science Framework) resolver for files, projects, and preprints.
+//!
+//! Recognizes `osf.io/<guid>`, `osf.io/download/<guid>`, and
+//! `osf.io/preprints/<provider>/<guid>` URLs, the branded preprint hosts
+//! (`psyarxiv.com/<guid>`, `socarxiv.org/<guid>`, ...), and `*/osf.io/<guid>`
+//! preprint DOIs. The guid is looked up through the OSF API (`/v2/guids/<guid>/`)
+//! and resolved by what it points at:
+//!
+//! - a file resolves to its download link;
+//! - a preprint resolves to its primary file;
+//! - a project or registration resolves to its largest top-level PDF (or
+//!   largest file) in OSF Storage, with the other files in
+//!   [`ResolvedUrl::fallback_urls`].
+//!
+//! Anything else (users, folders, empty projects) is a soft failure so the
+//! chain moves on.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use tracing::{debug, warn};
+use url::Url;
+
+use crate::parser::InputType;
+
+use super::http_client::{build_resolver_http_client, send_with_retry, standard_user_agent};
+use super::utils::{canonical_host, extract_year_from_str};
+use super::{ResolveContext, ResolveError, ResolveStep, ResolvedUrl, Resolver, ResolverPriority};
+
+// ==================== Constants ====================
+
+const DEFAULT_OSF_API_BASE_URL: &str = "https://api.osf.io";
+const OSF_HOST: &str = "osf.io";
+const DOI_HOSTS: &[&str] = &["doi.org", "dx.doi.org"];
+/// Branded OSF Preprints hosts; their paths are `/<guid>[/download]` like `osf.io`.
+const PREPRINT_PROVIDER_HOSTS: &[&str] = &[
+    "africarxiv.org",
+    "eartharxiv.org",
+    "edarxiv.org",
+    "engrxiv.org",
+    "lawarxiv.info",
+    "mindrxiv.org",
+    "paleorxiv.org",
+    "psyarxiv.com",
+    "socarxiv.org",
+    "sportrxiv.org",
+    "thesiscommons.org",
+];
+/// `osf.io` top-level paths that look like guids but are site pages.
+const RESERVED_PATHS: &[&str] = &[
+    "dashboard",
+    "download",
+    "institutions",
+    "login",
+    "logout",
+    "meetings",
+    "myprojects",
+    "preprints",
+    "register",
+    "registries",
+    "search",
+    "support",
+];
+
+// ==================== API response types ====================
+
+/// JSON:API envelope used by every OSF v2 endpoint.
+#[derive(Debug, Deserialize)]
+struct OsfDocument<T> {
+    data: T,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsfEntity {
+    #[serde(rename = "type")]
+    entity_type: String,
+    #[serde(default)]
+    attributes: OsfAttributes,
+    #[serde(default)]
+    links: OsfLinks,
+    #[serde(default)]
+    relationships: OsfRelationships,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct OsfAttributes {
+    #[serde(default)]
+    title: Option<String>,
+    /// File name (files only).
+    #[serde(default)]
+    name: Option<String>,
+    /// `"file"` or `"folder"` (files only).
+    #[serde(default)]
+    kind: Option<String>,
+    #[serde(default)]
+    size: Option<u64>,
+    #[serde(default)]
+    date_published: Option<String>,
+    #[serde(default)]
+    date_registered: Option<String>,
+    #[serde(default)]
+    date_created: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct OsfLinks {
+    #[serde(default)]
+    download: Option<String>,
+    #[serde(default)]
+    html: Option<String>,
+    /// `https://doi.org/...` link minted for preprints.
+    #[serde(default)]
+    preprint_doi: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct OsfRelationships {
+    #[serde(default)]
+    primary_file: Option<OsfRelationship>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsfRelationship {
+    links: OsfRelationshipLinks,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsfRelationshipLinks {
+    related: OsfHref,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsfHref {
+    href: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsfContributor {
+    #[serde(default)]
+    embeds: OsfContributorEmbeds,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct OsfContributorEmbeds {
+    #[serde(default)]
+    users: Option<OsfDocument<OsfUser>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsfUser {
+    #[serde(default)]
+    attributes: OsfUserAttributes,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct OsfUserAttributes {
+    #[serde(default)]
+    full_name: Option<String>,
+}
+
+// ==================== OsfResolver ====================
+
+/// Specialized resolver for OSF guids and OSF-hosted preprint servers.
+pub struct OsfResolver {
+    client: Client,
+    api_base_url: String,
+}
+
+impl OsfResolver {
+    /// Creates a new `OsfResolver` using the production OSF API.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResolveError`] if HTTP client construction fails.
+    pub fn new() -> Result<Self, ResolveError> {
+        Self::build(DEFAULT_OSF_API_BASE_URL.to_string())
+    }
+
+    /// Creates an `OsfResolver` with a custom API base URL.
+    ///
+    /// Intended for use in tests with a wiremock server.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResolveError`] if HTTP client construction fails.
+    pub fn with_base_url(api_base_url: impl Into<String>) -> Result<Self, ResolveError> {
+        Self::build(api_base_url.into().trim_end_matches('/').to_string())
+    }
+
+    fn build(api_base_url: String) -> Result<Self, ResolveError> {
+        let user_agent = standard_user_agent("osf");
+        let client = build_resolver_http_client("osf", user_agent, None)?;
+        Ok(Self {
+            client,
+            api_base_url,
+        })
+    }
+
+    async fn fetch_json<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        input: &str,
+        ctx: &ResolveContext,
+    ) -> Result<T, ResolveStep> {
+        ctx.throttle(url).await;
+        debug!(api_url = %url, "Calling OSF API");
+
+        let response = match send_with_retry(self.client.get(url), "osf").await {
+            Ok(resp) => resp,
+            Err(e) => {
+                warn!(error = %e, "OSF API request failed");
+                return Err(ResolveStep::Failed(ResolveError::resolution_failed(
+                    input,
+                    "Cannot reach the OSF API. Check your network connection.",
+                )));
+            }
+        };
+
+        let status = response.status();
+        if !status.is_success() {
+            let reason = match status.as_u16() {
+                401 | 403 => "OSF item is private. \
+                              Why: only contributors can see it. \
+                              Fix: ask the authors to make it public."
+                    .to_string(),
+                404 | 410 => "OSF item not found or withdrawn".to_string(),
+                429 => "OSF rate limit exceeded. Try again in a few seconds.".to_string(),
+                s if s >= 500 => "OSF API unavailable. Try again later.".to_string(),
+                s => format!("OSF API returned HTTP {s}"),
+            };
+            debug!(status = status.as_u16(), %reason, "OSF API error");
+            return Err(ResolveStep::Failed(ResolveError::resolution_failed(
+                input, &reason,
+            )));
+        }
+
+        response.json::<T>().await.map_err(|e| {
+            warn!(error = %e, "Failed to parse OSF response");
+            ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                "Unexpected OSF API response format",
+            ))
+        })
+    }
+
+    /// Bibliographic contributor names; empty when the list cannot be fetched.
+    async fn fetch_contributors(
+        &self,
+        collection: &str,
+        guid: &str,
+        input: &str,
+        ctx: &ResolveContext,
+    ) -> Vec<String> {
+        let url = format!(
+            "{}/v2/{collection}/{guid}/contributors/?filter[bibliographic]=true",
+            self.api_base_url
+        );
+        if let Ok(document) = self
+            .fetch_json::<OsfDocument<Vec<OsfContributor>>>(&url, input, ctx)
+            .await
+        {
+            document
+                .data
+                .into_iter()
+                .filter_map(|contributor| contributor.embeds.users)
+                .filter_map(|user| user.data.attributes.full_name)
+                .map(|name| name.trim().to_string())
+                .filter(|name| !name.is_empty())
+                .collect()
+        } else {
+            debug!(
+                guid,
+                "OSF contributors unavailable; continuing without authors"
+            );
+            Vec::new()
+        }
+    }
+}
+
+impl std::fmt::Debug for OsfResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OsfResolver")
+            .field("api_base_url", &self.api_base_url)
+            .finish_non_exhaustive()
+    }
+}
+
+// ==================== Resolver trait ====================
+
+#[async_trait]
+impl Resolver for OsfResolver {
+    fn name(&self) -> &'static str {
+        "osf"
+    }
+
+    fn priority(&self) -> ResolverPriority {
+        ResolverPriority::Specialized
+    }
+
+    fn can_handle(&self, input: &str, input_type: InputType) -> bool {
+        matches!(input_type, InputType::Url | InputType::Doi) && extract_guid(input).is_some()
+    }
+
+    #[tracing::instrument(skip(self, ctx), fields(resolver = "osf", input = %input))]
+    async fn resolve(
+        &self,
+        input: &str,
+        ctx: &ResolveContext,
+    ) -> Result<ResolveStep, ResolveError> {
+        let Some(guid) = extract_guid(input) else {
+            return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                "Input is not a recognized OSF URL or DOI",
+            )));
+        };
+
+        let guid_url = format!("{}/v2/guids/{guid}/", self.api_base_url);
+        let entity = match self
+            .fetch_json::<OsfDocument<OsfEntity>>(&guid_url, input, ctx)
+            .await
+        {
+            Ok(document) => document.data,
+            Err(step) => return Ok(step),
+        };
+
+        let (mut file_urls, collection) = match entity.entity_type.as_str() {
+            "files" => (file_download_url(&entity).into_iter().collect(), None),
+            "preprints" => {
+                let Some(primary) = &entity.relationships.primary_file else {
+                    return Ok(not_downloadable(input, &guid, "preprint without a file"));
+                };
+                let file = match self
+                    .fetch_json::<OsfDocument<OsfEntity>>(&primary.links.related.href, input, ctx)
+                    .await
+                {
+                    Ok(document) => document.data,
+                    Err(step) => return Ok(step),
+                };
+                (
+                    file_download_url(&file).into_iter().collect(),
+                    Some("preprints"),
+                )
+            }
+            collection @ ("nodes" | "registrations") => {
+                let url = format!(
+                    "{}/v2/{collection}/{guid}/files/osfstorage/",
+                    self.api_base_url
+                );
+                let files = match self
+                    .fetch_json::<OsfDocument<Vec<OsfEntity>>>(&url, input, ctx)
+                    .await
+                {
+                    Ok(document) => document.data,
+                    Err(step) => return Ok(step),
+                };
+                (ranked_file_urls(&files), Some(collection))
+            }
+            other => return Ok(not_downloadable(input, &guid, other)),
+        };
+        if file_urls.is_empty() {
+            return Ok(not_downloadable(
+                input,
+                &guid,
+                &format!("{} without files", entity.entity_type),
+            ));
+        }
+        let primary = file_urls.remove(0);
+
+        let authors = match collection {
+            Some(collection) => self.fetch_contributors(collection, &guid, input, ctx).await,
+            None => Vec::new(),
+        };
+        let metadata = entity_metadata(&entity, &authors);
+
+        Ok(ResolveStep::Url(
+            ResolvedUrl::with_metadata(primary, metadata).with_fallback_urls(file_urls),
+        ))
+    }
+}
+
+// ==================== Helpers ====================
+
+fn not_downloadable(input: &str, guid: &str, what: &str) -> ResolveStep {
+    debug!(guid, what, "OSF guid has nothing to download");
+    ResolveStep::Failed(ResolveError::resolution_failed(
+        input,
+        &format!("OSF guid {guid} is not a downloadable file ({what})"),
+    ))
+}
+
+/// Extracts the OSF guid from an `osf.io` or preprint-provider URL, a
+/// `*/osf.io/<guid>` DOI, or a `doi.org` link to one.
+fn extract_guid(input: &str) -> Option<String> {
+    let trimmed = input.trim();
+    if let Some(guid) = guid_from_doi(trimmed) {
+        return Some(guid);
+    }
+
+    let parsed = Url::parse(trimmed).ok()?;
+    let host = canonical_host(parsed.host_str()?);
+    if DOI_HOSTS.contains(&host.as_str()) {
+        let doi = urlencoding::decode(parsed.path().trim_start_matches('/')).ok()?;
+        return guid_from_doi(&doi);
+    }
+
+    let segments: Vec<&str> = parsed
+        .path_segments()?
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    let candidate = if host == OSF_HOST {
+        match segments.as_slice() {
+            ["download", guid, ..] => *guid,
+            ["preprints", _provider, guid, ..] => *guid,
+            [guid, ..] if !RESERVED_PATHS.contains(guid) => *guid,
+            _ => return None,
+        }
+    } else if PREPRINT_PROVIDER_HOSTS.contains(&host.as_str()) {
+        match segments.as_slice() {
+            ["preprint" | "preprints", guid, ..] | [guid, ..] => *guid,
+            [] => return None,
+        }
+    } else {
+        return None;
+    };
+    normalize_guid(candidate)
+}
+
+/// Preprint DOIs embed the guid: `10.31234/osf.io/abcde`.
+fn guid_from_doi(doi: &str) -> Option<String> {
+    let lowered = doi.trim().to_ascii_lowercase();
+    if !lowered.starts_with("10.") {
+        return None;
+    }
+    let (_, suffix) = lowered.split_once("/osf.io/")?;
+    normalize_guid(suffix.trim_end_matches('/'))
+}
+
+/// OSF guids are five or more lowercase alphanumerics, optionally followed by
+/// a preprint version (`abcde_v2`).
+fn normalize_guid(candidate: &str) -> Option<String> {
+    let lowered = candidate.to_ascii_lowercase();
+    let (base, version) = match lowered.split_once("_v") {
+        Some((base, version)) => (base, Some(version)),
+        None => (lowered.as_str(), None),
+    };
+    let base_ok = base.len() >= 5 && base.bytes().all(|b| b.is_ascii_alphanumeric());
+    let version_ok = version.is_none_or(|v| !v.is_empty() && v.bytes().all(|b| b.is_ascii_digit()));
+    (base_ok && version_ok).then_some(lowered)
+}
+
+fn file_download_url(file: &OsfEntity) -> Option<String> {
+    if file.attributes.kind.as_deref() == Some("folder") {
+        return None;
+    }
+    file.links.download.clone()
+}
+
+/// Orders downloadable file links: the largest PDF first (or the largest file
+/// when there is no PDF), then the remaining files in listing order. Folders
+/// are skipped.
+fn ranked_file_urls(files: &[OsfEntity]) -> Vec<String> {
+    let downloadable: Vec<(&OsfEntity, String)> = files
+        .iter()
+        .filter_map(|file| file_download_url(file).map(|url| (file, url)))
+        .collect();
+
+    let primary_index = downloadable
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, (file, _))| (is_pdf(file), file.attributes.size.unwrap_or(0)))
+        .map(|(index, _)| index);
+
+    let mut urls = Vec::with_capacity(downloadable.len());
+    if let Some(index) = primary_index {
+        urls.push(downloadable[index].1.clone());
+    }
+    urls.extend(
+        downloadable
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| Some(*index) != primary_index)
+            .map(|(_, (_, url))| url.clone()),
+    );
+    urls
+}
+
+fn is_pdf(file: &OsfEntity) -> bool {
+    file.attributes.name.as_deref().is_some_and(|name| {
+        std::path::Path::new(name)
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("pdf"))
+    })
+}
+
+fn entity_metadata(entity: &OsfEntity, authors: &[String]) -> HashMap<String, String> {
+    let mut metadata = HashMap::new();
+    let attributes = &entity.attributes;
+
+    if let Some(title) = attributes.title.as_deref().map(str::trim)
+        && !title.is_empty()
+    {
+        metadata.insert("title".to_string(), title.to_string());
+    }
+    if !authors.is_empty() {
+        metadata.insert("authors".to_string(), authors.join("; "));
+    }
+    if let Some(year) = attributes
+        .date_published
+        .as_deref()
+        .or(attributes.date_registered.as_deref())
+        .or(attributes.date_created.as_deref())
+        .and_then(extract_year_from_str)
+    {
+        metadata.insert("year".to_string(), year);
+    }
+    if let Some(doi) = entity.links.preprint_doi.as_deref().and_then(doi_from_link) {
+        metadata.insert("doi".to_string(), doi);
+    }
+    if let Some(html) = entity.links.html.as_deref() {
+        metadata.insert("source_url".to_string(), html.to_string());
+    }
+
+    metadata
+}
+
+fn doi_from_link(link: &str) -> Option<String> {
+    let parsed = Url::parse(link).ok()?;
+    let doi = urlencoding::decode(parsed.path().trim_start_matches('/')).ok()?;
+    doi.starts_with("10.").then(|| doi.into_owned())
+}
+
+// ==================== Tests ====================
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::test_support::socket_guard::start_mock_server_or_skip;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[test]
+    fn test_extract_guid_from_supported_inputs() {
+        for input in [
+            "https://osf.io/abcde",
+            "https://osf.io/abcde/",
+            "https://osf.io/ABCDE/files/osfstorage",
+            "https://osf.io/download/abcde/",
+            "https://osf.io/preprints/psyarxiv/abcde",
+            "https://osf.io/preprints/socarxiv/abcde/download",
+            "https://psyarxiv.com/abcde",
+            "https://www.socarxiv.org/abcde/download",
+            "10.31234/osf.io/abcde",
+            "https://doi.org/10.31235/osf.io/abcde",
+        ] {
+            assert_eq!(extract_guid(input).as_deref(), Some("abcde"), "{input}");
+        }
+        assert_eq!(
+            extract_guid("https://osf.io/preprints/psyarxiv/abcde_v2").as_deref(),
+            Some("abcde_v2")
+        );
+    }
+
+    #[test]
+    fn test_extract_guid_rejects_other_inputs() {
+        for input in [
+            "https://osf.io/",
+            "https://osf.io/search/",
+            "https://osf.io/preprints/psyarxiv",
+            "https://osf.io/abc",
+            "https://example.com/abcde",
+            "10.5281/zenodo.1234567",
+            "https://doi.org/10.1000/xyz",
+        ] {
+            assert!(extract_guid(input).is_none(), "{input}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_file_guid_returns_download_link() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/v2/guids/fi1e5/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "type": "files",
+                    "attributes": {"name": "data.csv", "kind": "file",
+                                   "date_created": "2020-02-03T00:00:00"},
+                    "links": {"download": "https://osf.io/download/fi1e5/",
+                              "html": "https://osf.io/fi1e5/"}
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = OsfResolver::with_base_url(mock_server.uri()).unwrap();
+        let step = resolver
+            .resolve("https://osf.io/fi1e5/", &ResolveContext::default())
+            .await
+            .unwrap();
+
+        let ResolveStep::Url(result) = step else {
+            panic!("expected ResolveStep::Url, got {step:?}");
+        };
+        assert_eq!(result.url, "https://osf.io/download/fi1e5/");
+        assert!(result.fallback_urls.is_empty());
+        assert_eq!(result.metadata["year"], "2020");
+        assert_eq!(result.metadata["source_url"], "https://osf.io/fi1e5/");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_preprint_uses_primary_file_and_contributors() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        let uri = mock_server.uri();
+        Mock::given(method("GET"))
+            .and(path("/v2/guids/prep1/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "type": "preprints",
+                    "attributes": {"title": "Replication Study",
+                                   "date_published": "2022-05-06T10:00:00"},
+                    "links": {"html": "https://osf.io/preprints/psyarxiv/prep1/",
+                              "preprint_doi": "https://doi.org/10.31234/osf.io/prep1"},
+                    "relationships": {"primary_file": {"links": {"related": {
+                        "href": format!("{uri}/v2/files/file9/")
+                    }}}}
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v2/files/file9/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "type": "files",
+                    "attributes": {"name": "manuscript.pdf", "kind": "file"},
+                    "links": {"download": "https://osf.io/download/file9/"}
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v2/preprints/prep1/contributors/"))
+            .and(query_param("filter[bibliographic]", "true"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    {"embeds": {"users": {"data": {"attributes": {"full_name": "Jane Doe"}}}}},
+                    {"embeds": {"users": {"data": {"attributes": {"full_name": "Rick Roe"}}}}}
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = OsfResolver::with_base_url(uri).unwrap();
+        let step = resolver
+            .resolve("https://psyarxiv.com/prep1", &ResolveContext::default())
+            .await
+            .unwrap();
+
+        let ResolveStep::Url(result) = step else {
+            panic!("expected ResolveStep::Url, got {step:?}");
+        };
+        assert_eq!(result.url, "https://osf.io/download/file9/");
+        assert_eq!(result.metadata["title"], "Replication Study");
+        assert_eq!(result.metadata["authors"], "Jane Doe; Rick Roe");
+        assert_eq!(result.metadata["year"], "2022");
+        assert_eq!(result.metadata["doi"], "10.31234/osf.io/prep1");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_project_ranks_files_into_fallbacks() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/v2/guids/proj1/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "type": "nodes",
+                    "attributes": {"title": "Materials", "date_created": "2019-01-01T00:00:00"}
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v2/nodes/proj1/files/osfstorage/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    {"type": "files", "attributes": {"name": "raw", "kind": "folder"},
+                     "links": {}},
+                    {"type": "files", "attributes": {"name": "data.zip", "kind": "file", "size": 9000},
+                     "links": {"download": "https://osf.io/download/zip01/"}},
+                    {"type": "files", "attributes": {"name": "paper.pdf", "kind": "file", "size": 300},
+                     "links": {"download": "https://osf.io/download/pdf01/"}}
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v2/nodes/proj1/contributors/"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = OsfResolver::with_base_url(mock_server.uri()).unwrap();
+        let step = resolver
+            .resolve("https://osf.io/proj1/", &ResolveContext::default())
+            .await
+            .unwrap();
+
+        let ResolveStep::Url(result) = step else {
+            panic!("expected ResolveStep::Url, got {step:?}");
+        };
+        assert_eq!(result.url, "https://osf.io/download/pdf01/");
+        assert_eq!(result.fallback_urls, vec!["https://osf.io/download/zip01/"]);
+        assert_eq!(result.metadata["title"], "Materials");
+        assert!(!result.metadata.contains_key("authors"));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_non_file_guid_fails_softly() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/v2/guids/user1/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"type": "users", "attributes": {"full_name": "Jane Doe"}}
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v2/guids/gone1/"))
+            .respond_with(ResponseTemplate::new(410))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = OsfResolver::with_base_url(mock_server.uri()).unwrap();
+        for input in ["https://osf.io/user1/", "https://osf.io/gone1/"] {
+            let step = resolver
+                .resolve(input, &ResolveContext::default())
+                .await
+                .unwrap();
+            assert!(
+                matches!(step, ResolveStep::Failed(_)),
+                "expected soft failure for {input}, got {step:?}"
+            );
+        }
+    }
+}
@@ -1,4 +1,9 @@
 //! `PubMed` resolver for routing `PubMed` records to `PMC` full-text PDF URLs.
+//!
+//! A PMID identifies the article record; a PMCID identifies its open-access
+//! full text. PMIDs are mapped to PMCIDs through the NCBI E-utilities `elink`
+//! endpoint (`pubmed_pmc` link set) so the PMC PDF is preferred, falling back
+//! to scraping the `PubMed` record page when E-utilities is unreachable.
 
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -9,11 +14,13 @@ use regex::Regex;
 use reqwest::Client;
 use reqwest::cookie::Jar;
 use reqwest::header::ACCEPT;
+use serde::Deserialize;
+use tracing::{debug, warn};
 use url::Url;
 
-use crate::parser::{BARE_PMC_ID_PATTERN, InputType};
+use crate::parser::{BARE_PMC_ID_PATTERN, InputType, PMCID_PREFIX};
 
-use super::http_client::{build_resolver_http_client, standard_user_agent};
+use super::http_client::{build_resolver_http_client, send_with_retry, standard_user_agent};
 use super::utils::{
     CITATION_PDF_RE, absolutize_url, compile_static_regex, hosts_match, parse_host_or_fallback,
 };
@@ -21,11 +28,15 @@ use super::{ResolveContext, ResolveError, ResolveStep, ResolvedUrl, Resolver, Re
 
 const DEFAULT_PUBMED_BASE_URL: &str = "https://pubmed.ncbi.nlm.nih.gov";
 const DEFAULT_PMC_BASE_URL: &str = "https://pmc.ncbi.nlm.nih.gov";
+const DEFAULT_EUTILS_BASE_URL: &str = "https://eutils.ncbi.nlm.nih.gov/entrez/eutils";
+/// `elink` link set from a `PubMed` record to its own PMC full text (not citing articles).
+const PUBMED_PMC_LINKNAME: &str = "pubmed_pmc";
 
 static PMCID_RE: LazyLock<Regex> = LazyLock::new(|| compile_static_regex(r"(?i)\b(PMC\d{4,})\b"));
-/// Matches a bare PMC identifier as the whole input (e.g. "PMC1234567").
+/// Matches a PMC identifier as the whole input (e.g. "PMC1234567"), once any
+/// `pmcid:` prefix is stripped.
 static BARE_PMCID_RE: LazyLock<Regex> = LazyLock::new(|| compile_static_regex(BARE_PMC_ID_PATTERN));
-/// Matches the normalised PMID form emitted by the parser ("PMID:12345678").
+/// Matches the normalised PMID form emitted by the parser ("pmid:12345678").
 static BARE_PMID_RE: LazyLock<Regex> =
     LazyLock::new(|| compile_static_regex(r"(?i)^PMID:(\d{1,9})$"));
 static PDF_LINK_RE: LazyLock<Regex> = LazyLock::new(|| {
@@ -37,6 +48,7 @@ pub struct PubMedResolver {
     client: Client,
     pubmed_base_url: String,
     pmc_base_url: String,
+    eutils_base_url: String,
     pubmed_host: String,
     pmc_host: String,
 }
@@ -74,8 +86,17 @@ impl PubMedResolver {
             pmc_host: parse_host_or_fallback(&pmc_base_url),
             pubmed_base_url,
             pmc_base_url,
+            eutils_base_url: DEFAULT_EUTILS_BASE_URL.to_string(),
         })
     }
+
+    /// Overrides the NCBI E-utilities base URL used for PMID lookups (for
+    /// testing with wiremock).
+    #[must_use]
+    pub fn with_eutils_base_url(mut self, eutils_base_url: impl Into<String>) -> Self {
+        self.eutils_base_url = eutils_base_url.into();
+        self
+    }
 }
 
 impl std::fmt::Debug for PubMedResolver {
@@ -83,6 +104,7 @@ impl std::fmt::Debug for PubMedResolver {
         f.debug_struct("PubMedResolver")
             .field("pubmed_base_url", &self.pubmed_base_url)
             .field("pmc_base_url", &self.pmc_base_url)
+            .field("eutils_base_url", &self.eutils_base_url)
             .finish_non_exhaustive()
     }
 }
@@ -98,11 +120,11 @@ impl Resolver for PubMedResolver {
     }
 
     fn can_handle(&self, input: &str, input_type: InputType) -> bool {
-        // Accept bare PMC IDs ("PMC1234567") and PMID-prefixed inputs ("PMID:12345678")
-        // that the parser emits as InputType::Unknown.
+        // Accept the PMC IDs ("pmcid:PMC1234567", or bare "PMC1234567") and PMIDs
+        // ("pmid:12345678") that the parser emits as InputType::Unknown.
         if input_type == InputType::Unknown {
             let trimmed = input.trim();
-            return BARE_PMCID_RE.is_match(trimmed) || BARE_PMID_RE.is_match(trimmed);
+            return bare_pmcid(trimmed).is_some() || BARE_PMID_RE.is_match(trimmed);
         }
 
         if input_type != InputType::Url {
@@ -123,18 +145,17 @@ impl Resolver for PubMedResolver {
         hosts_match(host, &self.pubmed_host)
     }
 
-    #[tracing::instrument(skip(self, _ctx), fields(resolver = "pubmed", input = %input))]
+    #[tracing::instrument(skip(self, ctx), fields(resolver = "pubmed", input = %input))]
     async fn resolve(
         &self,
         input: &str,
-        _ctx: &ResolveContext,
+        ctx: &ResolveContext,
     ) -> Result<ResolveStep, ResolveError> {
         let trimmed = input.trim();
 
-        // Bare PMC ID (e.g. "PMC1234567") → redirect to the PMC article page,
+        // PMC ID (e.g. "pmcid:PMC1234567") → redirect to the PMC article page,
         // which the URL-based branch below then resolves to a PDF.
-        if BARE_PMCID_RE.is_match(trimmed) {
-            let pmcid = trimmed.to_ascii_uppercase();
+        if let Some(pmcid) = bare_pmcid(trimmed) {
             let redirect_url = format!(
                 "{}/articles/{}/",
                 self.pmc_base_url.trim_end_matches('/'),
@@ -143,11 +164,9 @@ impl Resolver for PubMedResolver {
             return Ok(ResolveStep::Redirect(redirect_url));
         }
 
-        // PMID with prefix (e.g. "PMID:12345678") → redirect to the PubMed record.
+        // PMID with prefix (e.g. "pmid:12345678") → PMC full text via E-utilities.
         if let Some(caps) = BARE_PMID_RE.captures(trimmed) {
-            let pmid = &caps[1];
-            let redirect_url = format!("{}/{}/", self.pubmed_base_url.trim_end_matches('/'), pmid);
-            return Ok(ResolveStep::Redirect(redirect_url));
+            return self.resolve_pmid(input, &caps[1], ctx).await;
         }
 
         let Ok(parsed) = Url::parse(input) else {
@@ -169,15 +188,14 @@ impl Resolver for PubMedResolver {
             )));
         }
 
-        let Ok(pubmed_response) = self
-            .client
-            .get(input)
-            .header(
+        let Ok(pubmed_response) = send_with_retry(
+            self.client.get(input).header(
                 ACCEPT,
                 "text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8",
-            )
-            .send()
-            .await
+            ),
+            "pubmed",
+        )
+        .await
         else {
             return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
                 input,
@@ -212,6 +230,70 @@ impl Resolver for PubMedResolver {
 }
 
 impl PubMedResolver {
+    async fn resolve_pmid(
+        &self,
+        input: &str,
+        pmid: &str,
+        ctx: &ResolveContext,
+    ) -> Result<ResolveStep, ResolveError> {
+        let record_url = format!("{}/{}/", self.pubmed_base_url.trim_end_matches('/'), pmid);
+
+        match self.lookup_pmcid(pmid, ctx).await {
+            Ok(Some(pmc_id)) => {
+                let Ok(source_url) = Url::parse(&record_url) else {
+                    return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                        input,
+                        "PubMed base URL is not a valid URL",
+                    )));
+                };
+                self.resolve_pmcid_from_pubmed(&pmc_id, &source_url).await
+            }
+            Ok(None) => Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                &format!(
+                    "PMID {pmid} has no PMC full text; try the article DOI or publisher URL instead"
+                ),
+            ))),
+            Err(reason) => {
+                warn!(
+                    pmid,
+                    reason,
+                    "E-utilities PMID lookup failed; falling back to the PubMed record page"
+                );
+                Ok(ResolveStep::Redirect(record_url))
+            }
+        }
+    }
+
+    /// Looks up the PMCID linked to `pmid`; `Ok(None)` means there is no PMC full text.
+    async fn lookup_pmcid(
+        &self,
+        pmid: &str,
+        ctx: &ResolveContext,
+    ) -> Result<Option<String>, &'static str> {
+        let url = format!(
+            "{}/elink.fcgi?dbfrom=pubmed&db=pmc&linkname={PUBMED_PMC_LINKNAME}&retmode=json&id={pmid}",
+            self.eutils_base_url.trim_end_matches('/')
+        );
+        ctx.throttle(&url).await;
+        debug!(elink_url = %url, "Looking up PMC full text for PMID");
+
+        let response = send_with_retry(
+            self.client.get(&url).header(ACCEPT, "application/json"),
+            "pubmed",
+        )
+        .await
+        .map_err(|_| "request failed")?;
+        if !response.status().is_success() {
+            return Err("non-success status");
+        }
+        let body = response
+            .json::<ELinkResponse>()
+            .await
+            .map_err(|_| "unexpected response format")?;
+        Ok(pmcid_from_elink(&body))
+    }
+
     async fn resolve_pmc_url(&self, input: &str, parsed: Url) -> Result<ResolveStep, ResolveError> {
         if looks_like_direct_pdf_path(parsed.path()) {
             let mut metadata = HashMap::new();
@@ -247,15 +329,14 @@ impl PubMedResolver {
             pmcid
         );
 
-        let Ok(response) = self
-            .client
-            .get(&pmc_article)
-            .header(
+        let Ok(response) = send_with_retry(
+            self.client.get(&pmc_article).header(
                 ACCEPT,
                 "text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8",
-            )
-            .send()
-            .await
+            ),
+            "pubmed",
+        )
+        .await
         else {
             return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
                 &pmc_article,
@@ -303,6 +384,48 @@ impl PubMedResolver {
     }
 }
 
+/// The subset of an E-utilities `elink` JSON response used for PMID lookups.
+#[derive(Debug, Deserialize)]
+struct ELinkResponse {
+    #[serde(default)]
+    linksets: Vec<ELinkSet>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ELinkSet {
+    #[serde(default)]
+    linksetdbs: Vec<ELinkSetDb>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ELinkSetDb {
+    linkname: String,
+    #[serde(default)]
+    links: Vec<String>,
+}
+
+/// Returns the first `pubmed_pmc` link as a `PMC`-prefixed identifier.
+fn pmcid_from_elink(response: &ELinkResponse) -> Option<String> {
+    response
+        .linksets
+        .iter()
+        .flat_map(|linkset| &linkset.linksetdbs)
+        .filter(|db| db.linkname == PUBMED_PMC_LINKNAME)
+        .flat_map(|db| &db.links)
+        .map(|link| link.trim())
+        .find(|link| !link.is_empty() && link.chars().all(|c| c.is_ascii_digit()))
+        .map(|id| format!("PMC{id}"))
+}
+
+/// `pmcid:PMC1234567` or `PMC1234567` → `PMC1234567`.
+fn bare_pmcid(value: &str) -> Option<String> {
+    let id = match value.get(..PMCID_PREFIX.len()) {
+        Some(prefix) if prefix.eq_ignore_ascii_case(PMCID_PREFIX) => &value[PMCID_PREFIX.len()..],
+        _ => value,
+    };
+    BARE_PMCID_RE.is_match(id).then(|| id.to_ascii_uppercase())
+}
+
 fn extract_pmcid(value: &str) -> Option<String> {
     PMCID_RE
         .captures(value)
@@ -345,6 +468,9 @@ fn extract_pmid(url: &Url) -> Option<String> {
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
+    use crate::test_support::socket_guard::start_mock_server_or_skip;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
 
     #[test]
     fn test_pubmed_can_handle_pubmed_and_pmc_hosts() {
@@ -377,6 +503,8 @@ mod tests {
         assert!(resolver.can_handle("pmc9999999", InputType::Unknown));
         assert!(!resolver.can_handle("1234567", InputType::Unknown)); // bare number not accepted
         assert!(!resolver.can_handle("PMC123", InputType::Unknown)); // too short (< 4 digits)
+        assert!(resolver.can_handle("pmcid:PMC1234567", InputType::Unknown));
+        assert!(!resolver.can_handle("pmcid:1234567", InputType::Unknown)); // parser adds "PMC"
     }
 
     #[test]
@@ -396,7 +524,7 @@ mod tests {
         )
         .unwrap();
         let ctx = ResolveContext::default();
-        let step = resolver.resolve("PMC1234567", &ctx).await.unwrap();
+        let step = resolver.resolve("pmcid:PMC1234567", &ctx).await.unwrap();
         match step {
             ResolveStep::Redirect(url) => {
                 assert_eq!(url, "https://pmc.ncbi.nlm.nih.gov/articles/PMC1234567/");
@@ -427,15 +555,25 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_pubmed_resolve_pmid_prefix_redirects() {
+    async fn test_pubmed_resolve_pmid_prefix_redirects_when_eutils_unavailable() {
+        let Some(server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/elink.fcgi"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
         let resolver = PubMedResolver::with_base_urls(
             None,
             "https://pubmed.ncbi.nlm.nih.gov",
             "https://pmc.ncbi.nlm.nih.gov",
         )
-        .unwrap();
+        .unwrap()
+        .with_eutils_base_url(server.uri());
         let ctx = ResolveContext::default();
-        let step = resolver.resolve("PMID:12345678", &ctx).await.unwrap();
+        let step = resolver.resolve("pmid:12345678", &ctx).await.unwrap();
         match step {
             ResolveStep::Redirect(url) => {
                 assert_eq!(url, "https://pubmed.ncbi.nlm.nih.gov/12345678/");
@@ -444,6 +582,78 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_pubmed_resolve_pmid_prefers_pmc_pdf_via_eutils() {
+        let Some(server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/elink.fcgi"))
+            .and(query_param("id", "31452104"))
+            .and(query_param("linkname", "pubmed_pmc"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                r#"{"linksets": [{"dbfrom": "pubmed", "ids": ["31452104"], "linksetdbs": [
+                    {"dbto": "pmc", "linkname": "pubmed_pmc_refs", "links": ["1111111"]},
+                    {"dbto": "pmc", "linkname": "pubmed_pmc", "links": ["6716437"]}
+                ]}]}"#,
+                "application/json",
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/articles/PMC6716437/"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                r#"<meta name="citation_pdf_url" content="/articles/PMC6716437/pdf/main.pdf">"#,
+                "text/html",
+            ))
+            .mount(&server)
+            .await;
+
+        let resolver = PubMedResolver::with_base_urls(None, server.uri(), server.uri())
+            .unwrap()
+            .with_eutils_base_url(server.uri());
+        let ctx = ResolveContext::default();
+        for input in ["PMID:31452104", "pmid:31452104"] {
+            let step = resolver.resolve(input, &ctx).await.unwrap();
+            let ResolveStep::Url(resolved) = step else {
+                panic!("expected Url for {input}, got {step:?}");
+            };
+            assert_eq!(
+                resolved.url,
+                format!("{}/articles/PMC6716437/pdf/main.pdf", server.uri())
+            );
+            assert_eq!(resolved.metadata["pmid"], "31452104");
+            assert_eq!(resolved.metadata["pmcid"], "PMC6716437");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pubmed_resolve_pmid_without_pmc_full_text_fails() {
+        let Some(server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/elink.fcgi"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                r#"{"linksets": [{"dbfrom": "pubmed", "ids": ["12345678"]}]}"#,
+                "application/json",
+            ))
+            .mount(&server)
+            .await;
+
+        let resolver = PubMedResolver::with_base_urls(None, server.uri(), server.uri())
+            .unwrap()
+            .with_eutils_base_url(server.uri());
+        let step = resolver
+            .resolve("PMID:12345678", &ResolveContext::default())
+            .await
+            .unwrap();
+        let ResolveStep::Failed(error) = step else {
+            panic!("expected Failed, got {step:?}");
+        };
+        assert!(error.to_string().contains("no PMC full text"), "{error}");
+    }
+
     #[test]
     fn test_extract_pdf_url_prefers_citation_meta() {
         let html = r#"
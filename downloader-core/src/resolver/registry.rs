@@ -3,11 +3,21 @@
 //! The [`ResolverRegistry`] manages a collection of resolvers and orchestrates
 //! the resolution loop, including fallback chains and redirect handling.
 
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use futures_util::future::join_all;
+use tokio::sync::Semaphore;
 use tracing::{debug, info, warn};
 
 use crate::parser::InputType;
 
-use super::{ResolveContext, ResolveError, ResolveStep, ResolvedUrl, Resolver};
+use super::http_client::resolver_call_budget;
+use super::{
+    CrossrefResolver, RESOLVER_PATH_SEPARATOR, ResolveContext, ResolveError, ResolveStep,
+    ResolvedUrl, Resolver,
+};
 
 /// A priority-ordered collection of resolvers with resolution loop.
 ///
@@ -16,6 +26,12 @@ use super::{ResolveContext, ResolveError, ResolveStep, ResolvedUrl, Resolver};
 /// registration order.
 pub struct ResolverRegistry {
     resolvers: Vec<Box<dyn Resolver>>,
+    /// Per-resolver wall-clock limit for a single `resolve` call, keyed by name.
+    call_timeouts: HashMap<String, Duration>,
+    /// Optional Crossref lookup that fills metadata gaps after a successful resolve.
+    metadata_enricher: Option<CrossrefResolver>,
+    /// Names of resolvers skipped by the resolution loop, even when they can handle the input.
+    disabled: HashSet<String>,
 }
 
 impl ResolverRegistry {
@@ -24,10 +40,17 @@ impl ResolverRegistry {
     pub fn new() -> Self {
         Self {
             resolvers: Vec::new(),
+            call_timeouts: HashMap::new(),
+            metadata_enricher: None,
+            disabled: HashSet::new(),
         }
     }
 
     /// Registers a resolver with the registry.
+    ///
+    /// If a read-timeout override is configured for the resolver's name (see
+    /// [`configure_resolver_timeout_overrides`](super::configure_resolver_timeout_overrides)),
+    /// each of its `resolve` calls is bounded accordingly.
     #[tracing::instrument(skip(self, resolver), fields(resolver_name))]
     pub fn register(&mut self, resolver: Box<dyn Resolver>) {
         tracing::Span::current().record("resolver_name", resolver.name());
@@ -36,9 +59,85 @@ impl ResolverRegistry {
             priority = ?resolver.priority(),
             "Registering resolver"
         );
+        if let Some(budget) = resolver_call_budget(resolver.name()) {
+            self.call_timeouts
+                .insert(resolver.name().to_string(), budget);
+        }
         self.resolvers.push(resolver);
     }
 
+    /// Bounds every `resolve` call of the named resolver to `timeout`.
+    ///
+    /// A call that runs past the limit is treated as [`ResolveStep::Failed`]
+    /// and the next handler is tried.
+    pub fn set_resolver_timeout(&mut self, resolver_name: &str, timeout: Duration) {
+        self.call_timeouts
+            .insert(resolver_name.to_string(), timeout);
+    }
+
+    /// Disables the named resolver for this registry.
+    ///
+    /// A disabled resolver stays registered but is never returned by
+    /// [`find_handlers`](Self::find_handlers), so the resolution loop skips it
+    /// even when its `can_handle` matches. Names are matched case-insensitively
+    /// against [`Resolver::name`].
+    pub fn disable(&mut self, resolver_name: &str) {
+        self.set_enabled(resolver_name, false);
+    }
+
+    /// Enables or disables the named resolver for this registry.
+    ///
+    /// `set_enabled(name, false)` is [`disable`](Self::disable); passing
+    /// `true` puts a disabled resolver back into the resolution loop. Names
+    /// are matched case-insensitively, and unregistered names are accepted so
+    /// a resolver can be disabled before it is registered.
+    pub fn set_enabled(&mut self, resolver_name: &str, enabled: bool) {
+        let name = resolver_name.trim().to_ascii_lowercase();
+        if enabled {
+            self.disabled.remove(&name);
+        } else {
+            self.disabled.insert(name);
+        }
+    }
+
+    /// Returns true if the named resolver has been disabled.
+    #[must_use]
+    pub fn is_disabled(&self, resolver_name: &str) -> bool {
+        self.disabled
+            .contains(&resolver_name.trim().to_ascii_lowercase())
+    }
+
+    /// Returns true if a resolver with this name is registered.
+    #[must_use]
+    pub fn has_resolver(&self, resolver_name: &str) -> bool {
+        self.resolvers
+            .iter()
+            .any(|resolver| resolver.name().eq_ignore_ascii_case(resolver_name.trim()))
+    }
+
+    /// Returns the names of registered, non-disabled resolvers in priority order.
+    #[must_use]
+    pub fn active_resolver_names(&self) -> Vec<&str> {
+        let mut active: Vec<&dyn Resolver> = self
+            .resolvers
+            .iter()
+            .map(AsRef::as_ref)
+            .filter(|resolver| !self.is_disabled(resolver.name()))
+            .collect();
+        active.sort_by_key(|resolver| resolver.priority());
+        active.into_iter().map(Resolver::name).collect()
+    }
+
+    /// Enables Crossref metadata enrichment for successful resolutions.
+    ///
+    /// When a resolved result carries a `doi` metadata key, `enricher` fetches
+    /// the Crossref record for it and any keys the primary resolver did not set
+    /// are filled in. The resolved URL is never changed, and enrichment failures
+    /// are logged and ignored. Costs one extra Crossref request per item.
+    pub fn set_metadata_enricher(&mut self, enricher: CrossrefResolver) {
+        self.metadata_enricher = Some(enricher);
+    }
+
     /// Returns the number of registered resolvers.
     #[must_use]
     pub fn resolver_count(&self) -> usize {
@@ -55,13 +154,14 @@ impl ResolverRegistry {
     ///
     /// Resolvers are returned in priority order: Specialized first, then General,
     /// then Fallback. Within the same priority level, registration order is preserved.
+    /// Disabled resolvers (see [`disable`](Self::disable)) are never returned.
     #[must_use]
     #[tracing::instrument(skip(self), fields(input_type = ?input_type))]
     pub fn find_handlers(&self, input: &str, input_type: InputType) -> Vec<&dyn Resolver> {
         let mut handlers: Vec<&dyn Resolver> = self
             .resolvers
             .iter()
-            .filter(|r| r.can_handle(input, input_type))
+            .filter(|r| !self.is_disabled(r.name()) && r.can_handle(input, input_type))
             .map(AsRef::as_ref)
             .collect();
         handlers.sort_by_key(|r| r.priority());
@@ -73,7 +173,8 @@ impl ResolverRegistry {
     /// This method orchestrates the full resolution process:
     /// 1. Finds all applicable resolvers via `find_handlers()`
     /// 2. Tries each in priority order
-    /// 3. On `ResolveStep::Url` → returns success
+    /// 3. On `ResolveStep::Url` → returns success, recording the resolvers that
+    ///    fired in [`ResolvedUrl::resolver_path`]
     /// 4. On `ResolveStep::Redirect` → follows redirect with new handlers
     /// 5. On `ResolveStep::NeedsAuth` → returns `AuthRequired` error
     /// 6. On `ResolveStep::Failed` → tries next resolver
@@ -95,6 +196,7 @@ impl ResolverRegistry {
         let mut current_input = input.to_string();
         let mut current_type = input_type;
         let mut redirect_count: usize = 0;
+        let mut resolver_path: Vec<String> = Vec::new();
 
         loop {
             let handlers = self.find_handlers(&current_input, current_type);
@@ -120,8 +222,12 @@ impl ResolverRegistry {
                     "Trying resolver"
                 );
 
-                match handler.resolve(&current_input, ctx).await {
-                    Ok(ResolveStep::Url(resolved)) => {
+                match self.invoke(*handler, &current_input, ctx).await {
+                    Ok(ResolveStep::Url(mut resolved)) => {
+                        resolver_path.push(handler.name().to_string());
+                        resolved.resolver = Some(handler.name().to_string());
+                        resolved.resolver_path = Some(resolver_path.join(RESOLVER_PATH_SEPARATOR));
+                        self.enrich_metadata(&mut resolved, ctx).await;
                         info!(
                             resolver = handler.name(),
                             url = %resolved.url,
@@ -130,6 +236,7 @@ impl ResolverRegistry {
                         return Ok(resolved);
                     }
                     Ok(ResolveStep::Redirect(new_url)) => {
+                        resolver_path.push(handler.name().to_string());
                         redirect_count += 1;
                         if redirect_count > ctx.max_redirects {
                             return Err(ResolveError::too_many_redirects(input, redirect_count));
@@ -175,6 +282,100 @@ impl ResolverRegistry {
             return Err(ResolveError::all_failed(input, tried_count));
         }
     }
+
+    /// Fills metadata gaps in `resolved` from Crossref when enrichment is enabled.
+    ///
+    /// Keys set by the primary resolver always win. Results already produced by
+    /// the Crossref resolver are left alone since they hold the same record.
+    async fn enrich_metadata(&self, resolved: &mut ResolvedUrl, ctx: &ResolveContext) {
+        let Some(enricher) = &self.metadata_enricher else {
+            return;
+        };
+        if resolved.resolver.as_deref() == Some(enricher.name()) {
+            return;
+        }
+        let Some(doi) = resolved.metadata.get("doi").cloned() else {
+            return;
+        };
+
+        match enricher.fetch_metadata(&doi, ctx).await {
+            Ok(extra) => {
+                let mut filled: usize = 0;
+                for (key, value) in extra {
+                    if let Entry::Vacant(slot) = resolved.metadata.entry(key) {
+                        slot.insert(value);
+                        filled += 1;
+                    }
+                }
+                debug!(doi = %doi, filled, "Enriched metadata from Crossref");
+            }
+            Err(err) => {
+                debug!(doi = %doi, error = %err, "Crossref metadata enrichment skipped");
+            }
+        }
+    }
+
+    /// Calls `handler.resolve`, applying its configured call timeout if any.
+    async fn invoke(
+        &self,
+        handler: &dyn Resolver,
+        input: &str,
+        ctx: &ResolveContext,
+    ) -> Result<ResolveStep, ResolveError> {
+        let Some(&limit) = self.call_timeouts.get(handler.name()) else {
+            return handler.resolve(input, ctx).await;
+        };
+        if let Ok(outcome) = tokio::time::timeout(limit, handler.resolve(input, ctx)).await {
+            outcome
+        } else {
+            warn!(
+                resolver = handler.name(),
+                timeout_secs = limit.as_secs_f64(),
+                "Resolver timed out"
+            );
+            Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                &format!(
+                    "{name} resolver timed out after {secs:.1}s. \
+                     Why: the host is slow or unresponsive. \
+                     Fix: raise resolver_timeouts.{name}_read_secs in config.",
+                    name = handler.name(),
+                    secs = limit.as_secs_f64()
+                ),
+            )))
+        }
+    }
+
+    /// Resolves many inputs concurrently, returning results in input order.
+    ///
+    /// At most `concurrency` inputs (minimum 1) are in flight at once, bounded
+    /// by a [`Semaphore`]. Every input shares this registry's resolvers (and so
+    /// their HTTP clients and cookie jar) and `ctx`, so a rate limiter attached
+    /// via [`ResolveContext::with_rate_limiter`] spaces requests per host across
+    /// all in-flight resolutions.
+    pub async fn resolve_many(
+        &self,
+        inputs: &[(String, InputType)],
+        concurrency: usize,
+        ctx: &ResolveContext,
+    ) -> Vec<Result<ResolvedUrl, ResolveError>> {
+        let semaphore = Semaphore::new(concurrency.max(1));
+        debug!(
+            input_count = inputs.len(),
+            concurrency = concurrency.max(1),
+            "Resolving inputs concurrently"
+        );
+
+        join_all(inputs.iter().map(|(input, input_type)| {
+            let semaphore = &semaphore;
+            async move {
+                // The semaphore is never closed, so acquire cannot fail.
+                let _permit = semaphore.acquire().await.ok();
+                self.resolve_to_url(input, *input_type, ctx).await
+            }
+        }))
+        .await
+    }
 }
 
 impl std::fmt::Debug for ResolverRegistry {
@@ -183,6 +384,9 @@ impl std::fmt::Debug for ResolverRegistry {
         f.debug_struct("ResolverRegistry")
             .field("resolver_count", &self.resolvers.len())
             .field("resolvers", &names)
+            .field("call_timeouts", &self.call_timeouts)
+            .field("metadata_enrichment", &self.metadata_enricher.is_some())
+            .field("disabled", &self.disabled)
             .finish()
     }
 }
@@ -201,7 +405,11 @@ mod tests {
         AuthRequirement, ResolveContext, ResolveError, ResolveStep, ResolvedUrl, Resolver,
         ResolverPriority,
     };
+    use crate::test_support::socket_guard::start_mock_server_or_skip;
     use async_trait::async_trait;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
 
     // ==================== MockResolver for Testing ====================
 
@@ -423,8 +631,68 @@ mod tests {
         let result = registry
             .resolve_to_url("https://example.com", InputType::Url, &ctx)
             .await;
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap().url, "https://example.com/resolved.pdf");
+        let matched = result.unwrap();
+        assert_eq!(matched.url, "https://example.com/resolved.pdf");
+        assert_eq!(matched.resolver.as_deref(), Some("fallback"));
+    }
+
+    #[tokio::test]
+    async fn test_registry_skips_disabled_resolver() {
+        let mut registry = ResolverRegistry::new();
+        registry.register(Box::new(mock_url_resolver(
+            "outage",
+            ResolverPriority::Specialized,
+            "https://example.com/stalled.pdf",
+        )));
+        registry.register(Box::new(mock_url_resolver(
+            "fallback",
+            ResolverPriority::Fallback,
+            "https://example.com/resolved.pdf",
+        )));
+
+        registry.disable("Outage");
+
+        assert!(registry.is_disabled("outage"));
+        assert!(registry.has_resolver("outage"));
+        assert_eq!(registry.active_resolver_names(), ["fallback"]);
+        assert_eq!(
+            registry
+                .find_handlers("https://example.com", InputType::Url)
+                .len(),
+            1
+        );
+        let resolved = registry
+            .resolve_to_url(
+                "https://example.com",
+                InputType::Url,
+                &ResolveContext::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resolved.resolver.as_deref(), Some("fallback"));
+    }
+
+    #[test]
+    fn test_set_enabled_toggles_resolver_back_on() {
+        let mut registry = ResolverRegistry::new();
+        registry.register(Box::new(mock_url_resolver(
+            "ieee",
+            ResolverPriority::Specialized,
+            "https://example.com/ieee.pdf",
+        )));
+        registry.register(Box::new(mock_url_resolver(
+            "fallback",
+            ResolverPriority::Fallback,
+            "https://example.com/resolved.pdf",
+        )));
+
+        registry.set_enabled("IEEE", false);
+        assert!(registry.is_disabled("ieee"));
+        assert_eq!(registry.active_resolver_names(), ["fallback"]);
+
+        registry.set_enabled("ieee", true);
+        assert!(!registry.is_disabled("ieee"));
+        assert_eq!(registry.active_resolver_names(), ["ieee", "fallback"]);
     }
 
     #[tokio::test]
@@ -452,6 +720,37 @@ mod tests {
         assert_eq!(result.unwrap().url, "https://example.com/paper.pdf");
     }
 
+    #[tokio::test]
+    async fn test_registry_resolve_to_url_records_resolver_path() {
+        let mut registry = ResolverRegistry::new();
+        registry.register(Box::new(mock_redirect_resolver(
+            "doi-resolver",
+            ResolverPriority::General,
+            "https://example.com/paper.pdf",
+        )));
+        // Failed attempts are not part of the path.
+        registry.register(Box::new(mock_failing_resolver(
+            "site-resolver",
+            ResolverPriority::Specialized,
+            vec![InputType::Url],
+        )));
+        registry.register(Box::new(mock_url_resolver(
+            "url-handler",
+            ResolverPriority::Fallback,
+            "https://example.com/paper.pdf",
+        )));
+
+        let resolved = registry
+            .resolve_to_url("10.1234/test", InputType::Doi, &ResolveContext::default())
+            .await
+            .unwrap();
+        assert_eq!(resolved.resolver.as_deref(), Some("url-handler"));
+        assert_eq!(
+            resolved.resolver_path.as_deref(),
+            Some("doi-resolver->url-handler")
+        );
+    }
+
     #[tokio::test]
     async fn test_registry_resolve_to_url_too_many_redirects() {
         let mut registry = ResolverRegistry::new();
@@ -531,4 +830,268 @@ mod tests {
             err
         );
     }
+
+    // ==================== Metadata enrichment ====================
+
+    /// Mounts a Crossref works record and returns an enricher for it.
+    ///
+    /// Crossref URLs carry the DOI percent-encoded, so the mock matches any DOI path.
+    async fn crossref_enricher(server: &wiremock::MockServer) -> CrossrefResolver {
+        use wiremock::matchers::{method, path_regex};
+        use wiremock::{Mock, ResponseTemplate};
+
+        Mock::given(method("GET"))
+            .and(path_regex(r"/works/10\..+"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "ok",
+                "message": {
+                    "DOI": "10.1234/enrich",
+                    "title": ["Crossref Title"],
+                    "container-title": ["Journal of Tests"],
+                    "volume": "12",
+                    "published": {"date-parts": [[2021]]}
+                }
+            })))
+            .mount(server)
+            .await;
+        CrossrefResolver::with_base_url("test@example.com", server.uri()).unwrap()
+    }
+
+    fn doi_metadata_resolver(doi: &str) -> MockResolver {
+        let metadata = HashMap::from([
+            ("doi".to_string(), doi.to_string()),
+            ("title".to_string(), "Primary Title".to_string()),
+        ]);
+        MockResolver {
+            mock_name: "primary",
+            mock_priority: ResolverPriority::Specialized,
+            handles: vec![InputType::Url],
+            step: ResolveStep::Url(ResolvedUrl::with_metadata(
+                "https://example.com/paper.pdf",
+                metadata,
+            )),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_enrichment_fills_gaps_without_overriding_primary() {
+        let Some(server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        let mut registry = ResolverRegistry::new();
+        registry.register(Box::new(doi_metadata_resolver("10.1234/enrich")));
+        registry.set_metadata_enricher(crossref_enricher(&server).await);
+
+        let resolved = registry
+            .resolve_to_url(
+                "https://example.com/abs",
+                InputType::Url,
+                &ResolveContext::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(resolved.url, "https://example.com/paper.pdf");
+        assert_eq!(resolved.resolver.as_deref(), Some("primary"));
+        assert_eq!(resolved.metadata["title"], "Primary Title");
+        assert_eq!(resolved.metadata["journal"], "Journal of Tests");
+        assert_eq!(resolved.metadata["volume"], "12");
+        assert_eq!(resolved.metadata["year"], "2021");
+    }
+
+    #[tokio::test]
+    async fn test_enrichment_failure_keeps_primary_result() {
+        let Some(server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        let mut registry = ResolverRegistry::new();
+        registry.register(Box::new(doi_metadata_resolver("10.1234/unknown")));
+        // No record is mounted, so the enrichment lookup answers 404.
+        registry.set_metadata_enricher(
+            CrossrefResolver::with_base_url("test@example.com", server.uri()).unwrap(),
+        );
+
+        let resolved = registry
+            .resolve_to_url(
+                "https://example.com/abs",
+                InputType::Url,
+                &ResolveContext::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(resolved.url, "https://example.com/paper.pdf");
+        assert_eq!(resolved.metadata.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_enrichment_disabled_by_default() {
+        let Some(server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        let _enricher = crossref_enricher(&server).await;
+        let mut registry = ResolverRegistry::new();
+        registry.register(Box::new(doi_metadata_resolver("10.1234/enrich")));
+
+        let resolved = registry
+            .resolve_to_url(
+                "https://example.com/abs",
+                InputType::Url,
+                &ResolveContext::default(),
+            )
+            .await
+            .unwrap();
+
+        assert!(!resolved.metadata.contains_key("journal"));
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+
+    // ==================== resolve_many ====================
+
+    /// Echoes its input as a URL after a delay that shrinks with the input's
+    /// index (so later inputs finish first), tracking peak concurrency.
+    struct EchoResolver {
+        in_flight: Arc<AtomicUsize>,
+        peak_in_flight: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Resolver for EchoResolver {
+        fn name(&self) -> &'static str {
+            "echo"
+        }
+
+        fn priority(&self) -> ResolverPriority {
+            ResolverPriority::General
+        }
+
+        fn can_handle(&self, _input: &str, input_type: InputType) -> bool {
+            input_type == InputType::Doi
+        }
+
+        async fn resolve(
+            &self,
+            input: &str,
+            _ctx: &ResolveContext,
+        ) -> Result<ResolveStep, ResolveError> {
+            let current = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak_in_flight.fetch_max(current, Ordering::SeqCst);
+            let index: u64 = input.rsplit('/').next().unwrap().parse().unwrap();
+            tokio::time::sleep(Duration::from_millis(40 - index * 5)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(ResolveStep::Url(ResolvedUrl::new(format!(
+                "https://example.com/{input}"
+            ))))
+        }
+    }
+
+    /// Returns a registry with a single [`EchoResolver`] and its peak-concurrency counter.
+    fn echo_registry() -> (ResolverRegistry, Arc<AtomicUsize>) {
+        let peak_in_flight = Arc::new(AtomicUsize::new(0));
+        let mut registry = ResolverRegistry::new();
+        registry.register(Box::new(EchoResolver {
+            in_flight: Arc::new(AtomicUsize::new(0)),
+            peak_in_flight: Arc::clone(&peak_in_flight),
+        }));
+        (registry, peak_in_flight)
+    }
+
+    fn doi_inputs(count: u64) -> Vec<(String, InputType)> {
+        (0..count)
+            .map(|i| (format!("10.1234/{i}"), InputType::Doi))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_resolve_many_preserves_input_order() {
+        let (registry, _) = echo_registry();
+        let mut inputs = doi_inputs(6);
+        inputs.push(("https://unhandled.example".to_string(), InputType::Url));
+
+        let results = registry
+            .resolve_many(&inputs, 4, &ResolveContext::default())
+            .await;
+
+        assert_eq!(results.len(), inputs.len());
+        for (i, result) in results.iter().take(6).enumerate() {
+            assert_eq!(
+                result.as_ref().unwrap().url,
+                format!("https://example.com/10.1234/{i}")
+            );
+        }
+        assert!(matches!(
+            results.last().unwrap(),
+            Err(ResolveError::NoResolver { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_many_bounds_concurrency() {
+        let (registry, peak_in_flight) = echo_registry();
+
+        registry
+            .resolve_many(&doi_inputs(8), 3, &ResolveContext::default())
+            .await;
+
+        let peak = peak_in_flight.load(Ordering::SeqCst);
+        assert!(peak > 1, "expected concurrent resolution, peak was {peak}");
+        assert!(peak <= 3, "concurrency bound exceeded, peak was {peak}");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_many_zero_concurrency_resolves_serially() {
+        let (registry, peak_in_flight) = echo_registry();
+
+        let results = registry
+            .resolve_many(&doi_inputs(2), 0, &ResolveContext::default())
+            .await;
+
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(peak_in_flight.load(Ordering::SeqCst), 1);
+    }
+
+    /// Never finishes, standing in for a resolver whose host stopped responding.
+    struct HangingResolver;
+
+    #[async_trait]
+    impl Resolver for HangingResolver {
+        fn name(&self) -> &'static str {
+            "hanging"
+        }
+
+        fn priority(&self) -> ResolverPriority {
+            ResolverPriority::Specialized
+        }
+
+        fn can_handle(&self, _input: &str, input_type: InputType) -> bool {
+            input_type == InputType::Doi
+        }
+
+        async fn resolve(
+            &self,
+            _input: &str,
+            _ctx: &ResolveContext,
+        ) -> Result<ResolveStep, ResolveError> {
+            std::future::pending().await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolver_timeout_falls_through_to_next_resolver() {
+        let mut registry = ResolverRegistry::new();
+        registry.register(Box::new(HangingResolver));
+        registry.register(Box::new(MockResolver {
+            mock_name: "fallback",
+            mock_priority: ResolverPriority::Fallback,
+            handles: vec![InputType::Doi],
+            step: ResolveStep::Url(ResolvedUrl::new("https://example.com/paper.pdf")),
+        }));
+        registry.set_resolver_timeout("hanging", Duration::from_millis(20));
+
+        let result = registry
+            .resolve_to_url("10.1234/slow", InputType::Doi, &ResolveContext::default())
+            .await
+            .unwrap();
+        assert_eq!(result.url, "https://example.com/paper.pdf");
+    }
 }
@@ -2,22 +2,32 @@
 //!
 //! Used by [`AcmResolver`](super::acm::AcmResolver) and
 //! [`WileyResolver`](super::wiley::WileyResolver) to query open-access metadata
-//! before falling back to publisher PDFs.
+//! before falling back to publisher PDFs, and by
+//! [`SemanticScholarResolver`](super::semantic_scholar_resolver::SemanticScholarResolver)
+//! for general DOI and title lookups.
 
 use std::collections::HashMap;
+use std::time::Duration;
 
 use reqwest::Client;
+use reqwest::header::RETRY_AFTER;
 use serde::Deserialize;
+use serde::de::DeserializeOwned;
 use tracing::{debug, warn};
 use url::Url;
 
+use super::http_client::send_with_retry;
 use super::{ResolveError, ResolveStep, ResolvedUrl};
+use crate::download::{RateLimiter, parse_retry_after};
 
 // ==================== Constants ====================
 
 pub(crate) const DEFAULT_S2_BASE_URL: &str = "https://api.semanticscholar.org";
 pub(crate) const S2_FIELDS: &str = "externalIds,openAccessPdf,title,authors,year";
 
+/// Back-off recorded against the API host when a 429 carries no usable `Retry-After`.
+const S2_DEFAULT_RATE_LIMIT_BACKOFF: Duration = Duration::from_secs(5);
+
 // ==================== Config ====================
 
 /// Configuration for a Semantic Scholar resolution call.
@@ -55,6 +65,24 @@ pub(crate) struct S2Author {
 pub(crate) struct S2ExternalIds {
     #[serde(rename = "ArXiv")]
     pub arxiv: Option<String>,
+    #[serde(rename = "DOI")]
+    pub doi: Option<String>,
+}
+
+/// Response body of `/graph/v1/paper/search/match` (best title match first).
+#[derive(Debug, Deserialize)]
+pub(crate) struct S2MatchResponse {
+    #[serde(default)]
+    pub data: Vec<S2PaperMatch>,
+}
+
+/// One `/paper/search/match` result: the paper plus its title match score.
+#[derive(Debug, Deserialize)]
+pub(crate) struct S2PaperMatch {
+    #[serde(rename = "matchScore")]
+    pub match_score: Option<f64>,
+    #[serde(flatten)]
+    pub paper: S2PaperResponse,
 }
 
 #[derive(Debug, Deserialize)]
@@ -79,13 +107,45 @@ pub(crate) async fn resolve_via_s2(
 ) -> Result<ResolveStep, ResolveError> {
     let url = format!("{s2_base_url}/graph/v1/paper/DOI:{doi}?fields={S2_FIELDS}");
 
-    debug!(s2_url = %url, resolver = config.resolver_name, "Querying Semantic Scholar");
+    let paper: S2PaperResponse =
+        match fetch_s2_json(client, &url, original_input, config.resolver_name, None).await {
+            Ok(paper) => paper,
+            Err(step) => return Ok(step),
+        };
+
+    let pdf_url = select_best_pdf_url(&paper, config);
+    let metadata = build_metadata(&paper, doi);
 
-    let response = match client.get(&url).send().await {
+    debug!(pdf_url = %pdf_url, resolver = config.resolver_name, "Resolved paper to PDF URL");
+    Ok(ResolveStep::Url(ResolvedUrl::with_metadata(
+        pdf_url, metadata,
+    )))
+}
+
+/// Sends a Graph API request and decodes the JSON body.
+///
+/// Every failure (network, non-2xx status, unparseable body) is returned as a
+/// soft [`ResolveStep::Failed`] so the registry moves on to the next resolver.
+/// When `rate_limiter` is set, the request waits for the API host's slot and a
+/// 429 response records the server's `Retry-After` back-off.
+pub(crate) async fn fetch_s2_json<T: DeserializeOwned>(
+    client: &Client,
+    url: &str,
+    original_input: &str,
+    resolver_name: &str,
+    rate_limiter: Option<&RateLimiter>,
+) -> Result<T, ResolveStep> {
+    if let Some(limiter) = rate_limiter {
+        limiter.acquire(url).await;
+    }
+
+    debug!(s2_url = %url, resolver = resolver_name, "Querying Semantic Scholar");
+
+    let response = match send_with_retry(client.get(url), resolver_name).await {
         Ok(resp) => resp,
         Err(e) => {
-            warn!(error = %e, doi, resolver = config.resolver_name, "Semantic Scholar request failed");
-            return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+            warn!(error = %e, resolver = resolver_name, "Semantic Scholar request failed");
+            return Err(ResolveStep::Failed(ResolveError::resolution_failed(
                 original_input,
                 "Cannot reach Semantic Scholar API. \
                  Why: network error or API unavailable. \
@@ -96,42 +156,44 @@ pub(crate) async fn resolve_via_s2(
 
     let status = response.status();
     if !status.is_success() {
+        if status.as_u16() == 429 {
+            if let Some(limiter) = rate_limiter {
+                let delay = response
+                    .headers()
+                    .get(RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(parse_retry_after)
+                    .unwrap_or(S2_DEFAULT_RATE_LIMIT_BACKOFF);
+                limiter.record_rate_limit(url, delay);
+            }
+        }
         let reason = s2_error_reason(status.as_u16());
         debug!(
             status = status.as_u16(),
             %reason,
-            resolver = config.resolver_name,
+            resolver = resolver_name,
             "Semantic Scholar API error"
         );
-        return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+        return Err(ResolveStep::Failed(ResolveError::resolution_failed(
             original_input,
             &reason,
         )));
     }
 
-    let paper: S2PaperResponse = match response.json().await {
-        Ok(parsed) => parsed,
+    match response.json().await {
+        Ok(parsed) => Ok(parsed),
         Err(e) => {
             warn!(
                 error = %e,
-                doi,
-                resolver = config.resolver_name,
+                resolver = resolver_name,
                 "Failed to parse Semantic Scholar response"
             );
-            return Ok(ResolveStep::body_parse_failed(
+            Err(ResolveStep::body_parse_failed(
                 original_input,
                 "Semantic Scholar",
-            ));
+            ))
         }
-    };
-
-    let pdf_url = select_best_pdf_url(&paper, config);
-    let metadata = build_metadata(&paper, doi);
-
-    debug!(pdf_url = %pdf_url, resolver = config.resolver_name, "Resolved paper to PDF URL");
-    Ok(ResolveStep::Url(ResolvedUrl::with_metadata(
-        pdf_url, metadata,
-    )))
+    }
 }
 
 // ==================== URL selection ====================
@@ -198,10 +260,15 @@ pub(crate) fn is_usable_oa_url(url: &str, rejected_domains: &[&str]) -> bool {
 // ==================== Metadata ====================
 
 pub(crate) fn build_metadata(paper: &S2PaperResponse, doi: &str) -> HashMap<String, String> {
-    let mut metadata = HashMap::with_capacity(5);
-
+    let mut metadata = paper_metadata(paper);
     metadata.insert("doi".to_string(), doi.to_string());
     metadata.insert("source_url".to_string(), format!("https://doi.org/{doi}"));
+    metadata
+}
+
+/// Collects title, authors, and year from a paper, skipping empty values.
+pub(crate) fn paper_metadata(paper: &S2PaperResponse) -> HashMap<String, String> {
+    let mut metadata = HashMap::with_capacity(5);
 
     if let Some(title) = &paper.title {
         if !title.is_empty() {
@@ -281,6 +348,7 @@ mod tests {
             year: None,
             external_ids: arxiv_id.map(|id| S2ExternalIds {
                 arxiv: Some(id.to_string()),
+                doi: None,
             }),
             open_access_pdf: oa_url.map(|url| S2OpenAccessPdf {
                 url: Some(url.to_string()),
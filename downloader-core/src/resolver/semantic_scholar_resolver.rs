@@ -0,0 +1,489 @@
+//! Semantic Scholar resolver for generic DOIs and free-text references.
+//!
+//! Queries the Semantic Scholar Graph API for an open-access PDF and returns it
+//! with `title`/`authors`/`year` metadata. Registered at
+//! [`ResolverPriority::General`] ahead of Crossref, so site-specific resolvers
+//! still run first and Crossref remains the fallback whenever Semantic Scholar
+//! has no open copy.
+//!
+//! # Resolution strategy
+//!
+//! - DOI inputs are looked up with `/graph/v1/paper/DOI:{doi}`.
+//! - Reference inputs are matched by their extracted title with
+//!   `/graph/v1/paper/search/match`. Low-confidence references are not searched,
+//!   and a match scoring below `MIN_TITLE_MATCH_SCORE` is rejected.
+//! - The resolved URL is `openAccessPdf.url` (or the arXiv PDF for papers with
+//!   an `ArXiv` external ID). Papers without an open copy, 404s, and 429s all
+//!   return [`ResolveStep::Failed`] so the chain continues.
+//!
+//! Requests wait on the resolver's own one-per-second limiter as well as any
+//! shared limiter attached to the [`ResolveContext`].
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::Client;
+
+use tracing::debug;
+
+use crate::download::RateLimiter;
+use crate::parser::{
+    Confidence, InputType, extract_reference_confidence, parse_reference_metadata,
+};
+
+use super::http_client::{build_resolver_http_client, standard_user_agent};
+use super::semantic_scholar::{
+    self, DEFAULT_S2_BASE_URL, S2_FIELDS, S2MatchResponse, S2PaperResponse,
+};
+use super::utils::looks_like_doi;
+use super::{ResolveContext, ResolveError, ResolveStep, ResolvedUrl, Resolver, ResolverPriority};
+
+// ==================== Constants ====================
+
+/// Minimum spacing between unauthenticated Graph API requests.
+///
+/// Semantic Scholar's shared public pool allows roughly one request per second.
+const S2_MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Minimum `matchScore` for a title match to be trusted.
+///
+/// Scores are unnormalized; exact title matches typically score well above
+/// this, while loose matches on a few common words fall below it.
+const MIN_TITLE_MATCH_SCORE: f64 = 50.0;
+
+// ==================== SemanticScholarResolver ====================
+
+/// Resolves DOIs and references to open-access PDFs via Semantic Scholar.
+pub struct SemanticScholarResolver {
+    client: Client,
+    s2_base_url: String,
+    rate_limiter: Arc<RateLimiter>,
+}
+
+impl SemanticScholarResolver {
+    /// Creates a new `SemanticScholarResolver` using the production Graph API.
+    ///
+    /// Requests are spaced by a private [`RateLimiter`]; use
+    /// [`with_rate_limiter`](Self::with_rate_limiter) to share one instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResolveError`] if HTTP client construction fails.
+    pub fn new() -> Result<Self, ResolveError> {
+        Self::build(
+            DEFAULT_S2_BASE_URL.to_string(),
+            Arc::new(RateLimiter::new(S2_MIN_REQUEST_INTERVAL)),
+        )
+    }
+
+    /// Creates a `SemanticScholarResolver` with a custom Graph API base URL.
+    ///
+    /// Intended for use in tests with a wiremock server; rate limiting is disabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResolveError`] if HTTP client construction fails.
+    pub fn with_base_url(s2_base_url: impl Into<String>) -> Result<Self, ResolveError> {
+        Self::build(s2_base_url.into(), Arc::new(RateLimiter::disabled()))
+    }
+
+    /// Replaces the resolver's rate limiter, e.g. with one shared across resolvers.
+    #[must_use]
+    pub fn with_rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    fn build(s2_base_url: String, rate_limiter: Arc<RateLimiter>) -> Result<Self, ResolveError> {
+        let user_agent = standard_user_agent("semantic_scholar");
+        let client = build_resolver_http_client("semantic_scholar", user_agent, None)?;
+        Ok(Self {
+            client,
+            s2_base_url,
+            rate_limiter,
+        })
+    }
+
+    async fn resolve_doi(&self, doi: &str, input: &str, ctx: &ResolveContext) -> ResolveStep {
+        let url = format!(
+            "{}/graph/v1/paper/DOI:{doi}?fields={S2_FIELDS}",
+            self.s2_base_url
+        );
+        ctx.throttle(&url).await;
+        let paper: S2PaperResponse = match semantic_scholar::fetch_s2_json(
+            &self.client,
+            &url,
+            input,
+            self.name(),
+            Some(&self.rate_limiter),
+        )
+        .await
+        {
+            Ok(paper) => paper,
+            Err(step) => return step,
+        };
+
+        let metadata = semantic_scholar::build_metadata(&paper, doi);
+        open_access_step(&paper, input, metadata)
+    }
+
+    async fn resolve_title(&self, title: &str, input: &str, ctx: &ResolveContext) -> ResolveStep {
+        let url = format!(
+            "{}/graph/v1/paper/search/match?query={}&fields={S2_FIELDS}",
+            self.s2_base_url,
+            urlencoding::encode(title)
+        );
+        ctx.throttle(&url).await;
+        let matches: S2MatchResponse = match semantic_scholar::fetch_s2_json(
+            &self.client,
+            &url,
+            input,
+            self.name(),
+            Some(&self.rate_limiter),
+        )
+        .await
+        {
+            Ok(matches) => matches,
+            Err(step) => return step,
+        };
+
+        let Some(best) = matches.data.first() else {
+            return ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                "No Semantic Scholar paper matches this title. \
+                 Why: the title may be misspelled or the paper is not indexed. \
+                 Fix: provide the DOI or a direct URL instead.",
+            ));
+        };
+        let score = best.match_score.unwrap_or(0.0);
+        if score < MIN_TITLE_MATCH_SCORE {
+            debug!(score, "Rejected weak Semantic Scholar title match");
+            return ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                &format!(
+                    "Best Semantic Scholar title match is uncertain (score {score:.1}). \
+                     Why: the title may be truncated or too generic. \
+                     Fix: provide the DOI or a direct URL instead."
+                ),
+            ));
+        }
+        let paper = &best.paper;
+
+        let doi = paper
+            .external_ids
+            .as_ref()
+            .and_then(|ids| ids.doi.as_deref());
+        let metadata = match doi {
+            Some(doi) => semantic_scholar::build_metadata(paper, doi),
+            None => semantic_scholar::paper_metadata(paper),
+        };
+        open_access_step(paper, input, metadata)
+    }
+}
+
+impl std::fmt::Debug for SemanticScholarResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SemanticScholarResolver")
+            .field("s2_base_url", &self.s2_base_url)
+            .finish_non_exhaustive()
+    }
+}
+
+// ==================== Resolver trait ====================
+
+#[async_trait]
+impl Resolver for SemanticScholarResolver {
+    fn name(&self) -> &'static str {
+        "semantic_scholar"
+    }
+
+    fn priority(&self) -> ResolverPriority {
+        ResolverPriority::General
+    }
+
+    fn can_handle(&self, input: &str, input_type: InputType) -> bool {
+        match input_type {
+            InputType::Doi => looks_like_doi(input, "10."),
+            InputType::Reference => reference_title(input).is_some(),
+            _ => false,
+        }
+    }
+
+    #[tracing::instrument(skip(self, ctx), fields(resolver = "semantic_scholar", input = %input))]
+    async fn resolve(
+        &self,
+        input: &str,
+        ctx: &ResolveContext,
+    ) -> Result<ResolveStep, ResolveError> {
+        if looks_like_doi(input, "10.") {
+            return Ok(self.resolve_doi(input.trim(), input, ctx).await);
+        }
+
+        let confidence = extract_reference_confidence(input);
+        if confidence.level == Confidence::Low {
+            debug!(confidence = %confidence.level, "Reference too sparse for Semantic Scholar search");
+            return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                "Reference is too incomplete to search Semantic Scholar. \
+                 Why: fewer than two of author, year, and title were recognized. \
+                 Fix: paste the full citation or provide the DOI directly.",
+            )));
+        }
+
+        match reference_title(input) {
+            Some(title) => Ok(self.resolve_title(&title, input, ctx).await),
+            None => Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                "Could not extract a title for Semantic Scholar lookup. \
+                 Why: input is neither a DOI nor a reference with a recognizable title. \
+                 Fix: provide the DOI or a direct URL instead.",
+            ))),
+        }
+    }
+}
+
+// ==================== Helpers ====================
+
+/// Returns the title extracted from a free-text reference, if any.
+fn reference_title(input: &str) -> Option<String> {
+    parse_reference_metadata(input)
+        .title
+        .filter(|title| !title.trim().is_empty())
+}
+
+/// Builds the resolve step for a paper: its open-access PDF, or a soft failure.
+fn open_access_step(
+    paper: &S2PaperResponse,
+    input: &str,
+    metadata: std::collections::HashMap<String, String>,
+) -> ResolveStep {
+    match open_access_pdf_url(paper) {
+        Some(pdf_url) => ResolveStep::Url(ResolvedUrl::with_metadata(pdf_url, metadata)),
+        None => ResolveStep::Failed(ResolveError::resolution_failed(
+            input,
+            "Semantic Scholar lists no open-access PDF for this paper. \
+             Why: the paper may be paywalled. \
+             Fix: other resolvers (e.g. Crossref) will be tried automatically.",
+        )),
+    }
+}
+
+/// Picks `openAccessPdf.url` when usable, otherwise the arXiv PDF for the paper's arXiv ID.
+fn open_access_pdf_url(paper: &S2PaperResponse) -> Option<String> {
+    let oa_url = paper
+        .open_access_pdf
+        .as_ref()
+        .and_then(|oa| oa.url.as_deref())
+        .filter(|url| semantic_scholar::is_usable_oa_url(url, &[]));
+    if let Some(url) = oa_url {
+        return Some(url.to_string());
+    }
+
+    paper
+        .external_ids
+        .as_ref()
+        .and_then(|ids| ids.arxiv.as_deref())
+        .map(|arxiv_id| format!("https://arxiv.org/pdf/{arxiv_id}"))
+}
+
+// ==================== Tests ====================
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::test_support::socket_guard::start_mock_server_or_skip;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    const REFERENCE: &str =
+        "Vaswani, A., Shazeer, N. (2017). Attention is all you need. Advances in NeurIPS.";
+
+    #[test]
+    fn test_name_and_priority() {
+        let resolver = SemanticScholarResolver::new().unwrap();
+        assert_eq!(resolver.name(), "semantic_scholar");
+        assert_eq!(resolver.priority(), ResolverPriority::General);
+    }
+
+    #[test]
+    fn test_can_handle_doi_and_reference_only() {
+        let resolver = SemanticScholarResolver::new().unwrap();
+        assert!(resolver.can_handle("10.1234/example", InputType::Doi));
+        assert!(resolver.can_handle(REFERENCE, InputType::Reference));
+        assert!(!resolver.can_handle("https://example.com/a.pdf", InputType::Url));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_doi_returns_open_access_pdf_with_metadata() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/graph/v1/paper/DOI:10.1234/example"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "title": "Example Paper",
+                "authors": [{"name": "Ada Lovelace"}],
+                "year": 2021,
+                "openAccessPdf": {"url": "https://repository.example.org/paper.pdf"}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = SemanticScholarResolver::with_base_url(mock_server.uri()).unwrap();
+        let step = resolver
+            .resolve("10.1234/example", &ResolveContext::default())
+            .await
+            .unwrap();
+
+        let ResolveStep::Url(result) = step else {
+            panic!("expected Url step, got {step:?}");
+        };
+        assert_eq!(result.url, "https://repository.example.org/paper.pdf");
+        assert_eq!(result.metadata.get("title").unwrap(), "Example Paper");
+        assert_eq!(result.metadata.get("authors").unwrap(), "Ada Lovelace");
+        assert_eq!(result.metadata.get("year").unwrap(), "2021");
+        assert_eq!(result.metadata.get("doi").unwrap(), "10.1234/example");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_doi_without_open_access_fails_softly() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/graph/v1/paper/DOI:10.1234/closed"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "title": "Closed Paper",
+                "openAccessPdf": null
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = SemanticScholarResolver::with_base_url(mock_server.uri()).unwrap();
+        let step = resolver
+            .resolve("10.1234/closed", &ResolveContext::default())
+            .await
+            .unwrap();
+        assert!(matches!(step, ResolveStep::Failed(_)));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_doi_404_and_429_fail_softly() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/graph/v1/paper/DOI:10.1234/missing"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/graph/v1/paper/DOI:10.1234/throttled"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "1"))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = SemanticScholarResolver::with_base_url(mock_server.uri()).unwrap();
+        for doi in ["10.1234/missing", "10.1234/throttled"] {
+            let step = resolver
+                .resolve(doi, &ResolveContext::default())
+                .await
+                .unwrap();
+            assert!(
+                matches!(step, ResolveStep::Failed(_)),
+                "expected soft failure for {doi}, got {step:?}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_reference_matches_by_title() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        let title = reference_title(REFERENCE).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/graph/v1/paper/search/match"))
+            .and(query_param("query", title.as_str()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{
+                    "matchScore": 182.4,
+                    "title": "Attention Is All You Need",
+                    "year": 2017,
+                    "externalIds": {"ArXiv": "1706.03762", "DOI": "10.48550/arXiv.1706.03762"},
+                    "openAccessPdf": null
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = SemanticScholarResolver::with_base_url(mock_server.uri()).unwrap();
+        let step = resolver
+            .resolve(REFERENCE, &ResolveContext::default())
+            .await
+            .unwrap();
+
+        let ResolveStep::Url(result) = step else {
+            panic!("expected Url step, got {step:?}");
+        };
+        assert_eq!(result.url, "https://arxiv.org/pdf/1706.03762");
+        assert_eq!(
+            result.metadata.get("doi").unwrap(),
+            "10.48550/arXiv.1706.03762"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_reference_rejects_weak_title_match() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/graph/v1/paper/search/match"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{
+                    "matchScore": 12.5,
+                    "title": "Attention Mechanisms in Crowds",
+                    "openAccessPdf": {"url": "https://example.com/unrelated.pdf"}
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = SemanticScholarResolver::with_base_url(mock_server.uri()).unwrap();
+        let step = resolver
+            .resolve(REFERENCE, &ResolveContext::default())
+            .await
+            .unwrap();
+
+        let ResolveStep::Failed(error) = step else {
+            panic!("expected soft failure, got {step:?}");
+        };
+        assert!(error.to_string().contains("uncertain (score 12.5)"));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_low_confidence_reference_skips_search() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let resolver = SemanticScholarResolver::with_base_url(mock_server.uri()).unwrap();
+        let step = resolver
+            .resolve(
+                "Some notes about attention in transformers",
+                &ResolveContext::default(),
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(step, ResolveStep::Failed(_)), "got {step:?}");
+    }
+}
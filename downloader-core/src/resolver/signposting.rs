@@ -0,0 +1,194 @@
+//! FAIR signposting discovery of PDF links on landing pages.
+//!
+//! Repositories that implement signposting advertise the content file either
+//! as an HTTP `Link: <...>; rel="item"; type="application/pdf"` response
+//! header or as an HTML `<link rel="alternate" type="application/pdf">` tag.
+//! These helpers pick the PDF target out of both forms; the landing-page
+//! fetch itself lives in [`super::CitationMetaResolver`].
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+use reqwest::header::{HeaderMap, LINK};
+use url::Url;
+
+use super::meta::html_unescape_basic;
+use super::utils::{absolutize_url, compile_static_regex};
+
+/// Link relations that may point at the content file of a landing page.
+const PDF_RELATIONS: &[&str] = &["item", "alternate"];
+const PDF_MEDIA_TYPE: &str = "application/pdf";
+
+static LINK_TAG_RE: LazyLock<Regex> =
+    LazyLock::new(|| compile_static_regex(r"(?is)<link\s+[^>]*>"));
+static LINK_ATTR_RE: LazyLock<Regex> = LazyLock::new(|| {
+    compile_static_regex(r#"([a-zA-Z_:][-a-zA-Z0-9_:.]*)\s*=\s*(?:"([^"]*)"|'([^']*)')"#)
+});
+
+/// Returns the first PDF advertised in the response's `Link` headers.
+pub(crate) fn pdf_link_from_headers(headers: &HeaderMap, base_url: &Url) -> Option<String> {
+    headers
+        .get_all(LINK)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(split_link_header)
+        .find_map(|entry| {
+            let (target, params) = parse_link_entry(entry)?;
+            let rel = link_param(&params, "rel")?;
+            let media_type = link_param(&params, "type");
+            is_pdf_link(&target, &rel, media_type.as_deref())
+                .then(|| absolutize_url(&target, base_url))
+                .flatten()
+        })
+}
+
+/// Returns the first PDF advertised by an HTML `<link rel>` tag.
+pub(crate) fn pdf_link_from_html(html: &str, base_url: &Url) -> Option<String> {
+    LINK_TAG_RE.find_iter(html).find_map(|tag| {
+        let mut rel = None;
+        let mut media_type = None;
+        let mut href = None;
+        for attr in LINK_ATTR_RE.captures_iter(tag.as_str()) {
+            let key = attr.get(1).map_or("", |m| m.as_str()).to_ascii_lowercase();
+            let value = attr
+                .get(2)
+                .or_else(|| attr.get(3))
+                .map_or("", |m| m.as_str())
+                .trim()
+                .to_string();
+            match key.as_str() {
+                "rel" => rel = Some(value),
+                "type" => media_type = Some(value),
+                "href" => href = Some(html_unescape_basic(&value)),
+                _ => {}
+            }
+        }
+        let href = href.filter(|href| !href.is_empty())?;
+        is_pdf_link(&href, &rel?, media_type.as_deref())
+            .then(|| absolutize_url(&href, base_url))
+            .flatten()
+    })
+}
+
+/// A link counts when one of its relations is `item`/`alternate` and it is
+/// typed as PDF, or untyped but pointing at a `.pdf` path.
+fn is_pdf_link(target: &str, rel: &str, media_type: Option<&str>) -> bool {
+    let has_relation = rel.split_ascii_whitespace().any(|relation| {
+        PDF_RELATIONS
+            .iter()
+            .any(|candidate| relation.eq_ignore_ascii_case(candidate))
+    });
+    if !has_relation {
+        return false;
+    }
+    match media_type {
+        Some(media_type) => media_type
+            .split(';')
+            .next()
+            .is_some_and(|essence| essence.trim().eq_ignore_ascii_case(PDF_MEDIA_TYPE)),
+        None => target
+            .split(['?', '#'])
+            .next()
+            .is_some_and(|path| path.to_ascii_lowercase().ends_with(".pdf")),
+    }
+}
+
+/// Splits a `Link` header value into entries, ignoring commas inside `<...>`
+/// targets and quoted parameter values.
+fn split_link_header(value: &str) -> Vec<&str> {
+    let mut entries = Vec::new();
+    let mut start = 0;
+    let mut in_target = false;
+    let mut in_quotes = false;
+    for (index, ch) in value.char_indices() {
+        match ch {
+            '<' if !in_quotes => in_target = true,
+            '>' if !in_quotes => in_target = false,
+            '"' if !in_target => in_quotes = !in_quotes,
+            ',' if !in_target && !in_quotes => {
+                entries.push(&value[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    entries.push(&value[start..]);
+    entries
+}
+
+/// Parses `<target>; key="value"; ...` into the target and its parameters.
+fn parse_link_entry(entry: &str) -> Option<(String, Vec<(String, String)>)> {
+    let entry = entry.trim();
+    let rest = entry.strip_prefix('<')?;
+    let (target, params) = rest.split_once('>')?;
+    let params = params
+        .split(';')
+        .filter_map(|param| {
+            let (key, value) = param.split_once('=')?;
+            Some((
+                key.trim().to_ascii_lowercase(),
+                value.trim().trim_matches('"').to_string(),
+            ))
+        })
+        .collect();
+    Some((target.trim().to_string(), params))
+}
+
+fn link_param(params: &[(String, String)], key: &str) -> Option<String> {
+    params
+        .iter()
+        .find(|(name, _)| name == key)
+        .map(|(_, value)| value.clone())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn base() -> Url {
+        Url::parse("https://repo.example.org/records/42").unwrap()
+    }
+
+    #[test]
+    fn test_pdf_link_from_headers_picks_typed_item() {
+        let mut headers = HeaderMap::new();
+        headers.append(
+            LINK,
+            HeaderValue::from_static(
+                r#"<https://doi.org/10.1234/x>; rel="cite-as", </files/data.zip>; rel="item"; type="application/zip", </files/paper,v2.pdf>; rel="item"; type="application/pdf""#,
+            ),
+        );
+
+        assert_eq!(
+            pdf_link_from_headers(&headers, &base()).as_deref(),
+            Some("https://repo.example.org/files/paper,v2.pdf")
+        );
+    }
+
+    #[test]
+    fn test_pdf_link_from_headers_ignores_non_pdf_relations() {
+        let mut headers = HeaderMap::new();
+        headers.append(
+            LINK,
+            HeaderValue::from_static(r#"</files/paper.pdf>; rel="describedby""#),
+        );
+        assert_eq!(pdf_link_from_headers(&headers, &base()), None);
+    }
+
+    #[test]
+    fn test_pdf_link_from_html_reads_alternate_link_tag() {
+        let html = r#"<head>
+            <link rel="stylesheet" href="/style.css">
+            <link rel="alternate" type="application/rss+xml" href="/feed">
+            <link type="application/pdf" rel="alternate" href="/bitstream/42/paper.pdf?seq=1&amp;x=2">
+            </head>"#;
+
+        assert_eq!(
+            pdf_link_from_html(html, &base()).as_deref(),
+            Some("https://repo.example.org/bitstream/42/paper.pdf?seq=1&x=2")
+        );
+        assert_eq!(pdf_link_from_html("<head></head>", &base()), None);
+    }
+}
@@ -0,0 +1,381 @@
+//! SSRN resolver for abstract pages and `10.2139/ssrn.*` DOIs.
+//!
+//! SSRN exposes the same paper under several URL shapes (`ssrn.com/abstract=<id>`,
+//! `papers.cfm?abstract_id=<id>`, `Delivery.cfm?abstractid=<id>`), all keyed by
+//! the numeric abstract id. The resolver fetches the abstract page for its
+//! `citation_*` metadata and maps the id to the `Delivery.cfm` PDF endpoint.
+//! Papers whose "Download This Paper" button leads to the SSRN sign-in page
+//! come back as `NeedsAuth`.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use reqwest::Client;
+use reqwest::cookie::Jar;
+use reqwest::header::ACCEPT;
+use tracing::{debug, warn};
+use url::Url;
+
+use crate::parser::InputType;
+
+use super::citation_meta::citation_metadata;
+use super::http_client::{build_resolver_http_client, standard_user_agent};
+use super::meta::collect_meta_tags;
+use super::utils::{canonical_host, is_auth_required_status, looks_like_doi};
+use super::{
+    AuthRequirement, ResolveContext, ResolveError, ResolveStep, ResolvedUrl, Resolver,
+    ResolverPriority,
+};
+
+const DEFAULT_BASE_URL: &str = "https://papers.ssrn.com";
+const SSRN_HOST: &str = "ssrn.com";
+const DOI_HOST: &str = "doi.org";
+const SSRN_DOI_PREFIX: &str = "10.2139/ssrn.";
+/// Query keys SSRN uses for the abstract id across its page and download URLs.
+const ABSTRACT_ID_KEYS: &[&str] = &["abstract_id", "abstractid", "abstract"];
+const AUTH_MESSAGE: &str = "SSRN requires a signed-in session to download this paper. Sign in at ssrn.com, run `downloader auth capture --save-cookies`, then retry.";
+
+/// A site-specific resolver for SSRN abstract pages.
+pub struct SsrnResolver {
+    client: Client,
+    base_url: String,
+}
+
+impl SsrnResolver {
+    /// Creates a resolver that sends `cookie_jar` cookies to SSRN.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResolveError`] if the HTTP client cannot be constructed.
+    #[tracing::instrument(skip(cookie_jar), fields(resolver = "ssrn"))]
+    pub fn new(cookie_jar: Option<Arc<Jar>>) -> Result<Self, ResolveError> {
+        Self::with_base_url(cookie_jar, DEFAULT_BASE_URL)
+    }
+
+    /// Creates a resolver with a custom SSRN base URL (for tests).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResolveError`] if the HTTP client cannot be constructed.
+    #[tracing::instrument(skip(cookie_jar, base_url), fields(resolver = "ssrn"))]
+    pub fn with_base_url(
+        cookie_jar: Option<Arc<Jar>>,
+        base_url: impl Into<String>,
+    ) -> Result<Self, ResolveError> {
+        Ok(Self {
+            client: build_resolver_http_client("ssrn", standard_user_agent("ssrn"), cookie_jar)?,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+        })
+    }
+
+    fn abstract_page_url(&self, abstract_id: &str) -> String {
+        format!(
+            "{}/sol3/papers.cfm?abstract_id={abstract_id}",
+            self.base_url
+        )
+    }
+
+    fn delivery_url(&self, abstract_id: &str) -> String {
+        format!(
+            "{}/sol3/Delivery.cfm?abstractid={abstract_id}",
+            self.base_url
+        )
+    }
+}
+
+impl std::fmt::Debug for SsrnResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SsrnResolver")
+            .field("base_url", &self.base_url)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl Resolver for SsrnResolver {
+    fn name(&self) -> &'static str {
+        "ssrn"
+    }
+
+    fn priority(&self) -> ResolverPriority {
+        ResolverPriority::Specialized
+    }
+
+    fn can_handle(&self, input: &str, input_type: InputType) -> bool {
+        match input_type {
+            InputType::Doi => abstract_id_from_doi(input).is_some(),
+            InputType::Url => abstract_id_from_url(input).is_some(),
+            _ => false,
+        }
+    }
+
+    #[tracing::instrument(skip(self, _ctx), fields(resolver = "ssrn", input = %input))]
+    async fn resolve(
+        &self,
+        input: &str,
+        _ctx: &ResolveContext,
+    ) -> Result<ResolveStep, ResolveError> {
+        let Some(abstract_id) = extract_abstract_id(input) else {
+            return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                "Input is not a recognized SSRN abstract URL or DOI",
+            )));
+        };
+
+        let page_url = self.abstract_page_url(&abstract_id);
+        debug!(url = %page_url, "Fetching SSRN abstract page for resolution");
+
+        let response = match self
+            .client
+            .get(&page_url)
+            .header(ACCEPT, "text/html,application/xhtml+xml;q=0.9,*/*;q=0.8")
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(error) => {
+                warn!(error = %error, "SSRN request failed");
+                return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                    input,
+                    "Cannot reach SSRN. Check network and try again.",
+                )));
+            }
+        };
+
+        let status = response.status();
+        let final_url = response.url().clone();
+        if is_auth_required_status(status.as_u16()) || is_login_url(&final_url) {
+            return Ok(ResolveStep::NeedsAuth(AuthRequirement::new(
+                SSRN_HOST,
+                AUTH_MESSAGE,
+            )));
+        }
+        if !status.is_success() {
+            return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                &format!("SSRN returned HTTP {}", status.as_u16()),
+            )));
+        }
+
+        let html = match response.text().await {
+            Ok(text) => text,
+            Err(error) => {
+                warn!(error = %error, "Failed to read SSRN response body");
+                return Ok(ResolveStep::body_parse_failed(input, "SSRN"));
+            }
+        };
+        if download_requires_login(&html) {
+            return Ok(ResolveStep::NeedsAuth(AuthRequirement::new(
+                SSRN_HOST,
+                AUTH_MESSAGE,
+            )));
+        }
+
+        let mut metadata = citation_metadata(&collect_meta_tags(&html));
+        metadata
+            .entry("doi".to_string())
+            .or_insert_with(|| format!("{SSRN_DOI_PREFIX}{abstract_id}"));
+        metadata.insert("source_url".to_string(), final_url.to_string());
+
+        Ok(ResolveStep::Url(ResolvedUrl::with_metadata(
+            self.delivery_url(&abstract_id),
+            metadata,
+        )))
+    }
+}
+
+/// Extracts the abstract id from an SSRN URL, a `10.2139/ssrn.<id>` DOI (or
+/// `doi.org` link to one), or a bare `abstract_id=<id>` fragment.
+fn extract_abstract_id(input: &str) -> Option<String> {
+    let trimmed = input.trim();
+    abstract_id_from_url(trimmed)
+        .or_else(|| abstract_id_from_doi(trimmed))
+        .or_else(|| abstract_id_from_pair(trimmed))
+}
+
+fn abstract_id_from_url(input: &str) -> Option<String> {
+    let url = Url::parse(input.trim()).ok()?;
+    let host = canonical_host(url.host_str()?);
+    if host == DOI_HOST {
+        return abstract_id_from_doi(
+            &urlencoding::decode(url.path().trim_start_matches('/')).ok()?,
+        );
+    }
+    if host != SSRN_HOST && !host.ends_with(".ssrn.com") {
+        return None;
+    }
+
+    // `ssrn.com/abstract=<id>` carries the id in the path itself.
+    if let Some(id) = url
+        .path_segments()
+        .into_iter()
+        .flatten()
+        .find_map(abstract_id_from_pair)
+    {
+        return Some(id);
+    }
+    url.query_pairs().find_map(|(key, value)| {
+        ABSTRACT_ID_KEYS
+            .iter()
+            .any(|candidate| key.eq_ignore_ascii_case(candidate))
+            .then(|| normalize_abstract_id(&value))
+            .flatten()
+    })
+}
+
+fn abstract_id_from_doi(doi: &str) -> Option<String> {
+    let trimmed = doi.trim();
+    if !looks_like_doi(trimmed, SSRN_DOI_PREFIX) {
+        return None;
+    }
+    normalize_abstract_id(&trimmed[SSRN_DOI_PREFIX.len()..])
+}
+
+/// Parses `abstract_id=<id>` (or `abstractid=`/`abstract=`).
+fn abstract_id_from_pair(value: &str) -> Option<String> {
+    let (key, id) = value.split_once('=')?;
+    ABSTRACT_ID_KEYS
+        .iter()
+        .any(|candidate| key.trim().eq_ignore_ascii_case(candidate))
+        .then(|| normalize_abstract_id(id))
+        .flatten()
+}
+
+/// SSRN abstract ids are purely numeric.
+fn normalize_abstract_id(id: &str) -> Option<String> {
+    let id = id.trim();
+    (!id.is_empty() && id.chars().all(|c| c.is_ascii_digit())).then(|| id.to_string())
+}
+
+fn is_login_url(url: &Url) -> bool {
+    url.path().to_ascii_lowercase().contains("/login")
+}
+
+/// True when the abstract page offers no download link, only a sign-in link.
+fn download_requires_login(html: &str) -> bool {
+    let normalized = html.to_ascii_lowercase();
+    !normalized.contains("delivery.cfm") && normalized.contains("/login/")
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::test_support::socket_guard::start_mock_server_or_skip;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[test]
+    fn test_ssrn_can_handle_common_url_shapes_and_dois() {
+        let resolver = SsrnResolver::new(None).unwrap();
+        assert!(resolver.can_handle("https://ssrn.com/abstract=123456", InputType::Url));
+        assert!(resolver.can_handle(
+            "https://papers.ssrn.com/sol3/papers.cfm?abstract_id=123456",
+            InputType::Url
+        ));
+        assert!(resolver.can_handle("10.2139/ssrn.123456", InputType::Doi));
+        assert!(resolver.can_handle("https://doi.org/10.2139/ssrn.123456", InputType::Url));
+        assert!(!resolver.can_handle("https://www.ssrn.com/index.cfm/en/", InputType::Url));
+        assert!(!resolver.can_handle(
+            "https://example.com/papers.cfm?abstract_id=123456",
+            InputType::Url
+        ));
+    }
+
+    #[test]
+    fn test_extract_abstract_id_variants() {
+        for input in [
+            "https://ssrn.com/abstract=123456",
+            "https://www.ssrn.com/abstract=123456",
+            "https://papers.ssrn.com/sol3/papers.cfm?abstract_id=123456",
+            "https://papers.ssrn.com/sol3/Delivery.cfm/SSRN_ID123456_code1.pdf?abstractid=123456&mirid=1",
+            "10.2139/ssrn.123456",
+            "abstract_id=123456",
+            " abstractid = 123456 ",
+        ] {
+            assert_eq!(
+                extract_abstract_id(input).as_deref(),
+                Some("123456"),
+                "{input}"
+            );
+        }
+        assert_eq!(extract_abstract_id("abstract_id=12ab"), None);
+        assert_eq!(extract_abstract_id("10.2139/other.1"), None);
+    }
+
+    #[test]
+    fn test_download_requires_login_only_without_delivery_link() {
+        assert!(download_requires_login(
+            r#"<a href="https://hq.ssrn.com/login/pubSignInJoin.cfm">Sign in to download</a>"#
+        ));
+        assert!(!download_requires_login(
+            r#"<a href="Delivery.cfm/SSRN_ID1.pdf?abstractid=1">Download This Paper</a>
+               <a href="https://hq.ssrn.com/login/pubSignInJoin.cfm">Sign in</a>"#
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_maps_abstract_url_to_delivery_endpoint_with_metadata() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/sol3/papers.cfm"))
+            .and(query_param("abstract_id", "123456"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"<html><head>
+                <meta name="citation_title" content="Law and Markets">
+                <meta name="citation_author" content="Doe, Jane">
+                <meta name="citation_author" content="Roe, Richard">
+                <meta name="citation_publication_date" content="2019/05/01">
+                </head><body><a href="Delivery.cfm/SSRN_ID123456.pdf?abstractid=123456">Download This Paper</a></body></html>"#,
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = SsrnResolver::with_base_url(None, mock_server.uri()).unwrap();
+        let step = resolver
+            .resolve(
+                "https://ssrn.com/abstract=123456",
+                &ResolveContext::default(),
+            )
+            .await
+            .unwrap();
+        let ResolveStep::Url(resolved) = step else {
+            panic!("expected Url, got {step:?}");
+        };
+        assert_eq!(
+            resolved.url,
+            format!("{}/sol3/Delivery.cfm?abstractid=123456", mock_server.uri())
+        );
+        assert_eq!(resolved.metadata["title"], "Law and Markets");
+        assert_eq!(resolved.metadata["authors"], "Doe, Jane; Roe, Richard");
+        assert_eq!(resolved.metadata["year"], "2019");
+        assert_eq!(resolved.metadata["doi"], "10.2139/ssrn.123456");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_needs_auth_when_page_only_offers_sign_in() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/sol3/papers.cfm"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"<a href="https://hq.ssrn.com/login/pubSignInJoin.cfm">Sign in to download</a>"#,
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = SsrnResolver::with_base_url(None, mock_server.uri()).unwrap();
+        let step = resolver
+            .resolve("10.2139/ssrn.654321", &ResolveContext::default())
+            .await
+            .unwrap();
+        let ResolveStep::NeedsAuth(requirement) = step else {
+            panic!("expected NeedsAuth, got {step:?}");
+        };
+        assert_eq!(requirement.domain, "ssrn.com");
+        assert!(requirement.message.contains("auth capture"));
+    }
+}
@@ -0,0 +1,385 @@
+//! Unpaywall resolver for open-access copies of DOIs.
+//!
+//! Queries the Unpaywall REST API (`/v2/{doi}?email=...`) and returns
+//! `best_oa_location.url_for_pdf` with `title`/`authors`/`year` metadata.
+//! Registered at [`ResolverPriority::General`] ahead of Crossref, whose DOI
+//! step only redirects to the publisher landing page, so paywalled DOIs still
+//! pick up a repository or preprint copy when one exists.
+//!
+//! Unpaywall requires a contact email on every request; the resolver reuses the
+//! Crossref polite-pool `mailto`. DOIs without an open PDF, unknown DOIs, API
+//! errors, and network failures all return [`ResolveStep::Failed`] so the chain
+//! continues.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+use tracing::{debug, warn};
+
+use crate::parser::InputType;
+
+use super::http_client::{build_resolver_http_client, send_with_retry, standard_user_agent};
+use super::utils::{looks_like_doi, validate_crossref_mailto};
+use super::{ResolveContext, ResolveError, ResolveStep, ResolvedUrl, Resolver, ResolverPriority};
+
+// ==================== Constants ====================
+
+const DEFAULT_UNPAYWALL_BASE_URL: &str = "https://api.unpaywall.org";
+
+// ==================== API response types ====================
+
+#[derive(Debug, Deserialize)]
+struct UnpaywallWork {
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    year: Option<i32>,
+    #[serde(default)]
+    doi_url: Option<String>,
+    #[serde(default)]
+    z_authors: Option<Vec<UnpaywallAuthor>>,
+    #[serde(default)]
+    best_oa_location: Option<UnpaywallLocation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UnpaywallAuthor {
+    #[serde(default)]
+    given: Option<String>,
+    #[serde(default)]
+    family: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UnpaywallLocation {
+    #[serde(default)]
+    url_for_pdf: Option<String>,
+}
+
+// ==================== UnpaywallResolver ====================
+
+/// Resolves DOIs to open-access PDFs via the Unpaywall API.
+pub struct UnpaywallResolver {
+    client: Client,
+    base_url: String,
+    email: String,
+}
+
+impl UnpaywallResolver {
+    /// Creates a new `UnpaywallResolver` using the production API.
+    ///
+    /// `email` is sent with every request, as Unpaywall requires.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResolveError`] if HTTP client construction fails or `email` is invalid.
+    pub fn new(email: impl Into<String>) -> Result<Self, ResolveError> {
+        Self::build(email.into(), DEFAULT_UNPAYWALL_BASE_URL.to_string())
+    }
+
+    /// Creates an `UnpaywallResolver` with a custom API base URL.
+    ///
+    /// Intended for use in tests with a wiremock server.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResolveError`] if HTTP client construction fails or `email` is invalid.
+    pub fn with_base_url(
+        email: impl Into<String>,
+        base_url: impl Into<String>,
+    ) -> Result<Self, ResolveError> {
+        Self::build(
+            email.into(),
+            base_url.into().trim_end_matches('/').to_string(),
+        )
+    }
+
+    fn build(email: String, base_url: String) -> Result<Self, ResolveError> {
+        validate_crossref_mailto(&email)?;
+        let client =
+            build_resolver_http_client("unpaywall", standard_user_agent("unpaywall"), None)?;
+        Ok(Self {
+            client,
+            base_url,
+            email,
+        })
+    }
+
+    async fn fetch_work(
+        &self,
+        doi: &str,
+        input: &str,
+        ctx: &ResolveContext,
+    ) -> Result<UnpaywallWork, ResolveStep> {
+        let url = format!(
+            "{}/v2/{doi}?email={}",
+            self.base_url,
+            urlencoding::encode(&self.email)
+        );
+        ctx.throttle(&url).await;
+        debug!(api_url = %url, "Calling Unpaywall API");
+
+        let request = self.client.get(&url);
+        let response = match send_with_retry(request, "unpaywall").await {
+            Ok(resp) => resp,
+            Err(e) => {
+                warn!(error = %e, "Unpaywall request failed");
+                return Err(ResolveStep::Failed(ResolveError::resolution_failed(
+                    input,
+                    "Cannot reach the Unpaywall API. \
+                     Why: network error, timeout, or API unavailable. \
+                     Fix: check your internet connection; other resolvers are tried meanwhile.",
+                )));
+            }
+        };
+
+        let status = response.status();
+        if !status.is_success() {
+            let reason = match status.as_u16() {
+                404 => "Unpaywall has no record for this DOI".to_string(),
+                422 => "Unpaywall rejected the request. \
+                        Why: the contact email is not accepted. \
+                        Fix: set `crossref_mailto` to a real address."
+                    .to_string(),
+                429 => "Unpaywall rate limit exceeded. Try again later.".to_string(),
+                s if s >= 500 => "Unpaywall API unavailable. Try again later.".to_string(),
+                s => format!("Unpaywall API returned HTTP {s}"),
+            };
+            debug!(status = status.as_u16(), %reason, "Unpaywall API error");
+            return Err(ResolveStep::Failed(ResolveError::resolution_failed(
+                input, &reason,
+            )));
+        }
+
+        response.json::<UnpaywallWork>().await.map_err(|e| {
+            warn!(error = %e, "Failed to parse Unpaywall response");
+            ResolveStep::body_parse_failed(input, "Unpaywall")
+        })
+    }
+}
+
+impl std::fmt::Debug for UnpaywallResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UnpaywallResolver")
+            .field("base_url", &self.base_url)
+            .field("email", &self.email)
+            .finish_non_exhaustive()
+    }
+}
+
+// ==================== Resolver trait ====================
+
+#[async_trait]
+impl Resolver for UnpaywallResolver {
+    fn name(&self) -> &'static str {
+        "unpaywall"
+    }
+
+    fn priority(&self) -> ResolverPriority {
+        ResolverPriority::General
+    }
+
+    fn can_handle(&self, input: &str, input_type: InputType) -> bool {
+        input_type == InputType::Doi && looks_like_doi(input, "10.")
+    }
+
+    #[tracing::instrument(skip(self, ctx), fields(resolver = "unpaywall", input = %input))]
+    async fn resolve(
+        &self,
+        input: &str,
+        ctx: &ResolveContext,
+    ) -> Result<ResolveStep, ResolveError> {
+        let doi = input.trim();
+        let work = match self.fetch_work(doi, input, ctx).await {
+            Ok(work) => work,
+            Err(step) => return Ok(step),
+        };
+
+        let pdf_url = work
+            .best_oa_location
+            .as_ref()
+            .and_then(|location| location.url_for_pdf.as_deref())
+            .map(str::trim)
+            .filter(|url| url.starts_with("http://") || url.starts_with("https://"));
+        let Some(pdf_url) = pdf_url else {
+            debug!(doi, "Unpaywall lists no open-access PDF");
+            return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                "Unpaywall lists no open-access PDF for this DOI. \
+                 Why: the work may be paywalled or only have an open landing page. \
+                 Fix: other resolvers (e.g. Crossref) will be tried automatically.",
+            )));
+        };
+
+        Ok(ResolveStep::Url(ResolvedUrl::with_metadata(
+            pdf_url,
+            work_metadata(&work, doi),
+        )))
+    }
+}
+
+// ==================== Helpers ====================
+
+fn work_metadata(work: &UnpaywallWork, doi: &str) -> HashMap<String, String> {
+    let mut metadata = HashMap::new();
+    metadata.insert("doi".to_string(), doi.to_string());
+
+    if let Some(title) = work.title.as_deref().map(str::trim)
+        && !title.is_empty()
+    {
+        metadata.insert("title".to_string(), title.to_string());
+    }
+    let authors: Vec<String> = work
+        .z_authors
+        .iter()
+        .flatten()
+        .filter_map(author_name)
+        .collect();
+    if !authors.is_empty() {
+        metadata.insert("authors".to_string(), authors.join("; "));
+    }
+    if let Some(year) = work.year {
+        metadata.insert("year".to_string(), year.to_string());
+    }
+    if let Some(doi_url) = work.doi_url.as_deref() {
+        metadata.insert("source_url".to_string(), doi_url.to_string());
+    }
+    metadata
+}
+
+/// `Given Family`, or whichever part is present.
+fn author_name(author: &UnpaywallAuthor) -> Option<String> {
+    let parts: Vec<&str> = [author.given.as_deref(), author.family.as_deref()]
+        .into_iter()
+        .flatten()
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect();
+    (!parts.is_empty()).then(|| parts.join(" "))
+}
+
+// ==================== Tests ====================
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::test_support::socket_guard::start_mock_server_or_skip;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    const EMAIL: &str = "team@example.org";
+
+    #[test]
+    fn test_name_priority_and_can_handle() {
+        let resolver = UnpaywallResolver::new(EMAIL).unwrap();
+        assert_eq!(resolver.name(), "unpaywall");
+        assert_eq!(resolver.priority(), ResolverPriority::General);
+        assert!(resolver.can_handle("10.1234/example", InputType::Doi));
+        assert!(!resolver.can_handle("https://example.com/a.pdf", InputType::Url));
+        assert!(!resolver.can_handle("Smith (2020). Title.", InputType::Reference));
+    }
+
+    #[test]
+    fn test_rejects_invalid_email() {
+        assert!(UnpaywallResolver::new("invalid\nmailto@example.com").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_returns_best_oa_pdf_with_metadata() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/v2/10.1234/example"))
+            .and(query_param("email", EMAIL))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "doi": "10.1234/example",
+                "doi_url": "https://doi.org/10.1234/example",
+                "title": "Example Paper",
+                "year": 2021,
+                "z_authors": [
+                    {"given": "Ada", "family": "Lovelace"},
+                    {"family": "Babbage"}
+                ],
+                "is_oa": true,
+                "best_oa_location": {
+                    "url": "https://repository.example.org/record/1",
+                    "url_for_pdf": "https://repository.example.org/paper.pdf"
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = UnpaywallResolver::with_base_url(EMAIL, mock_server.uri()).unwrap();
+        let step = resolver
+            .resolve("10.1234/example", &ResolveContext::default())
+            .await
+            .unwrap();
+
+        let ResolveStep::Url(result) = step else {
+            panic!("expected Url step, got {step:?}");
+        };
+        assert_eq!(result.url, "https://repository.example.org/paper.pdf");
+        assert_eq!(result.metadata.get("title").unwrap(), "Example Paper");
+        assert_eq!(
+            result.metadata.get("authors").unwrap(),
+            "Ada Lovelace; Babbage"
+        );
+        assert_eq!(result.metadata.get("year").unwrap(), "2021");
+        assert_eq!(result.metadata.get("doi").unwrap(), "10.1234/example");
+        assert_eq!(
+            result.metadata.get("source_url").unwrap(),
+            "https://doi.org/10.1234/example"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_without_oa_pdf_or_record_fails_softly() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/v2/10.1234/closed"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "title": "Closed Paper",
+                "is_oa": true,
+                "best_oa_location": {"url": "https://publisher.example.com/landing", "url_for_pdf": null}
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v2/10.1234/missing"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = UnpaywallResolver::with_base_url(EMAIL, mock_server.uri()).unwrap();
+        for doi in ["10.1234/closed", "10.1234/missing"] {
+            let step = resolver
+                .resolve(doi, &ResolveContext::default())
+                .await
+                .unwrap();
+            assert!(
+                matches!(step, ResolveStep::Failed(_)),
+                "expected soft failure for {doi}, got {step:?}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_network_error_fails_softly() {
+        // Port 1 is reserved and refuses connections.
+        let resolver = UnpaywallResolver::with_base_url(EMAIL, "http://127.0.0.1:1").unwrap();
+        let step = resolver
+            .resolve("10.1234/example", &ResolveContext::default())
+            .await
+            .unwrap();
+        let ResolveStep::Failed(error) = step else {
+            panic!("expected soft failure, got {step:?}");
+        };
+        assert!(error.to_string().contains("Cannot reach the Unpaywall API"));
+    }
+}
@@ -156,6 +156,21 @@ pub fn validate_crossref_mailto(mailto: &str) -> Result<(), ResolveError> {
     Ok(())
 }
 
+/// Returns true if `value` has the shape of an email address: one `@` with a
+/// non-empty local part and domain, and no whitespace or control characters.
+#[must_use]
+pub fn looks_like_email(value: &str) -> bool {
+    let Some((local, domain)) = value.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !domain.is_empty()
+        && !domain.contains('@')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !value.chars().any(|c| c.is_whitespace() || c.is_control())
+}
+
 /// Returns true if the HTTP status code indicates authentication is required.
 #[must_use]
 pub fn is_auth_required_status(status: u16) -> bool {
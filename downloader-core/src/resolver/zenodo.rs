@@ -0,0 +1,492 @@
+//! Zenodo resolver for dataset and supplementary-file records.
+//!
+//! Recognizes `zenodo.org/record(s)/<id>` URLs, `10.5281/zenodo.<id>` DOIs,
+//! and `doi.org` links to them. The record is fetched from the Zenodo REST
+//! API (`/api/records/<id>`) and resolved to one of its files: the largest
+//! PDF if there is one, otherwise the largest file. Every other file is
+//! returned in [`ResolvedUrl::fallback_urls`].
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+use tracing::{debug, warn};
+use url::Url;
+
+use crate::parser::InputType;
+
+use super::http_client::{build_resolver_http_client, send_with_retry, standard_user_agent};
+use super::utils::{canonical_host, extract_year_from_str};
+use super::{ResolveContext, ResolveError, ResolveStep, ResolvedUrl, Resolver, ResolverPriority};
+
+// ==================== Constants ====================
+
+const DEFAULT_ZENODO_BASE_URL: &str = "https://zenodo.org";
+const ZENODO_HOST: &str = "zenodo.org";
+const ZENODO_DOI_PREFIX: &str = "10.5281/zenodo.";
+const DOI_HOSTS: &[&str] = &["doi.org", "dx.doi.org"];
+
+// ==================== API response types ====================
+
+#[derive(Debug, Deserialize)]
+struct ZenodoRecord {
+    #[serde(default)]
+    doi: Option<String>,
+    #[serde(default)]
+    metadata: ZenodoMetadata,
+    #[serde(default)]
+    files: Vec<ZenodoFile>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ZenodoMetadata {
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    creators: Vec<ZenodoCreator>,
+    #[serde(default)]
+    publication_date: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ZenodoCreator {
+    #[serde(default)]
+    name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ZenodoFile {
+    /// File name; older API versions call it `filename`.
+    #[serde(default, alias = "filename")]
+    key: Option<String>,
+    #[serde(default)]
+    size: u64,
+    #[serde(default)]
+    links: ZenodoFileLinks,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ZenodoFileLinks {
+    #[serde(default, rename = "self")]
+    self_link: Option<String>,
+}
+
+// ==================== ZenodoResolver ====================
+
+/// Specialized resolver for Zenodo records.
+pub struct ZenodoResolver {
+    client: Client,
+    base_url: String,
+}
+
+impl ZenodoResolver {
+    /// Creates a new `ZenodoResolver` using the production Zenodo API.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResolveError`] if HTTP client construction fails.
+    pub fn new() -> Result<Self, ResolveError> {
+        Self::build(DEFAULT_ZENODO_BASE_URL.to_string())
+    }
+
+    /// Creates a `ZenodoResolver` with a custom API base URL.
+    ///
+    /// Intended for use in tests with a wiremock server.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResolveError`] if HTTP client construction fails.
+    pub fn with_base_url(base_url: impl Into<String>) -> Result<Self, ResolveError> {
+        Self::build(base_url.into())
+    }
+
+    fn build(base_url: String) -> Result<Self, ResolveError> {
+        let user_agent = standard_user_agent("zenodo");
+        let client = build_resolver_http_client("zenodo", user_agent, None)?;
+        Ok(Self { client, base_url })
+    }
+
+    async fn fetch_record(
+        &self,
+        record_id: &str,
+        input: &str,
+        ctx: &ResolveContext,
+    ) -> Result<ZenodoRecord, ResolveStep> {
+        let url = format!("{}/api/records/{record_id}", self.base_url);
+        ctx.throttle(&url).await;
+        debug!(api_url = %url, "Calling Zenodo records API");
+
+        let response = match send_with_retry(self.client.get(&url), "zenodo").await {
+            Ok(resp) => resp,
+            Err(e) => {
+                warn!(error = %e, "Zenodo API request failed");
+                return Err(ResolveStep::Failed(ResolveError::resolution_failed(
+                    input,
+                    "Cannot reach the Zenodo API. Check your network connection.",
+                )));
+            }
+        };
+
+        let status = response.status();
+        if !status.is_success() {
+            let reason = match status.as_u16() {
+                404 | 410 => format!("Zenodo record {record_id} not found or withdrawn"),
+                429 => "Zenodo rate limit exceeded. Try again in a few seconds.".to_string(),
+                s if s >= 500 => "Zenodo API unavailable. Try again later.".to_string(),
+                s => format!("Zenodo API returned HTTP {s}"),
+            };
+            debug!(status = status.as_u16(), %reason, "Zenodo API error");
+            return Err(ResolveStep::Failed(ResolveError::resolution_failed(
+                input, &reason,
+            )));
+        }
+
+        response.json::<ZenodoRecord>().await.map_err(|e| {
+            warn!(error = %e, "Failed to parse Zenodo response");
+            ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                "Unexpected Zenodo API response format",
+            ))
+        })
+    }
+}
+
+impl std::fmt::Debug for ZenodoResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ZenodoResolver")
+            .field("base_url", &self.base_url)
+            .finish_non_exhaustive()
+    }
+}
+
+// ==================== Resolver trait ====================
+
+#[async_trait]
+impl Resolver for ZenodoResolver {
+    fn name(&self) -> &'static str {
+        "zenodo"
+    }
+
+    fn priority(&self) -> ResolverPriority {
+        ResolverPriority::Specialized
+    }
+
+    fn can_handle(&self, input: &str, input_type: InputType) -> bool {
+        matches!(input_type, InputType::Url | InputType::Doi) && extract_record_id(input).is_some()
+    }
+
+    #[tracing::instrument(skip(self, ctx), fields(resolver = "zenodo", input = %input))]
+    async fn resolve(
+        &self,
+        input: &str,
+        ctx: &ResolveContext,
+    ) -> Result<ResolveStep, ResolveError> {
+        let Some(record_id) = extract_record_id(input) else {
+            return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                "Input is not a recognized Zenodo record URL or DOI",
+            )));
+        };
+
+        let record = match self.fetch_record(&record_id, input, ctx).await {
+            Ok(record) => record,
+            Err(step) => return Ok(step),
+        };
+
+        let mut file_urls = ranked_file_urls(&record.files);
+        if file_urls.is_empty() {
+            return Ok(ResolveStep::Failed(ResolveError::resolution_failed(
+                input,
+                "Zenodo record has no openly downloadable files. \
+                 Why: access may be restricted or the record is metadata-only. \
+                 Fix: request access on the Zenodo record page.",
+            )));
+        }
+        let primary = file_urls.remove(0);
+
+        let mut metadata = record_metadata(&record, &record_id);
+        metadata.insert(
+            "source_url".to_string(),
+            format!("{}/records/{record_id}", self.base_url),
+        );
+
+        Ok(ResolveStep::Url(
+            ResolvedUrl::with_metadata(primary, metadata).with_fallback_urls(file_urls),
+        ))
+    }
+}
+
+// ==================== Helpers ====================
+
+/// Extracts the numeric record ID from a Zenodo URL, DOI, or `doi.org` link.
+fn extract_record_id(input: &str) -> Option<String> {
+    let trimmed = input.trim();
+    if let Some(id) = record_id_from_doi(trimmed) {
+        return Some(id);
+    }
+
+    let parsed = Url::parse(trimmed).ok()?;
+    let host = canonical_host(parsed.host_str()?);
+    if DOI_HOSTS.contains(&host.as_str()) {
+        let doi = urlencoding::decode(parsed.path().trim_start_matches('/')).ok()?;
+        return record_id_from_doi(&doi);
+    }
+    if host != ZENODO_HOST {
+        return None;
+    }
+
+    let mut segments = parsed.path_segments()?;
+    match segments.next()? {
+        "record" | "records" => segments
+            .next()
+            .filter(|id| is_record_id(id))
+            .map(str::to_string),
+        _ => None,
+    }
+}
+
+fn record_id_from_doi(doi: &str) -> Option<String> {
+    let lowered = doi.to_ascii_lowercase();
+    let id = lowered.strip_prefix(ZENODO_DOI_PREFIX)?;
+    is_record_id(id).then(|| id.to_string())
+}
+
+fn is_record_id(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Orders downloadable file links: the largest PDF first (or the largest file
+/// when there is no PDF), then the remaining files in record order.
+fn ranked_file_urls(files: &[ZenodoFile]) -> Vec<String> {
+    let downloadable: Vec<(&ZenodoFile, &str)> = files
+        .iter()
+        .filter_map(|file| file.links.self_link.as_deref().map(|url| (file, url)))
+        .collect();
+
+    let primary_index = downloadable
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, (file, _))| (is_pdf(file), file.size))
+        .map(|(index, _)| index);
+
+    let mut urls = Vec::with_capacity(downloadable.len());
+    if let Some(index) = primary_index {
+        urls.push(downloadable[index].1.to_string());
+    }
+    urls.extend(
+        downloadable
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| Some(*index) != primary_index)
+            .map(|(_, (_, url))| (*url).to_string()),
+    );
+    urls
+}
+
+fn is_pdf(file: &ZenodoFile) -> bool {
+    file.key.as_deref().is_some_and(|key| {
+        std::path::Path::new(key)
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("pdf"))
+    })
+}
+
+fn record_metadata(record: &ZenodoRecord, record_id: &str) -> HashMap<String, String> {
+    let mut metadata = HashMap::new();
+
+    if let Some(title) = record.metadata.title.as_deref().map(str::trim)
+        && !title.is_empty()
+    {
+        metadata.insert("title".to_string(), title.to_string());
+    }
+
+    let authors: Vec<&str> = record
+        .metadata
+        .creators
+        .iter()
+        .filter_map(|creator| creator.name.as_deref())
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .collect();
+    if !authors.is_empty() {
+        metadata.insert("authors".to_string(), authors.join("; "));
+    }
+
+    if let Some(year) = record
+        .metadata
+        .publication_date
+        .as_deref()
+        .and_then(extract_year_from_str)
+    {
+        metadata.insert("year".to_string(), year);
+    }
+
+    let doi = record
+        .doi
+        .clone()
+        .unwrap_or_else(|| format!("{ZENODO_DOI_PREFIX}{record_id}"));
+    metadata.insert("doi".to_string(), doi);
+
+    metadata
+}
+
+// ==================== Tests ====================
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::test_support::socket_guard::start_mock_server_or_skip;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    fn file(key: &str, size: u64) -> ZenodoFile {
+        ZenodoFile {
+            key: Some(key.to_string()),
+            size,
+            links: ZenodoFileLinks {
+                self_link: Some(format!("https://zenodo.org/files/{key}")),
+            },
+        }
+    }
+
+    #[test]
+    fn test_extract_record_id_from_supported_inputs() {
+        for input in [
+            "https://zenodo.org/record/1234567",
+            "https://zenodo.org/records/1234567/files/data.csv",
+            "https://www.zenodo.org/records/1234567",
+            "10.5281/zenodo.1234567",
+            "10.5281/ZENODO.1234567",
+            "https://doi.org/10.5281/zenodo.1234567",
+        ] {
+            assert_eq!(
+                extract_record_id(input).as_deref(),
+                Some("1234567"),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_extract_record_id_rejects_other_inputs() {
+        for input in [
+            "https://zenodo.org/communities/foo",
+            "https://zenodo.org/records/abc",
+            "https://example.com/records/1234567",
+            "10.1234/zenodo.1234567",
+            "https://doi.org/10.1000/xyz",
+        ] {
+            assert!(extract_record_id(input).is_none(), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_ranked_file_urls_prefers_largest_pdf() {
+        let files = vec![
+            file("data.zip", 10_000),
+            file("paper.pdf", 500),
+            file("appendix.pdf", 200),
+        ];
+        assert_eq!(
+            ranked_file_urls(&files),
+            vec![
+                "https://zenodo.org/files/paper.pdf",
+                "https://zenodo.org/files/data.zip",
+                "https://zenodo.org/files/appendix.pdf",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ranked_file_urls_falls_back_to_largest_file() {
+        let files = vec![file("small.csv", 10), file("large.csv", 1_000)];
+        assert_eq!(
+            ranked_file_urls(&files)[0],
+            "https://zenodo.org/files/large.csv"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_record_returns_primary_file_and_metadata() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/api/records/1234567"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "doi": "10.5281/zenodo.1234567",
+                "metadata": {
+                    "title": "Survey Dataset",
+                    "creators": [{"name": "Doe, Jane"}, {"name": "Roe, Rick"}],
+                    "publication_date": "2021-03-04"
+                },
+                "files": [
+                    {"key": "data.csv", "size": 9000,
+                     "links": {"self": "https://zenodo.org/api/records/1234567/files/data.csv/content"}},
+                    {"key": "report.pdf", "size": 300,
+                     "links": {"self": "https://zenodo.org/api/records/1234567/files/report.pdf/content"}}
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = ZenodoResolver::with_base_url(mock_server.uri()).unwrap();
+        let step = resolver
+            .resolve("10.5281/zenodo.1234567", &ResolveContext::default())
+            .await
+            .unwrap();
+
+        let ResolveStep::Url(result) = step else {
+            panic!("expected ResolveStep::Url, got {step:?}");
+        };
+        assert_eq!(
+            result.url,
+            "https://zenodo.org/api/records/1234567/files/report.pdf/content"
+        );
+        assert_eq!(
+            result.fallback_urls,
+            vec!["https://zenodo.org/api/records/1234567/files/data.csv/content"]
+        );
+        assert_eq!(result.metadata["title"], "Survey Dataset");
+        assert_eq!(result.metadata["authors"], "Doe, Jane; Roe, Rick");
+        assert_eq!(result.metadata["year"], "2021");
+        assert_eq!(result.metadata["doi"], "10.5281/zenodo.1234567");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_record_without_files_fails_softly() {
+        let Some(mock_server) = start_mock_server_or_skip().await else {
+            return;
+        };
+        Mock::given(method("GET"))
+            .and(path("/api/records/42"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "metadata": {"title": "Restricted"},
+                "files": []
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/records/404404"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = ZenodoResolver::with_base_url(mock_server.uri()).unwrap();
+        for input in [
+            "https://zenodo.org/records/42",
+            "https://zenodo.org/records/404404",
+        ] {
+            let step = resolver
+                .resolve(input, &ResolveContext::default())
+                .await
+                .unwrap();
+            assert!(
+                matches!(step, ResolveStep::Failed(_)),
+                "expected soft failure for {input}, got {step:?}"
+            );
+        }
+    }
+}
@@ -1,7 +1,8 @@
-//! JSON-LD sidecar file generation for downloaded documents.
+//! Sidecar file generation for downloaded documents.
 //!
-//! Writes machine-readable metadata files (`.json`) alongside downloaded files
-//! following the Schema.org/ScholarlyArticle vocabulary.
+//! Writes machine-readable metadata files alongside downloaded files: JSON-LD
+//! (`.json`) following the Schema.org/ScholarlyArticle vocabulary by default,
+//! or a single-record RIS file (`.ris`) for direct reference-manager import.
 //!
 //! # Module structure note
 //!
@@ -20,7 +21,8 @@ use serde::Serialize;
 use thiserror::Error;
 use tracing::{debug, instrument};
 
-use crate::atomic_write::atomic_write_json;
+use crate::atomic_write::{atomic_write, atomic_write_json};
+use crate::export::{SidecarAuthor, SidecarEntry, generate_ris, parse_author_name};
 use crate::queue::QueueItem;
 
 static QUARANTINE_SEQ: AtomicU64 = AtomicU64::new(0);
@@ -41,10 +43,33 @@ pub enum SidecarError {
 /// Used by callers in `main.rs` to check the enabled flag before calling
 /// `generate_sidecar()`. The function itself does not take a `SidecarConfig`
 /// parameter — the enabled check is the caller's responsibility.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct SidecarConfig {
     /// Whether sidecar generation is active for this run.
     pub enabled: bool,
+    /// File format written for each sidecar.
+    pub format: SidecarFormat,
+}
+
+/// On-disk format of a generated sidecar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SidecarFormat {
+    /// Schema.org `ScholarlyArticle` JSON-LD (`.json`).
+    #[default]
+    JsonLd,
+    /// Single-record RIS (`.ris`).
+    Ris,
+}
+
+impl SidecarFormat {
+    /// File extension (without the dot) used for sidecars of this format.
+    #[must_use]
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::JsonLd => "json",
+            Self::Ris => "ris",
+        }
+    }
 }
 
 /// Schema.org/ScholarlyArticle JSON-LD document root.
@@ -64,6 +89,8 @@ struct ScholarlyArticle {
     identifier: Option<DoiIdentifier>,
     #[serde(skip_serializing_if = "Option::is_none")]
     url: Option<String>,
+    #[serde(rename = "abstract", skip_serializing_if = "Option::is_none")]
+    abstract_text: Option<String>,
 }
 
 /// A single author entry in the JSON-LD document.
@@ -71,7 +98,15 @@ struct ScholarlyArticle {
 struct Author {
     #[serde(rename = "@type")]
     type_: &'static str,
+    /// ORCID iD URL (`https://orcid.org/<id>`) when known.
+    #[serde(rename = "@id", skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    /// Full display form, exactly as the metadata gave it.
     name: String,
+    #[serde(rename = "familyName", skip_serializing_if = "Option::is_none")]
+    family_name: Option<String>,
+    #[serde(rename = "givenName", skip_serializing_if = "Option::is_none")]
+    given_name: Option<String>,
 }
 
 /// DOI expressed as a Schema.org `PropertyValue`.
@@ -86,10 +121,24 @@ struct DoiIdentifier {
 
 /// Generates a JSON-LD sidecar file alongside the downloaded file for `item`.
 ///
+/// Equivalent to [`generate_sidecar_with_format`] with [`SidecarFormat::JsonLd`].
+///
+/// # Errors
+///
+/// Returns [`SidecarError`] on I/O or serialization failure.
+pub fn generate_sidecar(item: &QueueItem) -> Result<Option<PathBuf>, SidecarError> {
+    generate_sidecar_with_format(item, SidecarFormat::JsonLd)
+}
+
+/// Generates a sidecar file in `format` alongside the downloaded file for `item`.
+///
 /// Returns `None` (with a `debug!` log) if:
 /// - `item.saved_path` is `None` (download location unknown)
 /// - the sidecar file already exists on disk (idempotent by design)
 ///
+/// An existing sidecar that does not parse as `format` is quarantined and
+/// regenerated.
+///
 /// Returns `Some(sidecar_path)` on success.
 ///
 /// Callers MUST check `SidecarConfig::enabled` before calling this function.
@@ -98,7 +147,10 @@ struct DoiIdentifier {
 ///
 /// Returns [`SidecarError`] on I/O or serialization failure.
 #[instrument(fields(item_id = item.id, saved_path = ?item.saved_path))]
-pub fn generate_sidecar(item: &QueueItem) -> Result<Option<PathBuf>, SidecarError> {
+pub fn generate_sidecar_with_format(
+    item: &QueueItem,
+    format: SidecarFormat,
+) -> Result<Option<PathBuf>, SidecarError> {
     let Some(ref saved_path_str) = item.saved_path else {
         debug!("No saved_path, skipping sidecar generation");
         return Ok(None);
@@ -112,9 +164,9 @@ pub fn generate_sidecar(item: &QueueItem) -> Result<Option<PathBuf>, SidecarErro
         );
         return Ok(None);
     }
-    let sidecar_path = derive_sidecar_path(saved_path);
+    let sidecar_path = derive_sidecar_path(saved_path, format);
     if sidecar_path.exists() {
-        if sidecar_is_valid_json(&sidecar_path) {
+        if sidecar_is_valid(&sidecar_path, format) {
             debug!(
                 path = %sidecar_path.display(),
                 "Sidecar already exists, skipping"
@@ -129,30 +181,43 @@ pub fn generate_sidecar(item: &QueueItem) -> Result<Option<PathBuf>, SidecarErro
         );
     }
 
-    let article = build_scholarly_article(item);
-    atomic_write_json(&sidecar_path, &article)?;
+    match format {
+        SidecarFormat::JsonLd => atomic_write_json(&sidecar_path, &build_scholarly_article(item))?,
+        SidecarFormat::Ris => atomic_write(
+            &sidecar_path,
+            build_ris_record(item, &sidecar_path).as_bytes(),
+        )?,
+    }
 
     debug!(path = %sidecar_path.display(), "Sidecar created");
     Ok(Some(sidecar_path))
 }
 
-/// Derives the sidecar `.json` path from a downloaded file path.
+/// Derives the sidecar path from a downloaded file path by swapping in the
+/// extension for `format`.
 ///
-/// Examples:
+/// Examples (JSON-LD):
 /// - `paper.pdf` → `paper.json`
 /// - `article.html` → `article.json`
 /// - `no_extension` → `no_extension.json`
-fn derive_sidecar_path(downloaded_path: &Path) -> PathBuf {
+fn derive_sidecar_path(downloaded_path: &Path, format: SidecarFormat) -> PathBuf {
     let mut p = downloaded_path.to_path_buf();
-    p.set_extension("json");
+    p.set_extension(format.extension());
     p
 }
 
-fn sidecar_is_valid_json(path: &Path) -> bool {
-    match fs::read_to_string(path) {
-        Ok(content) => serde_json::from_str::<serde_json::Value>(&content).is_ok(),
-        Err(err) if err.kind() == ErrorKind::NotFound => false,
-        Err(_) => false,
+fn sidecar_is_valid(path: &Path, format: SidecarFormat) -> bool {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == ErrorKind::NotFound => return false,
+        Err(_) => return false,
+    };
+    match format {
+        SidecarFormat::JsonLd => serde_json::from_str::<serde_json::Value>(&content).is_ok(),
+        SidecarFormat::Ris => {
+            content.trim_start().starts_with("TY  -")
+                && content.lines().any(|line| line.trim_end() == "ER  -")
+        }
     }
 }
 
@@ -186,14 +251,18 @@ fn sidecar_quarantine_path(path: &Path) -> PathBuf {
         .file_stem()
         .and_then(|value| value.to_str())
         .unwrap_or("sidecar");
-    let filename = format!("{stem}.corrupt.{ts}.{pid}.{seq}.json");
+    let extension = path
+        .extension()
+        .and_then(|value| value.to_str())
+        .unwrap_or("json");
+    let filename = format!("{stem}.corrupt.{ts}.{pid}.{seq}.{extension}");
     path.with_file_name(filename)
 }
 
 /// Builds a `ScholarlyArticle` from `QueueItem` metadata.
 fn build_scholarly_article(item: &QueueItem) -> ScholarlyArticle {
     let author = item.meta_authors.as_deref().and_then(|s| {
-        let authors = parse_authors(s);
+        let authors = parse_authors_with_orcids(s, item.meta_author_orcids.as_deref());
         if authors.is_empty() {
             None
         } else {
@@ -219,9 +288,38 @@ fn build_scholarly_article(item: &QueueItem) -> ScholarlyArticle {
         date_published: item.meta_year.clone().filter(|s| !s.is_empty()),
         identifier,
         url: Some(item.url.clone()),
+        abstract_text: item.meta_abstract.clone().filter(|s| !s.trim().is_empty()),
     }
 }
 
+/// Renders `item` as a single RIS record.
+///
+/// Reuses the corpus exporter so `.ris` sidecars and `downloader export --format ris`
+/// produce identical records; authors go through [`parse_authors`] so each gets
+/// its own `AU` line.
+fn build_ris_record(item: &QueueItem, sidecar_path: &Path) -> String {
+    let entry = SidecarEntry {
+        path: sidecar_path.to_path_buf(),
+        title: item.meta_title.clone().filter(|s| !s.is_empty()),
+        authors: item
+            .meta_authors
+            .as_deref()
+            .map(parse_authors)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|author| SidecarAuthor {
+                name: author.name,
+                family_name: author.family_name,
+                given_name: author.given_name,
+            })
+            .collect(),
+        date_published: item.meta_year.clone().filter(|s| !s.is_empty()),
+        doi: item.meta_doi.clone().filter(|s| !s.is_empty()),
+        url: Some(item.url.clone()),
+    };
+    generate_ris(std::slice::from_ref(&entry))
+}
+
 /// Parses a metadata author string into individual `Author` entries.
 ///
 /// Strategy (per audit recommendation QA-2):
@@ -230,8 +328,19 @@ fn build_scholarly_article(item: &QueueItem) -> ScholarlyArticle {
 /// 3. Fallback: keep the entire input as a single author to avoid mis-splitting
 ///    family/given name forms like `"Smith, John"`.
 ///
-/// Each token is trimmed of whitespace. Empty tokens are discarded.
+/// Each token is trimmed of whitespace. Empty tokens are discarded. Tokens
+/// that split unambiguously also get `familyName`/`givenName`; `name` keeps the
+/// full display form either way.
 fn parse_authors(authors_str: &str) -> Vec<Author> {
+    parse_authors_with_orcids(authors_str, None)
+}
+
+/// Like [`parse_authors`], attaching ORCID iDs from a `;`-separated list.
+///
+/// ORCID slots are matched to author tokens by position *before* empty tokens
+/// are dropped, so the two lists stay aligned. Empty or malformed slots leave
+/// that author without an `@id`.
+fn parse_authors_with_orcids(authors_str: &str, orcids: Option<&str>) -> Vec<Author> {
     let normalized = authors_str.trim();
     if normalized.is_empty() {
         return Vec::new();
@@ -252,17 +361,52 @@ fn parse_authors(authors_str: &str) -> Vec<Author> {
         }
     };
 
+    let orcid_slots: Vec<&str> = orcids.map(|o| o.split(';').collect()).unwrap_or_default();
+
     tokens
         .into_iter()
-        .map(str::trim)
-        .filter(|t| !t.is_empty())
-        .map(|name| Author {
-            type_: "Person",
-            name: name.to_string(),
+        .enumerate()
+        .map(|(idx, token)| (idx, token.trim()))
+        .filter(|(_, name)| !name.is_empty())
+        .map(|(idx, name)| {
+            let structured = parse_author_name(name).filter(|parsed| !parsed.is_ambiguous());
+            let (family_name, given_name) =
+                structured.map_or((None, None), |parsed| (Some(parsed.family), parsed.given));
+            Author {
+                type_: "Person",
+                id: orcid_slots.get(idx).copied().and_then(orcid_url),
+                name: name.to_string(),
+                family_name,
+                given_name,
+            }
         })
         .collect()
 }
 
+/// Normalizes a bare ORCID iD or `orcid.org` URL to `https://orcid.org/<id>`.
+///
+/// Returns `None` unless the iD has the `0000-0000-0000-000X` shape.
+fn orcid_url(value: &str) -> Option<String> {
+    let value = value.trim();
+    let lower = value.to_ascii_lowercase();
+    let id = ["https://orcid.org/", "http://orcid.org/", "orcid.org/"]
+        .iter()
+        .find(|prefix| lower.starts_with(*prefix))
+        .map_or(value, |prefix| &value[prefix.len()..])
+        .trim_end_matches('/')
+        .to_ascii_uppercase();
+
+    let groups: Vec<&str> = id.split('-').collect();
+    let well_formed = groups.len() == 4
+        && groups.iter().all(|group| group.len() == 4)
+        && id
+            .chars()
+            .filter(|c| *c != '-')
+            .enumerate()
+            .all(|(pos, c)| c.is_ascii_digit() || (pos == 15 && c == 'X'));
+    well_formed.then(|| format!("https://orcid.org/{id}"))
+}
+
 fn looks_like_full_name(token: &str) -> bool {
     token.split_whitespace().count() >= 2
 }
@@ -354,6 +498,8 @@ mod tests {
             suggested_filename: None,
             meta_title: title.map(String::from),
             meta_authors: authors.map(String::from),
+            meta_author_orcids: None,
+            meta_abstract: None,
             meta_year: year.map(String::from),
             meta_doi: doi.map(String::from),
             topics: None,
@@ -362,6 +508,10 @@ mod tests {
             saved_path: saved_path.map(String::from),
             bytes_downloaded: 0,
             content_length: None,
+            dead_letter: false,
+            read_timeout_secs: None,
+            resolver_path: None,
+            input_source_file: None,
             created_at: "2026-01-01".to_string(),
             updated_at: "2026-01-01".to_string(),
         }
@@ -372,14 +522,17 @@ mod tests {
     #[test]
     fn test_sidecar_path_replaces_pdf_extension() {
         let path = Path::new("/tmp/paper.pdf");
-        assert_eq!(derive_sidecar_path(path), PathBuf::from("/tmp/paper.json"));
+        assert_eq!(
+            derive_sidecar_path(path, SidecarFormat::JsonLd),
+            PathBuf::from("/tmp/paper.json")
+        );
     }
 
     #[test]
     fn test_sidecar_path_replaces_html_extension() {
         let path = Path::new("/tmp/article.html");
         assert_eq!(
-            derive_sidecar_path(path),
+            derive_sidecar_path(path, SidecarFormat::JsonLd),
             PathBuf::from("/tmp/article.json")
         );
     }
@@ -388,11 +541,20 @@ mod tests {
     fn test_sidecar_path_no_extension_appends_json() {
         let path = Path::new("/tmp/no_extension");
         assert_eq!(
-            derive_sidecar_path(path),
+            derive_sidecar_path(path, SidecarFormat::JsonLd),
             PathBuf::from("/tmp/no_extension.json")
         );
     }
 
+    #[test]
+    fn test_sidecar_path_ris_replaces_pdf_extension() {
+        let path = Path::new("/tmp/paper.pdf");
+        assert_eq!(
+            derive_sidecar_path(path, SidecarFormat::Ris),
+            PathBuf::from("/tmp/paper.ris")
+        );
+    }
+
     // ───── parse_authors ────────────────────────────────────────────────────
 
     #[test]
@@ -481,6 +643,29 @@ mod tests {
         assert_eq!(authors[1]["name"], "Noam Shazeer");
     }
 
+    #[test]
+    fn test_scholarly_article_adds_structured_names_only_when_unambiguous() {
+        let item = make_item(
+            Some("/tmp/paper.pdf"),
+            None,
+            Some("Smith, John; Jan van der Berg; Maria Garcia Lopez"),
+            None,
+            None,
+            "https://example.com/paper.pdf",
+        );
+        let json = serde_json::to_value(build_scholarly_article(&item)).unwrap();
+
+        let authors = json["author"].as_array().unwrap();
+        assert_eq!(authors[0]["name"], "Smith, John");
+        assert_eq!(authors[0]["familyName"], "Smith");
+        assert_eq!(authors[0]["givenName"], "John");
+        assert_eq!(authors[1]["name"], "Jan van der Berg");
+        assert_eq!(authors[1]["familyName"], "van der Berg");
+        assert_eq!(authors[2]["name"], "Maria Garcia Lopez");
+        assert!(authors[2].get("familyName").is_none());
+        assert!(authors[2].get("givenName").is_none());
+    }
+
     #[test]
     fn test_scholarly_article_missing_doi_omits_identifier_field() {
         let item = make_item(
@@ -506,6 +691,29 @@ mod tests {
             json.get("datePublished").is_none(),
             "datePublished should be absent when year is None"
         );
+        assert!(
+            json.get("abstract").is_none(),
+            "abstract should be absent when the item has none"
+        );
+    }
+
+    #[test]
+    fn test_scholarly_article_emits_abstract_when_present() {
+        let mut item = make_item(
+            Some("/tmp/paper.pdf"),
+            Some("Test Paper"),
+            None,
+            None,
+            None,
+            "https://example.com/paper.pdf",
+        );
+        item.meta_abstract = Some("We test abstracts.".to_string());
+        let json = serde_json::to_value(build_scholarly_article(&item)).unwrap();
+        assert_eq!(json["abstract"], "We test abstracts.");
+
+        item.meta_abstract = Some("   ".to_string());
+        let json = serde_json::to_value(build_scholarly_article(&item)).unwrap();
+        assert!(json.get("abstract").is_none());
     }
 
     #[test]
@@ -523,6 +731,53 @@ mod tests {
         assert_eq!(json["url"], "https://example.com/paper.pdf");
     }
 
+    #[test]
+    fn test_scholarly_article_emits_orcid_ids_only_for_authors_that_have_them() {
+        let mut item = make_item(
+            Some("/tmp/paper.pdf"),
+            None,
+            Some("First, A; Second, B; Third, C"),
+            None,
+            None,
+            "https://example.com/paper.pdf",
+        );
+        item.meta_author_orcids =
+            Some("http://orcid.org/0000-0002-1825-0097;;0000-0001-5109-370x".to_string());
+        let json = serde_json::to_value(build_scholarly_article(&item)).unwrap();
+
+        let authors = json["author"].as_array().unwrap();
+        assert_eq!(authors.len(), 3);
+        assert_eq!(authors[0]["@id"], "https://orcid.org/0000-0002-1825-0097");
+        assert!(authors[1].get("@id").is_none());
+        assert_eq!(authors[1]["name"], "Second, B");
+        assert_eq!(authors[2]["@id"], "https://orcid.org/0000-0001-5109-370X");
+    }
+
+    #[test]
+    fn test_parse_authors_with_orcids_keeps_alignment_across_empty_tokens() {
+        let authors =
+            parse_authors_with_orcids("Smith, J.;; Doe, J.", Some(";0000-0002-1825-0097;bogus"));
+        assert_eq!(authors.len(), 2);
+        assert_eq!(authors[0].name, "Smith, J.");
+        assert!(authors[0].id.is_none());
+        assert_eq!(authors[1].name, "Doe, J.");
+        assert!(
+            authors[1].id.is_none(),
+            "malformed ORCID in the matching slot is dropped"
+        );
+    }
+
+    #[test]
+    fn test_orcid_url_rejects_malformed_ids() {
+        assert_eq!(
+            orcid_url("https://orcid.org/0000-0002-1825-0097/").as_deref(),
+            Some("https://orcid.org/0000-0002-1825-0097")
+        );
+        assert!(orcid_url("").is_none());
+        assert!(orcid_url("0000-0002-1825").is_none());
+        assert!(orcid_url("0000-000X-1825-0097").is_none());
+    }
+
     // ───── generate_sidecar ─────────────────────────────────────────────────
 
     #[test]
@@ -768,4 +1023,157 @@ mod tests {
         let result = generate_sidecar(&item).unwrap().unwrap();
         assert_eq!(result, tmp.path().join("paper.json"));
     }
+
+    // ───── generate_sidecar_with_format (RIS) ───────────────────────────────
+
+    #[test]
+    fn test_generate_ris_sidecar_creates_file_with_correct_content() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let pdf_path = tmp.path().join("paper.pdf");
+        std::fs::write(&pdf_path, b"fake pdf content").unwrap();
+
+        let item = make_item(
+            Some(pdf_path.to_str().unwrap()),
+            Some("Test Paper"),
+            Some("Smith, J.; Doe, J."),
+            Some("2024"),
+            Some("10.1234/test"),
+            "https://example.com/paper.pdf",
+        );
+
+        let result = generate_sidecar_with_format(&item, SidecarFormat::Ris).unwrap();
+        let sidecar_path = tmp.path().join("paper.ris");
+        assert_eq!(result.as_deref(), Some(sidecar_path.as_path()));
+        assert!(
+            !tmp.path().join("paper.json").exists(),
+            "RIS format must not also write a JSON-LD sidecar"
+        );
+
+        let content = std::fs::read_to_string(&sidecar_path).unwrap();
+        assert_eq!(
+            content,
+            "TY  - JOUR\n\
+             TI  - Test Paper\n\
+             AU  - Smith, J.\n\
+             AU  - Doe, J.\n\
+             PY  - 2024\n\
+             DO  - 10.1234/test\n\
+             UR  - https://example.com/paper.pdf\n\
+             ER  - \n"
+        );
+    }
+
+    #[test]
+    fn test_generate_ris_sidecar_missing_metadata_keeps_type_url_and_terminator() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let pdf_path = tmp.path().join("paper.pdf");
+        std::fs::write(&pdf_path, b"fake pdf").unwrap();
+
+        let item = make_item(
+            Some(pdf_path.to_str().unwrap()),
+            None,
+            None,
+            None,
+            None,
+            "https://example.com/paper.pdf",
+        );
+
+        generate_sidecar_with_format(&item, SidecarFormat::Ris).unwrap();
+        let content = std::fs::read_to_string(tmp.path().join("paper.ris")).unwrap();
+        assert_eq!(
+            content,
+            "TY  - JOUR\nUR  - https://example.com/paper.pdf\nER  - \n"
+        );
+    }
+
+    #[test]
+    fn test_generate_ris_sidecar_existing_sidecar_not_overwritten() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let pdf_path = tmp.path().join("paper.pdf");
+        std::fs::write(&pdf_path, b"fake pdf").unwrap();
+
+        let sidecar_path = tmp.path().join("paper.ris");
+        let sentinel = "TY  - JOUR\nTI  - Original\nER  - \n";
+        std::fs::write(&sidecar_path, sentinel).unwrap();
+
+        let item = make_item(
+            Some(pdf_path.to_str().unwrap()),
+            Some("New Paper"),
+            None,
+            None,
+            None,
+            "https://example.com/paper.pdf",
+        );
+
+        let result = generate_sidecar_with_format(&item, SidecarFormat::Ris).unwrap();
+        assert!(
+            result.is_none(),
+            "should return None when sidecar already exists"
+        );
+        let content = std::fs::read_to_string(&sidecar_path).unwrap();
+        assert_eq!(
+            content, sentinel,
+            "existing sidecar content should not be overwritten"
+        );
+    }
+
+    #[test]
+    fn test_generate_ris_sidecar_quarantines_invalid_existing_sidecar_and_regenerates() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let pdf_path = tmp.path().join("paper.pdf");
+        std::fs::write(&pdf_path, b"fake pdf").unwrap();
+
+        let sidecar_path = tmp.path().join("paper.ris");
+        std::fs::write(&sidecar_path, b"TY  - JOUR\nTI  - Trunc").unwrap();
+
+        let item = make_item(
+            Some(pdf_path.to_str().unwrap()),
+            Some("Recovered Paper"),
+            None,
+            None,
+            None,
+            "https://example.com/paper.pdf",
+        );
+
+        let result = generate_sidecar_with_format(&item, SidecarFormat::Ris).unwrap();
+        assert_eq!(result.as_deref(), Some(sidecar_path.as_path()));
+
+        let content = std::fs::read_to_string(&sidecar_path).unwrap();
+        assert!(content.contains("TI  - Recovered Paper"));
+
+        let quarantined = std::fs::read_dir(tmp.path())
+            .unwrap()
+            .filter_map(Result::ok)
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .filter(|name| {
+                name.starts_with("paper.corrupt.")
+                    && Path::new(name).extension().is_some_and(|ext| ext == "ris")
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            quarantined.len(),
+            1,
+            "one invalid sidecar should be quarantined"
+        );
+    }
+
+    #[test]
+    fn test_generate_ris_sidecar_ignores_existing_json_ld_sidecar() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let pdf_path = tmp.path().join("paper.pdf");
+        std::fs::write(&pdf_path, b"fake pdf").unwrap();
+
+        let item = make_item(
+            Some(pdf_path.to_str().unwrap()),
+            Some("Test Paper"),
+            None,
+            None,
+            None,
+            "https://example.com/paper.pdf",
+        );
+
+        assert!(generate_sidecar(&item).unwrap().is_some());
+        let result = generate_sidecar_with_format(&item, SidecarFormat::Ris).unwrap();
+        assert_eq!(result, Some(tmp.path().join("paper.ris")));
+    }
 }
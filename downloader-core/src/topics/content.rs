@@ -0,0 +1,226 @@
+//! Topic extraction from the text of downloaded PDFs.
+//!
+//! Titles alone yield thin topics. When enabled, the first pages of each
+//! downloaded PDF are run through [`TopicExtractor`] together with the title.
+//! Encrypted, image-only, or unreadable PDFs fall back to the title.
+
+use std::path::Path;
+
+use lopdf::Document;
+use thiserror::Error;
+use tracing::{debug, instrument};
+
+use super::{TopicExtractor, match_custom_topics, normalize_topics};
+
+/// Default number of leading PDF pages read for topic extraction.
+pub const DEFAULT_CONTENT_TOPIC_PAGES: u32 = 3;
+
+/// Why text could not be taken from a PDF.
+#[derive(Debug, Error)]
+pub enum PdfTextError {
+    /// The file could not be parsed as a PDF.
+    #[error("cannot parse PDF: {0}")]
+    Parse(#[from] lopdf::Error),
+
+    /// The PDF is encrypted.
+    #[error("PDF is encrypted")]
+    Encrypted,
+
+    /// The pages hold no extractable text (for example, scanned images).
+    #[error("PDF has no extractable text")]
+    NoText,
+}
+
+/// Extracts the text of the first `max_pages` pages of the PDF at `path`.
+///
+/// # Errors
+///
+/// Returns [`PdfTextError`] when the file is not a readable PDF, is
+/// encrypted, or its leading pages contain no text.
+#[instrument(skip(path), fields(path = %path.display()))]
+pub fn extract_pdf_text(path: &Path, max_pages: u32) -> Result<String, PdfTextError> {
+    let document = Document::load(path)?;
+    if document.is_encrypted() {
+        return Err(PdfTextError::Encrypted);
+    }
+
+    let pages: Vec<u32> = document
+        .get_pages()
+        .into_keys()
+        .take(usize::try_from(max_pages).unwrap_or(usize::MAX))
+        .collect();
+    let text = document.extract_text(&pages)?;
+    if text.trim().is_empty() {
+        return Err(PdfTextError::NoText);
+    }
+    Ok(text)
+}
+
+/// Derives topics for a downloaded file from its PDF text and title.
+#[derive(Debug)]
+pub struct ContentTopicExtractor {
+    extractor: TopicExtractor,
+    max_pages: u32,
+    custom_topics: Vec<String>,
+}
+
+impl ContentTopicExtractor {
+    /// Creates an extractor reading `max_pages` pages per PDF.
+    ///
+    /// A non-empty `custom_topics` list is matched like `--topics-file`.
+    #[must_use]
+    pub fn new(max_pages: u32, custom_topics: Vec<String>) -> Self {
+        Self {
+            extractor: TopicExtractor::new(),
+            max_pages: max_pages.max(1),
+            custom_topics,
+        }
+    }
+
+    /// Returns topics for the file at `path`, or `None` when neither the PDF
+    /// text nor `title` yields any.
+    ///
+    /// Blocking: parses the PDF on the calling thread.
+    #[must_use]
+    pub fn topics_for_file(&self, path: &Path, title: Option<&str>) -> Option<Vec<String>> {
+        let raw_keywords = match extract_pdf_text(path, self.max_pages) {
+            Ok(text) => self
+                .extractor
+                .extract_from_metadata(title.unwrap_or_default(), Some(&text)),
+            Err(error) => {
+                debug!(
+                    path = %path.display(),
+                    %error,
+                    "no usable PDF text; falling back to title-based topics"
+                );
+                self.extractor.extract_from_metadata(title?, None)
+            }
+        };
+        if raw_keywords.is_empty() {
+            return None;
+        }
+
+        let topics = if self.custom_topics.is_empty() {
+            normalize_topics(raw_keywords)
+        } else {
+            match_custom_topics(raw_keywords, self.custom_topics.clone())
+        };
+        (!topics.is_empty()).then_some(topics)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use lopdf::content::{Content, Operation};
+    use lopdf::{Object, Stream, dictionary};
+    use tempfile::TempDir;
+
+    /// Writes a one-page PDF; `text` of `None` leaves the page blank.
+    fn write_pdf(path: &Path, text: Option<&str>) {
+        let mut doc = Document::with_version("1.5");
+        let pages_id = doc.new_object_id();
+        let font_id = doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Courier",
+        });
+        let resources_id = doc.add_object(dictionary! {
+            "Font" => dictionary! { "F1" => font_id },
+        });
+        let operations = text.map_or_else(Vec::new, |text| {
+            vec![
+                Operation::new("BT", vec![]),
+                Operation::new("Tf", vec!["F1".into(), 12.into()]),
+                Operation::new("Td", vec![72.into(), 720.into()]),
+                Operation::new("Tj", vec![Object::string_literal(text)]),
+                Operation::new("ET", vec![]),
+            ]
+        });
+        let content = Content { operations };
+        let content_id = doc.add_object(Stream::new(dictionary! {}, content.encode().unwrap()));
+        let page_id = doc.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "Contents" => content_id,
+        });
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => vec![page_id.into()],
+                "Count" => 1,
+                "Resources" => resources_id,
+                "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+        doc.save(path).unwrap();
+    }
+
+    #[test]
+    fn test_extract_pdf_text_reads_page_text() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("paper.pdf");
+        write_pdf(&path, Some("Quantum error correction with surface codes"));
+
+        let text = extract_pdf_text(&path, DEFAULT_CONTENT_TOPIC_PAGES).unwrap();
+        assert!(text.contains("surface codes"), "{text}");
+    }
+
+    #[test]
+    fn test_extract_pdf_text_image_only_pdf_has_no_text() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("scan.pdf");
+        write_pdf(&path, None);
+
+        let error = extract_pdf_text(&path, DEFAULT_CONTENT_TOPIC_PAGES).unwrap_err();
+        assert!(matches!(error, PdfTextError::NoText), "{error}");
+    }
+
+    #[test]
+    fn test_topics_for_file_uses_pdf_text() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("paper.pdf");
+        write_pdf(
+            &path,
+            Some("Surface codes suppress logical errors in superconducting qubits"),
+        );
+
+        let topics = ContentTopicExtractor::new(DEFAULT_CONTENT_TOPIC_PAGES, Vec::new())
+            .topics_for_file(&path, Some("A short title"))
+            .unwrap();
+        assert!(
+            topics.iter().any(|topic| topic.contains("superconducting")),
+            "{topics:?}"
+        );
+    }
+
+    #[test]
+    fn test_topics_for_file_falls_back_to_title_for_unreadable_pdf() {
+        let dir = TempDir::new().unwrap();
+        let scan = dir.path().join("scan.pdf");
+        write_pdf(&scan, None);
+        let garbage = dir.path().join("broken.pdf");
+        std::fs::write(&garbage, b"not a pdf").unwrap();
+        let extractor = ContentTopicExtractor::new(DEFAULT_CONTENT_TOPIC_PAGES, Vec::new());
+
+        for path in [&scan, &garbage] {
+            let topics = extractor
+                .topics_for_file(path, Some("Deep reinforcement learning"))
+                .unwrap();
+            assert!(
+                topics
+                    .iter()
+                    .any(|topic| topic.contains("reinforcement learning")),
+                "{topics:?}"
+            );
+        }
+        assert!(extractor.topics_for_file(&garbage, None).is_none());
+    }
+}
@@ -2,11 +2,16 @@
 //!
 //! This module provides keyword extraction from titles and abstracts using
 //! the RAKE (Rapid Automatic Keyword Extraction) algorithm. Topics are
-//! normalized and can be matched against custom topic lists.
+//! normalized and can be matched against custom topic lists. Downloaded PDFs
+//! can also contribute their leading pages' text (see [`ContentTopicExtractor`]).
 
+mod content;
 mod extractor;
 mod normalizer;
 
+pub use content::{
+    ContentTopicExtractor, DEFAULT_CONTENT_TOPIC_PAGES, PdfTextError, extract_pdf_text,
+};
 pub use extractor::{TopicExtractor, extract_keywords};
 pub use normalizer::{match_custom_topics, normalize_topics};
 
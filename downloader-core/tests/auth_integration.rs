@@ -16,7 +16,7 @@ use support::socket_guard::start_mock_server_or_skip;
 fn jar_from_str(input: &str) -> Arc<reqwest::cookie::Jar> {
     let reader = Cursor::new(input.as_bytes());
     let result = parse_netscape_cookies(reader).expect("valid cookie input");
-    load_cookies_into_jar(&result.cookies)
+    load_cookies_into_jar(&result.cookies, None)
 }
 
 // ---- Integration test: cookies sent with matching domain (AC1, AC4) ----
@@ -16,7 +16,7 @@ use crate::support::socket_guard::{socket_skip_return, start_mock_server_or_skip
 fn jar_from_str(input: &str) -> Arc<reqwest::cookie::Jar> {
     let reader = Cursor::new(input.as_bytes());
     let result = parse_netscape_cookies(reader).expect("valid cookie input");
-    load_cookies_into_jar(&result.cookies)
+    load_cookies_into_jar(&result.cookies, None)
 }
 
 #[tokio::test]
@@ -9,8 +9,8 @@ use std::time::Duration;
 
 use downloader_core::{
     Database, DownloadAttemptQuery, DownloadAttemptStatus, DownloadEngine, DownloadErrorType,
-    HttpClient, Queue, QueueMetadata, QueueProcessingOptions, QueueStatus, RateLimiter,
-    RetryPolicy,
+    HttpClient, LayoutMode, Queue, QueueMetadata, QueueProcessingOptions, QueueStatus, RateLimiter,
+    RetryPolicy, RobotsCache,
 };
 use tempfile::TempDir;
 use wiremock::matchers::{method, path};
@@ -193,6 +193,66 @@ async fn test_process_queue_interruptible_with_options_generates_sidecar_when_en
     Ok(())
 }
 
+#[tokio::test]
+async fn test_resolver_abstract_round_trips_into_json_ld_sidecar()
+-> Result<(), Box<dyn std::error::Error>> {
+    use std::sync::atomic::AtomicBool;
+
+    let (db, _temp_dir) = setup_test_db().await?;
+    let queue = Queue::new(db);
+
+    let mock_server = require_mock_server!();
+    Mock::given(method("GET"))
+        .and(path("/abstract.pdf"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(b"pdf-content"))
+        .mount(&mock_server)
+        .await;
+
+    let resolved_metadata = std::collections::HashMap::from([(
+        "abstract".to_string(),
+        "Rivers warm faster than lakes.".to_string(),
+    )]);
+    let metadata = QueueMetadata {
+        title: Some("Warming Rivers".to_string()),
+        abstract_text: resolved_metadata.get("abstract").cloned(),
+        ..QueueMetadata::default()
+    };
+    let url = format!("{}/abstract.pdf", mock_server.uri());
+    let id = queue
+        .enqueue_with_metadata(&url, "doi", None, Some(&metadata))
+        .await?;
+    assert_eq!(
+        queue.get(id).await?.and_then(|item| item.meta_abstract),
+        Some("Rivers warm faster than lakes.".to_string())
+    );
+
+    let client = HttpClient::new();
+    let engine = create_engine_no_retry(1)?;
+    let output_dir = TempDir::new()?;
+    engine
+        .process_queue_interruptible_with_options(
+            &queue,
+            &client,
+            output_dir.path(),
+            Arc::new(AtomicBool::new(false)),
+            QueueProcessingOptions {
+                generate_sidecars: true,
+                ..QueueProcessingOptions::default()
+            },
+        )
+        .await?;
+
+    let item = queue.get(id).await?.expect("queued item should exist");
+    let mut sidecar_path = std::path::PathBuf::from(
+        item.saved_path
+            .expect("completed item should have saved path"),
+    );
+    sidecar_path.set_extension("json");
+    let json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&sidecar_path)?)?;
+    assert_eq!(json["abstract"], "Rivers warm faster than lakes.");
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_process_queue_interruptible_with_options_skips_sidecar_when_disabled()
 -> Result<(), Box<dyn std::error::Error>> {
@@ -243,6 +303,62 @@ async fn test_process_queue_interruptible_with_options_skips_sidecar_when_disabl
     Ok(())
 }
 
+#[tokio::test]
+async fn test_process_queue_with_year_layout_saves_into_year_subfolder()
+-> Result<(), Box<dyn std::error::Error>> {
+    use std::sync::atomic::AtomicBool;
+
+    let (db, _temp_dir) = setup_test_db().await?;
+    let queue = Queue::new(db);
+
+    let mock_server = require_mock_server!();
+    Mock::given(method("GET"))
+        .and(path("/paper-layout.pdf"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(b"pdf-content"))
+        .mount(&mock_server)
+        .await;
+
+    let url = format!("{}/paper-layout.pdf", mock_server.uri());
+    let metadata = QueueMetadata {
+        year: Some("2024".to_string()),
+        ..QueueMetadata::default()
+    };
+    let id = queue
+        .enqueue_with_metadata(&url, "direct_url", None, Some(&metadata))
+        .await?;
+
+    let client = HttpClient::new();
+    let engine = create_engine_no_retry(1)?;
+    let output_dir = TempDir::new()?;
+    let interrupted = Arc::new(AtomicBool::new(false));
+
+    let stats = engine
+        .process_queue_interruptible_with_options(
+            &queue,
+            &client,
+            output_dir.path(),
+            interrupted,
+            QueueProcessingOptions {
+                layout: LayoutMode::ByYear,
+                ..QueueProcessingOptions::default()
+            },
+        )
+        .await?;
+
+    assert_eq!(stats.completed(), 1);
+    let item = queue.get(id).await?.expect("queued item should exist");
+    let saved_path = std::path::PathBuf::from(
+        item.saved_path
+            .expect("completed item should have saved path"),
+    );
+    assert_eq!(
+        saved_path.parent(),
+        Some(output_dir.path().join("2024").as_path())
+    );
+    assert!(saved_path.exists());
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_process_queue_success_writes_download_log_row()
 -> Result<(), Box<dyn std::error::Error>> {
@@ -261,6 +377,8 @@ async fn test_process_queue_success_writes_download_log_row()
         suggested_filename: Some("Logged_2026_Test.pdf".to_string()),
         title: Some("Logged Success".to_string()),
         authors: Some("Author, A".to_string()),
+        author_orcids: None,
+        abstract_text: None,
         year: Some("2026".to_string()),
         doi: Some("10.1234/logged".to_string()),
         topics: None,
@@ -269,6 +387,9 @@ async fn test_process_queue_success_writes_download_log_row()
             r#"{"has_authors":false,"has_year":true,"has_title":false,"author_count":0}"#
                 .to_string(),
         ),
+        read_timeout_secs: None,
+        resolver_path: None,
+        input_source_file: None,
     };
     queue
         .enqueue_with_metadata(&url, "doi", Some("10.1234/logged"), Some(&metadata))
@@ -339,11 +460,16 @@ async fn test_process_queue_single_item_failure() -> Result<(), Box<dyn std::err
     assert_eq!(stats.completed(), 0);
     assert_eq!(stats.failed(), 1);
     assert_eq!(stats.retried(), 0); // 404 is permanent, no retries
+    assert_eq!(stats.dead_lettered(), 1);
     assert_eq!(stats.total(), 1);
 
-    // Verify queue item marked failed
+    // Verify queue item marked failed and dead-lettered
     let item = queue.get(id).await?.unwrap();
     assert_eq!(item.status(), QueueStatus::Failed);
+    assert!(
+        item.dead_letter,
+        "404 is permanent and should be dead-lettered"
+    );
     assert!(item.last_error.is_some());
     Ok(())
 }
@@ -432,6 +558,8 @@ async fn test_process_queue_failure_propagates_parse_confidence_to_download_log(
         suggested_filename: Some("Reference_Failure.pdf".to_string()),
         title: Some("Reference Failure".to_string()),
         authors: Some("Author, B".to_string()),
+        author_orcids: None,
+        abstract_text: None,
         year: Some("2026".to_string()),
         doi: None,
         topics: None,
@@ -440,6 +568,9 @@ async fn test_process_queue_failure_propagates_parse_confidence_to_download_log(
             r#"{"has_authors":false,"has_year":true,"has_title":false,"author_count":0}"#
                 .to_string(),
         ),
+        read_timeout_secs: None,
+        resolver_path: None,
+        input_source_file: None,
     };
     queue
         .enqueue_with_metadata(&url, "reference", Some("Weak reference"), Some(&metadata))
@@ -466,6 +597,83 @@ async fn test_process_queue_failure_propagates_parse_confidence_to_download_log(
     Ok(())
 }
 
+#[tokio::test]
+async fn test_process_queue_min_file_size_fails_tiny_and_non_pdf_bodies()
+-> Result<(), Box<dyn std::error::Error>> {
+    let (db, _temp_dir) = setup_test_db().await?;
+    let queue = Queue::new(db);
+
+    let mock_server = require_mock_server!();
+    Mock::given(method("GET"))
+        .and(path("/tiny.pdf"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(b"x"))
+        .mount(&mock_server)
+        .await;
+    let denied_page = format!(
+        "<html><body>Access denied{}</body></html>",
+        " ".repeat(2048)
+    );
+    Mock::given(method("GET"))
+        .and(path("/denied.pdf"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/pdf")
+                .set_body_string(denied_page),
+        )
+        .mount(&mock_server)
+        .await;
+
+    queue
+        .enqueue(
+            &format!("{}/tiny.pdf", mock_server.uri()),
+            "direct_url",
+            None,
+        )
+        .await?;
+    queue
+        .enqueue(
+            &format!("{}/denied.pdf", mock_server.uri()),
+            "direct_url",
+            None,
+        )
+        .await?;
+
+    let engine = create_engine_no_retry(1)?;
+    let output_dir = TempDir::new()?;
+    let stats = engine
+        .process_queue_interruptible_with_options(
+            &queue,
+            &HttpClient::new(),
+            output_dir.path(),
+            Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            QueueProcessingOptions {
+                min_file_size: Some(1024),
+                ..QueueProcessingOptions::default()
+            },
+        )
+        .await?;
+
+    assert_eq!(stats.completed(), 0);
+    assert_eq!(stats.failed(), 2);
+    let mut query = DownloadAttemptQuery::default();
+    query.status = Some(DownloadAttemptStatus::Failed);
+    let rows = queue.query_download_attempts(&query).await?;
+    assert_eq!(rows.len(), 2);
+    for row in &rows {
+        assert_eq!(
+            row.error_type(),
+            Some(DownloadErrorType::UnexpectedContentType)
+        );
+    }
+    assert_eq!(
+        std::fs::read_dir(output_dir.path())?.count(),
+        0,
+        "rejected bodies must not be left on disk"
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_process_queue_failure_auth_classification() -> Result<(), Box<dyn std::error::Error>>
 {
@@ -513,7 +721,7 @@ async fn test_process_queue_failure_network_classification()
     let queue = Queue::new(db);
 
     let url = "http://127.0.0.1:1/network-failure.pdf";
-    queue.enqueue(url, "direct_url", None).await?;
+    let id = queue.enqueue(url, "direct_url", None).await?;
 
     let client = HttpClient::new();
     let engine = create_engine_no_retry(1)?;
@@ -522,6 +730,11 @@ async fn test_process_queue_failure_network_classification()
         .process_queue(&queue, &client, output_dir.path())
         .await?;
     assert_eq!(stats.failed(), 1);
+    assert_eq!(stats.dead_lettered(), 0);
+    assert!(
+        !queue.get(id).await?.unwrap().dead_letter,
+        "transient network failures stay retryable"
+    );
 
     let mut query = DownloadAttemptQuery::default();
     query.status = Some(DownloadAttemptStatus::Failed);
@@ -529,6 +742,11 @@ async fn test_process_queue_failure_network_classification()
     assert_eq!(rows.len(), 1);
     assert_eq!(rows[0].error_type(), Some(DownloadErrorType::Network));
 
+    query.error_type = Some(DownloadErrorType::Network);
+    assert_eq!(queue.query_download_attempts(&query).await?.len(), 1);
+    query.error_type = Some(DownloadErrorType::Dns);
+    assert!(queue.query_download_attempts(&query).await?.is_empty());
+
     Ok(())
 }
 
@@ -795,6 +1013,122 @@ async fn test_semaphore_limits_concurrent_downloads() -> Result<(), Box<dyn std:
     Ok(())
 }
 
+// ==================== Segmented Download Tests ====================
+
+/// Serves `body` honoring single `Range: bytes=a-b` headers and records each range.
+struct RangeResponder {
+    body: Vec<u8>,
+    ranges: Arc<std::sync::Mutex<Vec<String>>>,
+}
+
+impl Respond for RangeResponder {
+    fn respond(&self, request: &wiremock::Request) -> ResponseTemplate {
+        let Some(range) = request
+            .headers
+            .get("range")
+            .and_then(|value| value.to_str().ok())
+        else {
+            return ResponseTemplate::new(200).set_body_bytes(self.body.clone());
+        };
+        self.ranges.lock().unwrap().push(range.to_string());
+        let (start, end) = range
+            .trim_start_matches("bytes=")
+            .split_once('-')
+            .map(|(start, end)| {
+                (
+                    start.parse::<usize>().unwrap(),
+                    end.parse::<usize>().unwrap(),
+                )
+            })
+            .unwrap();
+        ResponseTemplate::new(206)
+            .insert_header(
+                "content-range",
+                format!("bytes {start}-{end}/{}", self.body.len()).as_str(),
+            )
+            .set_body_bytes(self.body[start..=end].to_vec())
+    }
+}
+
+#[tokio::test]
+async fn test_segmented_download_reassembles_parallel_ranges()
+-> Result<(), Box<dyn std::error::Error>> {
+    use std::sync::atomic::AtomicBool;
+
+    let (db, _temp_dir) = setup_test_db().await?;
+    let queue = Queue::new(db);
+
+    let body: Vec<u8> = (0..64 * 1024).map(|i| (i % 251) as u8).collect();
+    let ranges = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let mock_server = require_mock_server!();
+    Mock::given(method("HEAD"))
+        .and(path("/dataset.zip"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("accept-ranges", "bytes")
+                .insert_header("content-type", "application/zip")
+                .insert_header("content-length", body.len().to_string().as_str()),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/dataset.zip"))
+        .respond_with(RangeResponder {
+            body: body.clone(),
+            ranges: Arc::clone(&ranges),
+        })
+        .mount(&mock_server)
+        .await;
+
+    let url = format!("{}/dataset.zip", mock_server.uri());
+    let id = queue.enqueue(&url, "direct_url", None).await?;
+
+    let engine = create_engine_no_retry(1)?;
+    let output_dir = TempDir::new()?;
+    let stats = engine
+        .process_queue_interruptible_with_options(
+            &queue,
+            &HttpClient::new(),
+            output_dir.path(),
+            Arc::new(AtomicBool::new(false)),
+            QueueProcessingOptions {
+                segmented_threshold: Some(1024),
+                segments: 4,
+                ..QueueProcessingOptions::default()
+            },
+        )
+        .await?;
+
+    assert_eq!(stats.completed(), 1);
+    let item = queue.get(id).await?.expect("queued item should exist");
+    let saved_path = item.saved_path.expect("completed item should have path");
+    assert_eq!(std::fs::read(&saved_path)?, body);
+
+    let mut seen = ranges.lock().unwrap().clone();
+    seen.sort_by_key(|range| {
+        range
+            .trim_start_matches("bytes=")
+            .split('-')
+            .next()
+            .and_then(|start| start.parse::<u64>().ok())
+    });
+    assert_eq!(
+        seen,
+        [
+            "bytes=0-16383",
+            "bytes=16384-32767",
+            "bytes=32768-49151",
+            "bytes=49152-65535"
+        ]
+    );
+    let leftover_parts = std::fs::read_dir(output_dir.path())?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "part"))
+        .count();
+    assert_eq!(leftover_parts, 0, "no .part file should remain");
+    Ok(())
+}
+
 // ==================== Error Isolation Tests ====================
 
 #[tokio::test]
@@ -1249,6 +1583,46 @@ async fn test_429_respects_retry_after_header() -> Result<(), Box<dyn std::error
     Ok(())
 }
 
+#[tokio::test]
+async fn test_adaptive_rate_limit_spacing_grows_with_repeated_429s()
+-> Result<(), Box<dyn std::error::Error>> {
+    let (db, _temp_dir) = setup_test_db().await?;
+    let queue = Queue::new(db);
+
+    let mock_server = require_mock_server!();
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(429))
+        .mount(&mock_server)
+        .await;
+
+    let rate_limiter = Arc::new(RateLimiter::new(Duration::from_millis(10)).with_adaptive());
+    let engine = DownloadEngine::new(
+        1,
+        RetryPolicy::with_max_attempts(1),
+        Arc::clone(&rate_limiter),
+    )?;
+    let client = HttpClient::new();
+    let output_dir = TempDir::new()?;
+
+    let mut spacing = Vec::new();
+    for n in 0..3 {
+        let url = format!("{}/throttled-{n}.pdf", mock_server.uri());
+        queue.enqueue(&url, "direct_url", None).await?;
+        let stats = engine
+            .process_queue(&queue, &client, output_dir.path())
+            .await?;
+        assert_eq!(stats.failed(), 1);
+        spacing.push(rate_limiter.effective_delay(&url));
+    }
+
+    assert_eq!(
+        spacing,
+        [20, 40, 80].map(Duration::from_millis),
+        "each 429 from the host should double its request spacing"
+    );
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_max_retries_exhausted_marks_item_failed() -> Result<(), Box<dyn std::error::Error>> {
     let (db, _temp_dir) = setup_test_db().await?;
@@ -1605,37 +1979,278 @@ async fn test_interrupt_requeues_item_waiting_for_permit() -> Result<(), Box<dyn
     Ok(())
 }
 
-// ==================== Queue Dedup Tests ====================
+// ==================== Pause Handling Tests ====================
 
 #[tokio::test]
-async fn test_has_active_url_detects_pending_duplicate() -> Result<(), Box<dyn std::error::Error>> {
+async fn test_pause_blocks_dequeue_until_resumed() -> Result<(), Box<dyn std::error::Error>> {
+    use std::sync::atomic::AtomicBool;
+
     let (db, _temp_dir) = setup_test_db().await?;
     let queue = Queue::new(db);
+    let mock_server = require_mock_server!();
 
-    queue
-        .enqueue("https://example.com/paper.pdf", "direct_url", None)
-        .await?;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(b"content"))
+        .mount(&mock_server)
+        .await;
 
-    assert!(
-        queue
-            .has_active_url("https://example.com/paper.pdf")
-            .await?,
-        "Should detect pending URL as active"
-    );
-    assert!(
-        !queue
-            .has_active_url("https://example.com/other.pdf")
-            .await?,
-        "Should not detect absent URL as active"
-    );
+    for i in 0..3 {
+        let url = format!("{}/file{}.txt", mock_server.uri(), i);
+        queue.enqueue(&url, "direct_url", None).await?;
+    }
 
-    Ok(())
-}
+    let client = HttpClient::new();
+    let engine = create_engine_no_retry(2)?;
+    let output_dir = TempDir::new()?;
 
-#[tokio::test]
-async fn test_has_active_url_detects_in_progress_item() -> Result<(), Box<dyn std::error::Error>> {
-    let (db, _temp_dir) = setup_test_db().await?;
-    let queue = Queue::new(db);
+    // Start paused; nothing may be claimed until the flag is cleared.
+    let paused = Arc::new(AtomicBool::new(true));
+    let options = QueueProcessingOptions {
+        paused: Some(Arc::clone(&paused)),
+        ..QueueProcessingOptions::default()
+    };
+
+    let observer_queue = queue.clone();
+    let observer = async {
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        let pending_while_paused = observer_queue.count_by_status(QueueStatus::Pending).await;
+        paused.store(false, Ordering::SeqCst);
+        pending_while_paused
+    };
+    let run = engine.process_queue_interruptible_with_options(
+        &queue,
+        &client,
+        output_dir.path(),
+        Arc::new(AtomicBool::new(false)),
+        options,
+    );
+    let (pending_while_paused, stats) = tokio::join!(observer, run);
+
+    assert_eq!(
+        pending_while_paused?, 3,
+        "paused run must not claim any items"
+    );
+    let stats = stats?;
+    assert!(!stats.was_interrupted());
+    assert_eq!(
+        stats.completed(),
+        3,
+        "all items should complete after resume"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pause_lets_in_flight_item_finish_and_honors_interrupt()
+-> Result<(), Box<dyn std::error::Error>> {
+    use std::sync::atomic::AtomicBool;
+
+    let (db, _temp_dir) = setup_test_db().await?;
+    let queue = Queue::new(db);
+    let mock_server = require_mock_server!();
+
+    Mock::given(method("GET"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_bytes(b"content")
+                .set_delay(Duration::from_millis(300)),
+        )
+        .mount(&mock_server)
+        .await;
+
+    for i in 0..3 {
+        let url = format!("{}/file{}.txt", mock_server.uri(), i);
+        queue.enqueue(&url, "direct_url", None).await?;
+    }
+
+    let client = HttpClient::new();
+    let engine = DownloadEngine::new(1, RetryPolicy::with_max_attempts(1), test_rate_limiter())?;
+    let output_dir = TempDir::new()?;
+
+    let paused = Arc::new(AtomicBool::new(false));
+    let interrupted = Arc::new(AtomicBool::new(false));
+    let options = QueueProcessingOptions {
+        paused: Some(Arc::clone(&paused)),
+        ..QueueProcessingOptions::default()
+    };
+
+    // Pause while the first item is in flight, then interrupt long after it finished.
+    let paused_signal = Arc::clone(&paused);
+    let interrupted_signal = Arc::clone(&interrupted);
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        paused_signal.store(true, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(1000)).await;
+        interrupted_signal.store(true, Ordering::SeqCst);
+    });
+
+    let stats = engine
+        .process_queue_interruptible_with_options(
+            &queue,
+            &client,
+            output_dir.path(),
+            interrupted,
+            options,
+        )
+        .await?;
+
+    assert!(stats.was_interrupted(), "interrupt must end a paused run");
+    assert!(
+        stats.completed() >= 1,
+        "in-flight item should finish before the pause takes effect"
+    );
+    assert_eq!(
+        queue.count_by_status(QueueStatus::InProgress).await?,
+        0,
+        "pausing must not leave items half-processed"
+    );
+    assert!(
+        queue.count_by_status(QueueStatus::Pending).await? >= 1,
+        "items not yet claimed should remain pending"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_two_stage_interrupt_finishes_in_flight_then_abort_releases_items()
+-> Result<(), Box<dyn std::error::Error>> {
+    use std::sync::atomic::AtomicBool;
+
+    let (db, _temp_dir) = setup_test_db().await?;
+    let queue = Queue::new(db);
+    let mock_server = require_mock_server!();
+
+    Mock::given(method("GET"))
+        .and(path("/quick.txt"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_bytes(b"quick")
+                .set_delay(Duration::from_millis(300)),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/slow.txt"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_bytes(b"slow")
+                .set_delay(Duration::from_secs(30)),
+        )
+        .mount(&mock_server)
+        .await;
+
+    queue
+        .enqueue(
+            &format!("{}/quick.txt", mock_server.uri()),
+            "direct_url",
+            None,
+        )
+        .await?;
+    queue
+        .enqueue(
+            &format!("{}/slow.txt", mock_server.uri()),
+            "direct_url",
+            None,
+        )
+        .await?;
+    queue
+        .enqueue(
+            &format!("{}/never.txt", mock_server.uri()),
+            "direct_url",
+            None,
+        )
+        .await?;
+
+    let client = HttpClient::new();
+    let engine = DownloadEngine::new(2, RetryPolicy::with_max_attempts(1), test_rate_limiter())?;
+    let output_dir = TempDir::new()?;
+
+    let interrupted = Arc::new(AtomicBool::new(false));
+    let abort = Arc::new(AtomicBool::new(false));
+    let options = QueueProcessingOptions {
+        abort: Some(Arc::clone(&abort)),
+        ..QueueProcessingOptions::default()
+    };
+
+    // First stage while both downloads are in flight; second stage after the
+    // quick one has finished but long before the slow one would.
+    let interrupted_signal = Arc::clone(&interrupted);
+    let abort_signal = Arc::clone(&abort);
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        interrupted_signal.store(true, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(700)).await;
+        abort_signal.store(true, Ordering::SeqCst);
+    });
+
+    let started = std::time::Instant::now();
+    let stats = engine
+        .process_queue_interruptible_with_options(
+            &queue,
+            &client,
+            output_dir.path(),
+            interrupted,
+            options,
+        )
+        .await?;
+
+    assert!(stats.was_interrupted());
+    assert!(
+        started.elapsed() < Duration::from_secs(5),
+        "abort must not wait for the slow download"
+    );
+    assert_eq!(
+        stats.completed(),
+        1,
+        "the in-flight quick download finishes"
+    );
+    assert_eq!(
+        queue.count_by_status(QueueStatus::InProgress).await?,
+        0,
+        "aborted items must not be left in progress"
+    );
+    assert_eq!(
+        queue.count_by_status(QueueStatus::Pending).await?,
+        2,
+        "the aborted and the unclaimed item stay pending for the next run"
+    );
+
+    Ok(())
+}
+
+// ==================== Queue Dedup Tests ====================
+
+#[tokio::test]
+async fn test_has_active_url_detects_pending_duplicate() -> Result<(), Box<dyn std::error::Error>> {
+    let (db, _temp_dir) = setup_test_db().await?;
+    let queue = Queue::new(db);
+
+    queue
+        .enqueue("https://example.com/paper.pdf", "direct_url", None)
+        .await?;
+
+    assert!(
+        queue
+            .has_active_url("https://example.com/paper.pdf")
+            .await?,
+        "Should detect pending URL as active"
+    );
+    assert!(
+        !queue
+            .has_active_url("https://example.com/other.pdf")
+            .await?,
+        "Should not detect absent URL as active"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_has_active_url_detects_in_progress_item() -> Result<(), Box<dyn std::error::Error>> {
+    let (db, _temp_dir) = setup_test_db().await?;
+    let queue = Queue::new(db);
 
     queue
         .enqueue("https://example.com/downloading.pdf", "direct_url", None)
@@ -1684,7 +2299,7 @@ async fn test_resume_partial_file_sends_range_request() -> Result<(), Box<dyn st
 
     // Full content: "AAABBB" (6 bytes). Simulate partial file with first 3 bytes on disk.
     let output_dir = TempDir::new()?;
-    std::fs::write(output_dir.path().join("resume.bin"), b"AAA")?;
+    std::fs::write(output_dir.path().join("resume.bin.part"), b"AAA")?;
 
     // HEAD response: supports ranges
     Mock::given(method("HEAD"))
@@ -1740,6 +2355,10 @@ async fn test_resume_partial_file_sends_range_request() -> Result<(), Box<dyn st
         final_content, b"AAABBB",
         "Resumed file should have full content"
     );
+    assert!(
+        !output_dir.path().join("resume.bin.part").exists(),
+        "partial file should be renamed into place"
+    );
 
     Ok(())
 }
@@ -1752,7 +2371,7 @@ async fn test_resume_server_no_range_support_restarts() -> Result<(), Box<dyn st
 
     // Create partial file on disk
     let output_dir = TempDir::new()?;
-    std::fs::write(output_dir.path().join("norange.bin"), b"partial")?;
+    std::fs::write(output_dir.path().join("norange.bin.part"), b"partial")?;
 
     // HEAD response: no Accept-Ranges header
     Mock::given(method("HEAD"))
@@ -1783,15 +2402,20 @@ async fn test_resume_server_no_range_support_restarts() -> Result<(), Box<dyn st
 
     assert_eq!(stats.completed(), 1);
 
-    // Without range support, the engine writes to a new unique-named file
-    // (since norange.bin already exists and we're not resuming)
+    // Without range support the download restarts: the stale partial is
+    // overwritten and renamed into place as the full file.
     let files: Vec<_> = std::fs::read_dir(output_dir.path())?
         .filter_map(|e| e.ok())
+        .map(|entry| entry.file_name())
         .collect();
     assert_eq!(
-        files.len(),
-        2,
-        "Should have original partial + new full download"
+        files,
+        ["norange.bin"],
+        "Only the full download should remain"
+    );
+    assert_eq!(
+        std::fs::read(output_dir.path().join("norange.bin"))?,
+        b"full content"
     );
 
     Ok(())
@@ -1851,7 +2475,7 @@ async fn test_resume_with_mismatched_content_length_fails() -> Result<(), Box<dy
 
     // Partial file: 3 bytes on disk
     let output_dir = TempDir::new()?;
-    std::fs::write(output_dir.path().join("integrity.bin"), b"AAA")?;
+    std::fs::write(output_dir.path().join("integrity.bin.part"), b"AAA")?;
 
     // HEAD: supports ranges
     Mock::given(method("HEAD"))
@@ -1890,6 +2514,10 @@ async fn test_resume_with_mismatched_content_length_fails() -> Result<(), Box<dy
     // The download should fail (integrity mismatch or transport error)
     assert_eq!(stats.completed(), 0, "Mismatched resume should not succeed");
     assert_eq!(stats.failed(), 1);
+    assert!(
+        !output_dir.path().join("integrity.bin").exists(),
+        "Failed resume must not leave a final-named file"
+    );
 
     Ok(())
 }
@@ -2109,11 +2737,16 @@ async fn test_metadata_suggested_filename_is_used_for_download_path()
         suggested_filename: Some("Smith_2024_Climate_Study.pdf".to_string()),
         title: Some("Climate Study".to_string()),
         authors: Some("Smith, John".to_string()),
+        author_orcids: None,
+        abstract_text: None,
         year: Some("2024".to_string()),
         doi: Some("10.1000/test".to_string()),
         topics: None,
         parse_confidence: None,
         parse_confidence_factors: None,
+        read_timeout_secs: None,
+        resolver_path: None,
+        input_source_file: None,
     };
     queue
         .enqueue_with_metadata(&url, "doi", Some("10.1000/test"), Some(&metadata))
@@ -2138,6 +2771,127 @@ async fn test_metadata_suggested_filename_is_used_for_download_path()
     Ok(())
 }
 
+#[tokio::test]
+async fn test_per_item_read_timeout_overrides_client_default()
+-> Result<(), Box<dyn std::error::Error>> {
+    let (db, _temp_dir) = setup_test_db().await?;
+    let queue = Queue::new(db);
+
+    let mock_server = require_mock_server!();
+    for name in ["extended.zip", "default.zip"] {
+        Mock::given(method("GET"))
+            .and(path(format!("/{name}")))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(b"supplement")
+                    .set_delay(Duration::from_secs(2)),
+            )
+            .mount(&mock_server)
+            .await;
+    }
+
+    let extended = QueueMetadata {
+        read_timeout_secs: Some(10),
+        ..QueueMetadata::default()
+    };
+    let extended_id = queue
+        .enqueue_with_metadata(
+            &format!("{}/extended.zip", mock_server.uri()),
+            "direct_url",
+            None,
+            Some(&extended),
+        )
+        .await?;
+    let default_id = queue
+        .enqueue(
+            &format!("{}/default.zip", mock_server.uri()),
+            "direct_url",
+            None,
+        )
+        .await?;
+
+    let client = HttpClient::new_with_timeouts(30, 1);
+    let engine = create_engine_no_retry(2)?;
+    let output_dir = TempDir::new()?;
+
+    let stats = engine
+        .process_queue(&queue, &client, output_dir.path())
+        .await?;
+    assert_eq!(stats.completed(), 1);
+    assert_eq!(stats.failed(), 1);
+
+    let extended_item = queue.get(extended_id).await?.ok_or("missing item")?;
+    assert_eq!(extended_item.status(), QueueStatus::Completed);
+    assert_eq!(extended_item.read_timeout_secs, Some(10));
+    let default_item = queue.get(default_id).await?.ok_or("missing item")?;
+    assert_eq!(default_item.status(), QueueStatus::Failed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resolver_path_and_input_file_are_recorded_in_download_history()
+-> Result<(), Box<dyn std::error::Error>> {
+    let (db, _temp_dir) = setup_test_db().await?;
+    let queue = Queue::new(db);
+
+    let mock_server = require_mock_server!();
+    Mock::given(method("GET"))
+        .and(path("/paper.pdf"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(b"%PDF-1.4"))
+        .mount(&mock_server)
+        .await;
+
+    let metadata = QueueMetadata {
+        resolver_path: Some("crossref->direct".to_string()),
+        input_source_file: Some("refs.ris".to_string()),
+        ..QueueMetadata::default()
+    };
+    queue
+        .enqueue_with_metadata(
+            &format!("{}/paper.pdf", mock_server.uri()),
+            "doi",
+            Some("10.1234/paper"),
+            Some(&metadata),
+        )
+        .await?;
+    queue
+        .enqueue(
+            &format!("{}/legacy.pdf", mock_server.uri()),
+            "direct_url",
+            None,
+        )
+        .await?;
+
+    let engine = create_engine_no_retry(1)?;
+    let output_dir = TempDir::new()?;
+    engine
+        .process_queue(&queue, &HttpClient::new(), output_dir.path())
+        .await?;
+
+    let history = queue
+        .query_download_attempts(&DownloadAttemptQuery::default())
+        .await?;
+    let path_for = |suffix: &str| {
+        history
+            .iter()
+            .find(|attempt| attempt.url.ends_with(suffix))
+            .map(|attempt| attempt.resolver_path.clone())
+    };
+    assert_eq!(
+        path_for("/paper.pdf"),
+        Some(Some("crossref->direct".to_string()))
+    );
+    assert_eq!(path_for("/legacy.pdf"), Some(None));
+    let paper = history
+        .iter()
+        .find(|attempt| attempt.url.ends_with("/paper.pdf"))
+        .expect("paper attempt logged");
+    assert_eq!(paper.input_source_file.as_deref(), Some("refs.ris"));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_metadata_duplicate_suffix_starts_at_two() -> Result<(), Box<dyn std::error::Error>> {
     let (db, _temp_dir) = setup_test_db().await?;
@@ -2161,11 +2915,16 @@ async fn test_metadata_duplicate_suffix_starts_at_two() -> Result<(), Box<dyn st
         suggested_filename: Some("Smith_2024_Climate_Study.pdf".to_string()),
         title: Some("Climate Study".to_string()),
         authors: Some("Smith, John".to_string()),
+        author_orcids: None,
+        abstract_text: None,
         year: Some("2024".to_string()),
         doi: Some("10.1000/test".to_string()),
         topics: None,
         parse_confidence: None,
         parse_confidence_factors: None,
+        read_timeout_secs: None,
+        resolver_path: None,
+        input_source_file: None,
     };
     queue
         .enqueue_with_metadata(&url, "doi", Some("10.1000/test"), Some(&metadata))
@@ -2188,3 +2947,198 @@ async fn test_metadata_duplicate_suffix_starts_at_two() -> Result<(), Box<dyn st
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_robots_allow_overrides_disallow_only_for_listed_domains()
+-> Result<(), Box<dyn std::error::Error>> {
+    use std::sync::atomic::AtomicBool;
+
+    let (db, _temp_dir) = setup_test_db().await?;
+    let queue = Queue::new(db);
+    let mock_server = require_mock_server!();
+
+    Mock::given(method("GET"))
+        .and(path("/robots.txt"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string("User-agent: *\nDisallow: /private/\n"),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/private/allowed.pdf"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(b"%PDF-1.4 allowed"))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/private/blocked.pdf"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(b"%PDF-1.4 blocked"))
+        .mount(&mock_server)
+        .await;
+
+    // Same server under two host names: only `localhost` is allowlisted.
+    let port = mock_server.address().port();
+    let allowed_url = format!("http://localhost:{port}/private/allowed.pdf");
+    let blocked_url = format!("http://127.0.0.1:{port}/private/blocked.pdf");
+    queue.enqueue(&allowed_url, "direct_url", None).await?;
+    queue.enqueue(&blocked_url, "direct_url", None).await?;
+
+    let client = HttpClient::new();
+    let engine = create_engine_no_retry(1)?;
+    let output_dir = TempDir::new()?;
+    let options = QueueProcessingOptions {
+        check_robots: true,
+        robots_cache: Some(Arc::new(RobotsCache::new())),
+        robots_allow: vec!["localhost".to_string()],
+        ..QueueProcessingOptions::default()
+    };
+    let stats = engine
+        .process_queue_interruptible_with_options(
+            &queue,
+            &client,
+            output_dir.path(),
+            Arc::new(AtomicBool::new(false)),
+            options,
+        )
+        .await?;
+
+    assert_eq!(stats.completed(), 1, "allowlisted domain should download");
+    assert_eq!(stats.failed(), 1, "other domains stay blocked");
+    assert!(output_dir.path().join("allowed.pdf").exists());
+    assert!(!output_dir.path().join("blocked.pdf").exists());
+
+    let mut query = DownloadAttemptQuery::default();
+    query.status = Some(DownloadAttemptStatus::Failed);
+    let rows = queue.query_download_attempts(&query).await?;
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].url, blocked_url);
+    assert_eq!(
+        rows[0].error_type(),
+        Some(DownloadErrorType::RobotsDisallowed)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_max_items_caps_started_items_and_ignores_retries()
+-> Result<(), Box<dyn std::error::Error>> {
+    let (db, _temp_dir) = setup_test_db().await?;
+    let queue = Queue::new(db);
+    let mock_server = require_mock_server!();
+
+    // The first item needs a retry; it must still count as one started item.
+    Mock::given(method("GET"))
+        .and(path("/file0.txt"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(b"content"))
+        .mount(&mock_server)
+        .await;
+
+    for i in 0..4 {
+        let url = format!("{}/file{}.txt", mock_server.uri(), i);
+        queue.enqueue(&url, "direct_url", None).await?;
+    }
+
+    let retry_policy = RetryPolicy::new(3, Duration::from_millis(1), Duration::from_millis(1), 1.0);
+    let engine = create_engine_with_policy(1, retry_policy)?;
+    let output_dir = TempDir::new()?;
+    let capped = QueueProcessingOptions {
+        max_items: Some(2),
+        ..QueueProcessingOptions::default()
+    };
+
+    let stats = engine
+        .process_queue_interruptible_with_options(
+            &queue,
+            &HttpClient::new(),
+            output_dir.path(),
+            Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            capped.clone(),
+        )
+        .await?;
+
+    assert_eq!(stats.completed(), 2);
+    assert_eq!(stats.retried(), 1);
+    assert!(stats.was_capped());
+    assert!(!stats.was_interrupted());
+    assert_eq!(queue.count_by_status(QueueStatus::Pending).await?, 2);
+
+    // The second run takes the rest; an exhausted queue is not reported as capped.
+    let stats = engine
+        .process_queue_interruptible_with_options(
+            &queue,
+            &HttpClient::new(),
+            output_dir.path(),
+            Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            capped,
+        )
+        .await?;
+
+    assert_eq!(stats.completed(), 2);
+    assert!(!stats.was_capped());
+    assert_eq!(queue.count_by_status(QueueStatus::Pending).await?, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_byte_budget_stops_dequeuing_and_persists_across_runs()
+-> Result<(), Box<dyn std::error::Error>> {
+    let (db, _temp_dir) = setup_test_db().await?;
+    let queue = Queue::new(db);
+    let mock_server = require_mock_server!();
+
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![b'x'; 10]))
+        .mount(&mock_server)
+        .await;
+    for i in 0..4 {
+        let url = format!("{}/file{}.txt", mock_server.uri(), i);
+        queue.enqueue(&url, "direct_url", None).await?;
+    }
+
+    let engine = create_engine(1)?;
+    let output_dir = TempDir::new()?;
+    let budgeted = QueueProcessingOptions {
+        byte_budget: Some(15),
+        ..QueueProcessingOptions::default()
+    };
+
+    let stats = engine
+        .process_queue_interruptible_with_options(
+            &queue,
+            &HttpClient::new(),
+            output_dir.path(),
+            Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            budgeted.clone(),
+        )
+        .await?;
+
+    // The second download starts under budget and is allowed to finish.
+    assert_eq!(stats.completed(), 2);
+    assert_eq!(stats.budget_exhausted(), Some(0));
+    assert_eq!(queue.downloaded_bytes_in_window().await?, 20);
+    assert_eq!(queue.count_by_status(QueueStatus::Pending).await?, 2);
+
+    // The counter is persisted, so the next run starts out of budget.
+    let stats = engine
+        .process_queue_interruptible_with_options(
+            &queue,
+            &HttpClient::new(),
+            output_dir.path(),
+            Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            budgeted,
+        )
+        .await?;
+
+    assert_eq!(stats.completed(), 0);
+    assert_eq!(stats.budget_exhausted(), Some(0));
+    assert_eq!(queue.count_by_status(QueueStatus::Pending).await?, 2);
+
+    Ok(())
+}
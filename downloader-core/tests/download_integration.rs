@@ -270,3 +270,139 @@ async fn test_download_to_nonexistent_directory_fails() {
         result
     );
 }
+
+#[tokio::test]
+async fn test_download_one_resolves_and_saves_single_url() {
+    use downloader_core::{DownloadOptions, download_one};
+
+    let Some(mock_server) = setup_mock_file("/papers/attention.pdf", b"%PDF-1.4 one").await else {
+        return;
+    };
+    let temp_dir = TempDir::new().expect("failed to create temp dir");
+    let output_dir = temp_dir.path().join("nested").join("out");
+    let url = format!("{}/papers/attention.pdf", mock_server.uri());
+
+    let outcome = download_one(&url, &output_dir, DownloadOptions::default())
+        .await
+        .expect("download_one should succeed");
+
+    assert_eq!(outcome.url, url);
+    assert_eq!(outcome.resolver.as_deref(), Some("direct"));
+    assert!(outcome.path.starts_with(&output_dir));
+    assert_eq!(
+        std::fs::read(&outcome.path).expect("saved file"),
+        b"%PDF-1.4 one"
+    );
+}
+
+#[tokio::test]
+async fn test_download_one_reports_input_and_download_failures() {
+    use downloader_core::{DownloadOneError, DownloadOptions, RetryPolicy, download_one};
+
+    let temp_dir = TempDir::new().expect("failed to create temp dir");
+    let error = download_one("   ", temp_dir.path(), DownloadOptions::default())
+        .await
+        .expect_err("blank input must fail");
+    assert!(matches!(error, DownloadOneError::NoInput), "{error:?}");
+
+    let Some(mock_server) = start_mock_server_or_skip().await else {
+        return;
+    };
+    Mock::given(method("GET"))
+        .and(path("/missing.pdf"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+    let url = format!("{}/missing.pdf", mock_server.uri());
+    let opts = DownloadOptions {
+        retry_policy: RetryPolicy::with_max_attempts(1),
+        ..DownloadOptions::default()
+    };
+
+    let error = download_one(&url, temp_dir.path(), opts)
+        .await
+        .expect_err("404 must fail");
+    let DownloadOneError::Download {
+        url: failed_url,
+        message,
+    } = error
+    else {
+        panic!("expected Download error, got {error:?}");
+    };
+    assert_eq!(failed_url, url);
+    assert!(message.contains("404"), "{message}");
+    assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+}
+
+#[tokio::test]
+async fn test_segmented_download_falls_back_when_server_ignores_ranges() {
+    let Some(mock_server) = start_mock_server_or_skip().await else {
+        return;
+    };
+    let body = vec![7u8; 8 * 1024];
+    // HEAD advertises ranges, but every GET returns the whole body.
+    Mock::given(method("HEAD"))
+        .and(path("/archive.zip"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("accept-ranges", "bytes")
+                .insert_header("content-length", body.len().to_string().as_str()),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/archive.zip"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(body.clone()))
+        .mount(&mock_server)
+        .await;
+    let temp_dir = TempDir::new().expect("failed to create temp dir");
+
+    let client = HttpClient::new().with_segmented_downloads(1024, 4);
+    let url = format!("{}/archive.zip", mock_server.uri());
+    let result = client
+        .download_to_file_with_metadata(&url, temp_dir.path())
+        .await
+        .expect("fallback download should succeed");
+
+    assert_eq!(std::fs::read(&result.path).expect("saved file"), body);
+    assert_eq!(result.bytes_downloaded, body.len() as u64);
+    assert!(!Path::new(&format!("{}.part", result.path.display())).exists());
+}
+
+#[tokio::test]
+async fn test_segmented_download_skips_files_below_threshold() {
+    let Some(mock_server) = start_mock_server_or_skip().await else {
+        return;
+    };
+    Mock::given(method("HEAD"))
+        .and(path("/small.zip"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("accept-ranges", "bytes")
+                .insert_header("content-length", "5"),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/small.zip"))
+        .and(wiremock::matchers::header_exists("range"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/small.zip"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(b"small".to_vec()))
+        .mount(&mock_server)
+        .await;
+    let temp_dir = TempDir::new().expect("failed to create temp dir");
+
+    let client = HttpClient::new().with_segmented_downloads(1024, 4);
+    let url = format!("{}/small.zip", mock_server.uri());
+    let path = client
+        .download_to_file(&url, temp_dir.path())
+        .await
+        .expect("download should succeed");
+
+    assert_eq!(std::fs::read(path).expect("saved file"), b"small");
+}
@@ -6,7 +6,7 @@
 
 use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use downloader_core::{
     Database, DatabaseOptions, DownloadEngine, EngineError, HttpClient, Queue, QueueStatus,
@@ -81,7 +81,8 @@ async fn setup_download_bench(
         &db_path,
         &DatabaseOptions {
             max_connections: 4,
-            busy_timeout_ms: 5_000,
+            busy_timeout: Duration::from_millis(5_000),
+            ..DatabaseOptions::default()
         },
     )
     .await?;
@@ -109,7 +110,8 @@ async fn gate_queue_throughput_regression_is_within_5_percent()
         "throughput_gate.db",
         DatabaseOptions {
             max_connections: 4,
-            busy_timeout_ms: 5_000,
+            busy_timeout: Duration::from_millis(5_000),
+            ..DatabaseOptions::default()
         },
     )
     .await?;
@@ -152,7 +154,8 @@ async fn gate_retry_path_p95_regression_is_within_7_percent()
         "retry_p95_gate.db",
         DatabaseOptions {
             max_connections: 2,
-            busy_timeout_ms: 5_000,
+            busy_timeout: Duration::from_millis(5_000),
+            ..DatabaseOptions::default()
         },
     )
     .await?;
@@ -212,7 +215,8 @@ async fn gate_db_busy_lock_incidence_stays_below_half_percent()
         "lock_gate.db",
         DatabaseOptions {
             max_connections: 8,
-            busy_timeout_ms: 200,
+            busy_timeout: Duration::from_millis(200),
+            ..DatabaseOptions::default()
         },
     )
     .await?;
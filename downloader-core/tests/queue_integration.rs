@@ -2,9 +2,12 @@
 //!
 //! These tests verify Queue operations against a real SQLite database.
 
+use std::time::Duration;
+
 use downloader_core::{
-    Database, DownloadAttemptQuery, DownloadAttemptStatus, DownloadErrorType, DownloadSearchQuery,
-    NewDownloadAttempt, Queue, QueueError, QueueMetadata, QueueStatus, parse_input,
+    DEFAULT_CLAIM_LEASE_TIMEOUT, Database, DownloadAttemptQuery, DownloadAttemptStatus,
+    DownloadErrorType, DownloadSearchQuery, NewDownloadAttempt, Queue, QueueError, QueueMetadata,
+    QueueStatus, UNKNOWN_STATS_HOST, parse_input,
 };
 use sqlx::Row;
 use tempfile::TempDir;
@@ -70,6 +73,8 @@ async fn test_enqueue_with_metadata_persists_parse_confidence_fields() {
         suggested_filename: Some("Reference.pdf".to_string()),
         title: Some("Reference Title".to_string()),
         authors: Some("Author, A".to_string()),
+        author_orcids: None,
+        abstract_text: None,
         year: Some("2024".to_string()),
         doi: None,
         topics: None,
@@ -78,6 +83,9 @@ async fn test_enqueue_with_metadata_persists_parse_confidence_fields() {
             r#"{"has_authors":false,"has_year":true,"has_title":false,"author_count":0}"#
                 .to_string(),
         ),
+        read_timeout_secs: None,
+        resolver_path: None,
+        input_source_file: None,
     };
 
     let id = queue
@@ -155,6 +163,69 @@ async fn test_dequeue_respects_priority_order() {
     assert_eq!(item.url, "https://example.com/second.pdf");
 }
 
+#[tokio::test]
+async fn test_enqueue_with_priority_is_dequeued_first() {
+    let (db, _temp_dir) = setup_test_db().await;
+    let queue = Queue::new(db);
+
+    queue
+        .enqueue("https://example.com/low.pdf", "direct_url", None)
+        .await
+        .unwrap();
+    let high = queue
+        .enqueue_with_priority("https://example.com/high.pdf", "doi", None, None, None, 20)
+        .await
+        .unwrap();
+
+    let stored = queue.get(high).await.unwrap().unwrap();
+    assert_eq!(stored.priority, 20);
+
+    let item = queue.dequeue().await.unwrap().unwrap();
+    assert_eq!(item.url, "https://example.com/high.pdf");
+}
+
+#[tokio::test]
+async fn test_set_priority_reorders_pending_items_mid_run() {
+    let (db, _temp_dir) = setup_test_db().await;
+    let queue = Queue::new(db);
+
+    for name in ["a", "b", "c"] {
+        queue
+            .enqueue(
+                &format!("https://example.com/{name}.pdf"),
+                "direct_url",
+                None,
+            )
+            .await
+            .unwrap();
+    }
+
+    // Claim one item, then bump the last pending item as a user would mid-run.
+    let first = queue.dequeue().await.unwrap().unwrap();
+    assert_eq!(first.url, "https://example.com/a.pdf");
+    let last = queue
+        .list_by_status(QueueStatus::Pending)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_eq!(last.url, "https://example.com/c.pdf");
+    queue.set_priority(last.id, 100).await.unwrap();
+
+    let next = queue.dequeue().await.unwrap().unwrap();
+    assert_eq!(next.url, "https://example.com/c.pdf");
+    assert_eq!(next.priority, 100);
+}
+
+#[tokio::test]
+async fn test_set_priority_missing_item_returns_not_found() {
+    let (db, _temp_dir) = setup_test_db().await;
+    let queue = Queue::new(db);
+
+    let result = queue.set_priority(999, 5).await;
+    assert!(matches!(result, Err(QueueError::ItemNotFound(999))));
+}
+
 // ==================== Status Transitions ====================
 
 #[tokio::test]
@@ -451,6 +522,159 @@ async fn test_reset_in_progress() {
     );
 }
 
+#[tokio::test]
+async fn test_reset_in_progress_keeps_live_claims_of_other_processes() {
+    let (db, _temp_dir) = setup_test_db().await;
+    let db_for_update = db.clone();
+    let queue = Queue::new(db);
+
+    let own_id = queue
+        .enqueue("https://example.com/own.pdf", "direct_url", None)
+        .await
+        .unwrap();
+    let other_id = queue
+        .enqueue("https://example.com/other.pdf", "direct_url", None)
+        .await
+        .unwrap();
+    queue.dequeue().await.unwrap();
+    queue.dequeue().await.unwrap();
+
+    // Simulate a second live process holding `other_id`.
+    sqlx::query("UPDATE queue SET claimed_by = 'pid-other-process' WHERE id = ?")
+        .bind(other_id)
+        .execute(db_for_update.pool())
+        .await
+        .unwrap();
+
+    assert_eq!(queue.reset_in_progress().await.unwrap(), 1);
+    let own = queue.get(own_id).await.unwrap().unwrap();
+    assert_eq!(own.status(), QueueStatus::Pending);
+    let other = queue.get(other_id).await.unwrap().unwrap();
+    assert_eq!(other.status(), QueueStatus::InProgress);
+
+    // Once the other process's lease expires, recovery reclaims the row.
+    let expired = format!("-{} seconds", DEFAULT_CLAIM_LEASE_TIMEOUT.as_secs() + 60);
+    sqlx::query("UPDATE queue SET claimed_at = datetime('now', ?) WHERE id = ?")
+        .bind(expired)
+        .bind(other_id)
+        .execute(db_for_update.pool())
+        .await
+        .unwrap();
+    assert_eq!(queue.reset_in_progress().await.unwrap(), 1);
+    let other = queue.get(other_id).await.unwrap().unwrap();
+    assert_eq!(other.status(), QueueStatus::Pending);
+}
+
+#[tokio::test]
+async fn test_reset_in_progress_reclaims_rows_of_exited_processes() {
+    let (db, _temp_dir) = setup_test_db().await;
+    let db_for_update = db.clone();
+    let queue = Queue::new(db);
+
+    let dead_id = queue
+        .enqueue("https://example.com/dead.pdf", "direct_url", None)
+        .await
+        .unwrap();
+    let live_id = queue
+        .enqueue("https://example.com/live.pdf", "direct_url", None)
+        .await
+        .unwrap();
+    queue.dequeue().await.unwrap();
+    queue.dequeue().await.unwrap();
+
+    // A PID above any kernel's pid_max cannot be running; PID 1 always is.
+    for (claimant, id) in [("pid-999999999-1", dead_id), ("pid-1-1", live_id)] {
+        sqlx::query("UPDATE queue SET claimed_by = ? WHERE id = ?")
+            .bind(claimant)
+            .bind(id)
+            .execute(db_for_update.pool())
+            .await
+            .unwrap();
+    }
+
+    let expected_reset = if cfg!(unix) { 1 } else { 0 };
+    assert_eq!(queue.reset_in_progress().await.unwrap(), expected_reset);
+    let live = queue.get(live_id).await.unwrap().unwrap();
+    assert_eq!(live.status(), QueueStatus::InProgress);
+    if cfg!(unix) {
+        let dead = queue.get(dead_id).await.unwrap().unwrap();
+        assert_eq!(dead.status(), QueueStatus::Pending);
+    }
+}
+
+#[tokio::test]
+async fn test_renew_claim_refreshes_lease_of_own_claims_only() {
+    let (db, _temp_dir) = setup_test_db().await;
+    let db_for_update = db.clone();
+    let queue = Queue::new(db);
+
+    let id = queue
+        .enqueue("https://example.com/long.pdf", "direct_url", None)
+        .await
+        .unwrap();
+    queue.dequeue().await.unwrap();
+
+    sqlx::query("UPDATE queue SET claimed_at = datetime('now', '-20 minutes') WHERE id = ?")
+        .bind(id)
+        .execute(db_for_update.pool())
+        .await
+        .unwrap();
+    assert!(queue.renew_claim(id).await.unwrap());
+    assert_eq!(
+        queue
+            .reset_stale_in_progress(Duration::from_secs(600))
+            .await
+            .unwrap(),
+        0
+    );
+
+    sqlx::query("UPDATE queue SET claimed_by = 'pid-other-process' WHERE id = ?")
+        .bind(id)
+        .execute(db_for_update.pool())
+        .await
+        .unwrap();
+    assert!(!queue.renew_claim(id).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_reset_stale_in_progress_only_reclaims_old_leases() {
+    let (db, _temp_dir) = setup_test_db().await;
+    let db_for_update = db.clone();
+    let queue = Queue::new(db);
+
+    let id = queue
+        .enqueue("https://example.com/slow.pdf", "direct_url", None)
+        .await
+        .unwrap();
+    queue.dequeue().await.unwrap();
+
+    // A fresh lease is kept, even one held by this process.
+    assert_eq!(
+        queue
+            .reset_stale_in_progress(Duration::from_secs(600))
+            .await
+            .unwrap(),
+        0
+    );
+
+    sqlx::query("UPDATE queue SET claimed_at = datetime('now', '-20 minutes') WHERE id = ?")
+        .bind(id)
+        .execute(db_for_update.pool())
+        .await
+        .unwrap();
+    assert_eq!(
+        queue
+            .reset_stale_in_progress(Duration::from_secs(600))
+            .await
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        queue.get(id).await.unwrap().unwrap().status(),
+        QueueStatus::Pending
+    );
+}
+
 // ==================== Removal Operations ====================
 
 #[tokio::test]
@@ -616,6 +840,336 @@ async fn test_long_url_and_error_message() {
     assert_eq!(item.last_error, Some(long_error));
 }
 
+// ==================== Dead Letter ====================
+
+#[tokio::test]
+async fn test_dead_letter_items_are_listed_and_skipped_by_recovery() {
+    let (db, _temp_dir) = setup_test_db().await;
+    let queue = Queue::new(db);
+
+    let dead_id = queue
+        .enqueue("https://example.com/gone.pdf", "direct_url", None)
+        .await
+        .unwrap();
+    let failed_id = queue
+        .enqueue("https://example.com/flaky.pdf", "direct_url", None)
+        .await
+        .unwrap();
+    queue
+        .mark_dead_letter(dead_id, "HTTP 404", 0)
+        .await
+        .expect("mark_dead_letter should succeed");
+    queue.mark_failed(failed_id, "timeout", 2).await.unwrap();
+
+    let dead = queue.list_dead_letter().await.unwrap();
+    assert_eq!(dead.len(), 1);
+    assert_eq!(dead[0].id, dead_id);
+    assert!(dead[0].dead_letter);
+    assert_eq!(dead[0].status(), QueueStatus::Failed);
+    assert_eq!(dead[0].last_error.as_deref(), Some("HTTP 404"));
+
+    assert_eq!(queue.reset_in_progress().await.unwrap(), 0);
+    assert_eq!(
+        queue.count_by_status(QueueStatus::Pending).await.unwrap(),
+        0
+    );
+    assert!(queue.dequeue().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_requeue_dead_letter_returns_item_to_pending() {
+    let (db, _temp_dir) = setup_test_db().await;
+    let queue = Queue::new(db);
+
+    let id = queue
+        .enqueue("https://example.com/gone.pdf", "direct_url", None)
+        .await
+        .unwrap();
+    queue.mark_dead_letter(id, "HTTP 404", 1).await.unwrap();
+
+    queue
+        .requeue_dead_letter(id)
+        .await
+        .expect("requeue_dead_letter should succeed");
+
+    let item = queue.get(id).await.unwrap().unwrap();
+    assert_eq!(item.status(), QueueStatus::Pending);
+    assert!(!item.dead_letter);
+    assert_eq!(item.retry_count, 0);
+    assert!(queue.list_dead_letter().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_requeue_dead_letter_rejects_plain_failed_items() {
+    let (db, _temp_dir) = setup_test_db().await;
+    let queue = Queue::new(db);
+
+    let id = queue
+        .enqueue("https://example.com/flaky.pdf", "direct_url", None)
+        .await
+        .unwrap();
+    queue.mark_failed(id, "timeout", 0).await.unwrap();
+
+    let result = queue.requeue_dead_letter(id).await;
+    assert!(matches!(result, Err(QueueError::ItemNotFound(found)) if found == id));
+}
+
+#[tokio::test]
+async fn test_requeue_all_dead_letter_respects_project_scope() {
+    let (db, _temp_dir) = setup_test_db().await;
+    let queue = Queue::new(db);
+
+    for (url, project) in [
+        ("https://example.com/a.pdf", "project-a"),
+        ("https://example.com/b.pdf", "project-b"),
+    ] {
+        let id = queue
+            .enqueue_in_project(url, "direct_url", None, Some(project))
+            .await
+            .unwrap();
+        queue.mark_dead_letter(id, "HTTP 410", 0).await.unwrap();
+    }
+
+    let requeued = queue
+        .requeue_all_dead_letter_in_project(Some("project-a"))
+        .await
+        .unwrap();
+    assert_eq!(requeued, 1);
+    assert_eq!(
+        queue
+            .list_dead_letter_in_project(Some("project-b"))
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+// ==================== Export / Import ====================
+
+#[tokio::test]
+async fn test_set_topics_replaces_stored_topics() {
+    let (db, _temp_dir) = setup_test_db().await;
+    let queue = Queue::new(db);
+    let id = queue
+        .enqueue("https://example.com/paper.pdf", "direct_url", None)
+        .await
+        .expect("Failed to enqueue");
+
+    let topics = vec!["surface codes".to_string(), "qubits".to_string()];
+    queue.set_topics(id, &topics).await.expect("set topics");
+    let item = queue.get(id).await.unwrap().unwrap();
+    assert_eq!(item.parse_topics(), topics);
+
+    queue.set_topics(id, &[]).await.expect("clear topics");
+    assert!(queue.get(id).await.unwrap().unwrap().topics.is_none());
+
+    let missing = queue.set_topics(9999, &topics).await;
+    assert!(matches!(missing, Err(QueueError::ItemNotFound(9999))));
+}
+
+#[tokio::test]
+async fn test_export_import_round_trip_preserves_metadata_and_completed_paths() {
+    let (source_db, _source_dir) = setup_test_db().await;
+    let source = Queue::new(source_db);
+
+    let metadata = QueueMetadata {
+        suggested_filename: Some("Smith_2024_Paper.pdf".to_string()),
+        title: Some("A Paper".to_string()),
+        authors: Some("Smith, J".to_string()),
+        author_orcids: None,
+        abstract_text: Some("We study climate models.".to_string()),
+        year: Some("2024".to_string()),
+        doi: Some("10.1234/paper".to_string()),
+        topics: Some(vec!["climate".to_string(), "models".to_string()]),
+        parse_confidence: Some("high".to_string()),
+        parse_confidence_factors: Some(r#"{"has_year":true}"#.to_string()),
+        read_timeout_secs: None,
+        resolver_path: None,
+        input_source_file: None,
+    };
+    let pending_id = source
+        .enqueue_with_metadata_in_project(
+            "https://example.com/pending.pdf",
+            "doi",
+            Some("10.1234/paper"),
+            Some(&metadata),
+            Some("old-project"),
+        )
+        .await
+        .expect("enqueue pending should succeed");
+    let done_id = source
+        .enqueue("https://example.com/done.pdf", "direct_url", None)
+        .await
+        .expect("enqueue done should succeed");
+    source
+        .mark_completed_with_path(done_id, Some(std::path::Path::new("/data/done.pdf")))
+        .await
+        .expect("mark completed should succeed");
+
+    let mut payload = Vec::new();
+    source
+        .export_json(&mut payload)
+        .await
+        .expect("export should succeed");
+
+    let (target_db, _target_dir) = setup_test_db().await;
+    let target = Queue::new(target_db);
+    target
+        .enqueue("https://example.com/unrelated.pdf", "direct_url", None)
+        .await
+        .expect("enqueue unrelated should succeed");
+
+    let imported = target
+        .import_json(payload.as_slice())
+        .await
+        .expect("import should succeed");
+    assert_eq!(imported, 2);
+
+    let original = source.get(pending_id).await.unwrap().unwrap();
+    let pending = target
+        .list_by_status(QueueStatus::Pending)
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|item| item.url == "https://example.com/pending.pdf")
+        .expect("imported pending item should exist");
+    assert_ne!(pending.id, original.id, "ids are reassigned on import");
+    assert_eq!(pending.source_type, original.source_type);
+    assert_eq!(pending.original_input, original.original_input);
+    assert_eq!(pending.project, original.project);
+    assert_eq!(pending.suggested_filename, original.suggested_filename);
+    assert_eq!(pending.meta_title, original.meta_title);
+    assert_eq!(pending.meta_authors, original.meta_authors);
+    assert_eq!(pending.meta_year, original.meta_year);
+    assert_eq!(pending.meta_doi, original.meta_doi);
+    assert_eq!(pending.meta_abstract, original.meta_abstract);
+    assert_eq!(pending.topics, original.topics);
+    assert_eq!(pending.parse_confidence, original.parse_confidence);
+    assert_eq!(
+        pending.parse_confidence_factors,
+        original.parse_confidence_factors
+    );
+
+    let completed = target.list_by_status(QueueStatus::Completed).await.unwrap();
+    assert_eq!(completed.len(), 1);
+    assert_eq!(completed[0].saved_path.as_deref(), Some("/data/done.pdf"));
+}
+
+#[tokio::test]
+async fn test_import_skips_urls_already_active_and_rescopes_project() {
+    let (source_db, _source_dir) = setup_test_db().await;
+    let source = Queue::new(source_db);
+    for url in ["https://example.com/a.pdf", "https://example.com/b.pdf"] {
+        source
+            .enqueue_in_project(url, "direct_url", None, Some("/old/machine"))
+            .await
+            .expect("enqueue should succeed");
+    }
+    let mut payload = Vec::new();
+    source.export_json(&mut payload).await.unwrap();
+
+    let (target_db, _target_dir) = setup_test_db().await;
+    let target = Queue::new(target_db);
+    target
+        .enqueue_in_project(
+            "https://example.com/a.pdf",
+            "direct_url",
+            None,
+            Some("/new/machine"),
+        )
+        .await
+        .unwrap();
+
+    let imported = target
+        .import_json_into_project(payload.as_slice(), Some("/new/machine"))
+        .await
+        .expect("import should succeed");
+    assert_eq!(imported, 1, "already-active url should be skipped");
+    assert_eq!(
+        target
+            .count_by_status_in_project(QueueStatus::Pending, Some("/new/machine"))
+            .await
+            .unwrap(),
+        2
+    );
+}
+
+#[tokio::test]
+async fn test_import_rejects_non_queue_payload() {
+    let (db, _temp_dir) = setup_test_db().await;
+    let queue = Queue::new(db);
+    let result = queue.import_json(&br#"{"items": 3}"#[..]).await;
+    assert!(matches!(result, Err(QueueError::Transfer(_))));
+}
+
+#[tokio::test]
+async fn test_oldest_item_age_secs_in_project_reports_oldest_scoped_item() {
+    let (db, _temp_dir) = setup_test_db().await;
+    let db_for_query = db.clone();
+    let queue = Queue::new(db);
+    assert_eq!(
+        queue
+            .oldest_item_age_secs_in_project(QueueStatus::Pending, Some("/p"))
+            .await
+            .unwrap(),
+        None
+    );
+
+    let old = queue
+        .enqueue_in_project(
+            "https://example.com/old.pdf",
+            "direct_url",
+            None,
+            Some("/p"),
+        )
+        .await
+        .unwrap();
+    queue
+        .enqueue_in_project(
+            "https://example.com/new.pdf",
+            "direct_url",
+            None,
+            Some("/p"),
+        )
+        .await
+        .unwrap();
+    queue
+        .enqueue_in_project(
+            "https://example.com/other.pdf",
+            "direct_url",
+            None,
+            Some("/other"),
+        )
+        .await
+        .unwrap();
+    sqlx::query("UPDATE queue SET created_at = datetime('now', '-2 hours') WHERE id = ?")
+        .bind(old)
+        .execute(db_for_query.pool())
+        .await
+        .unwrap();
+
+    let age = queue
+        .oldest_item_age_secs_in_project(QueueStatus::Pending, Some("/p"))
+        .await
+        .unwrap()
+        .expect("pending items exist");
+    assert!((7_190..=7_210).contains(&age), "got {age}");
+    let other_age = queue
+        .oldest_item_age_secs_in_project(QueueStatus::Pending, Some("/other"))
+        .await
+        .unwrap()
+        .expect("pending item exists");
+    assert!(other_age < 60, "got {other_age}");
+    assert_eq!(
+        queue
+            .oldest_item_age_secs_in_project(QueueStatus::Completed, None)
+            .await
+            .unwrap(),
+        None
+    );
+}
+
 // ==================== Concurrency ====================
 
 #[tokio::test]
@@ -746,6 +1300,8 @@ async fn test_log_download_attempt_persists_success_row() {
         parse_confidence_factors: Some(
             r#"{"has_authors":true,"has_year":true,"has_title":true,"author_count":1}"#,
         ),
+        resolver_path: Some("arxiv->direct"),
+        input_source_file: None,
     };
 
     let id = queue
@@ -773,6 +1329,7 @@ async fn test_log_download_attempt_persists_success_row() {
         row.parse_confidence_factors.as_deref(),
         Some(r#"{"has_authors":true,"has_year":true,"has_title":true,"author_count":1}"#)
     );
+    assert_eq!(row.resolver_path.as_deref(), Some("arxiv->direct"));
 }
 
 #[tokio::test]
@@ -803,6 +1360,8 @@ async fn test_log_download_attempt_confidence_factors_are_queryable_as_json() {
         parse_confidence_factors: Some(
             r#"{"has_authors":false,"has_year":true,"has_title":false,"author_count":0}"#,
         ),
+        resolver_path: None,
+        input_source_file: None,
     };
     queue
         .log_download_attempt(&low_attempt)
@@ -829,6 +1388,8 @@ async fn test_log_download_attempt_confidence_factors_are_queryable_as_json() {
         topics: None,
         parse_confidence: None,
         parse_confidence_factors: None,
+        resolver_path: None,
+        input_source_file: None,
     };
     queue
         .log_download_attempt(&legacy_attempt)
@@ -897,6 +1458,8 @@ async fn test_log_download_attempt_persists_topics_in_download_log() {
         topics: Some(topics_json),
         parse_confidence: None,
         parse_confidence_factors: None,
+        resolver_path: None,
+        input_source_file: None,
     };
 
     let id = queue
@@ -948,6 +1511,8 @@ async fn test_log_download_attempt_stores_null_when_topics_is_none() {
         topics: None,
         parse_confidence: None,
         parse_confidence_factors: None,
+        resolver_path: None,
+        input_source_file: None,
     };
 
     let id = queue
@@ -996,6 +1561,8 @@ async fn test_query_download_attempts_filters_status_project_and_date() {
         parse_confidence_factors: Some(
             r#"{"has_authors":false,"has_year":true,"has_title":false,"author_count":0}"#,
         ),
+        resolver_path: None,
+        input_source_file: None,
     };
     queue
         .log_download_attempt(&success_attempt)
@@ -1024,6 +1591,8 @@ async fn test_query_download_attempts_filters_status_project_and_date() {
         parse_confidence_factors: Some(
             r#"{"has_authors":true,"has_year":true,"has_title":false,"author_count":1}"#,
         ),
+        resolver_path: None,
+        input_source_file: None,
     };
     queue
         .log_download_attempt(&failed_attempt)
@@ -1117,6 +1686,112 @@ async fn test_query_download_attempts_filters_status_project_and_date() {
     );
 }
 
+#[tokio::test]
+async fn test_query_domain_stats_aggregates_throughput_per_host() {
+    let (db, _temp_dir) = setup_test_db().await;
+    let queue = Queue::new(db);
+
+    // (url, status, file_size, duration_ms, project)
+    let rows: [(&str, DownloadAttemptStatus, Option<i64>, Option<i64>, &str); 6] = [
+        (
+            "https://slow.example.org/a.pdf",
+            DownloadAttemptStatus::Success,
+            Some(1_000),
+            Some(2_000),
+            "project-a",
+        ),
+        (
+            "https://slow.example.org/b.pdf",
+            DownloadAttemptStatus::Success,
+            None,
+            Some(8_000),
+            "project-a",
+        ),
+        (
+            "https://slow.example.org/c.pdf",
+            DownloadAttemptStatus::Failed,
+            None,
+            Some(100),
+            "project-a",
+        ),
+        (
+            "https://fast.example.org/d.pdf",
+            DownloadAttemptStatus::Success,
+            Some(10_000),
+            Some(1_000),
+            "project-a",
+        ),
+        (
+            "https://fast.example.org/e.pdf",
+            DownloadAttemptStatus::Skipped,
+            None,
+            None,
+            "project-a",
+        ),
+        (
+            "https://other.example.org/f.pdf",
+            DownloadAttemptStatus::Success,
+            Some(5),
+            Some(5),
+            "project-b",
+        ),
+    ];
+    for (url, status, file_size, duration_ms, project) in rows {
+        let attempt = NewDownloadAttempt {
+            url,
+            final_url: None,
+            status,
+            file_path: None,
+            file_size,
+            content_type: None,
+            error_message: None,
+            error_type: None,
+            retry_count: 0,
+            project: Some(project),
+            original_input: Some(url),
+            http_status: None,
+            duration_ms,
+            title: None,
+            authors: None,
+            doi: None,
+            topics: None,
+            parse_confidence: None,
+            parse_confidence_factors: None,
+            resolver_path: None,
+            input_source_file: None,
+        };
+        queue
+            .log_download_attempt(&attempt)
+            .await
+            .expect("history row insert should succeed");
+    }
+
+    let stats = queue
+        .query_domain_stats(Some("project-a"), None)
+        .await
+        .expect("domain stats query should succeed");
+    assert_eq!(stats.len(), 2);
+    assert!(stats.iter().all(|entry| entry.domain != UNKNOWN_STATS_HOST));
+
+    let fast = &stats[0];
+    assert_eq!(fast.domain, "fast.example.org");
+    assert_eq!(fast.summary.attempts, 1, "skipped rows are ignored");
+    assert_eq!(fast.summary.bytes_per_sec, Some(10_000.0));
+
+    let slow = &stats[1];
+    assert_eq!(slow.domain, "slow.example.org");
+    assert_eq!(slow.summary.attempts, 3);
+    assert_eq!(slow.summary.succeeded, 2);
+    assert_eq!(slow.summary.total_bytes, 1_000);
+    assert_eq!(
+        slow.summary.bytes_per_sec,
+        Some(500.0),
+        "rows without file_size are left out of throughput"
+    );
+    assert_eq!(slow.summary.p50_duration_ms, Some(2_000));
+    assert_eq!(slow.summary.p95_duration_ms, Some(8_000));
+}
+
 #[tokio::test]
 async fn test_query_download_attempts_domain_filter_paginates_past_non_matches() {
     let (db, _temp_dir) = setup_test_db().await;
@@ -1145,6 +1820,8 @@ async fn test_query_download_attempts_domain_filter_paginates_past_non_matches()
         parse_confidence_factors: Some(
             r#"{"has_authors":false,"has_year":true,"has_title":false,"author_count":0}"#,
         ),
+        resolver_path: None,
+        input_source_file: None,
     };
     queue
         .log_download_attempt(&matching_attempt)
@@ -1173,6 +1850,8 @@ async fn test_query_download_attempts_domain_filter_paginates_past_non_matches()
             topics: None,
             parse_confidence: None,
             parse_confidence_factors: None,
+            resolver_path: None,
+            input_source_file: None,
         };
         queue
             .log_download_attempt(&noise_attempt)
@@ -1222,6 +1901,8 @@ async fn test_query_download_search_candidates_filters_openable_project_and_date
         topics: None,
         parse_confidence: None,
         parse_confidence_factors: None,
+        resolver_path: None,
+        input_source_file: None,
     };
     queue
         .log_download_attempt(&success_openable)
@@ -1248,6 +1929,8 @@ async fn test_query_download_search_candidates_filters_openable_project_and_date
         topics: None,
         parse_confidence: None,
         parse_confidence_factors: None,
+        resolver_path: None,
+        input_source_file: None,
     };
     queue
         .log_download_attempt(&success_without_path)
@@ -1274,6 +1957,8 @@ async fn test_query_download_search_candidates_filters_openable_project_and_date
         topics: None,
         parse_confidence: None,
         parse_confidence_factors: None,
+        resolver_path: None,
+        input_source_file: None,
     };
     queue
         .log_download_attempt(&failed_row)
@@ -1300,6 +1985,8 @@ async fn test_query_download_search_candidates_filters_openable_project_and_date
         topics: None,
         parse_confidence: None,
         parse_confidence_factors: None,
+        resolver_path: None,
+        input_source_file: None,
     };
     queue
         .log_download_attempt(&other_project)
@@ -1365,6 +2052,8 @@ async fn test_query_download_search_candidates_orders_by_recency_desc() {
             topics: None,
             parse_confidence: None,
             parse_confidence_factors: None,
+            resolver_path: None,
+            input_source_file: None,
         };
         queue
             .log_download_attempt(&attempt)
@@ -1414,6 +2103,8 @@ async fn test_query_download_search_candidates_since_until_bounds_are_inclusive(
             topics: None,
             parse_confidence: None,
             parse_confidence_factors: None,
+            resolver_path: None,
+            input_source_file: None,
         })
         .await
         .expect("first row insert should succeed");
@@ -1439,6 +2130,8 @@ async fn test_query_download_search_candidates_since_until_bounds_are_inclusive(
             topics: None,
             parse_confidence: None,
             parse_confidence_factors: None,
+            resolver_path: None,
+            input_source_file: None,
         })
         .await
         .expect("middle row insert should succeed");
@@ -1464,6 +2157,8 @@ async fn test_query_download_search_candidates_since_until_bounds_are_inclusive(
             topics: None,
             parse_confidence: None,
             parse_confidence_factors: None,
+            resolver_path: None,
+            input_source_file: None,
         })
         .await
         .expect("last row insert should succeed");
@@ -1507,3 +2202,96 @@ async fn test_query_download_search_candidates_since_until_bounds_are_inclusive(
     assert_eq!(inclusive_rows[0].id, middle_id);
     assert_eq!(inclusive_rows[1].id, first_id);
 }
+
+#[tokio::test]
+async fn test_prune_download_attempts_deletes_only_old_matching_rows() {
+    let (db, _temp_dir) = setup_test_db().await;
+    let db_for_update = db.clone();
+    let queue = Queue::new(db);
+    queue
+        .enqueue("https://example.com/pending.pdf", "direct_url", None)
+        .await
+        .unwrap();
+
+    for (url, status) in [
+        (
+            "https://example.com/old-failed.pdf",
+            DownloadAttemptStatus::Failed,
+        ),
+        (
+            "https://example.com/old-success.pdf",
+            DownloadAttemptStatus::Success,
+        ),
+        (
+            "https://example.com/new-failed.pdf",
+            DownloadAttemptStatus::Failed,
+        ),
+    ] {
+        let attempt = NewDownloadAttempt {
+            url,
+            final_url: None,
+            status,
+            file_path: None,
+            file_size: None,
+            content_type: None,
+            error_message: None,
+            error_type: None,
+            retry_count: 0,
+            project: None,
+            original_input: None,
+            http_status: None,
+            duration_ms: None,
+            title: None,
+            authors: None,
+            doi: None,
+            topics: None,
+            parse_confidence: None,
+            parse_confidence_factors: None,
+            resolver_path: None,
+            input_source_file: None,
+        };
+        queue.log_download_attempt(&attempt).await.unwrap();
+    }
+    sqlx::query(
+        "UPDATE download_log SET started_at = '2024-06-01 12:00:00' WHERE url LIKE '%/old-%'",
+    )
+    .execute(db_for_update.pool())
+    .await
+    .unwrap();
+
+    let failed = Some(DownloadAttemptStatus::Failed);
+    assert_eq!(
+        queue
+            .count_download_attempts_before("2025-01-01", failed)
+            .await
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        queue
+            .prune_download_attempts("2025-01-01", failed)
+            .await
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        queue
+            .prune_download_attempts("2025-01-01", None)
+            .await
+            .unwrap(),
+        1,
+        "the old success row remains until pruned without a status filter"
+    );
+
+    let remaining = queue
+        .query_download_attempts(&DownloadAttemptQuery::default())
+        .await
+        .unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert!(remaining[0].url.ends_with("/new-failed.pdf"));
+    assert_eq!(
+        queue.count_by_status(QueueStatus::Pending).await.unwrap(),
+        1,
+        "queue items are not touched"
+    );
+}
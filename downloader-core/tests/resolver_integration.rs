@@ -6,9 +6,10 @@ use std::sync::Arc;
 
 use downloader_core::parser::InputType;
 use downloader_core::resolver::{
-    ArxivResolver, CrossrefResolver, DirectResolver, IeeeResolver, OxfordAcademicResolver,
-    PubMedResolver, ResolveContext, ResolvedUrl, ResolverRegistry, STANDARD_METADATA_KEYS,
-    ScienceDirectResolver, SpringerResolver, build_default_resolver_registry,
+    ArxivResolver, CitationMetaResolver, CrossrefConfig, CrossrefResolver, DEFAULT_CROSSREF_MAILTO,
+    DirectResolver, IeeeResolver, OpenAlexResolver, OxfordAcademicResolver, PubMedResolver,
+    ResolveContext, ResolvedUrl, ResolverRegistry, STANDARD_METADATA_KEYS, ScienceDirectResolver,
+    SpringerResolver, build_default_resolver_registry,
 };
 use reqwest::cookie::Jar;
 use wiremock::matchers::{header_regex, method, path, path_regex};
@@ -199,6 +200,50 @@ async fn test_crossref_resolver_in_registry_resolves_doi_to_pdf() {
     );
 }
 
+#[tokio::test]
+async fn test_openalex_supplies_oa_copy_after_crossref_doi_redirect() {
+    let Some(mock_server) = start_mock_server_or_skip().await else {
+        return;
+    };
+
+    Mock::given(method("GET"))
+        .and(path_regex(r"/works/10\..+"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(crossref_no_pdf_json()))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/works/doi:10.1234/test.paper"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "title": "Integration Test Paper",
+            "open_access": {"is_oa": true, "oa_url": "https://repository.example.org/paper.pdf"}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut registry = ResolverRegistry::new();
+    registry.register(Box::new(
+        CrossrefResolver::with_base_url("test@example.com", mock_server.uri()).unwrap(),
+    ));
+    registry.register(Box::new(
+        OpenAlexResolver::with_base_url("test@example.com", mock_server.uri()).unwrap(),
+    ));
+    registry.register(Box::new(DirectResolver::new()));
+
+    let resolved = registry
+        .resolve_to_url(
+            "10.1234/test.paper",
+            InputType::Doi,
+            &ResolveContext::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resolved.url, "https://repository.example.org/paper.pdf");
+    assert_eq!(
+        resolved.resolver_path.as_deref(),
+        Some("crossref->openalex")
+    );
+}
+
 #[tokio::test]
 async fn test_crossref_resolver_no_pdf_redirects_through_direct() {
     let Some(mock_server) = start_mock_server_or_skip().await else {
@@ -1301,11 +1346,26 @@ async fn test_springer_resolver_builds_canonical_pdf_url_when_citation_meta_abse
 
 #[tokio::test]
 async fn test_shared_default_registry_applies_specialized_priority_matrix() {
-    let registry = build_default_resolver_registry(None, "test@example.com");
+    let registry =
+        build_default_resolver_registry(None, &CrossrefConfig::new("test@example.com"), &[]);
 
     let cases = [
         ("10.48550/arXiv.2301.12345", InputType::Doi, "arxiv"),
         ("10.1109/5.771073", InputType::Doi, "ieee"),
+        ("10.2307/1234567", InputType::Doi, "jstor"),
+        (
+            "https://www.jstor.org/stable/1234567",
+            InputType::Url,
+            "jstor",
+        ),
+        ("10.2139/ssrn.123456", InputType::Doi, "ssrn"),
+        (
+            "https://papers.ssrn.com/sol3/papers.cfm?abstract_id=123456",
+            InputType::Url,
+            "ssrn",
+        ),
+        ("https://osf.io/abcde/", InputType::Url, "osf"),
+        ("https://psyarxiv.com/abcde", InputType::Url, "osf"),
         ("10.1093/brain/awab497", InputType::Doi, "oxford"),
         ("10.1007/s00134-020-06294-x", InputType::Doi, "springer"),
         (
@@ -1331,7 +1391,8 @@ async fn test_shared_default_registry_applies_specialized_priority_matrix() {
 
 #[tokio::test]
 async fn test_default_registry_falls_through_unknown_urls_cleanly() {
-    let registry = build_default_resolver_registry(None, "test@example.com");
+    let registry =
+        build_default_resolver_registry(None, &CrossrefConfig::new("test@example.com"), &[]);
     let ctx = ResolveContext::default();
 
     let result = registry
@@ -1342,9 +1403,50 @@ async fn test_default_registry_falls_through_unknown_urls_cleanly() {
     assert_eq!(result.url, "https://example.com/unknown.pdf");
 }
 
+#[tokio::test]
+async fn test_citation_meta_landing_page_resolves_before_direct_passthrough() {
+    let Some(mock_server) = start_mock_server_or_skip().await else {
+        return;
+    };
+    Mock::given(method("GET"))
+        .and(path("/article/abc"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"<meta name="citation_title" content="Landing Page Paper">
+               <meta name="citation_pdf_url" content="/pdf/abc.pdf">"#,
+            "text/html",
+        ))
+        .mount(&mock_server)
+        .await;
+
+    let mut registry = ResolverRegistry::new();
+    registry.register(Box::new(CitationMetaResolver::new(None).unwrap()));
+    registry.register(Box::new(DirectResolver::new()));
+
+    let ctx = ResolveContext::default();
+    let landing = registry
+        .resolve_to_url(
+            &format!("{}/article/abc", mock_server.uri()),
+            InputType::Url,
+            &ctx,
+        )
+        .await
+        .unwrap();
+    assert_eq!(landing.url, format!("{}/pdf/abc.pdf", mock_server.uri()));
+    assert_eq!(landing.metadata.get("title").unwrap(), "Landing Page Paper");
+
+    // A page without citation meta falls through to the direct passthrough.
+    let plain_url = format!("{}/about", mock_server.uri());
+    let plain = registry
+        .resolve_to_url(&plain_url, InputType::Url, &ctx)
+        .await
+        .unwrap();
+    assert_eq!(plain.url, plain_url);
+}
+
 #[tokio::test]
 async fn regression_default_registry_registers_crossref_for_generic_dois() {
-    let registry = build_default_resolver_registry(None, "test@example.com");
+    let registry =
+        build_default_resolver_registry(None, &CrossrefConfig::new("test@example.com"), &[]);
     let handlers = registry.find_handlers("10.1234/example-doi", InputType::Doi);
     assert!(
         handlers.iter().any(|handler| handler.name() == "crossref"),
@@ -1353,12 +1455,57 @@ async fn regression_default_registry_registers_crossref_for_generic_dois() {
 }
 
 #[tokio::test]
-async fn regression_default_registry_skips_crossref_when_mailto_is_invalid() {
-    let registry = build_default_resolver_registry(None, "invalid\nmailto@example.com");
+async fn test_default_registry_orders_unpaywall_crossref_openalex_for_dois() {
+    let registry =
+        build_default_resolver_registry(None, &CrossrefConfig::new("test@example.com"), &[]);
+    let names: Vec<&str> = registry
+        .find_handlers("10.1234/example-doi", InputType::Doi)
+        .iter()
+        .map(|handler| handler.name())
+        .collect();
+    let position = |name: &str| names.iter().position(|handler| *handler == name);
+    let unpaywall = position("unpaywall").expect("Unpaywall handles generic DOIs");
+    let crossref = position("crossref").expect("Crossref handles generic DOIs");
+    assert!(unpaywall < crossref);
+    assert!(position("openalex").is_some_and(|openalex| crossref < openalex));
+    assert!(position("direct").is_none_or(|direct| unpaywall < direct));
+}
+
+#[tokio::test]
+async fn test_default_registry_skips_disabled_resolvers() {
+    let registry = build_default_resolver_registry(
+        None,
+        &CrossrefConfig::new("test@example.com"),
+        &[
+            "ieee".to_string(),
+            "crossref".to_string(),
+            "no-such".to_string(),
+        ],
+    );
+
+    let active = registry.active_resolver_names();
+    assert!(!active.contains(&"ieee"));
+    assert!(!active.contains(&"crossref"));
+    assert!(active.contains(&"direct"));
+    assert!(
+        registry
+            .find_handlers("10.1109/5.771073", InputType::Doi)
+            .iter()
+            .all(|handler| handler.name() != "ieee"),
+        "disabled IEEE resolver must not handle its own DOIs"
+    );
+}
+
+#[tokio::test]
+async fn regression_default_registry_keeps_crossref_when_mailto_is_invalid() {
+    let crossref = CrossrefConfig::new("invalid\nmailto@example.com");
+    assert_eq!(crossref.mailto(), DEFAULT_CROSSREF_MAILTO);
+
+    let registry = build_default_resolver_registry(None, &crossref, &[]);
     let handlers = registry.find_handlers("10.1234/example-doi", InputType::Doi);
     assert!(
-        handlers.is_empty(),
-        "invalid mailto should prevent Crossref registration; no other resolver should match generic DOI"
+        handlers.iter().any(|handler| handler.name() == "crossref"),
+        "invalid mailto should fall back to the default instead of dropping Crossref"
     );
 }
 